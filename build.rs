@@ -0,0 +1,26 @@
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Build script: embeds git SHA and build timestamp (seconds since unix epoch) as compile time env vars
+/// Important: build timestamp is taken from SOURCE_DATE_EPOCH env var if it is defined (reproducible builds),
+/// otherwise the current time is embedded
+fn main() {
+    let git_sha = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|git_sha| git_sha.trim().to_string())
+        .unwrap_or(String::from("unknown"));
+    let build_timestamp = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|source_date_epoch| source_date_epoch.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs());
+
+    println!("cargo:rustc-env=BUILD_GIT_SHA={git_sha}");
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={build_timestamp}");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+}
@@ -0,0 +1,52 @@
+use crate::constant::constants::{API_SERVER_ADDITIONAL_ADDRESSES_ENV_VAR, API_SERVER_HOST_DEFAULT, API_SERVER_HOST_ENV_VAR, API_SERVER_PORT_DEFAULT, API_SERVER_PORT_ENV_VAR, API_SERVER_UNIX_SOCKET_PATH_ENV_VAR};
+
+/// Gets [Vec<String>] API server TCP addresses ("host:port" values): configured host and port address first,
+/// then additional addresses (please, check constants.rs)
+pub fn get_api_server_addresses() -> Vec<String> {
+    let api_server_host = std::env::var(API_SERVER_HOST_ENV_VAR).unwrap_or(String::from(API_SERVER_HOST_DEFAULT));
+    let api_server_port = std::env::var(API_SERVER_PORT_ENV_VAR).unwrap_or(String::from(API_SERVER_PORT_DEFAULT));
+    let additional_addresses = std::env::var(API_SERVER_ADDITIONAL_ADDRESSES_ENV_VAR).unwrap_or_default();
+
+    create_api_server_addresses(format!("{api_server_host}:{api_server_port}"), &additional_addresses)
+}
+
+/// Gets [Option<String>] API server Unix domain socket path (API server just listens on TCP addresses if it is not defined)
+pub fn get_api_server_unix_socket_path() -> Option<String> {
+    std::env::var(API_SERVER_UNIX_SOCKET_PATH_ENV_VAR)
+        .ok()
+        .filter(|unix_socket_path| !unix_socket_path.trim().is_empty())
+}
+
+/// Creates [Vec<String>] API server TCP addresses by [String] main address and [&str] additional addresses
+/// (comma separated "host:port" values - blank and repeated values are skipped)
+fn create_api_server_addresses(main_address: String, additional_addresses: &str) -> Vec<String> {
+    let mut api_server_addresses = vec![main_address];
+    additional_addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|additional_address| !additional_address.is_empty())
+        .for_each(|additional_address| {
+            if !api_server_addresses.iter().any(|api_server_address| api_server_address == additional_address) {
+                api_server_addresses.push(String::from(additional_address));
+            }
+        });
+    api_server_addresses
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::api_listeners::create_api_server_addresses;
+
+    /// Scenario:
+    /// Creates API server addresses with blank and repeated additional addresses
+    /// Expectation:
+    /// Main address and unique additional addresses should be retrieved in configured order
+    #[test]
+    fn when_create_api_server_addresses_should_skip_blank_and_repeated_addresses() {
+        let api_server_addresses =
+            create_api_server_addresses(String::from("0.0.0.0:8097"), " 127.0.0.1:9000, ,0.0.0.0:8097,[::1]:9000");
+
+        assert_eq!(vec!["0.0.0.0:8097", "127.0.0.1:9000", "[::1]:9000"], api_server_addresses);
+    }
+}
@@ -0,0 +1,190 @@
+use std::any::Any;
+
+use axum::Router;
+use axum::extract::DefaultBodyLimit;
+use axum::http::header::{CONTENT_DISPOSITION, RANGE};
+use axum::http::{HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::from_fn;
+use axum::response::{IntoResponse, Response};
+use crate::config::app_state::AppState;
+use crate::config::base_path::get_api_base_path;
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_usize};
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::constant::constants::{API_COMPRESSION_ENABLED_DEFAULT, API_COMPRESSION_ENABLED_ENV_VAR, API_CORS_ALLOWED_ORIGINS_ENV_VAR, API_REQUEST_BODY_MAX_BYTES_DEFAULT, API_REQUEST_BODY_MAX_BYTES_ENV_VAR, EXCLUDED_COUNT_HEADER};
+use crate::controller::admin_controller::{AdminController, AdminControllerTrait};
+use crate::controller::cost_estimate_controller::{CostEstimateController, CostEstimateControllerTrait};
+use crate::controller::download_controller::{DownloadController, DownloadControllerTrait};
+use crate::controller::download_job_controller::{DownloadJobController, DownloadJobControllerTrait};
+use crate::controller::export_history_controller::{ExportHistoryController, ExportHistoryControllerTrait};
+use crate::controller::export_template_controller::{ExportTemplateController, ExportTemplateControllerTrait};
+use crate::controller::health_controller::{HealthController, HealthControllerTrait};
+use crate::controller::object_controller::{ObjectController, ObjectControllerTrait};
+use crate::controller::s3_event_controller::{S3EventController, S3EventControllerTrait};
+use crate::controller::s3_object_controller::{S3ObjectController, S3ObjectControllerTrait};
+use crate::controller::search_controller::{SearchController, SearchControllerTrait};
+use crate::controller::signed_link_controller::{SignedLinkController, SignedLinkControllerTrait};
+use crate::controller::static_website_controller::{StaticWebsiteController, StaticWebsiteControllerTrait};
+use crate::controller::stats_controller::{StatsController, StatsControllerTrait};
+use crate::controller::version_controller::{VersionController, VersionControllerTrait};
+use crate::middleware::access_log_middleware::access_log_middleware;
+use crate::middleware::api_deprecation_middleware::api_deprecation_middleware;
+use crate::middleware::assume_role_middleware::assume_role_middleware;
+use crate::middleware::concurrency_class_middleware::concurrency_class_middleware;
+use crate::middleware::download_quota_middleware::download_quota_middleware;
+use crate::middleware::feature_flag_middleware::feature_flag_middleware;
+use crate::middleware::maintenance_middleware::maintenance_middleware;
+use crate::middleware::credentials_passthrough_middleware::credentials_passthrough_middleware;
+use crate::middleware::rbac_middleware::rbac_middleware;
+use crate::middleware::tenant_middleware::tenant_middleware;
+use log::info;
+use tower_http::catch_panic::CatchPanicLayer;
+use tower_http::compression::CompressionLayer;
+use tower_http::compression::predicate::{DefaultPredicate, NotForContentType, Predicate};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::limit::RequestBodyLimitLayer;
+
+/// Middleware stack options struct (optional layers configuration)
+#[derive(Clone, Debug)]
+pub struct MiddlewareStackOptions {
+    /// CORS allowed origins ("*" allows any origin - CORS layer is not applied if it is empty)
+    pub cors_allowed_origins: Vec<String>,
+    /// maximum request body size (in bytes)
+    pub request_body_max_bytes: usize,
+    /// gzip responses compression (zip files and raw S3 files are not compressed)
+    pub compression_enabled: bool,
+}
+
+/// default initialization (please, check constants.rs)
+impl Default for MiddlewareStackOptions {
+    fn default() -> Self {
+        MiddlewareStackOptions {
+            cors_allowed_origins: parse_cors_allowed_origins(&std::env::var(API_CORS_ALLOWED_ORIGINS_ENV_VAR).unwrap_or_default()),
+            request_body_max_bytes: get_request_body_max_bytes(),
+            compression_enabled: get_env_var_as_bool(API_COMPRESSION_ENABLED_ENV_VAR, API_COMPRESSION_ENABLED_DEFAULT),
+        }
+    }
+}
+
+/// Gets [usize] configured maximum request body size (in bytes)
+pub fn get_request_body_max_bytes() -> usize {
+    get_env_var_as_usize(API_REQUEST_BODY_MAX_BYTES_ENV_VAR, API_REQUEST_BODY_MAX_BYTES_DEFAULT).max(1)
+}
+
+/// Creates [Router] API router with all controllers end-points and the middleware stack
+/// by [&AppState] application state (mounted under the configured API base path if it is defined - please, check constants.rs)
+pub fn create_api_router(app_state: &AppState) -> Router {
+    let controllers_router = HealthController::config_endpoints(app_state)
+        .merge(AdminController::config_endpoints(app_state))
+        .merge(CostEstimateController::config_endpoints(app_state))
+        .merge(DownloadController::config_endpoints(app_state))
+        .merge(DownloadJobController::config_endpoints(app_state))
+        .merge(ExportTemplateController::config_endpoints(app_state))
+        .merge(ExportHistoryController::config_endpoints(app_state))
+        .merge(ObjectController::config_endpoints(app_state))
+        .merge(S3EventController::config_endpoints(app_state))
+        .merge(S3ObjectController::config_endpoints(app_state))
+        .merge(SearchController::config_endpoints(app_state))
+        .merge(SignedLinkController::config_endpoints(app_state))
+        .merge(StaticWebsiteController::config_endpoints(app_state))
+        .merge(StatsController::config_endpoints(app_state))
+        .merge(VersionController::config_endpoints(app_state));
+    let middleware_stack_options = MiddlewareStackOptions::default();
+    info!("create_api_router - middleware stack options: {middleware_stack_options:?}");
+    let api_router = apply_middleware_stack(controllers_router, &middleware_stack_options);
+
+    match get_api_base_path().as_str() {
+        "" => api_router,
+        api_base_path => {
+            info!("create_api_router - API base path: {api_base_path}");
+            Router::new().nest(api_base_path, api_router)
+        }
+    }
+}
+
+/// Applies the middleware stack to [Router] controllers router by [&MiddlewareStackOptions] options
+/// Layers order (outermost first - each request goes through them in this order):
+/// panic handling, access log (tracing), concurrency classes (in-flight requests budgets), CORS, compression, API deprecation headers, maintenance mode,
+/// request body limit, multi-tenancy (requested S3 paths are tenant-scoped before they are authorized), role-based access
+/// control (auth), caller credentials passthrough, feature flags and download quota (rate limits)
+fn apply_middleware_stack(controllers_router: Router, middleware_stack_options: &MiddlewareStackOptions) -> Router {
+    let mut api_router = controllers_router
+        .layer(from_fn(download_quota_middleware))
+        .layer(from_fn(feature_flag_middleware))
+        .layer(from_fn(credentials_passthrough_middleware))
+        .layer(from_fn(assume_role_middleware))
+        .layer(from_fn(rbac_middleware))
+        .layer(from_fn(tenant_middleware))
+        .layer(RequestBodyLimitLayer::new(middleware_stack_options.request_body_max_bytes))
+        .layer(DefaultBodyLimit::disable())
+        .layer(from_fn(maintenance_middleware))
+        .layer(from_fn(api_deprecation_middleware));
+    if middleware_stack_options.compression_enabled {
+        let compression_predicate = DefaultPredicate::new()
+            .and(NotForContentType::const_new("application/zip"))
+            .and(NotForContentType::const_new("application/octet-stream"))
+            .and(NotForContentType::const_new("multipart/"));
+        api_router = api_router.layer(CompressionLayer::new().gzip(true).compress_when(compression_predicate));
+    }
+    if !middleware_stack_options.cors_allowed_origins.is_empty() {
+        api_router = api_router.layer(create_cors_layer(&middleware_stack_options.cors_allowed_origins));
+    }
+    api_router
+        .layer(from_fn(concurrency_class_middleware))
+        .layer(from_fn(access_log_middleware))
+        .layer(CatchPanicLayer::custom(handle_panic))
+}
+
+/// Creates a new [CorsLayer] by [&[String]] allowed origins ("*" allows any origin)
+fn create_cors_layer(cors_allowed_origins: &[String]) -> CorsLayer {
+    let allow_origin = if cors_allowed_origins.iter().any(|allowed_origin| allowed_origin == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(cors_allowed_origins.iter().filter_map(|allowed_origin| HeaderValue::from_str(allowed_origin).ok()))
+    };
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers(tower_http::cors::Any)
+        .expose_headers([CONTENT_DISPOSITION, RANGE, HeaderName::from_static(EXCLUDED_COUNT_HEADER)])
+}
+
+/// Parses [Vec<String>] CORS allowed origins by [&str] comma separated origins (blank values are skipped)
+fn parse_cors_allowed_origins(cors_allowed_origins: &str) -> Vec<String> {
+    cors_allowed_origins
+        .split(',')
+        .map(|allowed_origin| allowed_origin.trim().trim_end_matches('/'))
+        .filter(|allowed_origin| !allowed_origin.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Handles [Box<dyn Any + Send>] panic details from any request (panic is reported by the error reporter)
+/// Returns a [Response] with internal server error status
+fn handle_panic(panic_details: Box<dyn Any + Send + 'static>) -> Response {
+    let panic_message = panic_details
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| panic_details.downcast_ref::<&str>().map(|panic_message| panic_message.to_string()))
+        .unwrap_or_else(|| String::from("unknown panic"));
+    ERROR_REPORTER.report_panic(&panic_message);
+    StatusCode::INTERNAL_SERVER_ERROR.into_response()
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::api_router::parse_cors_allowed_origins;
+
+    /// Scenario:
+    /// Parses CORS allowed origins with blank values and trailing slashes
+    /// Expectation:
+    /// Just not blank origins should be retrieved without trailing slashes
+    #[test]
+    fn when_parse_cors_allowed_origins_should_skip_blank_origins() {
+        assert_eq!(
+            vec!["https://app.example.com", "http://localhost:3000"],
+            parse_cors_allowed_origins(" https://app.example.com/, ,http://localhost:3000")
+        );
+        assert!(parse_cors_allowed_origins("").is_empty());
+    }
+}
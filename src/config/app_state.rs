@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use crate::service::cost_estimate_service::{CostEstimateService, DynCostEstimateService};
+use crate::service::download_job_service::{DownloadJobService, DynDownloadJobService};
+use crate::service::download_service::{DownloadService, DynDownloadService};
+use crate::service::s3_event_service::{DynS3EventService, S3EventService};
+use crate::service::search_service::{DynSearchService, SearchService};
+use crate::service::signed_link_service::{DynSignedLinkService, SignedLinkService};
+use crate::service::watch_service::{DynWatchService, WatchService};
+use log::info;
+
+/// Application state struct (services graph constructed once at bootstrap and shared by all controllers and background tasks)
+#[derive(Clone)]
+pub struct AppState {
+    /// AWS S3 service (shared by all services)
+    pub aws_s3_service: DynAwsSdkS3Service,
+    /// download service (shared by download jobs, signed links, S3 events and watch mode)
+    pub download_service: DynDownloadService,
+    /// download job service (shared by API v1 download jobs and API v2 exports)
+    pub download_job_service: DynDownloadJobService,
+    /// cost estimate service
+    pub cost_estimate_service: DynCostEstimateService,
+    /// S3 event service
+    pub s3_event_service: DynS3EventService,
+    /// full-text search service
+    pub search_service: DynSearchService,
+    /// signed link service
+    pub signed_link_service: DynSignedLinkService,
+    /// watch service (watch mode background task)
+    pub watch_service: DynWatchService,
+}
+
+/// default initialization (services are configured by environment variables - please, check constants.rs)
+impl Default for AppState {
+    fn default() -> Self {
+        info!("app_state - bootstrap - start");
+        let aws_s3_service = Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service;
+        let download_service = Arc::new(DownloadService::new(aws_s3_service.clone())) as DynDownloadService;
+        AppState {
+            download_job_service: Arc::new(DownloadJobService::new(aws_s3_service.clone(), download_service.clone())) as DynDownloadJobService,
+            cost_estimate_service: Arc::new(CostEstimateService::new(aws_s3_service.clone())) as DynCostEstimateService,
+            s3_event_service: Arc::new(S3EventService::new(aws_s3_service.clone(), download_service.clone())) as DynS3EventService,
+            search_service: Arc::new(SearchService::new(aws_s3_service.clone())) as DynSearchService,
+            signed_link_service: Arc::new(SignedLinkService::new(download_service.clone())) as DynSignedLinkService,
+            watch_service: Arc::new(WatchService::new(aws_s3_service.clone(), download_service.clone())) as DynWatchService,
+            aws_s3_service,
+            download_service,
+        }
+    }
+}
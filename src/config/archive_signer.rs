@@ -0,0 +1,80 @@
+use std::sync::LazyLock;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crate::config::external_secrets::get_secret_env_var;
+use crate::constant::constants::ARCHIVE_SIGNING_KEY_ENV_VAR;
+use ed25519_dalek::{Signer, SigningKey};
+use log::{info, warn};
+
+/// Archive signer struct (detached ed25519 signatures of exported archives by a deployment key)
+/// Important: archives are not signed if the signing key is not configured (or it is not valid)
+pub struct ArchiveSigner {
+    signing_key: Option<SigningKey>,
+}
+
+/// Archive signer shared instance (signing key should be configured - please, check constants.rs)
+pub static ARCHIVE_SIGNER: LazyLock<ArchiveSigner> =
+    LazyLock::new(|| ArchiveSigner::new(get_secret_env_var(ARCHIVE_SIGNING_KEY_ENV_VAR).ok().as_deref()));
+
+impl ArchiveSigner {
+    /// Creates a new [ArchiveSigner] by [Option<&str>] signing key (base64 encoded ed25519 32 bytes seed)
+    /// Important: the public key (base64 encoded) is logged, so it can be distributed to downstream consumers
+    fn new(signing_key_seed: Option<&str>) -> Self {
+        let signing_key = signing_key_seed
+            .filter(|signing_key_seed| !signing_key_seed.trim().is_empty())
+            .and_then(|signing_key_seed| match STANDARD.decode(signing_key_seed.trim()).ok().and_then(|seed| <[u8; 32]>::try_from(seed).ok()) {
+                Some(seed) => Some(SigningKey::from_bytes(&seed)),
+                None => {
+                    warn!("archive_signer - signing key is not a valid base64 encoded 32 bytes seed - archives are not signed");
+                    None
+                }
+            });
+        if let Some(signing_key) = &signing_key {
+            info!("archive_signer - archive signing enabled - public key: {}", STANDARD.encode(signing_key.verifying_key().as_bytes()));
+        }
+
+        ArchiveSigner { signing_key }
+    }
+
+    /// Gets [Option<String>] detached ed25519 signature (base64 encoded) by [&[u8]] archive SHA-256 digest
+    /// Returns [None] if archive signing is not enabled
+    pub fn sign_archive_digest(&self, archive_digest: &[u8]) -> Option<String> {
+        self.signing_key
+            .as_ref()
+            .map(|signing_key| STANDARD.encode(signing_key.sign(archive_digest).to_bytes()))
+    }
+
+    /// Gets [bool] true if archive signing is enabled (signing key is configured)
+    pub fn is_enabled(&self) -> bool {
+        self.signing_key.is_some()
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use crate::config::archive_signer::ArchiveSigner;
+    use ed25519_dalek::{Signature, SigningKey, Verifier};
+    use sha2::{Digest, Sha256};
+
+    /// Scenario:
+    /// Signs an archive digest with a configured signing key and with an invalid signing key
+    /// Expectation:
+    /// A signature verifiable by the deployment public key should be retrieved (none if the signing key is not valid)
+    #[test]
+    fn when_sign_archive_digest_should_retrieve_verifiable_signature() {
+        let seed = [7u8; 32];
+        let archive_signer = ArchiveSigner::new(Some(&STANDARD.encode(seed)));
+        let archive_digest = Sha256::digest(b"some archive content");
+
+        let archive_signature = archive_signer.sign_archive_digest(&archive_digest).unwrap();
+        let archive_signature = Signature::from_slice(&STANDARD.decode(archive_signature).unwrap()).unwrap();
+
+        assert!(SigningKey::from_bytes(&seed).verifying_key().verify(&archive_digest, &archive_signature).is_ok());
+        assert!(ArchiveSigner::new(Some("not-a-valid-key")).sign_archive_digest(&archive_digest).is_none());
+        assert!(ArchiveSigner::new(None).sign_archive_digest(&archive_digest).is_none());
+    }
+}
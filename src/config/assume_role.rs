@@ -0,0 +1,105 @@
+use std::future::Future;
+
+use aws_config::SdkConfig;
+use aws_config::sts::AssumeRoleProvider;
+use axum::http::HeaderMap;
+use crate::constant::constants::{AWS_ASSUME_ROLE_ARN_ENV_VAR, AWS_ASSUME_ROLE_SESSION_NAME, AWS_ASSUME_ROLE_SESSION_TAGS_DEFAULT, AWS_ASSUME_ROLE_SESSION_TAGS_ENV_VAR, AWS_SESSION_TAG_VALUE_MAX_LENGTH};
+
+tokio::task_local! {
+    /// Caller session tags (request scoped - set by assume role middleware)
+    static CALLER_SESSION_TAGS: Vec<(String, String)>;
+}
+
+/// Gets [Option<String>] assumed role ARN (none if assume role is not configured - please, check constants.rs)
+pub fn get_assume_role_arn() -> Option<String> {
+    std::env::var(AWS_ASSUME_ROLE_ARN_ENV_VAR).ok().filter(|role_arn| !role_arn.trim().is_empty())
+}
+
+/// Runs [Future] future with [Vec<(String, String)>] caller session tags (if there is any)
+pub async fn with_caller_session_tags<F: Future>(caller_session_tags: Vec<(String, String)>, future: F) -> F::Output {
+    match caller_session_tags.is_empty() {
+        true => future.await,
+        false => CALLER_SESSION_TAGS.scope(caller_session_tags, future).await,
+    }
+}
+
+/// Gets [Vec<(String, String)>] caller session tags (tag key and value) of the current request (empty if not defined)
+pub fn get_caller_session_tags() -> Vec<(String, String)> {
+    CALLER_SESSION_TAGS.try_with(Vec::clone).unwrap_or_default()
+}
+
+/// Gets [Vec<(String, String)>] caller session tags (tag key and value) by [&HeaderMap] request headers
+/// (session tags are mapped from request headers - please, check constants.rs)
+/// Important: headers not defined or with values not allowed by STS session tags are skipped
+pub fn parse_caller_session_tags(headers: &HeaderMap) -> Vec<(String, String)> {
+    let session_tag_mappings =
+        std::env::var(AWS_ASSUME_ROLE_SESSION_TAGS_ENV_VAR).unwrap_or_else(|_| String::from(AWS_ASSUME_ROLE_SESSION_TAGS_DEFAULT));
+    get_session_tag_mappings(&session_tag_mappings)
+        .into_iter()
+        .filter_map(|(tag_key, header_name)| {
+            headers
+                .get(header_name)
+                .and_then(|header_value| header_value.to_str().ok())
+                .filter(|tag_value| is_valid_session_tag_value(tag_value))
+                .map(|tag_value| (String::from(tag_key), String::from(tag_value)))
+        })
+        .collect()
+}
+
+/// Creates a new [AssumeRoleProvider] assume role credentials provider by [&SdkConfig] base AWS SDK configuration
+/// (base credentials can be an assumed role too, so roles are chained), [&str] role ARN and
+/// [Vec<(String, String)>] session tags (so S3 access is attributable and restrictable by ABAC policies)
+pub async fn create_assume_role_provider(aws_sdk_configuration: &SdkConfig, role_arn: &str, session_tags: Vec<(String, String)>) -> AssumeRoleProvider {
+    let mut assume_role_provider_builder =
+        AssumeRoleProvider::builder(role_arn).session_name(AWS_ASSUME_ROLE_SESSION_NAME).configure(aws_sdk_configuration);
+    if !session_tags.is_empty() {
+        assume_role_provider_builder = assume_role_provider_builder.tags(session_tags);
+    }
+    assume_role_provider_builder.build().await
+}
+
+/// Gets [Vec<(&str, &str)>] session tag mappings (tag key and request header name) by [&str] mappings
+/// (comma separated `{tag_key}={header_name}` values - not valid values are skipped)
+fn get_session_tag_mappings(session_tag_mappings: &str) -> Vec<(&str, &str)> {
+    session_tag_mappings
+        .split(',')
+        .filter_map(|session_tag_mapping| session_tag_mapping.split_once('='))
+        .map(|(tag_key, header_name)| (tag_key.trim(), header_name.trim()))
+        .filter(|(tag_key, header_name)| !tag_key.is_empty() && !header_name.is_empty())
+        .collect()
+}
+
+/// Gets [bool] true if [&str] tag value is allowed by STS session tags
+/// (letters, digits, spaces and `_.:/=+-@` characters, up to 256 characters)
+fn is_valid_session_tag_value(tag_value: &str) -> bool {
+    !tag_value.is_empty()
+        && tag_value.chars().count() <= AWS_SESSION_TAG_VALUE_MAX_LENGTH
+        && tag_value.chars().all(|character| character.is_alphanumeric() || character == ' ' || "_.:/=+-@".contains(character))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use crate::config::assume_role::parse_caller_session_tags;
+
+    /// Scenario:
+    /// Parses caller session tags from request headers with default session tag mappings
+    /// Expectation:
+    /// Session tags should be retrieved just for defined headers with allowed values
+    #[test]
+    fn when_parse_caller_session_tags_should_retrieve_allowed_tags() {
+        let mut headers = HeaderMap::new();
+        assert!(parse_caller_session_tags(&headers).is_empty());
+
+        headers.insert("x-tenant-id", "acme".parse().unwrap());
+        headers.insert("x-caller-id", "reports<reader>".parse().unwrap());
+        assert_eq!(vec![(String::from("tenant"), String::from("acme"))], parse_caller_session_tags(&headers));
+
+        headers.insert("x-caller-id", "reports-reader@acme.com".parse().unwrap());
+        assert_eq!(
+            vec![(String::from("tenant"), String::from("acme")), (String::from("user"), String::from("reports-reader@acme.com"))],
+            parse_caller_session_tags(&headers)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use async_trait::async_trait;
+use axum::http::header::CONTENT_TYPE;
+use crate::config::external_secrets::get_secret_env_var;
+use crate::config::rbac_policy::RBAC_POLICY;
+use crate::constant::constants::AUTHORIZATION_POLICY_URL_ENV_VAR;
+use crate::enums::authorization_decision::AuthorizationDecision;
+use crate::enums::rbac_operation::RbacOperation;
+use log::{info, warn};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Authorization hook (decides if a caller is allowed to execute an operation over an S3 bucket and path,
+/// i.e.: by an external policy service) - it is invoked by the authorization middleware before the service layer
+#[async_trait]
+pub trait AuthorizationHookTrait {
+    /// Gets [AuthorizationDecision] decision by [Option<&str>] caller (role request header), [RbacOperation] operation,
+    /// [&str] S3 bucket and [&str] S3 path (prefix)
+    async fn authorize(&self, caller: Option<&str>, operation: RbacOperation, bucket_name: &str, prefix: &str) -> AuthorizationDecision;
+
+    /// Gets [bool] true if authorization is enabled (otherwise, requests are not checked)
+    fn is_enabled(&self) -> bool {
+        true
+    }
+}
+
+/// Role-based access control authorization hook implementation struct (default hook: roles file policy)
+pub struct RbacAuthorizationHook {}
+
+/// Role-based access control authorization hook implementation logic
+#[async_trait]
+impl AuthorizationHookTrait for RbacAuthorizationHook {
+    /// Gets [AuthorizationDecision] decision by [Option<&str>] caller (role request header), [RbacOperation] operation,
+    /// [&str] S3 bucket and [&str] S3 path (prefix)
+    async fn authorize(&self, caller: Option<&str>, operation: RbacOperation, bucket_name: &str, prefix: &str) -> AuthorizationDecision {
+        match RBAC_POLICY.is_allowed(caller, operation, bucket_name, prefix) {
+            true => AuthorizationDecision::ALLOW,
+            false => AuthorizationDecision::DENY,
+        }
+    }
+
+    /// Gets [bool] true if authorization is enabled (roles file is configured)
+    fn is_enabled(&self) -> bool {
+        RBAC_POLICY.is_enabled()
+    }
+}
+
+/// Policy service authorization hook implementation struct (OPA-like decision end-point)
+pub struct PolicyServiceAuthorizationHook {
+    policy_url: String,
+    http_client: reqwest::Client,
+}
+
+/// Policy service authorization request input (sent as `{"input": {...}}`)
+#[derive(Serialize)]
+struct PolicyServiceInput<'a> {
+    caller: Option<&'a str>,
+    operation: RbacOperation,
+    bucket_name: &'a str,
+    prefix: &'a str,
+}
+
+/// Policy service authorization hook implementation logic
+#[async_trait]
+impl AuthorizationHookTrait for PolicyServiceAuthorizationHook {
+    /// Gets [AuthorizationDecision] decision by [Option<&str>] caller (role request header), [RbacOperation] operation,
+    /// [&str] S3 bucket and [&str] S3 path (prefix)
+    /// Important: requests are denied if the policy service is not available (fail closed)
+    async fn authorize(&self, caller: Option<&str>, operation: RbacOperation, bucket_name: &str, prefix: &str) -> AuthorizationDecision {
+        let policy_input = json!({ "input": PolicyServiceInput { caller, operation, bucket_name, prefix } });
+        match self
+            .http_client
+            .post(&self.policy_url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(policy_input.to_string())
+            .send()
+            .await
+            .and_then(|policy_response| policy_response.error_for_status())
+        {
+            Ok(policy_response) => get_policy_decision(&policy_response.bytes().await.unwrap_or_default()),
+            Err(policy_error) => {
+                warn!("authorize - policy service error - url: {} - error: {policy_error}", self.policy_url);
+                AuthorizationDecision::DENY
+            }
+        }
+    }
+}
+
+/// Custom authorization hook (set by deployments before the API router is created)
+static CUSTOM_AUTHORIZATION_HOOK: OnceLock<DynAuthorizationHook> = OnceLock::new();
+
+/// Authorization hook shared instance
+/// Important: custom hook is used if it is set (please, check [set_authorization_hook]), otherwise policy service hook
+/// is used if its URL env var is defined (please, check constants.rs), otherwise the role-based access control policy
+pub static AUTHORIZATION_HOOK: LazyLock<DynAuthorizationHook> =
+    LazyLock::new(|| CUSTOM_AUTHORIZATION_HOOK.get().cloned().unwrap_or_else(create_authorization_hook));
+
+/// Sets [DynAuthorizationHook] custom authorization hook (i.e.: a deployment specific policy client)
+/// Returns [bool] false if custom authorization hook is already set (hook is not replaced)
+/// Important: it should be set at bootstrap (before the first request is authorized)
+pub fn set_authorization_hook(authorization_hook: DynAuthorizationHook) -> bool {
+    CUSTOM_AUTHORIZATION_HOOK.set(authorization_hook).is_ok()
+}
+
+/// Creates a new [DynAuthorizationHook] authorization hook (please, check [AUTHORIZATION_HOOK])
+fn create_authorization_hook() -> DynAuthorizationHook {
+    match get_secret_env_var(AUTHORIZATION_POLICY_URL_ENV_VAR) {
+        Ok(policy_url) if !policy_url.is_empty() => {
+            info!("create_authorization_hook - policy service authorization hook enabled");
+            Arc::new(PolicyServiceAuthorizationHook { policy_url, http_client: reqwest::Client::new() }) as DynAuthorizationHook
+        }
+        _ => Arc::new(RbacAuthorizationHook {}) as DynAuthorizationHook,
+    }
+}
+
+/// Gets [AuthorizationDecision] decision by [&[u8]] policy service response body
+/// (`{"result": true}` or `{"result": {"allow": true}}` - any other response is denied)
+fn get_policy_decision(policy_response: &[u8]) -> AuthorizationDecision {
+    let policy_result = serde_json::from_slice::<Value>(policy_response).ok().and_then(|policy_response| {
+        policy_response
+            .get("result")
+            .map(|policy_result| policy_result.get("allow").unwrap_or(policy_result).as_bool() == Some(true))
+    });
+    match policy_result {
+        Some(true) => AuthorizationDecision::ALLOW,
+        _ => AuthorizationDecision::DENY,
+    }
+}
+
+/// Authorization hook trait for shared instance
+pub type DynAuthorizationHook = Arc<dyn AuthorizationHookTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::authorization_hook::get_policy_decision;
+    use crate::enums::authorization_decision::AuthorizationDecision;
+
+    /// Scenario:
+    /// Gets policy decisions from allowed, denied and not valid policy service responses
+    /// Expectation:
+    /// Just allowed results (boolean or `allow` field) should be allowed
+    #[test]
+    fn when_get_policy_decision_should_allow_just_allowed_results() {
+        assert_eq!(AuthorizationDecision::ALLOW, get_policy_decision(br#"{"result": true}"#));
+        assert_eq!(AuthorizationDecision::ALLOW, get_policy_decision(br#"{"result": {"allow": true}}"#));
+        assert_eq!(AuthorizationDecision::DENY, get_policy_decision(br#"{"result": {"allow": false}}"#));
+        assert_eq!(AuthorizationDecision::DENY, get_policy_decision(br#"{}"#));
+        assert_eq!(AuthorizationDecision::DENY, get_policy_decision(b"not a json"));
+    }
+}
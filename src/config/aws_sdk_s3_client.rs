@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
-use aws_sdk_s3::config::BehaviorVersion;
+use aws_sdk_s3::config::{Builder, Region, SharedCredentialsProvider};
 use aws_sdk_s3::Client;
+use crate::config::assume_role::{create_assume_role_provider, get_assume_role_arn, get_caller_session_tags};
+use crate::config::caller_credentials::get_caller_credentials;
+use crate::config::client_pool::{get_base_aws_sdk_configuration, ClientPoolKey, CLIENT_POOL};
+use crate::config::credentials_cache::CREDENTIALS_CACHE;
 
 use log::debug;
 
@@ -10,8 +14,11 @@ use log::debug;
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait AwsSdkS3ClientTrait {
-    /// Creates a new [Client] AWS SDK Client
+    /// Creates a new [Client] AWS SDK Client (default region)
     async fn create_aws_sdk_client(&self) -> Client;
+
+    /// Creates a new [Client] AWS SDK Client by [Option<String>] region (none for default region)
+    async fn create_aws_sdk_regional_client(&self, region: Option<String>) -> Client;
 }
 
 #[derive(Default)]
@@ -20,13 +27,67 @@ pub struct AwsSdkS3Client {}
 /// AWS SDK S3 client implementation logic
 #[async_trait]
 impl AwsSdkS3ClientTrait for AwsSdkS3Client {
-    /// Creates a new [Client] AWS SDK Client
+    /// Creates a new [Client] AWS SDK Client (default region)
     async fn create_aws_sdk_client(&self) -> Client {
-        debug!("create_aws_sdk_client - start");
-        let aws_sdk_configuration = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        self.create_aws_sdk_regional_client(None).await
+    }
+
+    /// Creates a new [Client] AWS SDK Client by [Option<String>] region (none for default region)
+    /// Important: caller temporary AWS credentials of the current request are used if they are defined, otherwise the
+    /// assumed role (tagged with caller session tags of the current request, if any) is used if it is configured
+    /// (assumed role and base credentials are cached by role and session tags - please, check credentials_cache.rs)
+    /// Important: clients are pooled by region, role and endpoint (but caller credentials ones - please, check client_pool.rs)
+    async fn create_aws_sdk_regional_client(&self, region: Option<String>) -> Client {
+        debug!("create_aws_sdk_regional_client - start - region: {region:?}");
+        let aws_sdk_configuration = get_base_aws_sdk_configuration().await;
+        let region = region.or_else(|| aws_sdk_configuration.region().map(ToString::to_string));
+        let create_client_configuration_builder = || {
+            let client_configuration_builder = Builder::from(aws_sdk_configuration);
+            match &region {
+                Some(region) => client_configuration_builder.region(Region::new(region.clone())),
+                None => client_configuration_builder,
+            }
+        };
+        if let Some(caller_credentials) = get_caller_credentials() {
+            debug!("create_aws_sdk_regional_client - done (caller credentials)");
+            return Client::from_conf(create_client_configuration_builder().credentials_provider(caller_credentials).build());
+        }
+
+        let role_arn = get_assume_role_arn();
+        let session_tags = match role_arn {
+            Some(_) => get_caller_session_tags(),
+            None => Vec::new(),
+        };
+        let client_pool_key = ClientPoolKey {
+            region: region.clone(),
+            role_arn: role_arn.clone(),
+            session_tags: session_tags.clone(),
+            endpoint_url: aws_sdk_configuration.endpoint_url().map(String::from),
+        };
+        let client = CLIENT_POOL
+            .get_client(client_pool_key, async {
+                let credentials_provider = match (role_arn, aws_sdk_configuration.credentials_provider()) {
+                    (Some(role_arn), _) => Some(
+                        CREDENTIALS_CACHE
+                            .get_credentials_provider(Some(&role_arn), session_tags.clone(), async {
+                                SharedCredentialsProvider::new(create_assume_role_provider(aws_sdk_configuration, &role_arn, session_tags).await)
+                            })
+                            .await,
+                    ),
+                    (None, Some(credentials_provider)) => {
+                        Some(CREDENTIALS_CACHE.get_credentials_provider(None, Vec::new(), async { credentials_provider }).await)
+                    }
+                    (None, None) => None,
+                };
+                match credentials_provider {
+                    Some(credentials_provider) => Client::from_conf(create_client_configuration_builder().credentials_provider(credentials_provider).build()),
+                    None => Client::from_conf(create_client_configuration_builder().build()),
+                }
+            })
+            .await;
 
-        debug!("create_aws_sdk_client - done");
-        Client::new(&aws_sdk_configuration)
+        debug!("create_aws_sdk_regional_client - done");
+        client
     }
 }
 
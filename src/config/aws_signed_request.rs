@@ -0,0 +1,64 @@
+use log::debug;
+
+/// Sends a [String] body request (signed by AWS SigV4 with the default AWS SDK credentials) to [&str] AWS service name
+/// regional end-point by [Option<&str>] AWS region (default AWS SDK region if it is not defined), [&str] content type
+/// and [Option<&str>] AWS target header
+/// Returns [String] response body or a [String] error if request can't be signed or sent, or AWS service rejects it
+pub async fn send_aws_signed_request(
+    http_client: &reqwest::Client,
+    service_name: &str,
+    region: Option<&str>,
+    content_type: &str,
+    amz_target: Option<&str>,
+    body: String,
+) -> Result<String, String> {
+    use aws_sdk_s3::config::{BehaviorVersion, ProvideCredentials};
+    use aws_sigv4::http_request::{SignableBody, SignableRequest, SigningSettings, sign};
+    use aws_sigv4::sign::v4::SigningParams;
+    use std::time::SystemTime;
+
+    let aws_sdk_configuration = aws_config::load_defaults(BehaviorVersion::latest()).await;
+    let region = region
+        .map(String::from)
+        .or_else(|| aws_sdk_configuration.region().map(|region| region.to_string()))
+        .ok_or_else(|| String::from("AWS region not defined"))?;
+    let credentials = aws_sdk_configuration
+        .credentials_provider()
+        .ok_or_else(|| String::from("AWS credentials not defined"))?
+        .provide_credentials()
+        .await
+        .map_err(|credentials_error| credentials_error.to_string())?;
+
+    let identity = credentials.into();
+    let signing_params = SigningParams::builder()
+        .identity(&identity)
+        .region(&region)
+        .name(service_name)
+        .time(SystemTime::now())
+        .settings(SigningSettings::default())
+        .build()
+        .map_err(|signing_error| signing_error.to_string())?
+        .into();
+
+    let url = format!("https://{service_name}.{region}.amazonaws.com/");
+    let mut headers = vec![("content-type", content_type)];
+    if let Some(amz_target) = amz_target {
+        headers.push(("x-amz-target", amz_target));
+    }
+    let signable_request = SignableRequest::new("POST", &url, headers.clone().into_iter(), SignableBody::Bytes(body.as_bytes()))
+        .map_err(|signing_error| signing_error.to_string())?;
+    let (signing_instructions, _) = sign(signable_request, &signing_params)
+        .map_err(|signing_error| signing_error.to_string())?
+        .into_parts();
+
+    debug!("send_aws_signed_request - service: {service_name} - region: {region}");
+    let mut request = http_client.post(&url);
+    for (header_name, header_value) in headers.into_iter().chain(signing_instructions.headers()) {
+        request = request.header(header_name, header_value);
+    }
+    let response = request.body(body).send().await.map_err(|request_error| request_error.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("{} - {}", response.status(), response.text().await.unwrap_or_default()));
+    }
+    response.text().await.map_err(|response_error| response_error.to_string())
+}
@@ -0,0 +1,90 @@
+use axum::http::HeaderMap;
+use crate::config::env_vars::get_env_var_as_bool;
+use crate::constant::constants::{API_BASE_PATH_ENV_VAR, API_FORWARDED_HEADERS_ENABLED_DEFAULT, API_FORWARDED_HEADERS_ENABLED_ENV_VAR, FORWARDED_HOST_HEADER, FORWARDED_PREFIX_HEADER, FORWARDED_PROTO_HEADER, PUBLIC_BASE_URL_DEFAULT, PUBLIC_BASE_URL_ENV_VAR};
+
+/// Gets [String] API base path (i.e.: "/s3-exporter" - empty if API end-points are mounted at the root path)
+pub fn get_api_base_path() -> String {
+    normalize_base_path(&std::env::var(API_BASE_PATH_ENV_VAR).unwrap_or_default())
+}
+
+/// Gets [Option<String>] public base URL by [&HeaderMap] request forwarded headers (please, check constants.rs)
+/// Important: forwarded headers are just honored if they are trusted by configuration (a forwarded host is required)
+pub fn get_forwarded_public_base_url(request_headers: &HeaderMap) -> Option<String> {
+    if !get_env_var_as_bool(API_FORWARDED_HEADERS_ENABLED_ENV_VAR, API_FORWARDED_HEADERS_ENABLED_DEFAULT) {
+        return None;
+    }
+    create_forwarded_public_base_url(request_headers, &get_api_base_path())
+}
+
+/// Creates a new [String] public base URL (including API base path) by [Option<&str>] forwarded public base URL
+/// (configured public base URL is used if it is not defined)
+pub fn create_public_base_url(forwarded_public_base_url: Option<&str>) -> String {
+    match forwarded_public_base_url {
+        Some(forwarded_public_base_url) => String::from(forwarded_public_base_url),
+        None => {
+            let public_base_url = std::env::var(PUBLIC_BASE_URL_ENV_VAR).unwrap_or(String::from(PUBLIC_BASE_URL_DEFAULT));
+            format!("{}{}", public_base_url.trim_end_matches('/'), get_api_base_path())
+        }
+    }
+}
+
+/// Creates a new [Option<String>] public base URL by [&HeaderMap] request forwarded headers and [&str] API base path
+/// (forwarded prefix replaces API base path - first value is taken from comma separated header values)
+fn create_forwarded_public_base_url(request_headers: &HeaderMap, api_base_path: &str) -> Option<String> {
+    let forwarded_header = |header_name: &str| {
+        request_headers
+            .get(header_name)
+            .and_then(|header_value| header_value.to_str().ok())
+            .and_then(|header_value| header_value.split(',').next())
+            .map(str::trim)
+            .filter(|header_value| !header_value.is_empty())
+    };
+    let forwarded_host = forwarded_header(FORWARDED_HOST_HEADER)?;
+    if forwarded_host.contains(['/', '\\', '@']) {
+        return None;
+    }
+    let forwarded_proto = match forwarded_header(FORWARDED_PROTO_HEADER) {
+        Some("https") => "https",
+        _ => "http",
+    };
+    let base_path = forwarded_header(FORWARDED_PREFIX_HEADER).map(normalize_base_path).unwrap_or(String::from(api_base_path));
+    Some(format!("{forwarded_proto}://{forwarded_host}{base_path}"))
+}
+
+/// Normalizes [&str] base path (leading slash and no trailing slash - root path is normalized as an empty path)
+fn normalize_base_path(base_path: &str) -> String {
+    let base_path = base_path.trim().trim_matches('/');
+    if base_path.is_empty() { String::new() } else { format!("/{base_path}") }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue};
+    use crate::config::base_path::create_forwarded_public_base_url;
+    use crate::constant::constants::{FORWARDED_HOST_HEADER, FORWARDED_PREFIX_HEADER, FORWARDED_PROTO_HEADER};
+
+    /// Scenario:
+    /// Creates public base URL by proxy forwarded headers (with and without forwarded prefix)
+    /// Expectation:
+    /// Forwarded prefix should replace API base path and first forwarded host should be taken
+    #[test]
+    fn when_create_forwarded_public_base_url_should_honor_forwarded_headers() {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(FORWARDED_PROTO_HEADER, HeaderValue::from_static("https"));
+        request_headers.insert(FORWARDED_HOST_HEADER, HeaderValue::from_static("ingress.example.com, internal:8097"));
+
+        assert_eq!(
+            Some(String::from("https://ingress.example.com/s3-exporter")),
+            create_forwarded_public_base_url(&request_headers, "/s3-exporter")
+        );
+
+        request_headers.insert(FORWARDED_PREFIX_HEADER, HeaderValue::from_static("/shared/s3-exporter/"));
+        assert_eq!(
+            Some(String::from("https://ingress.example.com/shared/s3-exporter")),
+            create_forwarded_public_base_url(&request_headers, "/s3-exporter")
+        );
+
+        assert_eq!(None, create_forwarded_public_base_url(&HeaderMap::new(), "/s3-exporter"));
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+/// Bucket regions struct (discovered S3 bucket regions by S3 bucket name - buckets out of the default region)
+pub struct BucketRegions {
+    regions: RwLock<HashMap<String, String>>,
+}
+
+/// Bucket regions shared instance (bucket regions are discovered on S3 region redirects - please, check
+/// aws_sdk_s3_service.rs)
+pub static BUCKET_REGIONS: LazyLock<BucketRegions> = LazyLock::new(|| BucketRegions {
+    regions: RwLock::new(HashMap::new()),
+});
+
+impl BucketRegions {
+    /// Gets [Option<String>] discovered region by [&str] S3 bucket name (none if it is not discovered yet)
+    pub fn get(&self, bucket_name: &str) -> Option<String> {
+        self.regions
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(bucket_name)
+            .cloned()
+    }
+
+    /// Sets [&str] discovered region by [&str] S3 bucket name
+    pub fn put(&self, bucket_name: &str, region: &str) {
+        self.regions
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(String::from(bucket_name), String::from(region));
+    }
+}
+
+/// Gets [Option<String>] S3 bucket region by [Option<&str>] client region and [Option<&str>] S3 bucket region
+/// (i.e.: `x-amz-bucket-region` response header) - none if bucket region is not defined or it is the client region
+pub fn get_other_bucket_region(client_region: Option<&str>, bucket_region: Option<&str>) -> Option<String> {
+    bucket_region
+        .map(str::trim)
+        .filter(|bucket_region| !bucket_region.is_empty() && Some(*bucket_region) != client_region)
+        .map(String::from)
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::bucket_regions::get_other_bucket_region;
+
+    /// Scenario:
+    /// Gets bucket regions for the client region, other regions and not defined regions
+    /// Expectation:
+    /// Bucket region should be retrieved just if it is defined and it is not the client region
+    #[test]
+    fn when_get_other_bucket_region_should_retrieve_just_other_regions() {
+        assert_eq!(Some(String::from("eu-west-1")), get_other_bucket_region(Some("us-east-1"), Some("eu-west-1")));
+        assert_eq!(Some(String::from("eu-west-1")), get_other_bucket_region(None, Some("eu-west-1")));
+        assert_eq!(None, get_other_bucket_region(Some("us-east-1"), Some("us-east-1")));
+        assert_eq!(None, get_other_bucket_region(Some("us-east-1"), Some("")));
+        assert_eq!(None, get_other_bucket_region(Some("us-east-1"), None));
+    }
+}
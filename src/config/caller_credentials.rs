@@ -0,0 +1,94 @@
+use std::future::Future;
+
+use aws_sdk_s3::config::Credentials;
+use axum::http::HeaderMap;
+use crate::config::assume_role::{get_caller_session_tags, with_caller_session_tags};
+use crate::constant::constants::{CALLER_AWS_ACCESS_KEY_ID_HEADER, CALLER_AWS_SECRET_ACCESS_KEY_HEADER, CALLER_AWS_SESSION_TOKEN_HEADER};
+use crate::enums::common_error::CommonError;
+use tokio::task::JoinHandle;
+
+tokio::task_local! {
+    /// Caller temporary AWS credentials (request scoped - set by credentials passthrough middleware)
+    static CALLER_CREDENTIALS: Credentials;
+}
+
+/// Runs [Future] future with [Option<Credentials>] caller temporary AWS credentials (if they are defined)
+pub async fn with_caller_credentials<F: Future>(caller_credentials: Option<Credentials>, future: F) -> F::Output {
+    match caller_credentials {
+        Some(caller_credentials) => CALLER_CREDENTIALS.scope(caller_credentials, future).await,
+        None => future.await,
+    }
+}
+
+/// Gets [Option<Credentials>] caller temporary AWS credentials of the current request (none if they are not defined)
+pub fn get_caller_credentials() -> Option<Credentials> {
+    CALLER_CREDENTIALS.try_with(Credentials::clone).ok()
+}
+
+/// Spawns [Future] future as a new task with the caller temporary AWS credentials and session tags of the current
+/// request (if any), so request background tasks (i.e.: streamed listings) run under the caller's own permissions too
+pub fn spawn_with_caller_credentials<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(with_caller_credentials(get_caller_credentials(), with_caller_session_tags(get_caller_session_tags(), future)))
+}
+
+/// Parses [Option<Credentials>] caller temporary AWS credentials by [&HeaderMap] request headers
+/// (access key id, secret access key and session token headers - none if no header is defined)
+/// Returns a [CommonError] if just some of them are defined (all of them are required)
+pub fn parse_caller_credentials(headers: &HeaderMap) -> Result<Option<Credentials>, CommonError> {
+    let get_header = |header_name: &str| {
+        headers
+            .get(header_name)
+            .and_then(|header_value| header_value.to_str().ok())
+            .filter(|header_value| !header_value.is_empty())
+    };
+    match (
+        get_header(CALLER_AWS_ACCESS_KEY_ID_HEADER),
+        get_header(CALLER_AWS_SECRET_ACCESS_KEY_HEADER),
+        get_header(CALLER_AWS_SESSION_TOKEN_HEADER),
+    ) {
+        (None, None, None) => Ok(None),
+        (Some(access_key_id), Some(secret_access_key), Some(session_token)) => Ok(Some(Credentials::new(
+            access_key_id,
+            secret_access_key,
+            Some(String::from(session_token)),
+            None,
+            "caller-credentials",
+        ))),
+        _ => Err(CommonError::NO_VALID_INPUT_OR_PARAMETER),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use crate::config::caller_credentials::{get_caller_credentials, parse_caller_credentials, with_caller_credentials};
+
+    /// Scenario:
+    /// Parses caller credentials from complete, partial and missing headers and runs a future with them
+    /// Expectation:
+    /// Credentials should be retrieved just for complete headers (error for partial ones) and be scoped to the future
+    #[tokio::test]
+    async fn when_parse_caller_credentials_should_retrieve_complete_credentials() {
+        let mut headers = HeaderMap::new();
+        assert!(parse_caller_credentials(&headers).unwrap().is_none());
+
+        headers.insert("x-aws-access-key-id", "ASIAEXAMPLE".parse().unwrap());
+        headers.insert("x-aws-secret-access-key", "some-secret".parse().unwrap());
+        assert!(parse_caller_credentials(&headers).is_err());
+
+        headers.insert("x-aws-session-token", "some-session-token".parse().unwrap());
+        let caller_credentials = parse_caller_credentials(&headers).unwrap();
+        let scoped_access_key_id = with_caller_credentials(caller_credentials, async {
+            get_caller_credentials().map(|caller_credentials| String::from(caller_credentials.access_key_id()))
+        })
+        .await;
+
+        assert_eq!(Some(String::from("ASIAEXAMPLE")), scoped_access_key_id);
+        assert!(get_caller_credentials().is_none());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{LazyLock, Mutex};
+
+use aws_config::{BehaviorVersion, SdkConfig};
+use aws_sdk_s3::Client;
+use crate::config::env_vars::get_env_var_as_usize;
+use crate::constant::constants::{AWS_CLIENT_POOL_MAX_SIZE_DEFAULT, AWS_CLIENT_POOL_MAX_SIZE_ENV_VAR};
+use log::debug;
+use tokio::sync::OnceCell;
+
+/// Base AWS SDK configuration (default region, endpoint and credentials - loaded once, on first use)
+static BASE_AWS_SDK_CONFIGURATION: OnceCell<SdkConfig> = OnceCell::const_new();
+
+/// Client pool key (region, assumed role ARN and session tags - none for base credentials - and endpoint URL)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ClientPoolKey {
+    pub region: Option<String>,
+    pub role_arn: Option<String>,
+    pub session_tags: Vec<(String, String)>,
+    pub endpoint_url: Option<String>,
+}
+
+/// Client pool struct (AWS SDK S3 clients by region, role and endpoint - clients are created lazily, on first use)
+pub struct ClientPool {
+    clients: Mutex<HashMap<ClientPoolKey, Client>>,
+    max_size: usize,
+}
+
+/// Client pool shared instance (max size should be configured - please, check constants.rs)
+pub static CLIENT_POOL: LazyLock<ClientPool> =
+    LazyLock::new(|| ClientPool::new(get_env_var_as_usize(AWS_CLIENT_POOL_MAX_SIZE_ENV_VAR, AWS_CLIENT_POOL_MAX_SIZE_DEFAULT).max(1)));
+
+impl ClientPool {
+    /// Creates a new [ClientPool] by [usize] max size (pooled clients quantity)
+    pub fn new(max_size: usize) -> Self {
+        ClientPool {
+            clients: Mutex::new(HashMap::new()),
+            max_size,
+        }
+    }
+
+    /// Gets [Client] pooled AWS SDK S3 client by [ClientPoolKey] key - [Future] client creation is awaited just if
+    /// there is no pooled client yet
+    /// Important: an arbitrary pooled client is discarded if max size is reached (i.e.: many tenant session tags)
+    pub async fn get_client<F>(&self, client_pool_key: ClientPoolKey, create_client: F) -> Client
+    where
+        F: Future<Output = Client>,
+    {
+        if let Some(client) = self.lock_clients().get(&client_pool_key) {
+            return client.clone();
+        }
+
+        let client = create_client.await;
+        let mut clients = self.lock_clients();
+        if clients.len() >= self.max_size
+            && let Some(discarded_client_pool_key) = clients.keys().next().cloned()
+        {
+            clients.remove(&discarded_client_pool_key);
+        }
+        debug!("get_client - pooled client created - region: {:?} - role: {:?}", client_pool_key.region, client_pool_key.role_arn);
+        clients.entry(client_pool_key).or_insert(client).clone()
+    }
+
+    /// Gets [usize] pooled clients quantity
+    pub fn len(&self) -> usize {
+        self.lock_clients().len()
+    }
+
+    /// Gets [bool] true if there is no pooled client
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks [HashMap] pooled clients by key (poisoned lock is recovered)
+    fn lock_clients(&self) -> std::sync::MutexGuard<'_, HashMap<ClientPoolKey, Client>> {
+        self.clients.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Gets [&SdkConfig] base AWS SDK configuration (it is loaded on first use)
+pub async fn get_base_aws_sdk_configuration() -> &'static SdkConfig {
+    BASE_AWS_SDK_CONFIGURATION
+        .get_or_init(|| aws_config::load_defaults(BehaviorVersion::latest()))
+        .await
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::Client;
+    use aws_sdk_s3::config::{BehaviorVersion, Builder, Region};
+    use crate::config::client_pool::{ClientPool, ClientPoolKey};
+
+    /// Scenario:
+    /// Gets pooled clients for the same and different regions with a pool of two clients
+    /// Expectation:
+    /// Clients should be created once by key and pool size should not exceed its max size
+    #[tokio::test]
+    async fn when_get_client_should_create_client_once_by_key() {
+        let client_pool = ClientPool::new(2);
+        let create_client = |region: &'static str| async move {
+            Client::from_conf(Builder::new().behavior_version(BehaviorVersion::latest()).region(Region::new(region)).build())
+        };
+        let client_pool_key = |region: &str| ClientPoolKey {
+            region: Some(String::from(region)),
+            role_arn: None,
+            session_tags: Vec::new(),
+            endpoint_url: None,
+        };
+
+        client_pool.get_client(client_pool_key("eu-west-1"), create_client("eu-west-1")).await;
+        let client = client_pool.get_client(client_pool_key("eu-west-1"), create_client("us-east-1")).await;
+        assert_eq!(Some("eu-west-1"), client.config().region().map(Region::as_ref));
+        assert_eq!(1, client_pool.len());
+
+        client_pool.get_client(client_pool_key("us-east-1"), create_client("us-east-1")).await;
+        client_pool.get_client(client_pool_key("ap-south-1"), create_client("ap-south-1")).await;
+        assert_eq!(2, client_pool.len());
+    }
+}
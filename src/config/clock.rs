@@ -0,0 +1,6 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Gets [u64] current time as seconds since unix epoch
+pub fn current_epoch_seconds() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
@@ -0,0 +1,54 @@
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use crate::config::env_vars::{get_env_var_as_u64, get_env_var_as_usize};
+use crate::constant::constants::{API_CONCURRENCY_ADMIN_MAX_REQUESTS_DEFAULT, API_CONCURRENCY_ADMIN_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_DEFAULT, API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_LISTING_MAX_REQUESTS_DEFAULT, API_CONCURRENCY_LISTING_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_DEFAULT, API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_ENV_VAR};
+use crate::enums::concurrency_class::ConcurrencyClass;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::error::Elapsed;
+use tokio::time::timeout;
+
+/// Concurrency classes struct (separate in-flight requests budgets - cheap listing requests keep being served
+/// while archive streaming requests are running)
+pub struct ConcurrencyClasses {
+    listing_semaphore: Option<Arc<Semaphore>>,
+    archive_semaphore: Option<Arc<Semaphore>>,
+    admin_semaphore: Option<Arc<Semaphore>>,
+    queue_timeout: Duration,
+}
+
+/// Concurrency classes shared instance (budgets should be configured - please, check constants.rs)
+pub static CONCURRENCY_CLASSES: LazyLock<ConcurrencyClasses> = LazyLock::new(|| ConcurrencyClasses {
+    listing_semaphore: create_class_semaphore(API_CONCURRENCY_LISTING_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_LISTING_MAX_REQUESTS_DEFAULT),
+    archive_semaphore: create_class_semaphore(API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_DEFAULT),
+    admin_semaphore: create_class_semaphore(API_CONCURRENCY_ADMIN_MAX_REQUESTS_ENV_VAR, API_CONCURRENCY_ADMIN_MAX_REQUESTS_DEFAULT),
+    queue_timeout: Duration::from_millis(get_env_var_as_u64(
+        API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_ENV_VAR,
+        API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_DEFAULT,
+    )),
+});
+
+impl ConcurrencyClasses {
+    /// Acquires a request slot of [ConcurrencyClass] concurrency class (waits for a free slot up to the configured queue timeout)
+    /// Returns [None] request permit if the class is unlimited (the request slot is released once the permit is dropped)
+    /// or an [Elapsed] error if no slot was released in time
+    pub async fn acquire(&self, concurrency_class: ConcurrencyClass) -> Result<Option<OwnedSemaphorePermit>, Elapsed> {
+        let semaphore = match concurrency_class {
+            ConcurrencyClass::LISTING => &self.listing_semaphore,
+            ConcurrencyClass::ARCHIVE => &self.archive_semaphore,
+            ConcurrencyClass::ADMIN => &self.admin_semaphore,
+        };
+        let Some(semaphore) = semaphore else {
+            return Ok(None);
+        };
+        timeout(self.queue_timeout, semaphore.clone().acquire_owned()).await.map(Result::ok)
+    }
+}
+
+/// Creates a new [Option<Arc<Semaphore>>] concurrency class semaphore by [&str] maximum requests environment variable
+/// name and default value ([None] if maximum requests is zero - unlimited class)
+fn create_class_semaphore(env_var_name: &str, env_var_default: &str) -> Option<Arc<Semaphore>> {
+    Some(get_env_var_as_usize(env_var_name, env_var_default))
+        .filter(|max_requests| *max_requests > 0)
+        .map(|max_requests| Arc::new(Semaphore::new(max_requests)))
+}
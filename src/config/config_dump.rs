@@ -0,0 +1,276 @@
+use crate::constant::constants::*;
+use crate::dto::config_dump::{ConfigDump, ConfigEntry};
+use crate::enums::config_value_kind::ConfigValueKind;
+use crate::enums::config_value_source::ConfigValueSource;
+use log::{info, warn};
+
+/// Redacted secret value
+const REDACTED_VALUE: &str = "********";
+
+/// Known configuration entries (environment variable name, default value and value kind)
+const CONFIG_ENTRIES: &[(&str, Option<&str>, ConfigValueKind)] = &[
+    (LOGGING_CONFIG_FILE_ENV_VAR, Some(LOGGING_CONFIG_FILE_DEFAULT), ConfigValueKind::TEXT),
+    (API_SERVER_HOST_ENV_VAR, Some(API_SERVER_HOST_DEFAULT), ConfigValueKind::TEXT),
+    (API_SERVER_PORT_ENV_VAR, Some(API_SERVER_PORT_DEFAULT), ConfigValueKind::NUMBER),
+    (API_SERVER_ADDITIONAL_ADDRESSES_ENV_VAR, None, ConfigValueKind::TEXT),
+    (API_SERVER_HTTP2_ENABLED_ENV_VAR, Some(API_SERVER_HTTP2_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_ENV_VAR, Some(API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, Some(API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_ENV_VAR, Some(API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_ENV_VAR, Some(API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (API_SERVER_TCP_NODELAY_ENABLED_ENV_VAR, Some(API_SERVER_TCP_NODELAY_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (API_SERVER_UNIX_SOCKET_PATH_ENV_VAR, None, ConfigValueKind::TEXT),
+    (API_BASE_PATH_ENV_VAR, None, ConfigValueKind::TEXT),
+    (API_FORWARDED_HEADERS_ENABLED_ENV_VAR, Some(API_FORWARDED_HEADERS_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (API_V1_SUNSET_DATE_ENV_VAR, None, ConfigValueKind::TEXT),
+    (PUBLIC_BASE_URL_ENV_VAR, Some(PUBLIC_BASE_URL_DEFAULT), ConfigValueKind::TEXT),
+    (AWS_S3_MAX_FILE_QUANTITY_ENV_VAR, Some(AWS_S3_MAX_FILE_QUANTITY_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (AWS_S3_MAX_FILE_SIZE_BYTES_ENV_VAR, Some(AWS_S3_MAX_FILE_SIZE_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (AWS_S3_ALLOW_OVERSIZE_ENABLED_ENV_VAR, Some(AWS_S3_ALLOW_OVERSIZE_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (AWS_S3_FETCH_WINDOW_SIZE_ENV_VAR, Some(AWS_S3_FETCH_WINDOW_SIZE_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (AWS_S3_MAX_MULTIPART_CHUNK_BYTES_ENV_VAR, Some(AWS_S3_MAX_MULTIPART_CHUNK_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (AWS_S3_STREAM_MIN_BYTES_ENV_VAR, Some(AWS_S3_STREAM_MIN_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_ENV_VAR, Some(AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (EXPORT_MEMORY_BUDGET_BYTES_ENV_VAR, Some(EXPORT_MEMORY_BUDGET_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, Some(EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR, Some(DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR, None, ConfigValueKind::SECRET),
+    (DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_ENV_VAR, Some(DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_ENV_VAR, Some(DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (DOWNLOAD_TOKEN_TTL_SECONDS_ENV_VAR, Some(DOWNLOAD_TOKEN_TTL_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (ARCHIVE_SIGNING_KEY_ENV_VAR, None, ConfigValueKind::SECRET),
+    (DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (EXPORT_HISTORY_RETENTION_SECONDS_ENV_VAR, Some(EXPORT_HISTORY_RETENTION_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (ARTIFACT_STORE_BUCKET_ENV_VAR, None, ConfigValueKind::TEXT),
+    (ARTIFACT_STORE_PATH_ENV_VAR, Some(ARTIFACT_STORE_PATH_DEFAULT), ConfigValueKind::TEXT),
+    (EXPORT_SSE_KMS_KEY_ID_ENV_VAR, None, ConfigValueKind::TEXT),
+    (EXPORT_KMS_ENVELOPE_KEY_ID_ENV_VAR, None, ConfigValueKind::TEXT),
+    (DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (EXPORT_OBJECT_RETENTION_SECONDS_ENV_VAR, Some(EXPORT_OBJECT_RETENTION_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_JOB_LEASE_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_LEASE_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_ENV_VAR, Some(DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_BATCH_CONCURRENCY_ENV_VAR, Some(DOWNLOAD_JOB_BATCH_CONCURRENCY_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_ENV_VAR, Some(DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_MAX_ATTEMPTS_ENV_VAR, Some(DOWNLOAD_JOB_MAX_ATTEMPTS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_ENV_VAR, Some(DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_ENV_VAR, Some(DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_ENV_VAR, Some(DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (DIFF_MAX_KEYS_ENV_VAR, Some(DIFF_MAX_KEYS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (ARCHIVE_READ_AHEAD_BYTES_ENV_VAR, Some(ARCHIVE_READ_AHEAD_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (ARCHIVE_ENTRY_MAX_BYTES_ENV_VAR, Some(ARCHIVE_ENTRY_MAX_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (LISTING_MAX_PARALLEL_PREFIXES_ENV_VAR, Some(LISTING_MAX_PARALLEL_PREFIXES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (LISTING_CACHE_TTL_SECONDS_ENV_VAR, Some(LISTING_CACHE_TTL_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (OBJECT_BODY_CACHE_MAX_BYTES_ENV_VAR, Some(OBJECT_BODY_CACHE_MAX_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (THUMBNAIL_CACHE_MAX_BYTES_ENV_VAR, Some(THUMBNAIL_CACHE_MAX_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (THUMBNAIL_SOURCE_MAX_BYTES_ENV_VAR, Some(THUMBNAIL_SOURCE_MAX_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (SEARCH_MAX_OBJECTS_ENV_VAR, Some(SEARCH_MAX_OBJECTS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (SEARCH_MAX_OBJECT_BYTES_ENV_VAR, Some(SEARCH_MAX_OBJECT_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (SEARCH_MAX_PARALLEL_FETCHES_ENV_VAR, Some(SEARCH_MAX_PARALLEL_FETCHES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (TEMP_STORAGE_MIN_FREE_BYTES_ENV_VAR, Some(TEMP_STORAGE_MIN_FREE_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (USAGE_STATS_WINDOW_SECONDS_ENV_VAR, Some(USAGE_STATS_WINDOW_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (EXPORT_ALERT_DURATION_SECONDS_ENV_VAR, Some(EXPORT_ALERT_DURATION_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (EXPORT_ALERT_SIZE_BYTES_ENV_VAR, Some(EXPORT_ALERT_SIZE_BYTES_DEFAULT), ConfigValueKind::NUMBER),
+    (EXPORT_ALERT_WEBHOOK_URL_ENV_VAR, None, ConfigValueKind::SECRET),
+    (API_REQUEST_BODY_MAX_BYTES_ENV_VAR, Some(API_REQUEST_BODY_MAX_BYTES_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (API_CORS_ALLOWED_ORIGINS_ENV_VAR, None, ConfigValueKind::TEXT),
+    (API_COMPRESSION_ENABLED_ENV_VAR, Some(API_COMPRESSION_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (API_CONCURRENCY_LISTING_MAX_REQUESTS_ENV_VAR, Some(API_CONCURRENCY_LISTING_MAX_REQUESTS_DEFAULT), ConfigValueKind::NUMBER),
+    (API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_ENV_VAR, Some(API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_DEFAULT), ConfigValueKind::NUMBER),
+    (API_CONCURRENCY_ADMIN_MAX_REQUESTS_ENV_VAR, Some(API_CONCURRENCY_ADMIN_MAX_REQUESTS_DEFAULT), ConfigValueKind::NUMBER),
+    (API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_ENV_VAR, Some(API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_DEFAULT), ConfigValueKind::NUMBER),
+    (ACCESS_LOG_SAMPLE_PERCENT_ENV_VAR, Some(ACCESS_LOG_SAMPLE_PERCENT_DEFAULT), ConfigValueKind::NUMBER),
+    (RBAC_CONFIG_FILE_ENV_VAR, None, ConfigValueKind::TEXT),
+    (AUTHORIZATION_POLICY_URL_ENV_VAR, None, ConfigValueKind::SECRET),
+    (ADMIN_API_TOKEN_ENV_VAR, None, ConfigValueKind::SECRET),
+    (CALLER_CREDENTIALS_ENABLED_ENV_VAR, Some(CALLER_CREDENTIALS_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (AWS_ASSUME_ROLE_ARN_ENV_VAR, None, ConfigValueKind::TEXT),
+    (AWS_ASSUME_ROLE_SESSION_TAGS_ENV_VAR, Some(AWS_ASSUME_ROLE_SESSION_TAGS_DEFAULT), ConfigValueKind::TEXT),
+    (AWS_CLIENT_POOL_MAX_SIZE_ENV_VAR, Some(AWS_CLIENT_POOL_MAX_SIZE_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_ENV_VAR, Some(CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (CREDENTIALS_CACHE_IDLE_SECONDS_ENV_VAR, Some(CREDENTIALS_CACHE_IDLE_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (TENANCY_ENABLED_ENV_VAR, Some(TENANCY_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (TENANT_TOKEN_SECRET_ENV_VAR, None, ConfigValueKind::SECRET),
+    (MAINTENANCE_MODE_ENV_VAR, None, ConfigValueKind::TEXT),
+    (FEATURE_FLAGS_ENV_VAR, None, ConfigValueKind::TEXT),
+    (COST_PRICING_FILE_ENV_VAR, None, ConfigValueKind::TEXT),
+    (SELF_CHECK_BUCKETS_ENV_VAR, None, ConfigValueKind::TEXT),
+    (STARTUP_WARMUP_ENABLED_ENV_VAR, Some(STARTUP_WARMUP_ENABLED_DEFAULT), ConfigValueKind::FLAG),
+    (STARTUP_WARMUP_BUCKETS_ENV_VAR, None, ConfigValueKind::TEXT),
+    (STARTUP_WARMUP_CONNECTIONS_ENV_VAR, Some(STARTUP_WARMUP_CONNECTIONS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (STARTUP_WARMUP_TIMEOUT_SECONDS_ENV_VAR, Some(STARTUP_WARMUP_TIMEOUT_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (STATIC_WEBSITE_BUCKET_ENV_VAR, None, ConfigValueKind::TEXT),
+    (STATIC_WEBSITE_PATH_ENV_VAR, None, ConfigValueKind::TEXT),
+    (STATIC_WEBSITE_INDEX_DOCUMENT_ENV_VAR, Some(STATIC_WEBSITE_INDEX_DOCUMENT_DEFAULT), ConfigValueKind::TEXT),
+    (WATCH_PREFIXES_ENV_VAR, None, ConfigValueKind::TEXT),
+    (WATCH_DESTINATION_BUCKET_ENV_VAR, None, ConfigValueKind::TEXT),
+    (WATCH_DESTINATION_PATH_ENV_VAR, Some(WATCH_DESTINATION_PATH_DEFAULT), ConfigValueKind::TEXT),
+    (WATCH_INTERVAL_SECONDS_ENV_VAR, Some(WATCH_INTERVAL_SECONDS_DEFAULT), ConfigValueKind::POSITIVE_NUMBER),
+    (S3_EVENT_RULES_FILE_ENV_VAR, None, ConfigValueKind::TEXT),
+    (S3_EVENT_SIGNING_SECRET_ENV_VAR, None, ConfigValueKind::SECRET),
+    (S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_ENV_VAR, Some(S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+    (ERROR_REPORTER_SENTRY_DSN_ENV_VAR, None, ConfigValueKind::SECRET),
+    (EMAIL_NOTIFIER_SES_SMTP_HOST_ENV_VAR, None, ConfigValueKind::TEXT),
+    (EMAIL_NOTIFIER_SES_SMTP_USERNAME_ENV_VAR, None, ConfigValueKind::SECRET),
+    (EMAIL_NOTIFIER_SES_SMTP_PASSWORD_ENV_VAR, None, ConfigValueKind::SECRET),
+    (EMAIL_NOTIFIER_SENDER_ENV_VAR, None, ConfigValueKind::TEXT),
+    (JOB_EVENTS_SNS_TOPIC_ARN_ENV_VAR, None, ConfigValueKind::TEXT),
+    (JOB_EVENTS_EVENT_BUS_NAME_ENV_VAR, None, ConfigValueKind::TEXT),
+    (REDIS_URL_ENV_VAR, None, ConfigValueKind::SECRET),
+    (EXTERNAL_SECRETS_REFRESH_SECONDS_ENV_VAR, Some(EXTERNAL_SECRETS_REFRESH_SECONDS_DEFAULT), ConfigValueKind::NUMBER),
+];
+
+/// Required configuration entries (environment variable name required by another environment variable name and reason)
+const REQUIRED_CONFIG_ENTRIES: &[(&str, &str, &str)] = &[(
+    DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR,
+    REDIS_URL_ENV_VAR,
+    "signed links of a random signing secret are not valid on other replicas",
+)];
+
+/// Gets [ConfigDump] fully resolved configuration (enabled features and environment variables with secrets redacted)
+pub fn get_config_dump() -> ConfigDump {
+    ConfigDump {
+        features: get_enabled_features(),
+        entries: CONFIG_ENTRIES
+            .iter()
+            .map(|(name, default_value, value_kind)| {
+                create_config_entry(name, std::env::var(name).ok(), *default_value, *value_kind)
+            })
+            .collect(),
+    }
+}
+
+/// Logs the startup banner with the fully resolved configuration (not valid values are logged as warnings)
+pub fn log_config_dump() {
+    let config_dump = get_config_dump();
+    info!("Config - {} v{} - features: {:?}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), config_dump.features);
+    config_dump.entries.iter().for_each(|config_entry| {
+        let value = config_entry.value.as_deref().unwrap_or_default();
+        if config_entry.valid {
+            info!("Config - {}: {value} ({:?})", config_entry.name, config_entry.source);
+        } else {
+            warn!("Config - {}: {value} ({:?}) - value not valid", config_entry.name, config_entry.source);
+        }
+    });
+}
+
+/// Gets [Vec<String>] configuration errors (one per not valid environment variable - secrets are not included)
+pub fn get_config_errors() -> Vec<String> {
+    CONFIG_ENTRIES
+        .iter()
+        .filter_map(|(name, _, value_kind)| {
+            std::env::var(name)
+                .ok()
+                .filter(|value| !value_kind.is_valid(value))
+                .map(|value| match value_kind {
+                    ConfigValueKind::SECRET => format!("{name} - not valid value"),
+                    _ => format!("{name} - not valid value: '{value}' ({value_kind:?} expected)"),
+                })
+        })
+        .chain(get_required_config_errors(|name| std::env::var(name).is_ok_and(|value| !value.is_empty())))
+        .collect()
+}
+
+/// Gets [Vec<String>] configuration errors (one per not defined environment variable required by a defined one - please,
+/// check [REQUIRED_CONFIG_ENTRIES]) by [Fn(&str) -> bool] defined environment variable function
+fn get_required_config_errors(is_defined: impl Fn(&str) -> bool) -> Vec<String> {
+    REQUIRED_CONFIG_ENTRIES
+        .iter()
+        .filter(|(name, required_by, _)| is_defined(required_by) && !is_defined(name))
+        .map(|(name, required_by, reason)| format!("{name} - required if {required_by} is defined ({reason})"))
+        .collect()
+}
+
+/// Gets [Vec<&str>] secret environment variable names (secret config values)
+pub fn get_secret_env_var_names() -> Vec<&'static str> {
+    CONFIG_ENTRIES
+        .iter()
+        .filter(|(_, _, value_kind)| *value_kind == ConfigValueKind::SECRET)
+        .map(|(name, _, _)| *name)
+        .collect()
+}
+
+/// Gets [Vec<String>] enabled compile time features
+pub fn get_enabled_features() -> Vec<String> {
+    let features = [
+        ("sentry", cfg!(feature = "sentry")),
+        ("ses-email", cfg!(feature = "ses-email")),
+        ("aws-events", cfg!(feature = "aws-events")),
+        ("redis", cfg!(feature = "redis")),
+        ("aws-secrets", cfg!(feature = "aws-secrets")),
+        ("thumbnails", cfg!(feature = "thumbnails")),
+    ];
+    features
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(feature, _)| String::from(feature))
+        .collect()
+}
+
+/// Creates a new [ConfigEntry] by [&str] environment variable name, [Option<String>] environment variable value,
+/// [Option<&str>] default value and [ConfigValueKind] value kind (secrets are redacted)
+fn create_config_entry(
+    name: &str,
+    env_value: Option<String>,
+    default_value: Option<&str>,
+    value_kind: ConfigValueKind,
+) -> ConfigEntry {
+    let (value, source) = match (env_value, default_value) {
+        (Some(env_value), _) => (Some(env_value), ConfigValueSource::ENVIRONMENT),
+        (None, Some(default_value)) => (Some(String::from(default_value)), ConfigValueSource::DEFAULT),
+        (None, None) => (None, ConfigValueSource::NOT_DEFINED),
+    };
+    let valid = value.as_deref().is_none_or(|value| value_kind.is_valid(value));
+
+    ConfigEntry {
+        name: String::from(name),
+        value: match value_kind {
+            ConfigValueKind::SECRET => value.map(|_| String::from(REDACTED_VALUE)),
+            _ => value,
+        },
+        source,
+        valid,
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::config_dump::{create_config_entry, get_required_config_errors};
+    use crate::enums::config_value_kind::ConfigValueKind;
+    use crate::enums::config_value_source::ConfigValueSource;
+
+    /// Scenario:
+    /// Creates config entries for a secret, a not valid number and a not defined number with default value
+    /// Expectation:
+    /// Secret should be redacted, not valid number should be flagged and default value should be used
+    #[test]
+    fn when_create_config_entry_should_redact_secrets_and_check_values() {
+        let secret_entry = create_config_entry("SECRET", Some(String::from("s3cr3t")), None, ConfigValueKind::SECRET);
+        let not_valid_entry = create_config_entry("NUMBER", Some(String::from("10MB")), Some("1"), ConfigValueKind::NUMBER);
+        let default_entry = create_config_entry("NUMBER", None, Some("1"), ConfigValueKind::NUMBER);
+
+        assert_eq!(Some(String::from("********")), secret_entry.value);
+        assert!(!not_valid_entry.valid);
+        assert_eq!(ConfigValueSource::ENVIRONMENT, not_valid_entry.source);
+        assert_eq!(Some(String::from("1")), default_entry.value);
+        assert_eq!(ConfigValueSource::DEFAULT, default_entry.source);
+        assert!(!ConfigValueKind::POSITIVE_NUMBER.is_valid("0"));
+    }
+
+    /// Scenario:
+    /// Gets required configuration errors with and without shared state store and signed links signing secret
+    /// Expectation:
+    /// Signing secret should be required just if the shared state store (multi-replica deployment) is configured
+    #[test]
+    fn when_get_required_config_errors_should_require_signing_secret_for_replicas() {
+        assert!(get_required_config_errors(|_| false).is_empty());
+        assert!(get_required_config_errors(|_| true).is_empty());
+
+        let config_errors = get_required_config_errors(|name| name == "REDIS_URL");
+        assert_eq!(1, config_errors.len());
+        assert!(config_errors[0].starts_with("DOWNLOAD_SIGNED_LINK_SECRET - required if REDIS_URL is defined"));
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+
+use aws_credential_types::Credentials;
+use aws_credential_types::provider::{future, ProvideCredentials, SharedCredentialsProvider};
+use aws_credential_types::provider::error::CredentialsError;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{CREDENTIALS_CACHE_IDLE_SECONDS_DEFAULT, CREDENTIALS_CACHE_IDLE_SECONDS_ENV_VAR, CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_DEFAULT, CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_ENV_VAR, CREDENTIALS_CACHE_REFRESH_INTERVAL_SECONDS};
+use log::{info, warn};
+
+/// Credentials cache key (assumed role ARN - none for base credentials - and session tags)
+type CredentialsCacheKey = (Option<String>, Vec<(String, String)>);
+
+/// Cached credentials struct (credentials provider, its last credentials and last use time as seconds since unix epoch)
+struct CachedCredentials {
+    credentials_provider: SharedCredentialsProvider,
+    credentials: Option<Credentials>,
+    last_used_at: u64,
+}
+
+impl CachedCredentials {
+    /// Creates a new [CachedCredentials] cached credentials (not provided yet) by [SharedCredentialsProvider] provider
+    fn new(credentials_provider: SharedCredentialsProvider) -> Self {
+        CachedCredentials { credentials_provider, credentials: None, last_used_at: current_epoch_seconds() }
+    }
+}
+
+/// Credentials cache struct (credentials by role and session tags, refreshed ahead of their expiration)
+pub struct CredentialsCache {
+    entries: Mutex<HashMap<CredentialsCacheKey, CachedCredentials>>,
+    refresh_ahead_seconds: u64,
+    idle_seconds: u64,
+}
+
+/// Credentials cache shared instance (please, check constants.rs)
+pub static CREDENTIALS_CACHE: LazyLock<CredentialsCache> = LazyLock::new(|| CredentialsCache {
+    entries: Mutex::new(HashMap::new()),
+    refresh_ahead_seconds: get_env_var_as_u64(CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_ENV_VAR, CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_DEFAULT),
+    idle_seconds: get_env_var_as_u64(CREDENTIALS_CACHE_IDLE_SECONDS_ENV_VAR, CREDENTIALS_CACHE_IDLE_SECONDS_DEFAULT),
+});
+
+/// Cached credentials provider struct (credentials are provided by [CREDENTIALS_CACHE] shared instance - credentials
+/// provider is kept, so evicted credentials are cached again on demand)
+#[derive(Debug)]
+struct CachedCredentialsProvider {
+    cache_key: CredentialsCacheKey,
+    credentials_provider: SharedCredentialsProvider,
+}
+
+/// Cached credentials provider implementation logic
+impl ProvideCredentials for CachedCredentialsProvider {
+    fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::new(CREDENTIALS_CACHE.provide_credentials(&self.cache_key, &self.credentials_provider))
+    }
+}
+
+impl CredentialsCache {
+    /// Gets [SharedCredentialsProvider] cached credentials provider by [Option<&str>] assumed role ARN (none for base
+    /// credentials) and [Vec<(String, String)>] session tags - [Future] credentials provider creation is awaited just
+    /// if there is no cached credentials provider yet
+    /// Important: clients created with it share cached credentials (i.e.: credentials are requested once per tenant)
+    pub async fn get_credentials_provider<F>(&self, role_arn: Option<&str>, session_tags: Vec<(String, String)>, create_credentials_provider: F) -> SharedCredentialsProvider
+    where
+        F: Future<Output = SharedCredentialsProvider>,
+    {
+        let cache_key = (role_arn.map(String::from), session_tags);
+        let cached_credentials_provider = self
+            .lock_entries()
+            .get(&cache_key)
+            .map(|cached_credentials| cached_credentials.credentials_provider.clone());
+        let credentials_provider = match cached_credentials_provider {
+            Some(credentials_provider) => credentials_provider,
+            None => {
+                let credentials_provider = create_credentials_provider.await;
+                self.lock_entries()
+                    .entry(cache_key.clone())
+                    .or_insert_with(|| CachedCredentials::new(credentials_provider))
+                    .credentials_provider
+                    .clone()
+            }
+        };
+        SharedCredentialsProvider::new(CachedCredentialsProvider { cache_key, credentials_provider })
+    }
+
+    /// Refreshes cached credentials expiring within the refresh ahead window (idle credentials are evicted)
+    /// Returns [usize] refreshed credentials quantity
+    pub async fn refresh(&self) -> usize {
+        let idle_since = current_epoch_seconds().saturating_sub(self.idle_seconds);
+        let expiring_cache_keys = {
+            let mut entries = self.lock_entries();
+            entries.retain(|_, cached_credentials| cached_credentials.last_used_at >= idle_since);
+            entries
+                .iter()
+                .filter(|(_, cached_credentials)| {
+                    cached_credentials.credentials.as_ref().is_some_and(|credentials| !self.is_fresh(credentials))
+                })
+                .map(|(cache_key, _)| cache_key.clone())
+                .collect::<Vec<CredentialsCacheKey>>()
+        };
+
+        let mut refreshed_total = 0;
+        for cache_key in expiring_cache_keys {
+            match self.refresh_credentials(&cache_key).await {
+                Ok(_) => refreshed_total += 1,
+                Err(credentials_error) => warn!("refresh - credentials not refreshed - role: {:?} - error: {credentials_error}", cache_key.0),
+            }
+        }
+        refreshed_total
+    }
+
+    /// Provides [Credentials] cached credentials by [&CredentialsCacheKey] cache key (they are requested to
+    /// [&SharedCredentialsProvider] credentials provider if they are not cached yet or they are expiring)
+    /// Returns a [CredentialsError] if credentials are not cached and they can't be provided
+    async fn provide_credentials(&self, cache_key: &CredentialsCacheKey, credentials_provider: &SharedCredentialsProvider) -> Result<Credentials, CredentialsError> {
+        {
+            let mut entries = self.lock_entries();
+            let cached_credentials =
+                entries.entry(cache_key.clone()).or_insert_with(|| CachedCredentials::new(credentials_provider.clone()));
+            cached_credentials.last_used_at = current_epoch_seconds();
+            if let Some(credentials) = cached_credentials.credentials.as_ref().filter(|credentials| self.is_fresh(credentials)) {
+                return Ok(credentials.clone());
+            }
+        }
+        self.refresh_credentials(cache_key).await
+    }
+
+    /// Refreshes [Credentials] cached credentials by [&CredentialsCacheKey] cache key
+    /// Returns a [CredentialsError] if credentials provider is not cached or credentials can't be provided
+    async fn refresh_credentials(&self, cache_key: &CredentialsCacheKey) -> Result<Credentials, CredentialsError> {
+        let credentials_provider = self
+            .lock_entries()
+            .get(cache_key)
+            .map(|cached_credentials| cached_credentials.credentials_provider.clone())
+            .ok_or_else(|| CredentialsError::provider_error("credentials provider is not cached (evicted)"))?;
+
+        let credentials = credentials_provider.provide_credentials().await?;
+        if let Some(cached_credentials) = self.lock_entries().get_mut(cache_key) {
+            cached_credentials.credentials = Some(credentials.clone());
+        }
+        Ok(credentials)
+    }
+
+    /// Gets [bool] true if [&Credentials] credentials don't expire within the refresh ahead window
+    fn is_fresh(&self, credentials: &Credentials) -> bool {
+        credentials
+            .expiry()
+            .is_none_or(|expiry| expiry > SystemTime::now() + Duration::from_secs(self.refresh_ahead_seconds))
+    }
+
+    /// Locks [HashMap] cached credentials by cache key (poisoned lock is recovered)
+    fn lock_entries(&self) -> std::sync::MutexGuard<'_, HashMap<CredentialsCacheKey, CachedCredentials>> {
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Starts a background task that refreshes cached credentials ahead of their expiration, so long running exports
+/// don't fail on expired sessions (please, check constants.rs)
+pub fn start_credentials_cache_refresh_task() {
+    info!("start_credentials_cache_refresh_task - refresh ahead (seconds): {}", CREDENTIALS_CACHE.refresh_ahead_seconds);
+
+    tokio::spawn(async move {
+        let mut refresh_interval = tokio::time::interval(Duration::from_secs(CREDENTIALS_CACHE_REFRESH_INTERVAL_SECONDS));
+        loop {
+            refresh_interval.tick().await;
+            let refreshed_total = CREDENTIALS_CACHE.refresh().await;
+            if refreshed_total > 0 {
+                info!("credentials_cache_refresh_task - refreshed credentials: {refreshed_total}");
+            }
+        }
+    });
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, SystemTime};
+
+    use aws_credential_types::Credentials;
+    use aws_credential_types::provider::{future, ProvideCredentials, SharedCredentialsProvider};
+    use crate::config::credentials_cache::CredentialsCache;
+
+    /// Test credentials provider struct (credentials expire after the given seconds - provided credentials are counted)
+    #[derive(Debug)]
+    struct TestCredentialsProvider {
+        expiration_seconds: u64,
+        provided_total: Arc<AtomicU64>,
+    }
+
+    impl ProvideCredentials for TestCredentialsProvider {
+        fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+        where
+            Self: 'a,
+        {
+            self.provided_total.fetch_add(1, Ordering::SeqCst);
+            let expiry = SystemTime::now() + Duration::from_secs(self.expiration_seconds);
+            future::ProvideCredentials::ready(Ok(Credentials::new("ASIAEXAMPLE", "some-secret", None, Some(expiry), "test")))
+        }
+    }
+
+    /// Scenario:
+    /// Provides cached credentials twice for long-lived and expiring credentials and refreshes them
+    /// Expectation:
+    /// Long-lived credentials should be provided once, expiring ones should be provided again and refreshed
+    #[tokio::test]
+    async fn when_provide_credentials_should_refresh_just_expiring_credentials() {
+        let credentials_cache = CredentialsCache { entries: Mutex::new(HashMap::new()), refresh_ahead_seconds: 300, idle_seconds: 3600 };
+        for (tenant_id, expiration_seconds, expected_provided_total) in [("acme", 3600, 1), ("globex", 60, 3)] {
+            let provided_total = Arc::new(AtomicU64::new(0));
+            let session_tags = vec![(String::from("tenant"), String::from(tenant_id))];
+            let credentials_provider = TestCredentialsProvider { expiration_seconds, provided_total: provided_total.clone() };
+            let credentials_provider = SharedCredentialsProvider::new(credentials_provider);
+
+            let cache_key = (Some(String::from("arn:aws:iam::123456789012:role/reader")), session_tags);
+            assert!(credentials_cache.provide_credentials(&cache_key, &credentials_provider).await.is_ok());
+            assert!(credentials_cache.provide_credentials(&cache_key, &credentials_provider).await.is_ok());
+            credentials_cache.refresh().await;
+
+            assert_eq!(expected_provided_total, provided_total.load(Ordering::SeqCst));
+        }
+    }
+}
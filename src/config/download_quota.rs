@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{DOWNLOAD_QUOTA_ANONYMOUS_CALLER, DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_DEFAULT, DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_ENV_VAR, DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_DEFAULT, DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_ENV_VAR};
+use crate::dto::download_quota_usage::{DownloadQuotaUsage, QuotaConsumption};
+
+/// Seconds per day (quotas are reset at 00:00 UTC)
+const SECONDS_PER_DAY: u64 = 86400;
+
+/// Download quota registry struct (downloaded bytes per S3 bucket and per caller for the current UTC day)
+/// Important: caps equal to zero are not enforced (unlimited)
+pub struct DownloadQuotaRegistry {
+    bucket_daily_bytes_cap: u64,
+    caller_daily_bytes_cap: u64,
+    consumption: Mutex<DailyConsumption>,
+}
+
+/// Daily consumption (registry state)
+#[derive(Default)]
+struct DailyConsumption {
+    epoch_day: u64,
+    bytes_by_bucket: HashMap<String, u64>,
+    bytes_by_caller: HashMap<String, u64>,
+}
+
+/// Download quota registry shared instance (daily caps should be configured - please, check constants.rs)
+pub static DOWNLOAD_QUOTA_REGISTRY: LazyLock<DownloadQuotaRegistry> = LazyLock::new(|| {
+    DownloadQuotaRegistry::new(
+        get_env_var_as_u64(DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_ENV_VAR, DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_DEFAULT),
+        get_env_var_as_u64(DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_ENV_VAR, DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_DEFAULT),
+    )
+});
+
+impl DownloadQuotaRegistry {
+    /// Creates a new [DownloadQuotaRegistry] by [u64] S3 bucket daily bytes cap and [u64] caller daily bytes cap
+    pub fn new(bucket_daily_bytes_cap: u64, caller_daily_bytes_cap: u64) -> Self {
+        DownloadQuotaRegistry {
+            bucket_daily_bytes_cap,
+            caller_daily_bytes_cap,
+            consumption: Mutex::new(DailyConsumption::default()),
+        }
+    }
+
+    /// Gets [bool] true if any daily cap is configured
+    pub fn is_enabled(&self) -> bool {
+        self.bucket_daily_bytes_cap > 0 || self.caller_daily_bytes_cap > 0
+    }
+
+    /// Gets [bool] true if caller daily cap is configured (download requests should have an authenticated caller)
+    pub fn is_caller_cap_enabled(&self) -> bool {
+        self.caller_daily_bytes_cap > 0
+    }
+
+    /// Gets [bool] true if [&str] S3 bucket or [Option<&str>] caller (anonymous if not defined) daily cap is reached
+    pub fn is_exceeded(&self, bucket_name: &str, caller_id: Option<&str>) -> bool {
+        let mut consumption = self.consumption.lock().unwrap();
+        reset_expired_consumption(&mut consumption);
+
+        let caller_id = caller_id.unwrap_or(DOWNLOAD_QUOTA_ANONYMOUS_CALLER);
+        is_cap_reached(self.bucket_daily_bytes_cap, consumption.bytes_by_bucket.get(bucket_name))
+            || is_cap_reached(self.caller_daily_bytes_cap, consumption.bytes_by_caller.get(caller_id))
+    }
+
+    /// Records [u64] downloaded bytes by [&str] S3 bucket and [Option<&str>] caller (anonymous if not defined)
+    pub fn record_bytes(&self, bucket_name: &str, caller_id: Option<&str>, bytes: u64) {
+        let mut consumption = self.consumption.lock().unwrap();
+        reset_expired_consumption(&mut consumption);
+
+        let caller_id = caller_id.unwrap_or(DOWNLOAD_QUOTA_ANONYMOUS_CALLER);
+        *consumption.bytes_by_bucket.entry(String::from(bucket_name)).or_default() += bytes;
+        *consumption.bytes_by_caller.entry(String::from(caller_id)).or_default() += bytes;
+    }
+
+    /// Gets [DownloadQuotaUsage] daily caps and consumption per S3 bucket and per caller (sorted by name)
+    pub fn get_quota_usage(&self) -> DownloadQuotaUsage {
+        let mut consumption = self.consumption.lock().unwrap();
+        reset_expired_consumption(&mut consumption);
+
+        DownloadQuotaUsage {
+            day_started_at: consumption.epoch_day * SECONDS_PER_DAY,
+            bucket_daily_bytes_cap: self.bucket_daily_bytes_cap,
+            caller_daily_bytes_cap: self.caller_daily_bytes_cap,
+            buckets: create_quota_consumption(&consumption.bytes_by_bucket, self.bucket_daily_bytes_cap),
+            callers: create_quota_consumption(&consumption.bytes_by_caller, self.caller_daily_bytes_cap),
+        }
+    }
+}
+
+/// Resets [DailyConsumption] daily consumption if the current UTC day is over
+fn reset_expired_consumption(consumption: &mut DailyConsumption) {
+    let epoch_day = current_epoch_seconds() / SECONDS_PER_DAY;
+    if consumption.epoch_day != epoch_day {
+        *consumption = DailyConsumption {
+            epoch_day,
+            ..Default::default()
+        };
+    }
+}
+
+/// Gets [bool] true if [u64] daily cap (zero is unlimited) is reached by [Option<&u64>] consumed bytes
+fn is_cap_reached(daily_bytes_cap: u64, bytes: Option<&u64>) -> bool {
+    daily_bytes_cap > 0 && bytes.is_some_and(|bytes| *bytes >= daily_bytes_cap)
+}
+
+/// Creates a new [Vec<QuotaConsumption>] by [&HashMap<String, u64>] consumed bytes by name and [u64] daily cap
+fn create_quota_consumption(bytes_by_name: &HashMap<String, u64>, daily_bytes_cap: u64) -> Vec<QuotaConsumption> {
+    let mut quota_consumption: Vec<QuotaConsumption> = bytes_by_name
+        .iter()
+        .map(|(name, bytes)| QuotaConsumption {
+            name: name.clone(),
+            bytes_total: *bytes,
+            bytes_remaining: (daily_bytes_cap > 0).then(|| daily_bytes_cap.saturating_sub(*bytes)),
+        })
+        .collect();
+    quota_consumption.sort_by(|a, b| a.name.cmp(&b.name));
+    quota_consumption
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::download_quota::DownloadQuotaRegistry;
+
+    /// Scenario:
+    /// Records downloaded bytes for two callers over the same S3 bucket (caller cap reached by one of them)
+    /// Expectation:
+    /// Just the caller over the cap should be exceeded and consumption should be retrieved per bucket and per caller
+    #[test]
+    fn when_record_bytes_should_enforce_caller_cap() {
+        let download_quota_registry = DownloadQuotaRegistry::new(0, 100);
+        download_quota_registry.record_bytes("bucket-a", Some("caller-a"), 100);
+        download_quota_registry.record_bytes("bucket-a", None, 40);
+
+        assert!(download_quota_registry.is_exceeded("bucket-a", Some("caller-a")));
+        assert!(!download_quota_registry.is_exceeded("bucket-a", None));
+
+        let download_quota_usage = download_quota_registry.get_quota_usage();
+        assert_eq!(140, download_quota_usage.buckets[0].bytes_total);
+        assert_eq!(None, download_quota_usage.buckets[0].bytes_remaining);
+        assert_eq!("anonymous", download_quota_usage.callers[0].name);
+        assert_eq!(Some(60), download_quota_usage.callers[0].bytes_remaining);
+    }
+}
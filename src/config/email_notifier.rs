@@ -0,0 +1,120 @@
+use std::sync::{Arc, LazyLock};
+
+use async_trait::async_trait;
+use crate::enums::common_error::CommonError;
+use log::info;
+
+/// Email notifier (sends human readable notifications, i.e.: Amazon SES)
+#[async_trait]
+pub trait EmailNotifierTrait {
+    /// Sends an email to [&str] recipient email address by [&str] subject and [&str] plain text body
+    /// Returns a [CommonError] if email can't be sent
+    async fn send_email(&self, recipient: &str, subject: &str, body: &str) -> Result<(), CommonError>;
+}
+
+/// Log email notifier implementation struct (default email notifier: emails are just logged)
+/// Important: just recipient and subject are logged - email body may include bearer download links (job result URLs)
+pub struct LogEmailNotifier {}
+
+/// Log email notifier implementation logic
+#[async_trait]
+impl EmailNotifierTrait for LogEmailNotifier {
+    /// Sends an email to [&str] recipient email address by [&str] subject and [&str] plain text body
+    /// Returns a [CommonError] if email can't be sent
+    async fn send_email(&self, recipient: &str, subject: &str, _body: &str) -> Result<(), CommonError> {
+        info!("send_email - email delivery not configured - recipient: {recipient} - subject: {subject}");
+        Ok(())
+    }
+}
+
+/// Amazon SES email notifier implementation struct (enabled by `ses-email` feature and SES SMTP env vars)
+#[cfg(feature = "ses-email")]
+pub struct SesEmailNotifier {
+    smtp_transport: lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
+    sender: lettre::message::Mailbox,
+}
+
+/// Amazon SES email notifier implementation logic
+#[cfg(feature = "ses-email")]
+#[async_trait]
+impl EmailNotifierTrait for SesEmailNotifier {
+    /// Sends an email to [&str] recipient email address by [&str] subject and [&str] plain text body
+    /// Returns a [CommonError] if email can't be sent
+    async fn send_email(&self, recipient: &str, subject: &str, body: &str) -> Result<(), CommonError> {
+        use lettre::AsyncTransport;
+        use log::error;
+
+        let Ok(recipient_mailbox) = recipient.parse() else {
+            error!("send_email - not valid recipient email address: {recipient}");
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        };
+        let email = lettre::Message::builder()
+            .from(self.sender.clone())
+            .to(recipient_mailbox)
+            .subject(subject)
+            .body(String::from(body))
+            .map_err(|email_error| {
+                error!("send_email - email can't be created - error: {email_error}");
+                CommonError::NO_VALID_INPUT_OR_PARAMETER
+            })?;
+
+        match self.smtp_transport.send(email).await {
+            Ok(_) => {
+                info!("send_email - done - recipient: {recipient}");
+                Ok(())
+            }
+            Err(smtp_error) => {
+                error!("send_email - email can't be sent - recipient: {recipient} - error: {smtp_error}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+}
+
+/// Email notifier shared instance
+/// Important: Amazon SES notifier (SMTP interface) is used if `ses-email` feature is enabled and SES SMTP env vars
+/// are defined (please, check constants.rs), otherwise emails are just logged
+pub static EMAIL_NOTIFIER: LazyLock<DynEmailNotifier> = LazyLock::new(create_email_notifier);
+
+/// Creates a new [DynEmailNotifier] email notifier (please, check [EMAIL_NOTIFIER])
+#[cfg(feature = "ses-email")]
+fn create_email_notifier() -> DynEmailNotifier {
+    use crate::constant::constants::{EMAIL_NOTIFIER_SENDER_ENV_VAR, EMAIL_NOTIFIER_SES_SMTP_HOST_ENV_VAR, EMAIL_NOTIFIER_SES_SMTP_PASSWORD_ENV_VAR, EMAIL_NOTIFIER_SES_SMTP_USERNAME_ENV_VAR};
+    use crate::config::external_secrets::get_secret_env_var;
+    use lettre::transport::smtp::authentication::Credentials;
+    use log::warn;
+
+    let ses_smtp_config = (
+        std::env::var(EMAIL_NOTIFIER_SES_SMTP_HOST_ENV_VAR),
+        get_secret_env_var(EMAIL_NOTIFIER_SES_SMTP_USERNAME_ENV_VAR),
+        get_secret_env_var(EMAIL_NOTIFIER_SES_SMTP_PASSWORD_ENV_VAR),
+        std::env::var(EMAIL_NOTIFIER_SENDER_ENV_VAR).ok().and_then(|sender| sender.parse().ok()),
+    );
+    let (Ok(ses_smtp_host), Ok(ses_smtp_username), Ok(ses_smtp_password), Some(sender)) = ses_smtp_config else {
+        warn!("create_email_notifier - SES SMTP configuration not defined (or not valid sender) - emails are just logged");
+        return Arc::new(LogEmailNotifier {}) as DynEmailNotifier;
+    };
+
+    match lettre::AsyncSmtpTransport::<lettre::Tokio1Executor>::starttls_relay(&ses_smtp_host) {
+        Ok(smtp_transport_builder) => {
+            info!("create_email_notifier - SES email notifier enabled - host: {ses_smtp_host}");
+            let smtp_transport = smtp_transport_builder
+                .credentials(Credentials::new(ses_smtp_username, ses_smtp_password))
+                .build();
+            Arc::new(SesEmailNotifier { smtp_transport, sender }) as DynEmailNotifier
+        }
+        Err(smtp_error) => {
+            warn!("create_email_notifier - SES SMTP host not valid - emails are just logged - error: {smtp_error}");
+            Arc::new(LogEmailNotifier {}) as DynEmailNotifier
+        }
+    }
+}
+
+/// Creates a new [DynEmailNotifier] email notifier (please, check [EMAIL_NOTIFIER])
+#[cfg(not(feature = "ses-email"))]
+fn create_email_notifier() -> DynEmailNotifier {
+    Arc::new(LogEmailNotifier {}) as DynEmailNotifier
+}
+
+/// Email notifier trait for shared instance
+pub type DynEmailNotifier = Arc<dyn EmailNotifierTrait + Send + Sync>;
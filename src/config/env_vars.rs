@@ -0,0 +1,23 @@
+/// Gets [i64] value by [&str] environment variable name and [&str] environment variable default value
+pub fn get_env_var_as_i64(env_var_name: &str, env_var_default: &str) -> i64 {
+    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
+    value.parse().unwrap_or_default()
+}
+
+/// Gets [u64] value by [&str] environment variable name and [&str] environment variable default value
+pub fn get_env_var_as_u64(env_var_name: &str, env_var_default: &str) -> u64 {
+    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
+    value.parse().unwrap_or_default()
+}
+
+/// Gets [usize] value by [&str] environment variable name and [&str] environment variable default value
+pub fn get_env_var_as_usize(env_var_name: &str, env_var_default: &str) -> usize {
+    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
+    value.parse().unwrap_or_default()
+}
+
+/// Gets [bool] value by [&str] environment variable name and [&str] environment variable default value
+pub fn get_env_var_as_bool(env_var_name: &str, env_var_default: &str) -> bool {
+    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
+    value.parse().unwrap_or_default()
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::constant::constants::{ENVELOPE_CONTENT_ALGORITHM, ENVELOPE_CONTENT_ALGORITHM_METADATA, ENVELOPE_ENCRYPTION_CONTEXT_KEY, ENVELOPE_IV_METADATA, ENVELOPE_MATERIAL_DESCRIPTION_METADATA, ENVELOPE_TAG_LENGTH_BITS, ENVELOPE_TAG_LENGTH_METADATA, ENVELOPE_WRAP_ALGORITHM, ENVELOPE_WRAP_ALGORITHM_METADATA, ENVELOPE_WRAPPED_KEY_METADATA, EXPORT_KMS_ENVELOPE_KEY_ID_ENV_VAR};
+use crate::enums::common_error::CommonError;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use log::{debug, error};
+
+/// Envelope encryption struct (client-side encryption of exports written to S3 by KMS generated data keys - disabled if
+/// the KMS key is not configured, please check constants.rs)
+pub struct EnvelopeEncryption {
+    kms_key_id: Option<String>,
+    http_client: reqwest::Client,
+}
+
+/// Envelope encryption shared instance
+pub static ENVELOPE_ENCRYPTION: LazyLock<EnvelopeEncryption> = LazyLock::new(|| EnvelopeEncryption {
+    kms_key_id: std::env::var(EXPORT_KMS_ENVELOPE_KEY_ID_ENV_VAR).ok().filter(|kms_key_id| !kms_key_id.is_empty()),
+    http_client: reqwest::Client::new(),
+});
+
+impl EnvelopeEncryption {
+    /// Gets [bool] true if envelope encryption KMS key is configured
+    pub fn is_enabled(&self) -> bool {
+        self.kms_key_id.is_some()
+    }
+
+    /// Encrypts [&[u8]] content by a new KMS data key (AES-256-GCM with a random IV)
+    /// Returns [(Vec<u8>, HashMap<String, String>)] ciphertext and envelope S3 object metadata (wrapped data key, IV and
+    /// algorithms)
+    /// Returns a [CommonError] if envelope encryption is not enabled or KMS data key can't be generated
+    pub async fn encrypt(&self, content: &[u8]) -> Result<(Vec<u8>, HashMap<String, String>), CommonError> {
+        let Some(kms_key_id) = &self.kms_key_id else {
+            error!("encrypt - envelope encryption KMS key not configured");
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        };
+        let (data_key, wrapped_data_key) = generate_data_key(&self.http_client, kms_key_id).await.map_err(|kms_error| {
+            error!("encrypt - KMS data key not generated - error: {kms_error}");
+            CommonError::AWS_ACCESS_ERROR
+        })?;
+        debug!("encrypt - KMS data key generated");
+        seal_envelope(&data_key, &wrapped_data_key, content)
+    }
+
+    /// Decrypts [&[u8]] ciphertext by [&HashMap<String, String>] envelope S3 object metadata (wrapped data key is
+    /// decrypted by KMS)
+    /// Returns [Vec<u8>] content
+    /// Returns a [CommonError] if envelope metadata is not valid, KMS data key can't be decrypted or content is tampered
+    pub async fn decrypt(&self, envelope_metadata: &HashMap<String, String>, ciphertext: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let wrapped_data_key = get_wrapped_data_key(envelope_metadata).ok_or(CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+        let data_key = decrypt_data_key(&self.http_client, &wrapped_data_key).await.map_err(|kms_error| {
+            error!("decrypt - KMS data key not decrypted - error: {kms_error}");
+            CommonError::AWS_ACCESS_ERROR
+        })?;
+        debug!("decrypt - KMS data key decrypted");
+        open_envelope(&data_key, envelope_metadata, ciphertext)
+    }
+}
+
+/// Gets [Option<Vec<u8>>] wrapped data key if [&HashMap<String, String>] S3 object metadata defines a supported
+/// envelope (KMS wrapped data key and AES-256-GCM content algorithm)
+pub fn get_wrapped_data_key(envelope_metadata: &HashMap<String, String>) -> Option<Vec<u8>> {
+    envelope_metadata
+        .get(ENVELOPE_WRAP_ALGORITHM_METADATA)
+        .filter(|wrap_algorithm| *wrap_algorithm == ENVELOPE_WRAP_ALGORITHM)
+        .and(envelope_metadata.get(ENVELOPE_CONTENT_ALGORITHM_METADATA))
+        .filter(|content_algorithm| *content_algorithm == ENVELOPE_CONTENT_ALGORITHM)
+        .and(envelope_metadata.get(ENVELOPE_WRAPPED_KEY_METADATA))
+        .and_then(|wrapped_data_key| STANDARD.decode(wrapped_data_key).ok())
+}
+
+/// Seals [&[u8]] content by [&[u8]] data key (AES-256-GCM with a random IV - authentication tag appended) and
+/// [&[u8]] wrapped data key (KMS encrypted data key stored in envelope metadata)
+/// Returns [(Vec<u8>, HashMap<String, String>)] ciphertext and envelope S3 object metadata
+/// Returns a [CommonError] if data key is not a 256-bit key
+fn seal_envelope(data_key: &[u8], wrapped_data_key: &[u8], content: &[u8]) -> Result<(Vec<u8>, HashMap<String, String>), CommonError> {
+    let cipher = Aes256Gcm::new_from_slice(data_key).map_err(|_| CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+    let iv = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&iv, content).map_err(|_| CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+
+    let envelope_metadata = HashMap::from([
+        (String::from(ENVELOPE_WRAPPED_KEY_METADATA), STANDARD.encode(wrapped_data_key)),
+        (String::from(ENVELOPE_IV_METADATA), STANDARD.encode(iv)),
+        (String::from(ENVELOPE_CONTENT_ALGORITHM_METADATA), String::from(ENVELOPE_CONTENT_ALGORITHM)),
+        (String::from(ENVELOPE_WRAP_ALGORITHM_METADATA), String::from(ENVELOPE_WRAP_ALGORITHM)),
+        (String::from(ENVELOPE_TAG_LENGTH_METADATA), String::from(ENVELOPE_TAG_LENGTH_BITS)),
+        (String::from(ENVELOPE_MATERIAL_DESCRIPTION_METADATA), create_encryption_context().to_string()),
+    ]);
+    Ok((ciphertext, envelope_metadata))
+}
+
+/// Opens [&[u8]] ciphertext by [&[u8]] data key and [&HashMap<String, String>] envelope S3 object metadata (IV)
+/// Returns [Vec<u8>] content
+/// Returns a [CommonError] if data key or IV are not valid, or content is tampered (authentication tag mismatch)
+fn open_envelope(data_key: &[u8], envelope_metadata: &HashMap<String, String>, ciphertext: &[u8]) -> Result<Vec<u8>, CommonError> {
+    let cipher = Aes256Gcm::new_from_slice(data_key).map_err(|_| CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+    let iv = envelope_metadata
+        .get(ENVELOPE_IV_METADATA)
+        .and_then(|iv| STANDARD.decode(iv).ok())
+        .filter(|iv| iv.len() == 12)
+        .ok_or(CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+    cipher.decrypt(Nonce::from_slice(&iv), ciphertext).map_err(|_| {
+        error!("open_envelope - content not authenticated (tampered content or wrong data key)");
+        CommonError::NO_VALID_INPUT_OR_PARAMETER
+    })
+}
+
+/// Creates a new [serde_json::Value] KMS encryption context (data keys are bound to the content algorithm)
+fn create_encryption_context() -> serde_json::Value {
+    serde_json::json!({ ENVELOPE_ENCRYPTION_CONTEXT_KEY: ENVELOPE_CONTENT_ALGORITHM })
+}
+
+/// Gets [Option<&str>] AWS region defined by [&str] KMS key ARN (format: arn:aws:kms:{region}:{account}:key/{key_id})
+#[cfg(feature = "kms-envelope")]
+fn get_kms_key_region(kms_key_id: &str) -> Option<&str> {
+    kms_key_id.strip_prefix("arn:").and_then(|kms_key_arn| kms_key_arn.split(':').nth(2))
+}
+
+/// Generates [(Vec<u8>, Vec<u8>)] a new 256-bit data key (plaintext and KMS encrypted) by [&str] KMS key identifier
+/// (by KMS GenerateDataKey)
+/// Returns a [String] error if KMS request fails or its response is not valid
+#[cfg(feature = "kms-envelope")]
+async fn generate_data_key(http_client: &reqwest::Client, kms_key_id: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
+    use crate::config::aws_signed_request::send_aws_signed_request;
+    use serde_json::{json, Value};
+
+    let body = json!({"KeyId": kms_key_id, "KeySpec": "AES_256", "EncryptionContext": create_encryption_context()});
+    let response = send_aws_signed_request(
+        http_client,
+        "kms",
+        get_kms_key_region(kms_key_id),
+        "application/x-amz-json-1.1",
+        Some("TrentService.GenerateDataKey"),
+        body.to_string(),
+    )
+    .await?;
+    let response: Value = serde_json::from_str(&response).map_err(|response_error| response_error.to_string())?;
+    let decode_field = |field_name: &str| {
+        response
+            .get(field_name)
+            .and_then(Value::as_str)
+            .and_then(|field_value| STANDARD.decode(field_value).ok())
+            .ok_or_else(|| format!("{field_name} not defined"))
+    };
+    Ok((decode_field("Plaintext")?, decode_field("CiphertextBlob")?))
+}
+
+/// Decrypts [Vec<u8>] data key by [&[u8]] KMS encrypted data key (by KMS Decrypt - KMS key is read from the encrypted
+/// data key)
+/// Returns a [String] error if KMS request fails or its response is not valid
+#[cfg(feature = "kms-envelope")]
+async fn decrypt_data_key(http_client: &reqwest::Client, wrapped_data_key: &[u8]) -> Result<Vec<u8>, String> {
+    use crate::config::aws_signed_request::send_aws_signed_request;
+    use serde_json::{json, Value};
+
+    let body = json!({"CiphertextBlob": STANDARD.encode(wrapped_data_key), "EncryptionContext": create_encryption_context()});
+    let region = ENVELOPE_ENCRYPTION.kms_key_id.as_deref().and_then(get_kms_key_region);
+    let response =
+        send_aws_signed_request(http_client, "kms", region, "application/x-amz-json-1.1", Some("TrentService.Decrypt"), body.to_string()).await?;
+    serde_json::from_str::<Value>(&response)
+        .map_err(|response_error| response_error.to_string())?
+        .get("Plaintext")
+        .and_then(Value::as_str)
+        .and_then(|plaintext| STANDARD.decode(plaintext).ok())
+        .ok_or_else(|| String::from("Plaintext not defined"))
+}
+
+/// Generates [(Vec<u8>, Vec<u8>)] a new data key by [&str] KMS key identifier
+/// Returns a [String] error (envelope encryption requires `kms-envelope` feature)
+#[cfg(not(feature = "kms-envelope"))]
+async fn generate_data_key(_http_client: &reqwest::Client, _kms_key_id: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
+    Err(String::from("envelope encryption is not enabled (kms-envelope feature)"))
+}
+
+/// Decrypts [Vec<u8>] data key by [&[u8]] KMS encrypted data key
+/// Returns a [String] error (envelope encryption requires `kms-envelope` feature)
+#[cfg(not(feature = "kms-envelope"))]
+async fn decrypt_data_key(_http_client: &reqwest::Client, _wrapped_data_key: &[u8]) -> Result<Vec<u8>, String> {
+    Err(String::from("envelope encryption is not enabled (kms-envelope feature)"))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use aes_gcm::aead::{KeyInit, OsRng};
+    use aes_gcm::Aes256Gcm;
+
+    use crate::config::envelope_encryption::{get_wrapped_data_key, open_envelope, seal_envelope};
+    use crate::constant::constants::{ENVELOPE_CONTENT_ALGORITHM, ENVELOPE_CONTENT_ALGORITHM_METADATA, ENVELOPE_IV_METADATA, ENVELOPE_WRAP_ALGORITHM, ENVELOPE_WRAP_ALGORITHM_METADATA};
+    use crate::enums::common_error::CommonError;
+
+    /// Scenario:
+    /// Seals an archive by a random data key and opens the ciphertext by the same data key and envelope metadata, then
+    /// opens a tampered ciphertext
+    /// Expectation:
+    /// Envelope metadata should define the wrapped data key, IV and algorithms, the archive should be retrieved as it was
+    /// and the tampered ciphertext should be rejected
+    #[test]
+    fn when_open_sealed_envelope_should_retrieve_original_content() {
+        let data_key = Aes256Gcm::generate_key(&mut OsRng);
+        let content = b"PK some zip archive content";
+
+        let (ciphertext, envelope_metadata) = seal_envelope(&data_key, b"kms-wrapped-data-key", content).unwrap();
+
+        assert_ne!(content.as_slice(), &ciphertext[..content.len()]);
+        assert_eq!(Some(b"kms-wrapped-data-key".to_vec()), get_wrapped_data_key(&envelope_metadata));
+        assert_eq!(ENVELOPE_CONTENT_ALGORITHM, envelope_metadata[ENVELOPE_CONTENT_ALGORITHM_METADATA]);
+        assert_eq!(ENVELOPE_WRAP_ALGORITHM, envelope_metadata[ENVELOPE_WRAP_ALGORITHM_METADATA]);
+        assert!(envelope_metadata.contains_key(ENVELOPE_IV_METADATA));
+        assert_eq!(content.to_vec(), open_envelope(&data_key, &envelope_metadata, &ciphertext).unwrap());
+
+        let mut tampered_ciphertext = ciphertext.clone();
+        tampered_ciphertext[0] ^= 1;
+        assert_eq!(Err(CommonError::NO_VALID_INPUT_OR_PARAMETER), open_envelope(&data_key, &envelope_metadata, &tampered_ciphertext));
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::{Arc, LazyLock};
+
+use crate::enums::common_error::CommonError;
+use log::error;
+
+/// Error reporter (captures failures with request context, i.e.: Sentry)
+pub trait ErrorReporterTrait {
+    /// Reports [CommonError] error by [&str] request context (i.e.: end-point, bucket name and path)
+    fn report_error(&self, error: &CommonError, request_context: &str);
+
+    /// Reports [&str] panic message
+    fn report_panic(&self, panic_message: &str);
+}
+
+/// Log error reporter implementation struct (default error reporter: failures are just logged)
+pub struct LogErrorReporter {}
+
+/// Log error reporter implementation logic
+impl ErrorReporterTrait for LogErrorReporter {
+    /// Reports [CommonError] error by [&str] request context (i.e.: end-point, bucket name and path)
+    fn report_error(&self, error: &CommonError, request_context: &str) {
+        error!("report_error - error: {error} - request context: {request_context}");
+    }
+
+    /// Reports [&str] panic message
+    fn report_panic(&self, panic_message: &str) {
+        error!("report_panic - panic: {panic_message}");
+    }
+}
+
+/// Sentry error reporter implementation struct (enabled by `sentry` feature and DSN env var)
+/// Important: Sentry client is flushed and closed when the reporter is dropped
+#[cfg(feature = "sentry")]
+pub struct SentryErrorReporter {
+    _sentry_client_guard: sentry::ClientInitGuard,
+}
+
+/// Sentry error reporter implementation logic
+#[cfg(feature = "sentry")]
+impl ErrorReporterTrait for SentryErrorReporter {
+    /// Reports [CommonError] error by [&str] request context (i.e.: end-point, bucket name and path)
+    fn report_error(&self, error: &CommonError, request_context: &str) {
+        LogErrorReporter {}.report_error(error, request_context);
+        sentry::with_scope(
+            |scope| scope.set_extra("request_context", request_context.into()),
+            || sentry::capture_message(&error.to_string(), sentry::Level::Error),
+        );
+    }
+
+    /// Reports [&str] panic message
+    /// Important: panics are already captured (with backtrace) by Sentry panic integration
+    fn report_panic(&self, panic_message: &str) {
+        LogErrorReporter {}.report_panic(panic_message);
+    }
+}
+
+/// Error reporter shared instance
+/// Important: Sentry reporter is used if `sentry` feature is enabled and DSN env var is defined
+/// (please, check constants.rs), otherwise failures are just logged
+pub static ERROR_REPORTER: LazyLock<DynErrorReporter> = LazyLock::new(create_error_reporter);
+
+/// Creates a new [DynErrorReporter] error reporter (please, check [ERROR_REPORTER])
+#[cfg(feature = "sentry")]
+fn create_error_reporter() -> DynErrorReporter {
+    use crate::config::external_secrets::get_secret_env_var;
+    use crate::constant::constants::ERROR_REPORTER_SENTRY_DSN_ENV_VAR;
+    use log::info;
+
+    match get_secret_env_var(ERROR_REPORTER_SENTRY_DSN_ENV_VAR) {
+        Ok(sentry_dsn) if !sentry_dsn.is_empty() => {
+            info!("create_error_reporter - sentry error reporter enabled");
+            let sentry_client_guard = sentry::init((
+                sentry_dsn,
+                sentry::ClientOptions { release: sentry::release_name!(), ..Default::default() },
+            ));
+            Arc::new(SentryErrorReporter { _sentry_client_guard: sentry_client_guard }) as DynErrorReporter
+        }
+        _ => Arc::new(LogErrorReporter {}) as DynErrorReporter,
+    }
+}
+
+/// Creates a new [DynErrorReporter] error reporter (please, check [ERROR_REPORTER])
+#[cfg(not(feature = "sentry"))]
+fn create_error_reporter() -> DynErrorReporter {
+    Arc::new(LogErrorReporter {}) as DynErrorReporter
+}
+
+/// Error reporter trait for shared instance
+pub type DynErrorReporter = Arc<dyn ErrorReporterTrait + Send + Sync>;
@@ -0,0 +1,100 @@
+use std::sync::LazyLock;
+
+use axum::http::header::CONTENT_TYPE;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::external_secrets::get_secret_env_var;
+use crate::constant::constants::{EXPORT_ALERT_DURATION_SECONDS_DEFAULT, EXPORT_ALERT_DURATION_SECONDS_ENV_VAR, EXPORT_ALERT_SIZE_BYTES_DEFAULT, EXPORT_ALERT_SIZE_BYTES_ENV_VAR, EXPORT_ALERT_WEBHOOK_URL_ENV_VAR};
+use crate::dto::export_alert::ExportAlert;
+use crate::enums::export_alert_reason::ExportAlertReason;
+use log::warn;
+
+/// Export alerter struct (slow and large exports warning events with optional webhook notifications)
+/// Important: thresholds equal to zero are not checked
+pub struct ExportAlerter {
+    duration_threshold_millis: u64,
+    size_threshold_bytes: u64,
+    webhook_url: Option<String>,
+    http_client: reqwest::Client,
+}
+
+/// Export alerter shared instance (thresholds and webhook should be configured - please, check constants.rs)
+pub static EXPORT_ALERTER: LazyLock<ExportAlerter> = LazyLock::new(|| ExportAlerter {
+    duration_threshold_millis: get_env_var_as_u64(EXPORT_ALERT_DURATION_SECONDS_ENV_VAR, EXPORT_ALERT_DURATION_SECONDS_DEFAULT)
+        .saturating_mul(1000),
+    size_threshold_bytes: get_env_var_as_u64(EXPORT_ALERT_SIZE_BYTES_ENV_VAR, EXPORT_ALERT_SIZE_BYTES_DEFAULT),
+    webhook_url: get_secret_env_var(EXPORT_ALERT_WEBHOOK_URL_ENV_VAR).ok().filter(|webhook_url| !webhook_url.is_empty()),
+    http_client: reqwest::Client::new(),
+});
+
+impl ExportAlerter {
+    /// Checks a finished export by [&str] S3 bucket, [u64] elapsed milliseconds and [u64] downloaded bytes:
+    /// a warning event is logged (and sent to the webhook, if configured) if any threshold is exceeded
+    /// Important: webhook errors are just logged and webhook is notified in background (it requires a tokio runtime)
+    pub fn check_export(&self, bucket_name: &str, elapsed_millis: u64, bytes_total: u64) {
+        let reasons = get_export_alert_reasons(self.duration_threshold_millis, self.size_threshold_bytes, elapsed_millis, bytes_total);
+        if reasons.is_empty() {
+            return;
+        }
+
+        let export_alert = ExportAlert {
+            bucket_name: String::from(bucket_name),
+            elapsed_millis,
+            bytes_total,
+            reasons,
+        };
+        let export_alert_json = serde_json::to_string(&export_alert).unwrap_or_default();
+        warn!("check_export - export alert: {export_alert_json}");
+
+        if let Some(webhook_url) = self.webhook_url.clone() {
+            let http_client = self.http_client.clone();
+            tokio::spawn(async move {
+                if let Err(webhook_error) = http_client
+                    .post(&webhook_url)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(export_alert_json)
+                    .send()
+                    .await
+                    .and_then(|webhook_response| webhook_response.error_for_status())
+                {
+                    warn!("check_export - export alert webhook error - url: {webhook_url} - error: {webhook_error}");
+                }
+            });
+        }
+    }
+}
+
+/// Gets [Vec<ExportAlertReason>] exceeded thresholds by [u64] duration threshold (in milliseconds), [u64] size threshold
+/// (in bytes), [u64] export elapsed milliseconds and [u64] export downloaded bytes (zero thresholds are not checked)
+fn get_export_alert_reasons(
+    duration_threshold_millis: u64,
+    size_threshold_bytes: u64,
+    elapsed_millis: u64,
+    bytes_total: u64,
+) -> Vec<ExportAlertReason> {
+    let mut reasons = Vec::new();
+    if duration_threshold_millis > 0 && elapsed_millis > duration_threshold_millis {
+        reasons.push(ExportAlertReason::SLOW);
+    }
+    if size_threshold_bytes > 0 && bytes_total > size_threshold_bytes {
+        reasons.push(ExportAlertReason::LARGE);
+    }
+    reasons
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::export_alerter::get_export_alert_reasons;
+    use crate::enums::export_alert_reason::ExportAlertReason;
+
+    /// Scenario:
+    /// Checks slow, large and disabled thresholds for a 10 seconds and 1 KB export
+    /// Expectation:
+    /// Just exceeded and enabled thresholds should be retrieved
+    #[test]
+    fn when_get_export_alert_reasons_should_retrieve_exceeded_thresholds() {
+        assert_eq!(vec![ExportAlertReason::SLOW], get_export_alert_reasons(5000, 2048, 10000, 1024));
+        assert_eq!(vec![ExportAlertReason::SLOW, ExportAlertReason::LARGE], get_export_alert_reasons(5000, 512, 10000, 1024));
+        assert!(get_export_alert_reasons(0, 0, 10000, 1024).is_empty());
+    }
+}
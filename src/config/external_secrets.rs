@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::env::VarError;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+use crate::config::config_dump::get_secret_env_var_names;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{EXTERNAL_SECRETS_REFRESH_SECONDS_DEFAULT, EXTERNAL_SECRETS_REFRESH_SECONDS_ENV_VAR, EXTERNAL_SECRET_SECRETS_MANAGER_PREFIX, EXTERNAL_SECRET_SSM_PREFIX};
+use log::{error, info};
+
+/// External secret reference (secret config value stored out of the environment)
+#[derive(Debug, Eq, PartialEq)]
+enum ExternalSecretReference {
+    /// AWS Secrets Manager secret (secret id or ARN)
+    SecretsManager(String),
+    /// AWS SSM Parameter Store parameter (parameter name or ARN - secure strings are decrypted)
+    Ssm(String),
+}
+
+/// External secrets struct (resolved secret config values by environment variable name)
+pub struct ExternalSecrets {
+    values: RwLock<HashMap<String, String>>,
+    http_client: reqwest::Client,
+}
+
+/// External secrets shared instance (secret references should be resolved at startup - please, check constants.rs)
+pub static EXTERNAL_SECRETS: LazyLock<ExternalSecrets> = LazyLock::new(|| ExternalSecrets {
+    values: RwLock::new(HashMap::new()),
+    http_client: reqwest::Client::new(),
+});
+
+impl ExternalSecrets {
+    /// Resolves secret config values defined as external secret references (previous values are kept on errors)
+    /// Returns [usize] resolved secret config values quantity
+    pub async fn resolve(&self) -> usize {
+        let mut resolved_total = 0;
+        for env_var_name in get_secret_env_var_names() {
+            let Some(external_secret_reference) =
+                std::env::var(env_var_name).ok().and_then(|env_var_value| parse_external_secret_reference(&env_var_value))
+            else {
+                continue;
+            };
+
+            match fetch_external_secret(&self.http_client, &external_secret_reference).await {
+                Ok(secret_value) => {
+                    self.values
+                        .write()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .insert(String::from(env_var_name), secret_value);
+                    resolved_total += 1;
+                }
+                Err(secret_error) => error!("resolve - external secret not resolved - env var: {env_var_name} - error: {secret_error}"),
+            }
+        }
+        resolved_total
+    }
+
+    /// Gets [Option<String>] resolved secret config value by [&str] environment variable name
+    pub fn get_value(&self, env_var_name: &str) -> Option<String> {
+        self.values
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(env_var_name)
+            .cloned()
+    }
+}
+
+/// Gets [String] secret config value by [&str] environment variable name (resolved external secret, if any)
+/// Returns a [VarError] if it is not defined or it is a not resolved external secret reference
+pub fn get_secret_env_var(env_var_name: &str) -> Result<String, VarError> {
+    if let Some(secret_value) = EXTERNAL_SECRETS.get_value(env_var_name) {
+        return Ok(secret_value);
+    }
+    std::env::var(env_var_name).and_then(|env_var_value| match parse_external_secret_reference(&env_var_value) {
+        Some(_) => Err(VarError::NotPresent),
+        None => Ok(env_var_value),
+    })
+}
+
+/// Starts a background task that refreshes external secrets (i.e.: rotated secrets)
+/// Important: refresh interval should be configured (please, check constants.rs) - values read at startup are not refreshed
+pub fn start_external_secrets_refresh_task() {
+    let refresh_interval_seconds = get_env_var_as_u64(EXTERNAL_SECRETS_REFRESH_SECONDS_ENV_VAR, EXTERNAL_SECRETS_REFRESH_SECONDS_DEFAULT);
+    if refresh_interval_seconds == 0 || EXTERNAL_SECRETS.values.read().unwrap_or_else(|poisoned| poisoned.into_inner()).is_empty() {
+        return;
+    }
+    info!("start_external_secrets_refresh_task - refresh interval (seconds): {refresh_interval_seconds}");
+
+    tokio::spawn(async move {
+        let mut refresh_interval = tokio::time::interval(Duration::from_secs(refresh_interval_seconds));
+        refresh_interval.tick().await; // first tick is immediate (secrets are already resolved)
+        loop {
+            refresh_interval.tick().await;
+            let resolved_total = EXTERNAL_SECRETS.resolve().await;
+            info!("external_secrets_refresh_task - refreshed secrets: {resolved_total}");
+        }
+    });
+}
+
+/// Parses [Option<ExternalSecretReference>] external secret reference by [&str] environment variable value
+/// (`secretsmanager:{secret_id}` or `ssm:{parameter_name}` - none if it is a plain value)
+fn parse_external_secret_reference(env_var_value: &str) -> Option<ExternalSecretReference> {
+    let env_var_value = env_var_value.trim();
+    if let Some(secret_id) = env_var_value.strip_prefix(EXTERNAL_SECRET_SECRETS_MANAGER_PREFIX).filter(|secret_id| !secret_id.is_empty()) {
+        return Some(ExternalSecretReference::SecretsManager(String::from(secret_id)));
+    }
+    env_var_value
+        .strip_prefix(EXTERNAL_SECRET_SSM_PREFIX)
+        .filter(|parameter_name| !parameter_name.is_empty())
+        .map(|parameter_name| ExternalSecretReference::Ssm(String::from(parameter_name)))
+}
+
+/// Fetches [String] secret value by [&ExternalSecretReference] external secret reference
+/// Returns a [String] error if secret can't be requested or it has no string value
+#[cfg(feature = "aws-secrets")]
+async fn fetch_external_secret(http_client: &reqwest::Client, external_secret_reference: &ExternalSecretReference) -> Result<String, String> {
+    use crate::config::aws_signed_request::send_aws_signed_request;
+    use serde_json::{json, Value};
+
+    let (service_name, amz_target, body, value_pointer) = match external_secret_reference {
+        ExternalSecretReference::SecretsManager(secret_id) => {
+            ("secretsmanager", "secretsmanager.GetSecretValue", json!({"SecretId": secret_id}), "/SecretString")
+        }
+        ExternalSecretReference::Ssm(parameter_name) => {
+            ("ssm", "AmazonSSM.GetParameter", json!({"Name": parameter_name, "WithDecryption": true}), "/Parameter/Value")
+        }
+    };
+    // secret ARNs define their own region (format: arn:aws:{service}:{region}:{account}:...)
+    let region = match external_secret_reference {
+        ExternalSecretReference::SecretsManager(secret_id) | ExternalSecretReference::Ssm(secret_id) => {
+            secret_id.strip_prefix("arn:").and_then(|secret_arn| secret_arn.split(':').nth(2))
+        }
+    };
+
+    let response = send_aws_signed_request(http_client, service_name, region, "application/x-amz-json-1.1", Some(amz_target), body.to_string()).await?;
+    serde_json::from_str::<Value>(&response)
+        .map_err(|response_error| response_error.to_string())?
+        .pointer(value_pointer)
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| String::from("secret has no string value"))
+}
+
+/// Fetches [String] secret value by [&ExternalSecretReference] external secret reference
+/// Returns a [String] error (external secrets require `aws-secrets` feature)
+#[cfg(not(feature = "aws-secrets"))]
+async fn fetch_external_secret(_http_client: &reqwest::Client, _external_secret_reference: &ExternalSecretReference) -> Result<String, String> {
+    Err(String::from("external secrets are not enabled (aws-secrets feature)"))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::external_secrets::{parse_external_secret_reference, ExternalSecretReference};
+
+    /// Scenario:
+    /// Parses AWS Secrets Manager and SSM Parameter Store references and plain values
+    /// Expectation:
+    /// External secret references should be retrieved just for prefixed non-empty values
+    #[test]
+    fn when_parse_external_secret_reference_should_retrieve_references() {
+        assert_eq!(
+            Some(ExternalSecretReference::SecretsManager(String::from("prod/s3-downloader/redis-url"))),
+            parse_external_secret_reference("secretsmanager:prod/s3-downloader/redis-url")
+        );
+        assert_eq!(
+            Some(ExternalSecretReference::Ssm(String::from("/prod/s3-downloader/signing-secret"))),
+            parse_external_secret_reference("ssm:/prod/s3-downloader/signing-secret")
+        );
+        assert_eq!(None, parse_external_secret_reference("ssm:"));
+        assert_eq!(None, parse_external_secret_reference("redis://some-redis-host:6379"));
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, RwLock};
+
+use crate::constant::constants::FEATURE_FLAGS_ENV_VAR;
+use crate::enums::feature_flag::FeatureFlag;
+use log::{error, info};
+use serde_json::Value;
+
+/// Default enabled flag by feature (experimental features are disabled by default)
+const DEFAULT_FEATURE_FLAGS: &[(FeatureFlag, bool)] = &[
+    (FeatureFlag::JOB_API, true),
+    (FeatureFlag::API_V2, false),
+    (FeatureFlag::BATCH_DOWNLOAD, true),
+    (FeatureFlag::LIST_STREAM, true),
+];
+
+/// Feature flag registry struct (enabled flag by feature - changed by the admin end-point)
+pub struct FeatureFlagRegistry {
+    flags: RwLock<BTreeMap<FeatureFlag, bool>>,
+}
+
+/// Feature flag registry shared instance (feature flags should be configured - please, check constants.rs)
+pub static FEATURE_FLAGS: LazyLock<FeatureFlagRegistry> =
+    LazyLock::new(|| FeatureFlagRegistry::new(&std::env::var(FEATURE_FLAGS_ENV_VAR).unwrap_or_default()));
+
+impl FeatureFlagRegistry {
+    /// Creates a new [FeatureFlagRegistry] by [&str] feature flags configuration
+    /// (comma separated "feature=true|false" values - a feature without value is enabled - i.e.: "api_v2,list_stream=false")
+    /// Important: not valid features or values are skipped (default value is kept)
+    pub fn new(feature_flags: &str) -> Self {
+        let mut flags: BTreeMap<FeatureFlag, bool> = DEFAULT_FEATURE_FLAGS.iter().copied().collect();
+        feature_flags
+            .split(',')
+            .map(str::trim)
+            .filter(|feature_flag| !feature_flag.is_empty())
+            .for_each(|feature_flag| {
+                let (feature, enabled) = feature_flag.split_once('=').unwrap_or((feature_flag, "true"));
+                let feature = serde_json::from_value::<FeatureFlag>(Value::String(feature.trim().to_lowercase()));
+                match (feature, enabled.trim().parse::<bool>()) {
+                    (Ok(feature), Ok(enabled)) => {
+                        flags.insert(feature, enabled);
+                    }
+                    _ => error!("new - feature flag not valid - feature flag: {feature_flag}"),
+                }
+            });
+        FeatureFlagRegistry { flags: RwLock::new(flags) }
+    }
+
+    /// Gets [bool] true if [FeatureFlag] feature is enabled
+    pub fn is_enabled(&self, feature: FeatureFlag) -> bool {
+        self.flags.read().unwrap().get(&feature).copied().unwrap_or_default()
+    }
+
+    /// Gets [BTreeMap<FeatureFlag, bool>] enabled flag by feature
+    pub fn get_flags(&self) -> BTreeMap<FeatureFlag, bool> {
+        self.flags.read().unwrap().clone()
+    }
+
+    /// Sets [BTreeMap<FeatureFlag, bool>] enabled flag by feature (features not defined are kept)
+    pub fn set_flags(&self, flags: BTreeMap<FeatureFlag, bool>) {
+        info!("set_flags - feature flags changed - flags: {flags:?}");
+        self.flags.write().unwrap().extend(flags);
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::feature_flags::FeatureFlagRegistry;
+    use crate::enums::feature_flag::FeatureFlag;
+
+    /// Scenario:
+    /// Creates a feature flag registry with enabled, disabled and not valid feature flags
+    /// Expectation:
+    /// Valid feature flags should override default values and not valid feature flags should be skipped
+    #[test]
+    fn when_new_feature_flag_registry_should_override_default_flags() {
+        let feature_flags = FeatureFlagRegistry::new(" api_v2, list_stream=false,tar_output=true,job_api=maybe");
+
+        assert!(feature_flags.is_enabled(FeatureFlag::API_V2));
+        assert!(!feature_flags.is_enabled(FeatureFlag::LIST_STREAM));
+        assert!(feature_flags.is_enabled(FeatureFlag::JOB_API));
+        assert!(feature_flags.is_enabled(FeatureFlag::BATCH_DOWNLOAD));
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use aws_sdk_s3::config::interceptors::{BeforeSerializationInterceptorContextRef, BeforeTransmitInterceptorContextRef};
+use aws_sdk_s3::config::{ConfigBag, Intercept, RuntimeComponents};
+use aws_sdk_s3::error::BoxError;
+
+/// AWS SDK interceptor that counts executed requests and request attempts (SDK retries included)
+/// Important: it should be added by operation (i.e.: `customize().interceptor(...)`) and shared
+/// by all requests related to the same S3 object
+#[derive(Debug, Clone, Default)]
+pub struct FetchAttemptsInterceptor {
+    executions: Arc<AtomicU32>,
+    attempts: Arc<AtomicU32>,
+}
+
+impl FetchAttemptsInterceptor {
+    /// Gets [u32] retries total (request attempts that were not the first attempt of a request)
+    pub fn retries(&self) -> u32 {
+        self.attempts
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.executions.load(Ordering::Relaxed))
+    }
+}
+
+/// [Intercept] trait implementation
+impl Intercept for FetchAttemptsInterceptor {
+    fn name(&self) -> &'static str {
+        "FetchAttemptsInterceptor"
+    }
+
+    fn read_before_execution(
+        &self,
+        _context: &BeforeSerializationInterceptorContextRef<'_>,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        self.executions.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn read_before_attempt(
+        &self,
+        _context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use axum::Router;
+use axum::serve::Listener;
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto::Builder;
+use hyper_util::server::graceful::GracefulShutdown;
+use hyper_util::service::TowerToHyperService;
+use log::{debug, info};
+use tokio_util::sync::CancellationToken;
+
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_u64};
+use crate::constant::constants::{API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_DEFAULT, API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_ENV_VAR, API_SERVER_HTTP2_ENABLED_DEFAULT, API_SERVER_HTTP2_ENABLED_ENV_VAR, API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT, API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_DEFAULT, API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_ENV_VAR, API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_DEFAULT, API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_ENV_VAR, API_SERVER_TCP_NODELAY_ENABLED_DEFAULT, API_SERVER_TCP_NODELAY_ENABLED_ENV_VAR};
+
+/// HTTP server tuning options struct (HTTP/1 and HTTP/2 connections settings)
+#[derive(Clone, Debug)]
+pub struct HttpServerOptions {
+    /// HTTP/2 connections are accepted (otherwise, just HTTP/1 connections are served)
+    pub http2_enabled: bool,
+    /// HTTP/2 maximum concurrent streams per connection
+    pub http2_max_concurrent_streams: u32,
+    /// HTTP/2 keep-alive pings interval (pings are disabled if it is not defined)
+    pub http2_keep_alive_interval: Option<Duration>,
+    /// HTTP/2 keep-alive pings acknowledgement timeout (connection is closed if it expires)
+    pub http2_keep_alive_timeout: Duration,
+    /// HTTP/1 persistent connections are enabled
+    pub http1_keep_alive: bool,
+    /// TCP_NODELAY flag is set on accepted TCP connections
+    pub tcp_nodelay: bool,
+}
+
+/// default initialization
+impl Default for HttpServerOptions {
+    fn default() -> Self {
+        let http2_keep_alive_interval_seconds =
+            get_env_var_as_u64(API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT);
+        HttpServerOptions {
+            http2_enabled: get_env_var_as_bool(API_SERVER_HTTP2_ENABLED_ENV_VAR, API_SERVER_HTTP2_ENABLED_DEFAULT),
+            http2_max_concurrent_streams: get_env_var_as_u64(API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_ENV_VAR, API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_DEFAULT)
+                .clamp(1, u32::MAX as u64) as u32,
+            http2_keep_alive_interval: Some(Duration::from_secs(http2_keep_alive_interval_seconds))
+                .filter(|_| http2_keep_alive_interval_seconds > 0),
+            http2_keep_alive_timeout: Duration::from_secs(
+                get_env_var_as_u64(API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_ENV_VAR, API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_DEFAULT),
+            ),
+            http1_keep_alive: get_env_var_as_bool(API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_ENV_VAR, API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_DEFAULT),
+            tcp_nodelay: get_env_var_as_bool(API_SERVER_TCP_NODELAY_ENABLED_ENV_VAR, API_SERVER_TCP_NODELAY_ENABLED_DEFAULT),
+        }
+    }
+}
+
+/// Serves [Router] API router on [L] listener (TCP or Unix domain socket) with [HttpServerOptions] tuning options
+/// until [CancellationToken] shutdown token is cancelled (in-flight connections are drained before returning)
+/// Important: TCP_NODELAY flag should be set by the listener (please, check [axum::serve::ListenerExt::tap_io])
+pub async fn serve_api<L: Listener>(
+    mut listener: L,
+    api_router: Router,
+    http_server_options: HttpServerOptions,
+    shutdown_token: CancellationToken,
+) {
+    let connection_builder = create_connection_builder(&http_server_options);
+    let graceful_shutdown = GracefulShutdown::new();
+
+    loop {
+        let (io, _) = tokio::select! {
+            connection = listener.accept() => connection,
+            _ = shutdown_token.cancelled() => break,
+        };
+        let hyper_service = TowerToHyperService::new(api_router.clone());
+        let connection = connection_builder
+            .serve_connection_with_upgrades(TokioIo::new(io), hyper_service)
+            .into_owned();
+        let connection = graceful_shutdown.watch(connection);
+        tokio::spawn(async move {
+            if let Err(connection_error) = connection.await {
+                debug!("serve_api - connection closed with error: {connection_error}");
+            }
+        });
+    }
+
+    info!("serve_api - listener closed - draining in-flight connections");
+    graceful_shutdown.shutdown().await;
+}
+
+/// Creates a new [Builder] HTTP connection builder (HTTP/1 and HTTP/2 - auto-detected) by [&HttpServerOptions] tuning options
+fn create_connection_builder(http_server_options: &HttpServerOptions) -> Builder<TokioExecutor> {
+    let mut connection_builder = Builder::new(TokioExecutor::new());
+    connection_builder
+        .http1()
+        .keep_alive(http_server_options.http1_keep_alive)
+        .timer(TokioTimer::new());
+    connection_builder
+        .http2()
+        .max_concurrent_streams(http_server_options.http2_max_concurrent_streams)
+        .keep_alive_interval(http_server_options.http2_keep_alive_interval)
+        .keep_alive_timeout(http_server_options.http2_keep_alive_timeout)
+        .timer(TokioTimer::new());
+
+    match http_server_options.http2_enabled {
+        true => connection_builder,
+        false => connection_builder.http1_only(),
+    }
+}
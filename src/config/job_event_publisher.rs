@@ -0,0 +1,122 @@
+use std::sync::{Arc, LazyLock};
+
+use async_trait::async_trait;
+use crate::dto::job_event::JobEvent;
+use log::info;
+
+/// Job event publisher (publishes finished job events for downstream automation, i.e.: Amazon SNS or EventBridge)
+#[async_trait]
+pub trait JobEventPublisherTrait {
+    /// Publishes [&JobEvent] job event
+    /// Important: publishing errors are just logged (job status is not affected)
+    async fn publish_job_event(&self, job_event: &JobEvent);
+}
+
+/// Log job event publisher implementation struct (default job event publisher: events are just logged)
+pub struct LogJobEventPublisher {}
+
+/// Log job event publisher implementation logic
+#[async_trait]
+impl JobEventPublisherTrait for LogJobEventPublisher {
+    /// Publishes [&JobEvent] job event
+    /// Important: publishing errors are just logged (job status is not affected)
+    async fn publish_job_event(&self, job_event: &JobEvent) {
+        info!("publish_job_event - job event: {}", serde_json::to_string(job_event).unwrap_or_default());
+    }
+}
+
+/// AWS job event publisher target (Amazon SNS topic or EventBridge event bus)
+#[cfg(feature = "aws-events")]
+enum AwsJobEventTarget {
+    SnsTopic(String),
+    EventBus(String),
+}
+
+/// AWS job event publisher implementation struct (enabled by `aws-events` feature and SNS topic or EventBridge bus env vars)
+/// Important: requests are signed (AWS SigV4) with the default AWS SDK credentials and region
+#[cfg(feature = "aws-events")]
+pub struct AwsJobEventPublisher {
+    target: AwsJobEventTarget,
+    http_client: reqwest::Client,
+}
+
+/// AWS job event publisher implementation logic
+#[cfg(feature = "aws-events")]
+#[async_trait]
+impl JobEventPublisherTrait for AwsJobEventPublisher {
+    /// Publishes [&JobEvent] job event
+    /// Important: publishing errors are just logged (job status is not affected)
+    async fn publish_job_event(&self, job_event: &JobEvent) {
+        use crate::config::aws_signed_request::send_aws_signed_request;
+        use crate::constant::constants::JOB_EVENTS_SOURCE;
+        use log::error;
+
+        let job_event_json = serde_json::to_string(job_event).unwrap_or_default();
+        let (service_name, content_type, amz_target, body) = match &self.target {
+            AwsJobEventTarget::SnsTopic(topic_arn) => (
+                "sns",
+                "application/x-www-form-urlencoded",
+                None,
+                form_urlencoded::Serializer::new(String::new())
+                    .append_pair("Action", "Publish")
+                    .append_pair("Version", "2010-03-31")
+                    .append_pair("TopicArn", topic_arn)
+                    .append_pair("Message", &job_event_json)
+                    .finish(),
+            ),
+            AwsJobEventTarget::EventBus(event_bus_name) => (
+                "events",
+                "application/x-amz-json-1.1",
+                Some("AWSEvents.PutEvents"),
+                serde_json::json!({"Entries": [{
+                    "Source": JOB_EVENTS_SOURCE,
+                    "DetailType": "Download Job Finished",
+                    "Detail": job_event_json,
+                    "EventBusName": event_bus_name,
+                }]})
+                .to_string(),
+            ),
+        };
+
+        let region = match &self.target {
+            // SNS topic ARN format: arn:aws:sns:{region}:{account}:{topic}
+            AwsJobEventTarget::SnsTopic(topic_arn) => topic_arn.split(':').nth(3),
+            AwsJobEventTarget::EventBus(_) => None,
+        };
+        match send_aws_signed_request(&self.http_client, service_name, region, content_type, amz_target, body).await {
+            Ok(_) => info!("publish_job_event - done - job id: {}", job_event.job_id),
+            Err(publish_error) => {
+                error!("publish_job_event - job event not published - job id: {} - error: {publish_error}", job_event.job_id)
+            }
+        }
+    }
+}
+
+/// Job event publisher shared instance
+/// Important: AWS publisher is used if `aws-events` feature is enabled and SNS topic ARN or EventBridge bus name
+/// env var is defined (please, check constants.rs), otherwise events are just logged
+pub static JOB_EVENT_PUBLISHER: LazyLock<DynJobEventPublisher> = LazyLock::new(create_job_event_publisher);
+
+/// Creates a new [DynJobEventPublisher] job event publisher (please, check [JOB_EVENT_PUBLISHER])
+#[cfg(feature = "aws-events")]
+fn create_job_event_publisher() -> DynJobEventPublisher {
+    use crate::constant::constants::{JOB_EVENTS_EVENT_BUS_NAME_ENV_VAR, JOB_EVENTS_SNS_TOPIC_ARN_ENV_VAR};
+
+    let target = match (std::env::var(JOB_EVENTS_SNS_TOPIC_ARN_ENV_VAR), std::env::var(JOB_EVENTS_EVENT_BUS_NAME_ENV_VAR)) {
+        (Ok(topic_arn), _) if !topic_arn.is_empty() => AwsJobEventTarget::SnsTopic(topic_arn),
+        (_, Ok(event_bus_name)) if !event_bus_name.is_empty() => AwsJobEventTarget::EventBus(event_bus_name),
+        _ => return Arc::new(LogJobEventPublisher {}) as DynJobEventPublisher,
+    };
+
+    info!("create_job_event_publisher - AWS job event publisher enabled");
+    Arc::new(AwsJobEventPublisher { target, http_client: reqwest::Client::new() }) as DynJobEventPublisher
+}
+
+/// Creates a new [DynJobEventPublisher] job event publisher (please, check [JOB_EVENT_PUBLISHER])
+#[cfg(not(feature = "aws-events"))]
+fn create_job_event_publisher() -> DynJobEventPublisher {
+    Arc::new(LogJobEventPublisher {}) as DynJobEventPublisher
+}
+
+/// Job event publisher trait for shared instance
+pub type DynJobEventPublisher = Arc<dyn JobEventPublisherTrait + Send + Sync>;
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::shared_state_store::SHARED_STATE_STORE;
+use crate::constant::constants::{DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_DEFAULT, DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_ENV_VAR};
+use crate::service::download_job_service::DynDownloadJobService;
+use log::{debug, info};
+
+/// Starts a background task that takes over stalled download jobs from other API replicas
+/// by [DynDownloadJobService] download job service
+/// Important: task is started just if shared state store is enabled (takeover interval should be configured -
+/// please, check constants.rs)
+pub fn start_job_takeover_task(download_job_service: DynDownloadJobService) {
+    if !SHARED_STATE_STORE.is_enabled() {
+        debug!("start_job_takeover_task - shared state store not enabled - task not started");
+        return;
+    }
+
+    let takeover_interval_seconds = get_env_var_as_u64(
+        DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_ENV_VAR,
+        DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_DEFAULT,
+    )
+    .max(1);
+    info!("start_job_takeover_task - takeover interval (seconds): {takeover_interval_seconds}");
+
+    tokio::spawn(async move {
+        let mut takeover_interval = tokio::time::interval(Duration::from_secs(takeover_interval_seconds));
+        loop {
+            takeover_interval.tick().await;
+            let taken_over_jobs_total = download_job_service.take_over_stalled_jobs().await;
+            if taken_over_jobs_total > 0 {
+                info!("job_takeover_task - taken over jobs: {taken_over_jobs_total}");
+            }
+        }
+    });
+}
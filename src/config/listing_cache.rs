@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::shared_state_store::SHARED_STATE_STORE;
+use crate::constant::constants::{LISTING_CACHE_TTL_SECONDS_DEFAULT, LISTING_CACHE_TTL_SECONDS_ENV_VAR, SHARED_STATE_LISTING_KEY_PREFIX};
+
+/// Listing cache struct (S3 key lists by S3 bucket name and S3 path, expired after the configured TTL)
+/// Important: S3 key lists are shared with other API replicas if shared state store is enabled
+pub struct ListingCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), ListingCacheEntry>>,
+}
+
+/// Listing cache entry (S3 key list + caching instant)
+struct ListingCacheEntry {
+    cached_at: Instant,
+    s3_key_list: Vec<String>,
+}
+
+/// Listing cache shared instance (TTL should be configured - please, check constants.rs)
+pub static LISTING_CACHE: LazyLock<ListingCache> = LazyLock::new(|| {
+    ListingCache::new(Duration::from_secs(get_env_var_as_u64(
+        LISTING_CACHE_TTL_SECONDS_ENV_VAR,
+        LISTING_CACHE_TTL_SECONDS_DEFAULT,
+    )))
+});
+
+impl ListingCache {
+    /// Creates a new [ListingCache] by [Duration] TTL (zero TTL disables the cache)
+    pub fn new(ttl: Duration) -> Self {
+        ListingCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gets [Option<Vec<String>>] cached S3 key list by [&str] S3 bucket name and [&str] S3 path
+    /// Returns [None] if S3 key list is not cached or it's expired
+    pub async fn get(&self, bucket_name: &str, path: &str) -> Option<Vec<String>> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+
+        let local_s3_key_list = {
+            let mut entries = self.entries.lock().unwrap();
+            let cache_key = (String::from(bucket_name), String::from(path));
+            match entries.get(&cache_key) {
+                Some(entry) if entry.cached_at.elapsed() < self.ttl => Some(entry.s3_key_list.clone()),
+                Some(_) => {
+                    entries.remove(&cache_key);
+                    None
+                }
+                None => None,
+            }
+        };
+        match local_s3_key_list {
+            Some(s3_key_list) => Some(s3_key_list),
+            None => SHARED_STATE_STORE
+                .get_value(&create_shared_key(bucket_name, path))
+                .await
+                .and_then(|s3_key_list| serde_json::from_str(&s3_key_list).ok()),
+        }
+    }
+
+    /// Caches [&[String]] S3 key list by [&str] S3 bucket name and [&str] S3 path (ignored if the cache is disabled)
+    pub async fn put(&self, bucket_name: &str, path: &str, s3_key_list: &[String]) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.retain(|_, entry| entry.cached_at.elapsed() < self.ttl);
+            entries.insert(
+                (String::from(bucket_name), String::from(path)),
+                ListingCacheEntry {
+                    cached_at: Instant::now(),
+                    s3_key_list: s3_key_list.to_vec(),
+                },
+            );
+        }
+        if SHARED_STATE_STORE.is_enabled()
+            && let Ok(s3_key_list) = serde_json::to_string(s3_key_list)
+        {
+            SHARED_STATE_STORE
+                .set_value(&create_shared_key(bucket_name, path), &s3_key_list, self.ttl.as_secs())
+                .await;
+        }
+    }
+
+    /// Invalidates cached S3 key lists by [&str] S3 bucket name and [Option<&str>] S3 path prefix
+    /// (all S3 paths of the S3 bucket are invalidated if S3 path prefix is not defined)
+    /// Returns [usize] invalidated entries quantity (shared entries if shared state store is enabled)
+    pub async fn invalidate(&self, bucket_name: &str, path_prefix: Option<&str>) -> usize {
+        let mut invalidated_entries = {
+            let mut entries = self.entries.lock().unwrap();
+            let entries_before = entries.len();
+            entries.retain(|(cached_bucket_name, cached_path), _| {
+                cached_bucket_name != bucket_name
+                    || path_prefix.is_some_and(|path_prefix| !cached_path.starts_with(path_prefix))
+            });
+            entries_before - entries.len()
+        };
+        if SHARED_STATE_STORE.is_enabled() {
+            invalidated_entries = SHARED_STATE_STORE
+                .remove_values(&create_shared_key(bucket_name, path_prefix.unwrap_or_default()))
+                .await;
+        }
+
+        debug!("invalidate - bucket name: {bucket_name} - invalidated entries: {invalidated_entries}");
+        invalidated_entries
+    }
+}
+
+/// Creates a new [String] shared state store key by [&str] S3 bucket name and [&str] S3 path
+fn create_shared_key(bucket_name: &str, path: &str) -> String {
+    format!("{SHARED_STATE_LISTING_KEY_PREFIX}{bucket_name}/{path}")
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::config::listing_cache::ListingCache;
+
+    /// Scenario:
+    /// Caches two S3 key lists (same S3 bucket) and invalidates just one S3 path prefix
+    /// Expectation:
+    /// Just the S3 key list under the invalidated S3 path prefix should be removed
+    #[tokio::test]
+    async fn when_invalidate_by_path_prefix_should_remove_just_matching_entries() {
+        let listing_cache = ListingCache::new(Duration::from_secs(60));
+        listing_cache.put("some-bucket", "some/path", &[String::from("a.txt")]).await;
+        listing_cache.put("some-bucket", "other/path", &[String::from("b.txt")]).await;
+
+        assert_eq!(1, listing_cache.invalidate("some-bucket", Some("some/")).await);
+        assert_eq!(None, listing_cache.get("some-bucket", "some/path").await);
+        assert_eq!(Some(vec![String::from("b.txt")]), listing_cache.get("some-bucket", "other/path").await);
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::{LazyLock, Mutex};
+
+use crate::constant::constants::MAINTENANCE_MODE_ENV_VAR;
+use crate::enums::maintenance_mode::MaintenanceMode;
+use log::{error, info};
+use serde_json::Value;
+
+/// Maintenance mode toggle struct (current API maintenance mode - changed by the admin end-point)
+pub struct MaintenanceModeToggle {
+    mode: Mutex<MaintenanceMode>,
+}
+
+/// Maintenance mode toggle shared instance (startup mode should be configured - please, check constants.rs)
+pub static MAINTENANCE_MODE: LazyLock<MaintenanceModeToggle> = LazyLock::new(|| {
+    MaintenanceModeToggle::new(std::env::var(MAINTENANCE_MODE_ENV_VAR).map(|mode| read_maintenance_mode(&mode)).unwrap_or_default())
+});
+
+impl MaintenanceModeToggle {
+    /// Creates a new [MaintenanceModeToggle] by [MaintenanceMode] startup mode
+    pub fn new(mode: MaintenanceMode) -> Self {
+        MaintenanceModeToggle { mode: Mutex::new(mode) }
+    }
+
+    /// Gets [MaintenanceMode] current maintenance mode
+    pub fn get_mode(&self) -> MaintenanceMode {
+        *self.mode.lock().unwrap()
+    }
+
+    /// Sets [MaintenanceMode] current maintenance mode
+    pub fn set_mode(&self, mode: MaintenanceMode) {
+        info!("set_mode - maintenance mode changed - mode: {mode:?}");
+        *self.mode.lock().unwrap() = mode;
+    }
+}
+
+/// Gets [MaintenanceMode] maintenance mode by [&str] mode name (i.e.: "read_only")
+/// Important: maintenance mode is off if mode name is not valid
+fn read_maintenance_mode(mode: &str) -> MaintenanceMode {
+    serde_json::from_value(Value::String(mode.trim().to_lowercase())).unwrap_or_else(|mode_error| {
+        error!("read_maintenance_mode - maintenance mode not valid - mode: {mode} - error: {mode_error}");
+        MaintenanceMode::OFF
+    })
+}
@@ -0,0 +1,90 @@
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{EXPORT_MEMORY_BUDGET_BYTES_DEFAULT, EXPORT_MEMORY_BUDGET_BYTES_ENV_VAR};
+
+/// Memory budget struct (bytes buffered across all in-flight exports)
+pub struct MemoryBudget {
+    budget_bytes: u64,
+    buffered_bytes: AtomicU64,
+}
+
+/// Memory budget shared instance (budget should be configured - please, check constants.rs)
+pub static MEMORY_BUDGET: LazyLock<MemoryBudget> = LazyLock::new(|| MemoryBudget {
+    budget_bytes: get_env_var_as_u64(EXPORT_MEMORY_BUDGET_BYTES_ENV_VAR, EXPORT_MEMORY_BUDGET_BYTES_DEFAULT),
+    buffered_bytes: AtomicU64::new(0),
+});
+
+impl MemoryBudget {
+    /// Gets [bool] true if buffered bytes reached the configured budget
+    pub fn is_exceeded(&self) -> bool {
+        self.buffered_bytes.load(Ordering::Relaxed) >= self.budget_bytes
+    }
+
+    /// Gets [u64] bytes currently buffered across all in-flight exports
+    pub fn buffered_bytes(&self) -> u64 {
+        self.buffered_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Reserves [u64] bytes from the memory budget
+    /// Returns a [MemoryReservation] that releases the reserved bytes when it is dropped
+    pub fn reserve(&'static self, bytes: u64) -> MemoryReservation {
+        self.buffered_bytes.fetch_add(bytes, Ordering::Relaxed);
+        MemoryReservation { memory_budget: self, bytes }
+    }
+}
+
+/// Memory reservation struct (reserved bytes are released when dropped)
+pub struct MemoryReservation {
+    memory_budget: &'static MemoryBudget,
+    bytes: u64,
+}
+
+impl MemoryReservation {
+    /// Resizes the reservation to [u64] bytes (i.e.: from the expected S3 object size to its actual content size)
+    pub fn resize(&mut self, bytes: u64) {
+        if bytes > self.bytes {
+            self.memory_budget.buffered_bytes.fetch_add(bytes - self.bytes, Ordering::Relaxed);
+        } else {
+            self.memory_budget.buffered_bytes.fetch_sub(self.bytes - bytes, Ordering::Relaxed);
+        }
+        self.bytes = bytes;
+    }
+}
+
+/// [Drop] trait implementation
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.memory_budget.buffered_bytes.fetch_sub(self.bytes, Ordering::Relaxed);
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU64;
+
+    use crate::config::memory_budget::MemoryBudget;
+
+    /// Scenario:
+    /// Reserves the expected size of a S3 object, resizes it to its actual content size and drops the reservation
+    /// Expectation:
+    /// Buffered bytes should follow the reservation size and the budget should be released once it is dropped
+    #[test]
+    fn when_resize_reservation_should_update_buffered_bytes() {
+        let memory_budget: &'static MemoryBudget = Box::leak(Box::new(MemoryBudget { budget_bytes: 100, buffered_bytes: AtomicU64::new(0) }));
+
+        let mut memory_reservation = memory_budget.reserve(0);
+        memory_reservation.resize(120);
+        assert_eq!(120, memory_budget.buffered_bytes());
+        assert!(memory_budget.is_exceeded());
+
+        memory_reservation.resize(40);
+        assert_eq!(40, memory_budget.buffered_bytes());
+        assert!(!memory_budget.is_exceeded());
+
+        drop(memory_reservation);
+        assert_eq!(0, memory_budget.buffered_bytes());
+    }
+}
@@ -1,2 +1,45 @@
+pub mod api_listeners;
+pub mod api_router;
+pub mod app_state;
+pub mod archive_signer;
+pub mod assume_role;
+pub mod authorization_hook;
 pub mod aws_sdk_s3_client;
+#[cfg(any(feature = "aws-events", feature = "aws-secrets", feature = "kms-envelope"))]
+pub mod aws_signed_request;
+pub mod base_path;
+pub mod bucket_regions;
+pub mod caller_credentials;
+pub mod client_pool;
+pub mod clock;
+pub mod concurrency_classes;
+pub mod config_dump;
+pub mod credentials_cache;
+pub mod env_vars;
+pub mod download_quota;
+pub mod email_notifier;
+pub mod envelope_encryption;
+pub mod error_reporter;
+pub mod export_alerter;
+pub mod external_secrets;
+pub mod feature_flags;
+pub mod fetch_attempts_interceptor;
 pub mod graceful_shutdown;
+pub mod http_server;
+pub mod job_event_publisher;
+pub mod job_takeover;
+pub mod listing_cache;
+pub mod maintenance_mode;
+pub mod memory_budget;
+pub mod object_body_cache;
+pub mod rbac_policy;
+pub mod replay_cache;
+pub mod request_validation;
+pub mod retention_cleanup;
+pub mod self_check;
+pub mod shared_state_store;
+pub mod startup_warmup;
+pub mod temp_storage;
+pub mod template_variables;
+pub mod usage_stats;
+pub mod watch_task;
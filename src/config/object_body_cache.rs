@@ -0,0 +1,104 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{OBJECT_BODY_CACHE_MAX_BYTES_DEFAULT, OBJECT_BODY_CACHE_MAX_BYTES_ENV_VAR};
+
+/// Object body cache struct (S3 object contents by S3 bucket name and S3 object key, validated by stored ETags)
+/// Important: oldest cached S3 objects are evicted once the configured maximum bytes is reached
+pub struct ObjectBodyCache {
+    max_bytes: u64,
+    entries: Mutex<ObjectBodyCacheEntries>,
+}
+
+/// Object body cache entries (cached S3 objects + insertion order + cached bytes)
+#[derive(Default)]
+struct ObjectBodyCacheEntries {
+    bodies: HashMap<(String, String), (String, Vec<u8>)>,
+    insertion_order: VecDeque<(String, String)>,
+    cached_bytes: u64,
+}
+
+/// Object body cache shared instance (maximum bytes should be configured - please, check constants.rs)
+pub static OBJECT_BODY_CACHE: LazyLock<ObjectBodyCache> = LazyLock::new(|| {
+    ObjectBodyCache::new(get_env_var_as_u64(OBJECT_BODY_CACHE_MAX_BYTES_ENV_VAR, OBJECT_BODY_CACHE_MAX_BYTES_DEFAULT))
+});
+
+impl ObjectBodyCache {
+    /// Creates a new [ObjectBodyCache] by [u64] maximum cached bytes (zero disables the cache)
+    pub fn new(max_bytes: u64) -> Self {
+        ObjectBodyCache {
+            max_bytes,
+            entries: Mutex::new(ObjectBodyCacheEntries::default()),
+        }
+    }
+
+    /// Gets [Option<String>] stored ETag by [&str] S3 bucket name and [&str] S3 object key
+    pub fn get_e_tag(&self, bucket_name: &str, s3_object_key: &str) -> Option<String> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .bodies
+            .get(&(String::from(bucket_name), String::from(s3_object_key)))
+            .map(|(e_tag, _)| e_tag.clone())
+    }
+
+    /// Gets [Option<Vec<u8>>] cached S3 object content by [&str] S3 bucket name, [&str] S3 object key and [&str] ETag
+    /// Returns [None] if S3 object is not cached or cached ETag doesn't match
+    pub fn get_body(&self, bucket_name: &str, s3_object_key: &str, e_tag: &str) -> Option<Vec<u8>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .bodies
+            .get(&(String::from(bucket_name), String::from(s3_object_key)))
+            .filter(|(cached_e_tag, _)| cached_e_tag == e_tag)
+            .map(|(_, body)| body.clone())
+    }
+
+    /// Caches [&[u8]] S3 object content by [&str] S3 bucket name, [&str] S3 object key and [&str] ETag
+    /// (ignored if the cache is disabled or S3 object content is greater than maximum cached bytes)
+    pub fn put(&self, bucket_name: &str, s3_object_key: &str, e_tag: &str, body: &[u8]) {
+        if body.len() as u64 > self.max_bytes {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let cache_key = (String::from(bucket_name), String::from(s3_object_key));
+        if let Some((_, previous_body)) = entries.bodies.remove(&cache_key) {
+            entries.cached_bytes -= previous_body.len() as u64;
+            entries.insertion_order.retain(|cached_key| cached_key != &cache_key);
+        }
+        while entries.cached_bytes + body.len() as u64 > self.max_bytes {
+            let Some(oldest_key) = entries.insertion_order.pop_front() else {
+                break;
+            };
+            if let Some((_, oldest_body)) = entries.bodies.remove(&oldest_key) {
+                entries.cached_bytes -= oldest_body.len() as u64;
+            }
+        }
+
+        entries.cached_bytes += body.len() as u64;
+        entries.insertion_order.push_back(cache_key.clone());
+        entries.bodies.insert(cache_key, (String::from(e_tag), body.to_vec()));
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::object_body_cache::ObjectBodyCache;
+
+    /// Scenario:
+    /// Caches three S3 objects (4 bytes each) into a 10 bytes cache
+    /// Expectation:
+    /// Oldest S3 object should be evicted and cached contents should be retrieved just for matching ETags
+    #[test]
+    fn when_put_over_max_bytes_should_evict_oldest_body() {
+        let object_body_cache = ObjectBodyCache::new(10);
+        object_body_cache.put("some-bucket", "path/a.txt", "\"a\"", b"aaaa");
+        object_body_cache.put("some-bucket", "path/b.txt", "\"b\"", b"bbbb");
+        object_body_cache.put("some-bucket", "path/c.txt", "\"c\"", b"cccc");
+
+        assert_eq!(None, object_body_cache.get_e_tag("some-bucket", "path/a.txt"));
+        assert_eq!(Some(b"bbbb".to_vec()), object_body_cache.get_body("some-bucket", "path/b.txt", "\"b\""));
+        assert_eq!(None, object_body_cache.get_body("some-bucket", "path/c.txt", "\"other\""));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::constant::constants::RBAC_CONFIG_FILE_ENV_VAR;
+use crate::dto::rbac_role_rule::RbacRoleRule;
+use crate::enums::rbac_operation::RbacOperation;
+use log::{error, info};
+
+/// Role-based access control policy struct (role name + role rules)
+pub struct RbacPolicy {
+    roles: Option<HashMap<String, Vec<RbacRoleRule>>>,
+}
+
+/// Role-based access control policy shared instance (roles file should be configured - please, check constants.rs)
+/// Important: access control is disabled if roles file is not configured
+pub static RBAC_POLICY: LazyLock<RbacPolicy> = LazyLock::new(|| match std::env::var(RBAC_CONFIG_FILE_ENV_VAR) {
+    Ok(rbac_config_file) => RbacPolicy::new(Some(read_rbac_roles(&rbac_config_file))),
+    Err(_) => {
+        info!("RBAC_POLICY - roles file not configured - access control disabled");
+        RbacPolicy::new(None)
+    }
+});
+
+impl RbacPolicy {
+    /// Creates a new [RbacPolicy] by [Option<HashMap<String, Vec<RbacRoleRule>>>] role rules by role name
+    /// (access control is disabled if roles are not defined)
+    pub fn new(roles: Option<HashMap<String, Vec<RbacRoleRule>>>) -> Self {
+        RbacPolicy { roles }
+    }
+
+    /// Gets [bool] true if access control is enabled (roles are defined)
+    pub fn is_enabled(&self) -> bool {
+        self.roles.is_some()
+    }
+
+    /// Gets [bool] true if [Option<&str>] role name is allowed to execute [RbacOperation] operation
+    /// over [&str] S3 bucket and [&str] S3 path (always true if access control is disabled)
+    pub fn is_allowed(&self, role_name: Option<&str>, operation: RbacOperation, bucket_name: &str, full_path: &str) -> bool {
+        let Some(roles) = &self.roles else {
+            return true;
+        };
+
+        role_name
+            .and_then(|role_name| roles.get(role_name))
+            .is_some_and(|role_rules| {
+                role_rules.iter().any(|role_rule| {
+                    role_rule.operations.contains(&operation)
+                        && (role_rule.bucket_name == "*" || role_rule.bucket_name == bucket_name)
+                        && role_rule
+                            .prefix_patterns
+                            .iter()
+                            .any(|prefix_pattern| is_prefix_pattern_match(prefix_pattern, full_path))
+                })
+            })
+    }
+}
+
+/// Gets [bool] true if [&str] S3 path matches [&str] prefix pattern
+/// (a trailing "*" matches any S3 path under the pattern prefix - otherwise, S3 path should be the same)
+fn is_prefix_pattern_match(prefix_pattern: &str, full_path: &str) -> bool {
+    let full_path = full_path.trim_matches('/');
+    match prefix_pattern.strip_suffix('*') {
+        Some(pattern_prefix) => full_path.starts_with(pattern_prefix.trim_start_matches('/')),
+        None => full_path == prefix_pattern.trim_matches('/'),
+    }
+}
+
+/// Reads [HashMap<String, Vec<RbacRoleRule>>] role rules by role name from [&str] JSON roles file
+/// Important: if roles file is not valid, no role is defined (every request is denied)
+fn read_rbac_roles(rbac_config_file: &str) -> HashMap<String, Vec<RbacRoleRule>> {
+    match std::fs::read_to_string(rbac_config_file)
+        .map_err(|read_error| read_error.to_string())
+        .and_then(|rbac_config| serde_json::from_str(&rbac_config).map_err(|parse_error| parse_error.to_string()))
+    {
+        Ok(roles) => roles,
+        Err(rbac_config_error) => {
+            error!("read_rbac_roles - roles file not valid - all requests are denied - error: {rbac_config_error}");
+            HashMap::new()
+        }
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::config::rbac_policy::RbacPolicy;
+    use crate::dto::rbac_role_rule::RbacRoleRule;
+    use crate::enums::rbac_operation::RbacOperation;
+
+    /// Scenario:
+    /// Checks a role allowed to list and download under a single S3 bucket prefix
+    /// Expectation:
+    /// Just the allowed operations under the allowed S3 bucket prefix should be allowed (unknown roles are denied)
+    #[test]
+    fn when_is_allowed_should_check_role_rules_by_bucket_prefix_and_operation() {
+        let rbac_policy = RbacPolicy::new(Some(HashMap::from([(
+            String::from("reports-reader"),
+            vec![RbacRoleRule {
+                bucket_name: String::from("bucket-a"),
+                prefix_patterns: vec![String::from("reports/*")],
+                operations: vec![RbacOperation::LIST, RbacOperation::DOWNLOAD],
+            }],
+        )])));
+
+        assert!(rbac_policy.is_allowed(Some("reports-reader"), RbacOperation::DOWNLOAD, "bucket-a", "reports/2025"));
+        assert!(!rbac_policy.is_allowed(Some("reports-reader"), RbacOperation::DELETE, "bucket-a", "reports/2025"));
+        assert!(!rbac_policy.is_allowed(Some("reports-reader"), RbacOperation::LIST, "bucket-a", "invoices"));
+        assert!(!rbac_policy.is_allowed(Some("reports-reader"), RbacOperation::LIST, "bucket-b", "reports/2025"));
+        assert!(!rbac_policy.is_allowed(Some("unknown"), RbacOperation::LIST, "bucket-a", "reports/2025"));
+        assert!(!rbac_policy.is_allowed(None, RbacOperation::LIST, "bucket-a", "reports/2025"));
+        assert!(RbacPolicy::new(None).is_allowed(None, RbacOperation::DELETE, "bucket-b", "any"));
+    }
+}
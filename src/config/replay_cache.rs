@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::config::clock::current_epoch_seconds;
+use crate::config::shared_state_store::SHARED_STATE_STORE;
+use crate::constant::constants::SHARED_STATE_NONCE_KEY_PREFIX;
+use uuid::Uuid;
+
+/// Replay cache struct (used nonces of signed requests by nonce key, until their expiration epoch seconds)
+/// Important: nonces are also registered in the shared state store (if it is enabled), so replays are rejected by all replicas
+pub struct ReplayCache {
+    nonces: Mutex<HashMap<String, u64>>,
+}
+
+/// Replay cache shared instance
+pub static REPLAY_CACHE: LazyLock<ReplayCache> = LazyLock::new(ReplayCache::default);
+
+/// default initialization
+impl Default for ReplayCache {
+    fn default() -> Self {
+        ReplayCache { nonces: Mutex::new(HashMap::new()) }
+    }
+}
+
+impl ReplayCache {
+    /// Registers [&str] nonce of [&str] nonce scope (i.e.: signed links) until [u64] expiration epoch seconds
+    /// Returns [bool] false if nonce is already registered (replayed request), otherwise true
+    pub async fn register_nonce(&self, nonce_scope: &str, nonce: &str, expires_at: u64) -> bool {
+        let nonce_key = format!("{nonce_scope}:{nonce}");
+        let now = current_epoch_seconds();
+        {
+            let mut nonces = self.nonces.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            nonces.retain(|_, nonce_expires_at| *nonce_expires_at > now);
+            if nonces.contains_key(&nonce_key) {
+                return false;
+            }
+            nonces.insert(nonce_key.clone(), expires_at);
+        }
+
+        // a unique owner per registration: the lease is just acquired by the first registration of the nonce
+        !SHARED_STATE_STORE.is_enabled()
+            || SHARED_STATE_STORE
+                .acquire_lease(
+                    &format!("{SHARED_STATE_NONCE_KEY_PREFIX}{nonce_key}"),
+                    &Uuid::new_v4().to_string(),
+                    expires_at.saturating_sub(now).max(1),
+                )
+                .await
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::clock::current_epoch_seconds;
+    use crate::config::replay_cache::ReplayCache;
+
+    /// Scenario:
+    /// Registers a nonce twice, the same nonce of another scope and a nonce already expired
+    /// Expectation:
+    /// Just the replayed nonce (same scope, not expired) should be rejected
+    #[tokio::test]
+    async fn when_register_nonce_should_reject_replayed_nonces() {
+        let replay_cache = ReplayCache::default();
+        let expires_at = current_epoch_seconds() + 60;
+
+        assert!(replay_cache.register_nonce("signed-link", "abc", expires_at).await);
+        assert!(!replay_cache.register_nonce("signed-link", "abc", expires_at).await);
+        assert!(replay_cache.register_nonce("s3-events", "abc", expires_at).await);
+        assert!(replay_cache.register_nonce("signed-link", "expired", current_epoch_seconds() - 1).await);
+        assert!(replay_cache.register_nonce("signed-link", "expired", expires_at).await);
+    }
+}
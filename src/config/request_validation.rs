@@ -0,0 +1,595 @@
+use std::ops::Deref;
+
+use axum::Json;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRequest, FromRequestParts, OptionalFromRequest, Query, Request};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use crate::config::clock::current_epoch_seconds;
+use crate::config::template_variables::{get_template_variable_values, resolve_template_request};
+use crate::constant::constants::{EXPORT_FILTER_MAX_CONDITIONS, EXPORT_FILTER_MAX_DEPTH, EXPORT_TEMPLATE_BUILTIN_VARIABLES, EXPORT_TEMPLATE_MAX_VARIABLES, EXPORT_TEMPLATE_NAME_MAX_LENGTH, EXPORT_TEMPLATE_VARIABLE_NAME_MAX_LENGTH, EXPORT_TEMPLATE_VARIABLE_VALUE_MAX_LENGTH, S3_BUCKET_NAME_MAX_LENGTH, S3_BUCKET_NAME_MIN_LENGTH, S3_KEY_MAX_LENGTH, SEARCH_MAX_MATCHES_LIMIT, SEARCH_QUERY_MAX_LENGTH, TEXT_PREVIEW_SIZE_KB_MAX, THUMBNAIL_SIZE_MAX, THUMBNAIL_SIZE_MIN};
+use crate::dto::archive_request::ArchiveRequest;
+use crate::dto::batch_download_request::BatchDownloadRequest;
+use crate::dto::cost_estimate_request::CostEstimateRequest;
+use crate::dto::diff_request::DiffRequest;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::exists_request::ExistsRequest;
+use crate::dto::export_history_request::ExportHistoryRequest;
+use crate::dto::export_template_request::ExportTemplateRequest;
+use crate::dto::field_error::FieldError;
+use crate::dto::keys_download_request::KeysDownloadRequest;
+use crate::dto::list_request::ListRequest;
+use crate::dto::object_request::ObjectRequest;
+use crate::dto::search_request::SearchRequest;
+use crate::dto::signed_link_request::SignedLinkRequest;
+use crate::dto::template_job_request::TemplateJobRequest;
+use crate::dto::text_preview_request::TextPreviewRequest;
+use crate::dto::thumbnail_request::ThumbnailRequest;
+use crate::dto::validation_error_response::ValidationErrorResponse;
+use crate::enums::export_filter::{parse_filter_date, ExportFilter};
+use log::debug;
+use serde::de::DeserializeOwned;
+
+/// Request validation trait (field-level validation of deserialized request DTOs)
+pub trait ValidateRequest {
+    /// Gets [Vec<FieldError>] field validation errors (empty if request is valid)
+    fn validate(&self) -> Vec<FieldError>;
+}
+
+/// Validated JSON request extractor (JSON body is deserialized and validated by [ValidateRequest] trait)
+/// Rejects requests with an unprocessable entity status response and field validation errors
+pub struct ValidJson<T>(pub T);
+
+impl<T> Deref for ValidJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// [FromRequest] trait implementation (JSON deserialization errors are rejected as usual)
+impl<S, T> FromRequest<S> for ValidJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + ValidateRequest,
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(json_request) = <Json<T> as FromRequest<S>>::from_request(request, state).await.map_err(IntoResponse::into_response)?;
+        let field_errors = json_request.validate();
+        if !field_errors.is_empty() {
+            debug!("valid_json - request not valid - fields: {field_errors:?}");
+            return Err(create_validation_error_response(field_errors));
+        }
+        Ok(ValidJson(json_request))
+    }
+}
+
+/// [OptionalFromRequest] trait implementation (optional JSON body - requests without content type are extracted as [None])
+impl<S, T> OptionalFromRequest<S> for ValidJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + ValidateRequest,
+    Json<T>: OptionalFromRequest<S, Rejection = JsonRejection>,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Option<Self>, Self::Rejection> {
+        let Some(Json(json_request)) = <Json<T> as OptionalFromRequest<S>>::from_request(request, state)
+            .await
+            .map_err(IntoResponse::into_response)?
+        else {
+            return Ok(None);
+        };
+        let field_errors = json_request.validate();
+        if !field_errors.is_empty() {
+            debug!("valid_json - request not valid - fields: {field_errors:?}");
+            return Err(create_validation_error_response(field_errors));
+        }
+        Ok(Some(ValidJson(json_request)))
+    }
+}
+
+/// Validated query params request extractor (query params are deserialized and validated by [ValidateRequest] trait)
+/// Rejects requests with an unprocessable entity status response and field validation errors
+pub struct ValidQuery<T>(pub T);
+
+/// [FromRequestParts] trait implementation (query params deserialization errors are rejected as usual)
+impl<S, T> FromRequestParts<S> for ValidQuery<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + ValidateRequest,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(request_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query_request) =
+            Query::<T>::from_request_parts(request_parts, state).await.map_err(IntoResponse::into_response)?;
+        let field_errors = query_request.validate();
+        if !field_errors.is_empty() {
+            debug!("valid_query - request not valid - fields: {field_errors:?}");
+            return Err(create_validation_error_response(field_errors));
+        }
+        Ok(ValidQuery(query_request))
+    }
+}
+
+/// Creates a new [Response] with unprocessable entity status by [Vec<FieldError>] field validation errors
+pub fn create_validation_error_response(field_errors: Vec<FieldError>) -> Response {
+    let validation_error_response = ValidationErrorResponse {
+        error: String::from("VALIDATION_FAILED"),
+        fields: field_errors,
+    };
+    (StatusCode::UNPROCESSABLE_ENTITY, Json(validation_error_response)).into_response()
+}
+
+/// Validates [&str] field name S3 bucket name [&str] value (S3 bucket naming rules: 3 to 63 lowercase letters, numbers, dots and hyphens)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_bucket_name(field: &str, bucket_name: &str, field_errors: &mut Vec<FieldError>) {
+    if bucket_name.trim().is_empty() {
+        add_field_error(field_errors, field, "must not be empty");
+    } else if !(S3_BUCKET_NAME_MIN_LENGTH..=S3_BUCKET_NAME_MAX_LENGTH).contains(&bucket_name.len()) {
+        add_field_error(
+            field_errors,
+            field,
+            &format!("length must be between {S3_BUCKET_NAME_MIN_LENGTH} and {S3_BUCKET_NAME_MAX_LENGTH} characters"),
+        );
+    } else if !bucket_name.chars().all(|bucket_char| bucket_char.is_ascii_lowercase() || bucket_char.is_ascii_digit() || bucket_char == '.' || bucket_char == '-') {
+        add_field_error(field_errors, field, "must contain just lowercase letters, numbers, dots and hyphens");
+    }
+}
+
+/// Validates [&str] field name S3 path or S3 key [&str] value length (S3 keys are up to 1024 bytes)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_s3_path(field: &str, s3_path: &str, field_errors: &mut Vec<FieldError>) {
+    if s3_path.len() > S3_KEY_MAX_LENGTH {
+        add_field_error(field_errors, field, &format!("length must be up to {S3_KEY_MAX_LENGTH} bytes"));
+    }
+}
+
+/// Validates [&str] field name S3 keys [&[String]] values (at least one S3 key - not empty S3 keys)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_s3_keys<'a>(field: &str, s3_keys: impl ExactSizeIterator<Item = &'a String>, field_errors: &mut Vec<FieldError>) {
+    if s3_keys.len() == 0 {
+        add_field_error(field_errors, field, "must contain at least one S3 key");
+    }
+    s3_keys.enumerate().for_each(|(index, s3_key)| {
+        if s3_key.trim().is_empty() {
+            add_field_error(field_errors, &format!("{field}[{index}]"), "must not be empty");
+        }
+        validate_s3_path(&format!("{field}[{index}]"), s3_key, field_errors);
+    });
+}
+
+/// Validates [&str] field name template variable [&str] name (ASCII alphanumeric or underscore characters)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_template_variable_name(field: &str, name: &str, field_errors: &mut Vec<FieldError>) {
+    if name.is_empty()
+        || name.len() > EXPORT_TEMPLATE_VARIABLE_NAME_MAX_LENGTH
+        || !name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_')
+    {
+        add_field_error(
+            field_errors,
+            field,
+            &format!("must be up to {EXPORT_TEMPLATE_VARIABLE_NAME_MAX_LENGTH} alphanumeric or underscore characters"),
+        );
+    }
+}
+
+/// Validates [&str] field name template variable [&str] value (not empty - placeholders, parent S3 path segments and
+/// control characters are not allowed, so resolved S3 paths can't escape the saved S3 path)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_template_variable_value(field: &str, value: &str, field_errors: &mut Vec<FieldError>) {
+    if value.trim().is_empty() {
+        add_field_error(field_errors, field, "must not be empty");
+    } else if value.chars().count() > EXPORT_TEMPLATE_VARIABLE_VALUE_MAX_LENGTH {
+        add_field_error(field_errors, field, &format!("length must be up to {EXPORT_TEMPLATE_VARIABLE_VALUE_MAX_LENGTH} characters"));
+    } else if value.contains(['{', '}']) || value.contains("..") || value.chars().any(char::is_control) {
+        add_field_error(field_errors, field, "must not contain placeholders, '..' or control characters");
+    }
+}
+
+/// Validates [&str] field name [Option<T>] optional numeric value (it should be positive if it is defined)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_positive<T: Default + PartialOrd>(field: &str, value: Option<T>, field_errors: &mut Vec<FieldError>) {
+    if value.is_some_and(|value| value <= T::default()) {
+        add_field_error(field_errors, field, "must be greater than zero");
+    }
+}
+
+/// Validates [&str] field name [&ExportFilter] export filter (not empty and / or conditions, not empty name patterns
+/// and tag keys, ordered size and date bounds, RFC 3339 dates and limited nesting depth and conditions)
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_export_filter(field: &str, export_filter: &ExportFilter, field_errors: &mut Vec<FieldError>) {
+    let mut conditions_total = 0;
+    validate_export_filter_condition(field, export_filter, 1, &mut conditions_total, field_errors);
+    if conditions_total > EXPORT_FILTER_MAX_CONDITIONS {
+        add_field_error(field_errors, field, &format!("must contain up to {EXPORT_FILTER_MAX_CONDITIONS} conditions"));
+    }
+}
+
+/// Validates [&str] field name [&ExportFilter] export filter condition at [usize] nesting depth (conditions are
+/// counted into [&mut usize] conditions total - please, check [validate_export_filter])
+/// Field errors are added to [&mut Vec<FieldError>] field errors
+fn validate_export_filter_condition(
+    field: &str,
+    export_filter: &ExportFilter,
+    depth: usize,
+    conditions_total: &mut usize,
+    field_errors: &mut Vec<FieldError>,
+) {
+    *conditions_total += 1;
+    if depth > EXPORT_FILTER_MAX_DEPTH {
+        add_field_error(field_errors, field, &format!("nesting depth must be up to {EXPORT_FILTER_MAX_DEPTH}"));
+        return;
+    }
+    match export_filter {
+        ExportFilter::AND(export_filters) | ExportFilter::OR(export_filters) => {
+            let field = format!("{field}.{}", if matches!(export_filter, ExportFilter::AND(_)) { "and" } else { "or" });
+            if export_filters.is_empty() {
+                add_field_error(field_errors, &field, "must contain at least one condition");
+            }
+            export_filters.iter().enumerate().for_each(|(index, export_filter)| {
+                validate_export_filter_condition(&format!("{field}[{index}]"), export_filter, depth + 1, conditions_total, field_errors)
+            });
+        }
+        ExportFilter::NOT(export_filter) => {
+            validate_export_filter_condition(&format!("{field}.not"), export_filter, depth + 1, conditions_total, field_errors)
+        }
+        ExportFilter::NAME(pattern) if pattern.is_empty() => add_field_error(field_errors, &format!("{field}.name"), "must not be empty"),
+        ExportFilter::NAME(pattern) => validate_s3_path(&format!("{field}.name"), pattern, field_errors),
+        ExportFilter::SIZE { min_bytes: Some(min_bytes), max_bytes: Some(max_bytes) } if min_bytes > max_bytes => {
+            add_field_error(field_errors, &format!("{field}.size"), "min_bytes must be up to max_bytes")
+        }
+        ExportFilter::SIZE { .. } => {}
+        ExportFilter::MTIME { after, before } => {
+            let after_date = after.as_deref().map(parse_filter_date);
+            let before_date = before.as_deref().map(parse_filter_date);
+            if matches!(after_date, Some(None)) || matches!(before_date, Some(None)) {
+                add_field_error(field_errors, &format!("{field}.mtime"), "dates must be RFC 3339 dates (i.e.: 2026-01-01T00:00:00Z)");
+            } else if let (Some(Some(after_date)), Some(Some(before_date))) = (after_date, before_date)
+                && after_date > before_date
+            {
+                add_field_error(field_errors, &format!("{field}.mtime"), "after must be up to before");
+            }
+        }
+        ExportFilter::TAG { key, .. } if key.trim().is_empty() => add_field_error(field_errors, &format!("{field}.tag.key"), "must not be empty"),
+        ExportFilter::TAG { .. } => {}
+        ExportFilter::STORAGE_CLASS(storage_classes) if storage_classes.is_empty() => {
+            add_field_error(field_errors, &format!("{field}.storage_class"), "must contain at least one storage class")
+        }
+        ExportFilter::STORAGE_CLASS(_) => {}
+    }
+}
+
+/// Adds a new [FieldError] by [&str] field name and [&str] message into [&mut Vec<FieldError>] field errors
+fn add_field_error(field_errors: &mut Vec<FieldError>, field: &str, message: &str) {
+    field_errors.push(FieldError {
+        field: String::from(field),
+        message: String::from(message),
+    });
+}
+
+/// [ValidateRequest] trait implementation for [DownloadRequest]
+impl ValidateRequest for DownloadRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_positive("max_parallel_fetches", self.max_parallel_fetches, &mut field_errors);
+        validate_positive("multipart_chunk_bytes", self.multipart_chunk_bytes, &mut field_errors);
+        validate_positive("volumes", self.volumes, &mut field_errors);
+        validate_positive("restore.days", self.restore.as_ref().and_then(|restore_request| restore_request.days), &mut field_errors);
+        if let Some(manifest) = &self.manifest {
+            validate_s3_keys("manifest.s3_key", manifest.iter().map(|manifest_entry| &manifest_entry.s3_key), &mut field_errors);
+        }
+        if let Some(export_filter) = &self.filter {
+            validate_export_filter("filter", export_filter, &mut field_errors);
+        }
+        if self.encryption_recipient.as_deref().is_some_and(|encryption_recipient| !encryption_recipient.trim().starts_with("age1")) {
+            add_field_error(&mut field_errors, "encryption_recipient", "must be an age public key (age1...)");
+        }
+        if self.notify_email.as_deref().is_some_and(|notify_email| !notify_email.contains('@')) {
+            add_field_error(&mut field_errors, "notify_email", "must be an email address");
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ExportTemplateRequest] (template name and download request fields)
+impl ValidateRequest for ExportTemplateRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        if self.name.trim().is_empty() {
+            add_field_error(&mut field_errors, "name", "must not be empty");
+        } else if self.name.trim().chars().count() > EXPORT_TEMPLATE_NAME_MAX_LENGTH {
+            add_field_error(&mut field_errors, "name", &format!("length must be up to {EXPORT_TEMPLATE_NAME_MAX_LENGTH} characters"));
+        }
+        if self.variables.len() > EXPORT_TEMPLATE_MAX_VARIABLES {
+            add_field_error(&mut field_errors, "variables", &format!("must contain up to {EXPORT_TEMPLATE_MAX_VARIABLES} variables"));
+        }
+        for (index, variable) in self.variables.iter().enumerate() {
+            validate_template_variable_name(&format!("variables.name[{index}]"), &variable.name, &mut field_errors);
+            if EXPORT_TEMPLATE_BUILTIN_VARIABLES.contains(&variable.name.as_str())
+                || self.variables[..index].iter().any(|other_variable| other_variable.name == variable.name)
+            {
+                add_field_error(&mut field_errors, &format!("variables.name[{index}]"), "must be unique (built-in variables included)");
+            }
+            if let Some(default_value) = &variable.default_value {
+                validate_template_variable_value(&format!("variables.default_value[{index}]"), default_value, &mut field_errors);
+            }
+        }
+
+        // saved request is validated as resolved by default values (variable names as sample values if not defined)
+        let sample_parameters = self
+            .variables
+            .iter()
+            .filter(|variable| variable.default_value.is_none())
+            .map(|variable| (variable.name.clone(), variable.name.clone()))
+            .collect();
+        let resolved_request = get_template_variable_values(&self.variables, &sample_parameters, current_epoch_seconds() as i64)
+            .ok()
+            .and_then(|variable_values| resolve_template_request(&self.request, &variable_values).ok());
+        match resolved_request {
+            Some(resolved_request) => field_errors.extend(resolved_request.validate()),
+            None => field_errors.extend(self.request.validate()),
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [TemplateJobRequest] (variable values and schedule time)
+impl ValidateRequest for TemplateJobRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        for (name, value) in &self.parameters {
+            validate_template_variable_name(&format!("parameters.{name}"), name, &mut field_errors);
+            validate_template_variable_value(&format!("parameters.{name}"), value, &mut field_errors);
+        }
+        if self.schedule_time.as_deref().is_some_and(|schedule_time| parse_filter_date(schedule_time).is_none()) {
+            add_field_error(&mut field_errors, "schedule_time", "must be a RFC 3339 date (i.e.: 2026-01-31T10:00:00Z)");
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ExportHistoryRequest] (S3 bucket, finish dates range, page size and cursor)
+impl ValidateRequest for ExportHistoryRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        if let Some(bucket_name) = &self.bucket {
+            validate_bucket_name("bucket", bucket_name, &mut field_errors);
+        }
+        for (field, date) in [("from", &self.from), ("to", &self.to)] {
+            if date.as_deref().is_some_and(|date| parse_filter_date(date).is_none()) {
+                add_field_error(&mut field_errors, field, "must be a RFC 3339 date (i.e.: 2026-01-31T10:00:00Z)");
+            }
+        }
+        validate_positive("limit", self.limit, &mut field_errors);
+        if self
+            .cursor
+            .as_deref()
+            .is_some_and(|cursor| cursor.split_once(':').is_none_or(|(finished_at, _)| finished_at.parse::<u64>().is_err()))
+        {
+            add_field_error(&mut field_errors, "cursor", "must be a previous page cursor");
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [KeysDownloadRequest]
+impl ValidateRequest for KeysDownloadRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_keys", self.s3_keys.iter(), &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ArchiveRequest]
+impl ValidateRequest for ArchiveRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_key", std::iter::once(&self.s3_key), &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [BatchDownloadRequest]
+impl ValidateRequest for BatchDownloadRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_keys", self.s3_keys.iter(), &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ListRequest]
+impl ValidateRequest for ListRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_positive("max_keys", self.max_keys, &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [DiffRequest]
+impl ValidateRequest for DiffRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        if let Some(target_bucket_name) = &self.target_bucket_name {
+            validate_bucket_name("target_bucket_name", target_bucket_name, &mut field_errors);
+        }
+        match (&self.target_full_path, &self.manifest) {
+            (Some(target_full_path), None) => validate_s3_path("target_full_path", target_full_path, &mut field_errors),
+            (None, Some(manifest)) => validate_s3_keys("manifest.s3_key", manifest.iter().map(|manifest_entry| &manifest_entry.s3_key), &mut field_errors),
+            _ => add_field_error(&mut field_errors, "target_full_path", "either target_full_path or manifest must be defined"),
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ExistsRequest]
+impl ValidateRequest for ExistsRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        if let Some(s3_key) = &self.s3_key {
+            validate_s3_path("s3_key", s3_key, &mut field_errors);
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [CostEstimateRequest]
+impl ValidateRequest for CostEstimateRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_positive("multipart_chunk_bytes", self.multipart_chunk_bytes, &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ObjectRequest]
+impl ValidateRequest for ObjectRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_key", std::iter::once(&self.s3_key), &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [SearchRequest]
+impl ValidateRequest for SearchRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        if self.query.is_empty() {
+            add_field_error(&mut field_errors, "query", "must not be empty");
+        } else if self.query.len() > SEARCH_QUERY_MAX_LENGTH {
+            add_field_error(&mut field_errors, "query", &format!("length must be up to {SEARCH_QUERY_MAX_LENGTH} bytes"));
+        }
+        validate_positive("max_matches", self.max_matches, &mut field_errors);
+        if self.max_matches.is_some_and(|max_matches| max_matches > SEARCH_MAX_MATCHES_LIMIT) {
+            add_field_error(&mut field_errors, "max_matches", &format!("must be up to {SEARCH_MAX_MATCHES_LIMIT}"));
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [SignedLinkRequest]
+impl ValidateRequest for SignedLinkRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_positive("expires_in_seconds", self.expires_in_seconds, &mut field_errors);
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [TextPreviewRequest]
+impl ValidateRequest for TextPreviewRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_key", std::iter::once(&self.s3_key), &mut field_errors);
+        validate_positive("size_kb", self.size_kb, &mut field_errors);
+        if self.size_kb.is_some_and(|size_kb| size_kb > TEXT_PREVIEW_SIZE_KB_MAX) {
+            add_field_error(&mut field_errors, "size_kb", &format!("must be up to {TEXT_PREVIEW_SIZE_KB_MAX} KB"));
+        }
+        field_errors
+    }
+}
+
+/// [ValidateRequest] trait implementation for [ThumbnailRequest]
+impl ValidateRequest for ThumbnailRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+        validate_bucket_name("bucket_name", &self.bucket_name, &mut field_errors);
+        validate_s3_path("full_path", &self.full_path, &mut field_errors);
+        validate_s3_keys("s3_key", std::iter::once(&self.s3_key), &mut field_errors);
+        if self.size.is_some_and(|size| !(THUMBNAIL_SIZE_MIN..=THUMBNAIL_SIZE_MAX).contains(&size)) {
+            add_field_error(
+                &mut field_errors,
+                "size",
+                &format!("must be between {THUMBNAIL_SIZE_MIN} and {THUMBNAIL_SIZE_MAX} pixels"),
+            );
+        }
+        field_errors
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::request_validation::ValidateRequest;
+    use crate::dto::download_request::DownloadRequest;
+    use crate::dto::export_template_request::ExportTemplateRequest;
+    use crate::dto::field_error::FieldError;
+    use crate::dto::manifest_entry::ManifestEntry;
+
+    /// Scenario:
+    /// Validates a download request with empty S3 bucket, a too long S3 path and an empty manifest S3 key
+    /// Expectation:
+    /// A field error should be retrieved for each not valid field
+    #[test]
+    fn when_validate_download_request_should_report_field_errors() {
+        let download_request = DownloadRequest {
+            bucket_name: String::from(" "),
+            full_path: "a".repeat(1025),
+            manifest: Some(vec![ManifestEntry { s3_key: String::from("a.txt"), archive_name: None }, ManifestEntry::default()]),
+            ..Default::default()
+        };
+
+        let field_fn = |field: &str, message: &str| FieldError { field: String::from(field), message: String::from(message) };
+        assert_eq!(
+            vec![
+                field_fn("bucket_name", "must not be empty"),
+                field_fn("full_path", "length must be up to 1024 bytes"),
+                field_fn("manifest.s3_key[1]", "must not be empty"),
+            ],
+            download_request.validate()
+        );
+    }
+
+    /// Scenario:
+    /// Parses and validates an export template request (template name and download request fields in the same body) with
+    /// an empty template name and an empty S3 bucket
+    /// Expectation:
+    /// The saved download request should be parsed from the flattened fields and a field error should be retrieved for
+    /// each not valid field
+    #[test]
+    fn when_validate_export_template_request_should_report_field_errors() {
+        let export_template_request: ExportTemplateRequest =
+            serde_json::from_str(r#"{"name":" ","bucket_name":"","full_path":"reports/daily"}"#).unwrap();
+
+        let field_fn = |field: &str, message: &str| FieldError { field: String::from(field), message: String::from(message) };
+        assert_eq!("reports/daily", export_template_request.request.full_path);
+        assert_eq!(
+            vec![field_fn("name", "must not be empty"), field_fn("bucket_name", "must not be empty")],
+            export_template_request.validate()
+        );
+    }
+}
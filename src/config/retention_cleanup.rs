@@ -0,0 +1,253 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{ARTIFACT_STORE_BUCKET_ENV_VAR, ARTIFACT_STORE_PATH_DEFAULT, ARTIFACT_STORE_PATH_ENV_VAR, DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_DEFAULT, DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_ENV_VAR, EXPORT_OBJECT_RETENTION_SECONDS_DEFAULT, EXPORT_OBJECT_RETENTION_SECONDS_ENV_VAR, WATCH_DESTINATION_BUCKET_ENV_VAR, WATCH_DESTINATION_PATH_DEFAULT, WATCH_DESTINATION_PATH_ENV_VAR};
+use crate::dto::retention_cleanup_stats::RetentionCleanupStats;
+use crate::enums::common_error::CommonError;
+use crate::enums::export_filter::parse_filter_date;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use crate::service::download_job_service::DynDownloadJobService;
+use crate::service::s3_event_service::DynS3EventService;
+use log::{debug, info, warn};
+
+/// Retention cleanup metrics (accumulated since server start)
+#[derive(Default)]
+pub struct RetentionCleanupMetrics {
+    /// removed jobs total
+    pub removed_jobs_total: AtomicU64,
+    /// reclaimed bytes total (stored job results)
+    pub reclaimed_bytes_total: AtomicU64,
+    /// removed S3 objects total (expired exports written to S3)
+    pub removed_objects_total: AtomicU64,
+    /// reclaimed S3 bytes total (expired exports written to S3)
+    pub reclaimed_object_bytes_total: AtomicU64,
+}
+
+/// Retention cleanup metrics shared instance
+pub static RETENTION_CLEANUP_METRICS: RetentionCleanupMetrics = RetentionCleanupMetrics {
+    removed_jobs_total: AtomicU64::new(0),
+    reclaimed_bytes_total: AtomicU64::new(0),
+    removed_objects_total: AtomicU64::new(0),
+    reclaimed_object_bytes_total: AtomicU64::new(0),
+};
+
+impl RetentionCleanupMetrics {
+    /// Records [usize] removed jobs and [u64] reclaimed bytes (stored job results)
+    pub fn record_removed_jobs(&self, removed_jobs_total: usize, reclaimed_bytes_total: u64) {
+        self.removed_jobs_total.fetch_add(removed_jobs_total as u64, Ordering::Relaxed);
+        self.reclaimed_bytes_total.fetch_add(reclaimed_bytes_total, Ordering::Relaxed);
+    }
+
+    /// Records [usize] removed S3 objects and [u64] reclaimed S3 bytes (expired exports written to S3)
+    pub fn record_removed_objects(&self, removed_objects_total: usize, reclaimed_object_bytes_total: u64) {
+        self.removed_objects_total.fetch_add(removed_objects_total as u64, Ordering::Relaxed);
+        self.reclaimed_object_bytes_total.fetch_add(reclaimed_object_bytes_total, Ordering::Relaxed);
+    }
+
+    /// Gets [RetentionCleanupStats] retention cleanup counters (accumulated since server start)
+    pub fn get_retention_cleanup_stats(&self) -> RetentionCleanupStats {
+        RetentionCleanupStats {
+            removed_jobs_total: self.removed_jobs_total.load(Ordering::Relaxed),
+            reclaimed_bytes_total: self.reclaimed_bytes_total.load(Ordering::Relaxed),
+            removed_objects_total: self.removed_objects_total.load(Ordering::Relaxed),
+            reclaimed_object_bytes_total: self.reclaimed_object_bytes_total.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Starts a background task that removes expired download jobs and stored results by [DynDownloadJobService] download
+/// job service and expired exports written to S3 (artifact store, watch destination and [DynS3EventService] S3 event
+/// rules destinations) by [DynAwsSdkS3Service] AWS S3 service
+/// Important: cleanup interval and exports retention window should be configured (please, check constants.rs)
+pub fn start_retention_cleanup_task(
+    download_job_service: DynDownloadJobService,
+    aws_s3_service: DynAwsSdkS3Service,
+    s3_event_service: DynS3EventService,
+) {
+    let cleanup_interval_seconds = get_env_var_as_u64(
+        DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_ENV_VAR,
+        DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_DEFAULT,
+    )
+    .max(1);
+    let export_retention_seconds = get_env_var_as_u64(EXPORT_OBJECT_RETENTION_SECONDS_ENV_VAR, EXPORT_OBJECT_RETENTION_SECONDS_DEFAULT);
+    let export_prefixes = if export_retention_seconds == 0 { Vec::new() } else { get_export_prefixes(&s3_event_service) };
+    info!("start_retention_cleanup_task - cleanup interval (seconds): {cleanup_interval_seconds}");
+    info!("start_retention_cleanup_task - exports retention (seconds): {export_retention_seconds} - prefixes: {export_prefixes:?}");
+
+    tokio::spawn(async move {
+        let mut cleanup_interval = tokio::time::interval(Duration::from_secs(cleanup_interval_seconds));
+        loop {
+            cleanup_interval.tick().await;
+            let (removed_jobs_total, reclaimed_bytes_total) = download_job_service.remove_expired_jobs().await;
+            if removed_jobs_total == 0 {
+                debug!("retention_cleanup_task - no job to remove");
+            } else {
+                RETENTION_CLEANUP_METRICS.record_removed_jobs(removed_jobs_total, reclaimed_bytes_total);
+                info!("retention_cleanup_task - removed jobs: {removed_jobs_total} - reclaimed bytes: {reclaimed_bytes_total}");
+            }
+
+            let expired_before = current_epoch_seconds().saturating_sub(export_retention_seconds) as i64;
+            for (bucket_name, path) in &export_prefixes {
+                match remove_expired_s3_objects(&aws_s3_service, bucket_name, path, expired_before).await {
+                    Ok((0, _)) => debug!("retention_cleanup_task - no export to remove - bucket name: {bucket_name} - path: {path}"),
+                    Ok((removed_objects_total, reclaimed_object_bytes_total)) => {
+                        RETENTION_CLEANUP_METRICS.record_removed_objects(removed_objects_total, reclaimed_object_bytes_total);
+                        info!(
+                            "retention_cleanup_task - removed exports: {removed_objects_total} - reclaimed bytes: {reclaimed_object_bytes_total} - bucket name: {bucket_name} - path: {path}"
+                        );
+                    }
+                    Err(_) => warn!("retention_cleanup_task - exports not listed - bucket name: {bucket_name} - path: {path}"),
+                }
+            }
+        }
+    });
+}
+
+/// Gets [Vec<(String, String)>] S3 buckets and paths of exports written to S3 (artifact store and watch destination
+/// configured by environment variables + [DynS3EventService] S3 event rules destinations)
+/// Important: S3 bucket roots (empty paths) are skipped - not exported S3 objects would be removed too
+fn get_export_prefixes(s3_event_service: &DynS3EventService) -> Vec<(String, String)> {
+    let configured_prefix = |bucket_env_var: &str, path_env_var: &str, path_default: &str| {
+        std::env::var(bucket_env_var)
+            .ok()
+            .filter(|bucket_name| !bucket_name.is_empty())
+            .map(|bucket_name| (bucket_name, std::env::var(path_env_var).unwrap_or_else(|_| String::from(path_default))))
+    };
+
+    let mut export_prefixes: Vec<(String, String)> = configured_prefix(ARTIFACT_STORE_BUCKET_ENV_VAR, ARTIFACT_STORE_PATH_ENV_VAR, ARTIFACT_STORE_PATH_DEFAULT)
+        .into_iter()
+        .chain(configured_prefix(WATCH_DESTINATION_BUCKET_ENV_VAR, WATCH_DESTINATION_PATH_ENV_VAR, WATCH_DESTINATION_PATH_DEFAULT))
+        .chain(s3_event_service.get_destination_prefixes())
+        .filter(|(_, path)| !path.trim_matches('/').is_empty())
+        .collect();
+    export_prefixes.sort();
+    export_prefixes.dedup();
+    export_prefixes
+}
+
+/// Removes all S3 objects under [&str] S3 path of [&str] S3 bucket (all nested folders included) last modified before
+/// [i64] expiration date (seconds since unix epoch - S3 objects without last modified date are kept)
+/// by [&DynAwsSdkS3Service] AWS S3 service
+/// Returns [(usize, u64)] removed S3 objects total and reclaimed bytes total (S3 objects not removed are retried by
+/// the next cleanup - S3 objects protected by Object Lock are skipped and reported)
+/// Returns a [CommonError] if S3 listing fails
+async fn remove_expired_s3_objects(
+    aws_s3_service: &DynAwsSdkS3Service,
+    bucket_name: &str,
+    path: &str,
+    expired_before: i64,
+) -> Result<(usize, u64), CommonError> {
+    let mut expired_s3_objects = Vec::new();
+    let mut continuation_token = None;
+    loop {
+        let (s3_object_metadata_list, next_continuation_token) = aws_s3_service
+            .get_s3_object_metadata_page(String::from(bucket_name), String::from(path), None, continuation_token)
+            .await?;
+        expired_s3_objects.extend(s3_object_metadata_list.into_iter().filter(|s3_object_metadata| {
+            s3_object_metadata
+                .last_modified
+                .as_deref()
+                .and_then(parse_filter_date)
+                .is_some_and(|last_modified| last_modified < expired_before)
+        }));
+        continuation_token = next_continuation_token;
+        if continuation_token.is_none() {
+            break;
+        }
+    }
+
+    let mut removed_objects_total = 0;
+    let mut reclaimed_object_bytes_total = 0;
+    for s3_object_metadata in expired_s3_objects {
+        match aws_s3_service
+            .remove_s3_object(String::from(bucket_name), String::from(path), s3_object_metadata.s3_key.clone())
+            .await
+        {
+            Ok(_) => {
+                removed_objects_total += 1;
+                reclaimed_object_bytes_total += s3_object_metadata.size_bytes.max(0) as u64;
+            }
+            Err(CommonError::OBJECT_LOCKED) => {
+                warn!("remove_expired_s3_objects - export locked (skipped) - bucket name: {bucket_name} - s3 key: {}", s3_object_metadata.s3_key);
+            }
+            Err(_) => {}
+        }
+    }
+    Ok((removed_objects_total, reclaimed_object_bytes_total))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::config::retention_cleanup::{remove_expired_s3_objects, RetentionCleanupMetrics};
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::common_error::CommonError;
+    use crate::enums::export_filter::parse_filter_date;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+
+    /// Scenario:
+    /// Removes expired exports from a S3 path listed in two pages with an expired S3 object, an expired S3 object
+    /// protected by Object Lock, a recent S3 object and a S3 object without last modified date
+    /// Expectation:
+    /// Just the expired not locked S3 object should be removed and its size should be reclaimed
+    #[tokio::test]
+    async fn when_remove_expired_s3_objects_should_remove_just_expired_exports() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_object_metadata_page()
+            .times(2)
+            .returning(|_, _, _, continuation_token| {
+                let s3_object_metadata_fn = |s3_key: &str, last_modified: Option<&str>| S3ObjectMetadata {
+                    s3_key: String::from(s3_key),
+                    size_bytes: 64,
+                    last_modified: last_modified.map(String::from),
+                    ..Default::default()
+                };
+                match continuation_token {
+                    None => Ok((
+                        vec![
+                            s3_object_metadata_fn("old.zip", Some("2026-01-01T00:00:00Z")),
+                            s3_object_metadata_fn("locked.zip", Some("2026-01-01T00:00:00Z")),
+                            s3_object_metadata_fn("unknown.zip", None),
+                        ],
+                        Some(String::from("next-page")),
+                    )),
+                    Some(_) => Ok((vec![s3_object_metadata_fn("recent.zip", Some("2026-03-01T00:00:00Z"))], None)),
+                }
+            });
+        aws_s3_service
+            .expect_remove_s3_object()
+            .withf(|bucket_name, path, _| bucket_name == "archive-bucket" && path == "archives")
+            .times(2)
+            .returning(|_, _, s3_key| if s3_key == "old.zip" { Ok(()) } else { Err(CommonError::OBJECT_LOCKED) });
+        let aws_s3_service = Arc::new(aws_s3_service) as DynAwsSdkS3Service;
+        let expired_before = parse_filter_date("2026-02-01T00:00:00Z").unwrap();
+
+        let removed_exports = remove_expired_s3_objects(&aws_s3_service, "archive-bucket", "archives", expired_before).await;
+
+        assert_eq!(Ok((1, 64)), removed_exports);
+    }
+
+    /// Scenario:
+    /// Records removed jobs and removed S3 objects twice
+    /// Expectation:
+    /// Retention cleanup stats should retrieve the accumulated counters
+    #[test]
+    fn when_get_retention_cleanup_stats_should_retrieve_accumulated_counters() {
+        let retention_cleanup_metrics = RetentionCleanupMetrics::default();
+        retention_cleanup_metrics.record_removed_jobs(2, 100);
+        retention_cleanup_metrics.record_removed_jobs(1, 50);
+        retention_cleanup_metrics.record_removed_objects(3, 300);
+
+        let retention_cleanup_stats = retention_cleanup_metrics.get_retention_cleanup_stats();
+
+        assert_eq!(3, retention_cleanup_stats.removed_jobs_total);
+        assert_eq!(150, retention_cleanup_stats.reclaimed_bytes_total);
+        assert_eq!(3, retention_cleanup_stats.removed_objects_total);
+        assert_eq!(300, retention_cleanup_stats.reclaimed_object_bytes_total);
+    }
+}
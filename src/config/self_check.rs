@@ -0,0 +1,118 @@
+use std::fmt::Formatter;
+use std::net::TcpListener;
+
+use aws_sdk_s3::config::{BehaviorVersion, ProvideCredentials};
+use aws_sdk_s3::Client;
+use crate::config::config_dump::get_config_errors;
+use crate::config::temp_storage::check_temp_dir_writable;
+use crate::constant::constants::{API_SERVER_HOST_DEFAULT, API_SERVER_HOST_ENV_VAR, API_SERVER_PORT_DEFAULT, API_SERVER_PORT_ENV_VAR, SELF_CHECK_BUCKETS_ENV_VAR};
+use log::debug;
+
+/// Self-check result struct
+pub struct SelfCheckResult {
+    /// check name
+    pub name: String,
+    /// check passed flag
+    pub passed: bool,
+    /// check details (i.e.: failure reason)
+    pub details: String,
+}
+
+/// [std::fmt::Display] trait implementation
+impl std::fmt::Display for SelfCheckResult {
+    /// just to simplify report lines (i.e.: "[OK] configuration - ...")
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = if self.passed { "OK" } else { "FAILED" };
+        write!(f, "[{status}] {} - {}", self.name, self.details)
+    }
+}
+
+/// Runs all startup self-checks (configuration, AWS credentials, S3 buckets reachability,
+/// temp dir writability and API server port binding)
+/// Returns [Vec<SelfCheckResult>] self-check results
+/// Important: S3 buckets to be checked should be configured (please, check constants.rs)
+pub async fn run_self_check() -> Vec<SelfCheckResult> {
+    debug!("run_self_check - start");
+    let mut self_check_results = vec![check_configuration()];
+
+    let aws_sdk_configuration = aws_config::load_defaults(BehaviorVersion::latest()).await;
+    let aws_credentials = match aws_sdk_configuration.credentials_provider() {
+        Some(credentials_provider) => credentials_provider.provide_credentials().await.map_err(|error| error.to_string()),
+        None => Err(String::from("no credentials provider found")),
+    };
+    self_check_results.push(create_self_check_result("aws credentials", aws_credentials.map(|_| String::from("loaded"))));
+
+    let aws_sdk_client = Client::new(&aws_sdk_configuration);
+    let bucket_names = std::env::var(SELF_CHECK_BUCKETS_ENV_VAR).unwrap_or_default();
+    for bucket_name in bucket_names.split(',').map(str::trim).filter(|bucket_name| !bucket_name.is_empty()) {
+        let head_bucket_result = aws_sdk_client
+            .head_bucket()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .map(|_| String::from("reachable"))
+            .map_err(|error| format!("{error:?}"));
+        self_check_results.push(create_self_check_result(&format!("s3 bucket {bucket_name}"), head_bucket_result));
+    }
+
+    self_check_results.push(check_temp_dir());
+    self_check_results.push(check_port_binding());
+
+    debug!("run_self_check - done");
+    self_check_results
+}
+
+/// Checks all configuration environment variables are valid (if they are defined)
+fn check_configuration() -> SelfCheckResult {
+    let config_errors = get_config_errors();
+    let check_result = if config_errors.is_empty() {
+        Ok(String::from("valid"))
+    } else {
+        Err(format!("not valid env vars: {}", config_errors.join(", ")))
+    };
+    create_self_check_result("configuration", check_result)
+}
+
+/// Checks temp dir is writable (a temp file is created and removed)
+fn check_temp_dir() -> SelfCheckResult {
+    create_self_check_result("temp dir", check_temp_dir_writable())
+}
+
+/// Checks API server address can be bound (listener is closed right away)
+fn check_port_binding() -> SelfCheckResult {
+    let api_server_host = std::env::var(API_SERVER_HOST_ENV_VAR).unwrap_or(String::from(API_SERVER_HOST_DEFAULT));
+    let api_server_port = std::env::var(API_SERVER_PORT_ENV_VAR).unwrap_or(String::from(API_SERVER_PORT_DEFAULT));
+    let api_server_address = format!("{api_server_host}:{api_server_port}");
+    let check_result = TcpListener::bind(&api_server_address)
+        .map(|_| format!("{api_server_address} is available"))
+        .map_err(|error| format!("{api_server_address} - {error}"));
+    create_self_check_result("port binding", check_result)
+}
+
+/// Creates a new [SelfCheckResult] by [&str] check name and [Result<String, String>] check details
+fn create_self_check_result(name: &str, check_result: Result<String, String>) -> SelfCheckResult {
+    SelfCheckResult {
+        name: String::from(name),
+        passed: check_result.is_ok(),
+        details: check_result.unwrap_or_else(|error| error),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::self_check::create_self_check_result;
+
+    /// Scenario:
+    /// Creates passed and failed self-check results
+    /// Expectation:
+    /// Report lines with proper status and details should be retrieved
+    #[test]
+    fn when_create_self_check_result_should_retrieve_report_line() {
+        let passed = create_self_check_result("temp dir", Ok(String::from("writable")));
+        let failed = create_self_check_result("port binding", Err(String::from("address in use")));
+
+        assert_eq!("[OK] temp dir - writable", passed.to_string());
+        assert_eq!("[FAILED] port binding - address in use", failed.to_string());
+    }
+}
@@ -0,0 +1,253 @@
+use std::sync::{Arc, LazyLock};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Shared state store (state shared by all API replicas, i.e.: Redis)
+#[async_trait]
+pub trait SharedStateStoreTrait {
+    /// Gets [bool] true if state is shared by all API replicas (otherwise, state is kept in memory by each replica)
+    fn is_enabled(&self) -> bool;
+
+    /// Gets [Option<String>] stored value by [&str] key
+    /// Returns [None] if value is not found, it's expired or the store can't be reached
+    async fn get_value(&self, key: &str) -> Option<String>;
+
+    /// Stores [&str] value by [&str] key and [u64] TTL (in seconds)
+    async fn set_value(&self, key: &str, value: &str, ttl_seconds: u64);
+
+    /// Removes all stored values by [&str] key prefix
+    /// Returns [usize] removed values quantity
+    async fn remove_values(&self, key_prefix: &str) -> usize;
+
+    /// Gets [Vec<String>] all stored keys by [&str] key prefix
+    async fn get_keys(&self, key_prefix: &str) -> Vec<String>;
+
+    /// Acquires (or renews, if it's already owned by [&str] owner) a lease by [&str] key and [u64] TTL (in seconds)
+    /// Returns [bool] true if lease is owned by the owner (leases are always acquired if state is not shared)
+    async fn acquire_lease(&self, key: &str, owner: &str, ttl_seconds: u64) -> bool;
+}
+
+/// Local state store implementation struct (default state store: nothing is shared, state is kept in memory by each replica)
+pub struct LocalStateStore {}
+
+/// Local state store implementation logic
+#[async_trait]
+impl SharedStateStoreTrait for LocalStateStore {
+    /// Gets [bool] true if state is shared by all API replicas (otherwise, state is kept in memory by each replica)
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    /// Gets [Option<String>] stored value by [&str] key
+    /// Returns [None] if value is not found, it's expired or the store can't be reached
+    async fn get_value(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    /// Stores [&str] value by [&str] key and [u64] TTL (in seconds)
+    async fn set_value(&self, _key: &str, _value: &str, _ttl_seconds: u64) {}
+
+    /// Removes all stored values by [&str] key prefix
+    /// Returns [usize] removed values quantity
+    async fn remove_values(&self, _key_prefix: &str) -> usize {
+        0
+    }
+
+    /// Gets [Vec<String>] all stored keys by [&str] key prefix
+    async fn get_keys(&self, _key_prefix: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Acquires (or renews, if it's already owned by [&str] owner) a lease by [&str] key and [u64] TTL (in seconds)
+    /// Returns [bool] true if lease is owned by the owner (leases are always acquired if state is not shared)
+    async fn acquire_lease(&self, _key: &str, _owner: &str, _ttl_seconds: u64) -> bool {
+        true
+    }
+}
+
+/// Redis state store implementation struct (enabled by `redis` feature and REDIS_URL env var)
+/// Important: Redis connection is opened on first use (and re-connected by the connection manager)
+#[cfg(feature = "redis")]
+pub struct RedisStateStore {
+    redis_client: redis::Client,
+    redis_connection: tokio::sync::OnceCell<redis::aio::ConnectionManager>,
+}
+
+#[cfg(feature = "redis")]
+impl RedisStateStore {
+    /// Gets [Option<redis::aio::ConnectionManager>] Redis connection (opened on first use)
+    /// Returns [None] if Redis can't be reached
+    async fn get_connection(&self) -> Option<redis::aio::ConnectionManager> {
+        match self
+            .redis_connection
+            .get_or_try_init(|| self.redis_client.get_connection_manager())
+            .await
+        {
+            Ok(redis_connection) => Some(redis_connection.clone()),
+            Err(redis_error) => {
+                log::error!("get_connection - Redis can't be reached - error: {redis_error}");
+                None
+            }
+        }
+    }
+}
+
+/// Redis state store implementation logic
+#[cfg(feature = "redis")]
+#[async_trait]
+impl SharedStateStoreTrait for RedisStateStore {
+    /// Gets [bool] true if state is shared by all API replicas (otherwise, state is kept in memory by each replica)
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Gets [Option<String>] stored value by [&str] key
+    /// Returns [None] if value is not found, it's expired or the store can't be reached
+    async fn get_value(&self, key: &str) -> Option<String> {
+        use crate::constant::constants::REDIS_KEY_PREFIX;
+        use redis::AsyncCommands;
+
+        let mut redis_connection = self.get_connection().await?;
+        redis_connection
+            .get::<_, Option<String>>(format!("{REDIS_KEY_PREFIX}{key}"))
+            .await
+            .inspect_err(|redis_error| log::error!("get_value - value can't be read - key: {key} - error: {redis_error}"))
+            .ok()
+            .flatten()
+    }
+
+    /// Stores [&str] value by [&str] key and [u64] TTL (in seconds)
+    async fn set_value(&self, key: &str, value: &str, ttl_seconds: u64) {
+        use crate::constant::constants::REDIS_KEY_PREFIX;
+        use redis::AsyncCommands;
+
+        let Some(mut redis_connection) = self.get_connection().await else {
+            return;
+        };
+        if let Err(redis_error) = redis_connection
+            .set_ex::<_, _, ()>(format!("{REDIS_KEY_PREFIX}{key}"), value, ttl_seconds.max(1))
+            .await
+        {
+            log::error!("set_value - value can't be stored - key: {key} - error: {redis_error}");
+        }
+    }
+
+    /// Removes all stored values by [&str] key prefix
+    /// Returns [usize] removed values quantity
+    async fn remove_values(&self, key_prefix: &str) -> usize {
+        use crate::constant::constants::REDIS_KEY_PREFIX;
+        use redis::AsyncCommands;
+
+        let redis_keys: Vec<String> = self
+            .get_keys(key_prefix)
+            .await
+            .into_iter()
+            .map(|key| format!("{REDIS_KEY_PREFIX}{key}"))
+            .collect();
+        let Some(mut redis_connection) = self.get_connection().await.filter(|_| !redis_keys.is_empty()) else {
+            return 0;
+        };
+
+        redis_connection
+            .del::<_, usize>(redis_keys)
+            .await
+            .inspect_err(|redis_error| log::error!("remove_values - keys can't be removed - key prefix: {key_prefix} - error: {redis_error}"))
+            .unwrap_or_default()
+    }
+
+    /// Gets [Vec<String>] all stored keys by [&str] key prefix
+    async fn get_keys(&self, key_prefix: &str) -> Vec<String> {
+        use crate::constant::constants::REDIS_KEY_PREFIX;
+        use redis::AsyncCommands;
+
+        let Some(mut redis_connection) = self.get_connection().await else {
+            return Vec::new();
+        };
+        let escaped_key_prefix: String = key_prefix
+            .chars()
+            .flat_map(|key_char| match key_char {
+                '*' | '?' | '[' | ']' | '\\' => vec!['\\', key_char],
+                _ => vec![key_char],
+            })
+            .collect();
+        let key_pattern = format!("{REDIS_KEY_PREFIX}{escaped_key_prefix}*");
+        match redis_connection.scan_match::<_, String>(&key_pattern).await {
+            Ok(mut redis_key_iterator) => {
+                let mut keys = Vec::new();
+                while let Some(redis_key) = redis_key_iterator.next_item().await {
+                    keys.extend(redis_key.strip_prefix(REDIS_KEY_PREFIX).map(String::from));
+                }
+                keys
+            }
+            Err(redis_error) => {
+                log::error!("get_keys - keys can't be scanned - key prefix: {key_prefix} - error: {redis_error}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Acquires (or renews, if it's already owned by [&str] owner) a lease by [&str] key and [u64] TTL (in seconds)
+    /// Returns [bool] true if lease is owned by the owner (leases are always acquired if state is not shared)
+    async fn acquire_lease(&self, key: &str, owner: &str, ttl_seconds: u64) -> bool {
+        use crate::constant::constants::{REDIS_ACQUIRE_LEASE_SCRIPT, REDIS_KEY_PREFIX};
+
+        let Some(mut redis_connection) = self.get_connection().await else {
+            return false;
+        };
+        redis::cmd("EVAL")
+            .arg(REDIS_ACQUIRE_LEASE_SCRIPT)
+            .arg(1)
+            .arg(format!("{REDIS_KEY_PREFIX}{key}"))
+            .arg(owner)
+            .arg(ttl_seconds.max(1))
+            .query_async::<i64>(&mut redis_connection)
+            .await
+            .inspect_err(|redis_error| log::error!("acquire_lease - lease can't be acquired - key: {key} - error: {redis_error}"))
+            .is_ok_and(|lease_acquired| lease_acquired == 1)
+    }
+}
+
+/// Replica identifier (random - lease owner for distributed job locking)
+pub static REPLICA_ID: LazyLock<String> = LazyLock::new(|| Uuid::new_v4().to_string());
+
+/// Shared state store instance
+/// Important: Redis state store is used if `redis` feature is enabled and REDIS_URL env var is defined
+/// (please, check constants.rs), otherwise state is kept in memory by each API replica
+pub static SHARED_STATE_STORE: LazyLock<DynSharedStateStore> = LazyLock::new(create_shared_state_store);
+
+/// Creates a new [DynSharedStateStore] shared state store (please, check [SHARED_STATE_STORE])
+#[cfg(feature = "redis")]
+fn create_shared_state_store() -> DynSharedStateStore {
+    use crate::config::external_secrets::get_secret_env_var;
+    use crate::constant::constants::REDIS_URL_ENV_VAR;
+    use log::{info, warn};
+
+    let Ok(redis_url) = get_secret_env_var(REDIS_URL_ENV_VAR) else {
+        warn!("create_shared_state_store - Redis URL not defined - state is kept in memory");
+        return Arc::new(LocalStateStore {}) as DynSharedStateStore;
+    };
+
+    match redis::Client::open(redis_url) {
+        Ok(redis_client) => {
+            info!("create_shared_state_store - Redis state store enabled");
+            Arc::new(RedisStateStore {
+                redis_client,
+                redis_connection: tokio::sync::OnceCell::new(),
+            }) as DynSharedStateStore
+        }
+        Err(redis_error) => {
+            warn!("create_shared_state_store - Redis URL not valid - state is kept in memory - error: {redis_error}");
+            Arc::new(LocalStateStore {}) as DynSharedStateStore
+        }
+    }
+}
+
+/// Creates a new [DynSharedStateStore] shared state store (please, check [SHARED_STATE_STORE])
+#[cfg(not(feature = "redis"))]
+fn create_shared_state_store() -> DynSharedStateStore {
+    Arc::new(LocalStateStore {}) as DynSharedStateStore
+}
+
+/// Shared state store trait for shared instance
+pub type DynSharedStateStore = Arc<dyn SharedStateStoreTrait + Send + Sync>;
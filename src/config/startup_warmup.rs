@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use aws_sdk_s3::config::ProvideCredentials;
+use crate::config::aws_sdk_s3_client::{AwsSdkS3Client, AwsSdkS3ClientTrait};
+use crate::config::client_pool::get_base_aws_sdk_configuration;
+use crate::config::credentials_cache::CREDENTIALS_CACHE;
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_u64, get_env_var_as_usize};
+use crate::constant::constants::{STARTUP_WARMUP_BUCKETS_ENV_VAR, STARTUP_WARMUP_CONNECTIONS_DEFAULT, STARTUP_WARMUP_CONNECTIONS_ENV_VAR, STARTUP_WARMUP_ENABLED_DEFAULT, STARTUP_WARMUP_ENABLED_ENV_VAR, STARTUP_WARMUP_TIMEOUT_SECONDS_DEFAULT, STARTUP_WARMUP_TIMEOUT_SECONDS_ENV_VAR};
+use log::{info, warn};
+use tokio::task::JoinSet;
+
+/// Runs startup warm-up, if it is enabled: the pooled AWS SDK S3 client is built, its credentials are resolved (and
+/// cached) and warm connections are opened by concurrent head bucket requests to the configured S3 buckets, so the first
+/// export after boot doesn't pay cold-start latency (please, check constants.rs)
+/// Important: warm-up failures are just logged (the API server starts anyway) and warm-up is bounded by a timeout
+pub async fn run_startup_warmup() {
+    if !get_env_var_as_bool(STARTUP_WARMUP_ENABLED_ENV_VAR, STARTUP_WARMUP_ENABLED_DEFAULT) {
+        return;
+    }
+
+    info!("run_startup_warmup - start");
+    let warmup_timeout = Duration::from_secs(get_env_var_as_u64(STARTUP_WARMUP_TIMEOUT_SECONDS_ENV_VAR, STARTUP_WARMUP_TIMEOUT_SECONDS_DEFAULT));
+    match tokio::time::timeout(warmup_timeout, warm_up_aws_sdk_client()).await {
+        Ok(()) => info!("run_startup_warmup - done"),
+        Err(_) => warn!("run_startup_warmup - timed out after {} seconds - API server starts anyway", warmup_timeout.as_secs()),
+    }
+}
+
+/// Warms up the pooled AWS SDK S3 client (default region): base credentials are resolved (and cached) and configured S3 buckets are requested
+/// by as many concurrent head bucket requests as warm connections should be opened
+async fn warm_up_aws_sdk_client() {
+    let aws_sdk_client = AwsSdkS3Client::default().create_aws_sdk_client().await;
+    match get_base_aws_sdk_configuration().await.credentials_provider() {
+        Some(credentials_provider) => match CREDENTIALS_CACHE
+            .get_credentials_provider(None, Vec::new(), async { credentials_provider })
+            .await
+            .provide_credentials()
+            .await
+        {
+            Ok(_) => info!("warm_up_aws_sdk_client - credentials resolved"),
+            Err(credentials_error) => warn!("warm_up_aws_sdk_client - can't resolve credentials - error: {credentials_error}"),
+        },
+        None => warn!("warm_up_aws_sdk_client - no credentials provider found"),
+    }
+
+    let connections = get_env_var_as_usize(STARTUP_WARMUP_CONNECTIONS_ENV_VAR, STARTUP_WARMUP_CONNECTIONS_DEFAULT).max(1);
+    let bucket_names = get_warmup_bucket_names(&std::env::var(STARTUP_WARMUP_BUCKETS_ENV_VAR).unwrap_or_default());
+    let mut head_bucket_tasks = JoinSet::new();
+    for bucket_name in bucket_names {
+        for _ in 0..connections {
+            let aws_sdk_client = aws_sdk_client.clone();
+            let bucket_name = bucket_name.clone();
+            head_bucket_tasks.spawn(async move {
+                let head_bucket_result = aws_sdk_client.head_bucket().bucket(&bucket_name).send().await;
+                (bucket_name, head_bucket_result.map_err(|error| format!("{error:?}")))
+            });
+        }
+    }
+
+    let mut opened_connections = 0;
+    while let Some(head_bucket_task_result) = head_bucket_tasks.join_next().await {
+        match head_bucket_task_result {
+            Ok((_, Ok(_))) => opened_connections += 1,
+            Ok((bucket_name, Err(head_bucket_error))) => {
+                warn!("warm_up_aws_sdk_client - can't reach s3 bucket: {bucket_name} - error: {head_bucket_error}")
+            }
+            Err(join_error) => warn!("warm_up_aws_sdk_client - head bucket task failed - error: {join_error}"),
+        }
+    }
+    info!("warm_up_aws_sdk_client - warm connections opened: {opened_connections}");
+}
+
+/// Gets [Vec<String>] S3 bucket names to be warmed up by [&str] comma separated S3 bucket names (blank names are skipped)
+fn get_warmup_bucket_names(bucket_names: &str) -> Vec<String> {
+    bucket_names
+        .split(',')
+        .map(str::trim)
+        .filter(|bucket_name| !bucket_name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::startup_warmup::get_warmup_bucket_names;
+
+    /// Scenario:
+    /// Gets warm-up S3 bucket names from a comma separated list with spaces and blank names
+    /// Expectation:
+    /// Just trimmed, not blank S3 bucket names should be retrieved
+    #[test]
+    fn when_get_warmup_bucket_names_should_skip_blank_names() {
+        assert_eq!(vec!["bucket-a", "bucket-b"], get_warmup_bucket_names(" bucket-a, ,bucket-b,"));
+        assert!(get_warmup_bucket_names("").is_empty());
+    }
+}
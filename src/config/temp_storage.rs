@@ -0,0 +1,58 @@
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{TEMP_STORAGE_MIN_FREE_BYTES_DEFAULT, TEMP_STORAGE_MIN_FREE_BYTES_ENV_VAR};
+use log::warn;
+
+/// Checks temp dir is writable (a temp file is created and removed)
+/// Returns a [String] error if the temp file can't be created or removed
+pub fn check_temp_dir_writable() -> Result<String, String> {
+    let temp_file_path = std::env::temp_dir().join(format!("rust-aws-s3-downloader-check-{}", std::process::id()));
+    std::fs::write(&temp_file_path, b"check")
+        .and_then(|_| std::fs::remove_file(&temp_file_path))
+        .map(|_| format!("{} is writable", std::env::temp_dir().display()))
+        .map_err(|error| error.to_string())
+}
+
+/// Checks temp storage readiness (temp dir is writable and its free disk space is greater than the configured minimum)
+/// Returns [None] if temp storage check is disabled (minimum free disk space should be configured - please, check constants.rs)
+/// Returns a [String] error if temp dir is not writable or free disk space is under the configured minimum
+pub fn check_temp_storage() -> Option<Result<String, String>> {
+    let min_free_bytes = get_env_var_as_u64(TEMP_STORAGE_MIN_FREE_BYTES_ENV_VAR, TEMP_STORAGE_MIN_FREE_BYTES_DEFAULT);
+    if min_free_bytes == 0 {
+        return None;
+    }
+
+    let check_result = check_temp_dir_writable().and_then(|_| {
+        let free_bytes = fs4::available_space(std::env::temp_dir()).map_err(|error| error.to_string())?;
+        check_free_bytes(free_bytes, min_free_bytes)
+    });
+    if let Err(check_error) = &check_result {
+        warn!("check_temp_storage - temp storage not ready - error: {check_error}");
+    }
+    Some(check_result)
+}
+
+/// Checks [u64] free disk space (in bytes) is greater than or equal to [u64] minimum free disk space (in bytes)
+/// Returns a [String] error if free disk space is under the minimum
+fn check_free_bytes(free_bytes: u64, min_free_bytes: u64) -> Result<String, String> {
+    if free_bytes < min_free_bytes {
+        return Err(format!("temp dir free bytes {free_bytes} under minimum {min_free_bytes}"));
+    }
+    Ok(format!("temp dir free bytes {free_bytes}"))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::config::temp_storage::check_free_bytes;
+
+    /// Scenario:
+    /// Checks free disk space over and under the minimum free disk space
+    /// Expectation:
+    /// Free disk space under the minimum should be retrieved as an error
+    #[test]
+    fn when_check_free_bytes_should_fail_under_minimum() {
+        assert!(check_free_bytes(2048, 1024).is_ok());
+        assert!(check_free_bytes(1024, 1024).is_ok());
+        assert_eq!(Err(String::from("temp dir free bytes 512 under minimum 1024")), check_free_bytes(512, 1024));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::field_error::FieldError;
+use crate::dto::template_variable::TemplateVariable;
+use crate::enums::common_error::CommonError;
+use log::debug;
+use serde_json::Value;
+
+/// Gets [BTreeMap<String, String>] template variables values by [&[TemplateVariable]] declared template variables,
+/// [&BTreeMap<String, String>] trigger parameters and [i64] schedule time (seconds since unix epoch - built-in variables)
+/// Returns [Vec<FieldError>] field errors if any parameter is not a declared variable or any declared variable without
+/// default value is not defined by trigger parameters
+pub fn get_template_variable_values(
+    variables: &[TemplateVariable],
+    parameters: &BTreeMap<String, String>,
+    schedule_time: i64,
+) -> Result<BTreeMap<String, String>, Vec<FieldError>> {
+    let field_error_fn = |name: &str, message: &str| FieldError { field: format!("parameters.{name}"), message: String::from(message) };
+    let mut field_errors: Vec<FieldError> = parameters
+        .keys()
+        .filter(|name| !variables.iter().any(|variable| &variable.name == *name))
+        .map(|name| field_error_fn(name, "is not a declared template variable"))
+        .collect();
+
+    let datetime = DateTime::from_secs(schedule_time).fmt(DateTimeFormat::DateTime).unwrap_or_default();
+    let mut variable_values = BTreeMap::from([
+        (String::from("date"), datetime.chars().take(10).collect()),
+        (String::from("timestamp"), schedule_time.to_string()),
+        (String::from("datetime"), datetime),
+    ]);
+    for variable in variables {
+        match parameters.get(&variable.name).or(variable.default_value.as_ref()) {
+            Some(value) => {
+                variable_values.insert(variable.name.clone(), value.clone());
+            }
+            None => field_errors.push(field_error_fn(&variable.name, "must be defined (variable without default value)")),
+        }
+    }
+
+    match field_errors.is_empty() {
+        true => Ok(variable_values),
+        false => Err(field_errors),
+    }
+}
+
+/// Gets [DownloadRequest] resolved download request by [&DownloadRequest] saved export template request and
+/// [&BTreeMap<String, String>] template variables values (`{name}` placeholders are replaced in all string fields -
+/// i.e.: S3 path, manifest S3 keys or filter patterns - not declared placeholders are kept as they are)
+/// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if resolved fields are not valid (i.e.: enum values)
+pub fn resolve_template_request(
+    download_request: &DownloadRequest,
+    variable_values: &BTreeMap<String, String>,
+) -> Result<DownloadRequest, CommonError> {
+    let mut request_json = serde_json::to_value(download_request).map_err(|_| CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+    replace_template_variables(&mut request_json, variable_values);
+    serde_json::from_value(request_json).map_err(|resolve_error| {
+        debug!("resolve_template_request - resolved request not valid - error: {resolve_error}");
+        CommonError::NO_VALID_INPUT_OR_PARAMETER
+    })
+}
+
+/// Replaces `{name}` placeholders in all [&mut Value] JSON string values (nested objects and arrays included) by
+/// [&BTreeMap<String, String>] template variables values
+fn replace_template_variables(json_value: &mut Value, variable_values: &BTreeMap<String, String>) {
+    match json_value {
+        Value::String(text) if text.contains('{') => {
+            for (name, value) in variable_values {
+                *text = text.replace(&format!("{{{name}}}"), value);
+            }
+        }
+        Value::Array(json_values) => json_values
+            .iter_mut()
+            .for_each(|json_value| replace_template_variables(json_value, variable_values)),
+        Value::Object(json_fields) => json_fields
+            .values_mut()
+            .for_each(|json_value| replace_template_variables(json_value, variable_values)),
+        _ => {}
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::config::template_variables::{get_template_variable_values, resolve_template_request};
+    use crate::dto::download_request::DownloadRequest;
+    use crate::dto::field_error::FieldError;
+    use crate::dto::manifest_entry::ManifestEntry;
+    use crate::dto::template_variable::TemplateVariable;
+    use crate::enums::export_filter::ExportFilter;
+
+    /// Scenario:
+    /// Resolves an export template request with a customer variable (trigger parameter), a region variable (default value)
+    /// and the built-in date variable in the S3 path, manifest S3 keys and filter, and then triggers it with a not declared
+    /// parameter and without the required customer variable
+    /// Expectation:
+    /// All placeholders should be replaced (not declared placeholders are kept) and a field error should be retrieved
+    /// for each not valid parameter
+    #[test]
+    fn when_resolve_template_request_should_replace_variables() {
+        let variables = vec![
+            TemplateVariable { name: String::from("customer_id"), default_value: None },
+            TemplateVariable { name: String::from("region"), default_value: Some(String::from("eu")) },
+        ];
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("{region}/customers/{customer_id}"),
+            manifest: Some(vec![ManifestEntry { s3_key: String::from("report-{date}.csv"), archive_name: None }]),
+            filter: Some(ExportFilter::NAME(String::from("{unknown}-*"))),
+            ..Default::default()
+        };
+
+        let parameters = BTreeMap::from([(String::from("customer_id"), String::from("42"))]);
+        let variable_values = get_template_variable_values(&variables, &parameters, 1_769_853_600).unwrap();
+        let resolved_request = resolve_template_request(&download_request, &variable_values).unwrap();
+        assert_eq!("eu/customers/42", resolved_request.full_path);
+        assert_eq!("report-2026-01-31.csv", resolved_request.manifest.unwrap()[0].s3_key);
+        assert_eq!(Some(ExportFilter::NAME(String::from("{unknown}-*"))), resolved_request.filter);
+        assert_eq!("2026-01-31T10:00:00Z", variable_values["datetime"]);
+
+        let parameters = BTreeMap::from([(String::from("customer"), String::from("42"))]);
+        let field_fn = |field: &str, message: &str| FieldError { field: String::from(field), message: String::from(message) };
+        assert_eq!(
+            Err(vec![
+                field_fn("parameters.customer", "is not a declared template variable"),
+                field_fn("parameters.customer_id", "must be defined (variable without default value)"),
+            ]),
+            get_template_variable_values(&variables, &parameters, 1_769_853_600)
+        );
+    }
+}
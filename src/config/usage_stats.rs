@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::retention_cleanup::RETENTION_CLEANUP_METRICS;
+use crate::constant::constants::{USAGE_STATS_TOP_BUCKETS_QUANTITY, USAGE_STATS_WINDOW_SECONDS_DEFAULT, USAGE_STATS_WINDOW_SECONDS_ENV_VAR};
+use crate::dto::usage_stats::{BucketUsageStats, UsageStats};
+
+/// Usage stats registry struct (rolling window of finished exports)
+pub struct UsageStatsRegistry {
+    window: Duration,
+    exports: Mutex<VecDeque<ExportUsage>>,
+    aborted_exports: Mutex<VecDeque<Instant>>,
+}
+
+/// Finished export usage (registry entry)
+struct ExportUsage {
+    finished_at: Instant,
+    bucket_name: String,
+    bytes: u64,
+    failed: bool,
+}
+
+/// Usage stats registry shared instance (rolling window should be configured - please, check constants.rs)
+pub static USAGE_STATS_REGISTRY: LazyLock<UsageStatsRegistry> = LazyLock::new(|| {
+    UsageStatsRegistry::new(Duration::from_secs(get_env_var_as_u64(
+        USAGE_STATS_WINDOW_SECONDS_ENV_VAR,
+        USAGE_STATS_WINDOW_SECONDS_DEFAULT,
+    )))
+});
+
+impl UsageStatsRegistry {
+    /// Creates a new [UsageStatsRegistry] by [Duration] rolling window
+    pub fn new(window: Duration) -> Self {
+        UsageStatsRegistry {
+            window,
+            exports: Mutex::new(VecDeque::new()),
+            aborted_exports: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Records a finished export by [&str] S3 bucket name, [u64] transferred bytes and [bool] failed flag
+    pub fn record_export(&self, bucket_name: &str, bytes: u64, failed: bool) {
+        let mut exports = self.exports.lock().unwrap();
+        exports.push_back(ExportUsage {
+            finished_at: Instant::now(),
+            bucket_name: String::from(bucket_name),
+            bytes,
+            failed,
+        });
+        self.remove_expired_exports(&mut exports);
+    }
+
+    /// Records an export aborted by a disconnected client (streamed response body dropped before being completely sent)
+    pub fn record_aborted_export(&self) {
+        let mut aborted_exports = self.aborted_exports.lock().unwrap();
+        aborted_exports.push_back(Instant::now());
+        self.remove_expired_aborted_exports(&mut aborted_exports);
+    }
+
+    /// Gets [UsageStats] usage stats summary for the rolling window (retention cleanup counters included)
+    pub fn get_usage_stats(&self) -> UsageStats {
+        let mut aborted_exports = self.aborted_exports.lock().unwrap();
+        self.remove_expired_aborted_exports(&mut aborted_exports);
+        let exports_aborted = aborted_exports.len() as u64;
+        drop(aborted_exports);
+
+        let mut exports = self.exports.lock().unwrap();
+        self.remove_expired_exports(&mut exports);
+
+        let mut bucket_usage_stats: HashMap<&str, BucketUsageStats> = HashMap::new();
+        exports.iter().for_each(|export_usage| {
+            let bucket_stats = bucket_usage_stats
+                .entry(&export_usage.bucket_name)
+                .or_insert_with(|| BucketUsageStats {
+                    bucket_name: export_usage.bucket_name.clone(),
+                    ..Default::default()
+                });
+            bucket_stats.exports_total += 1;
+            bucket_stats.bytes_total += export_usage.bytes;
+        });
+        let mut top_buckets: Vec<BucketUsageStats> = bucket_usage_stats.into_values().collect();
+        top_buckets.sort_by(|a, b| b.exports_total.cmp(&a.exports_total).then_with(|| a.bucket_name.cmp(&b.bucket_name)));
+        top_buckets.truncate(USAGE_STATS_TOP_BUCKETS_QUANTITY);
+
+        let exports_total = exports.len() as u64;
+        let exports_failed = exports.iter().filter(|export_usage| export_usage.failed).count() as u64;
+        UsageStats {
+            window_seconds: self.window.as_secs(),
+            exports_served: exports_total - exports_failed,
+            exports_failed,
+            exports_aborted,
+            error_rate: if exports_total == 0 { 0.0 } else { exports_failed as f64 / exports_total as f64 },
+            bytes_transferred: exports.iter().map(|export_usage| export_usage.bytes).sum(),
+            top_buckets,
+            retention_cleanup: RETENTION_CLEANUP_METRICS.get_retention_cleanup_stats(),
+        }
+    }
+
+    /// Removes all exports out of the rolling window from [VecDeque<ExportUsage>] registered exports
+    fn remove_expired_exports(&self, exports: &mut VecDeque<ExportUsage>) {
+        while exports
+            .front()
+            .is_some_and(|export_usage| export_usage.finished_at.elapsed() > self.window)
+        {
+            exports.pop_front();
+        }
+    }
+
+    /// Removes all aborted exports out of the rolling window from [VecDeque<Instant>] registered aborted exports
+    fn remove_expired_aborted_exports(&self, aborted_exports: &mut VecDeque<Instant>) {
+        while aborted_exports.front().is_some_and(|aborted_at| aborted_at.elapsed() > self.window) {
+            aborted_exports.pop_front();
+        }
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::config::retention_cleanup::RETENTION_CLEANUP_METRICS;
+    use crate::config::usage_stats::UsageStatsRegistry;
+
+    /// Scenario:
+    /// Records served and failed exports for two S3 buckets and an export aborted by a disconnected client
+    /// Expectation:
+    /// Usage stats with counters, error rate and top buckets sorted by exports should be retrieved
+    #[test]
+    fn when_get_usage_stats_should_retrieve_rolling_counters() {
+        let usage_stats_registry = UsageStatsRegistry::new(Duration::from_secs(60));
+        usage_stats_registry.record_export("bucket-a", 100, false);
+        usage_stats_registry.record_export("bucket-b", 50, false);
+        usage_stats_registry.record_export("bucket-b", 0, true);
+        usage_stats_registry.record_export("bucket-b", 25, false);
+        usage_stats_registry.record_aborted_export();
+
+        let usage_stats = usage_stats_registry.get_usage_stats();
+
+        assert_eq!(3, usage_stats.exports_served);
+        assert_eq!(1, usage_stats.exports_failed);
+        assert_eq!(1, usage_stats.exports_aborted);
+        assert_eq!(0.25, usage_stats.error_rate);
+        assert_eq!(175, usage_stats.bytes_transferred);
+        assert_eq!("bucket-b", usage_stats.top_buckets[0].bucket_name);
+        assert_eq!(3, usage_stats.top_buckets[0].exports_total);
+    }
+
+    /// Scenario:
+    /// Records removed S3 objects by the retention cleanup shared metrics
+    /// Expectation:
+    /// Usage stats should expose the retention cleanup counters
+    #[test]
+    fn when_get_usage_stats_should_retrieve_retention_cleanup_counters() {
+        let usage_stats_registry = UsageStatsRegistry::new(Duration::from_secs(60));
+        RETENTION_CLEANUP_METRICS.record_removed_objects(1, 64);
+
+        let usage_stats = usage_stats_registry.get_usage_stats();
+
+        assert!(usage_stats.retention_cleanup.removed_objects_total >= 1);
+        assert!(usage_stats.retention_cleanup.reclaimed_object_bytes_total >= 64);
+    }
+}
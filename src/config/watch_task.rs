@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{WATCH_INTERVAL_SECONDS_DEFAULT, WATCH_INTERVAL_SECONDS_ENV_VAR};
+use crate::service::watch_service::DynWatchService;
+use log::{debug, info};
+
+/// Starts a background task that archives new S3 objects from watched S3 prefixes by [DynWatchService] watch service (watch mode)
+/// Important: watched S3 prefixes, destination S3 bucket and polling interval should be configured
+/// (please, check constants.rs) - the task is not started if watch mode is not configured
+pub fn start_watch_task(watch_service: DynWatchService) {
+    if !watch_service.is_enabled() {
+        debug!("start_watch_task - watch mode not configured");
+        return;
+    }
+
+    let watch_interval_seconds = get_env_var_as_u64(WATCH_INTERVAL_SECONDS_ENV_VAR, WATCH_INTERVAL_SECONDS_DEFAULT).max(1);
+    info!("start_watch_task - watch interval (seconds): {watch_interval_seconds}");
+
+    tokio::spawn(async move {
+        let mut watch_interval = tokio::time::interval(Duration::from_secs(watch_interval_seconds));
+        loop {
+            watch_interval.tick().await;
+            let archived_objects_total = watch_service.archive_new_objects().await;
+            if archived_objects_total > 0 {
+                info!("watch_task - archived objects: {archived_objects_total}");
+            }
+        }
+    });
+}
@@ -11,6 +11,38 @@ pub const API_SERVER_PORT_ENV_VAR: &str = "API_SERVER_PORT";
 pub const API_SERVER_HOST_DEFAULT: &str = "0.0.0.0";
 /// API server port default value
 pub const API_SERVER_PORT_DEFAULT: &str = "8097";
+/// API server additional TCP addresses (comma separated "host:port" values) environment variable name
+pub const API_SERVER_ADDITIONAL_ADDRESSES_ENV_VAR: &str = "API_SERVER_ADDITIONAL_ADDRESSES";
+/// API server HTTP/2 connections enabled flag environment variable name and default value (HTTP/1 only if it is disabled)
+pub const API_SERVER_HTTP2_ENABLED_ENV_VAR: &str = "API_SERVER_HTTP2_ENABLED";
+pub const API_SERVER_HTTP2_ENABLED_DEFAULT: &str = "true";
+/// API server HTTP/2 maximum concurrent streams per connection environment variable name and default value
+pub const API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_ENV_VAR: &str = "API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS";
+pub const API_SERVER_HTTP2_MAX_CONCURRENT_STREAMS_DEFAULT: &str = "200";
+/// API server HTTP/2 keep-alive pings interval (in seconds) environment variable name and default value (zero disables pings)
+pub const API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR: &str = "API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS";
+pub const API_SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT: &str = "0";
+/// API server HTTP/2 keep-alive pings timeout (in seconds) environment variable name and default value
+pub const API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_ENV_VAR: &str = "API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS";
+pub const API_SERVER_HTTP2_KEEP_ALIVE_TIMEOUT_SECONDS_DEFAULT: &str = "20";
+/// API server HTTP/1 persistent connections enabled flag environment variable name and default value
+pub const API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_ENV_VAR: &str = "API_SERVER_HTTP1_KEEP_ALIVE_ENABLED";
+pub const API_SERVER_HTTP1_KEEP_ALIVE_ENABLED_DEFAULT: &str = "true";
+/// API server TCP_NODELAY flag (accepted TCP connections) environment variable name and default value
+pub const API_SERVER_TCP_NODELAY_ENABLED_ENV_VAR: &str = "API_SERVER_TCP_NODELAY_ENABLED";
+pub const API_SERVER_TCP_NODELAY_ENABLED_DEFAULT: &str = "false";
+/// API server Unix domain socket path (i.e.: for sidecar deployments - Unix platforms only) environment variable name
+pub const API_SERVER_UNIX_SOCKET_PATH_ENV_VAR: &str = "API_SERVER_UNIX_SOCKET_PATH";
+/// API base path (all end-points are mounted under this prefix, i.e.: "/s3-exporter") environment variable name
+pub const API_BASE_PATH_ENV_VAR: &str = "API_BASE_PATH";
+/// API forwarded headers (`X-Forwarded-Proto`, `X-Forwarded-Host` and `X-Forwarded-Prefix`) trusted flag
+/// (used to build links sent out of the API) environment variable name and default value
+pub const API_FORWARDED_HEADERS_ENABLED_ENV_VAR: &str = "API_FORWARDED_HEADERS_ENABLED";
+pub const API_FORWARDED_HEADERS_ENABLED_DEFAULT: &str = "false";
+/// API forwarded request headers (set by reverse proxies)
+pub const FORWARDED_PROTO_HEADER: &str = "x-forwarded-proto";
+pub const FORWARDED_HOST_HEADER: &str = "x-forwarded-host";
+pub const FORWARDED_PREFIX_HEADER: &str = "x-forwarded-prefix";
 /// server running status message
 pub const SERVER_RUNNING_STATUS: &str = "server is running";
 /// delete entity status message
@@ -18,13 +50,106 @@ pub const DELETE_OK_STATUS: &str = "deleted ok";
 
 /// API Health-check main path
 pub const API_HEALTH_CHECK_PATH: &str = "/health";
+/// API readiness check path (temp storage checks)
+pub const API_READINESS_CHECK_PATH: &str = "/health/ready";
+/// server ready status message
+pub const SERVER_READY_STATUS: &str = "server is ready";
 
 /// API main path
 pub const API_MAIN_PATH: &str = "/api/v1";
+/// API v1 sunset date (HTTP-date, i.e.: "Thu, 31 Dec 2026 23:59:59 GMT" - sent by `Sunset` response header
+/// on deprecated v1 end-points) environment variable name
+pub const API_V1_SUNSET_DATE_ENV_VAR: &str = "API_V1_SUNSET_DATE";
+
+/// API v2 main path (job-first export flow with typed errors)
+pub const API_V2_MAIN_PATH: &str = "/api/v2";
+pub const API_V2_EXPORTS_PATH: &str = "/exports";
+pub const API_V2_EXPORT_BY_ID_PATH: &str = "/exports/{job_id}";
+pub const API_V2_EXPORT_RESULT_PATH: &str = "/exports/results/{resume_token}";
+
+/// API S3 key (or S3 prefix) exists path
+pub const API_EXISTS_PATH: &str = "/exists";
+
+/// API S3 objects listing (paginated) path
+pub const API_LIST_PATH: &str = "/list";
+/// API S3 objects listing maximum S3 objects per page (S3 ListObjectsV2 limit)
+pub const API_LIST_MAX_KEYS_LIMIT: i32 = 1000;
+
+/// API S3 objects listing as NDJSON stream path
+pub const API_LIST_STREAM_PATH: &str = "/list/stream";
+
+/// API S3 prefixes diff path (S3 prefix compared against another S3 prefix or a manifest)
+pub const API_DIFF_PATH: &str = "/diff";
+/// API S3 prefixes diff maximum S3 objects listed by side environment variable name and default value
+pub const DIFF_MAX_KEYS_ENV_VAR: &str = "DIFF_MAX_KEYS";
+pub const DIFF_MAX_KEYS_DEFAULT: &str = "100000";
+
+/// API admin token (`Authorization: Bearer {token}` request header) environment variable name
+/// Important: admin end-points are disabled (forbidden status) if it is not defined
+pub const ADMIN_API_TOKEN_ENV_VAR: &str = "ADMIN_API_TOKEN";
+/// API admin paths prefix
+pub const API_ADMIN_PATH_PREFIX: &str = "/admin/";
+/// API admin listing cache invalidation path
+pub const API_ADMIN_CACHE_INVALIDATE_PATH: &str = "/admin/cache/invalidate";
+/// API admin resolved configuration path
+pub const API_ADMIN_CONFIG_PATH: &str = "/admin/config";
+/// API admin maintenance mode path
+pub const API_ADMIN_MAINTENANCE_PATH: &str = "/admin/maintenance";
+/// API admin feature flags path
+pub const API_ADMIN_FEATURE_FLAGS_PATH: &str = "/admin/feature-flags";
+
+/// API S3 event notifications ingestion path
+pub const API_S3_EVENTS_PATH: &str = "/api/v1/events/s3";
+
+/// API usage stats path
+pub const API_STATS_PATH: &str = "/api/v1/stats";
+pub const API_STATS_QUOTAS_PATH: &str = "/api/v1/stats/quotas";
+
+/// API version (build info) path
+pub const API_VERSION_PATH: &str = "/api/v1/version";
 
 /// API Download main path
 pub const API_DOWNLOAD_MAIN_PATH: &str = "/api/v1/download";
 pub const API_DOWNLOAD_ALL_AS_ZIP_PATH: &str = "/zip";
+/// S3-hosted archive (ZIP or tar) entries listing and selective extraction paths
+pub const API_DOWNLOAD_ARCHIVE_ENTRIES_PATH: &str = "/archive/entries";
+pub const API_DOWNLOAD_ARCHIVE_EXTRACT_PATH: &str = "/archive/extract";
+pub const API_DOWNLOAD_BATCH_PATH: &str = "/batch";
+pub const API_DOWNLOAD_ESTIMATE_PATH: &str = "/estimate";
+pub const API_DOWNLOAD_KEYS_AS_ZIP_PATH: &str = "/zip/keys";
+/// Single S3 object proxy end-point path (GET by query params - HTTP range requests are forwarded to S3)
+pub const API_DOWNLOAD_OBJECT_PATH: &str = "/object";
+/// Image S3 object thumbnail end-point path (GET by query params - `thumbnails` feature)
+pub const API_DOWNLOAD_THUMBNAIL_PATH: &str = "/thumbnail";
+/// Text S3 object preview end-point path (GET by query params - first bytes by a ranged request)
+pub const API_DOWNLOAD_TEXT_PREVIEW_PATH: &str = "/text";
+/// Single S3 object end-points paths scoped by query params (access control and multi-tenancy)
+pub const API_DOWNLOAD_OBJECT_QUERY_PATHS: [&str; 3] = [API_DOWNLOAD_OBJECT_PATH, API_DOWNLOAD_THUMBNAIL_PATH, API_DOWNLOAD_TEXT_PREVIEW_PATH];
+pub const API_DOWNLOAD_PREVIEW_PATH: &str = "/preview";
+/// Full-text search end-point path (text S3 objects under a S3 folder)
+pub const API_DOWNLOAD_SEARCH_PATH: &str = "/search";
+pub const API_DOWNLOAD_SIGNED_PATH: &str = "/signed";
+/// Download token exchange end-point path (one-time tokens for browser downloads by GET zip end-point)
+pub const API_DOWNLOAD_TOKENS_PATH: &str = "/tokens";
+
+/// API Download jobs paths (async export + stored result)
+pub const API_DOWNLOAD_JOBS_PATH: &str = "/jobs";
+pub const API_DOWNLOAD_JOB_BY_ID_PATH: &str = "/jobs/{job_id}";
+pub const API_DOWNLOAD_JOB_RESULT_PATH: &str = "/jobs/results/{resume_token}";
+pub const API_DOWNLOAD_JOB_RERUN_PATH: &str = "/jobs/{job_id}/rerun";
+/// API Download export templates paths (saved export requests triggered by template identifier)
+pub const API_DOWNLOAD_TEMPLATES_PATH: &str = "/templates";
+pub const API_DOWNLOAD_TEMPLATE_BY_ID_PATH: &str = "/templates/{template_id}";
+pub const API_DOWNLOAD_TEMPLATE_JOBS_PATH: &str = "/templates/{template_id}/jobs";
+/// API Download export history path (finished download jobs audit records)
+pub const API_DOWNLOAD_HISTORY_PATH: &str = "/history";
+/// API Download content-addressable artifacts path (stable URLs of stored job results)
+pub const API_DOWNLOAD_ARTIFACT_PATH: &str = "/artifacts/{artifact_name}";
+
+/// AWS S3 bucket name length limits and S3 key maximum length (in bytes) - requests validation
+pub const S3_BUCKET_NAME_MIN_LENGTH: usize = 3;
+pub const S3_BUCKET_NAME_MAX_LENGTH: usize = 63;
+pub const S3_KEY_MAX_LENGTH: usize = 1024;
 
 /// AWS S3 max files supported environment variable and default value
 pub const AWS_S3_MAX_FILE_QUANTITY_ENV_VAR: &str = "AWS_S3_MAX_FILE_QUANTITY";
@@ -34,6 +159,542 @@ pub const AWS_S3_MAX_FILE_QUANTITY_DEFAULT: &str = "100";
 pub const AWS_S3_MAX_FILE_SIZE_BYTES_ENV_VAR: &str = "AWS_S3_MAX_FILE_SIZE_BYTES";
 pub const AWS_S3_MAX_FILE_SIZE_BYTES_DEFAULT: &str = "2097152"; // ((bytes * 1024 = KB) * 1024 = MB)
 
+/// AWS S3 oversize files (greater than max file size) requests enabled flag environment variable and default value
+/// Important: `allow_oversize` requests are rejected if it is disabled (and require oversize operation if RBAC is enabled)
+pub const AWS_S3_ALLOW_OVERSIZE_ENABLED_ENV_VAR: &str = "AWS_S3_ALLOW_OVERSIZE_ENABLED";
+pub const AWS_S3_ALLOW_OVERSIZE_ENABLED_DEFAULT: &str = "false";
+
+/// AWS S3 fetch window size (parallel downloads + completed downloads waiting for previous objects
+/// to keep listing order) environment variable and default value
+pub const AWS_S3_FETCH_WINDOW_SIZE_ENV_VAR: &str = "AWS_S3_FETCH_WINDOW_SIZE";
+pub const AWS_S3_FETCH_WINDOW_SIZE_DEFAULT: &str = "8";
+
+/// AWS S3 max multipart chunk size (in bytes) for ranged downloads environment variable and default value
+pub const AWS_S3_MAX_MULTIPART_CHUNK_BYTES_ENV_VAR: &str = "AWS_S3_MAX_MULTIPART_CHUNK_BYTES";
+pub const AWS_S3_MAX_MULTIPART_CHUNK_BYTES_DEFAULT: &str = "8388608"; // 8 MB
+/// AWS S3 min multipart chunk size (in bytes) for ranged downloads
+pub const AWS_S3_MULTIPART_CHUNK_BYTES_MIN: u64 = 65536; // 64 KB
+/// AWS S3 min object size (in bytes) to stream zip entries chunk by chunk (instead of buffering whole S3 objects)
+/// environment variable and default value
+pub const AWS_S3_STREAM_MIN_BYTES_ENV_VAR: &str = "AWS_S3_STREAM_MIN_BYTES";
+pub const AWS_S3_STREAM_MIN_BYTES_DEFAULT: &str = "8388608"; // 8 MB
+
+/// AWS S3 downloaded objects channel capacity (objects waiting to be added to the zip file)
+pub const AWS_S3_OBJECT_CHANNEL_CAPACITY: usize = 4;
+
+/// Download job result retention (in seconds) environment variable and default value
+pub const DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_RESULT_RETENTION_SECONDS";
+pub const DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_DEFAULT: &str = "3600";
+
+/// Export history records retention (in seconds) environment variable and default value
+pub const EXPORT_HISTORY_RETENTION_SECONDS_ENV_VAR: &str = "EXPORT_HISTORY_RETENTION_SECONDS";
+pub const EXPORT_HISTORY_RETENTION_SECONDS_DEFAULT: &str = "7776000"; // 90 days
+/// Export history maximum records kept by each API replica (oldest records are removed first - shared state store records
+/// are just removed by retention)
+pub const EXPORT_HISTORY_MAX_RECORDS: usize = 10_000;
+/// Export history page size default value and maximum value
+pub const EXPORT_HISTORY_PAGE_SIZE_DEFAULT: usize = 50;
+pub const EXPORT_HISTORY_PAGE_SIZE_LIMIT: usize = 500;
+
+/// Content-addressable artifact store S3 bucket environment variable name (job results are stored under content-hash
+/// S3 keys - artifact store is disabled if it is not defined)
+pub const ARTIFACT_STORE_BUCKET_ENV_VAR: &str = "ARTIFACT_STORE_BUCKET";
+/// Content-addressable artifact store S3 path environment variable and default value
+pub const ARTIFACT_STORE_PATH_ENV_VAR: &str = "ARTIFACT_STORE_PATH";
+pub const ARTIFACT_STORE_PATH_DEFAULT: &str = "artifacts";
+/// Exports written to S3 (artifact store, watch and S3 event destinations) SSE-KMS key identifier environment variable
+/// name (KMS key ID, ARN or alias - exports are uploaded with the bucket default encryption if it is not defined)
+pub const EXPORT_SSE_KMS_KEY_ID_ENV_VAR: &str = "EXPORT_SSE_KMS_KEY_ID";
+/// Exports written to S3 client-side envelope encryption KMS key identifier environment variable name (KMS key ID, ARN
+/// or alias - exports are encrypted by AES-256-GCM with a KMS generated data key, wrapped data key stored in S3 object
+/// metadata - requires kms-envelope feature)
+pub const EXPORT_KMS_ENVELOPE_KEY_ID_ENV_VAR: &str = "EXPORT_KMS_ENVELOPE_KEY_ID";
+/// Client-side envelope encryption S3 object metadata names (x-amz-meta-* - AWS S3 Encryption Client V2 format) and values
+pub const ENVELOPE_WRAPPED_KEY_METADATA: &str = "x-amz-key-v2";
+pub const ENVELOPE_IV_METADATA: &str = "x-amz-iv";
+pub const ENVELOPE_CONTENT_ALGORITHM_METADATA: &str = "x-amz-cek-alg";
+pub const ENVELOPE_WRAP_ALGORITHM_METADATA: &str = "x-amz-wrap-alg";
+pub const ENVELOPE_MATERIAL_DESCRIPTION_METADATA: &str = "x-amz-matdesc";
+pub const ENVELOPE_TAG_LENGTH_METADATA: &str = "x-amz-tag-len";
+pub const ENVELOPE_CONTENT_ALGORITHM: &str = "AES/GCM/NoPadding";
+pub const ENVELOPE_WRAP_ALGORITHM: &str = "kms+context";
+pub const ENVELOPE_TAG_LENGTH_BITS: &str = "128";
+/// Client-side envelope encryption KMS encryption context key (bound to the content algorithm)
+pub const ENVELOPE_ENCRYPTION_CONTEXT_KEY: &str = "aws:x-amz-cek-alg";
+
+/// Download job wait time (in seconds) before retrying to start a job queued by memory budget
+pub const DOWNLOAD_JOB_MEMORY_BUDGET_WAIT_SECONDS: u64 = 1;
+
+/// Download job retention cleanup interval (in seconds) environment variable and default value
+pub const DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS";
+pub const DOWNLOAD_JOB_CLEANUP_INTERVAL_SECONDS_DEFAULT: &str = "60";
+/// Exports written to S3 (artifact store, watch and S3 event destinations) retention window (in seconds) environment
+/// variable and default value (older exports are removed by the retention cleanup task - exports are kept if it is zero)
+pub const EXPORT_OBJECT_RETENTION_SECONDS_ENV_VAR: &str = "EXPORT_OBJECT_RETENTION_SECONDS";
+pub const EXPORT_OBJECT_RETENTION_SECONDS_DEFAULT: &str = "0";
+
+/// Export memory budget (in bytes buffered across all in-flight exports) environment variable and default value
+pub const EXPORT_MEMORY_BUDGET_BYTES_ENV_VAR: &str = "EXPORT_MEMORY_BUDGET_BYTES";
+pub const EXPORT_MEMORY_BUDGET_BYTES_DEFAULT: &str = "268435456"; // 256 MB
+/// Export memory budget exceeded retry after (in seconds) header value
+pub const EXPORT_MEMORY_BUDGET_RETRY_AFTER_SECONDS: &str = "5";
+/// Streamed export keep-alive interval (in seconds) environment variable name and default value: exports not started
+/// within it (i.e.: long S3 listings) send response headers and keep-alive bytes while they are started (zero disables it)
+pub const EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR: &str = "EXPORT_KEEP_ALIVE_INTERVAL_SECONDS";
+pub const EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT: &str = "10";
+/// Streamed export keep-alive bytes (sent before the zip content - zip readers locate entries by the central directory)
+pub const EXPORT_KEEP_ALIVE_BYTES: &[u8] = b"\n";
+/// Export report slowest files quantity
+pub const EXPORT_REPORT_SLOWEST_FILES_QUANTITY: usize = 5;
+
+/// Download request manifest maximum entries environment variable name
+pub const DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR: &str = "DOWNLOAD_MANIFEST_MAX_ENTRIES";
+/// Download request manifest maximum entries default value
+pub const DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT: &str = "1000";
+
+/// Download signed links HMAC secret environment variable name (a random secret is used if it is not defined)
+pub const DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR: &str = "DOWNLOAD_SIGNED_LINK_SECRET";
+/// Download signed links maximum expiration (in seconds) environment variable name
+pub const DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_ENV_VAR: &str = "DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS";
+/// Download signed links maximum expiration (in seconds) default value
+pub const DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_DEFAULT: &str = "86400"; // 1 day
+/// Download signed links expiration (in seconds) if it is not requested
+pub const DOWNLOAD_SIGNED_LINK_EXPIRATION_SECONDS_DEFAULT: u64 = 3600;
+/// Download signed links replay protection enabled flag environment variable name and default value
+/// (if it is enabled, each signed link nonce is accepted just once - captured links can't be reused)
+pub const DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_ENV_VAR: &str = "DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED";
+pub const DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_DEFAULT: &str = "true";
+/// Download signed links replay cache nonce scope
+pub const DOWNLOAD_SIGNED_LINK_NONCE_SCOPE: &str = "signed-link";
+/// Download tokens TTL (in seconds) environment variable name and default value (one-time tokens for browser downloads)
+pub const DOWNLOAD_TOKEN_TTL_SECONDS_ENV_VAR: &str = "DOWNLOAD_TOKEN_TTL_SECONDS";
+pub const DOWNLOAD_TOKEN_TTL_SECONDS_DEFAULT: &str = "60";
+/// Download tokens replay cache nonce scope
+pub const DOWNLOAD_TOKEN_NONCE_SCOPE: &str = "download-token";
+
+/// Zip export file name (streamed exports without metadata report, checksum audit or encryption)
+pub const ZIP_EXPORT_FILE_NAME: &str = "s3-export.zip";
+/// Zip file reserved folder for export entries (exported files can't be named under it - no collisions with S3 keys)
+pub const ZIP_RESERVED_FOLDER: &str = ".s3-export/";
+/// Zip file entry name for deduplicated files manifest
+pub const ZIP_DEDUP_MANIFEST_FILE_NAME: &str = ".s3-export/dedup-manifest.txt";
+/// Zip file entry name for missing files (S3 keys deleted after listing)
+pub const ZIP_MISSING_FILES_FILE_NAME: &str = ".s3-export/missing-files.txt";
+/// Zip file entry name for excluded files (S3 keys greater than the maximum file size)
+pub const ZIP_EXCLUDED_FILES_FILE_NAME: &str = ".s3-export/excluded.txt";
+/// Excluded files total response header name
+pub const EXCLUDED_COUNT_HEADER: &str = "x-excluded-count";
+/// Archive SHA-256 checksum (hex encoded) response header name (sent as trailer header by streamed exports)
+pub const ARCHIVE_SHA256_HEADER: &str = "x-archive-sha256";
+/// Archive detached ed25519 signature (base64 encoded) response header name (sent as trailer header by streamed exports)
+pub const ARCHIVE_SIGNATURE_HEADER: &str = "x-archive-signature";
+/// Archive signing key (base64 encoded ed25519 32 bytes seed) environment variable name (archives are not signed
+/// if it is not defined)
+pub const ARCHIVE_SIGNING_KEY_ENV_VAR: &str = "ARCHIVE_SIGNING_KEY";
+
+/// Metadata report file name (without extension - `.csv` or `.json` by requested format)
+pub const METADATA_REPORT_FILE_NAME: &str = "s3-inventory";
+/// Metadata report CSV header row
+pub const METADATA_REPORT_CSV_HEADER: &str = "s3_key,size_bytes,last_modified,e_tag,storage_class,tags";
+
+/// Checksum audit report file name
+pub const CHECKSUM_AUDIT_FILE_NAME: &str = "s3-checksum-audit.json";
+
+/// Archive extraction file name (selected entries of a S3-hosted archive)
+pub const ARCHIVE_EXTRACTION_FILE_NAME: &str = "s3-archive-entries.zip";
+/// S3-hosted archives ranged reads size (in bytes - smaller reads are extended to it and buffered) environment
+/// variable name and default value
+pub const ARCHIVE_READ_AHEAD_BYTES_ENV_VAR: &str = "ARCHIVE_READ_AHEAD_BYTES";
+pub const ARCHIVE_READ_AHEAD_BYTES_DEFAULT: &str = "1048576";
+/// S3-hosted archives entry maximum size (in bytes - uncompressed - bigger entries can't be extracted) environment
+/// variable name and default value
+pub const ARCHIVE_ENTRY_MAX_BYTES_ENV_VAR: &str = "ARCHIVE_ENTRY_MAX_BYTES";
+pub const ARCHIVE_ENTRY_MAX_BYTES_DEFAULT: &str = "104857600";
+
+/// Encrypted zip file (age format) extension
+pub const ENCRYPTED_EXPORT_FILE_EXTENSION: &str = ".age";
+
+/// NDJSON listing stream buffer size (in bytes) shared between the listing writer and the response body
+pub const NDJSON_STREAM_BUFFER_SIZE: usize = 16384;
+
+/// NDJSON listing pages channel capacity (S3 listing pages waiting to be written)
+pub const NDJSON_LISTING_PAGE_CHANNEL_CAPACITY: usize = 4;
+/// NDJSON listing maximum sub-folders listed in parallel environment variable name and default value
+pub const LISTING_MAX_PARALLEL_PREFIXES_ENV_VAR: &str = "LISTING_MAX_PARALLEL_PREFIXES";
+pub const LISTING_MAX_PARALLEL_PREFIXES_DEFAULT: &str = "8";
+
+/// Zip file stream buffer size (in bytes) shared between the zip writer and the response body
+pub const ZIP_STREAM_BUFFER_SIZE: usize = 65536;
+
+/// Error reporter Sentry DSN environment variable name (requires `sentry` feature)
+pub const ERROR_REPORTER_SENTRY_DSN_ENV_VAR: &str = "SENTRY_DSN";
+
+/// Email notifier Amazon SES SMTP interface host (i.e.: email-smtp.us-east-1.amazonaws.com) environment variable name
+/// (requires `ses-email` feature)
+pub const EMAIL_NOTIFIER_SES_SMTP_HOST_ENV_VAR: &str = "SES_SMTP_HOST";
+/// Email notifier Amazon SES SMTP credentials environment variable names (requires `ses-email` feature)
+pub const EMAIL_NOTIFIER_SES_SMTP_USERNAME_ENV_VAR: &str = "SES_SMTP_USERNAME";
+pub const EMAIL_NOTIFIER_SES_SMTP_PASSWORD_ENV_VAR: &str = "SES_SMTP_PASSWORD";
+/// Email notifier sender (SES verified identity) environment variable name (requires `ses-email` feature)
+pub const EMAIL_NOTIFIER_SENDER_ENV_VAR: &str = "SES_EMAIL_SENDER";
+/// Job events Amazon SNS topic ARN environment variable name (requires `aws-events` feature)
+pub const JOB_EVENTS_SNS_TOPIC_ARN_ENV_VAR: &str = "JOB_EVENTS_SNS_TOPIC_ARN";
+/// Job events Amazon EventBridge event bus name environment variable name (requires `aws-events` feature)
+pub const JOB_EVENTS_EVENT_BUS_NAME_ENV_VAR: &str = "JOB_EVENTS_EVENT_BUS_NAME";
+/// Job events Amazon EventBridge source
+pub const JOB_EVENTS_SOURCE: &str = "rust-aws-s3-downloader";
+/// Public base URL (used in links sent out of the API, i.e.: job result emails) environment variable name and default value
+pub const PUBLIC_BASE_URL_ENV_VAR: &str = "PUBLIC_BASE_URL";
+pub const PUBLIC_BASE_URL_DEFAULT: &str = "http://localhost:8097";
+
+/// Self-check mode command line argument
+pub const SELF_CHECK_ARG: &str = "--check";
+/// Ignore configuration errors command line argument (API server starts even with not valid configuration values)
+pub const IGNORE_CONFIG_ERRORS_ARG: &str = "--ignore-config-errors";
+/// Self-check S3 buckets to be checked (comma separated) environment variable name
+pub const SELF_CHECK_BUCKETS_ENV_VAR: &str = "SELF_CHECK_BUCKETS";
+
+/// Startup warm-up enabled flag environment variable name and default value (if it is enabled, the AWS SDK S3 client is
+/// built, its credentials are resolved and warm connections are opened before the API server starts listening)
+pub const STARTUP_WARMUP_ENABLED_ENV_VAR: &str = "STARTUP_WARMUP_ENABLED";
+pub const STARTUP_WARMUP_ENABLED_DEFAULT: &str = "false";
+/// Startup warm-up S3 buckets (comma separated - warm connections are opened by head bucket requests) environment variable name
+pub const STARTUP_WARMUP_BUCKETS_ENV_VAR: &str = "STARTUP_WARMUP_BUCKETS";
+/// Startup warm-up connections per S3 bucket environment variable name and default value
+pub const STARTUP_WARMUP_CONNECTIONS_ENV_VAR: &str = "STARTUP_WARMUP_CONNECTIONS";
+pub const STARTUP_WARMUP_CONNECTIONS_DEFAULT: &str = "4";
+/// Startup warm-up timeout (in seconds - API server starts anyway once it is reached) environment variable name and default value
+pub const STARTUP_WARMUP_TIMEOUT_SECONDS_ENV_VAR: &str = "STARTUP_WARMUP_TIMEOUT_SECONDS";
+pub const STARTUP_WARMUP_TIMEOUT_SECONDS_DEFAULT: &str = "10";
+
+/// Usage stats rolling window (in seconds) environment variable name
+pub const USAGE_STATS_WINDOW_SECONDS_ENV_VAR: &str = "USAGE_STATS_WINDOW_SECONDS";
+/// Usage stats rolling window (in seconds) default value
+pub const USAGE_STATS_WINDOW_SECONDS_DEFAULT: &str = "3600"; // 1 hour
+/// Usage stats top buckets quantity
+pub const USAGE_STATS_TOP_BUCKETS_QUANTITY: usize = 5;
+
+/// Role-based access control roles file (JSON role rules by role name) environment variable name
+/// (access control is disabled if it is not defined)
+pub const RBAC_CONFIG_FILE_ENV_VAR: &str = "RBAC_CONFIG_FILE";
+/// Role-based access control role request header name
+pub const RBAC_ROLE_HEADER: &str = "x-api-role";
+/// Role-based access control S3 bucket name of requests without S3 bucket and path (i.e.: admin end-points - just role
+/// rules for any S3 bucket and S3 path match them)
+pub const RBAC_GLOBAL_BUCKET_NAME: &str = "*";
+/// Authorization policy service URL (OPA-like decision end-point) environment variable name
+/// (if it is defined, it replaces the role-based access control policy as authorization hook)
+pub const AUTHORIZATION_POLICY_URL_ENV_VAR: &str = "AUTHORIZATION_POLICY_URL";
+
+/// Caller credentials passthrough enabled flag environment variable name and default value
+/// (if it is enabled, synchronous end-points run under caller temporary AWS credentials from request headers, if any)
+pub const CALLER_CREDENTIALS_ENABLED_ENV_VAR: &str = "CALLER_CREDENTIALS_ENABLED";
+pub const CALLER_CREDENTIALS_ENABLED_DEFAULT: &str = "false";
+/// Caller temporary AWS credentials (access key id, secret access key and session token) request header names
+pub const CALLER_AWS_ACCESS_KEY_ID_HEADER: &str = "x-aws-access-key-id";
+pub const CALLER_AWS_SECRET_ACCESS_KEY_HEADER: &str = "x-aws-secret-access-key";
+pub const CALLER_AWS_SESSION_TOKEN_HEADER: &str = "x-aws-session-token";
+
+/// Assumed role ARN environment variable name (if it is defined, S3 requests run under the assumed role with caller
+/// session tags - base credentials can be an assumed role too, so roles are chained)
+pub const AWS_ASSUME_ROLE_ARN_ENV_VAR: &str = "AWS_ASSUME_ROLE_ARN";
+/// Assumed role session tags (comma separated `{tag_key}={header_name}` values - tag values are read from request headers)
+/// environment variable name and default value
+pub const AWS_ASSUME_ROLE_SESSION_TAGS_ENV_VAR: &str = "AWS_ASSUME_ROLE_SESSION_TAGS";
+pub const AWS_ASSUME_ROLE_SESSION_TAGS_DEFAULT: &str = "tenant=x-tenant-id,user=x-caller-id";
+/// Assumed role session name
+pub const AWS_ASSUME_ROLE_SESSION_NAME: &str = "rust-aws-s3-downloader";
+/// STS session tag value maximum length
+pub const AWS_SESSION_TAG_VALUE_MAX_LENGTH: usize = 256;
+
+/// AWS S3 bucket region response header name (defined by S3 region redirects and `HeadBucket` responses)
+pub const AWS_S3_BUCKET_REGION_HEADER: &str = "x-amz-bucket-region";
+
+/// AWS SDK S3 client pool max size (pooled clients by region, role and endpoint) environment variable name and default value
+pub const AWS_CLIENT_POOL_MAX_SIZE_ENV_VAR: &str = "AWS_CLIENT_POOL_MAX_SIZE";
+pub const AWS_CLIENT_POOL_MAX_SIZE_DEFAULT: &str = "256";
+
+/// Credentials cache refresh ahead window (in seconds) environment variable name and default value
+/// (cached credentials - assumed role or base credentials, i.e.: web identity - are refreshed in background when they
+/// expire within this window, so long running exports don't fail on expired sessions)
+pub const CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_ENV_VAR: &str = "CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS";
+pub const CREDENTIALS_CACHE_REFRESH_AHEAD_SECONDS_DEFAULT: &str = "300"; // 5 minutes
+/// Credentials cache idle time (in seconds) environment variable name and default value
+/// (cached credentials not used within this time are evicted instead of refreshed)
+pub const CREDENTIALS_CACHE_IDLE_SECONDS_ENV_VAR: &str = "CREDENTIALS_CACHE_IDLE_SECONDS";
+pub const CREDENTIALS_CACHE_IDLE_SECONDS_DEFAULT: &str = "3600"; // 1 hour
+/// Credentials cache background refresh interval (in seconds)
+pub const CREDENTIALS_CACHE_REFRESH_INTERVAL_SECONDS: u64 = 60;
+
+/// Multi-tenancy enabled flag environment variable name and default value
+pub const TENANCY_ENABLED_ENV_VAR: &str = "TENANCY_ENABLED";
+pub const TENANCY_ENABLED_DEFAULT: &str = "false";
+/// Multi-tenancy tenant request header name (set by the multi-tenancy middleware from the authenticated tenant token -
+/// any requested value is discarded)
+pub const TENANT_HEADER: &str = "x-tenant-id";
+/// Multi-tenancy tenant token (HS256 JWT from `Authorization: Bearer {token}` request header) signing secret
+/// environment variable name (tenant requests are rejected if it is not defined)
+pub const TENANT_TOKEN_SECRET_ENV_VAR: &str = "TENANT_TOKEN_SECRET";
+/// Multi-tenancy tenant token claim name (tenant identifier)
+pub const TENANT_TOKEN_CLAIM: &str = "tenant_id";
+/// Multi-tenancy tenant identifier maximum length
+pub const TENANT_ID_MAX_LENGTH: usize = 64;
+/// Multi-tenancy S3 path prefix (all tenant S3 paths are scoped under `tenants/{tenant_id}/`)
+pub const TENANT_PATH_PREFIX: &str = "tenants";
+
+/// Download quota S3 bucket daily cap (in bytes) environment variable name and default value (zero is unlimited)
+pub const DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_ENV_VAR: &str = "DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES";
+pub const DOWNLOAD_QUOTA_BUCKET_DAILY_BYTES_DEFAULT: &str = "0";
+/// Download quota caller daily cap (in bytes) environment variable name and default value (zero is unlimited)
+pub const DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_ENV_VAR: &str = "DOWNLOAD_QUOTA_CALLER_DAILY_BYTES";
+pub const DOWNLOAD_QUOTA_CALLER_DAILY_BYTES_DEFAULT: &str = "0";
+/// Caller request header name (access log caller - it is not authenticated, so download quotas are tracked by the
+/// authenticated tenant token instead)
+pub const DOWNLOAD_QUOTA_CALLER_HEADER: &str = "x-caller-id";
+/// Download quota caller for requests without authenticated caller (just if caller daily cap is not configured)
+pub const DOWNLOAD_QUOTA_ANONYMOUS_CALLER: &str = "anonymous";
+
+/// Maintenance mode at startup environment variable name (off, read_only or full - off if it is not defined)
+pub const MAINTENANCE_MODE_ENV_VAR: &str = "MAINTENANCE_MODE";
+/// Maintenance mode rejected requests retry after (in seconds) header value
+pub const MAINTENANCE_RETRY_AFTER_SECONDS: &str = "300";
+
+/// Feature flags (comma separated "feature=true|false" values, i.e.: "api_v2,list_stream=false") environment variable name
+pub const FEATURE_FLAGS_ENV_VAR: &str = "FEATURE_FLAGS";
+
+/// Export alert duration threshold (in seconds) environment variable name and default value (zero disables it)
+pub const EXPORT_ALERT_DURATION_SECONDS_ENV_VAR: &str = "EXPORT_ALERT_DURATION_SECONDS";
+pub const EXPORT_ALERT_DURATION_SECONDS_DEFAULT: &str = "0";
+/// Export alert size threshold (in bytes) environment variable name and default value (zero disables it)
+pub const EXPORT_ALERT_SIZE_BYTES_ENV_VAR: &str = "EXPORT_ALERT_SIZE_BYTES";
+pub const EXPORT_ALERT_SIZE_BYTES_DEFAULT: &str = "0";
+/// Export alert webhook URL environment variable name (alerts are just logged if it is not defined)
+pub const EXPORT_ALERT_WEBHOOK_URL_ENV_VAR: &str = "EXPORT_ALERT_WEBHOOK_URL";
+
+/// Access log sampled requests percentage (from 0 to 100) environment variable name and default value
+/// (server errors are always logged)
+pub const ACCESS_LOG_SAMPLE_PERCENT_ENV_VAR: &str = "ACCESS_LOG_SAMPLE_PERCENT";
+pub const ACCESS_LOG_SAMPLE_PERCENT_DEFAULT: &str = "100";
+/// Access log lines log target (to be routed by logger configuration)
+pub const ACCESS_LOG_TARGET: &str = "access_log";
+
+/// API maximum request body size (in bytes - also read by middlewares to check or rewrite the requested S3 bucket and path)
+/// environment variable name and default value
+pub const API_REQUEST_BODY_MAX_BYTES_ENV_VAR: &str = "API_REQUEST_BODY_MAX_BYTES";
+pub const API_REQUEST_BODY_MAX_BYTES_DEFAULT: &str = "2097152"; // 2 MB
+/// API CORS allowed origins (comma separated origins - "*" allows any origin - CORS is disabled if it is not defined)
+/// environment variable name
+pub const API_CORS_ALLOWED_ORIGINS_ENV_VAR: &str = "API_CORS_ALLOWED_ORIGINS";
+/// API gzip responses compression flag (zip files are not compressed) environment variable name and default value
+pub const API_COMPRESSION_ENABLED_ENV_VAR: &str = "API_COMPRESSION_ENABLED";
+pub const API_COMPRESSION_ENABLED_DEFAULT: &str = "false";
+/// API concurrency classes maximum in-flight requests (listing, archive streaming and admin requests - zero means unlimited)
+/// environment variables names and default values
+pub const API_CONCURRENCY_LISTING_MAX_REQUESTS_ENV_VAR: &str = "API_CONCURRENCY_LISTING_MAX_REQUESTS";
+pub const API_CONCURRENCY_LISTING_MAX_REQUESTS_DEFAULT: &str = "256";
+pub const API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_ENV_VAR: &str = "API_CONCURRENCY_ARCHIVE_MAX_REQUESTS";
+pub const API_CONCURRENCY_ARCHIVE_MAX_REQUESTS_DEFAULT: &str = "32";
+pub const API_CONCURRENCY_ADMIN_MAX_REQUESTS_ENV_VAR: &str = "API_CONCURRENCY_ADMIN_MAX_REQUESTS";
+pub const API_CONCURRENCY_ADMIN_MAX_REQUESTS_DEFAULT: &str = "8";
+/// API concurrency classes queue timeout (in milliseconds - requests wait for a free slot of their class up to this time)
+/// environment variable name and default value
+pub const API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_ENV_VAR: &str = "API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS";
+pub const API_CONCURRENCY_QUEUE_TIMEOUT_MILLIS_DEFAULT: &str = "2000";
+/// API concurrency class budget exhausted retry after (in seconds) header value
+pub const API_CONCURRENCY_RETRY_AFTER_SECONDS: &str = "1";
+
+/// Static website mode paths (S3 objects under the configured S3 bucket and path are served by request path)
+pub const STATIC_WEBSITE_MAIN_PATH: &str = "/files";
+pub const STATIC_WEBSITE_FILE_PATH: &str = "/files/{*file_path}";
+/// Static website mode S3 bucket environment variable name (static website mode is enabled just if it is defined)
+pub const STATIC_WEBSITE_BUCKET_ENV_VAR: &str = "STATIC_WEBSITE_BUCKET";
+/// Static website mode S3 path environment variable name (S3 bucket root by default)
+pub const STATIC_WEBSITE_PATH_ENV_VAR: &str = "STATIC_WEBSITE_PATH";
+/// Static website mode index document (folder requests) environment variable name and default value
+pub const STATIC_WEBSITE_INDEX_DOCUMENT_ENV_VAR: &str = "STATIC_WEBSITE_INDEX_DOCUMENT";
+pub const STATIC_WEBSITE_INDEX_DOCUMENT_DEFAULT: &str = "index.html";
+
+/// Watch mode watched S3 prefixes (comma separated "bucket:path" values) environment variable name
+pub const WATCH_PREFIXES_ENV_VAR: &str = "WATCH_PREFIXES";
+/// Watch mode destination S3 bucket (incremental zip files) environment variable name
+pub const WATCH_DESTINATION_BUCKET_ENV_VAR: &str = "WATCH_DESTINATION_BUCKET";
+/// Watch mode destination S3 path environment variable name and default value
+pub const WATCH_DESTINATION_PATH_ENV_VAR: &str = "WATCH_DESTINATION_PATH";
+pub const WATCH_DESTINATION_PATH_DEFAULT: &str = "watch-archives";
+/// Watch mode polling interval (in seconds) environment variable name and default value
+pub const WATCH_INTERVAL_SECONDS_ENV_VAR: &str = "WATCH_INTERVAL_SECONDS";
+pub const WATCH_INTERVAL_SECONDS_DEFAULT: &str = "300";
+
+/// S3 event rules file (JSON rules list) environment variable name (no action is triggered if it is not defined)
+pub const S3_EVENT_RULES_FILE_ENV_VAR: &str = "S3_EVENT_RULES_FILE";
+/// S3 event rules destination S3 path default value (archive and copy actions)
+pub const S3_EVENT_DESTINATION_PATH_DEFAULT: &str = "s3-events";
+/// S3 object created event name prefix (i.e.: "ObjectCreated:Put")
+pub const S3_EVENT_OBJECT_CREATED_PREFIX: &str = "ObjectCreated:";
+/// S3 event notifications HMAC secret environment variable name (notifications are rejected if it is not defined)
+/// Important: notifications should be signed (hex encoded HMAC-SHA256 of "{timestamp}.{nonce}.{body}")
+pub const S3_EVENT_SIGNING_SECRET_ENV_VAR: &str = "S3_EVENT_SIGNING_SECRET";
+/// S3 event notifications signature timestamp tolerance (in seconds) environment variable name and default value
+pub const S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_ENV_VAR: &str = "S3_EVENT_SIGNATURE_TOLERANCE_SECONDS";
+pub const S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_DEFAULT: &str = "300"; // 5 minutes
+/// S3 event notifications signature, signature timestamp (epoch seconds) and signature nonce request header names
+pub const S3_EVENT_SIGNATURE_HEADER: &str = "x-signature";
+pub const S3_EVENT_SIGNATURE_TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+pub const S3_EVENT_SIGNATURE_NONCE_HEADER: &str = "x-signature-nonce";
+/// S3 event notifications replay cache nonce scope
+pub const S3_EVENT_NONCE_SCOPE: &str = "s3-events";
+
+/// Listing cache TTL (in seconds) environment variable name and default value (zero disables the cache)
+pub const LISTING_CACHE_TTL_SECONDS_ENV_VAR: &str = "LISTING_CACHE_TTL_SECONDS";
+pub const LISTING_CACHE_TTL_SECONDS_DEFAULT: &str = "30";
+
+/// External secrets refresh interval (in seconds) environment variable name and default value (zero disables the refresh)
+/// Important: secret config values (please, check config_dump.rs) defined as external secret references (`aws-secrets`
+/// feature) are resolved from AWS Secrets Manager (`secretsmanager:{secret_id}`) or SSM Parameter Store
+/// (`ssm:{parameter_name}`) at startup
+pub const EXTERNAL_SECRETS_REFRESH_SECONDS_ENV_VAR: &str = "EXTERNAL_SECRETS_REFRESH_SECONDS";
+pub const EXTERNAL_SECRETS_REFRESH_SECONDS_DEFAULT: &str = "3600"; // 1 hour
+/// External secret references prefixes (AWS Secrets Manager secret and SSM Parameter Store parameter)
+pub const EXTERNAL_SECRET_SECRETS_MANAGER_PREFIX: &str = "secretsmanager:";
+pub const EXTERNAL_SECRET_SSM_PREFIX: &str = "ssm:";
+
+/// Object body cache maximum bytes environment variable name and default value (zero disables the cache)
+/// Important: cached S3 objects are re-validated by If-None-Match requests (stored ETags) and reused on 304 responses
+pub const OBJECT_BODY_CACHE_MAX_BYTES_ENV_VAR: &str = "OBJECT_BODY_CACHE_MAX_BYTES";
+pub const OBJECT_BODY_CACHE_MAX_BYTES_DEFAULT: &str = "0";
+
+/// Thumbnails cache maximum bytes environment variable name and default value (zero disables the cache)
+pub const THUMBNAIL_CACHE_MAX_BYTES_ENV_VAR: &str = "THUMBNAIL_CACHE_MAX_BYTES";
+pub const THUMBNAIL_CACHE_MAX_BYTES_DEFAULT: &str = "33554432"; // 32 MiB
+/// Thumbnails source image maximum size (in bytes) environment variable name and default value
+pub const THUMBNAIL_SOURCE_MAX_BYTES_ENV_VAR: &str = "THUMBNAIL_SOURCE_MAX_BYTES";
+pub const THUMBNAIL_SOURCE_MAX_BYTES_DEFAULT: &str = "20971520"; // 20 MiB
+/// Thumbnail default and allowed sizes (maximum width and height - in pixels - aspect ratio is preserved)
+pub const THUMBNAIL_SIZE_DEFAULT: u32 = 256;
+pub const THUMBNAIL_SIZE_MIN: u32 = 16;
+pub const THUMBNAIL_SIZE_MAX: u32 = 1024;
+/// Thumbnail content type (thumbnails are always encoded as JPEG images)
+pub const THUMBNAIL_CONTENT_TYPE: &str = "image/jpeg";
+
+/// Text preview default and maximum sizes (in KB)
+pub const TEXT_PREVIEW_SIZE_KB_DEFAULT: u64 = 64;
+pub const TEXT_PREVIEW_SIZE_KB_MAX: u64 = 1024;
+
+/// Full-text search maximum S3 objects listed environment variable name and default value
+pub const SEARCH_MAX_OBJECTS_ENV_VAR: &str = "SEARCH_MAX_OBJECTS";
+pub const SEARCH_MAX_OBJECTS_DEFAULT: &str = "1000";
+/// Full-text search maximum S3 object size (in bytes - larger S3 objects are skipped) environment variable name and default value
+pub const SEARCH_MAX_OBJECT_BYTES_ENV_VAR: &str = "SEARCH_MAX_OBJECT_BYTES";
+pub const SEARCH_MAX_OBJECT_BYTES_DEFAULT: &str = "1048576"; // 1 MiB
+/// Full-text search maximum S3 objects fetched in parallel environment variable name and default value
+pub const SEARCH_MAX_PARALLEL_FETCHES_ENV_VAR: &str = "SEARCH_MAX_PARALLEL_FETCHES";
+pub const SEARCH_MAX_PARALLEL_FETCHES_DEFAULT: &str = "8";
+/// Full-text search default and maximum matching lines
+pub const SEARCH_MAX_MATCHES_DEFAULT: usize = 100;
+pub const SEARCH_MAX_MATCHES_LIMIT: usize = 1000;
+/// Full-text search maximum query length (in bytes) and matching line snippet length (in characters)
+pub const SEARCH_QUERY_MAX_LENGTH: usize = 256;
+pub const SEARCH_SNIPPET_MAX_CHARS: usize = 200;
+
+/// Redis URL environment variable name (shared state store for multi-replica deployments - `redis` feature)
+pub const REDIS_URL_ENV_VAR: &str = "REDIS_URL";
+/// Redis keys prefix (all shared state keys)
+pub const REDIS_KEY_PREFIX: &str = "rust-aws-s3-downloader:";
+/// Shared state store listing cache and download jobs keys prefixes
+pub const SHARED_STATE_LISTING_KEY_PREFIX: &str = "listing:";
+pub const SHARED_STATE_JOB_KEY_PREFIX: &str = "job:";
+/// Shared state store export templates keys prefix (templates are stored for [EXPORT_TEMPLATE_RETENTION_SECONDS] since
+/// their last update)
+pub const SHARED_STATE_TEMPLATE_KEY_PREFIX: &str = "template:";
+/// Shared state store export history keys prefix (records are stored for the export history retention window)
+pub const SHARED_STATE_HISTORY_KEY_PREFIX: &str = "history:";
+pub const EXPORT_TEMPLATE_RETENTION_SECONDS: u64 = 31_536_000; // 1 year
+/// Export template name maximum length (in characters)
+pub const EXPORT_TEMPLATE_NAME_MAX_LENGTH: usize = 128;
+/// Export template variables maximum quantity and variable names and values maximum length (in characters)
+pub const EXPORT_TEMPLATE_MAX_VARIABLES: usize = 16;
+pub const EXPORT_TEMPLATE_VARIABLE_NAME_MAX_LENGTH: usize = 64;
+pub const EXPORT_TEMPLATE_VARIABLE_VALUE_MAX_LENGTH: usize = 256;
+/// Export template built-in variables (resolved from the trigger schedule time - `{date}` as `2026-01-31`,
+/// `{datetime}` as `2026-01-31T10:00:00Z` and `{timestamp}` as seconds since unix epoch)
+pub const EXPORT_TEMPLATE_BUILTIN_VARIABLES: [&str; 3] = ["date", "datetime", "timestamp"];
+/// Shared state store download job requests and leases keys prefixes (distributed job locking)
+pub const SHARED_STATE_JOB_REQUEST_KEY_PREFIX: &str = "job-request:";
+pub const SHARED_STATE_JOB_LEASE_KEY_PREFIX: &str = "job-lease:";
+/// Shared state store download tokens keys prefix (exchanged download requests)
+pub const SHARED_STATE_DOWNLOAD_TOKEN_KEY_PREFIX: &str = "download-token:";
+/// Shared state store signed requests used nonces keys prefix (replay protection)
+pub const SHARED_STATE_NONCE_KEY_PREFIX: &str = "nonce:";
+/// Redis lease acquisition script (lease is renewed if it's owned by the same owner, otherwise it's set if not exists)
+pub const REDIS_ACQUIRE_LEASE_SCRIPT: &str = "if redis.call('GET', KEYS[1]) == ARGV[1] then \
+    return redis.call('EXPIRE', KEYS[1], ARGV[2]) end \
+    if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'EX', ARGV[2]) then return 1 end return 0";
+
+/// Download job lease TTL (in seconds) environment variable name and default value
+/// Important: leases are renewed (heartbeat) every third of the TTL while jobs are running, stalled jobs
+/// (expired leases) are taken over by other replicas
+pub const DOWNLOAD_JOB_LEASE_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_LEASE_SECONDS";
+pub const DOWNLOAD_JOB_LEASE_SECONDS_DEFAULT: &str = "30";
+/// Download job stalled jobs takeover interval (in seconds) environment variable name and default value
+pub const DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS";
+pub const DOWNLOAD_JOB_TAKEOVER_INTERVAL_SECONDS_DEFAULT: &str = "15";
+
+/// Download job maximum volumes (sharded exports by S3 key ranges)
+pub const DOWNLOAD_JOB_MAX_VOLUMES: usize = 64;
+/// Download job maximum folder archives (exports split by first-level sub-folder) environment variable name and default value
+pub const DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_ENV_VAR: &str = "DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES";
+pub const DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_DEFAULT: &str = "256";
+/// Download job volumes status polling interval (in seconds) - sharded exports coordinator
+pub const DOWNLOAD_JOB_VOLUMES_POLL_SECONDS: u64 = 2;
+/// Download job volumes manifest file name (sharded exports coordinator result)
+pub const DOWNLOAD_JOB_VOLUMES_MANIFEST_FILE_NAME: &str = "s3-export-manifest.json";
+
+/// Download jobs concurrency budgets (running jobs by priority) environment variables names and default values
+/// Important: jobs keep pending status (queued) while their priority budget is exhausted
+pub const DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_ENV_VAR: &str = "DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY";
+pub const DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_DEFAULT: &str = "4";
+pub const DOWNLOAD_JOB_BATCH_CONCURRENCY_ENV_VAR: &str = "DOWNLOAD_JOB_BATCH_CONCURRENCY";
+pub const DOWNLOAD_JOB_BATCH_CONCURRENCY_DEFAULT: &str = "1";
+
+/// Download job maximum attempts environment variable name and default value (failed attempts are retried with backoff,
+/// jobs are moved to dead letter status once all attempts fail)
+pub const DOWNLOAD_JOB_MAX_ATTEMPTS_ENV_VAR: &str = "DOWNLOAD_JOB_MAX_ATTEMPTS";
+pub const DOWNLOAD_JOB_MAX_ATTEMPTS_DEFAULT: &str = "3";
+/// Download job retry backoff (in seconds - doubled by each attempt) environment variable name and default value
+pub const DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS";
+pub const DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_DEFAULT: &str = "5";
+/// Download job maximum retry backoff (in seconds)
+pub const DOWNLOAD_JOB_RETRY_MAX_BACKOFF_SECONDS: u64 = 300;
+/// Download job archived S3 objects restore status polling interval (in seconds) environment variable name and default value
+pub const DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS";
+pub const DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_DEFAULT: &str = "300";
+/// Download job archived S3 objects restore maximum wait (in seconds) environment variable name and default value
+/// (job continues once it is exceeded and not retrievable S3 objects fail the download flow)
+pub const DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_ENV_VAR: &str = "DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS";
+pub const DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_DEFAULT: &str = "172800"; // 48 hours
+/// Download job archived S3 objects restored copies lifetime (in days) if it is not requested
+pub const DOWNLOAD_JOB_RESTORE_DAYS_DEFAULT: i32 = 1;
+
+/// Export cost estimate pricing file (JSON format) environment variable name
+/// Important: us-east-1 public prices are used if it is not defined (please, check PricingTable)
+pub const COST_PRICING_FILE_ENV_VAR: &str = "COST_PRICING_FILE";
+/// AWS S3 default storage class (S3 listings omit it for some S3 compatible services)
+pub const AWS_S3_DEFAULT_STORAGE_CLASS: &str = "STANDARD";
+/// Export filter maximum nesting depth and conditions (and / or / not conditions included)
+pub const EXPORT_FILTER_MAX_DEPTH: usize = 8;
+pub const EXPORT_FILTER_MAX_CONDITIONS: usize = 64;
+/// AWS S3 archive storage classes (S3 objects require a restore before they can be downloaded)
+pub const AWS_S3_RESTORE_REQUIRED_STORAGE_CLASSES: [&str; 2] = ["GLACIER", "DEEP_ARCHIVE"];
+/// AWS S3 lifecycle pending transitions (listing, exists and estimate responses) environment variable and default value
+/// (S3 bucket lifecycle rules are requested once by listing page - lifecycle rules filtered by tags are not supported)
+pub const AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_ENV_VAR: &str = "AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED";
+pub const AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_DEFAULT: &str = "true";
+
+/// Temp storage minimum free disk space (in bytes) environment variable name and default value
+/// (readiness check fails if temp dir is not writable or its free disk space is under it - zero disables the check)
+pub const TEMP_STORAGE_MIN_FREE_BYTES_ENV_VAR: &str = "TEMP_STORAGE_MIN_FREE_BYTES";
+pub const TEMP_STORAGE_MIN_FREE_BYTES_DEFAULT: &str = "0";
+
 /// Unit test cases
 #[cfg(test)]
 mod tests {}
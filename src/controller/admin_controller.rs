@@ -0,0 +1,82 @@
+use axum::middleware::from_fn;
+use axum::{Json, Router, response::IntoResponse, routing::{get, post}};
+
+use crate::config::app_state::AppState;
+use crate::config::config_dump::get_config_dump;
+use crate::config::feature_flags::FEATURE_FLAGS;
+use crate::config::listing_cache::LISTING_CACHE;
+use crate::config::maintenance_mode::MAINTENANCE_MODE;
+use crate::constant::constants::{API_ADMIN_CACHE_INVALIDATE_PATH, API_ADMIN_CONFIG_PATH, API_ADMIN_FEATURE_FLAGS_PATH, API_ADMIN_MAINTENANCE_PATH};
+use crate::dto::cache_invalidate_request::CacheInvalidateRequest;
+use crate::dto::cache_invalidate_response::CacheInvalidateResponse;
+use crate::dto::feature_flags_state::FeatureFlagsState;
+use crate::dto::maintenance_state::MaintenanceState;
+use crate::middleware::admin_auth_middleware::admin_auth_middleware;
+
+/// Admin controller
+pub trait AdminControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Admin controller implementation struct
+pub struct AdminController {}
+
+/// Admin controller implementation logic
+impl AdminControllerTrait for AdminController {
+    /// Configure declared endpoints for this controller (all of them require the admin token - please, check
+    /// [admin_auth_middleware])
+    fn config_endpoints(_app_state: &AppState) -> Router {
+        Router::new()
+            .route(API_ADMIN_CACHE_INVALIDATE_PATH, post(map_cache_invalidate))
+            .route(API_ADMIN_CONFIG_PATH, get(map_config))
+            .route(API_ADMIN_MAINTENANCE_PATH, get(map_get_maintenance).post(map_set_maintenance))
+            .route(API_ADMIN_FEATURE_FLAGS_PATH, get(map_get_feature_flags).post(map_set_feature_flags))
+            .route_layer(from_fn(admin_auth_middleware))
+    }
+}
+
+/// Maps listing cache invalidation end-point
+async fn map_cache_invalidate(cache_invalidate_request: Json<CacheInvalidateRequest>) -> impl IntoResponse {
+    Json(CacheInvalidateResponse {
+        invalidated_entries: LISTING_CACHE
+            .invalidate(&cache_invalidate_request.bucket_name, cache_invalidate_request.full_path.as_deref())
+            .await,
+    })
+}
+
+/// Maps resolved configuration end-point (secrets are redacted)
+async fn map_config() -> impl IntoResponse {
+    Json(get_config_dump())
+}
+
+/// Maps maintenance mode query end-point
+async fn map_get_maintenance() -> impl IntoResponse {
+    Json(MaintenanceState {
+        mode: MAINTENANCE_MODE.get_mode(),
+    })
+}
+
+/// Maps maintenance mode toggle end-point
+async fn map_set_maintenance(maintenance_state: Json<MaintenanceState>) -> impl IntoResponse {
+    MAINTENANCE_MODE.set_mode(maintenance_state.mode);
+    maintenance_state
+}
+
+/// Maps feature flags query end-point
+async fn map_get_feature_flags() -> impl IntoResponse {
+    Json(FeatureFlagsState {
+        flags: FEATURE_FLAGS.get_flags(),
+    })
+}
+
+/// Maps feature flags toggle end-point (just defined features are changed)
+async fn map_set_feature_flags(feature_flags_state: Json<FeatureFlagsState>) -> impl IntoResponse {
+    FEATURE_FLAGS.set_flags(feature_flags_state.0.flags);
+    map_get_feature_flags().await
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+}
@@ -0,0 +1,50 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{
+    Json, Router,
+    response::IntoResponse,
+    routing::post,
+};
+use crate::config::app_state::AppState;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::request_validation::ValidJson;
+use crate::constant::constants::{API_DOWNLOAD_ESTIMATE_PATH, API_DOWNLOAD_MAIN_PATH};
+use crate::dto::cost_estimate_request::CostEstimateRequest;
+use crate::service::cost_estimate_service::DynCostEstimateService;
+
+/// Cost estimate controller
+pub trait CostEstimateControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Cost estimate controller implementation struct
+pub struct CostEstimateController {}
+
+/// Cost estimate controller implementation logic
+impl CostEstimateControllerTrait for CostEstimateController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_DOWNLOAD_MAIN_PATH, Router::new().route(API_DOWNLOAD_ESTIMATE_PATH, post(map_estimate)))
+            .with_state(app_state.cost_estimate_service.clone())
+    }
+}
+
+/// Maps export cost estimate end-point
+async fn map_estimate(
+    State(cost_estimate_service): State<DynCostEstimateService>,
+    cost_estimate_request: ValidJson<CostEstimateRequest>,
+) -> impl IntoResponse {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ESTIMATE_PATH} - bucket name: {} - path: {}",
+        cost_estimate_request.bucket_name, cost_estimate_request.full_path
+    );
+    match cost_estimate_service.estimate_export_cost(cost_estimate_request.0).await {
+        Ok(cost_estimate) => Json(cost_estimate).into_response(),
+        Err(estimate_error) => {
+            ERROR_REPORTER.report_error(&estimate_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,24 +1,53 @@
-use std::io::Cursor;
+use std::future;
+use std::io::Error;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::task::Poll;
+use std::time::Duration;
 
 use axum::extract::State;
 use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::{
     Json, Router,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::post,
 };
-use axum::body::Body;
-use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::body::{Body, Bytes};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, RETRY_AFTER, TRAILER};
+use futures_util::{stream, Stream, StreamExt};
+use http_body::Frame;
+use http_body_util::{BodyStream, StreamBody};
+use sha2::{Digest, Sha256};
+use tokio::io::DuplexStream;
+use tokio::sync::oneshot;
 use tokio_util::io::ReaderStream;
-use crate::constant::constants::{API_DOWNLOAD_ALL_AS_ZIP_PATH, API_DOWNLOAD_MAIN_PATH};
+use crate::config::app_state::AppState;
+use crate::config::archive_signer::ARCHIVE_SIGNER;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::request_validation::ValidJson;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{API_DOWNLOAD_ALL_AS_ZIP_PATH, API_DOWNLOAD_ARCHIVE_ENTRIES_PATH, API_DOWNLOAD_ARCHIVE_EXTRACT_PATH, API_DOWNLOAD_BATCH_PATH, API_DOWNLOAD_KEYS_AS_ZIP_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_PREVIEW_PATH, ARCHIVE_SHA256_HEADER, ARCHIVE_SIGNATURE_HEADER, ENCRYPTED_EXPORT_FILE_EXTENSION, EXCLUDED_COUNT_HEADER, EXPORT_KEEP_ALIVE_BYTES, EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT, EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, EXPORT_MEMORY_BUDGET_RETRY_AFTER_SECONDS, ZIP_EXPORT_FILE_NAME};
+use crate::dto::archive_request::ArchiveRequest;
+use crate::dto::batch_download_request::BatchDownloadRequest;
+use crate::dto::download_export::DownloadExport;
 use crate::dto::download_request::DownloadRequest;
-use crate::service::download_service::{DownloadService, DynDownloadService};
+use crate::dto::export_report::ExportReport;
+use crate::dto::keys_download_request::KeysDownloadRequest;
+use crate::dto::limit_exceeded_response::LimitExceededResponse;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::service::download_service::{encode_archive_digest, DynDownloadService};
+use log::warn;
+use tokio_util::sync::CancellationToken;
 
 /// Download controller
 pub trait DownloadControllerTrait {
     /// Configure declared endpoints for this controller
-    fn config_endpoints() -> Router;
+    fn config_endpoints(app_state: &AppState) -> Router;
 }
 
 /// Download controller implementation struct
@@ -27,11 +56,10 @@ pub struct DownloadController {}
 /// Download controller implementation logic
 impl DownloadControllerTrait for DownloadController {
     /// Configure declared endpoints for this controller
-    fn config_endpoints() -> Router {
-        let download_service = Arc::new(DownloadService::default()) as DynDownloadService;
+    fn config_endpoints(app_state: &AppState) -> Router {
         Router::new()
             .nest(API_DOWNLOAD_MAIN_PATH, create_routes())
-            .with_state(download_service)
+            .with_state(app_state.download_service.clone())
     }
 }
 
@@ -39,27 +67,501 @@ impl DownloadControllerTrait for DownloadController {
 fn create_routes() -> Router<DynDownloadService> {
     Router::new()
         .route(API_DOWNLOAD_ALL_AS_ZIP_PATH, post(map_download))
+        .route(API_DOWNLOAD_KEYS_AS_ZIP_PATH, post(map_download_keys))
+        .route(API_DOWNLOAD_BATCH_PATH, post(map_download_batch))
+        .route(API_DOWNLOAD_PREVIEW_PATH, post(map_preview))
+        .route(API_DOWNLOAD_ARCHIVE_ENTRIES_PATH, post(map_archive_entries))
+        .route(API_DOWNLOAD_ARCHIVE_EXTRACT_PATH, post(map_archive_extract))
 }
 
-/// Maps download end-point
+/// Maps download end-point (pending S3 downloads are aborted if the client disconnects)
 async fn map_download(
     State(download_service): State<DynDownloadService>,
-    download_request: Json<DownloadRequest>,
+    download_request: ValidJson<DownloadRequest>,
 ) -> impl IntoResponse {
-    match download_service.download_files(download_request.0.bucket_name, download_request.0.full_path).await {
-        Ok(export_file_content) => {
-            let headers = create_export_headers(&export_file_content.0);
-            let body = Body::from_stream(ReaderStream::new(Cursor::new(export_file_content.1)));
-            (headers, body).into_response()
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ALL_AS_ZIP_PATH} - bucket name: {} - path: {}",
+        download_request.bucket_name, download_request.full_path
+    );
+    let mut download_request = download_request.0;
+    let cancellation_token = CancellationToken::new();
+    download_request.cancellation_token = cancellation_token.clone();
+    let download_response = create_keep_alive_download_response(download_service, download_request, request_context).await;
+    cancel_on_disconnect(download_response, cancellation_token)
+}
+
+/// Creates a new [Response] by [DynDownloadService] download service and [DownloadRequest] download request (streamed
+/// zip file or error status - please, check [create_download_response]) and [String] request context
+/// Important: plain zip exports not started within the keep-alive interval (i.e.: long S3 listings) send response
+/// headers and keep-alive bytes right away (please, check [create_keep_alive_archive_body])
+async fn create_keep_alive_download_response(
+    download_service: DynDownloadService,
+    download_request: DownloadRequest,
+    request_context: String,
+) -> Response {
+    let keep_alive_interval = match download_request.encryption_recipient.is_none()
+        && download_request.metadata_report.is_none()
+        && !download_request.checksum_audit
+    {
+        true => get_env_var_as_u64(EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_ENV_VAR, EXPORT_KEEP_ALIVE_INTERVAL_SECONDS_DEFAULT),
+        false => 0,
+    };
+    let download_export = Box::pin(async move { download_service.download_files(download_request).await });
+    start_download_export(download_export, Duration::from_secs(keep_alive_interval), request_context).await
+}
+
+/// Creates a new [Response] by [DownloadExportFuture] pending zip export, [Duration] keep-alive interval (zero disables
+/// keep-alive bytes) and [String] request context: if the export is started within the keep-alive interval, the
+/// response is created by [create_download_response], otherwise zip headers are sent right away and the export is
+/// streamed by a keep-alive body (please, check [create_keep_alive_archive_body])
+async fn start_download_export(mut download_export: DownloadExportFuture, keep_alive_interval: Duration, request_context: String) -> Response {
+    if keep_alive_interval.is_zero() {
+        return create_download_response(download_export.await, &request_context);
+    }
+    match tokio::time::timeout(keep_alive_interval, &mut download_export).await {
+        Ok(download_result) => create_download_response(download_result, &request_context),
+        Err(_) => {
+            warn!("start_download_export - export not started within keep-alive interval - keep-alive bytes sent: {request_context}");
+            let mut headers = create_export_headers(ZIP_EXPORT_FILE_NAME);
+            headers.insert(TRAILER, HeaderValue::from_str(&create_trailer_header(Some(EXCLUDED_COUNT_HEADER))).unwrap());
+            let export_completion = ExportCompletion::default();
+            let archive_body = create_keep_alive_archive_body(download_export, keep_alive_interval, export_completion.clone(), request_context);
+            let mut download_response = (headers, archive_body).into_response();
+            download_response.extensions_mut().insert(export_completion);
+            download_response
+        }
+    }
+}
+
+/// Pending zip export (started once the S3 listing is completed)
+type DownloadExportFuture = Pin<Box<dyn Future<Output = Result<DownloadExport, CommonError>> + Send>>;
+
+/// Creates a new streamed [Body] by [DownloadExportFuture] pending zip export: [EXPORT_KEEP_ALIVE_BYTES] keep-alive
+/// bytes are sent every [Duration] keep-alive interval while the export is started and the archive content is sent
+/// afterward (please, check [create_archive_checksum_body] - the SHA-256 checksum includes the keep-alive bytes and
+/// the excluded files total is sent as [EXCLUDED_COUNT_HEADER] trailer header)
+/// Important: if the export can't be started, the body ends with an error (response headers were already sent) and
+/// unexpected errors are reported by [String] request context
+fn create_keep_alive_archive_body(
+    download_export: DownloadExportFuture,
+    keep_alive_interval: Duration,
+    export_completion: ExportCompletion,
+    request_context: String,
+) -> Body {
+    let keep_alive_ticks = tokio::time::interval(keep_alive_interval);
+    let archive_frames = stream::unfold(
+        Some((download_export, keep_alive_ticks, Sha256::new(), export_completion, request_context)),
+        |keep_alive_state| async move {
+            let (mut download_export, mut keep_alive_ticks, mut archive_hasher, export_completion, request_context) = keep_alive_state?;
+            tokio::select! {
+                biased;
+                download_result = &mut download_export => {
+                    let archive_frames = match download_result {
+                        Ok(download_export) => {
+                            let mut trailers = HeaderMap::new();
+                            trailers.insert(EXCLUDED_COUNT_HEADER, HeaderValue::from(download_export.excluded_count));
+                            create_archive_frames(archive_hasher, download_export.content, download_export.report, export_completion, trailers).boxed()
+                        }
+                        Err(download_error) => {
+                            warn!("create_keep_alive_archive_body - export not started - response body ended with error: {download_error:?}");
+                            if !is_expected_download_error(&download_error) {
+                                ERROR_REPORTER.report_error(&download_error, &request_context);
+                            }
+                            stream::iter([Err(Error::other("export not started"))]).boxed()
+                        }
+                    };
+                    Some((archive_frames, None))
+                }
+                _ = keep_alive_ticks.tick() => {
+                    archive_hasher.update(EXPORT_KEEP_ALIVE_BYTES);
+                    let keep_alive_frame = Ok(Frame::data(Bytes::from_static(EXPORT_KEEP_ALIVE_BYTES)));
+                    Some((
+                        stream::iter([keep_alive_frame]).boxed(),
+                        Some((download_export, keep_alive_ticks, archive_hasher, export_completion, request_context)),
+                    ))
+                }
+            }
         },
-        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    );
+    Body::new(StreamBody::new(archive_frames.flatten()))
+}
+
+/// Gets [bool] whether [&CommonError] download error is expected (mapped to a client or retryable error status by
+/// [create_download_response] - not reported)
+fn is_expected_download_error(download_error: &CommonError) -> bool {
+    matches!(
+        download_error,
+        CommonError::NO_VALID_INPUT_OR_PARAMETER
+            | CommonError::NO_DATA_FOUND
+            | CommonError::ACCESS_DENIED
+            | CommonError::MEMORY_BUDGET_EXCEEDED
+            | CommonError::QUOTA_EXCEEDED
+            | CommonError::LIMIT_EXCEEDED { .. }
+    )
+}
+
+/// Export completion struct (response extension set by streamed export responses - flagged by the response body just
+/// once the export writer signals success, please, check [create_archive_checksum_body])
+#[derive(Clone, Default)]
+struct ExportCompletion(Arc<AtomicBool>);
+
+impl ExportCompletion {
+    /// Marks the export as completed (the export writer signalled success)
+    fn complete(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Gets [bool] whether the export writer signalled success
+    fn is_completed(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Export disconnect guard struct (cancels the export once the response body is dropped)
+struct ExportDisconnectGuard {
+    cancellation_token: CancellationToken,
+    completed: bool,
+}
+
+impl ExportDisconnectGuard {
+    /// Marks the export as completed (the whole response body was sent)
+    fn complete(&mut self) {
+        self.completed = true;
+    }
+}
+
+/// Export disconnect guard drop logic (exports dropped before being completely sent are recorded as aborted)
+impl Drop for ExportDisconnectGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            warn!("cancel_on_disconnect - response body dropped (client disconnected?) - export aborted");
+            USAGE_STATS_REGISTRY.record_aborted_export();
+        }
+        self.cancellation_token.cancel();
+    }
+}
+
+/// Gets [Response] by [Response] streamed export response and [CancellationToken] export cancellation token: the token
+/// is cancelled once the response body is dropped (completely sent or abandoned by a disconnected client - pending S3
+/// fetch tasks are aborted and the export is recorded as aborted)
+/// Important: exports with an [ExportCompletion] response extension are recorded as completed just if the export
+/// writer signalled success once the response body ends (otherwise, they are recorded as aborted)
+pub fn cancel_on_disconnect(response: Response, cancellation_token: CancellationToken) -> Response {
+    let mut disconnect_guard = ExportDisconnectGuard { cancellation_token, completed: false };
+    let (response_parts, response_body) = response.into_parts();
+    let export_completion = response_parts.extensions.get::<ExportCompletion>().cloned();
+    let response_frames = BodyStream::new(response_body).chain(stream::poll_fn(move |_| {
+        if export_completion.as_ref().is_none_or(ExportCompletion::is_completed) {
+            disconnect_guard.complete();
+        }
+        Poll::Ready(None)
+    }));
+    Response::from_parts(response_parts, Body::new(StreamBody::new(response_frames)))
+}
+
+/// Creates a new [Response] by [Result<DownloadExport, CommonError>] download result (streamed zip file or error status)
+/// and [&str] request context (to report unexpected errors)
+pub fn create_download_response(download_result: Result<DownloadExport, CommonError>, request_context: &str) -> Response {
+    match download_result {
+        Ok(download_export) => {
+            let mut headers = create_export_headers(&download_export.file_name);
+            headers.insert(EXCLUDED_COUNT_HEADER, HeaderValue::from(download_export.excluded_count));
+            headers.insert(TRAILER, HeaderValue::from_str(&create_trailer_header(None)).unwrap());
+            let export_completion = ExportCompletion::default();
+            let archive_body = create_archive_checksum_body(download_export.content, download_export.report, export_completion.clone());
+            let mut download_response = (headers, archive_body).into_response();
+            download_response.extensions_mut().insert(export_completion);
+            download_response
+        },
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER) => StatusCode::BAD_REQUEST.into_response(),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NOT_FOUND.into_response(),
+        Err(CommonError::ACCESS_DENIED) => StatusCode::FORBIDDEN.into_response(),
+        Err(CommonError::MEMORY_BUDGET_EXCEEDED) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, EXPORT_MEMORY_BUDGET_RETRY_AFTER_SECONDS)],
+        )
+            .into_response(),
+        Err(CommonError::QUOTA_EXCEEDED) => StatusCode::TOO_MANY_REQUESTS.into_response(),
+        Err(CommonError::LIMIT_EXCEEDED { limit, actual }) => create_limit_exceeded_response(limit, actual),
+        Err(download_error) => {
+            ERROR_REPORTER.report_error(&download_error, request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Creates a new [String] trailer header value ([ARCHIVE_SHA256_HEADER], [ARCHIVE_SIGNATURE_HEADER] if archive signing
+/// is enabled and [Option<&str>] additional trailer header name)
+fn create_trailer_header(additional_trailer: Option<&str>) -> String {
+    let mut trailer_headers = vec![ARCHIVE_SHA256_HEADER];
+    if ARCHIVE_SIGNER.is_enabled() {
+        trailer_headers.push(ARCHIVE_SIGNATURE_HEADER);
+    }
+    trailer_headers.extend(additional_trailer);
+    trailer_headers.join(", ")
+}
+
+/// Creates a new streamed [Body] by [DuplexStream] archive content: the archive SHA-256 checksum is computed while
+/// the content is streamed and sent as [ARCHIVE_SHA256_HEADER] trailer header once the whole archive was sent
+/// (and its detached signature as [ARCHIVE_SIGNATURE_HEADER] trailer header, if archive signing is enabled)
+/// Important:
+/// - archive is completed just if its [oneshot::Receiver<ExportReport>] export report is received once the content
+///   is sent (the archive writer drops it on failure), otherwise the body ends with an error (the client gets a
+///   broken response instead of a truncated archive)
+/// - [ExportCompletion] export completion is marked just once the export report is received
+/// - HTTP/1.1 clients must send "TE: trailers" request header to receive trailer headers
+fn create_archive_checksum_body(
+    archive_content: DuplexStream,
+    export_report: oneshot::Receiver<ExportReport>,
+    export_completion: ExportCompletion,
+) -> Body {
+    let archive_frames = create_archive_frames(Sha256::new(), archive_content, export_report, export_completion, HeaderMap::new());
+    Body::new(StreamBody::new(archive_frames))
+}
+
+/// Creates a new [Stream] of archive body frames by [Sha256] archive hasher (bytes already sent included),
+/// [DuplexStream] archive content, [oneshot::Receiver<ExportReport>] export report, [ExportCompletion] export completion
+/// and [HeaderMap] additional trailer headers (please, check [create_archive_checksum_body])
+fn create_archive_frames(
+    archive_hasher: Sha256,
+    archive_content: DuplexStream,
+    export_report: oneshot::Receiver<ExportReport>,
+    export_completion: ExportCompletion,
+    additional_trailers: HeaderMap,
+) -> impl Stream<Item = Result<Frame<Bytes>, Error>> + Send + 'static {
+    stream::unfold(
+        (ReaderStream::new(archive_content), Some((archive_hasher, export_report, export_completion, additional_trailers))),
+        |(mut archive_chunks, archive_state)| async move {
+            let (mut archive_hasher, export_report, export_completion, additional_trailers) = archive_state?;
+            match archive_chunks.next().await {
+                Some(Ok(archive_chunk)) => {
+                    archive_hasher.update(&archive_chunk);
+                    let archive_state = Some((archive_hasher, export_report, export_completion, additional_trailers));
+                    Some((Ok(Frame::data(archive_chunk)), (archive_chunks, archive_state)))
+                }
+                Some(Err(stream_error)) => Some((Err(stream_error), (archive_chunks, None))),
+                None if export_report.await.is_err() => {
+                    warn!("create_archive_checksum_body - archive not completed - response body ended with error");
+                    Some((Err(Error::other("archive not completed")), (archive_chunks, None)))
+                }
+                None => {
+                    export_completion.complete();
+                    let mut trailers = create_archive_integrity_headers(&archive_hasher.finalize());
+                    trailers.extend(additional_trailers);
+                    Some((Ok(Frame::<Bytes>::trailers(trailers)), (archive_chunks, None)))
+                }
+            }
+        },
+    )
+}
+
+/// Creates a new [HeaderMap] with [ARCHIVE_SHA256_HEADER] and [ARCHIVE_SIGNATURE_HEADER] (if archive signing
+/// is enabled) headers by [&[u8]] archive SHA-256 digest
+pub fn create_archive_integrity_headers(archive_digest: &[u8]) -> HeaderMap {
+    let mut header_map = HeaderMap::new();
+    header_map.insert(ARCHIVE_SHA256_HEADER, HeaderValue::from_str(&encode_archive_digest(archive_digest)).unwrap());
+    if let Some(archive_signature) = ARCHIVE_SIGNER.sign_archive_digest(archive_digest) {
+        header_map.insert(ARCHIVE_SIGNATURE_HEADER, HeaderValue::from_str(&archive_signature).unwrap());
+    }
+
+    header_map
+}
+
+/// Creates a new [Response] with payload too large status by [usize] configured limit and [usize] actual request value
+pub fn create_limit_exceeded_response(limit: usize, actual: usize) -> Response {
+    let limit_exceeded_response = LimitExceededResponse {
+        error: String::from("LIMIT_EXCEEDED"),
+        limit,
+        actual,
+    };
+    (StatusCode::PAYLOAD_TOO_LARGE, Json(limit_exceeded_response)).into_response()
+}
+
+/// Maps download by keys end-point (selected S3 keys with optional zip entry names - pending S3 downloads are aborted
+/// if the client disconnects)
+async fn map_download_keys(
+    State(download_service): State<DynDownloadService>,
+    keys_download_request: ValidJson<KeysDownloadRequest>,
+) -> impl IntoResponse {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_KEYS_AS_ZIP_PATH} - bucket name: {} - path: {}",
+        keys_download_request.bucket_name, keys_download_request.full_path
+    );
+    let cancellation_token = CancellationToken::new();
+    let download_request = create_download_request(keys_download_request.0, cancellation_token.clone());
+    let download_response = create_keep_alive_download_response(download_service, download_request, request_context).await;
+    cancel_on_disconnect(download_response, cancellation_token)
+}
+
+/// Creates a new [DownloadRequest] download request (with a manifest) by [KeysDownloadRequest] keys download request
+/// and [CancellationToken] export cancellation token
+fn create_download_request(mut keys_download_request: KeysDownloadRequest, cancellation_token: CancellationToken) -> DownloadRequest {
+    let manifest = keys_download_request
+        .s3_keys
+        .into_iter()
+        .map(|s3_key| ManifestEntry {
+            archive_name: keys_download_request.rename.remove(&s3_key),
+            s3_key,
+        })
+        .collect();
+
+    DownloadRequest {
+        bucket_name: keys_download_request.bucket_name,
+        full_path: keys_download_request.full_path,
+        deduplicate: false,
+        max_parallel_fetches: None,
+        multipart_chunk_bytes: None,
+        manifest: Some(manifest),
+        filter: None,
+        duplicate_entry_policy: keys_download_request.duplicate_entry_policy,
+        missing_key_policy: MissingKeyPolicy::default(),
+        direct_key_lookup: keys_download_request.direct_key_lookup,
+        allow_oversize: keys_download_request.allow_oversize,
+        encryption_recipient: keys_download_request.encryption_recipient,
+        notify_email: None,
+        volumes: None,
+        split_by_folder: false,
+        priority: DownloadJobPriority::default(),
+        caller_id: keys_download_request.caller_id,
+        restore: None,
+        metadata_report: None,
+        checksum_audit: false,
+        public_base_url: None,
+        cancellation_token,
     }
 }
 
+/// Maps batch download end-point (raw files as multipart/mixed parts - no archive packaging - pending S3 downloads
+/// are aborted if the client disconnects)
+async fn map_download_batch(
+    State(download_service): State<DynDownloadService>,
+    batch_download_request: ValidJson<BatchDownloadRequest>,
+) -> impl IntoResponse {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_BATCH_PATH} - bucket name: {} - path: {}",
+        batch_download_request.bucket_name, batch_download_request.full_path
+    );
+    let mut batch_download_request = batch_download_request.0;
+    let cancellation_token = CancellationToken::new();
+    batch_download_request.cancellation_token = cancellation_token.clone();
+    match download_service.download_files_as_multipart(batch_download_request).await {
+        Ok((multipart_boundary, multipart_content, multipart_completion)) => {
+            let content_type = format!("multipart/mixed; boundary={multipart_boundary}");
+            let export_completion = ExportCompletion::default();
+            let body = create_multipart_body(multipart_content, multipart_completion, export_completion.clone());
+            let mut batch_response = ([(CONTENT_TYPE, content_type)], body).into_response();
+            batch_response.extensions_mut().insert(export_completion);
+            cancel_on_disconnect(batch_response, cancellation_token)
+        }
+        Err(CommonError::MEMORY_BUDGET_EXCEEDED) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, EXPORT_MEMORY_BUDGET_RETRY_AFTER_SECONDS)],
+        )
+            .into_response(),
+        Err(CommonError::QUOTA_EXCEEDED) => StatusCode::TOO_MANY_REQUESTS.into_response(),
+        Err(CommonError::LIMIT_EXCEEDED { limit, actual }) => create_limit_exceeded_response(limit, actual),
+        Err(download_error) => {
+            ERROR_REPORTER.report_error(&download_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Creates a new streamed [Body] by [DuplexStream] multipart content: the content is completed just if its
+/// [oneshot::Receiver<()>] multipart completion is received once the content is sent (the multipart writer drops it
+/// on failure - i.e.: a file can't be fetched), otherwise the body ends with an error (the client gets a broken
+/// response instead of a multipart content without closing boundary) - [ExportCompletion] export completion is marked
+/// just once the multipart completion is received
+fn create_multipart_body(
+    multipart_content: DuplexStream,
+    multipart_completion: oneshot::Receiver<()>,
+    export_completion: ExportCompletion,
+) -> Body {
+    let multipart_end = stream::once(async move {
+        if multipart_completion.await.is_err() {
+            warn!("create_multipart_body - multipart content not completed - response body ended with error");
+            return Some(Err(Error::other("multipart content not completed")));
+        }
+        export_completion.complete();
+        None
+    })
+    .filter_map(future::ready);
+    Body::from_stream(ReaderStream::new(multipart_content).chain(multipart_end))
+}
+
+/// Maps archive preview end-point (export file name and entry names - nothing is downloaded)
+async fn map_preview(
+    State(download_service): State<DynDownloadService>,
+    download_request: ValidJson<DownloadRequest>,
+) -> Response {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_PREVIEW_PATH} - bucket name: {} - path: {}",
+        download_request.bucket_name, download_request.full_path
+    );
+    match download_service.preview_archive(download_request.0).await {
+        Ok(archive_preview) => Json(archive_preview).into_response(),
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER) => StatusCode::BAD_REQUEST.into_response(),
+        Err(CommonError::ACCESS_DENIED) => StatusCode::FORBIDDEN.into_response(),
+        Err(CommonError::LIMIT_EXCEEDED { limit, actual }) => create_limit_exceeded_response(limit, actual),
+        Err(preview_error) => {
+            ERROR_REPORTER.report_error(&preview_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Maps S3-hosted archive entries listing end-point (ZIP central directory or tar headers - archive is not downloaded)
+async fn map_archive_entries(
+    State(download_service): State<DynDownloadService>,
+    archive_request: ValidJson<ArchiveRequest>,
+) -> Response {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ARCHIVE_ENTRIES_PATH} - bucket name: {} - s3 key: {}",
+        archive_request.bucket_name, archive_request.s3_key
+    );
+    match download_service.list_archive_entries(archive_request.0).await {
+        Ok(archive_entries) => Json(archive_entries).into_response(),
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER) => StatusCode::BAD_REQUEST.into_response(),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NOT_FOUND.into_response(),
+        Err(CommonError::ACCESS_DENIED) => StatusCode::FORBIDDEN.into_response(),
+        Err(archive_error) => {
+            ERROR_REPORTER.report_error(&archive_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Maps S3-hosted archive selective extraction end-point (selected entries streamed as zip file - pending archive
+/// entry reads are aborted if the client disconnects)
+async fn map_archive_extract(
+    State(download_service): State<DynDownloadService>,
+    archive_request: ValidJson<ArchiveRequest>,
+) -> Response {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ARCHIVE_EXTRACT_PATH} - bucket name: {} - s3 key: {}",
+        archive_request.bucket_name, archive_request.s3_key
+    );
+    let mut archive_request = archive_request.0;
+    let cancellation_token = CancellationToken::new();
+    archive_request.cancellation_token = cancellation_token.clone();
+    let download_response = create_download_response(download_service.extract_archive_entries(archive_request).await, &request_context);
+    cancel_on_disconnect(download_response, cancellation_token)
+}
+
 /// Creates a new [HeaderMap] with [CONTENT_TYPE] and [CONTENT_DISPOSITION] headers based on [&str] filename
+/// (encrypted zip files are sent as binary content, sharded exports manifests and JSON metadata reports as JSON content
+/// and CSV metadata reports as CSV content)
 pub fn create_export_headers(filename: &str) -> HeaderMap {
     let mut header_map = HeaderMap::new();
-    header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/zip; charset=utf-8"));
+    if filename.ends_with(ENCRYPTED_EXPORT_FILE_EXTENSION) {
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/octet-stream"));
+    } else if filename.ends_with(".json") {
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    } else if filename.ends_with(".csv") {
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("text/csv; charset=utf-8"));
+    } else {
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/zip; charset=utf-8"));
+    }
     header_map.insert(CONTENT_DISPOSITION, HeaderValue::from_str(&format!("attachment; filename=\"{filename}\"")).unwrap());
 
     header_map
@@ -68,4 +570,168 @@ pub fn create_export_headers(filename: &str) -> HeaderMap {
 /// Unit test cases
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use crate::constant::constants::{ARCHIVE_SHA256_HEADER, EXCLUDED_COUNT_HEADER, EXPORT_KEEP_ALIVE_BYTES};
+    use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+    use crate::controller::download_controller::{cancel_on_disconnect, create_archive_checksum_body, create_download_response, start_download_export, ExportCompletion};
+    use crate::dto::download_export::DownloadExport;
+    use crate::dto::export_report::ExportReport;
+    use crate::enums::common_error::CommonError;
+    use crate::service::download_service::encode_archive_digest;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::response::Response;
+    use http_body_util::BodyExt;
+    use sha2::{Digest, Sha256};
+    use tokio::io::{duplex, AsyncWriteExt};
+    use tokio::sync::oneshot;
+    use tokio_util::sync::CancellationToken;
+
+    /// Scenario:
+    /// Streams an archive content through a checksum body
+    /// Expectation:
+    /// The whole archive content should be sent, followed by its SHA-256 checksum as trailer header
+    #[tokio::test]
+    async fn when_stream_archive_checksum_body_should_send_sha256_trailer() {
+        let (mut archive_content_writer, archive_content) = duplex(16);
+        let (export_report_sender, export_report) = oneshot::channel();
+        tokio::spawn(async move {
+            archive_content_writer.write_all(b"some archive content to be streamed").await.unwrap();
+            export_report_sender.send(ExportReport::default()).unwrap();
+        });
+
+        let collected_body = create_archive_checksum_body(archive_content, export_report, Default::default()).collect().await.unwrap();
+        let archive_sha256 = collected_body.trailers().unwrap().get(ARCHIVE_SHA256_HEADER).unwrap().to_str().unwrap().to_owned();
+
+        assert_eq!(encode_archive_digest(&Sha256::digest(b"some archive content to be streamed")), archive_sha256);
+        assert_eq!(b"some archive content to be streamed".as_slice(), collected_body.to_bytes().as_ref());
+    }
+
+    /// Scenario:
+    /// Streams an archive content through a checksum body, but the archive writer fails (export report is dropped)
+    /// Expectation:
+    /// The body should end with an error (instead of a clean end of a truncated archive)
+    #[tokio::test]
+    async fn when_archive_writer_fails_should_end_body_with_error() {
+        let (mut archive_content_writer, archive_content) = duplex(16);
+        let (export_report_sender, export_report) = oneshot::channel::<ExportReport>();
+        tokio::spawn(async move {
+            archive_content_writer.write_all(b"some truncated archive").await.unwrap();
+            drop(export_report_sender);
+        });
+
+        assert!(create_archive_checksum_body(archive_content, export_report, Default::default()).collect().await.is_err());
+    }
+
+    /// Scenario:
+    /// Abandons a streamed export response before its body is completely sent (client disconnected) and sends another
+    /// streamed export response completely
+    /// Expectation:
+    /// Both export cancellation tokens should be cancelled just once the response bodies are dropped and the abandoned
+    /// export should be recorded as aborted
+    #[tokio::test]
+    async fn when_response_dropped_should_cancel_export() {
+        let exports_aborted = USAGE_STATS_REGISTRY.get_usage_stats().exports_aborted;
+        let cancellation_token = CancellationToken::new();
+        let download_response = cancel_on_disconnect(Response::new(Body::from("zip content")), cancellation_token.clone());
+        assert!(!cancellation_token.is_cancelled());
+
+        drop(download_response);
+        assert!(cancellation_token.is_cancelled());
+        assert!(USAGE_STATS_REGISTRY.get_usage_stats().exports_aborted > exports_aborted);
+
+        let cancellation_token = CancellationToken::new();
+        let download_response = cancel_on_disconnect(Response::new(Body::from("zip content")), cancellation_token.clone());
+        let collected_body = download_response.into_body().collect().await.unwrap();
+        assert_eq!(b"zip content".as_slice(), collected_body.to_bytes().as_ref());
+        assert!(cancellation_token.is_cancelled());
+    }
+
+    /// Scenario:
+    /// Sends a streamed archive export response until its body ends, but the archive writer fails (export report is
+    /// dropped) and sends another streamed archive export response whose writer succeeds
+    /// Expectation:
+    /// Just the export whose writer succeeds should be marked as completed
+    #[tokio::test]
+    async fn when_archive_writer_fails_should_not_complete_export() {
+        for writer_succeeds in [false, true] {
+            let (mut archive_content_writer, archive_content) = duplex(16);
+            let (export_report_sender, export_report) = oneshot::channel::<ExportReport>();
+            tokio::spawn(async move {
+                archive_content_writer.write_all(b"some archive content").await.unwrap();
+                if writer_succeeds {
+                    export_report_sender.send(ExportReport::default()).unwrap();
+                }
+            });
+            let download_export = DownloadExport {
+                file_name: String::from("export.zip"),
+                content: archive_content,
+                report: export_report,
+                excluded_count: 0,
+            };
+
+            let download_response = create_download_response(Ok(download_export), "test");
+            let export_completion = download_response.extensions().get::<ExportCompletion>().cloned().unwrap();
+            let _ = download_response.into_body().collect().await;
+
+            assert_eq!(writer_succeeds, export_completion.is_completed());
+        }
+    }
+
+    /// Scenario:
+    /// Starts a zip export whose S3 listing is still pending once the keep-alive interval is elapsed and completes the
+    /// S3 listing just once the first response body chunk is received
+    /// Expectation:
+    /// Response headers and keep-alive bytes should be sent before the S3 listing is completed, followed by the archive
+    /// content and its SHA-256 checksum (keep-alive bytes included) and excluded files total as trailer headers
+    #[tokio::test]
+    async fn when_export_not_started_within_keep_alive_interval_should_send_keep_alive_bytes() {
+        let (listing_sender, listing_receiver) = oneshot::channel::<Result<DownloadExport, CommonError>>();
+        let download_export = Box::pin(async move { listing_receiver.await.unwrap() });
+
+        let download_response = start_download_export(download_export, Duration::from_millis(10), String::from("test")).await;
+        assert_eq!(StatusCode::OK, download_response.status());
+        let mut response_body = download_response.into_body();
+        let first_chunk = response_body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(EXPORT_KEEP_ALIVE_BYTES, first_chunk.as_ref());
+
+        let (mut archive_content_writer, archive_content) = duplex(16);
+        let (export_report_sender, export_report) = oneshot::channel();
+        let download_export = DownloadExport {
+            file_name: String::from("s3-export.zip"),
+            content: archive_content,
+            report: export_report,
+            excluded_count: 2,
+        };
+        assert!(listing_sender.send(Ok(download_export)).is_ok());
+        archive_content_writer.write_all(b"zip content").await.unwrap();
+        drop(archive_content_writer);
+        export_report_sender.send(ExportReport::default()).unwrap();
+        let collected_body = response_body.collect().await.unwrap();
+        let trailers = collected_body.trailers().cloned().unwrap();
+        let sent_content = [first_chunk.as_ref(), collected_body.to_bytes().as_ref()].concat();
+
+        assert!(sent_content.ends_with(b"zip content"));
+        assert_eq!(encode_archive_digest(&Sha256::digest(&sent_content)), trailers.get(ARCHIVE_SHA256_HEADER).unwrap().to_str().unwrap());
+        assert_eq!("2", trailers.get(EXCLUDED_COUNT_HEADER).unwrap().to_str().unwrap());
+    }
+
+    /// Scenario:
+    /// Starts a zip export whose S3 listing is still pending once the keep-alive interval is elapsed, but the S3 path
+    /// doesn't exist
+    /// Expectation:
+    /// The response body should end with an error (response headers were already sent)
+    #[tokio::test]
+    async fn when_export_not_started_after_keep_alive_bytes_should_end_body_with_error() {
+        let (listing_sender, listing_receiver) = oneshot::channel::<Result<DownloadExport, CommonError>>();
+        let download_export = Box::pin(async move { listing_receiver.await.unwrap() });
+
+        let download_response = start_download_export(download_export, Duration::from_millis(10), String::from("test")).await;
+        let mut response_body = download_response.into_body();
+        assert!(response_body.frame().await.unwrap().is_ok());
+        assert!(listing_sender.send(Err(CommonError::NO_DATA_FOUND)).is_ok());
+
+        assert!(response_body.collect().await.is_err());
+    }
 }
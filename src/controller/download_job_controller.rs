@@ -0,0 +1,230 @@
+use axum::body::{Body, Bytes};
+use axum::extract::{Path, State};
+use axum::http::header::{ACCEPT_RANGES, CONTENT_RANGE, RANGE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use crate::config::app_state::AppState;
+use crate::config::base_path::get_forwarded_public_base_url;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::request_validation::ValidJson;
+use crate::constant::constants::{API_DOWNLOAD_ARTIFACT_PATH, API_DOWNLOAD_JOBS_PATH, API_DOWNLOAD_JOB_BY_ID_PATH, API_DOWNLOAD_JOB_RERUN_PATH, API_DOWNLOAD_JOB_RESULT_PATH, API_DOWNLOAD_MAIN_PATH, API_V2_MAIN_PATH};
+use crate::controller::download_controller::{create_archive_integrity_headers, create_export_headers};
+use crate::controller::export_controller::create_export_routes;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::rerun_job_request::RerunJobRequest;
+use crate::enums::common_error::CommonError;
+use crate::middleware::download_quota_middleware::get_request_caller_id;
+use crate::middleware::rbac_middleware::is_download_request_allowed;
+use crate::middleware::tenant_middleware::{get_request_tenant_id, is_tenant_path};
+use crate::service::download_job_service::DynDownloadJobService;
+use sha2::{Digest, Sha256};
+
+/// Download job controller
+pub trait DownloadJobControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Download job controller implementation struct
+pub struct DownloadJobController {}
+
+/// Download job controller implementation logic
+impl DownloadJobControllerTrait for DownloadJobController {
+    /// Configure declared endpoints for this controller
+    /// (API v1 download job routes and API v2 export routes share the same download job service)
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_DOWNLOAD_MAIN_PATH, create_routes())
+            .nest(API_V2_MAIN_PATH, create_export_routes())
+            .with_state(app_state.download_job_service.clone())
+    }
+}
+
+/// Creates download job routes
+fn create_routes() -> Router<DynDownloadJobService> {
+    Router::new()
+        .route(API_DOWNLOAD_JOBS_PATH, post(map_create_job))
+        .route(API_DOWNLOAD_JOB_BY_ID_PATH, get(map_get_job))
+        .route(API_DOWNLOAD_JOB_RERUN_PATH, post(map_rerun_job))
+        .route(API_DOWNLOAD_JOB_RESULT_PATH, get(map_get_job_result))
+        .route(API_DOWNLOAD_ARTIFACT_PATH, get(map_get_artifact))
+}
+
+/// Maps create download job end-point
+async fn map_create_job(
+    State(download_job_service): State<DynDownloadJobService>,
+    request_headers: HeaderMap,
+    ValidJson(mut download_request): ValidJson<DownloadRequest>,
+) -> impl IntoResponse {
+    download_request.public_base_url = get_forwarded_public_base_url(&request_headers);
+    let download_job = download_job_service.create_job(download_request).await;
+    (StatusCode::ACCEPTED, Json(download_job)).into_response()
+}
+
+/// Maps get download job end-point (jobs of other tenants are not found, if multi-tenancy is enabled)
+async fn map_get_job(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(job_id): Path<String>,
+    request_headers: HeaderMap,
+) -> impl IntoResponse {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    match download_job_service.get_job(job_id).await {
+        Ok(download_job) if tenant_id.is_none() || download_job.tenant_id == tenant_id => Json(download_job).into_response(),
+        _ => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Maps re-run download job end-point (the export request of a previous job - from export history - is started as a new
+/// download job, optionally in incremental mode)
+/// Important: the previous S3 bucket and path are authorized for the requester role (the request has no body to be scoped
+/// by the access control middleware) and, if multi-tenancy is enabled, just the requester tenant jobs are found
+async fn map_rerun_job(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(job_id): Path<String>,
+    request_headers: HeaderMap,
+    rerun_job_request: Option<Json<RerunJobRequest>>,
+) -> impl IntoResponse {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let mut export_record = match download_job_service.get_export_record(job_id).await {
+        Ok(export_record) if tenant_id.as_deref().is_none_or(|tenant_id| is_tenant_path(tenant_id, &export_record.full_path)) => {
+            export_record
+        }
+        _ => return StatusCode::NOT_FOUND.into_response(),
+    };
+    if !is_download_request_allowed(&request_headers, &export_record.request).await {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}/jobs/{}/rerun - bucket name: {} - path: {}",
+        export_record.job_id, export_record.bucket_name, export_record.full_path
+    );
+    export_record.request.caller_id = get_request_caller_id(&request_headers);
+    export_record.request.public_base_url = get_forwarded_public_base_url(&request_headers);
+    let incremental = rerun_job_request.is_some_and(|Json(rerun_job_request)| rerun_job_request.incremental);
+    match download_job_service.rerun_job(export_record, incremental).await {
+        Ok(download_job) => (StatusCode::ACCEPTED, Json(download_job)).into_response(),
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER) => StatusCode::BAD_REQUEST.into_response(),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NO_CONTENT.into_response(),
+        Err(CommonError::ACCESS_DENIED) => StatusCode::FORBIDDEN.into_response(),
+        Err(rerun_error) => {
+            ERROR_REPORTER.report_error(&rerun_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Maps get download job result end-point
+/// Important: supports a single byte range by [RANGE] header to resume interrupted downloads
+async fn map_get_job_result(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(resume_token): Path<String>,
+    request_headers: HeaderMap,
+) -> impl IntoResponse {
+    match download_job_service.get_job_result(resume_token).await {
+        Ok((zip_file_name, zip_content)) => create_job_result_response(&zip_file_name, zip_content, &request_headers),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Maps get content-addressable artifact end-point (stable URL of stored job results)
+/// Important: supports a single byte range by [RANGE] header to resume interrupted downloads
+async fn map_get_artifact(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(artifact_name): Path<String>,
+    request_headers: HeaderMap,
+) -> impl IntoResponse {
+    match download_job_service.get_artifact(artifact_name).await {
+        Ok((artifact_name, artifact_content)) => create_job_result_response(&artifact_name, artifact_content, &request_headers),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Creates a new [Response] by [&str] stored zip file name, [Bytes] zip content and [&HeaderMap] request headers
+/// (full content, or just the requested byte range if a valid [RANGE] header is defined - whole archive checksum
+/// and signature headers are always sent)
+pub fn create_job_result_response(zip_file_name: &str, zip_content: Bytes, request_headers: &HeaderMap) -> Response {
+    let mut headers = create_export_headers(zip_file_name);
+    headers.insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    headers.extend(create_archive_integrity_headers(&Sha256::digest(&zip_content)));
+
+    let range_header = request_headers.get(RANGE).and_then(|range| range.to_str().ok());
+    match range_header.map(|range| parse_range_header(range, zip_content.len())) {
+        None => (headers, Body::from(zip_content)).into_response(),
+        Some(Some((range_start, range_end))) => {
+            let content_range = format!("bytes {range_start}-{range_end}/{}", zip_content.len());
+            headers.insert(CONTENT_RANGE, HeaderValue::from_str(&content_range).unwrap());
+            let body = Body::from(zip_content.slice(range_start..=range_end));
+            (StatusCode::PARTIAL_CONTENT, headers, body).into_response()
+        }
+        Some(None) => {
+            let content_range = format!("bytes */{}", zip_content.len());
+            headers.insert(CONTENT_RANGE, HeaderValue::from_str(&content_range).unwrap());
+            (StatusCode::RANGE_NOT_SATISFIABLE, headers).into_response()
+        }
+    }
+}
+
+/// Gets [Option<(usize, usize)>] inclusive byte range by [&str] range header value
+/// (i.e.: "bytes=0-99", "bytes=100-" or "bytes=-100") and [usize] content length
+/// Returns [None] if range is not valid or can't be satisfied (multiple ranges are not supported)
+fn parse_range_header(range: &str, content_length: usize) -> Option<(usize, usize)> {
+    let (range_start, range_end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let last_byte = content_length.checked_sub(1)?;
+
+    let (range_start, range_end) = match (range_start.trim(), range_end.trim()) {
+        ("", suffix_length) => {
+            let suffix_length: usize = suffix_length.parse().ok()?;
+            (content_length.checked_sub(suffix_length.min(content_length))?, last_byte)
+        }
+        (range_start, "") => (range_start.parse().ok()?, last_byte),
+        (range_start, range_end) => (range_start.parse().ok()?, range_end.parse::<usize>().ok()?.min(last_byte)),
+    };
+
+    if range_start > range_end {
+        return None;
+    }
+
+    Some((range_start, range_end))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::controller::download_job_controller::parse_range_header;
+
+    /// Scenario:
+    /// Parses valid range header values (start-end, open end and suffix)
+    /// Expectation:
+    /// Inclusive byte ranges limited by content length should be retrieved
+    #[test]
+    fn when_parse_valid_range_header_should_retrieve_byte_range() {
+        assert_eq!(Some((0, 99)), parse_range_header("bytes=0-99", 1000));
+        assert_eq!(Some((900, 999)), parse_range_header("bytes=900-", 1000));
+        assert_eq!(Some((900, 999)), parse_range_header("bytes=-100", 1000));
+        assert_eq!(Some((500, 999)), parse_range_header("bytes=500-5000", 1000));
+    }
+
+    /// Scenario:
+    /// Parses not valid or not satisfiable range header values
+    /// Expectation:
+    /// No byte range should be retrieved
+    #[test]
+    fn when_parse_not_valid_range_header_should_retrieve_none() {
+        assert_eq!(None, parse_range_header("bytes=1000-", 1000));
+        assert_eq!(None, parse_range_header("bytes=10-5", 1000));
+        assert_eq!(None, parse_range_header("bytes=0-1,5-9", 1000));
+        assert_eq!(None, parse_range_header("items=0-1", 1000));
+        assert_eq!(None, parse_range_header("bytes=0-", 0));
+    }
+}
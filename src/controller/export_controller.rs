@@ -0,0 +1,119 @@
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::header::LOCATION;
+use axum::http::{HeaderMap, StatusCode};
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use crate::config::base_path::{get_api_base_path, get_forwarded_public_base_url};
+use crate::config::request_validation::{ValidateRequest, create_validation_error_response};
+use crate::constant::constants::{API_V2_EXPORTS_PATH, API_V2_EXPORT_BY_ID_PATH, API_V2_EXPORT_RESULT_PATH, API_V2_MAIN_PATH};
+use crate::controller::download_job_controller::create_job_result_response;
+use crate::dto::api_error::ApiError;
+use crate::dto::download_job::DownloadJob;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::export_job::ExportJob;
+use crate::enums::common_error::CommonError;
+use crate::middleware::tenant_middleware::get_request_tenant_id;
+use crate::service::download_job_service::DynDownloadJobService;
+
+/// Creates API v2 export routes (job-first flow: exports are always executed as download jobs)
+pub fn create_export_routes() -> Router<DynDownloadJobService> {
+    Router::new()
+        .route(API_V2_EXPORTS_PATH, post(map_create_export))
+        .route(API_V2_EXPORT_BY_ID_PATH, get(map_get_export))
+        .route(API_V2_EXPORT_RESULT_PATH, get(map_get_export_result))
+}
+
+/// Maps create export end-point (accepted status with job status location)
+async fn map_create_export(
+    State(download_job_service): State<DynDownloadJobService>,
+    request_headers: HeaderMap,
+    download_request: Result<Json<DownloadRequest>, JsonRejection>,
+) -> Response {
+    let Json(mut download_request) = match download_request {
+        Ok(download_request) => download_request,
+        Err(json_rejection) => {
+            return create_api_error_response(&CommonError::NO_VALID_INPUT_OR_PARAMETER, &json_rejection.body_text());
+        }
+    };
+    let field_errors = download_request.validate();
+    if !field_errors.is_empty() {
+        return create_validation_error_response(field_errors);
+    }
+
+    download_request.public_base_url = get_forwarded_public_base_url(&request_headers);
+    let export_job = create_export_job(download_job_service.create_job(download_request).await);
+    (StatusCode::ACCEPTED, [(LOCATION, export_job.location.clone())], Json(export_job)).into_response()
+}
+
+/// Maps get export end-point (export jobs of other tenants are not found, if multi-tenancy is enabled)
+async fn map_get_export(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(job_id): Path<String>,
+    request_headers: HeaderMap,
+) -> Response {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    match download_job_service.get_job(job_id).await {
+        Ok(download_job) if tenant_id.is_none() || download_job.tenant_id == tenant_id => {
+            Json(create_export_job(download_job)).into_response()
+        }
+        Ok(_) => create_api_error_response(&CommonError::NO_DATA_FOUND, "export job not found"),
+        Err(get_job_error) => create_api_error_response(&get_job_error, "export job not found"),
+    }
+}
+
+/// Maps get export result end-point
+/// Important: supports a single byte range by [axum::http::header::RANGE] header to resume interrupted downloads
+async fn map_get_export_result(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(resume_token): Path<String>,
+    request_headers: HeaderMap,
+) -> Response {
+    match download_job_service.get_job_result(resume_token).await {
+        Ok((zip_file_name, zip_content)) => create_job_result_response(&zip_file_name, zip_content, &request_headers),
+        Err(get_job_result_error) => create_api_error_response(&get_job_result_error, "export result not found or expired"),
+    }
+}
+
+/// Creates a new [ExportJob] by [DownloadJob] download job (relative status and result locations)
+fn create_export_job(download_job: DownloadJob) -> ExportJob {
+    let api_v2_path = format!("{}{API_V2_MAIN_PATH}", get_api_base_path());
+    ExportJob {
+        location: format!("{api_v2_path}{}", API_V2_EXPORT_BY_ID_PATH.replace("{job_id}", &download_job.job_id)),
+        result_location: download_job
+            .resume_token
+            .as_deref()
+            .map(|resume_token| format!("{api_v2_path}{}", API_V2_EXPORT_RESULT_PATH.replace("{resume_token}", resume_token))),
+        job: download_job,
+    }
+}
+
+/// Creates a new [Response] with a typed [ApiError] payload by [&CommonError] error and [&str] error message
+pub fn create_api_error_response(common_error: &CommonError, message: &str) -> Response {
+    let status_code = match common_error {
+        CommonError::NO_VALID_INPUT_OR_PARAMETER => StatusCode::BAD_REQUEST,
+        CommonError::NO_DATA_FOUND => StatusCode::NOT_FOUND,
+        CommonError::ACCESS_DENIED => StatusCode::FORBIDDEN,
+        CommonError::QUOTA_EXCEEDED => StatusCode::TOO_MANY_REQUESTS,
+        CommonError::LIMIT_EXCEEDED { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+        CommonError::MEMORY_BUDGET_EXCEEDED => StatusCode::SERVICE_UNAVAILABLE,
+        CommonError::RANGE_NOT_SATISFIABLE => StatusCode::RANGE_NOT_SATISFIABLE,
+        CommonError::OBJECT_LOCKED => StatusCode::LOCKED,
+        CommonError::AWS_ACCESS_ERROR => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let error_code = match common_error {
+        CommonError::LIMIT_EXCEEDED { .. } => String::from("LIMIT_EXCEEDED"),
+        common_error => common_error.to_string(),
+    };
+    let api_error = ApiError {
+        error: error_code,
+        message: String::from(message),
+    };
+    (status_code, Json(api_error)).into_response()
+}
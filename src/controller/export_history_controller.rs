@@ -0,0 +1,102 @@
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use crate::config::app_state::AppState;
+use crate::config::authorization_hook::AUTHORIZATION_HOOK;
+use crate::config::request_validation::ValidQuery;
+use crate::constant::constants::{API_DOWNLOAD_HISTORY_PATH, API_DOWNLOAD_MAIN_PATH, EXPORT_HISTORY_PAGE_SIZE_DEFAULT, EXPORT_HISTORY_PAGE_SIZE_LIMIT, RBAC_ROLE_HEADER};
+use crate::dto::export_history_request::ExportHistoryRequest;
+use crate::dto::export_history_response::ExportHistoryResponse;
+use crate::dto::export_record::ExportRecord;
+use crate::enums::authorization_decision::AuthorizationDecision;
+use crate::enums::export_filter::parse_filter_date;
+use crate::enums::rbac_operation::RbacOperation;
+use crate::middleware::tenant_middleware::{get_request_tenant_id, is_tenant_path};
+use crate::service::download_job_service::DynDownloadJobService;
+
+/// Export history controller (finished download jobs audit records)
+pub trait ExportHistoryControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Export history controller implementation struct
+pub struct ExportHistoryController {}
+
+/// Export history controller implementation logic
+impl ExportHistoryControllerTrait for ExportHistoryController {
+    /// Configure declared endpoints for this controller (records are stored by the download job service)
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_DOWNLOAD_MAIN_PATH, Router::new().route(API_DOWNLOAD_HISTORY_PATH, get(map_get_export_history)))
+            .with_state(app_state.download_job_service.clone())
+    }
+}
+
+/// Maps get export history end-point (one page of export records - newest records first)
+/// Important: just records of the requester tenant (if multi-tenancy is enabled) and records the requester role is
+/// allowed to list (if access control is enabled) are retrieved
+async fn map_get_export_history(
+    State(download_job_service): State<DynDownloadJobService>,
+    request_headers: HeaderMap,
+    ValidQuery(export_history_request): ValidQuery<ExportHistoryRequest>,
+) -> Response {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let role_name = request_headers.get(RBAC_ROLE_HEADER).and_then(|role_name| role_name.to_str().ok());
+    let parse_date_fn = |date: &Option<String>| date.as_deref().and_then(parse_filter_date).map(|date| date.max(0) as u64);
+    let cursor = export_history_request.cursor.as_deref().and_then(parse_history_cursor);
+    let limit = export_history_request
+        .limit
+        .unwrap_or(EXPORT_HISTORY_PAGE_SIZE_DEFAULT)
+        .min(EXPORT_HISTORY_PAGE_SIZE_LIMIT);
+
+    let export_records = download_job_service
+        .get_export_records(
+            export_history_request.bucket.clone(),
+            parse_date_fn(&export_history_request.from),
+            parse_date_fn(&export_history_request.to),
+        )
+        .await;
+    let mut export_history_response = ExportHistoryResponse { records: Vec::new(), next_cursor: None };
+    for export_record in export_records {
+        if cursor
+            .as_ref()
+            .is_some_and(|(finished_at, job_id)| (export_record.finished_at, &export_record.job_id) >= (*finished_at, job_id))
+            || tenant_id.as_deref().is_some_and(|tenant_id| !is_tenant_path(tenant_id, &export_record.full_path))
+        {
+            continue;
+        }
+        if AUTHORIZATION_HOOK.is_enabled()
+            && AUTHORIZATION_HOOK
+                .authorize(role_name, RbacOperation::LIST, &export_record.bucket_name, &export_record.full_path)
+                .await
+                == AuthorizationDecision::DENY
+        {
+            continue;
+        }
+        if export_history_response.records.len() == limit {
+            export_history_response.next_cursor = export_history_response.records.last().map(create_history_cursor);
+            break;
+        }
+        export_history_response.records.push(export_record);
+    }
+    Json(export_history_response).into_response()
+}
+
+/// Creates a new [String] export history page cursor by [&ExportRecord] last export record of the page
+fn create_history_cursor(export_record: &ExportRecord) -> String {
+    format!("{}:{}", export_record.finished_at, export_record.job_id)
+}
+
+/// Gets [Option<(u64, String)>] finish date and job identifier of the previous page last export record by [&str] cursor
+fn parse_history_cursor(cursor: &str) -> Option<(u64, String)> {
+    let (finished_at, job_id) = cursor.split_once(':')?;
+    Some((finished_at.parse().ok()?, String::from(job_id)))
+}
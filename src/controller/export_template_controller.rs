@@ -0,0 +1,220 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use crate::config::app_state::AppState;
+use crate::config::base_path::get_forwarded_public_base_url;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::request_validation::{create_validation_error_response, ValidJson, ValidateRequest};
+use crate::config::template_variables::{get_template_variable_values, resolve_template_request};
+use crate::constant::constants::{API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_TEMPLATES_PATH, API_DOWNLOAD_TEMPLATE_BY_ID_PATH, API_DOWNLOAD_TEMPLATE_JOBS_PATH};
+use crate::dto::export_template::ExportTemplate;
+use crate::dto::export_template_request::ExportTemplateRequest;
+use crate::dto::template_job_request::TemplateJobRequest;
+use crate::enums::export_filter::parse_filter_date;
+use crate::middleware::download_quota_middleware::get_request_caller_id;
+use crate::middleware::rbac_middleware::is_download_request_allowed;
+use crate::middleware::tenant_middleware::get_request_tenant_id;
+use crate::service::download_job_service::DynDownloadJobService;
+use uuid::Uuid;
+
+/// Export template controller (saved export requests - recurring exports are triggered by template identifier)
+pub trait ExportTemplateControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Export template controller implementation struct
+pub struct ExportTemplateController {}
+
+/// Export template controller implementation logic
+impl ExportTemplateControllerTrait for ExportTemplateController {
+    /// Configure declared endpoints for this controller (templates are stored by the download job service)
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(
+                API_DOWNLOAD_MAIN_PATH,
+                Router::new()
+                    .route(API_DOWNLOAD_TEMPLATES_PATH, post(map_create_template).get(map_get_templates))
+                    .route(
+                        API_DOWNLOAD_TEMPLATE_BY_ID_PATH,
+                        get(map_get_template).put(map_update_template).delete(map_remove_template),
+                    )
+                    .route(API_DOWNLOAD_TEMPLATE_JOBS_PATH, post(map_create_template_job)),
+            )
+            .with_state(app_state.download_job_service.clone())
+    }
+}
+
+/// Maps create export template end-point
+async fn map_create_template(
+    State(download_job_service): State<DynDownloadJobService>,
+    request_headers: HeaderMap,
+    ValidJson(export_template_request): ValidJson<ExportTemplateRequest>,
+) -> Response {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let now = current_epoch_seconds();
+    let export_template = create_export_template(Uuid::new_v4().to_string(), tenant_id, now, now, export_template_request);
+    download_job_service.save_template(export_template.clone()).await;
+    (StatusCode::CREATED, Json(export_template)).into_response()
+}
+
+/// Maps get export templates end-point (just the requester tenant templates, if multi-tenancy is enabled)
+async fn map_get_templates(State(download_job_service): State<DynDownloadJobService>, request_headers: HeaderMap) -> Response {
+    let tenant_id = match get_request_tenant_id(&request_headers) {
+        Ok(tenant_id) => tenant_id,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let export_templates: Vec<ExportTemplate> = download_job_service
+        .get_templates()
+        .await
+        .into_iter()
+        .filter(|export_template| export_template.tenant_id == tenant_id)
+        .collect();
+    Json(export_templates).into_response()
+}
+
+/// Maps get export template end-point
+async fn map_get_template(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(template_id): Path<String>,
+    request_headers: HeaderMap,
+) -> Response {
+    match get_tenant_template(&download_job_service, template_id, &request_headers).await {
+        Ok(export_template) => Json(export_template).into_response(),
+        Err(status_code) => status_code.into_response(),
+    }
+}
+
+/// Maps update export template end-point (template name and export request are replaced)
+async fn map_update_template(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(template_id): Path<String>,
+    request_headers: HeaderMap,
+    ValidJson(export_template_request): ValidJson<ExportTemplateRequest>,
+) -> Response {
+    let export_template = match get_tenant_template(&download_job_service, template_id, &request_headers).await {
+        Ok(export_template) => export_template,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let export_template = create_export_template(
+        export_template.template_id,
+        export_template.tenant_id,
+        export_template.created_at,
+        current_epoch_seconds(),
+        export_template_request,
+    );
+    download_job_service.save_template(export_template.clone()).await;
+    Json(export_template).into_response()
+}
+
+/// Maps remove export template end-point
+/// Important: the saved S3 bucket and path are authorized for the requester role (the request has no body to be scoped
+/// by the access control middleware)
+async fn map_remove_template(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(template_id): Path<String>,
+    request_headers: HeaderMap,
+) -> Response {
+    let export_template = match get_tenant_template(&download_job_service, template_id, &request_headers).await {
+        Ok(export_template) => export_template,
+        Err(status_code) => return status_code.into_response(),
+    };
+    if !is_download_request_allowed(&request_headers, &export_template.request).await {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match download_job_service.remove_template(export_template.template_id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Maps create download job by export template end-point (the saved export request is started as a download job - template
+/// variables are resolved by the optional request body parameters and schedule time)
+/// Important: the resolved S3 bucket and path are authorized for the requester role (the request has no body to be scoped
+/// by the access control middleware) and the caller is read from the request headers (download quotas)
+async fn map_create_template_job(
+    State(download_job_service): State<DynDownloadJobService>,
+    Path(template_id): Path<String>,
+    request_headers: HeaderMap,
+    template_job_request: Option<ValidJson<TemplateJobRequest>>,
+) -> Response {
+    let export_template = match get_tenant_template(&download_job_service, template_id, &request_headers).await {
+        Ok(export_template) => export_template,
+        Err(status_code) => return status_code.into_response(),
+    };
+    let template_job_request = template_job_request.map(|ValidJson(template_job_request)| template_job_request).unwrap_or_default();
+    let schedule_time = template_job_request
+        .schedule_time
+        .as_deref()
+        .and_then(parse_filter_date)
+        .unwrap_or_else(|| current_epoch_seconds() as i64);
+    let variable_values = match get_template_variable_values(&export_template.variables, &template_job_request.parameters, schedule_time) {
+        Ok(variable_values) => variable_values,
+        Err(field_errors) => return create_validation_error_response(field_errors),
+    };
+    let mut download_request = match resolve_template_request(&export_template.request, &variable_values) {
+        Ok(download_request) => download_request,
+        Err(_) => return StatusCode::BAD_REQUEST.into_response(),
+    };
+    let field_errors = download_request.validate();
+    if !field_errors.is_empty() {
+        return create_validation_error_response(field_errors);
+    }
+
+    if !is_download_request_allowed(&request_headers, &download_request).await {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    download_request.caller_id = get_request_caller_id(&request_headers);
+    download_request.public_base_url = get_forwarded_public_base_url(&request_headers);
+    let download_job = download_job_service.create_job(download_request).await;
+    (StatusCode::ACCEPTED, Json(download_job)).into_response()
+}
+
+/// Gets [ExportTemplate] export template by [DynDownloadJobService] download job service, [String] template identifier
+/// and [&HeaderMap] request headers (templates of other tenants are not found, if multi-tenancy is enabled)
+/// Returns an error [StatusCode] if tenant is not valid or template is not found
+async fn get_tenant_template(
+    download_job_service: &DynDownloadJobService,
+    template_id: String,
+    request_headers: &HeaderMap,
+) -> Result<ExportTemplate, StatusCode> {
+    let tenant_id = get_request_tenant_id(request_headers)?;
+    download_job_service
+        .get_template(template_id)
+        .await
+        .ok()
+        .filter(|export_template| export_template.tenant_id == tenant_id)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Creates a new [ExportTemplate] by [String] template identifier, [Option<String>] tenant identifier, [u64] creation
+/// and update dates and [ExportTemplateRequest] export template request (request caller and public base URL are not
+/// saved - they are set once the template is triggered)
+fn create_export_template(
+    template_id: String,
+    tenant_id: Option<String>,
+    created_at: u64,
+    updated_at: u64,
+    export_template_request: ExportTemplateRequest,
+) -> ExportTemplate {
+    let mut request = export_template_request.request;
+    request.caller_id = None;
+    request.public_base_url = None;
+    ExportTemplate {
+        template_id,
+        name: String::from(export_template_request.name.trim()),
+        tenant_id,
+        created_at,
+        updated_at,
+        variables: export_template_request.variables,
+        request,
+    }
+}
@@ -1,12 +1,15 @@
+use crate::config::app_state::AppState;
+use crate::config::temp_storage::check_temp_storage;
 use crate::constant::constants::*;
 use crate::dto::health::Health;
-use axum::{Router, response::IntoResponse, routing::get};
+use axum::http::StatusCode;
+use axum::{Json, Router, response::IntoResponse, routing::get};
 use serde_json::json;
 
 /// Health controller
 pub trait HealthControllerTrait {
     /// Configure declared endpoints for this controller
-    fn config_endpoints() -> Router;
+    fn config_endpoints(app_state: &AppState) -> Router;
 }
 
 /// Health controller implementation struct
@@ -15,7 +18,7 @@ pub struct HealthController {}
 /// Health controller implementation logic
 impl HealthControllerTrait for HealthController {
     /// Configure declared endpoints for this controller
-    fn config_endpoints() -> Router {
+    fn config_endpoints(_app_state: &AppState) -> Router {
         /// Maps health check end-point
         async fn map_health() -> impl IntoResponse {
             format!(
@@ -26,7 +29,18 @@ impl HealthControllerTrait for HealthController {
             )
         }
 
-        Router::new().route(API_HEALTH_CHECK_PATH, get(map_health))
+        /// Maps readiness check end-point (service unavailable status if temp storage is not ready)
+        async fn map_readiness() -> impl IntoResponse {
+            let (status_code, status) = match check_temp_storage() {
+                Some(Err(check_error)) => (StatusCode::SERVICE_UNAVAILABLE, check_error),
+                _ => (StatusCode::OK, String::from(SERVER_READY_STATUS)),
+            };
+            (status_code, Json(Health { status }))
+        }
+
+        Router::new()
+            .route(API_HEALTH_CHECK_PATH, get(map_health))
+            .route(API_READINESS_CHECK_PATH, get(map_readiness))
     }
 }
 
@@ -1,2 +1,16 @@
+pub mod admin_controller;
+pub mod cost_estimate_controller;
 pub mod download_controller;
+pub mod download_job_controller;
+pub mod export_controller;
+pub mod export_history_controller;
+pub mod export_template_controller;
 pub mod health_controller;
+pub mod object_controller;
+pub mod s3_event_controller;
+pub mod s3_object_controller;
+pub mod search_controller;
+pub mod signed_link_controller;
+pub mod static_website_controller;
+pub mod stats_controller;
+pub mod version_controller;
@@ -0,0 +1,386 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RANGE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+use crate::config::app_state::AppState;
+use crate::config::env_vars::get_env_var_as_usize;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::request_validation::ValidQuery;
+use crate::constant::constants::{API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_OBJECT_PATH, API_DOWNLOAD_TEXT_PREVIEW_PATH, API_DOWNLOAD_THUMBNAIL_PATH, TEXT_PREVIEW_SIZE_KB_DEFAULT, THUMBNAIL_CONTENT_TYPE, THUMBNAIL_SIZE_DEFAULT, THUMBNAIL_SOURCE_MAX_BYTES_DEFAULT, THUMBNAIL_SOURCE_MAX_BYTES_ENV_VAR};
+use crate::controller::download_controller::create_limit_exceeded_response;
+use crate::dto::object_request::ObjectRequest;
+use crate::dto::s3_object_stream::S3ObjectStream;
+use crate::dto::s3_object_stream_options::S3ObjectStreamOptions;
+use crate::dto::text_preview::TextPreview;
+use crate::dto::text_preview_request::TextPreviewRequest;
+use crate::dto::thumbnail_request::ThumbnailRequest;
+use crate::enums::common_error::CommonError;
+use crate::enums::text_encoding::TextEncoding;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use crate::utils::thumbnail_renderer::{THUMBNAIL_CACHE, THUMBNAIL_RENDERER};
+use log::error;
+use tokio_util::io::ReaderStream;
+
+/// Object controller (single S3 object proxy - S3 object content is streamed as it is)
+pub trait ObjectControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Object controller implementation struct
+pub struct ObjectController {}
+
+/// Object controller implementation logic
+impl ObjectControllerTrait for ObjectController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(
+                API_DOWNLOAD_MAIN_PATH,
+                Router::new()
+                    .route(API_DOWNLOAD_OBJECT_PATH, get(map_object))
+                    .route(API_DOWNLOAD_THUMBNAIL_PATH, get(map_thumbnail))
+                    .route(API_DOWNLOAD_TEXT_PREVIEW_PATH, get(map_text_preview)),
+            )
+            .with_state(app_state.aws_s3_service.clone())
+    }
+}
+
+/// Maps single S3 object proxy end-point (the `Range` request header is forwarded to S3, so media players and
+/// resumable downloaders can request partial content, and `If-None-Match` and `If-Modified-Since` request headers are
+/// forwarded to S3, so browser caches can revalidate proxied S3 objects)
+/// Important: just single byte ranges are supported by S3 (the whole S3 object is retrieved for multiple ranges)
+async fn map_object(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    request_headers: HeaderMap,
+    object_request: ValidQuery<ObjectRequest>,
+) -> Response {
+    let object_request = object_request.0;
+    match aws_s3_service
+        .get_s3_object_stream(
+            object_request.bucket_name,
+            object_request.full_path,
+            object_request.s3_key,
+            create_s3_object_stream_options(&request_headers),
+        )
+        .await
+    {
+        Ok(s3_object_stream) => create_object_response(s3_object_stream),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NOT_FOUND.into_response(),
+        Err(CommonError::RANGE_NOT_SATISFIABLE) => StatusCode::RANGE_NOT_SATISFIABLE.into_response(),
+        Err(object_error) => {
+            ERROR_REPORTER.report_error(&object_error, &format!("GET {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_OBJECT_PATH}"));
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Maps image S3 object thumbnail end-point (JPEG thumbnail - rendered thumbnails are cached by source S3 object ETag)
+/// Important: just available if `thumbnails` feature is enabled (otherwise a not found status response is retrieved)
+async fn map_thumbnail(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    thumbnail_request: ValidQuery<ThumbnailRequest>,
+) -> Response {
+    if !THUMBNAIL_RENDERER.is_enabled() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let thumbnail_request = thumbnail_request.0;
+    let thumbnail_size = thumbnail_request.size.unwrap_or(THUMBNAIL_SIZE_DEFAULT);
+    let request_context = format!("GET {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_THUMBNAIL_PATH}");
+
+    let s3_object_metadata = match aws_s3_service
+        .get_s3_object_metadata(
+            thumbnail_request.bucket_name.clone(),
+            thumbnail_request.full_path.clone(),
+            Some(thumbnail_request.s3_key.clone()),
+        )
+        .await
+    {
+        Ok(Some(s3_object_metadata)) => s3_object_metadata,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(thumbnail_error) => {
+            ERROR_REPORTER.report_error(&thumbnail_error, &request_context);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let cache_key = format!("{}/{}#{thumbnail_size}", thumbnail_request.full_path, thumbnail_request.s3_key);
+    let e_tag = s3_object_metadata.e_tag.unwrap_or_default();
+    if let Some(thumbnail_content) = THUMBNAIL_CACHE.get_body(&thumbnail_request.bucket_name, &cache_key, &e_tag) {
+        return create_thumbnail_response(thumbnail_content);
+    }
+
+    let source_max_bytes = get_env_var_as_usize(THUMBNAIL_SOURCE_MAX_BYTES_ENV_VAR, THUMBNAIL_SOURCE_MAX_BYTES_DEFAULT);
+    if s3_object_metadata.size_bytes.max(0) as usize > source_max_bytes {
+        return create_limit_exceeded_response(source_max_bytes, s3_object_metadata.size_bytes as usize);
+    }
+    let image_content = match aws_s3_service
+        .get_s3_object_stream(
+            thumbnail_request.bucket_name.clone(),
+            thumbnail_request.full_path,
+            thumbnail_request.s3_key,
+            S3ObjectStreamOptions::default(),
+        )
+        .await
+    {
+        Ok(s3_object_stream) => match s3_object_stream.content.collect().await {
+            Ok(image_content) => image_content.into_bytes(),
+            Err(read_error) => {
+                error!("map_thumbnail - image content can't be read - error: {read_error}");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+        Err(CommonError::NO_DATA_FOUND) => return StatusCode::NOT_FOUND.into_response(),
+        Err(thumbnail_error) => {
+            ERROR_REPORTER.report_error(&thumbnail_error, &request_context);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    match tokio::task::spawn_blocking(move || THUMBNAIL_RENDERER.render_thumbnail(&image_content, thumbnail_size)).await {
+        Ok(Ok(thumbnail_content)) => {
+            THUMBNAIL_CACHE.put(&thumbnail_request.bucket_name, &cache_key, &e_tag, &thumbnail_content);
+            create_thumbnail_response(thumbnail_content)
+        }
+        Ok(Err(_)) => StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response(),
+        Err(join_error) => {
+            error!("map_thumbnail - thumbnail rendering task failed - error: {join_error}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Creates a new [Response] by [Vec<u8>] JPEG thumbnail content (thumbnails can be cached by clients)
+fn create_thumbnail_response(thumbnail_content: Vec<u8>) -> Response {
+    (
+        [(CONTENT_TYPE, THUMBNAIL_CONTENT_TYPE), (CACHE_CONTROL, "private, max-age=3600")],
+        thumbnail_content,
+    )
+        .into_response()
+}
+
+/// Maps text S3 object preview end-point (just the first bytes are requested by a ranged request, so log or CSV files
+/// can be peeked without a full download)
+async fn map_text_preview(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    text_preview_request: ValidQuery<TextPreviewRequest>,
+) -> Response {
+    let text_preview_request = text_preview_request.0;
+    let preview_bytes = text_preview_request.size_kb.unwrap_or(TEXT_PREVIEW_SIZE_KB_DEFAULT) * 1024;
+    match aws_s3_service
+        .get_s3_object_range(
+            text_preview_request.bucket_name,
+            text_preview_request.full_path,
+            text_preview_request.s3_key.clone(),
+            format!("bytes=0-{}", preview_bytes - 1),
+        )
+        .await
+    {
+        Ok((content, size_bytes)) => Json(create_text_preview(
+            text_preview_request.s3_key,
+            &content,
+            size_bytes,
+            text_preview_request.detect_encoding,
+        ))
+        .into_response(),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NOT_FOUND.into_response(),
+        Err(text_preview_error) => {
+            ERROR_REPORTER.report_error(&text_preview_error, &format!("GET {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_TEXT_PREVIEW_PATH}"));
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Creates a new [TextPreview] by [String] S3 key, [&[u8]] previewed content, [u64] S3 object size and [bool] detect
+/// encoding flag (byte order mark, UTF-8 content or ISO-8859-1 fallback - otherwise content is decoded as UTF-8)
+fn create_text_preview(s3_key: String, content: &[u8], size_bytes: u64, detect_encoding: bool) -> TextPreview {
+    let (encoding, text_content) = match detect_encoding {
+        true => decode_detected_text(content),
+        false => (TextEncoding::UTF_8, decode_utf8_text(content)),
+    };
+    TextPreview {
+        s3_key,
+        size_bytes,
+        encoding,
+        content: text_content,
+        truncated: size_bytes > content.len() as u64,
+    }
+}
+
+/// Gets [(TextEncoding, String)] detected text encoding and decoded text by [&[u8]] content
+fn decode_detected_text(content: &[u8]) -> (TextEncoding, String) {
+    if let Some(content) = content.strip_prefix(b"\xEF\xBB\xBF") {
+        return (TextEncoding::UTF_8, decode_utf8_text(content));
+    }
+    if let Some(content) = content.strip_prefix(b"\xFF\xFE") {
+        return (TextEncoding::UTF_16LE, decode_utf16_text(content, u16::from_le_bytes));
+    }
+    if let Some(content) = content.strip_prefix(b"\xFE\xFF") {
+        return (TextEncoding::UTF_16BE, decode_utf16_text(content, u16::from_be_bytes));
+    }
+
+    match std::str::from_utf8(content) {
+        Err(utf8_error) if utf8_error.error_len().is_some() => {
+            (TextEncoding::ISO_8859_1, content.iter().map(|content_byte| char::from(*content_byte)).collect())
+        }
+        _ => (TextEncoding::UTF_8, decode_utf8_text(content)),
+    }
+}
+
+/// Gets [String] decoded UTF-8 text by [&[u8]] content (a trailing character split by the preview range is discarded
+/// and invalid sequences are replaced)
+fn decode_utf8_text(content: &[u8]) -> String {
+    let content = match std::str::from_utf8(content) {
+        Err(utf8_error) if utf8_error.error_len().is_none() => &content[..utf8_error.valid_up_to()],
+        _ => content,
+    };
+    String::from_utf8_lossy(content).into_owned()
+}
+
+/// Gets [String] decoded UTF-16 text by [&[u8]] content and [fn([u8; 2]) -> u16] code unit byte order
+/// (invalid sequences are replaced)
+fn decode_utf16_text(content: &[u8], to_code_unit: fn([u8; 2]) -> u16) -> String {
+    let code_units = content
+        .chunks_exact(2)
+        .map(|code_unit_bytes| to_code_unit([code_unit_bytes[0], code_unit_bytes[1]]))
+        .collect::<Vec<u16>>();
+    String::from_utf16_lossy(&code_units)
+}
+
+/// Creates a new [S3ObjectStreamOptions] by [&HeaderMap] request headers (just single byte ranges and valid
+/// conditional request headers are forwarded to S3)
+pub fn create_s3_object_stream_options(request_headers: &HeaderMap) -> S3ObjectStreamOptions {
+    let request_header = |header_name| request_headers.get(header_name).and_then(|header_value| header_value.to_str().ok());
+    S3ObjectStreamOptions {
+        range: request_header(RANGE).filter(|range| range.starts_with("bytes=")).map(String::from),
+        if_none_match: request_header(IF_NONE_MATCH).map(String::from),
+        if_modified_since: request_header(IF_MODIFIED_SINCE)
+            .and_then(|if_modified_since| DateTime::from_str(if_modified_since, DateTimeFormat::HttpDate).ok()),
+    }
+}
+
+/// Creates a new [Response] by [S3ObjectStream] S3 object stream (partial content status if S3 retrieved a content range
+/// and not modified status - just validator headers - if S3 object was not modified)
+pub fn create_object_response(s3_object_stream: S3ObjectStream) -> Response {
+    let mut headers = HeaderMap::new();
+    if let Some(e_tag) = s3_object_stream.e_tag.and_then(|e_tag| HeaderValue::from_str(&e_tag).ok()) {
+        headers.insert(ETAG, e_tag);
+    }
+    if let Some(last_modified) = s3_object_stream
+        .last_modified
+        .and_then(|last_modified| last_modified.fmt(DateTimeFormat::HttpDate).ok())
+        .and_then(|last_modified| HeaderValue::from_str(&last_modified).ok())
+    {
+        headers.insert(LAST_MODIFIED, last_modified);
+    }
+    if s3_object_stream.not_modified {
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    headers.insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    let content_type = s3_object_stream.content_type.as_deref().unwrap_or("application/octet-stream");
+    if let Ok(content_type) = HeaderValue::from_str(content_type) {
+        headers.insert(CONTENT_TYPE, content_type);
+    }
+    if let Some(content_length) = s3_object_stream.content_length {
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(content_length));
+    }
+
+    let status_code = match s3_object_stream.content_range.and_then(|content_range| HeaderValue::from_str(&content_range).ok()) {
+        Some(content_range) => {
+            headers.insert(CONTENT_RANGE, content_range);
+            StatusCode::PARTIAL_CONTENT
+        }
+        None => StatusCode::OK,
+    };
+    let body = Body::from_stream(ReaderStream::new(s3_object_stream.content.into_async_read()));
+    (status_code, headers, body).into_response()
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::primitives::ByteStream;
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use axum::http::header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG};
+    use crate::controller::object_controller::{create_object_response, create_text_preview};
+    use crate::dto::s3_object_stream::S3ObjectStream;
+    use crate::enums::text_encoding::TextEncoding;
+
+    /// Scenario:
+    /// Creates a S3 object response for a S3 content range and for a whole S3 object
+    /// Expectation:
+    /// Content range should be retrieved as partial content with S3 content range and length headers, otherwise
+    /// the whole S3 object should be retrieved as ok status
+    #[tokio::test]
+    async fn when_create_object_response_should_retrieve_partial_content_for_content_range() {
+        let s3_object_stream = |content: &'static [u8], content_range: Option<&str>| S3ObjectStream {
+            not_modified: false,
+            content: ByteStream::from_static(content),
+            content_length: Some(content.len() as i64),
+            content_range: content_range.map(String::from),
+            content_type: Some(String::from("video/mp4")),
+            e_tag: None,
+            last_modified: None,
+        };
+
+        let response = create_object_response(s3_object_stream(b"cont", Some("bytes 0-3/12")));
+        assert_eq!(StatusCode::PARTIAL_CONTENT, response.status());
+        assert_eq!("bytes 0-3/12", response.headers()[CONTENT_RANGE]);
+        assert_eq!("4", response.headers()[CONTENT_LENGTH]);
+        assert_eq!("bytes", response.headers()[ACCEPT_RANGES]);
+        assert_eq!("video/mp4", response.headers()[CONTENT_TYPE]);
+        assert_eq!(b"cont".as_slice(), to_bytes(response.into_body(), usize::MAX).await.unwrap().as_ref());
+
+        let response = create_object_response(s3_object_stream(b"content body", None));
+        assert_eq!(StatusCode::OK, response.status());
+        assert!(!response.headers().contains_key(CONTENT_RANGE));
+        assert_eq!("12", response.headers()[CONTENT_LENGTH]);
+    }
+
+    /// Scenario:
+    /// Creates a S3 object response for a not modified S3 object (conditional request)
+    /// Expectation:
+    /// Not modified status should be retrieved with the S3 object entity tag and without content headers
+    #[tokio::test]
+    async fn when_create_object_response_for_not_modified_object_should_retrieve_not_modified() {
+        let response = create_object_response(S3ObjectStream {
+            not_modified: true,
+            content: ByteStream::from_static(&[]),
+            content_length: None,
+            content_range: None,
+            content_type: None,
+            e_tag: Some(String::from("\"9a0364b9e99bb480dd25e1f0284c8555\"")),
+            last_modified: None,
+        });
+        assert_eq!(StatusCode::NOT_MODIFIED, response.status());
+        assert_eq!("\"9a0364b9e99bb480dd25e1f0284c8555\"", response.headers()[ETAG]);
+        assert!(!response.headers().contains_key(CONTENT_TYPE));
+    }
+
+    /// Scenario:
+    /// Creates text previews for a truncated UTF-8 content (last character split by the preview range), a UTF-16
+    /// little endian content with byte order mark and a ISO-8859-1 content, with encoding detection
+    /// Expectation:
+    /// Split characters should be discarded and contents should be decoded by their detected encoding
+    #[test]
+    fn when_create_text_preview_should_decode_content_by_detected_encoding() {
+        let text_preview = create_text_preview(String::from("app.log"), "línea ñ".as_bytes().split_last().unwrap().1, 100, true);
+        assert_eq!((TextEncoding::UTF_8, "línea "), (text_preview.encoding, text_preview.content.as_str()));
+        assert!(text_preview.truncated);
+
+        let text_preview = create_text_preview(String::from("data.csv"), b"\xFF\xFEa\x00,\x00b\x00", 8, true);
+        assert_eq!((TextEncoding::UTF_16LE, "a,b"), (text_preview.encoding, text_preview.content.as_str()));
+        assert!(!text_preview.truncated);
+
+        let text_preview = create_text_preview(String::from("legacy.txt"), b"caf\xE9;1", 6, true);
+        assert_eq!((TextEncoding::ISO_8859_1, "café;1"), (text_preview.encoding, text_preview.content.as_str()));
+    }
+}
@@ -0,0 +1,53 @@
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use crate::config::app_state::AppState;
+use crate::constant::constants::{API_S3_EVENTS_PATH, S3_EVENT_SIGNATURE_HEADER, S3_EVENT_SIGNATURE_NONCE_HEADER, S3_EVENT_SIGNATURE_TIMESTAMP_HEADER};
+use crate::dto::s3_event_notification::S3EventNotification;
+use crate::service::s3_event_service::DynS3EventService;
+
+/// S3 event controller
+pub trait S3EventControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// S3 event controller implementation struct
+pub struct S3EventController {}
+
+/// S3 event controller implementation logic
+impl S3EventControllerTrait for S3EventController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .route(API_S3_EVENTS_PATH, post(map_s3_events))
+            .with_state(app_state.s3_event_service.clone())
+    }
+}
+
+/// Maps S3 event notification ingestion end-point
+/// Returns an unauthorized status response if notification signature is not verified (or signing secret is not configured)
+async fn map_s3_events(State(s3_event_service): State<DynS3EventService>, headers: HeaderMap, body: Bytes) -> Response {
+    let get_header = |header_name: &str| headers.get(header_name).and_then(|header_value| header_value.to_str().ok());
+    if !s3_event_service
+        .verify_s3_events_signature(
+            get_header(S3_EVENT_SIGNATURE_TIMESTAMP_HEADER),
+            get_header(S3_EVENT_SIGNATURE_NONCE_HEADER),
+            get_header(S3_EVENT_SIGNATURE_HEADER),
+            &body,
+        )
+        .await
+    {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    match serde_json::from_slice::<S3EventNotification>(&body) {
+        Ok(s3_event_notification) => Json(s3_event_service.process_s3_events(s3_event_notification).await).into_response(),
+        Err(parse_error) => (StatusCode::BAD_REQUEST, parse_error.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,521 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::body::Body;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::{
+    Json, Router,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use crate::config::app_state::AppState;
+use crate::config::caller_credentials::spawn_with_caller_credentials;
+use crate::config::env_vars::get_env_var_as_usize;
+use crate::config::request_validation::ValidJson;
+use crate::constant::constants::{API_DIFF_PATH, API_EXISTS_PATH, API_LIST_MAX_KEYS_LIMIT, API_LIST_PATH, API_LIST_STREAM_PATH, API_MAIN_PATH, DIFF_MAX_KEYS_DEFAULT, DIFF_MAX_KEYS_ENV_VAR, LISTING_MAX_PARALLEL_PREFIXES_DEFAULT, LISTING_MAX_PARALLEL_PREFIXES_ENV_VAR, NDJSON_LISTING_PAGE_CHANNEL_CAPACITY, NDJSON_STREAM_BUFFER_SIZE};
+use crate::controller::download_controller::create_limit_exceeded_response;
+use crate::dto::diff_request::DiffRequest;
+use crate::dto::diff_response::DiffResponse;
+use crate::dto::exists_request::ExistsRequest;
+use crate::dto::exists_response::ExistsResponse;
+use crate::dto::list_request::ListRequest;
+use crate::dto::list_response::ListResponse;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use log::{debug, warn};
+use serde_json::json;
+use tokio::io::{duplex, AsyncWriteExt, DuplexStream};
+use tokio::sync::Semaphore;
+use tokio::sync::mpsc::{channel, Sender};
+use tokio_util::io::ReaderStream;
+
+/// S3 object controller (lightweight S3 lookups - no S3 object content is downloaded)
+pub trait S3ObjectControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// S3 object controller implementation struct
+pub struct S3ObjectController {}
+
+/// S3 object controller implementation logic
+impl S3ObjectControllerTrait for S3ObjectController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_MAIN_PATH, create_routes())
+            .with_state(app_state.aws_s3_service.clone())
+    }
+}
+
+/// Creates S3 object routes
+fn create_routes() -> Router<DynAwsSdkS3Service> {
+    Router::new()
+        .route(API_EXISTS_PATH, post(map_exists))
+        .route(API_LIST_PATH, post(map_list))
+        .route(API_LIST_STREAM_PATH, post(map_list_stream))
+        .route(API_DIFF_PATH, post(map_diff))
+}
+
+/// Maps S3 key (or S3 prefix) exists end-point
+async fn map_exists(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    exists_request: ValidJson<ExistsRequest>,
+) -> impl IntoResponse {
+    let exists_request = exists_request.0;
+    match aws_s3_service
+        .get_s3_object_metadata(exists_request.bucket_name, exists_request.full_path, exists_request.s3_key)
+        .await
+    {
+        Ok(s3_object_metadata) => Json(ExistsResponse {
+            exists: s3_object_metadata.is_some(),
+            metadata: s3_object_metadata,
+        })
+        .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Maps S3 objects listing end-point (one page by request - next page is requested by `next_cursor` value)
+async fn map_list(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    list_request: ValidJson<ListRequest>,
+) -> impl IntoResponse {
+    let list_request = list_request.0;
+    let continuation_token = match list_request.cursor.as_deref() {
+        Some(cursor) => match decode_list_cursor(&list_request, cursor) {
+            Some(continuation_token) => Some(continuation_token),
+            None => return StatusCode::BAD_REQUEST.into_response(),
+        },
+        None => None,
+    };
+    let max_keys = list_request.max_keys.map(|max_keys| max_keys.clamp(1, API_LIST_MAX_KEYS_LIMIT));
+
+    match aws_s3_service
+        .get_s3_object_metadata_page(
+            list_request.bucket_name.clone(),
+            list_request.full_path.clone(),
+            max_keys,
+            continuation_token,
+        )
+        .await
+    {
+        Ok((s3_object_metadata_list, next_continuation_token)) => Json(ListResponse {
+            s3_objects: s3_object_metadata_list,
+            next_cursor: next_continuation_token
+                .map(|next_continuation_token| encode_list_cursor(&list_request, &next_continuation_token)),
+        })
+        .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Gets [String] opaque listing cursor by [&ListRequest] list request and [&str] S3 continuation token
+/// Important: cursor is bound to the requested S3 bucket and path
+fn encode_list_cursor(list_request: &ListRequest, continuation_token: &str) -> String {
+    let cursor = format!("{}\n{}\n{continuation_token}", list_request.bucket_name, list_request.full_path);
+    URL_SAFE_NO_PAD.encode(cursor)
+}
+
+/// Gets [Option<String>] S3 continuation token by [&ListRequest] list request and [&str] opaque listing cursor
+/// Returns [None] if cursor is not valid or it belongs to another S3 bucket or path
+fn decode_list_cursor(list_request: &ListRequest, cursor: &str) -> Option<String> {
+    let cursor = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+    let mut cursor_values = cursor.splitn(3, '\n');
+    let (bucket_name, full_path, continuation_token) = (cursor_values.next()?, cursor_values.next()?, cursor_values.next()?);
+
+    if bucket_name != list_request.bucket_name || full_path != list_request.full_path || continuation_token.is_empty() {
+        return None;
+    }
+    Some(String::from(continuation_token))
+}
+
+/// Maps S3 objects listing as NDJSON stream end-point (one S3 object metadata JSON per line)
+/// Important: S3 listing is paginated server-side while lines are streamed, so clients can process
+/// the first S3 objects before listing completes
+async fn map_list_stream(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    list_request: ValidJson<ListRequest>,
+) -> impl IntoResponse {
+    let (ndjson_content_reader, ndjson_content_writer) = duplex(NDJSON_STREAM_BUFFER_SIZE);
+    spawn_with_caller_credentials(write_ndjson_listing(aws_s3_service, list_request.0, ndjson_content_writer));
+
+    let body = Body::from_stream(ReaderStream::new(ndjson_content_reader));
+    ([(CONTENT_TYPE, "application/x-ndjson")], body).into_response()
+}
+
+/// Maps S3 prefixes diff end-point (source S3 path compared against a target S3 path or a manifest by S3 key, size and
+/// entity tag - i.e.: replication or backup completeness verification)
+/// Important: each side is limited to the configured maximum S3 objects (please, check constants.rs)
+async fn map_diff(
+    State(aws_s3_service): State<DynAwsSdkS3Service>,
+    diff_request: ValidJson<DiffRequest>,
+) -> Response {
+    let diff_request = diff_request.0;
+    let max_keys = get_env_var_as_usize(DIFF_MAX_KEYS_ENV_VAR, DIFF_MAX_KEYS_DEFAULT).max(1);
+
+    let source_s3_objects =
+        match list_diff_s3_objects(&aws_s3_service, &diff_request.bucket_name, &diff_request.full_path, max_keys).await {
+            Ok(source_s3_objects) => source_s3_objects,
+            Err(error_response) => return error_response,
+        };
+    let target_s3_objects = match diff_request.manifest {
+        Some(manifest) if manifest.len() > max_keys => return create_limit_exceeded_response(max_keys, manifest.len()),
+        Some(manifest) => manifest,
+        None => {
+            let target_bucket_name = diff_request.target_bucket_name.as_deref().unwrap_or(&diff_request.bucket_name);
+            let target_full_path = diff_request.target_full_path.as_deref().unwrap_or_default();
+            match list_diff_s3_objects(&aws_s3_service, target_bucket_name, target_full_path, max_keys).await {
+                Ok(target_s3_objects) => target_s3_objects,
+                Err(error_response) => return error_response,
+            }
+        }
+    };
+
+    Json(create_diff_response(source_s3_objects, target_s3_objects)).into_response()
+}
+
+/// Lists [Vec<S3ObjectMetadata>] all S3 objects metadata by [&str] bucket name and [&str] path (all S3 listing pages
+/// are requested by [DynAwsSdkS3Service] AWS S3 service)
+/// Returns an error [Response] if S3 throws any error or S3 objects exceed [usize] max keys
+async fn list_diff_s3_objects(
+    aws_s3_service: &DynAwsSdkS3Service,
+    bucket_name: &str,
+    path: &str,
+    max_keys: usize,
+) -> Result<Vec<S3ObjectMetadata>, Response> {
+    let mut s3_objects = Vec::new();
+    let mut continuation_token = None;
+    loop {
+        let (s3_object_metadata_list, next_continuation_token) = aws_s3_service
+            .get_s3_object_metadata_page(String::from(bucket_name), String::from(path), None, continuation_token)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())?;
+        s3_objects.extend(s3_object_metadata_list);
+        if s3_objects.len() > max_keys {
+            warn!("list_diff_s3_objects - too many s3 objects - bucket name: {bucket_name} - path: {path}");
+            return Err(create_limit_exceeded_response(max_keys, s3_objects.len()));
+        }
+
+        continuation_token = next_continuation_token;
+        if continuation_token.is_none() {
+            return Ok(s3_objects);
+        }
+    }
+}
+
+/// Creates a new [DiffResponse] diff response by [Vec<S3ObjectMetadata>] source and target S3 objects metadata
+/// Important: S3 objects are changed if sizes are different or entity tags are different (just if both are defined)
+fn create_diff_response(source_s3_objects: Vec<S3ObjectMetadata>, target_s3_objects: Vec<S3ObjectMetadata>) -> DiffResponse {
+    let mut source_s3_objects: BTreeMap<String, S3ObjectMetadata> = source_s3_objects
+        .into_iter()
+        .map(|s3_object_metadata| (s3_object_metadata.s3_key.clone(), s3_object_metadata))
+        .collect();
+
+    let mut diff_response = DiffResponse { added: Vec::new(), removed: Vec::new(), changed: Vec::new(), unchanged_total: 0 };
+    for target_s3_object in target_s3_objects {
+        match source_s3_objects.remove(&target_s3_object.s3_key) {
+            None => diff_response.added.push(target_s3_object.s3_key),
+            Some(source_s3_object)
+                if source_s3_object.size_bytes != target_s3_object.size_bytes
+                    || (source_s3_object.e_tag.is_some() && target_s3_object.e_tag.is_some() && source_s3_object.e_tag != target_s3_object.e_tag) =>
+            {
+                diff_response.changed.push(target_s3_object.s3_key)
+            }
+            Some(_) => diff_response.unchanged_total += 1,
+        }
+    }
+    diff_response.removed = source_s3_objects.into_keys().collect();
+    diff_response.added.sort();
+    diff_response.changed.sort();
+    diff_response
+}
+
+/// Writes all S3 objects metadata by [ListRequest] list request as NDJSON lines into [DuplexStream] NDJSON
+/// content writer (S3 listing pages are requested by [DynAwsSdkS3Service] AWS S3 service)
+/// Important:
+/// - if S3 throws any error, an error line is written and listing is stopped
+/// - if parallel sub-prefixes listing is requested, first-level sub-folders are listed in parallel
+///   (bounded by configured listing concurrency - please, check constants.rs)
+async fn write_ndjson_listing(
+    aws_s3_service: DynAwsSdkS3Service,
+    list_request: ListRequest,
+    mut ndjson_content_writer: DuplexStream,
+) {
+    debug!("write_ndjson_listing - start");
+    let (s3_listing_page_sender, mut s3_listing_page_receiver) = channel(NDJSON_LISTING_PAGE_CHANNEL_CAPACITY);
+    if list_request.parallel_sub_prefixes {
+        spawn_with_caller_credentials(send_sub_prefix_listing_pages(
+            aws_s3_service,
+            list_request.bucket_name,
+            list_request.full_path,
+            s3_listing_page_sender,
+        ));
+    } else {
+        spawn_with_caller_credentials(send_listing_pages(
+            aws_s3_service,
+            list_request.bucket_name,
+            list_request.full_path,
+            String::new(),
+            s3_listing_page_sender,
+        ));
+    }
+
+    let mut s3_objects_total = 0;
+    while let Some(s3_listing_page) = s3_listing_page_receiver.recv().await {
+        let s3_object_metadata_list = match s3_listing_page {
+            Ok(s3_object_metadata_list) => s3_object_metadata_list,
+            Err(list_error) => {
+                let error_line = format!("{}\n", json!({ "error": list_error.to_string() }));
+                let _ = ndjson_content_writer.write_all(error_line.as_bytes()).await;
+                return;
+            }
+        };
+
+        for s3_object_metadata in s3_object_metadata_list {
+            let s3_object_metadata_line = format!("{}\n", json!(s3_object_metadata));
+            if let Err(write_error) = ndjson_content_writer.write_all(s3_object_metadata_line.as_bytes()).await {
+                warn!("write_ndjson_listing - can't write line (client disconnected?) - error: {write_error}");
+                return;
+            }
+            s3_objects_total += 1;
+        }
+    }
+
+    debug!("write_ndjson_listing - done - s3 objects total: {s3_objects_total}");
+}
+
+/// Sends all S3 listing pages (S3 objects metadata with S3 keys prefixed by [String] S3 key prefix) by [String] bucket name
+/// and [String] path into [Sender<Result<Vec<S3ObjectMetadata>, CommonError>>] S3 listing page sender
+/// Important: listing is stopped if S3 throws any error (error is sent) or the receiver is dropped
+async fn send_listing_pages(
+    aws_s3_service: DynAwsSdkS3Service,
+    bucket_name: String,
+    path: String,
+    s3_key_prefix: String,
+    s3_listing_page_sender: Sender<Result<Vec<S3ObjectMetadata>, CommonError>>,
+) {
+    let mut continuation_token = None;
+    loop {
+        let s3_listing_page = aws_s3_service
+            .get_s3_object_metadata_page(bucket_name.clone(), path.clone(), None, continuation_token)
+            .await;
+        let (s3_object_metadata_list, next_continuation_token) = match s3_listing_page {
+            Ok(s3_object_metadata_page) => s3_object_metadata_page,
+            Err(list_error) => {
+                let _ = s3_listing_page_sender.send(Err(list_error)).await;
+                return;
+            }
+        };
+
+        let s3_object_metadata_list = s3_object_metadata_list
+            .into_iter()
+            .map(|s3_object_metadata| S3ObjectMetadata {
+                s3_key: format!("{s3_key_prefix}{}", s3_object_metadata.s3_key),
+                ..s3_object_metadata
+            })
+            .collect();
+        if s3_listing_page_sender.send(Ok(s3_object_metadata_list)).await.is_err() {
+            return;
+        }
+
+        continuation_token = next_continuation_token;
+        if continuation_token.is_none() {
+            return;
+        }
+    }
+}
+
+/// Sends all S3 listing pages by [String] bucket name and [String] path into
+/// [Sender<Result<Vec<S3ObjectMetadata>, CommonError>>] S3 listing page sender: S3 objects directly under the S3 path
+/// are sent first, then first-level sub-folders are listed in parallel (bounded by configured listing concurrency)
+async fn send_sub_prefix_listing_pages(
+    aws_s3_service: DynAwsSdkS3Service,
+    bucket_name: String,
+    path: String,
+    s3_listing_page_sender: Sender<Result<Vec<S3ObjectMetadata>, CommonError>>,
+) {
+    let (s3_object_metadata_list, s3_sub_folders) =
+        match aws_s3_service.get_s3_first_level_entries(bucket_name.clone(), path.clone()).await {
+            Ok(s3_first_level_entries) => s3_first_level_entries,
+            Err(list_error) => {
+                let _ = s3_listing_page_sender.send(Err(list_error)).await;
+                return;
+            }
+        };
+    if s3_listing_page_sender.send(Ok(s3_object_metadata_list)).await.is_err() {
+        return;
+    }
+
+    debug!("send_sub_prefix_listing_pages - sub-folders listed in parallel: {}", s3_sub_folders.len());
+    let listing_permits = Arc::new(Semaphore::new(
+        get_env_var_as_usize(LISTING_MAX_PARALLEL_PREFIXES_ENV_VAR, LISTING_MAX_PARALLEL_PREFIXES_DEFAULT).max(1),
+    ));
+    for s3_sub_folder in s3_sub_folders {
+        let Ok(listing_permit) = listing_permits.clone().acquire_owned().await else {
+            return;
+        };
+        let sub_folder_path = format!("{}/{s3_sub_folder}", path.trim_end_matches('/'));
+        let sub_folder_listing = send_listing_pages(
+            aws_s3_service.clone(),
+            bucket_name.clone(),
+            sub_folder_path,
+            format!("{s3_sub_folder}/"),
+            s3_listing_page_sender.clone(),
+        );
+        spawn_with_caller_credentials(async move {
+            sub_folder_listing.await;
+            drop(listing_permit);
+        });
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::controller::s3_object_controller::{create_diff_response, decode_list_cursor, encode_list_cursor, write_ndjson_listing};
+    use crate::dto::diff_response::DiffResponse;
+    use crate::dto::list_request::ListRequest;
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use tokio::io::{duplex, AsyncReadExt};
+
+    /// Scenario:
+    /// Streams a S3 listing with two pages (one S3 object per page)
+    /// Expectation:
+    /// Both pages should be requested and one NDJSON line per S3 object should be written
+    #[tokio::test]
+    async fn when_write_ndjson_listing_should_write_all_pages() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_object_metadata_page()
+            .returning(|_, _, _, continuation_token| {
+                let s3_key = if continuation_token.is_none() { "a.txt" } else { "b.txt" };
+                let next_continuation_token = continuation_token.xor(Some(String::from("next-page")));
+                let s3_object_metadata = S3ObjectMetadata { s3_key: String::from(s3_key), ..Default::default() };
+                Ok((vec![s3_object_metadata], next_continuation_token))
+            });
+        let list_request = ListRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+        let (mut ndjson_content_reader, ndjson_content_writer) = duplex(1024);
+
+        write_ndjson_listing(Arc::new(aws_s3_service) as DynAwsSdkS3Service, list_request, ndjson_content_writer).await;
+        let mut ndjson_content = String::new();
+        ndjson_content_reader.read_to_string(&mut ndjson_content).await.unwrap();
+
+        let ndjson_lines: Vec<&str> = ndjson_content.lines().collect();
+        assert_eq!(2, ndjson_lines.len());
+        assert!(ndjson_lines[0].contains("\"s3_key\":\"a.txt\""));
+        assert!(ndjson_lines[1].contains("\"s3_key\":\"b.txt\""));
+    }
+
+    /// Scenario:
+    /// Streams a S3 listing with parallel sub-prefixes (one top-level S3 object and two sub-folders)
+    /// Expectation:
+    /// Top-level S3 object should be written first and sub-folders S3 objects should be written with the sub-folder key prefix
+    #[tokio::test]
+    async fn when_write_ndjson_listing_with_parallel_sub_prefixes_should_list_sub_folders() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_first_level_entries()
+            .returning(|_, _| {
+                let s3_object_metadata = S3ObjectMetadata { s3_key: String::from("root.txt"), ..Default::default() };
+                Ok((vec![s3_object_metadata], vec![String::from("a"), String::from("b")]))
+            });
+        aws_s3_service
+            .expect_get_s3_object_metadata_page()
+            .returning(|_, path, _, _| {
+                assert!(path == "some/path/a" || path == "some/path/b");
+                let s3_object_metadata = S3ObjectMetadata { s3_key: String::from("c.txt"), ..Default::default() };
+                Ok((vec![s3_object_metadata], None))
+            });
+        let list_request = ListRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            parallel_sub_prefixes: true,
+            ..Default::default()
+        };
+        let (mut ndjson_content_reader, ndjson_content_writer) = duplex(1024);
+
+        write_ndjson_listing(Arc::new(aws_s3_service) as DynAwsSdkS3Service, list_request, ndjson_content_writer).await;
+        let mut ndjson_content = String::new();
+        ndjson_content_reader.read_to_string(&mut ndjson_content).await.unwrap();
+
+        let ndjson_lines: Vec<&str> = ndjson_content.lines().collect();
+        assert_eq!(3, ndjson_lines.len());
+        assert!(ndjson_lines[0].contains("\"s3_key\":\"root.txt\""));
+        assert!(ndjson_content.contains("\"s3_key\":\"a/c.txt\""));
+        assert!(ndjson_content.contains("\"s3_key\":\"b/c.txt\""));
+    }
+
+    /// Scenario:
+    /// Encodes a S3 continuation token as listing cursor and decodes it for the same and another S3 path
+    /// Expectation:
+    /// S3 continuation token should be retrieved just for the same S3 bucket and path
+    #[test]
+    fn when_decode_list_cursor_should_retrieve_continuation_token_for_same_path() {
+        let list_request = ListRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+        let other_list_request = ListRequest {
+            full_path: String::from("other/path"),
+            ..list_request.clone()
+        };
+
+        let cursor = encode_list_cursor(&list_request, "some-continuation-token");
+
+        assert_eq!(Some(String::from("some-continuation-token")), decode_list_cursor(&list_request, &cursor));
+        assert_eq!(None, decode_list_cursor(&other_list_request, &cursor));
+        assert_eq!(None, decode_list_cursor(&list_request, "not-valid-cursor"));
+    }
+
+    /// Scenario:
+    /// Compares source and target S3 objects with added, removed, changed (by size and entity tag) and unchanged S3 keys
+    /// Expectation:
+    /// S3 keys should be classified by presence, size and entity tag (missing entity tags are not compared)
+    #[test]
+    fn when_create_diff_response_should_classify_s3_keys() {
+        let s3_object_fn = |s3_key: &str, size_bytes: i64, e_tag: Option<&str>| S3ObjectMetadata {
+            s3_key: String::from(s3_key),
+            size_bytes,
+            e_tag: e_tag.map(String::from),
+            ..Default::default()
+        };
+        let source_s3_objects = vec![
+            s3_object_fn("a.txt", 10, Some("\"a\"")),
+            s3_object_fn("b.txt", 10, Some("\"b\"")),
+            s3_object_fn("c.txt", 10, Some("\"c\"")),
+            s3_object_fn("d.txt", 10, Some("\"d\"")),
+            s3_object_fn("e.txt", 10, Some("\"e\"")),
+        ];
+        let target_s3_objects = vec![
+            s3_object_fn("f.txt", 10, None),
+            s3_object_fn("e.txt", 10, None),
+            s3_object_fn("d.txt", 10, Some("\"other\"")),
+            s3_object_fn("c.txt", 20, Some("\"c\"")),
+            s3_object_fn("b.txt", 10, Some("\"b\"")),
+        ];
+
+        assert_eq!(
+            DiffResponse {
+                added: vec![String::from("f.txt")],
+                removed: vec![String::from("a.txt")],
+                changed: vec![String::from("c.txt"), String::from("d.txt")],
+                unchanged_total: 2,
+            },
+            create_diff_response(source_s3_objects, target_s3_objects)
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{
+    Json, Router,
+    response::IntoResponse,
+    routing::post,
+};
+use crate::config::app_state::AppState;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::request_validation::ValidJson;
+use crate::constant::constants::{API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_SEARCH_PATH};
+use crate::controller::download_controller::create_limit_exceeded_response;
+use crate::dto::search_request::SearchRequest;
+use crate::enums::common_error::CommonError;
+use crate::service::search_service::DynSearchService;
+
+/// Search controller
+pub trait SearchControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Search controller implementation struct
+pub struct SearchController {}
+
+/// Search controller implementation logic
+impl SearchControllerTrait for SearchController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_DOWNLOAD_MAIN_PATH, Router::new().route(API_DOWNLOAD_SEARCH_PATH, post(map_search)))
+            .with_state(app_state.search_service.clone())
+    }
+}
+
+/// Maps full-text search end-point (text S3 objects under a S3 folder - S3 keys and matching line snippets)
+async fn map_search(
+    State(search_service): State<DynSearchService>,
+    search_request: ValidJson<SearchRequest>,
+) -> impl IntoResponse {
+    let request_context = format!(
+        "POST {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SEARCH_PATH} - bucket name: {} - path: {}",
+        search_request.bucket_name, search_request.full_path
+    );
+    match search_service.search_objects(search_request.0).await {
+        Ok(search_response) => Json(search_response).into_response(),
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER) => StatusCode::BAD_REQUEST.into_response(),
+        Err(CommonError::NO_DATA_FOUND) => StatusCode::NOT_FOUND.into_response(),
+        Err(CommonError::ACCESS_DENIED) => StatusCode::FORBIDDEN.into_response(),
+        Err(CommonError::LIMIT_EXCEEDED { limit, actual }) => create_limit_exceeded_response(limit, actual),
+        Err(search_error) => {
+            ERROR_REPORTER.report_error(&search_error, &request_context);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
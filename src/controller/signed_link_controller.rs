@@ -0,0 +1,100 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::{
+    Json, Router,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use crate::config::app_state::AppState;
+use crate::config::request_validation::ValidJson;
+use crate::constant::constants::{API_DOWNLOAD_ALL_AS_ZIP_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_SIGNED_PATH, API_DOWNLOAD_TOKENS_PATH};
+use crate::controller::download_controller::{cancel_on_disconnect, create_download_response};
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::signed_link_query::SignedLinkQuery;
+use crate::dto::signed_link_request::SignedLinkRequest;
+use crate::service::signed_link_service::DynSignedLinkService;
+use tokio_util::sync::CancellationToken;
+
+/// Signed link controller
+pub trait SignedLinkControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Signed link controller implementation struct
+pub struct SignedLinkController {}
+
+/// Signed link controller implementation logic
+impl SignedLinkControllerTrait for SignedLinkController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router {
+        Router::new()
+            .nest(API_DOWNLOAD_MAIN_PATH, create_routes())
+            .with_state(app_state.signed_link_service.clone())
+    }
+}
+
+/// Creates signed link routes
+fn create_routes() -> Router<DynSignedLinkService> {
+    Router::new()
+        .route(API_DOWNLOAD_SIGNED_PATH, get(map_download_signed).post(map_create_signed_link))
+        .route(API_DOWNLOAD_TOKENS_PATH, post(map_create_download_token))
+        .route(API_DOWNLOAD_ALL_AS_ZIP_PATH, get(map_download_by_token))
+}
+
+/// Maps create signed link end-point
+async fn map_create_signed_link(
+    State(signed_link_service): State<DynSignedLinkService>,
+    signed_link_request: ValidJson<SignedLinkRequest>,
+) -> impl IntoResponse {
+    match signed_link_service.create_signed_link(signed_link_request.0).await {
+        Ok(signed_link) => (StatusCode::CREATED, Json(signed_link)).into_response(),
+        Err(_) => StatusCode::BAD_REQUEST.into_response(),
+    }
+}
+
+/// Maps download by signed link end-point (no credentials required - just a valid and not expired token - pending S3
+/// downloads are aborted if the client disconnects)
+async fn map_download_signed(
+    State(signed_link_service): State<DynSignedLinkService>,
+    Query(signed_link_query): Query<SignedLinkQuery>,
+) -> impl IntoResponse {
+    let request_context = format!("GET {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SIGNED_PATH}");
+    let cancellation_token = CancellationToken::new();
+    let download_response = create_download_response(
+        signed_link_service.download_files_by_signed_link(signed_link_query.token, cancellation_token.clone()).await,
+        &request_context,
+    );
+    cancel_on_disconnect(download_response, cancellation_token)
+}
+
+/// Maps download token exchange end-point (authenticated download request for a one-time download token)
+async fn map_create_download_token(
+    State(signed_link_service): State<DynSignedLinkService>,
+    download_request: ValidJson<DownloadRequest>,
+) -> impl IntoResponse {
+    match signed_link_service.create_download_token(download_request.0).await {
+        Ok(download_token) => (StatusCode::CREATED, Json(download_token)).into_response(),
+        Err(_) => StatusCode::BAD_REQUEST.into_response(),
+    }
+}
+
+/// Maps download by one-time token end-point (browser navigation downloads - no JSON body nor credentials required -
+/// pending S3 downloads are aborted if the client disconnects)
+async fn map_download_by_token(
+    State(signed_link_service): State<DynSignedLinkService>,
+    Query(signed_link_query): Query<SignedLinkQuery>,
+) -> impl IntoResponse {
+    let request_context = format!("GET {API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ALL_AS_ZIP_PATH}");
+    let cancellation_token = CancellationToken::new();
+    let download_response = create_download_response(
+        signed_link_service.download_files_by_download_token(signed_link_query.token, cancellation_token.clone()).await,
+        &request_context,
+    );
+    cancel_on_disconnect(download_response, cancellation_token)
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+}
@@ -0,0 +1,193 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::{
+    Router,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use crate::config::app_state::AppState;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::constant::constants::{STATIC_WEBSITE_BUCKET_ENV_VAR, STATIC_WEBSITE_FILE_PATH, STATIC_WEBSITE_INDEX_DOCUMENT_DEFAULT, STATIC_WEBSITE_INDEX_DOCUMENT_ENV_VAR, STATIC_WEBSITE_MAIN_PATH, STATIC_WEBSITE_PATH_ENV_VAR};
+use crate::controller::object_controller::{create_object_response, create_s3_object_stream_options};
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use log::{debug, info};
+
+/// Content types by file extension (S3 objects stored without content type or with a generic binary content type)
+const STATIC_WEBSITE_CONTENT_TYPES: [(&str, &str); 20] = [
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("gif", "image/gif"),
+    ("htm", "text/html; charset=utf-8"),
+    ("html", "text/html; charset=utf-8"),
+    ("ico", "image/x-icon"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("mjs", "text/javascript"),
+    ("mp4", "video/mp4"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("svg", "image/svg+xml"),
+    ("txt", "text/plain; charset=utf-8"),
+    ("wasm", "application/wasm"),
+    ("webp", "image/webp"),
+    ("woff2", "font/woff2"),
+    ("xml", "application/xml"),
+];
+
+/// Static website controller (optional static website mode - a private S3 website gateway)
+pub trait StaticWebsiteControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Static website controller implementation struct
+pub struct StaticWebsiteController {}
+
+/// Static website state struct (AWS S3 service and configured S3 bucket, S3 path and index document)
+#[derive(Clone)]
+struct StaticWebsiteState {
+    aws_s3_service: DynAwsSdkS3Service,
+    bucket_name: String,
+    path: String,
+    index_document: String,
+}
+
+/// Static website controller implementation logic
+impl StaticWebsiteControllerTrait for StaticWebsiteController {
+    /// Configure declared endpoints for this controller (no end-point is declared if static website S3 bucket is not defined)
+    fn config_endpoints(app_state: &AppState) -> Router {
+        let Some(bucket_name) = std::env::var(STATIC_WEBSITE_BUCKET_ENV_VAR).ok().filter(|bucket_name| !bucket_name.is_empty()) else {
+            return Router::new();
+        };
+        let static_website_state = StaticWebsiteState {
+            aws_s3_service: app_state.aws_s3_service.clone(),
+            bucket_name,
+            path: std::env::var(STATIC_WEBSITE_PATH_ENV_VAR).unwrap_or_default(),
+            index_document: std::env::var(STATIC_WEBSITE_INDEX_DOCUMENT_ENV_VAR)
+                .ok()
+                .filter(|index_document| !index_document.is_empty())
+                .unwrap_or_else(|| String::from(STATIC_WEBSITE_INDEX_DOCUMENT_DEFAULT)),
+        };
+        info!(
+            "config_endpoints - static website mode enabled - bucket name: {} - path: {}",
+            static_website_state.bucket_name, static_website_state.path
+        );
+
+        Router::new()
+            .route(STATIC_WEBSITE_MAIN_PATH, get(map_static_website_index))
+            .route(&format!("{STATIC_WEBSITE_MAIN_PATH}/"), get(map_static_website_index))
+            .route(STATIC_WEBSITE_FILE_PATH, get(map_static_website_file))
+            .with_state(static_website_state)
+    }
+}
+
+/// Maps static website index document end-point
+async fn map_static_website_index(
+    State(static_website_state): State<StaticWebsiteState>,
+    request_headers: HeaderMap,
+) -> Response {
+    get_static_website_file(static_website_state, "", &request_headers).await
+}
+
+/// Maps static website file end-point (folder requests are served by its index document)
+async fn map_static_website_file(
+    State(static_website_state): State<StaticWebsiteState>,
+    Path(file_path): Path<String>,
+    request_headers: HeaderMap,
+) -> Response {
+    get_static_website_file(static_website_state, &file_path, &request_headers).await
+}
+
+/// Gets [Response] static website S3 object by [StaticWebsiteState] static website state, [&str] requested file path and
+/// [&HeaderMap] request headers (range and conditional request headers are forwarded to S3)
+/// Important: S3 key candidates are requested in order until any S3 object is found
+async fn get_static_website_file(static_website_state: StaticWebsiteState, file_path: &str, request_headers: &HeaderMap) -> Response {
+    for s3_key in get_static_website_s3_keys(file_path, &static_website_state.index_document) {
+        match static_website_state
+            .aws_s3_service
+            .get_s3_object_stream(
+                static_website_state.bucket_name.clone(),
+                static_website_state.path.clone(),
+                s3_key.clone(),
+                create_s3_object_stream_options(request_headers),
+            )
+            .await
+        {
+            Ok(mut s3_object_stream) => {
+                s3_object_stream.content_type = Some(get_static_website_content_type(&s3_key, s3_object_stream.content_type));
+                return create_object_response(s3_object_stream);
+            }
+            Err(CommonError::NO_DATA_FOUND) => debug!("get_static_website_file - s3 key not found - s3 key: {s3_key}"),
+            Err(CommonError::RANGE_NOT_SATISFIABLE) => return StatusCode::RANGE_NOT_SATISFIABLE.into_response(),
+            Err(static_website_error) => {
+                ERROR_REPORTER.report_error(&static_website_error, &format!("GET {STATIC_WEBSITE_MAIN_PATH}/{file_path}"));
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        }
+    }
+    StatusCode::NOT_FOUND.into_response()
+}
+
+/// Gets [Vec<String>] S3 key candidates by [&str] requested file path and [&str] index document (folder requests -
+/// trailing slash or no file extension - are served by its index document)
+/// Important: file paths with parent folder segments are not served (no S3 key candidate)
+fn get_static_website_s3_keys(file_path: &str, index_document: &str) -> Vec<String> {
+    let file_path = file_path.trim_start_matches('/');
+    if file_path.split('/').any(|path_segment| path_segment == "..") {
+        return Vec::new();
+    }
+    if file_path.is_empty() || file_path.ends_with('/') {
+        return vec![format!("{file_path}{index_document}")];
+    }
+
+    let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+    match file_name.contains('.') {
+        true => vec![String::from(file_path)],
+        false => vec![String::from(file_path), format!("{file_path}/{index_document}")],
+    }
+}
+
+/// Gets [String] content type by [&str] S3 key and [Option<String>] stored S3 object content type (stored content type
+/// is kept unless it is a generic binary content type - otherwise it is resolved by file extension)
+fn get_static_website_content_type(s3_key: &str, s3_content_type: Option<String>) -> String {
+    if let Some(s3_content_type) = s3_content_type
+        .filter(|s3_content_type| s3_content_type != "binary/octet-stream" && s3_content_type != "application/octet-stream")
+    {
+        return s3_content_type;
+    }
+
+    let file_extension = s3_key.rsplit_once('.').map(|(_, file_extension)| file_extension.to_ascii_lowercase());
+    STATIC_WEBSITE_CONTENT_TYPES
+        .iter()
+        .find(|(content_type_extension, _)| file_extension.as_deref() == Some(*content_type_extension))
+        .map(|(_, content_type)| String::from(*content_type))
+        .unwrap_or_else(|| String::from("application/octet-stream"))
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::controller::static_website_controller::{get_static_website_content_type, get_static_website_s3_keys};
+
+    /// Scenario:
+    /// Gets static website S3 key candidates and content types for file and folder requests
+    /// Expectation:
+    /// Folder requests should be served by the index document, parent folder segments should not be served and
+    /// generic binary content types should be resolved by file extension
+    #[test]
+    fn when_get_static_website_s3_keys_should_resolve_index_document_and_content_type() {
+        assert_eq!(vec!["index.html"], get_static_website_s3_keys("", "index.html"));
+        assert_eq!(vec!["docs/index.html"], get_static_website_s3_keys("docs/", "index.html"));
+        assert_eq!(vec!["docs", "docs/index.html"], get_static_website_s3_keys("docs", "index.html"));
+        assert_eq!(vec!["css/site.css"], get_static_website_s3_keys("css/site.css", "index.html"));
+        assert!(get_static_website_s3_keys("docs/../secret.txt", "index.html").is_empty());
+
+        assert_eq!("text/css", get_static_website_content_type("css/site.css", Some(String::from("binary/octet-stream"))));
+        assert_eq!("text/html; charset=utf-8", get_static_website_content_type("docs/index.HTML", None));
+        assert_eq!("image/avif", get_static_website_content_type("logo.avif", Some(String::from("image/avif"))));
+        assert_eq!("application/octet-stream", get_static_website_content_type("LICENSE", None));
+    }
+}
@@ -0,0 +1,39 @@
+use crate::config::download_quota::DOWNLOAD_QUOTA_REGISTRY;
+use crate::config::app_state::AppState;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{API_STATS_PATH, API_STATS_QUOTAS_PATH};
+use axum::{Json, Router, response::IntoResponse, routing::get};
+
+/// Stats controller
+pub trait StatsControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Stats controller implementation struct
+pub struct StatsController {}
+
+/// Stats controller implementation logic
+impl StatsControllerTrait for StatsController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(_app_state: &AppState) -> Router {
+        /// Maps usage stats end-point
+        async fn map_stats() -> impl IntoResponse {
+            Json(USAGE_STATS_REGISTRY.get_usage_stats())
+        }
+
+        /// Maps download quotas consumption end-point
+        async fn map_quotas() -> impl IntoResponse {
+            Json(DOWNLOAD_QUOTA_REGISTRY.get_quota_usage())
+        }
+
+        Router::new()
+            .route(API_STATS_PATH, get(map_stats))
+            .route(API_STATS_QUOTAS_PATH, get(map_quotas))
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+}
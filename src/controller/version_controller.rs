@@ -0,0 +1,42 @@
+use crate::config::config_dump::get_enabled_features;
+use crate::config::app_state::AppState;
+use crate::constant::constants::API_VERSION_PATH;
+use crate::dto::build_info::BuildInfo;
+use axum::{Json, Router, response::IntoResponse, routing::get};
+
+/// Version controller
+pub trait VersionControllerTrait {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(app_state: &AppState) -> Router;
+}
+
+/// Version controller implementation struct
+pub struct VersionController {}
+
+/// Version controller implementation logic
+impl VersionControllerTrait for VersionController {
+    /// Configure declared endpoints for this controller
+    fn config_endpoints(_app_state: &AppState) -> Router {
+        /// Maps version (build info) end-point
+        async fn map_version() -> impl IntoResponse {
+            Json(create_build_info())
+        }
+
+        Router::new().route(API_VERSION_PATH, get(map_version))
+    }
+}
+
+/// Creates a new [BuildInfo] with values embedded at compile time (please, check build.rs)
+fn create_build_info() -> BuildInfo {
+    BuildInfo {
+        version: String::from(env!("CARGO_PKG_VERSION")),
+        git_sha: String::from(env!("BUILD_GIT_SHA")),
+        build_timestamp: env!("BUILD_TIMESTAMP").parse().unwrap_or_default(),
+        features: get_enabled_features(),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Access log entry struct (one structured line per request)
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct AccessLogEntry {
+    /// request method
+    pub method: String,
+    /// request path
+    pub path: String,
+    /// response status code
+    pub status: u16,
+    /// latency in milliseconds (until the response body is completely sent)
+    pub latency_millis: u64,
+    /// response body sent bytes
+    pub bytes: u64,
+    /// requested S3 bucket (JSON request bodies only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+    /// caller identifier (caller request header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+}
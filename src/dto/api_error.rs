@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// API error struct (typed error payload - API v2 end-points)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ApiError {
+    /// error code (i.e.: "NO_DATA_FOUND")
+    pub error: String,
+    /// human readable error message
+    pub message: String,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Archive entry struct (entry of a ZIP or tar archive stored in S3)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ArchiveEntry {
+    /// entry name (path inside the archive)
+    pub name: String,
+    /// entry size (in bytes - uncompressed)
+    pub size_bytes: u64,
+    /// entry compressed size (in bytes - just ZIP archives)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compressed_size_bytes: Option<u64>,
+    /// entry is a directory
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub directory: bool,
+}
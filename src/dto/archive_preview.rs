@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Archive preview struct (export file name and entry names an export would contain - nothing is downloaded)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ArchivePreview {
+    /// export file name (i.e.: "s3-export.zip")
+    pub file_name: String,
+    /// zip entry names (in export order - renamed entries and excluded files list are included)
+    pub entries: Vec<String>,
+    /// S3 keys excluded by limits (files greater than the maximum file size)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_files: Vec<String>,
+}
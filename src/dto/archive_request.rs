@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+/// Archive request struct (ZIP or tar archive stored in S3 - entries listing or selective extraction)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ArchiveRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// archive S3 key (relative to the S3 folder full path - ".zip" or ".tar" extension)
+    pub s3_key: String,
+    /// archive entry names to extract (ignored by entries listing)
+    #[serde(default)]
+    pub entries: Vec<String>,
+    /// age public key (i.e.: "age1...") to encrypt the extracted entries zip file for (not encrypted if it is not defined)
+    pub encryption_recipient: Option<String>,
+    /// caller identifier (set by the download quota middleware from the caller request header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+    /// export cancellation token (cancelled by the download controller once the client disconnects - pending archive
+    /// entry reads are aborted)
+    #[serde(skip)]
+    pub cancellation_token: CancellationToken,
+}
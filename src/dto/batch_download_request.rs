@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+/// Batch download request struct (raw files streamed as multipart/mixed parts)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct BatchDownloadRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 keys (relative to the S3 folder full path) - parts are streamed in the same order
+    pub s3_keys: Vec<String>,
+    /// requested S3 keys are fetched directly instead of being re-validated against the S3 folder listing
+    /// (genuine 404 responses are skipped)
+    #[serde(default)]
+    pub direct_key_lookup: bool,
+    /// caller identifier (set by the download quota middleware from the caller request header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+    /// export cancellation token (cancelled by the download controller once the client disconnects - pending S3
+    /// downloads are aborted)
+    #[serde(skip)]
+    pub cancellation_token: CancellationToken,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Build info struct (embedded at compile time)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct BuildInfo {
+    /// crate version
+    pub version: String,
+    /// git commit SHA
+    pub git_sha: String,
+    /// build timestamp as seconds since unix epoch
+    pub build_timestamp: u64,
+    /// enabled cargo features
+    pub features: Vec<String>,
+}
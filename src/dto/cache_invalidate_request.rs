@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Listing cache invalidation request struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct CacheInvalidateRequest {
+    /// S3 bucket name
+    pub bucket_name: String,
+    /// S3 path prefix (all S3 paths of the S3 bucket are invalidated if not defined)
+    pub full_path: Option<String>,
+}
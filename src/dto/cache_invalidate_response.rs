@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Listing cache invalidation response struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct CacheInvalidateResponse {
+    /// invalidated cache entries quantity
+    pub invalidated_entries: usize,
+}
@@ -0,0 +1,22 @@
+use crate::enums::checksum_audit_status::ChecksumAuditStatus;
+use serde::{Deserialize, Serialize};
+
+/// Checksum audit entry struct (recomputed checksums of a single S3 object)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ChecksumAuditEntry {
+    /// S3 key (relative to the requested S3 path)
+    pub s3_key: String,
+    /// downloaded S3 object size (in bytes)
+    pub size_bytes: u64,
+    /// stored S3 object entity tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// recomputed MD5 checksum (hex encoded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+    /// recomputed SHA-256 checksum (hex encoded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// checksum audit status
+    pub status: ChecksumAuditStatus,
+}
@@ -0,0 +1,27 @@
+use crate::enums::config_value_source::ConfigValueSource;
+use serde::{Deserialize, Serialize};
+
+/// Config dump struct (fully resolved configuration with secrets redacted)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ConfigDump {
+    /// enabled compile time features
+    pub features: Vec<String>,
+    /// configuration entries (one per environment variable)
+    pub entries: Vec<ConfigEntry>,
+}
+
+/// Config entry struct
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(test, derive(Clone))]
+pub struct ConfigEntry {
+    /// environment variable name
+    pub name: String,
+    /// resolved value (secrets are redacted - not defined if there is no value)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// value source
+    pub source: ConfigValueSource,
+    /// false if the value is not valid (API server refuses to start unless configuration errors are ignored)
+    pub valid: bool,
+}
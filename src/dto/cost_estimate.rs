@@ -0,0 +1,32 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Export cost estimate struct (AWS S3 costs in USD by configured pricing table)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(test, derive(Default))]
+pub struct CostEstimate {
+    /// exported files total
+    pub files_total: usize,
+    /// exported bytes total
+    pub bytes_total: u64,
+    /// exported bytes by storage class
+    pub bytes_by_storage_class: BTreeMap<String, u64>,
+    /// LIST requests (S3 listing pages)
+    pub list_requests: u64,
+    /// GET requests (one per file or one per ranged request)
+    pub get_requests: u64,
+    /// LIST requests cost
+    pub list_requests_cost: f64,
+    /// GET requests cost
+    pub get_requests_cost: f64,
+    /// data transfer out cost
+    pub data_transfer_cost: f64,
+    /// data retrieval cost (infrequent access and archive storage classes)
+    pub retrieval_cost: f64,
+    /// total cost
+    pub total_cost: f64,
+    /// S3 keys that require a restore before export (archive storage classes)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restore_required_keys: Vec<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Export cost estimate request struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct CostEstimateRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 files would be downloaded by ranged requests of this size in bytes (one GET request per file if not defined)
+    pub multipart_chunk_bytes: Option<u64>,
+}
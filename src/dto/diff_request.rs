@@ -0,0 +1,19 @@
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use serde::{Deserialize, Serialize};
+
+/// Diff request struct (S3 objects under a S3 path compared against another S3 path or a manifest)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct DiffRequest {
+    /// source S3 bucket
+    pub bucket_name: String,
+    /// source S3 folder full path (nested folders are included)
+    pub full_path: String,
+    /// target S3 bucket (source S3 bucket if not defined - i.e.: replication or backup S3 bucket)
+    pub target_bucket_name: Option<String>,
+    /// target S3 folder full path (required if manifest is not defined)
+    pub target_full_path: Option<String>,
+    /// target manifest (S3 keys relative to the source S3 path with size and entity tag - required if target S3 path
+    /// is not defined)
+    pub manifest: Option<Vec<S3ObjectMetadata>>,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Diff response struct (S3 keys relative to the requested S3 paths, sorted)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct DiffResponse {
+    /// S3 keys found just in target
+    pub added: Vec<String>,
+    /// S3 keys found just in source
+    pub removed: Vec<String>,
+    /// S3 keys found in both with different size or entity tag
+    pub changed: Vec<String>,
+    /// S3 keys found in both with same size and entity tag quantity
+    pub unchanged_total: usize,
+}
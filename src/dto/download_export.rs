@@ -0,0 +1,15 @@
+use crate::dto::export_report::ExportReport;
+use tokio::io::DuplexStream;
+use tokio::sync::oneshot;
+
+/// Download export struct (streamed zip file)
+pub struct DownloadExport {
+    /// zip file name
+    pub file_name: String,
+    /// zip file content stream
+    pub content: DuplexStream,
+    /// export report (sent once the zip file is completed - dropped if the export fails)
+    pub report: oneshot::Receiver<ExportReport>,
+    /// S3 keys excluded by limits total (files greater than the maximum file size - listed in the excluded files zip entry)
+    pub excluded_count: usize,
+}
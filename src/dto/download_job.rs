@@ -0,0 +1,39 @@
+use crate::dto::export_report::ExportReport;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::download_job_status::DownloadJobStatus;
+use serde::{Deserialize, Serialize};
+
+/// Download job struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct DownloadJob {
+    /// job identifier
+    pub job_id: String,
+    /// job status
+    pub status: DownloadJobStatus,
+    /// job priority
+    #[serde(default)]
+    pub priority: DownloadJobPriority,
+    /// owner tenant identifier (job status is just visible to its owner tenant) - only if multi-tenancy is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    /// token to fetch (or resume with Range header) the job result - only for completed jobs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_token: Option<String>,
+    /// job (and result) expiration as seconds since unix epoch - only for finished jobs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// content-addressable artifact name (stable URL of the job result, shared by identical exports)
+    /// - only for completed jobs if artifact store is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_name: Option<String>,
+    /// job attempts (failed attempts are retried with backoff)
+    #[serde(default)]
+    pub attempts: u32,
+    /// job errors by attempt (error chain) - only for retried, failed or dead letter jobs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    /// export report (elapsed time, throughput, per file latencies and retries) - only for completed jobs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<ExportReport>,
+}
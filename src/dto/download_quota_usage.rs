@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Download quota usage struct (daily caps and consumption for the current UTC day)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct DownloadQuotaUsage {
+    /// current UTC day start (seconds since unix epoch)
+    pub day_started_at: u64,
+    /// S3 bucket daily cap in bytes (zero is unlimited)
+    pub bucket_daily_bytes_cap: u64,
+    /// caller daily cap in bytes (zero is unlimited)
+    pub caller_daily_bytes_cap: u64,
+    /// consumption by S3 bucket
+    pub buckets: Vec<QuotaConsumption>,
+    /// consumption by caller
+    pub callers: Vec<QuotaConsumption>,
+}
+
+/// Quota consumption struct (S3 bucket or caller)
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(test, derive(Clone))]
+pub struct QuotaConsumption {
+    /// S3 bucket name or caller identifier
+    pub name: String,
+    /// downloaded bytes total
+    pub bytes_total: u64,
+    /// remaining bytes until the daily cap (not defined if the cap is unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_remaining: Option<u64>,
+}
@@ -1,13 +1,84 @@
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::dto::restore_request::RestoreRequest;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use crate::enums::export_filter::ExportFilter;
+use crate::enums::metadata_report_format::MetadataReportFormat;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 
 /// Download request struct
-#[derive(Serialize, Deserialize)]
-#[cfg_attr(test, derive(Clone, Default))]
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
 pub struct DownloadRequest {
     /// S3 bucket
     pub bucket_name: String,
     /// S3 folder full path
     pub full_path: String,
+    /// stores files with identical content just once (duplicates are listed in a dedup manifest file)
+    #[serde(default)]
+    pub deduplicate: bool,
+    /// maximum parallel S3 file downloads (limited by server configuration)
+    pub max_parallel_fetches: Option<usize>,
+    /// S3 files are downloaded by ranged requests of this size in bytes (limited by server configuration)
+    pub multipart_chunk_bytes: Option<u64>,
+    /// selected files (with optional zip entry names) - all files from S3 folder full path are exported if it is not defined
+    pub manifest: Option<Vec<ManifestEntry>>,
+    /// S3 objects filter (JSON filter tree over name, size, last modified date, tags and storage class) - just matching
+    /// files are exported (manifest entries are filtered too, if defined)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ExportFilter>,
+    /// policy for manifest entries with the same zip entry name (error by default)
+    #[serde(default)]
+    pub duplicate_entry_policy: DuplicateEntryPolicy,
+    /// policy for S3 keys deleted after listing (skipped by default)
+    #[serde(default)]
+    pub missing_key_policy: MissingKeyPolicy,
+    /// manifest S3 keys are fetched directly instead of being re-validated against the S3 folder listing
+    /// (genuine 404 responses are reported as missing keys)
+    #[serde(default)]
+    pub direct_key_lookup: bool,
+    /// files greater than the maximum file size are exported instead of being excluded
+    /// (it should be enabled by server configuration and allowed by role)
+    #[serde(default)]
+    pub allow_oversize: bool,
+    /// age public key (i.e.: "age1...") to encrypt the zip file for (zip file is not encrypted if it is not defined)
+    pub encryption_recipient: Option<String>,
+    /// email address notified with the result link and export summary once the job is completed (download jobs only)
+    pub notify_email: Option<String>,
+    /// export is partitioned by S3 key ranges into this number of volumes (zip files) - download jobs only
+    /// (limited by server configuration)
+    pub volumes: Option<usize>,
+    /// export is split into one zip file per first-level sub-folder of the S3 path (i.e.: one zip file per customer
+    /// folder) - download jobs only (limited by server configuration)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub split_by_folder: bool,
+    /// job priority (interactive by default) - download jobs only
+    #[serde(default)]
+    pub priority: DownloadJobPriority,
+    /// archived S3 objects restores are requested before export and the job waits until they are retrievable
+    /// (archived S3 objects are not restored if it is not defined) - download jobs only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore: Option<RestoreRequest>,
+    /// objects metadata report (key, size, last modified date, entity tag, storage class and tags) is exported in
+    /// this format instead of objects contents (zip file is not created)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_report: Option<MetadataReportFormat>,
+    /// objects contents are downloaded and their checksums are recomputed and compared with stored checksums (entity
+    /// tags or SHA-256 checksums) - a JSON checksum audit report is exported instead of objects contents
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub checksum_audit: bool,
+    /// caller identifier (set by the download quota middleware from the caller request header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+    /// public base URL used in job result links (set by the download job controller from request forwarded headers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_base_url: Option<String>,
+    /// export cancellation token (cancelled by the download controller once the client disconnects - pending S3
+    /// downloads are aborted)
+    #[serde(skip)]
+    pub cancellation_token: CancellationToken,
 }
 
 /// Unit test cases
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Exists request struct (S3 key or S3 prefix existence)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ExistsRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path (checked as prefix if S3 key is not defined)
+    pub full_path: String,
+    /// S3 key (relative to the S3 folder full path)
+    pub s3_key: Option<String>,
+}
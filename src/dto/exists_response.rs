@@ -0,0 +1,13 @@
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use serde::{Deserialize, Serialize};
+
+/// Exists response struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ExistsResponse {
+    /// S3 key (or any S3 object under S3 prefix) exists flag
+    pub exists: bool,
+    /// S3 object metadata (first S3 object found for S3 prefixes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<S3ObjectMetadata>,
+}
@@ -0,0 +1,15 @@
+use crate::enums::export_alert_reason::ExportAlertReason;
+use serde::{Deserialize, Serialize};
+
+/// Export alert struct (slow or large export warning event - sent by alert webhook)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportAlert {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// export elapsed time (in milliseconds)
+    pub elapsed_millis: u64,
+    /// downloaded bytes total
+    pub bytes_total: u64,
+    /// exceeded thresholds
+    pub reasons: Vec<ExportAlertReason>,
+}
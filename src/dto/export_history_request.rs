@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Export history request struct (export records filters and page - read from query params)
+#[derive(Serialize, Deserialize, Default)]
+pub struct ExportHistoryRequest {
+    /// S3 bucket (all S3 buckets if it is not defined)
+    pub bucket: Option<String>,
+    /// records finished since this date (RFC 3339 format - inclusive)
+    pub from: Option<String>,
+    /// records finished until this date (RFC 3339 format - inclusive)
+    pub to: Option<String>,
+    /// maximum records per page (limited by server configuration)
+    pub limit: Option<usize>,
+    /// opaque cursor to get the next page (`next_cursor` value from the previous page)
+    pub cursor: Option<String>,
+}
@@ -0,0 +1,12 @@
+use crate::dto::export_record::ExportRecord;
+use serde::{Deserialize, Serialize};
+
+/// Export history response struct (one page of export records - newest records first)
+#[derive(Serialize, Deserialize)]
+pub struct ExportHistoryResponse {
+    /// export records
+    pub records: Vec<ExportRecord>,
+    /// opaque cursor to get the next page - only if there are more pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
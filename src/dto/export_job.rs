@@ -0,0 +1,16 @@
+use crate::dto::download_job::DownloadJob;
+use serde::{Deserialize, Serialize};
+
+/// Export job struct (API v2 download job representation)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ExportJob {
+    /// download job (identifier, status, attempts and report)
+    #[serde(flatten)]
+    pub job: DownloadJob,
+    /// job status location (relative URL)
+    pub location: String,
+    /// job result location (relative URL) - only for completed jobs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_location: Option<String>,
+}
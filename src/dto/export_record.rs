@@ -0,0 +1,34 @@
+use crate::dto::download_request::DownloadRequest;
+use crate::enums::download_job_status::DownloadJobStatus;
+use serde::{Deserialize, Serialize};
+
+/// Export record struct (finished download job audit record - who exported what and when)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExportRecord {
+    /// download job identifier
+    pub job_id: String,
+    /// final job status (completed, failed or dead letter)
+    pub status: DownloadJobStatus,
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// caller identifier (download quotas caller request header)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+    /// start date (seconds since unix epoch - once the job started running)
+    pub started_at: u64,
+    /// finish date (seconds since unix epoch)
+    pub finished_at: u64,
+    /// exported files total (completed jobs only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files_total: Option<usize>,
+    /// exported bytes total (completed jobs only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_total: Option<u64>,
+    /// exported S3 keys (sorted - completed single archive jobs only, not volume or folder split jobs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exported_files: Vec<String>,
+    /// export request (the same export can be triggered again by this request)
+    pub request: DownloadRequest,
+}
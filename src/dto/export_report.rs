@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Export report struct (download performance statistics)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(test, derive(Default))]
+pub struct ExportReport {
+    /// export elapsed time (in milliseconds)
+    pub elapsed_millis: u64,
+    /// downloaded files total
+    pub files_total: usize,
+    /// downloaded bytes total
+    pub bytes_total: u64,
+    /// aggregate throughput (downloaded bytes per second)
+    pub throughput_bytes_per_second: u64,
+    /// S3 request retries total
+    pub retries_total: u32,
+    /// slowest downloaded files (S3 keys sorted by fetch latency)
+    pub slowest_files: Vec<String>,
+    /// downloaded files statistics (archive order)
+    pub files: Vec<FileFetchReport>,
+    /// skipped S3 keys (deleted after listing)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_files: Vec<String>,
+    /// excluded S3 keys (files greater than the maximum file size)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_files: Vec<String>,
+    /// S3 keys with recomputed checksums different from their stored checksums (checksum audits only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksum_mismatches: Vec<String>,
+    /// delivered archive SHA-256 checksum (hex encoded - encrypted content if export is encrypted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_sha256: Option<String>,
+    /// delivered archive detached ed25519 signature of its SHA-256 digest (base64 encoded - if archive signing is enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_signature: Option<String>,
+}
+
+/// File fetch report struct (single S3 file download statistics)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(test, derive(Default))]
+pub struct FileFetchReport {
+    /// S3 key
+    pub s3_key: String,
+    /// file size (in bytes)
+    pub size_bytes: u64,
+    /// fetch latency (in milliseconds)
+    pub latency_millis: u64,
+    /// S3 request retries
+    pub retries: u32,
+}
@@ -0,0 +1,24 @@
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::template_variable::TemplateVariable;
+use serde::{Deserialize, Serialize};
+
+/// Export template struct (named export request - recurring exports are triggered by template identifier)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExportTemplate {
+    /// template identifier
+    pub template_id: String,
+    /// template name
+    pub name: String,
+    /// tenant owning the template (just if multi-tenancy is enabled - templates are visible just to its tenant)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    /// creation date (seconds since unix epoch)
+    pub created_at: u64,
+    /// last update date (seconds since unix epoch)
+    pub updated_at: u64,
+    /// declared template variables (`{name}` placeholders in saved export request string fields)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<TemplateVariable>,
+    /// saved export request (S3 bucket, S3 path, filter, format and notification - caller is set once triggered)
+    pub request: DownloadRequest,
+}
@@ -0,0 +1,17 @@
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::template_variable::TemplateVariable;
+use serde::{Deserialize, Serialize};
+
+/// Export template request struct (template name and the download request fields - same body as download jobs)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Default))]
+pub struct ExportTemplateRequest {
+    /// template name
+    pub name: String,
+    /// declared template variables (`{name}` placeholders in download request string fields - i.e.: S3 path)
+    #[serde(default)]
+    pub variables: Vec<TemplateVariable>,
+    /// saved export request
+    #[serde(flatten)]
+    pub request: DownloadRequest,
+}
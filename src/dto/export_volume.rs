@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Export volume struct (sharded export partition by S3 key range or by first-level sub-folder - final manifest entry)
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct ExportVolume {
+    /// volume number (S3 key ranges order)
+    pub volume: usize,
+    /// volume download job identifier
+    pub job_id: String,
+    /// first-level sub-folder (folder split exports only - S3 objects directly under the S3 path have no sub-folder)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    /// first S3 key of the volume key range (key range exports only)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub first_s3_key: String,
+    /// last S3 key of the volume key range (key range exports only)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub last_s3_key: String,
+    /// volume files total
+    pub files_total: usize,
+    /// volume zip file URL (resume token based)
+    pub result_location: Option<String>,
+}
@@ -0,0 +1,12 @@
+use std::collections::BTreeMap;
+
+use crate::enums::feature_flag::FeatureFlag;
+use serde::{Deserialize, Serialize};
+
+/// Feature flags state struct (admin feature flags end-point request and response)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct FeatureFlagsState {
+    /// enabled flag by feature (just defined features are changed by requests)
+    pub flags: BTreeMap<FeatureFlag, bool>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Field error struct (request field validation error)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct FieldError {
+    /// request field name (i.e.: "bucket_name" or "manifest[2].s3_key")
+    pub field: String,
+    /// validation error message
+    pub message: String,
+}
@@ -0,0 +1,22 @@
+use crate::enums::download_job_status::DownloadJobStatus;
+use serde::{Deserialize, Serialize};
+
+/// Download job event struct (published when a job is finished)
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct JobEvent {
+    /// job identifier
+    pub job_id: String,
+    /// job status (completed or failed)
+    pub status: DownloadJobStatus,
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// job status URL (the job result link is retrieved by this authenticated end-point - bearer job result URLs
+    /// are never published)
+    pub job_location: String,
+    /// job (and result) expiration as seconds since unix epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
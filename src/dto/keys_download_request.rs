@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use serde::{Deserialize, Serialize};
+
+/// Keys download request struct (selected S3 keys exported as zip file)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct KeysDownloadRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 keys (relative to the S3 folder full path)
+    pub s3_keys: Vec<String>,
+    /// zip entry names by S3 key (i.e.: to strip UUID prefixes) - S3 key is used if it is not found
+    #[serde(default)]
+    pub rename: HashMap<String, String>,
+    /// policy for S3 keys renamed with the same zip entry name (error by default)
+    #[serde(default)]
+    pub duplicate_entry_policy: DuplicateEntryPolicy,
+    /// age public key (i.e.: "age1...") to encrypt the zip file for (zip file is not encrypted if it is not defined)
+    pub encryption_recipient: Option<String>,
+    /// requested S3 keys are fetched directly instead of being re-validated against the S3 folder listing
+    /// (genuine 404 responses are reported as missing keys)
+    #[serde(default)]
+    pub direct_key_lookup: bool,
+    /// files greater than the maximum file size are exported instead of being excluded
+    /// (it should be enabled by server configuration and allowed by role)
+    #[serde(default)]
+    pub allow_oversize: bool,
+    /// caller identifier (set by the download quota middleware from the caller request header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle transition struct (pending S3 object storage class transition derived from S3 bucket lifecycle rules)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LifecycleTransition {
+    /// target storage class (i.e.: GLACIER, DEEP_ARCHIVE)
+    pub storage_class: String,
+    /// transition date (RFC 3339 format)
+    pub transition_date: String,
+    /// S3 object will require a restore before export once transitioned
+    pub restore_required: bool,
+}
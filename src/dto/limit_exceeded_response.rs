@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Limit exceeded response struct (configured limit and actual request value)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct LimitExceededResponse {
+    /// error code (always "LIMIT_EXCEEDED")
+    pub error: String,
+    /// configured limit (i.e.: maximum file quantity)
+    pub limit: usize,
+    /// actual request value (i.e.: listed file quantity)
+    pub actual: usize,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// List request struct (S3 objects metadata under a S3 path)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ListRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path (nested folders are included)
+    pub full_path: String,
+    /// maximum S3 objects per page (limited by server configuration - ignored by NDJSON listing)
+    pub max_keys: Option<i32>,
+    /// opaque cursor to get the next page (`next_cursor` value from the previous page - ignored by NDJSON listing)
+    pub cursor: Option<String>,
+    /// first-level sub-folders are listed in parallel (NDJSON listing only - S3 objects order is not preserved)
+    #[serde(default)]
+    pub parallel_sub_prefixes: bool,
+}
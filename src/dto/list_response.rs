@@ -0,0 +1,13 @@
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use serde::{Deserialize, Serialize};
+
+/// List response struct (one page of S3 objects metadata)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ListResponse {
+    /// S3 objects metadata
+    pub s3_objects: Vec<S3ObjectMetadata>,
+    /// opaque cursor to get the next page - only if there are more pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,10 @@
+use crate::enums::maintenance_mode::MaintenanceMode;
+use serde::{Deserialize, Serialize};
+
+/// Maintenance state struct (admin maintenance end-point request and response)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct MaintenanceState {
+    /// API maintenance mode
+    pub mode: MaintenanceMode,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Manifest entry struct (file selected for export)
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct ManifestEntry {
+    /// S3 key (relative to the S3 folder full path)
+    pub s3_key: String,
+    /// zip entry name (S3 key is used if it is not defined)
+    pub archive_name: Option<String>,
+}
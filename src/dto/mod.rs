@@ -1,2 +1,70 @@
+pub mod access_log_entry;
+pub mod api_error;
+pub mod archive_entry;
+pub mod archive_preview;
+pub mod archive_request;
+pub mod batch_download_request;
+pub mod build_info;
+pub mod cache_invalidate_request;
+pub mod cache_invalidate_response;
+pub mod checksum_audit_entry;
+pub mod config_dump;
+pub mod cost_estimate;
+pub mod cost_estimate_request;
+pub mod diff_request;
+pub mod diff_response;
+pub mod download_export;
+pub mod download_quota_usage;
+pub mod download_job;
 pub mod download_request;
+pub mod exists_request;
+pub mod exists_response;
+pub mod export_alert;
+pub mod export_history_request;
+pub mod export_history_response;
+pub mod export_job;
+pub mod export_record;
+pub mod export_report;
+pub mod export_template;
+pub mod export_template_request;
+pub mod export_volume;
+pub mod feature_flags_state;
+pub mod field_error;
 pub mod health;
+pub mod job_event;
+pub mod keys_download_request;
+pub mod lifecycle_transition;
+pub mod limit_exceeded_response;
+pub mod list_request;
+pub mod list_response;
+pub mod maintenance_state;
+pub mod manifest_entry;
+pub mod object_request;
+pub mod pricing_table;
+pub mod rbac_role_rule;
+pub mod rerun_job_request;
+pub mod restore_request;
+pub mod retention_cleanup_stats;
+pub mod s3_event_notification;
+pub mod s3_event_response;
+pub mod s3_event_rule;
+pub mod s3_event_webhook;
+pub mod s3_fetch_options;
+pub mod s3_object;
+pub mod s3_object_metadata;
+pub mod s3_object_stream;
+pub mod s3_object_stream_options;
+pub mod search_match;
+pub mod search_request;
+pub mod search_response;
+pub mod search_result;
+pub mod signed_link;
+pub mod signed_link_query;
+pub mod signed_link_request;
+pub mod template_job_request;
+pub mod template_variable;
+pub mod text_preview;
+pub mod text_preview_request;
+pub mod thumbnail_request;
+pub mod usage_stats;
+pub mod validation_error_response;
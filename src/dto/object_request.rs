@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Object request struct (single S3 object proxy - read from query params)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ObjectRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 key (relative to the S3 folder full path)
+    pub s3_key: String,
+}
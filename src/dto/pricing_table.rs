@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Pricing table struct (AWS S3 prices in USD used by export cost estimations)
+/// Important: default prices are based on us-east-1 public prices (please, check the configured pricing file)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PricingTable {
+    /// GET requests price (per 1,000 requests)
+    pub get_requests_per_thousand: f64,
+    /// LIST requests price (per 1,000 requests)
+    pub list_requests_per_thousand: f64,
+    /// data transfer out price (per GB)
+    pub data_transfer_per_gb: f64,
+    /// data retrieval prices by storage class (per GB - storage classes without retrieval fee are not listed)
+    pub retrieval_per_gb: HashMap<String, f64>,
+}
+
+/// default initialization (us-east-1 public prices)
+impl Default for PricingTable {
+    fn default() -> Self {
+        PricingTable {
+            get_requests_per_thousand: 0.0004,
+            list_requests_per_thousand: 0.005,
+            data_transfer_per_gb: 0.09,
+            retrieval_per_gb: HashMap::from([
+                (String::from("STANDARD_IA"), 0.01),
+                (String::from("ONEZONE_IA"), 0.01),
+                (String::from("GLACIER_IR"), 0.03),
+                (String::from("GLACIER"), 0.01),
+                (String::from("DEEP_ARCHIVE"), 0.02),
+            ]),
+        }
+    }
+}
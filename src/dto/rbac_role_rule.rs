@@ -0,0 +1,14 @@
+use crate::enums::rbac_operation::RbacOperation;
+use serde::{Deserialize, Serialize};
+
+/// Role-based access control role rule struct (a role is defined by a list of rules)
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct RbacRoleRule {
+    /// allowed S3 bucket ("*" for any S3 bucket)
+    pub bucket_name: String,
+    /// allowed S3 path patterns (i.e.: "reports/*" for any S3 path under reports folder, "*" for any S3 path)
+    pub prefix_patterns: Vec<String>,
+    /// allowed operations
+    pub operations: Vec<RbacOperation>,
+}
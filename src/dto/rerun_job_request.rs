@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Re-run job request struct (optional body to start a previous download job again)
+#[derive(Serialize, Deserialize, Default)]
+pub struct RerunJobRequest {
+    /// just files not exported by the previous job or modified since it started are exported (completed single archive
+    /// jobs only - not volume or folder split jobs)
+    #[serde(default)]
+    pub incremental: bool,
+}
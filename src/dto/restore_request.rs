@@ -0,0 +1,12 @@
+use crate::enums::s3_restore_tier::S3RestoreTier;
+use serde::{Deserialize, Serialize};
+
+/// Restore request struct (archived S3 objects restores requested by download jobs before export)
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RestoreRequest {
+    /// restored copies lifetime (in days - please, check constants.rs for the default value)
+    pub days: Option<i32>,
+    /// restore tier (standard by default)
+    #[serde(default)]
+    pub tier: S3RestoreTier,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Retention cleanup stats struct (accumulated since server start)
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(test, derive(Clone))]
+pub struct RetentionCleanupStats {
+    /// removed jobs total
+    pub removed_jobs_total: u64,
+    /// reclaimed bytes total (stored job results)
+    pub reclaimed_bytes_total: u64,
+    /// removed S3 objects total (expired exports written to S3)
+    pub removed_objects_total: u64,
+    /// reclaimed S3 bytes total (expired exports written to S3)
+    pub reclaimed_object_bytes_total: u64,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 event notification struct (Amazon S3 event notification payload - just used fields)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct S3EventNotification {
+    /// S3 event records
+    #[serde(rename = "Records", default)]
+    pub records: Vec<S3EventRecord>,
+}
+
+/// S3 event record struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+#[serde(rename_all = "camelCase")]
+pub struct S3EventRecord {
+    /// S3 event name (i.e.: "ObjectCreated:Put")
+    pub event_name: String,
+    /// S3 event entity
+    pub s3: S3EventEntity,
+}
+
+/// S3 event entity struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct S3EventEntity {
+    /// S3 bucket
+    pub bucket: S3EventBucket,
+    /// S3 object
+    pub object: S3EventObject,
+}
+
+/// S3 event bucket struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct S3EventBucket {
+    /// S3 bucket name
+    pub name: String,
+}
+
+/// S3 event object struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct S3EventObject {
+    /// S3 key (URL encoded)
+    pub key: String,
+    /// S3 object size (in bytes)
+    pub size: Option<i64>,
+}
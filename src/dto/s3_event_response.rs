@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 event ingestion response struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct S3EventResponse {
+    /// received S3 object created events
+    pub events_received: usize,
+    /// triggered actions (matching rules)
+    pub actions_triggered: usize,
+    /// failed actions
+    pub actions_failed: usize,
+}
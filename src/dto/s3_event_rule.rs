@@ -0,0 +1,19 @@
+use crate::enums::s3_event_action::S3EventAction;
+use serde::{Deserialize, Serialize};
+
+/// S3 event rule struct (action triggered for S3 objects created under a S3 prefix)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct S3EventRule {
+    /// S3 bucket (rules for any S3 bucket - "*" - are not allowed)
+    pub bucket_name: String,
+    /// S3 key prefix (i.e.: "incoming/")
+    pub prefix: String,
+    /// triggered action
+    pub action: S3EventAction,
+    /// destination S3 bucket (archive and copy actions)
+    pub destination_bucket: Option<String>,
+    /// destination S3 path (archive and copy actions)
+    pub destination_path: Option<String>,
+    /// webhook URL (webhook action)
+    pub webhook_url: Option<String>,
+}
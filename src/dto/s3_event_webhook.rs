@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 event webhook payload struct (sent by webhook action)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct S3EventWebhook {
+    /// S3 event name (i.e.: "ObjectCreated:Put")
+    pub event_name: String,
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 key (decoded)
+    pub s3_key: String,
+    /// S3 object size (in bytes)
+    pub size_bytes: Option<i64>,
+}
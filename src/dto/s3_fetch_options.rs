@@ -0,0 +1,19 @@
+use tokio_util::sync::CancellationToken;
+
+/// S3 fetch options struct (per-request tuning for S3 objects downloads)
+#[derive(Clone, Default)]
+pub struct S3FetchOptions {
+    /// maximum parallel S3 object downloads (limited by configured fetch window size)
+    pub max_parallel_fetches: Option<usize>,
+    /// S3 objects are downloaded by ranged requests of this size (in bytes) instead of a single request
+    pub multipart_chunk_bytes: Option<u64>,
+    /// requested S3 keys are fetched directly (without S3 path listing re-validation)
+    pub direct_key_lookup: bool,
+    /// S3 objects greater than the configured maximum file size are not excluded from S3 path listing
+    pub allow_oversize: bool,
+    /// S3 objects not smaller than the configured stream min size are yielded as content streams (not buffered - just
+    /// for single requests, multipart chunk bytes not defined)
+    pub stream_content: bool,
+    /// pending S3 object downloads are aborted once this token is cancelled (i.e.: the client disconnected)
+    pub cancellation_token: CancellationToken,
+}
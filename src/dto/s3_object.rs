@@ -0,0 +1,21 @@
+use aws_sdk_s3::primitives::ByteStream;
+use crate::config::memory_budget::MemoryReservation;
+use crate::enums::common_error::CommonError;
+
+/// Downloaded S3 object struct
+pub struct S3Object {
+    /// S3 key (relative to the requested S3 path)
+    pub s3_key: String,
+    /// S3 object content
+    pub content: Vec<u8>,
+    /// S3 object content stream (streamed S3 objects - content is empty and the stream is read by the consumer)
+    pub content_stream: Option<ByteStream>,
+    /// S3 object fetch latency (in milliseconds)
+    pub fetch_latency_millis: u64,
+    /// S3 object fetch retries (SDK retries)
+    pub fetch_retries: u32,
+    /// memory budget reservation for the S3 object content (released when the S3 object is dropped)
+    pub memory_reservation: MemoryReservation,
+    /// S3 object fetch error (content is empty) - [CommonError::NO_DATA_FOUND] if S3 key was deleted after listing
+    pub fetch_error: Option<CommonError>,
+}
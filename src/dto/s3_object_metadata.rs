@@ -0,0 +1,34 @@
+use crate::dto::lifecycle_transition::LifecycleTransition;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// S3 object metadata struct
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(test, derive(Default))]
+pub struct S3ObjectMetadata {
+    /// S3 key (relative to the requested S3 path)
+    pub s3_key: String,
+    /// S3 object size (in bytes)
+    pub size_bytes: i64,
+    /// S3 object last modified date (RFC 3339 format)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// S3 object entity tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// S3 object storage class (i.e.: STANDARD, GLACIER, DEEP_ARCHIVE)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<String>,
+    /// S3 object requires a restore before export (archive storage classes, i.e.: GLACIER, DEEP_ARCHIVE)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub restore_required: bool,
+    /// next pending storage class transition (derived from S3 bucket lifecycle rules, if enabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_transition: Option<LifecycleTransition>,
+    /// S3 object tags (just requested by metadata reports)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<BTreeMap<String, String>>,
+    /// S3 object user metadata (x-amz-meta-* - just requested by S3 key, i.e.: client-side envelope encryption metadata)
+    #[serde(skip)]
+    pub user_metadata: Option<HashMap<String, String>>,
+}
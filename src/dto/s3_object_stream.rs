@@ -0,0 +1,19 @@
+use aws_sdk_s3::primitives::{ByteStream, DateTime};
+
+/// S3 object stream struct (S3 object content - or content range - streamed as it is read from S3 and its HTTP headers)
+pub struct S3ObjectStream {
+    /// S3 object was not modified by the conditional request headers (S3 object content is empty)
+    pub not_modified: bool,
+    /// S3 object content (or requested content range)
+    pub content: ByteStream,
+    /// S3 object content length (in bytes - just the content range length for ranged requests)
+    pub content_length: Option<i64>,
+    /// S3 object content range (i.e.: "bytes 0-1023/4096" - just for ranged requests)
+    pub content_range: Option<String>,
+    /// S3 object content type
+    pub content_type: Option<String>,
+    /// S3 object entity tag
+    pub e_tag: Option<String>,
+    /// S3 object last modified date
+    pub last_modified: Option<DateTime>,
+}
@@ -0,0 +1,12 @@
+use aws_sdk_s3::primitives::DateTime;
+
+/// S3 object stream options struct (HTTP range and conditional request headers forwarded as they are to S3)
+#[derive(Clone, Default)]
+pub struct S3ObjectStreamOptions {
+    /// HTTP range (i.e.: "bytes=0-1023" - the whole S3 object content if it is not defined)
+    pub range: Option<String>,
+    /// S3 object content is not retrieved if its entity tag matches any of these entity tags
+    pub if_none_match: Option<String>,
+    /// S3 object content is not retrieved if it was not modified since this date
+    pub if_modified_since: Option<DateTime>,
+}
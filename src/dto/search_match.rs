@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Full-text search matching line struct
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SearchMatch {
+    /// line number (starting at 1)
+    pub line_number: usize,
+    /// matching line snippet (long lines are truncated)
+    pub snippet: String,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Full-text search request struct (text S3 objects under a S3 folder)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct SearchRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path (all nested folders are searched)
+    pub full_path: String,
+    /// searched text (a regular expression if regex is true)
+    pub query: String,
+    /// query is a regular expression (plain text by default)
+    #[serde(default)]
+    pub regex: bool,
+    /// query is matched ignoring case
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// maximum matching lines (default matching lines if not defined - please, check constants.rs)
+    pub max_matches: Option<usize>,
+}
@@ -0,0 +1,15 @@
+use crate::dto::search_result::SearchResult;
+use serde::{Deserialize, Serialize};
+
+/// Full-text search response struct (S3 objects with matching lines, sorted by S3 key)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SearchResponse {
+    /// S3 objects with matching lines
+    pub results: Vec<SearchResult>,
+    /// S3 objects searched quantity
+    pub scanned_total: usize,
+    /// S3 objects skipped quantity (too large, archived or binary S3 objects)
+    pub skipped_total: usize,
+    /// search stopped once the maximum matching lines were found
+    pub truncated: bool,
+}
@@ -0,0 +1,11 @@
+use crate::dto::search_match::SearchMatch;
+use serde::{Deserialize, Serialize};
+
+/// Full-text search result struct (matching lines of one S3 object)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SearchResult {
+    /// S3 key (relative to the requested S3 path)
+    pub s3_key: String,
+    /// matching lines
+    pub matches: Vec<SearchMatch>,
+}
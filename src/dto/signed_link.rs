@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Signed link struct (time-limited export link)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct SignedLink {
+    /// relative export URL with signed token
+    pub url: String,
+    /// signed token
+    pub token: String,
+    /// link expiration as seconds since unix epoch
+    pub expires_at: u64,
+}
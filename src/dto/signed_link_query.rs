@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Signed link query struct
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct SignedLinkQuery {
+    /// signed token
+    pub token: String,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Signed link request struct (time-limited export link)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct SignedLinkRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// link expiration (in seconds) - limited by server configuration
+    pub expires_in_seconds: Option<u64>,
+    /// link creator caller identifier (set by the download quota middleware from the authenticated caller - link
+    /// downloads are accounted to the link creator)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller_id: Option<String>,
+}
@@ -0,0 +1,13 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Template job request struct (optional body to trigger an export template - variable values and schedule time)
+#[derive(Serialize, Deserialize, Default)]
+pub struct TemplateJobRequest {
+    /// declared template variables values by variable name
+    #[serde(default)]
+    pub parameters: BTreeMap<String, String>,
+    /// schedule time used by built-in variables (RFC 3339 format - current time by default, i.e.: for delayed scheduled triggers)
+    pub schedule_time: Option<String>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Template variable struct (declared export template variable - `{name}` placeholders are replaced once the template is triggered)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TemplateVariable {
+    /// variable name (ASCII alphanumeric or underscore characters)
+    pub name: String,
+    /// value used if the trigger request doesn't define this variable (the variable is required otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+}
@@ -0,0 +1,17 @@
+use crate::enums::text_encoding::TextEncoding;
+use serde::{Deserialize, Serialize};
+
+/// Text preview struct (first bytes of a text S3 object decoded as text)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct TextPreview {
+    /// S3 key (relative to the requested S3 path)
+    pub s3_key: String,
+    /// S3 object size (in bytes)
+    pub size_bytes: u64,
+    /// decoded text encoding
+    pub encoding: TextEncoding,
+    /// previewed text content
+    pub content: String,
+    /// previewed text content is just the first bytes of the S3 object
+    pub truncated: bool,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Text preview request struct (first bytes of a text S3 object - read from query params)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct TextPreviewRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 key (relative to the S3 folder full path)
+    pub s3_key: String,
+    /// previewed size (in KB - please, check constants.rs for default and maximum sizes)
+    pub size_kb: Option<u64>,
+    /// text encoding is detected (byte order mark or content) instead of decoding content as UTF-8
+    #[serde(default)]
+    pub detect_encoding: bool,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Thumbnail request struct (image S3 object thumbnail - read from query params)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ThumbnailRequest {
+    /// S3 bucket
+    pub bucket_name: String,
+    /// S3 folder full path
+    pub full_path: String,
+    /// S3 key (relative to the S3 folder full path)
+    pub s3_key: String,
+    /// thumbnail maximum width and height (in pixels - please, check constants.rs for default and allowed sizes)
+    pub size: Option<u32>,
+}
@@ -0,0 +1,36 @@
+use crate::dto::retention_cleanup_stats::RetentionCleanupStats;
+use serde::{Deserialize, Serialize};
+
+/// Usage stats struct (rolling window summary)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct UsageStats {
+    /// rolling window (in seconds)
+    pub window_seconds: u64,
+    /// successfully served exports
+    pub exports_served: u64,
+    /// failed exports
+    pub exports_failed: u64,
+    /// exports aborted by disconnected clients (response body dropped before being completely sent)
+    pub exports_aborted: u64,
+    /// failed exports rate (from 0 to 1)
+    pub error_rate: f64,
+    /// transferred bytes (downloaded S3 files contents)
+    pub bytes_transferred: u64,
+    /// buckets with more exports
+    pub top_buckets: Vec<BucketUsageStats>,
+    /// retention cleanup counters (accumulated since server start - not limited by the rolling window)
+    pub retention_cleanup: RetentionCleanupStats,
+}
+
+/// Bucket usage stats struct
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(test, derive(Clone))]
+pub struct BucketUsageStats {
+    /// S3 bucket name
+    pub bucket_name: String,
+    /// exports total (served and failed)
+    pub exports_total: u64,
+    /// transferred bytes total
+    pub bytes_total: u64,
+}
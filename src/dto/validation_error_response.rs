@@ -0,0 +1,12 @@
+use crate::dto::field_error::FieldError;
+use serde::{Deserialize, Serialize};
+
+/// Validation error response struct (field-level request validation errors)
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(test, derive(Clone, Default))]
+pub struct ValidationErrorResponse {
+    /// error code (always "VALIDATION_FAILED")
+    pub error: String,
+    /// field validation errors
+    pub fields: Vec<FieldError>,
+}
@@ -0,0 +1,23 @@
+/// Archive format (S3-hosted archives supported by entries listing and selective extraction)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum ArchiveFormat {
+    /// ZIP archive (entries are listed from its central directory)
+    ZIP,
+    /// tar archive - not compressed (entries are listed from their headers)
+    TAR,
+}
+
+impl ArchiveFormat {
+    /// Gets [Option<ArchiveFormat>] archive format by [&str] S3 key extension (".zip" or ".tar" - case insensitive)
+    pub fn from_s3_key(s3_key: &str) -> Option<Self> {
+        let s3_key = s3_key.to_ascii_lowercase();
+        if s3_key.ends_with(".zip") {
+            Some(ArchiveFormat::ZIP)
+        } else if s3_key.ends_with(".tar") {
+            Some(ArchiveFormat::TAR)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Authorization decision (authorization hook result for a scoped request)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum AuthorizationDecision {
+    /// request is allowed (it goes on to the service layer)
+    ALLOW,
+    /// request is denied (forbidden status response)
+    DENY,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Checksum audit status (recomputed checksum compared with the stored checksum of a S3 object)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum ChecksumAuditStatus {
+    /// recomputed checksum is equal to the stored checksum
+    MATCHED,
+    /// recomputed checksum (or size) is different from the stored checksum (or size)
+    MISMATCHED,
+    /// there is no comparable stored checksum (i.e.: multipart uploads without a full object SHA-256 checksum)
+    UNVERIFIABLE,
+    /// S3 object was deleted after listing
+    MISSING,
+}
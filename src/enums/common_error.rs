@@ -7,6 +7,16 @@ use std::fmt::Formatter;
 pub enum CommonError {
     NO_VALID_INPUT_OR_PARAMETER,
     AWS_ACCESS_ERROR,
+    NO_DATA_FOUND,
+    MEMORY_BUDGET_EXCEEDED,
+    ACCESS_DENIED,
+    QUOTA_EXCEEDED,
+    /// configured limit (i.e.: maximum file quantity) exceeded by the actual request value
+    LIMIT_EXCEEDED { limit: usize, actual: usize },
+    /// requested HTTP range is not satisfiable by the S3 object size
+    RANGE_NOT_SATISFIABLE,
+    /// S3 object is protected by Object Lock (active retention period or legal hold) - it can't be overwritten or removed
+    OBJECT_LOCKED,
 }
 
 /// [std::fmt::Display] trait implementation
@@ -0,0 +1,11 @@
+/// API request concurrency class (each class has its own request budget)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum ConcurrencyClass {
+    /// listing and browse requests (S3 metadata, job status, estimates, previews...)
+    LISTING,
+    /// archive streaming requests (zip files and raw S3 objects - the budget is held until the response body is sent)
+    ARCHIVE,
+    /// admin requests
+    ADMIN,
+}
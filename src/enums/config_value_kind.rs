@@ -0,0 +1,27 @@
+/// Configuration value kind (to check and redact resolved configuration values)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum ConfigValueKind {
+    /// free text value (i.e.: paths or names)
+    TEXT,
+    /// numeric value (i.e.: limits or seconds)
+    NUMBER,
+    /// numeric value greater than zero (i.e.: limits or intervals disabling the service if they are zero)
+    POSITIVE_NUMBER,
+    /// boolean value
+    FLAG,
+    /// secret value (redacted)
+    SECRET,
+}
+
+impl ConfigValueKind {
+    /// Gets [bool] true if [&str] value is valid for this kind
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            ConfigValueKind::NUMBER => value.parse::<u64>().is_ok() || value.parse::<i64>().is_ok(),
+            ConfigValueKind::POSITIVE_NUMBER => value.parse::<u64>().is_ok_and(|value| value > 0),
+            ConfigValueKind::FLAG => value.parse::<bool>().is_ok(),
+            ConfigValueKind::TEXT | ConfigValueKind::SECRET => true,
+        }
+    }
+}
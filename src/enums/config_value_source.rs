@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration value source (resolved configuration dump)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum ConfigValueSource {
+    /// value is read from the environment variable
+    ENVIRONMENT,
+    /// environment variable is not defined (default value is used)
+    DEFAULT,
+    /// environment variable is not defined and there is no default value (feature is disabled)
+    #[default]
+    NOT_DEFINED,
+}
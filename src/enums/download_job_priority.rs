@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Download job priority (each priority has its own concurrency budget)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum DownloadJobPriority {
+    /// small exports requested by users (not queued behind batch jobs)
+    #[default]
+    INTERACTIVE,
+    /// long running exports (i.e.: scheduled or huge exports)
+    BATCH,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Download job status
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum DownloadJobStatus {
+    #[default]
+    PENDING,
+    RUNNING,
+    /// job is waiting for archived S3 objects restores (restore status is polled until they are retrievable)
+    WAITING_RESTORE,
+    COMPLETED,
+    FAILED,
+    /// terminal status once all retry attempts failed (job errors are kept)
+    DEAD_LETTER,
+}
+
+impl DownloadJobStatus {
+    /// Gets [bool] true if the job is finished (completed, failed or dead letter)
+    pub fn is_finished(&self) -> bool {
+        matches!(self, DownloadJobStatus::COMPLETED | DownloadJobStatus::FAILED | DownloadJobStatus::DEAD_LETTER)
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Duplicate zip entry name policy (i.e.: two selected S3 keys renamed with the same zip entry name)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum DuplicateEntryPolicy {
+    /// export is rejected
+    #[default]
+    ERROR,
+    /// duplicated entries are renamed with a numeric suffix (i.e.: "report (1).pdf")
+    SUFFIX,
+    /// just the most recently modified S3 object is exported
+    KEEP_NEWEST,
+}
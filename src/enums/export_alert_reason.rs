@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Export alert reason (configured threshold exceeded by an export)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum ExportAlertReason {
+    /// export duration threshold exceeded
+    SLOW,
+    /// export size threshold exceeded
+    LARGE,
+}
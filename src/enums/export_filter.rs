@@ -0,0 +1,149 @@
+use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+use crate::constant::constants::AWS_S3_DEFAULT_STORAGE_CLASS;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use serde::{Deserialize, Serialize};
+
+/// Export filter (JSON filter tree - and / or / not conditions over S3 objects name, size, last modified date, tags
+/// and storage class - i.e.: `{"and":[{"name":"*.csv"},{"size":{"max_bytes":1048576}}]}`)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum ExportFilter {
+    /// all conditions should match
+    AND(Vec<ExportFilter>),
+    /// any condition should match
+    OR(Vec<ExportFilter>),
+    /// condition should not match
+    NOT(Box<ExportFilter>),
+    /// S3 key (relative to the requested S3 path) matches a glob pattern (`*` any characters, `?` one character)
+    NAME(String),
+    /// S3 object size (in bytes) is within the defined bounds (both inclusive)
+    SIZE { min_bytes: Option<u64>, max_bytes: Option<u64> },
+    /// S3 object last modified date is within the defined bounds (RFC 3339 format - both inclusive)
+    MTIME { after: Option<String>, before: Option<String> },
+    /// S3 object has a tag with this key (and this value, if defined)
+    TAG { key: String, value: Option<String> },
+    /// S3 object storage class is any of these storage classes (i.e.: STANDARD, GLACIER)
+    #[serde(rename = "storage_class")]
+    STORAGE_CLASS(Vec<String>),
+}
+
+impl ExportFilter {
+    /// Gets [bool] true if any condition depends on S3 object tags (tags should be requested for each S3 object)
+    pub fn requires_tags(&self) -> bool {
+        match self {
+            ExportFilter::AND(export_filters) | ExportFilter::OR(export_filters) => export_filters.iter().any(ExportFilter::requires_tags),
+            ExportFilter::NOT(export_filter) => export_filter.requires_tags(),
+            ExportFilter::TAG { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Gets [bool] true if [&S3ObjectMetadata] S3 object metadata matches the filter (S3 objects without last modified
+    /// date or without requested tags don't match last modified date or tag conditions)
+    pub fn is_match(&self, s3_object_metadata: &S3ObjectMetadata) -> bool {
+        match self {
+            ExportFilter::AND(export_filters) => export_filters.iter().all(|export_filter| export_filter.is_match(s3_object_metadata)),
+            ExportFilter::OR(export_filters) => export_filters.iter().any(|export_filter| export_filter.is_match(s3_object_metadata)),
+            ExportFilter::NOT(export_filter) => !export_filter.is_match(s3_object_metadata),
+            ExportFilter::NAME(pattern) => is_glob_match(pattern.as_bytes(), s3_object_metadata.s3_key.as_bytes()),
+            ExportFilter::SIZE { min_bytes, max_bytes } => {
+                let size_bytes = s3_object_metadata.size_bytes.max(0) as u64;
+                min_bytes.is_none_or(|min_bytes| size_bytes >= min_bytes) && max_bytes.is_none_or(|max_bytes| size_bytes <= max_bytes)
+            }
+            ExportFilter::MTIME { after, before } => {
+                let Some(last_modified) = s3_object_metadata.last_modified.as_deref().and_then(parse_filter_date) else {
+                    return false;
+                };
+                after.as_deref().and_then(parse_filter_date).is_none_or(|after| last_modified >= after)
+                    && before.as_deref().and_then(parse_filter_date).is_none_or(|before| last_modified <= before)
+            }
+            ExportFilter::TAG { key, value } => s3_object_metadata
+                .tags
+                .as_ref()
+                .and_then(|tags| tags.get(key))
+                .is_some_and(|tag_value| value.as_ref().is_none_or(|value| value == tag_value)),
+            ExportFilter::STORAGE_CLASS(storage_classes) => {
+                let storage_class = s3_object_metadata.storage_class.as_deref().unwrap_or(AWS_S3_DEFAULT_STORAGE_CLASS);
+                storage_classes.iter().any(|filter_storage_class| filter_storage_class.eq_ignore_ascii_case(storage_class))
+            }
+        }
+    }
+}
+
+/// Gets [Option<i64>] epoch seconds by [&str] date (RFC 3339 format - [None] if date is not valid)
+pub fn parse_filter_date(date: &str) -> Option<i64> {
+    DateTime::from_str(date, DateTimeFormat::DateTime).ok().map(|date_time| date_time.secs())
+}
+
+/// Gets [bool] true if [&[u8]] text matches [&[u8]] glob pattern (`*` any characters, `?` one character)
+fn is_glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut pattern_index, mut text_index) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while text_index < text.len() {
+        match pattern.get(pattern_index) {
+            Some(b'*') => {
+                backtrack = Some((pattern_index, text_index));
+                pattern_index += 1;
+            }
+            Some(pattern_char) if *pattern_char == b'?' || *pattern_char == text[text_index] => {
+                pattern_index += 1;
+                text_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_text_index)) => {
+                    backtrack = Some((star_index, star_text_index + 1));
+                    pattern_index = star_index + 1;
+                    text_index = star_text_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[pattern_index..].iter().all(|pattern_char| *pattern_char == b'*')
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::export_filter::ExportFilter;
+
+    /// Scenario:
+    /// Matches a JSON filter tree (CSV files up to 1 KB, modified in 2026, tagged by the finance team and not archived)
+    /// against several S3 objects
+    /// Expectation:
+    /// Just the S3 object matching all conditions should match and tags should be required by the filter
+    #[test]
+    fn when_is_match_should_evaluate_filter_tree() {
+        let export_filter: ExportFilter = serde_json::from_str(
+            r#"{"and":[
+                {"or":[{"name":"*.csv"},{"name":"report-??.txt"}]},
+                {"size":{"max_bytes":1024}},
+                {"mtime":{"after":"2026-01-01T00:00:00Z"}},
+                {"tag":{"key":"team","value":"finance"}},
+                {"not":{"storage_class":["glacier","DEEP_ARCHIVE"]}}
+            ]}"#,
+        )
+        .unwrap();
+        let s3_object_metadata_fn = |s3_key: &str, size_bytes: i64, storage_class: &str| S3ObjectMetadata {
+            s3_key: String::from(s3_key),
+            size_bytes,
+            last_modified: Some(String::from("2026-03-01T10:00:00Z")),
+            storage_class: Some(String::from(storage_class)),
+            tags: Some(BTreeMap::from([(String::from("team"), String::from("finance"))])),
+            ..Default::default()
+        };
+
+        assert!(export_filter.requires_tags());
+        assert!(export_filter.is_match(&s3_object_metadata_fn("sales.csv", 512, "STANDARD")));
+        assert!(export_filter.is_match(&s3_object_metadata_fn("report-01.txt", 512, "STANDARD")));
+        assert!(!export_filter.is_match(&s3_object_metadata_fn("report-001.txt", 512, "STANDARD")));
+        assert!(!export_filter.is_match(&s3_object_metadata_fn("sales.csv.bak", 512, "STANDARD")));
+        assert!(!export_filter.is_match(&s3_object_metadata_fn("sales.csv", 2048, "STANDARD")));
+        assert!(!export_filter.is_match(&s3_object_metadata_fn("sales.csv", 512, "GLACIER")));
+        assert!(!export_filter.is_match(&S3ObjectMetadata { tags: None, ..s3_object_metadata_fn("sales.csv", 512, "STANDARD") }));
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Runtime feature flag (experimental end-points)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum FeatureFlag {
+    /// download jobs end-points (async export + stored result)
+    JOB_API,
+    /// API v2 exports end-points (job-first flow with typed errors)
+    API_V2,
+    /// batch download end-point (raw files as multipart/mixed parts)
+    BATCH_DOWNLOAD,
+    /// S3 objects listing as NDJSON stream end-point
+    LIST_STREAM,
+}
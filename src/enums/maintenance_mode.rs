@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// API maintenance mode
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum MaintenanceMode {
+    /// all requests are served
+    #[default]
+    OFF,
+    /// new exports are rejected (listings and job status queries are served)
+    READ_ONLY,
+    /// all requests are rejected except health, job status and admin requests
+    FULL,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Metadata report format (objects metadata exported instead of objects contents)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum MetadataReportFormat {
+    /// CSV report (one row per S3 object, with header row)
+    CSV,
+    /// JSON report (array of S3 objects metadata)
+    JSON,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Missing S3 key policy (i.e.: a listed S3 object deleted before its content is downloaded)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum MissingKeyPolicy {
+    /// missing S3 keys are skipped and listed in the export report (and in a missing files zip entry)
+    #[default]
+    SKIP,
+    /// export is aborted
+    FAIL,
+}
@@ -1 +1,22 @@
+pub mod archive_format;
+pub mod authorization_decision;
+pub mod checksum_audit_status;
 pub mod common_error;
+pub mod concurrency_class;
+pub mod config_value_kind;
+pub mod config_value_source;
+pub mod download_job_priority;
+pub mod download_job_status;
+pub mod duplicate_entry_policy;
+pub mod export_alert_reason;
+pub mod export_filter;
+pub mod feature_flag;
+pub mod maintenance_mode;
+pub mod metadata_report_format;
+pub mod missing_key_policy;
+pub mod rbac_operation;
+pub mod rbac_scope;
+pub mod s3_event_action;
+pub mod s3_restore_status;
+pub mod s3_restore_tier;
+pub mod text_encoding;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Role-based access control operation (allowed operations are defined by role rule)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum RbacOperation {
+    /// S3 objects listing (exists and list end-points)
+    LIST,
+    /// S3 objects download (zip, batch, jobs and signed links end-points)
+    DOWNLOAD,
+    /// S3 objects greater than the maximum file size download (`allow_oversize` download requests)
+    OVERSIZE,
+    /// S3 objects upload
+    UPLOAD,
+    /// S3 objects delete
+    DELETE,
+    /// admin end-points (cache invalidation, configuration, maintenance mode and feature flags)
+    ADMIN,
+    /// S3 event notifications (S3 event rules actions)
+    EVENTS,
+}
@@ -0,0 +1,11 @@
+/// Role-based access control request scope (where the requested S3 bucket and path are read from)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum RbacScope {
+    /// S3 bucket and path are read from the JSON request body (or from query params for GET requests)
+    REQUEST,
+    /// S3 bucket and path are read from a stored request and checked by the controller (i.e.: export templates or previous jobs)
+    STORED,
+    /// requests without S3 bucket and path (i.e.: admin end-points) - the operation is checked over any S3 bucket and path
+    GLOBAL,
+}
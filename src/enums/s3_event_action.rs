@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 event action (triggered for S3 objects created under a configured S3 prefix)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum S3EventAction {
+    /// S3 object is archived as a zip file into the destination S3 bucket
+    ARCHIVE,
+    /// S3 object is copied into the destination S3 bucket
+    COPY,
+    /// S3 object details are sent to the webhook URL
+    WEBHOOK,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 archived object restore status (archive storage classes, i.e.: GLACIER, DEEP_ARCHIVE)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum S3RestoreStatus {
+    /// restore is not requested (or restored copy is expired)
+    NOT_RESTORED,
+    /// restore is requested but S3 object is not retrievable yet
+    IN_PROGRESS,
+    /// restored copy is retrievable (until restore expiry date)
+    RESTORED,
+}
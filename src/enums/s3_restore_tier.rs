@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// S3 archived object restore tier (retrieval speed and cost)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum S3RestoreTier {
+    /// restored within minutes (GLACIER only - most expensive)
+    EXPEDITED,
+    /// restored within hours
+    #[default]
+    STANDARD,
+    /// restored within hours or days (cheapest)
+    BULK,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Text encoding (text preview content decoding - detected by byte order mark or content)
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum TextEncoding {
+    /// UTF-8 (default encoding - invalid sequences are replaced if encoding is not detected)
+    #[serde(rename = "utf-8")]
+    UTF_8,
+    /// UTF-16 little endian (detected by byte order mark)
+    #[serde(rename = "utf-16le")]
+    UTF_16LE,
+    /// UTF-16 big endian (detected by byte order mark)
+    #[serde(rename = "utf-16be")]
+    UTF_16BE,
+    /// ISO-8859-1 (detected if content is not a valid UTF-8 text)
+    #[serde(rename = "iso-8859-1")]
+    ISO_8859_1,
+}
@@ -3,4 +3,6 @@ pub mod constant;
 pub mod controller;
 pub mod dto;
 pub mod enums;
+pub mod middleware;
 pub mod service;
+pub mod utils;
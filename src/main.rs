@@ -1,45 +1,140 @@
-use log::{debug, info};
-use tower_http::catch_panic::CatchPanicLayer;
+use std::sync::LazyLock;
+
+use axum::serve::ListenerExt;
+use log::{error, info, warn};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use rust_aws_s3_downloader::config::api_listeners::{get_api_server_addresses, get_api_server_unix_socket_path};
+use rust_aws_s3_downloader::config::api_router::create_api_router;
+use rust_aws_s3_downloader::config::app_state::AppState;
+use rust_aws_s3_downloader::config::config_dump::{get_config_errors, log_config_dump};
+use rust_aws_s3_downloader::config::credentials_cache::start_credentials_cache_refresh_task;
+use rust_aws_s3_downloader::config::error_reporter::ERROR_REPORTER;
+use rust_aws_s3_downloader::config::external_secrets::{start_external_secrets_refresh_task, EXTERNAL_SECRETS};
 use rust_aws_s3_downloader::config::graceful_shutdown::graceful_shutdown_handler;
-use rust_aws_s3_downloader::constant::constants::{API_SERVER_HOST_DEFAULT, API_SERVER_HOST_ENV_VAR, API_SERVER_PORT_DEFAULT, API_SERVER_PORT_ENV_VAR, LOGGING_CONFIG_FILE_DEFAULT, LOGGING_CONFIG_FILE_ENV_VAR};
-use rust_aws_s3_downloader::controller::download_controller::*;
-use rust_aws_s3_downloader::controller::health_controller::*;
+use rust_aws_s3_downloader::config::http_server::{HttpServerOptions, serve_api};
+use rust_aws_s3_downloader::config::job_takeover::start_job_takeover_task;
+use rust_aws_s3_downloader::config::retention_cleanup::start_retention_cleanup_task;
+use rust_aws_s3_downloader::config::self_check::run_self_check;
+use rust_aws_s3_downloader::config::startup_warmup::run_startup_warmup;
+use rust_aws_s3_downloader::config::watch_task::start_watch_task;
+use rust_aws_s3_downloader::constant::constants::{IGNORE_CONFIG_ERRORS_ARG, LOGGING_CONFIG_FILE_DEFAULT, LOGGING_CONFIG_FILE_ENV_VAR, SELF_CHECK_ARG};
 
 /// App main function (multi-thread implemented by tokio dependency)
 ///
 /// **important:** if LOG4RS_CONFIG_FILE environment variable is not defined,
 /// logger configuration will be read from logging_config.yaml file from project´s root
+///
+/// **important:** secret configuration values can be defined as AWS Secrets Manager or SSM Parameter Store references
+/// (`aws-secrets` feature), resolved before anything else
+///
+/// **important:** if `--check` argument is defined, startup self-checks are executed instead of the API server
+/// (exit code is non-zero if any check fails)
+///
+/// **important:** API server refuses to start if any configuration value is not valid,
+/// unless `--ignore-config-errors` argument is defined
 #[tokio::main]
 async fn main() {
     let log_config_file = std::env::var(LOGGING_CONFIG_FILE_ENV_VAR).unwrap_or(String::from(LOGGING_CONFIG_FILE_DEFAULT));
     log4rs::init_file(log_config_file, Default::default()).unwrap();
+    EXTERNAL_SECRETS.resolve().await;
+    LazyLock::force(&ERROR_REPORTER);
+
+    if std::env::args().any(|arg| arg == SELF_CHECK_ARG) {
+        start_self_check().await;
+    }
 
     start_api_server().await;
 }
 
+/// Starts self-check mode (report is printed and process exits with non-zero code if any check fails)
+async fn start_self_check() {
+    info!("Self-check - starting...");
+    let self_check_results = run_self_check().await;
+    self_check_results.iter().for_each(|self_check_result| println!("{self_check_result}"));
+
+    if self_check_results.iter().all(|self_check_result| self_check_result.passed) {
+        info!("Self-check - all checks passed");
+        std::process::exit(0);
+    }
+    error!("Self-check - some checks failed");
+    std::process::exit(1);
+}
+
+/// Checks configuration (process exits with non-zero code if any value is not valid, unless configuration errors are ignored)
+fn check_configuration() {
+    let config_errors = get_config_errors();
+    if config_errors.is_empty() {
+        return;
+    }
+
+    config_errors.iter().for_each(|config_error| error!("Config - {config_error}"));
+    if std::env::args().any(|arg| arg == IGNORE_CONFIG_ERRORS_ARG) {
+        warn!("Config - configuration errors ignored ({IGNORE_CONFIG_ERRORS_ARG}) - zero or false is used for not valid values");
+        return;
+    }
+    error!("Axum server - not valid configuration - please, fix it or start with {IGNORE_CONFIG_ERRORS_ARG} argument");
+    std::process::exit(1);
+}
+
 /// Starts API server
 async fn start_api_server() {
     info!("Axum server - starting...");
+    log_config_dump();
+    check_configuration();
+
+    let app_state = AppState::default();
+    start_background_tasks(&app_state);
+    run_startup_warmup().await;
+    let api_router = create_api_router(&app_state);
+    let http_server_options = HttpServerOptions::default();
+    info!("Axum server - http server options: {http_server_options:?}");
+    let shutdown_token = CancellationToken::new();
+    let mut api_servers = JoinSet::new();
+
+    for api_server_address in get_api_server_addresses() {
+        info!("Axum server - listening on: {api_server_address}");
+        let tcp_nodelay = http_server_options.tcp_nodelay;
+        let api_tcp_listener = tokio::net::TcpListener::bind(api_server_address)
+            .await
+            .unwrap()
+            .tap_io(move |tcp_stream| {
+                if let Err(tcp_nodelay_error) = tcp_stream.set_nodelay(tcp_nodelay) {
+                    warn!("Axum server - can't set TCP_NODELAY flag - error: {tcp_nodelay_error}");
+                }
+            });
+        api_servers.spawn(serve_api(api_tcp_listener, api_router.clone(), http_server_options.clone(), shutdown_token.clone()));
+    }
 
-    let api_server_host = std::env::var(API_SERVER_HOST_ENV_VAR).unwrap_or(String::from(API_SERVER_HOST_DEFAULT));
-    debug!("start_api_server - api server host retrieved OK");
-
-    let api_server_port = std::env::var(API_SERVER_PORT_ENV_VAR).unwrap_or(String::from(API_SERVER_PORT_DEFAULT));
-    debug!("start_api_port - api server host retrieved OK");
-
-    let api_server_address = format!("{api_server_host}:{api_server_port}");
-    info!("Axum server - listening on: {api_server_address}");
-
-    let api_tcp_listener = tokio::net::TcpListener::bind(api_server_address)
-        .await
-        .unwrap();
-    let _ = axum::serve(
-        api_tcp_listener,
-        HealthController::config_endpoints()
-            .merge(DownloadController::config_endpoints())
-            .layer(CatchPanicLayer::new())
-            .into_make_service(),
-    )
-    .with_graceful_shutdown(graceful_shutdown_handler())
-    .await;
+    #[cfg(unix)]
+    if let Some(api_server_unix_socket_path) = get_api_server_unix_socket_path() {
+        info!("Axum server - listening on unix socket: {api_server_unix_socket_path}");
+        let _ = std::fs::remove_file(&api_server_unix_socket_path); // stale socket file from a previous run
+        let api_unix_listener = tokio::net::UnixListener::bind(&api_server_unix_socket_path).unwrap();
+        api_servers.spawn(serve_api(api_unix_listener, api_router.clone(), http_server_options.clone(), shutdown_token.clone()));
+    }
+
+    tokio::select! {
+        _ = graceful_shutdown_handler() => shutdown_token.cancel(),
+        Some(api_server_result) = api_servers.join_next() => {
+            error!("Axum server - listener stopped unexpectedly - result: {api_server_result:?}");
+            shutdown_token.cancel();
+        }
+    }
+    api_servers.join_all().await;
+}
+
+/// Starts background tasks (job results retention cleanup, orphaned jobs takeover, watch mode and external secrets refresh)
+/// by [&AppState] application state
+fn start_background_tasks(app_state: &AppState) {
+    start_external_secrets_refresh_task();
+    start_credentials_cache_refresh_task();
+    start_retention_cleanup_task(
+        app_state.download_job_service.clone(),
+        app_state.aws_s3_service.clone(),
+        app_state.s3_event_service.clone(),
+    );
+    start_job_takeover_task(app_state.download_job_service.clone());
+    start_watch_task(app_state.watch_service.clone());
 }
+
@@ -0,0 +1,121 @@
+use std::time::Instant;
+
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::CONTENT_TYPE;
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::constant::constants::{ACCESS_LOG_SAMPLE_PERCENT_DEFAULT, ACCESS_LOG_SAMPLE_PERCENT_ENV_VAR, ACCESS_LOG_TARGET, DOWNLOAD_QUOTA_CALLER_HEADER};
+use crate::dto::access_log_entry::AccessLogEntry;
+use crate::middleware::request_body::read_request_body;
+use futures_util::StreamExt;
+use log::info;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Bucket scoped request (S3 bucket from any JSON request body)
+#[derive(Deserialize)]
+struct BucketScopedRequest {
+    bucket_name: String,
+}
+
+/// Access log guard (access log line is written once the response body is completely sent or dropped)
+struct AccessLogGuard {
+    started_at: Instant,
+    access_log_entry: AccessLogEntry,
+}
+
+impl AccessLogGuard {
+    /// Adds [u64] sent bytes to the access log entry
+    fn add_bytes(&mut self, bytes: u64) {
+        self.access_log_entry.bytes += bytes;
+    }
+}
+
+/// [Drop] trait implementation (writes the access log line)
+impl Drop for AccessLogGuard {
+    fn drop(&mut self) {
+        self.access_log_entry.latency_millis = self.started_at.elapsed().as_millis() as u64;
+        if let Ok(access_log_line) = serde_json::to_string(&self.access_log_entry) {
+            info!(target: ACCESS_LOG_TARGET, "{access_log_line}");
+        }
+    }
+}
+
+/// Access log middleware (one structured JSON line per request is logged by [ACCESS_LOG_TARGET] log target)
+/// Important: requests are sampled by configured percentage (please, check constants.rs) - server errors are always logged
+pub async fn access_log_middleware(request: Request, next: Next) -> Response {
+    let started_at = Instant::now();
+    let mut access_log_entry = AccessLogEntry {
+        method: request.method().to_string(),
+        path: request.uri().path().to_string(),
+        caller: request
+            .headers()
+            .get(DOWNLOAD_QUOTA_CALLER_HEADER)
+            .and_then(|caller| caller.to_str().ok())
+            .map(String::from),
+        ..Default::default()
+    };
+
+    let is_json_request = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"));
+    let request = if is_json_request {
+        let (request_parts, request_bytes) = match read_request_body(request).await {
+            Ok(request_body) => request_body,
+            Err(error_response) => return error_response,
+        };
+        access_log_entry.bucket = serde_json::from_slice::<BucketScopedRequest>(&request_bytes)
+            .ok()
+            .map(|scoped_request| scoped_request.bucket_name);
+        Request::from_parts(request_parts, Body::from(request_bytes))
+    } else {
+        request
+    };
+
+    let response = next.run(request).await;
+    access_log_entry.status = response.status().as_u16();
+    let sample_percent = get_env_var_as_u64(ACCESS_LOG_SAMPLE_PERCENT_ENV_VAR, ACCESS_LOG_SAMPLE_PERCENT_DEFAULT);
+    if !response.status().is_server_error() && !is_sampled(sample_percent, Uuid::new_v4().as_u128()) {
+        return response;
+    }
+
+    let (response_parts, response_body) = response.into_parts();
+    let mut access_log_guard = AccessLogGuard {
+        started_at,
+        access_log_entry,
+    };
+    let response_stream = response_body.into_data_stream().map(move |response_chunk| {
+        if let Ok(response_bytes) = &response_chunk {
+            access_log_guard.add_bytes(response_bytes.len() as u64);
+        }
+        response_chunk
+    });
+    Response::from_parts(response_parts, Body::from_stream(response_stream))
+}
+
+/// Gets [bool] true if a request is sampled by [u64] sample percentage (from 0 to 100) and [u128] random value
+fn is_sampled(sample_percent: u64, random_value: u128) -> bool {
+    (random_value % 100) < sample_percent as u128
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::middleware::access_log_middleware::is_sampled;
+
+    /// Scenario:
+    /// Checks sampling for disabled, partial and full sample percentages
+    /// Expectation:
+    /// Requests should be sampled just if the random value falls under the sample percentage
+    #[test]
+    fn when_is_sampled_should_apply_sample_percent() {
+        assert!(!is_sampled(0, 0));
+        assert!(is_sampled(10, 109));
+        assert!(!is_sampled(10, 110));
+        assert!(is_sampled(100, 99));
+    }
+}
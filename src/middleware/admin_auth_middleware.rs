@@ -0,0 +1,62 @@
+use axum::extract::Request;
+use axum::http::header::AUTHORIZATION;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::external_secrets::get_secret_env_var;
+use crate::constant::constants::ADMIN_API_TOKEN_ENV_VAR;
+use log::warn;
+use sha2::{Digest, Sha256};
+
+/// Admin authentication middleware (admin token is read from `Authorization: Bearer {token}` request header)
+/// Returns an unauthorized status response if the admin token is not defined or it is not valid and a forbidden status
+/// response if no admin token is configured (admin end-points are disabled - please, check constants.rs)
+/// Important: it is applied to admin end-points only (the role should also be allowed to execute the admin operation,
+/// if access control is enabled)
+pub async fn admin_auth_middleware(request: Request, next: Next) -> Response {
+    let admin_token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| authorization.strip_prefix("Bearer "));
+    if let Err(status_code) = check_admin_token(get_secret_env_var(ADMIN_API_TOKEN_ENV_VAR).ok().as_deref(), admin_token) {
+        warn!("admin_auth_middleware - admin request rejected - status: {status_code} - path: {}", request.uri().path());
+        return status_code.into_response();
+    }
+    next.run(request).await
+}
+
+/// Checks [Option<&str>] requested admin token against [Option<&str>] configured admin token (tokens are compared by
+/// their SHA-256 digests, so comparison time doesn't depend on the matching prefix)
+/// Returns a [StatusCode::FORBIDDEN] status if no admin token is configured (or it is empty) and a
+/// [StatusCode::UNAUTHORIZED] status if requested admin token is not defined or it is not valid
+fn check_admin_token(configured_token: Option<&str>, admin_token: Option<&str>) -> Result<(), StatusCode> {
+    let configured_token = configured_token.filter(|configured_token| !configured_token.is_empty()).ok_or(StatusCode::FORBIDDEN)?;
+    match admin_token.is_some_and(|admin_token| Sha256::digest(admin_token) == Sha256::digest(configured_token)) {
+        true => Ok(()),
+        false => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use crate::middleware::admin_auth_middleware::check_admin_token;
+
+    /// Scenario:
+    /// Checks admin requests (feature flags, maintenance mode, cache invalidation and configuration end-points) without
+    /// admin token, with a not valid admin token and without configured admin token
+    /// Expectation:
+    /// Requests should be rejected as unauthorized and, if no admin token is configured, as forbidden (admin end-points
+    /// are disabled) - just requests with the configured admin token should be allowed
+    #[test]
+    fn when_check_admin_token_should_reject_not_valid_tokens() {
+        assert_eq!(Err(StatusCode::UNAUTHORIZED), check_admin_token(Some("some-admin-token"), None));
+        assert_eq!(Err(StatusCode::UNAUTHORIZED), check_admin_token(Some("some-admin-token"), Some("some-admin")));
+        assert_eq!(Err(StatusCode::UNAUTHORIZED), check_admin_token(Some("some-admin-token"), Some("")));
+        assert_eq!(Err(StatusCode::FORBIDDEN), check_admin_token(None, Some("some-admin-token")));
+        assert_eq!(Err(StatusCode::FORBIDDEN), check_admin_token(Some(""), Some("")));
+        assert_eq!(Ok(()), check_admin_token(Some("some-admin-token"), Some("some-admin-token")));
+    }
+}
@@ -0,0 +1,56 @@
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::config::base_path::get_api_base_path;
+use crate::constant::constants::{API_MAIN_PATH, API_V1_SUNSET_DATE_ENV_VAR, API_V2_MAIN_PATH};
+
+/// API deprecation middleware
+/// Adds `Deprecation`, `Sunset` (if API v1 sunset date is configured) and successor version `Link` response headers
+/// to API v1 end-points responses
+pub async fn api_deprecation_middleware(request: Request, next: Next) -> Response {
+    if !request.uri().path().starts_with(&format!("{API_MAIN_PATH}/")) {
+        return next.run(request).await;
+    }
+
+    let mut response = next.run(request).await;
+    let sunset_date = std::env::var(API_V1_SUNSET_DATE_ENV_VAR).ok();
+    response
+        .headers_mut()
+        .extend(create_deprecation_headers(sunset_date.as_deref(), &get_api_base_path()));
+    response
+}
+
+/// Creates a new [HeaderMap] with deprecation headers by [Option<&str>] sunset date (HTTP-date)
+/// and [&str] API base path (successor version link)
+fn create_deprecation_headers(sunset_date: Option<&str>, api_base_path: &str) -> HeaderMap {
+    let mut header_map = HeaderMap::new();
+    header_map.insert(HeaderName::from_static("deprecation"), HeaderValue::from_static("true"));
+    if let Some(sunset_date) = sunset_date.and_then(|sunset_date| HeaderValue::from_str(sunset_date.trim()).ok()) {
+        header_map.insert(HeaderName::from_static("sunset"), sunset_date);
+    }
+    if let Ok(successor_link) = HeaderValue::from_str(&format!("<{api_base_path}{API_V2_MAIN_PATH}>; rel=\"successor-version\"")) {
+        header_map.insert(HeaderName::from_static("link"), successor_link);
+    }
+    header_map
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::middleware::api_deprecation_middleware::create_deprecation_headers;
+
+    /// Scenario:
+    /// Creates deprecation headers with a configured sunset date and API base path
+    /// Expectation:
+    /// Deprecation, sunset and successor version link headers should be created
+    #[test]
+    fn when_create_deprecation_headers_should_include_sunset_and_successor_link() {
+        let header_map = create_deprecation_headers(Some("Thu, 31 Dec 2026 23:59:59 GMT"), "/s3-exporter");
+
+        assert_eq!("true", header_map.get("deprecation").unwrap());
+        assert_eq!("Thu, 31 Dec 2026 23:59:59 GMT", header_map.get("sunset").unwrap());
+        assert_eq!("</s3-exporter/api/v2>; rel=\"successor-version\"", header_map.get("link").unwrap());
+        assert!(create_deprecation_headers(None, "").get("sunset").is_none());
+    }
+}
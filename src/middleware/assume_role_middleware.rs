@@ -0,0 +1,21 @@
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::config::assume_role::{get_assume_role_arn, parse_caller_session_tags, with_caller_session_tags};
+use log::debug;
+
+/// Assume role middleware (caller identity session tags are read from request headers - please, check constants.rs)
+/// Requests with session tags are served by S3 clients under the assumed role tagged with them, so S3 access is
+/// attributable (i.e.: CloudTrail) and restrictable by ABAC policies (i.e.: `aws:PrincipalTag/tenant`)
+/// Important: session tags are ignored if assume role is not configured
+pub async fn assume_role_middleware(request: Request, next: Next) -> Response {
+    if get_assume_role_arn().is_none() {
+        return next.run(request).await;
+    }
+
+    let caller_session_tags = parse_caller_session_tags(request.headers());
+    if !caller_session_tags.is_empty() {
+        debug!("assume_role_middleware - caller session tags: {} - path: {}", caller_session_tags.len(), request.uri().path());
+    }
+    with_caller_session_tags(caller_session_tags, next.run(request)).await
+}
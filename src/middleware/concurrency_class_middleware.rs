@@ -0,0 +1,111 @@
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::concurrency_classes::CONCURRENCY_CLASSES;
+use crate::constant::constants::{API_ADMIN_PATH_PREFIX, API_CONCURRENCY_RETRY_AFTER_SECONDS, API_DOWNLOAD_ALL_AS_ZIP_PATH, API_DOWNLOAD_ARCHIVE_EXTRACT_PATH, API_DOWNLOAD_ARTIFACT_PATH, API_DOWNLOAD_BATCH_PATH, API_DOWNLOAD_JOB_RESULT_PATH, API_DOWNLOAD_KEYS_AS_ZIP_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_OBJECT_QUERY_PATHS, API_DOWNLOAD_SIGNED_PATH, API_HEALTH_CHECK_PATH, API_READINESS_CHECK_PATH, API_V2_EXPORT_RESULT_PATH, API_V2_MAIN_PATH, STATIC_WEBSITE_FILE_PATH};
+use crate::enums::concurrency_class::ConcurrencyClass;
+use futures_util::StreamExt;
+use log::warn;
+
+/// Download end-points streaming archives or raw S3 objects (relative to the download main path)
+const ARCHIVE_DOWNLOAD_PATHS: [&str; 6] = [
+    API_DOWNLOAD_ALL_AS_ZIP_PATH,
+    API_DOWNLOAD_KEYS_AS_ZIP_PATH,
+    API_DOWNLOAD_BATCH_PATH,
+    API_DOWNLOAD_ARCHIVE_EXTRACT_PATH,
+    API_DOWNLOAD_JOB_RESULT_PATH,
+    API_DOWNLOAD_ARTIFACT_PATH,
+];
+
+/// Concurrency class middleware (listing, archive streaming and admin requests have separate in-flight budgets)
+/// Returns a service unavailable status response (with [RETRY_AFTER] header) if no slot of the request class is released
+/// within the configured queue timeout
+/// Important: archive streaming requests hold their slot until the response body is completely sent or dropped and
+/// health requests are never limited
+pub async fn concurrency_class_middleware(request: Request, next: Next) -> Response {
+    let Some(concurrency_class) = get_concurrency_class(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let request_permit = match CONCURRENCY_CLASSES.acquire(concurrency_class).await {
+        Ok(request_permit) => request_permit,
+        Err(_) => {
+            warn!(
+                "concurrency_class_middleware - request rejected - class: {concurrency_class:?} - path: {}",
+                request.uri().path()
+            );
+            return (StatusCode::SERVICE_UNAVAILABLE, [(RETRY_AFTER, API_CONCURRENCY_RETRY_AFTER_SECONDS)]).into_response();
+        }
+    };
+
+    let response = next.run(request).await;
+    if concurrency_class != ConcurrencyClass::ARCHIVE || request_permit.is_none() {
+        return response;
+    }
+    let (response_parts, response_body) = response.into_parts();
+    let response_stream = response_body.into_data_stream().map(move |response_chunk| {
+        let _ = &request_permit;
+        response_chunk
+    });
+    Response::from_parts(response_parts, Body::from_stream(response_stream))
+}
+
+/// Gets [Option<ConcurrencyClass>] concurrency class by [&Method] request method and [&str] request path
+/// ([None] for health and CORS preflight requests - they are not limited)
+fn get_concurrency_class(method: &Method, path: &str) -> Option<ConcurrencyClass> {
+    if method == Method::OPTIONS || path == API_HEALTH_CHECK_PATH || path == API_READINESS_CHECK_PATH {
+        return None;
+    }
+    if path.starts_with(API_ADMIN_PATH_PREFIX) {
+        return Some(ConcurrencyClass::ADMIN);
+    }
+
+    let is_archive_request = path.strip_prefix(API_DOWNLOAD_MAIN_PATH).is_some_and(|download_path| {
+        ARCHIVE_DOWNLOAD_PATHS.iter().any(|archive_path| is_route_match(archive_path, download_path))
+            || API_DOWNLOAD_OBJECT_QUERY_PATHS.contains(&download_path)
+            || (method == Method::GET && download_path == API_DOWNLOAD_SIGNED_PATH)
+    }) || is_route_match(&format!("{API_V2_MAIN_PATH}{API_V2_EXPORT_RESULT_PATH}"), path)
+        || is_route_match(STATIC_WEBSITE_FILE_PATH, path);
+    match is_archive_request {
+        true => Some(ConcurrencyClass::ARCHIVE),
+        false => Some(ConcurrencyClass::LISTING),
+    }
+}
+
+/// Gets [bool] true if [&str] request path matches [&str] route path (a trailing path parameter, i.e.: `/jobs/results/{resume_token}`,
+/// matches any not empty value)
+fn is_route_match(route_path: &str, path: &str) -> bool {
+    match route_path.split_once('{') {
+        Some((route_prefix, _)) => path.len() > route_prefix.len() && path.starts_with(route_prefix),
+        None => path == route_path,
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::Method;
+
+    use crate::enums::concurrency_class::ConcurrencyClass;
+    use crate::middleware::concurrency_class_middleware::get_concurrency_class;
+
+    /// Scenario:
+    /// Gets the concurrency class of health, admin, listing, job status and archive streaming requests
+    /// Expectation:
+    /// Health requests should not be limited and each request should be classified by its end-point
+    #[test]
+    fn when_get_concurrency_class_should_classify_by_end_point() {
+        assert_eq!(None, get_concurrency_class(&Method::GET, "/health/ready"));
+        assert_eq!(Some(ConcurrencyClass::ADMIN), get_concurrency_class(&Method::POST, "/admin/maintenance"));
+        assert_eq!(Some(ConcurrencyClass::LISTING), get_concurrency_class(&Method::POST, "/api/v1/list"));
+        assert_eq!(Some(ConcurrencyClass::LISTING), get_concurrency_class(&Method::GET, "/api/v1/download/jobs/some-job"));
+        assert_eq!(Some(ConcurrencyClass::LISTING), get_concurrency_class(&Method::POST, "/api/v1/download/signed"));
+        assert_eq!(Some(ConcurrencyClass::ARCHIVE), get_concurrency_class(&Method::POST, "/api/v1/download/zip"));
+        assert_eq!(Some(ConcurrencyClass::ARCHIVE), get_concurrency_class(&Method::GET, "/api/v1/download/signed"));
+        assert_eq!(Some(ConcurrencyClass::ARCHIVE), get_concurrency_class(&Method::GET, "/api/v1/download/jobs/results/token"));
+        assert_eq!(Some(ConcurrencyClass::ARCHIVE), get_concurrency_class(&Method::GET, "/api/v2/exports/results/token"));
+        assert_eq!(Some(ConcurrencyClass::ARCHIVE), get_concurrency_class(&Method::GET, "/files/index.html"));
+    }
+}
@@ -0,0 +1,32 @@
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::caller_credentials::{parse_caller_credentials, with_caller_credentials};
+use crate::config::env_vars::get_env_var_as_bool;
+use crate::constant::constants::{CALLER_CREDENTIALS_ENABLED_DEFAULT, CALLER_CREDENTIALS_ENABLED_ENV_VAR};
+use log::{debug, warn};
+
+/// Credentials passthrough middleware (caller temporary AWS credentials are read from request headers)
+/// Requests with caller credentials are served by S3 clients built from them (downloads run under the caller's own
+/// permissions), otherwise service credentials are used
+/// Returns a bad request status response if just some credentials headers are defined
+/// Important: credentials headers are ignored if credentials passthrough is not enabled
+pub async fn credentials_passthrough_middleware(request: Request, next: Next) -> Response {
+    if !get_env_var_as_bool(CALLER_CREDENTIALS_ENABLED_ENV_VAR, CALLER_CREDENTIALS_ENABLED_DEFAULT) {
+        return next.run(request).await;
+    }
+
+    match parse_caller_credentials(request.headers()) {
+        Ok(caller_credentials) => {
+            if caller_credentials.is_some() {
+                debug!("credentials_passthrough_middleware - caller credentials - path: {}", request.uri().path());
+            }
+            with_caller_credentials(caller_credentials, next.run(request)).await
+        }
+        Err(_) => {
+            warn!("credentials_passthrough_middleware - incomplete caller credentials - path: {}", request.uri().path());
+            StatusCode::BAD_REQUEST.into_response()
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::download_quota::DOWNLOAD_QUOTA_REGISTRY;
+use crate::constant::constants::{API_DOWNLOAD_MAIN_PATH, API_V2_MAIN_PATH};
+use crate::middleware::request_body::read_request_body;
+use crate::middleware::tenant_middleware::get_authenticated_tenant_id;
+use log::warn;
+use serde_json::Value;
+
+/// Download quota middleware (caller is the authenticated tenant - tenant token from `Authorization: Bearer {token}`
+/// request header - so it can't be spoofed by any client supplied request header)
+/// Sets the caller (`caller_id`) into any JSON download request body, so downloaded bytes are accounted per caller
+/// (any caller sent by the request body is overwritten)
+/// Returns an unauthorized status response if caller daily cap is configured and the caller is not authenticated
+/// (unauthenticated requests can't share and exhaust a single anonymous quota)
+/// Important: just download requests (POST) are scoped and only if any daily cap is configured
+pub async fn download_quota_middleware(request: Request, next: Next) -> Response {
+    if request.method() != Method::POST
+        || !(request.uri().path().starts_with(API_DOWNLOAD_MAIN_PATH) || request.uri().path().starts_with(API_V2_MAIN_PATH))
+        || !DOWNLOAD_QUOTA_REGISTRY.is_enabled()
+    {
+        return next.run(request).await;
+    }
+
+    let caller_id = get_request_caller_id(request.headers());
+    if caller_id.is_none() && DOWNLOAD_QUOTA_REGISTRY.is_caller_cap_enabled() {
+        warn!("download_quota_middleware - caller not authenticated - path: {}", request.uri().path());
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    let (mut request_parts, request_bytes) = match read_request_body(request).await {
+        Ok(request_body) => request_body,
+        Err(error_response) => return error_response,
+    };
+    let mut request_json = match serde_json::from_slice::<Value>(&request_bytes) {
+        Ok(request_json) => request_json,
+        Err(_) => return next.run(Request::from_parts(request_parts, Body::from(request_bytes))).await,
+    };
+
+    if let Some(request_fields) = request_json.as_object_mut() {
+        request_fields.insert(String::from("caller_id"), caller_id.map(Value::String).unwrap_or(Value::Null));
+    }
+
+    request_parts.headers.remove(CONTENT_LENGTH);
+    next.run(Request::from_parts(request_parts, Body::from(request_json.to_string()))).await
+}
+
+/// Gets [Option<String>] authenticated caller by [&HeaderMap] request headers (i.e.: stored download requests without
+/// body to be scoped by [download_quota_middleware], like export templates or previous jobs)
+/// Important: just if any daily cap is configured
+pub fn get_request_caller_id(request_headers: &HeaderMap) -> Option<String> {
+    if !DOWNLOAD_QUOTA_REGISTRY.is_enabled() {
+        return None;
+    }
+    get_authenticated_tenant_id(request_headers)
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::header::AUTHORIZATION;
+    use axum::http::HeaderMap;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use crate::config::download_quota::DownloadQuotaRegistry;
+    use crate::constant::constants::DOWNLOAD_QUOTA_CALLER_HEADER;
+    use crate::middleware::tenant_middleware::get_token_tenant_id;
+    use hmac::{Hmac, Mac};
+    use serde_json::json;
+    use sha2::Sha256;
+
+    /// Scenario:
+    /// Records downloaded bytes up to the caller cap for an authenticated caller and changes the caller request header
+    /// (with and without the tenant token)
+    /// Expectation:
+    /// Spoofed caller header should not reset the authenticated caller consumption and requests without tenant token
+    /// should not have a caller
+    #[test]
+    fn when_caller_header_is_spoofed_should_keep_authenticated_caller_consumption() {
+        let download_quota_registry = DownloadQuotaRegistry::new(0, 100);
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(AUTHORIZATION, format!("Bearer {}", create_tenant_token("acme")).parse().unwrap());
+        request_headers.insert(DOWNLOAD_QUOTA_CALLER_HEADER, "reporting-app".parse().unwrap());
+        let caller_id = get_token_tenant_id(&request_headers, "some-secret", 1000);
+        download_quota_registry.record_bytes("some-bucket", caller_id.as_deref(), 100);
+
+        request_headers.insert(DOWNLOAD_QUOTA_CALLER_HEADER, "other-app".parse().unwrap());
+        let spoofed_caller_id = get_token_tenant_id(&request_headers, "some-secret", 1000);
+        assert_eq!(Some(String::from("acme")), spoofed_caller_id);
+        assert!(download_quota_registry.is_exceeded("some-bucket", spoofed_caller_id.as_deref()));
+
+        request_headers.remove(AUTHORIZATION);
+        assert_eq!(None, get_token_tenant_id(&request_headers, "some-secret", 1000));
+    }
+
+    /// Creates a new [String] tenant token (signed by `some-secret` and expiring at 2000) by [&str] tenant identifier
+    fn create_tenant_token(tenant_id: &str) -> String {
+        let signed_content = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(json!({"alg": "HS256", "typ": "JWT"}).to_string()),
+            URL_SAFE_NO_PAD.encode(json!({"tenant_id": tenant_id, "exp": 2000}).to_string())
+        );
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"some-secret").unwrap();
+        mac.update(signed_content.as_bytes());
+        format!("{signed_content}.{}", URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+}
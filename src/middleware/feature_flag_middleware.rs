@@ -0,0 +1,37 @@
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::feature_flags::FEATURE_FLAGS;
+use crate::constant::constants::{API_DOWNLOAD_BATCH_PATH, API_DOWNLOAD_JOBS_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_TEMPLATES_PATH, API_LIST_STREAM_PATH, API_MAIN_PATH, API_V2_MAIN_PATH};
+use crate::enums::feature_flag::FeatureFlag;
+use log::debug;
+
+/// Feature flag middleware
+/// Returns a not found status response if the requested end-point is gated by a disabled feature flag
+pub async fn feature_flag_middleware(request: Request, next: Next) -> Response {
+    match get_feature_flag(request.uri().path()) {
+        Some(feature) if !FEATURE_FLAGS.is_enabled(feature) => {
+            debug!("feature_flag_middleware - feature disabled - feature: {feature:?} - path: {}", request.uri().path());
+            StatusCode::NOT_FOUND.into_response()
+        }
+        _ => next.run(request).await,
+    }
+}
+
+/// Gets [Option<FeatureFlag>] feature gating [&str] request path (end-points without feature are always served)
+fn get_feature_flag(path: &str) -> Option<FeatureFlag> {
+    if path.starts_with(&format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_JOBS_PATH}"))
+        || path.starts_with(&format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_TEMPLATES_PATH}"))
+    {
+        Some(FeatureFlag::JOB_API)
+    } else if path.starts_with(&format!("{API_V2_MAIN_PATH}/")) {
+        Some(FeatureFlag::API_V2)
+    } else if path == format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_BATCH_PATH}") {
+        Some(FeatureFlag::BATCH_DOWNLOAD)
+    } else if path == format!("{API_MAIN_PATH}{API_LIST_STREAM_PATH}") {
+        Some(FeatureFlag::LIST_STREAM)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,84 @@
+use axum::extract::Request;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::maintenance_mode::MAINTENANCE_MODE;
+use crate::constant::constants::{API_ADMIN_PATH_PREFIX, API_DOWNLOAD_ARCHIVE_ENTRIES_PATH, API_DOWNLOAD_ESTIMATE_PATH, API_DOWNLOAD_JOBS_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_PREVIEW_PATH, API_DOWNLOAD_SEARCH_PATH, API_DOWNLOAD_SIGNED_PATH, API_DOWNLOAD_TEMPLATES_PATH, API_HEALTH_CHECK_PATH, API_READINESS_CHECK_PATH, API_S3_EVENTS_PATH, API_V2_EXPORTS_PATH, API_V2_MAIN_PATH, MAINTENANCE_RETRY_AFTER_SECONDS};
+use crate::enums::maintenance_mode::MaintenanceMode;
+use log::warn;
+
+/// Maintenance middleware (current mode is changed by the admin maintenance end-point)
+/// Returns a service unavailable status response (with [RETRY_AFTER] header) if the request is not allowed by the current mode
+/// Important: health, job status and admin requests are always served
+pub async fn maintenance_middleware(request: Request, next: Next) -> Response {
+    let maintenance_mode = MAINTENANCE_MODE.get_mode();
+    if is_request_allowed(maintenance_mode, request.method(), request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    warn!(
+        "maintenance_middleware - request rejected - mode: {maintenance_mode:?} - path: {}",
+        request.uri().path()
+    );
+    (StatusCode::SERVICE_UNAVAILABLE, [(RETRY_AFTER, MAINTENANCE_RETRY_AFTER_SECONDS)]).into_response()
+}
+
+/// Gets [bool] true if [MaintenanceMode] maintenance mode allows [Method] request method and [&str] request path
+fn is_request_allowed(maintenance_mode: MaintenanceMode, method: &Method, path: &str) -> bool {
+    let is_always_allowed = path == API_HEALTH_CHECK_PATH
+        || path == API_READINESS_CHECK_PATH
+        || path.starts_with(API_ADMIN_PATH_PREFIX)
+        || (method == Method::GET && path.starts_with(&format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_JOBS_PATH}/")))
+        || (method == Method::GET && path.starts_with(&format!("{API_V2_MAIN_PATH}{API_V2_EXPORTS_PATH}/")));
+    match maintenance_mode {
+        MaintenanceMode::OFF => true,
+        MaintenanceMode::FULL => is_always_allowed,
+        MaintenanceMode::READ_ONLY => is_always_allowed || !is_export_request(method, path),
+    }
+}
+
+/// Gets [bool] true if [Method] request method and [&str] request path start a new export
+/// (download, download job, API v2 export, signed link download or S3 event requests)
+fn is_export_request(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::POST => {
+            path == API_S3_EVENTS_PATH
+                || path == format!("{API_V2_MAIN_PATH}{API_V2_EXPORTS_PATH}")
+                || (path.starts_with(API_DOWNLOAD_MAIN_PATH)
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ESTIMATE_PATH}")
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_PREVIEW_PATH}")
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ARCHIVE_ENTRIES_PATH}")
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SEARCH_PATH}")
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_TEMPLATES_PATH}")
+                    && path != format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SIGNED_PATH}"))
+        }
+        Method::GET => path == format!("{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SIGNED_PATH}"),
+        _ => false,
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::Method;
+
+    use crate::enums::maintenance_mode::MaintenanceMode;
+    use crate::middleware::maintenance_middleware::is_request_allowed;
+
+    /// Scenario:
+    /// Checks export, listing, job status and health requests by read-only and full maintenance modes
+    /// Expectation:
+    /// Exports should be rejected by both modes, listings just by full mode and job status and health should be allowed
+    #[test]
+    fn when_is_request_allowed_should_reject_by_maintenance_mode() {
+        assert!(!is_request_allowed(MaintenanceMode::READ_ONLY, &Method::POST, "/api/v1/download/zip"));
+        assert!(!is_request_allowed(MaintenanceMode::READ_ONLY, &Method::POST, "/api/v1/download/jobs"));
+        assert!(!is_request_allowed(MaintenanceMode::READ_ONLY, &Method::POST, "/api/v2/exports"));
+        assert!(is_request_allowed(MaintenanceMode::READ_ONLY, &Method::POST, "/api/v1/list"));
+        assert!(!is_request_allowed(MaintenanceMode::FULL, &Method::POST, "/api/v1/list"));
+        assert!(is_request_allowed(MaintenanceMode::FULL, &Method::GET, "/api/v1/download/jobs/some-job-id"));
+        assert!(is_request_allowed(MaintenanceMode::FULL, &Method::GET, "/health"));
+        assert!(is_request_allowed(MaintenanceMode::OFF, &Method::POST, "/api/v1/download/zip"));
+    }
+}
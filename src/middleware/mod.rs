@@ -0,0 +1,12 @@
+pub mod access_log_middleware;
+pub mod admin_auth_middleware;
+pub mod api_deprecation_middleware;
+pub mod assume_role_middleware;
+pub mod concurrency_class_middleware;
+pub mod credentials_passthrough_middleware;
+pub mod download_quota_middleware;
+pub mod feature_flag_middleware;
+pub mod maintenance_middleware;
+pub mod rbac_middleware;
+pub mod request_body;
+pub mod tenant_middleware;
@@ -0,0 +1,291 @@
+use axum::body::Body;
+use axum::extract::{Query, Request};
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::config::authorization_hook::{AuthorizationHookTrait, AUTHORIZATION_HOOK};
+use crate::constant::constants::{API_ADMIN_PATH_PREFIX, API_DIFF_PATH, API_DOWNLOAD_ARCHIVE_ENTRIES_PATH, API_DOWNLOAD_ESTIMATE_PATH, API_DOWNLOAD_JOB_RERUN_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_OBJECT_QUERY_PATHS, API_DOWNLOAD_PREVIEW_PATH, API_DOWNLOAD_TEMPLATE_BY_ID_PATH, API_DOWNLOAD_TEMPLATE_JOBS_PATH, API_EXISTS_PATH, API_LIST_PATH, API_LIST_STREAM_PATH, API_MAIN_PATH, API_S3_EVENTS_PATH, API_V2_EXPORTS_PATH, API_V2_MAIN_PATH, RBAC_GLOBAL_BUCKET_NAME, RBAC_ROLE_HEADER};
+use crate::dto::download_request::DownloadRequest;
+use crate::enums::authorization_decision::AuthorizationDecision;
+use crate::enums::rbac_operation::RbacOperation;
+use crate::enums::rbac_scope::RbacScope;
+use crate::middleware::request_body::read_request_body;
+use log::warn;
+use serde::Deserialize;
+
+/// Scoped request (S3 bucket, S3 path, oversize files flag and target S3 bucket and path from any JSON request body
+/// or from query params - i.e.: single S3 object proxy)
+#[derive(Deserialize)]
+struct RbacScopedRequest {
+    bucket_name: String,
+    full_path: String,
+    #[serde(default)]
+    allow_oversize: bool,
+    target_bucket_name: Option<String>,
+    target_full_path: Option<String>,
+}
+
+impl RbacScopedRequest {
+    /// Creates a new [RbacScopedRequest] for requests without S3 bucket and path (any S3 bucket and path - please,
+    /// check [RBAC_GLOBAL_BUCKET_NAME])
+    fn global() -> Self {
+        RbacScopedRequest {
+            bucket_name: String::from(RBAC_GLOBAL_BUCKET_NAME),
+            full_path: String::new(),
+            allow_oversize: false,
+            target_bucket_name: None,
+            target_full_path: None,
+        }
+    }
+}
+
+/// Role-based access control middleware (role is read from [RBAC_ROLE_HEADER] request header)
+/// Returns a forbidden status response if the role is not allowed to execute the operation over requested S3 bucket and path
+/// by the configured authorization hook (oversize files requests also require [RbacOperation::OVERSIZE] operation and
+/// requests with a target S3 path - i.e.: diff - require the same operation over the target S3 bucket and path)
+/// Important: it runs after multi-tenancy middleware, so requested S3 paths are checked once they are tenant-scoped
+/// Important: requests fail closed - mutating requests (not GET) without a known operation are forbidden and scoped
+/// requests whose S3 bucket and path can't be read (JSON request body or query params) are bad requests
+/// Important: read-only requests without operation (i.e.: health-check or job status) are not scoped and requests
+/// by stored S3 bucket and path (i.e.: export templates or previous jobs) are checked by their controllers
+pub async fn rbac_middleware(request: Request, next: Next) -> Response {
+    if !AUTHORIZATION_HOOK.is_enabled() {
+        return next.run(request).await;
+    }
+    let (operation, rbac_scope) = match get_rbac_operation(request.method(), request.uri().path()) {
+        Some(rbac_operation) => rbac_operation,
+        None if is_read_only_method(request.method()) => return next.run(request).await,
+        None => {
+            warn!("rbac_middleware - access denied - unknown operation - method: {} - path: {}", request.method(), request.uri().path());
+            return StatusCode::FORBIDDEN.into_response();
+        }
+    };
+
+    let role_name = request
+        .headers()
+        .get(RBAC_ROLE_HEADER)
+        .and_then(|role_name| role_name.to_str().ok())
+        .map(String::from);
+    let (request, scoped_request) = match rbac_scope {
+        RbacScope::STORED => return next.run(request).await,
+        RbacScope::GLOBAL => (request, Ok(RbacScopedRequest::global())),
+        RbacScope::REQUEST if request.method() == Method::GET => {
+            let scoped_request = Query::<RbacScopedRequest>::try_from_uri(request.uri()).map(|Query(scoped_request)| scoped_request);
+            (request, scoped_request.map_err(|query_error| query_error.to_string()))
+        }
+        RbacScope::REQUEST => {
+            let (request_parts, request_bytes) = match read_request_body(request).await {
+                Ok(request_body) => request_body,
+                Err(error_response) => return error_response,
+            };
+            let scoped_request = serde_json::from_slice::<RbacScopedRequest>(&request_bytes).map_err(|parse_error| parse_error.to_string());
+            (Request::from_parts(request_parts, Body::from(request_bytes)), scoped_request)
+        }
+    };
+    let scoped_request = match scoped_request {
+        Ok(scoped_request) => scoped_request,
+        Err(scope_error) => {
+            warn!("rbac_middleware - access denied - S3 bucket and path not found - path: {} - error: {scope_error}", request.uri().path());
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+    };
+
+    if !is_scoped_request_allowed(AUTHORIZATION_HOOK.as_ref(), role_name.as_deref(), operation, &scoped_request).await {
+        warn!(
+            "rbac_middleware - access denied - role: {} - operation: {operation:?} - bucket name: {} - path: {}",
+            role_name.unwrap_or_default(),
+            scoped_request.bucket_name,
+            scoped_request.full_path
+        );
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Gets [bool] true if the role (read from [RBAC_ROLE_HEADER] header of [&HeaderMap] request headers) is allowed to
+/// export [&DownloadRequest] stored download request S3 bucket and path - i.e.: export templates or previous jobs, requests
+/// without body to be scoped by [rbac_middleware] (oversize files requests also require [RbacOperation::OVERSIZE] operation)
+/// Important: always true if access control is not enabled
+pub async fn is_download_request_allowed(request_headers: &HeaderMap, download_request: &DownloadRequest) -> bool {
+    if !AUTHORIZATION_HOOK.is_enabled() {
+        return true;
+    }
+    let role_name = request_headers.get(RBAC_ROLE_HEADER).and_then(|role_name| role_name.to_str().ok());
+    let mut operations = vec![RbacOperation::DOWNLOAD];
+    if download_request.allow_oversize {
+        operations.push(RbacOperation::OVERSIZE);
+    }
+    for operation in operations {
+        if AUTHORIZATION_HOOK
+            .authorize(role_name, operation, &download_request.bucket_name, &download_request.full_path)
+            .await
+            == AuthorizationDecision::DENY
+        {
+            warn!(
+                "is_download_request_allowed - access denied - role: {} - operation: {operation:?} - bucket name: {} - path: {}",
+                role_name.unwrap_or_default(),
+                download_request.bucket_name,
+                download_request.full_path
+            );
+            return false;
+        }
+    }
+    true
+}
+
+/// Gets [bool] true if [&dyn AuthorizationHookTrait] authorization hook allows [Option<&str>] role name to execute
+/// [RbacOperation] operation over [&RbacScopedRequest] scoped request S3 bucket and path (and oversize files and target
+/// S3 bucket and path operations, if they are requested)
+async fn is_scoped_request_allowed(
+    authorization_hook: &(dyn AuthorizationHookTrait + Send + Sync),
+    role_name: Option<&str>,
+    operation: RbacOperation,
+    scoped_request: &RbacScopedRequest,
+) -> bool {
+    let mut scoped_operations = vec![(operation, scoped_request.bucket_name.as_str(), scoped_request.full_path.as_str())];
+    if scoped_request.allow_oversize {
+        scoped_operations.push((RbacOperation::OVERSIZE, &scoped_request.bucket_name, &scoped_request.full_path));
+    }
+    if let Some(target_full_path) = &scoped_request.target_full_path {
+        let target_bucket_name = scoped_request.target_bucket_name.as_deref().unwrap_or(&scoped_request.bucket_name);
+        scoped_operations.push((operation, target_bucket_name, target_full_path));
+    }
+    for (operation, bucket_name, full_path) in scoped_operations {
+        if authorization_hook.authorize(role_name, operation, bucket_name, full_path).await == AuthorizationDecision::DENY {
+            return false;
+        }
+    }
+    true
+}
+
+/// Gets [bool] true if [&Method] request method is read-only (requests without operation are not scoped)
+fn is_read_only_method(method: &Method) -> bool {
+    method == Method::GET || method == Method::HEAD || method == Method::OPTIONS
+}
+
+/// Gets [Option<(RbacOperation, RbacScope)>] operation and request scope by [&Method] request method and [&str] request path
+/// ([None] if the request has no known operation)
+fn get_rbac_operation(method: &Method, path: &str) -> Option<(RbacOperation, RbacScope)> {
+    if path.starts_with(API_ADMIN_PATH_PREFIX) {
+        return Some((RbacOperation::ADMIN, RbacScope::GLOBAL));
+    }
+    if is_read_only_method(method) {
+        return path
+            .strip_prefix(API_DOWNLOAD_MAIN_PATH)
+            .filter(|api_path| method == Method::GET && API_DOWNLOAD_OBJECT_QUERY_PATHS.contains(api_path))
+            .map(|_| (RbacOperation::DOWNLOAD, RbacScope::REQUEST));
+    }
+    if path == API_S3_EVENTS_PATH {
+        return Some((RbacOperation::EVENTS, RbacScope::GLOBAL));
+    }
+    if let Some(download_path) = path.strip_prefix(API_DOWNLOAD_MAIN_PATH) {
+        if [API_DOWNLOAD_ESTIMATE_PATH, API_DOWNLOAD_PREVIEW_PATH, API_DOWNLOAD_ARCHIVE_ENTRIES_PATH].contains(&download_path) {
+            return Some((RbacOperation::LIST, RbacScope::REQUEST));
+        }
+        if is_route_path_match(API_DOWNLOAD_JOB_RERUN_PATH, download_path)
+            || is_route_path_match(API_DOWNLOAD_TEMPLATE_JOBS_PATH, download_path)
+            || (method == Method::DELETE && is_route_path_match(API_DOWNLOAD_TEMPLATE_BY_ID_PATH, download_path))
+        {
+            return Some((RbacOperation::DOWNLOAD, RbacScope::STORED));
+        }
+        return download_path.starts_with('/').then_some((RbacOperation::DOWNLOAD, RbacScope::REQUEST));
+    }
+    if path == format!("{API_V2_MAIN_PATH}{API_V2_EXPORTS_PATH}") {
+        return Some((RbacOperation::DOWNLOAD, RbacScope::REQUEST));
+    }
+    path.strip_prefix(API_MAIN_PATH)
+        .filter(|api_path| [API_EXISTS_PATH, API_LIST_PATH, API_LIST_STREAM_PATH, API_DIFF_PATH].contains(api_path))
+        .map(|_| (RbacOperation::LIST, RbacScope::REQUEST))
+}
+
+/// Gets [bool] true if [&str] request path matches [&str] route path segment by segment (path parameters, i.e.:
+/// `{job_id}`, match any not empty segment)
+fn is_route_path_match(route_path: &str, path: &str) -> bool {
+    let (route_segments, path_segments): (Vec<&str>, Vec<&str>) = (route_path.split('/').collect(), path.split('/').collect());
+    route_segments.len() == path_segments.len()
+        && route_segments.iter().zip(path_segments).all(|(route_segment, path_segment)| {
+            route_segment == &path_segment || (route_segment.starts_with('{') && !path_segment.is_empty())
+        })
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use axum::http::Method;
+    use crate::config::authorization_hook::AuthorizationHookTrait;
+    use crate::enums::authorization_decision::AuthorizationDecision;
+    use crate::enums::rbac_operation::RbacOperation;
+    use crate::enums::rbac_scope::RbacScope;
+    use crate::middleware::rbac_middleware::{get_rbac_operation, is_scoped_request_allowed, RbacScopedRequest};
+
+    /// Authorization hook allowing just listings and downloads under `tenants/acme/` S3 path of `some-bucket` S3 bucket
+    struct TenantReaderHook {}
+
+    #[async_trait]
+    impl AuthorizationHookTrait for TenantReaderHook {
+        async fn authorize(&self, _: Option<&str>, operation: RbacOperation, bucket_name: &str, prefix: &str) -> AuthorizationDecision {
+            match [RbacOperation::LIST, RbacOperation::DOWNLOAD].contains(&operation) && bucket_name == "some-bucket" && prefix.starts_with("tenants/acme/") {
+                true => AuthorizationDecision::ALLOW,
+                false => AuthorizationDecision::DENY,
+            }
+        }
+    }
+
+    /// Scenario:
+    /// Gets operations of listing, download, stored request, admin, S3 events and unknown mutating requests
+    /// Expectation:
+    /// Every mutating route should have an operation (unknown mutating requests have none, so they are denied)
+    #[test]
+    fn when_get_rbac_operation_should_map_mutating_routes() {
+        let rbac_operation_fn = |method: Method, path: &str| get_rbac_operation(&method, path);
+        assert_eq!(Some((RbacOperation::LIST, RbacScope::REQUEST)), rbac_operation_fn(Method::POST, "/api/v1/list"));
+        assert_eq!(Some((RbacOperation::DOWNLOAD, RbacScope::REQUEST)), rbac_operation_fn(Method::POST, "/api/v1/download/zip"));
+        assert_eq!(Some((RbacOperation::DOWNLOAD, RbacScope::REQUEST)), rbac_operation_fn(Method::PUT, "/api/v1/download/templates/42"));
+        assert_eq!(Some((RbacOperation::DOWNLOAD, RbacScope::STORED)), rbac_operation_fn(Method::DELETE, "/api/v1/download/templates/42"));
+        assert_eq!(Some((RbacOperation::DOWNLOAD, RbacScope::STORED)), rbac_operation_fn(Method::POST, "/api/v1/download/jobs/42/rerun"));
+        assert_eq!(Some((RbacOperation::ADMIN, RbacScope::GLOBAL)), rbac_operation_fn(Method::POST, "/admin/maintenance"));
+        assert_eq!(Some((RbacOperation::ADMIN, RbacScope::GLOBAL)), rbac_operation_fn(Method::GET, "/admin/config"));
+        assert_eq!(Some((RbacOperation::ADMIN, RbacScope::GLOBAL)), rbac_operation_fn(Method::POST, "/admin/feature-flags"));
+        assert_eq!(Some((RbacOperation::ADMIN, RbacScope::GLOBAL)), rbac_operation_fn(Method::POST, "/admin/cache/invalidate"));
+        assert_eq!(Some((RbacOperation::EVENTS, RbacScope::GLOBAL)), rbac_operation_fn(Method::POST, "/api/v1/events/s3"));
+        assert_eq!(None, rbac_operation_fn(Method::POST, "/api/v1/unknown"));
+        assert_eq!(None, rbac_operation_fn(Method::GET, "/api/v1/download/jobs/42"));
+    }
+
+    /// Scenario:
+    /// Checks tenant-scoped requests, oversize and target S3 path requests, admin requests and not valid request bodies
+    /// with an authorization hook allowing just one tenant S3 path
+    /// Expectation:
+    /// Just requests whose all scoped operations are allowed should be allowed and not valid request bodies should not
+    /// be scoped (they are denied as bad requests)
+    #[tokio::test]
+    async fn when_is_scoped_request_allowed_should_deny_not_allowed_scopes() {
+        let scoped_request_fn = |json: &str| serde_json::from_str::<RbacScopedRequest>(json).unwrap();
+        let authorization_hook = TenantReaderHook {};
+        let is_allowed_fn = async |operation: RbacOperation, scoped_request: RbacScopedRequest| {
+            is_scoped_request_allowed(&authorization_hook, Some("reader"), operation, &scoped_request).await
+        };
+
+        let tenant_request = r#"{"bucket_name":"some-bucket","full_path":"tenants/acme/reports"}"#;
+        assert!(is_allowed_fn(RbacOperation::DOWNLOAD, scoped_request_fn(tenant_request)).await);
+        assert!(!is_allowed_fn(RbacOperation::DOWNLOAD, scoped_request_fn(r#"{"bucket_name":"some-bucket","full_path":"reports"}"#)).await);
+        assert!(
+            !is_allowed_fn(
+                RbacOperation::DOWNLOAD,
+                scoped_request_fn(r#"{"bucket_name":"some-bucket","full_path":"tenants/acme/reports","allow_oversize":true}"#)
+            )
+            .await
+        );
+        assert!(
+            !is_allowed_fn(
+                RbacOperation::LIST,
+                scoped_request_fn(r#"{"bucket_name":"some-bucket","full_path":"tenants/acme/a","target_full_path":"tenants/other/b"}"#)
+            )
+            .await
+        );
+        assert!(!is_allowed_fn(RbacOperation::ADMIN, RbacScopedRequest::global()).await);
+        assert!(serde_json::from_str::<RbacScopedRequest>(r#"{"request":{"bucket_name":"some-bucket"}}"#).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use axum::body::{Bytes, to_bytes};
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use crate::config::api_router::get_request_body_max_bytes;
+
+/// Reads [Request] request into [(Parts, Bytes)] request parts and request body (to be checked or rewritten by middlewares)
+/// Returns a payload too large status [Response] if request body is greater than configured maximum size
+pub async fn read_request_body(request: Request) -> Result<(Parts, Bytes), Response> {
+    let (request_parts, request_body) = request.into_parts();
+    match to_bytes(request_body, get_request_body_max_bytes()).await {
+        Ok(request_bytes) => Ok((request_parts, request_bytes)),
+        Err(_) => Err(StatusCode::PAYLOAD_TOO_LARGE.into_response()),
+    }
+}
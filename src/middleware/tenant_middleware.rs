@@ -0,0 +1,320 @@
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::{AUTHORIZATION, CONTENT_LENGTH};
+use axum::http::uri::PathAndQuery;
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, Uri};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_bool;
+use crate::config::external_secrets::get_secret_env_var;
+use crate::constant::constants::{API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_OBJECT_QUERY_PATHS, API_MAIN_PATH, API_V2_MAIN_PATH, TENANCY_ENABLED_DEFAULT, TENANCY_ENABLED_ENV_VAR, TENANT_HEADER, TENANT_ID_MAX_LENGTH, TENANT_PATH_PREFIX, TENANT_TOKEN_CLAIM, TENANT_TOKEN_SECRET_ENV_VAR};
+use crate::middleware::request_body::read_request_body;
+use hmac::{Hmac, Mac};
+use log::warn;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use serde_json::Value;
+use sha2::Sha256;
+
+/// Multi-tenancy middleware (tenant is read from [TENANT_TOKEN_CLAIM] claim of the tenant token - HS256 JWT from
+/// `Authorization: Bearer {token}` request header signed by [TENANT_TOKEN_SECRET_ENV_VAR] secret)
+/// The authenticated tenant replaces any requested [TENANT_HEADER] request header value (read by controllers and AWS
+/// session tags), so it can't be spoofed
+/// Rewrites the requested S3 paths (`full_path` and `target_full_path` fields at any depth of any JSON request body - i.e.:
+/// export template requests) under `tenants/{tenant_id}/` S3 path, so a tenant can never address another tenant's S3 keys
+/// Returns an unauthorized status response if tenant token is not defined or it is not valid (signature, expiration or
+/// tenant claim)
+/// Important: just API requests with body (POST and PUT) and single S3 object requests (GET - `full_path` query param)
+/// are scoped and only if multi-tenancy is enabled
+pub async fn tenant_middleware(mut request: Request, next: Next) -> Response {
+    if !get_env_var_as_bool(TENANCY_ENABLED_ENV_VAR, TENANCY_ENABLED_DEFAULT) {
+        return next.run(request).await;
+    }
+    let tenant_id = get_authenticated_tenant_id(request.headers());
+    request.headers_mut().remove(TENANT_HEADER);
+    if let Some(tenant_header) = tenant_id.as_deref().and_then(|tenant_id| HeaderValue::from_str(tenant_id).ok()) {
+        request.headers_mut().insert(TENANT_HEADER, tenant_header);
+    }
+
+    let is_object_request = request.method() == Method::GET
+        && request
+            .uri()
+            .path()
+            .strip_prefix(API_DOWNLOAD_MAIN_PATH)
+            .is_some_and(|api_path| API_DOWNLOAD_OBJECT_QUERY_PATHS.contains(&api_path));
+    let is_api_body_request = (request.method() == Method::POST || request.method() == Method::PUT)
+        && (request.uri().path().starts_with(API_MAIN_PATH) || request.uri().path().starts_with(API_V2_MAIN_PATH));
+    if !(is_object_request || is_api_body_request) {
+        return next.run(request).await;
+    }
+
+    let Some(tenant_id) = tenant_id else {
+        warn!("tenant_middleware - tenant token not defined or not valid - path: {}", request.uri().path());
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    if is_object_request {
+        let (mut request_parts, request_body) = request.into_parts();
+        let Some(tenant_uri) = create_tenant_uri(&tenant_id, &request_parts.uri) else {
+            return StatusCode::BAD_REQUEST.into_response();
+        };
+        request_parts.uri = tenant_uri;
+        return next.run(Request::from_parts(request_parts, request_body)).await;
+    }
+
+    let (mut request_parts, request_bytes) = match read_request_body(request).await {
+        Ok(request_body) => request_body,
+        Err(error_response) => return error_response,
+    };
+    let mut request_json = match serde_json::from_slice::<Value>(&request_bytes) {
+        Ok(request_json) => request_json,
+        Err(_) => return next.run(Request::from_parts(request_parts, Body::from(request_bytes))).await,
+    };
+
+    scope_request_paths(&tenant_id, &mut request_json);
+
+    request_parts.headers.remove(CONTENT_LENGTH);
+    next.run(Request::from_parts(request_parts, Body::from(request_json.to_string()))).await
+}
+
+/// Gets [Option<String>] requester tenant identifier by [&HeaderMap] request headers (just if multi-tenancy is enabled -
+/// i.e.: requests scoped by stored S3 paths instead of request bodies, like export templates or download jobs)
+/// Important: [TENANT_HEADER] header is set by [tenant_middleware] from the authenticated tenant token
+/// Returns a [StatusCode::UNAUTHORIZED] status if multi-tenancy is enabled and tenant is not authenticated
+pub fn get_request_tenant_id(request_headers: &HeaderMap) -> Result<Option<String>, StatusCode> {
+    if !get_env_var_as_bool(TENANCY_ENABLED_ENV_VAR, TENANCY_ENABLED_DEFAULT) {
+        return Ok(None);
+    }
+    request_headers
+        .get(TENANT_HEADER)
+        .and_then(|tenant_id| tenant_id.to_str().ok())
+        .filter(|tenant_id| is_valid_tenant_id(tenant_id))
+        .map(|tenant_id| Some(String::from(tenant_id)))
+        .ok_or(StatusCode::UNAUTHORIZED)
+}
+
+/// Gets [Option<String>] owner tenant identifier by [&str] tenant-scoped S3 path (just if multi-tenancy is enabled -
+/// i.e.: download jobs, whose status is just visible to their owner tenant)
+pub fn get_path_tenant_id(s3_path: &str) -> Option<String> {
+    if !get_env_var_as_bool(TENANCY_ENABLED_ENV_VAR, TENANCY_ENABLED_DEFAULT) {
+        return None;
+    }
+    let tenant_path = s3_path.strip_prefix(TENANT_PATH_PREFIX)?.strip_prefix('/')?;
+    tenant_path
+        .split('/')
+        .next()
+        .filter(|tenant_id| is_valid_tenant_id(tenant_id))
+        .map(String::from)
+}
+
+/// Gets [bool] true if [&str] S3 path is under [&str] tenant identifier S3 path (i.e.: stored export records)
+pub fn is_tenant_path(tenant_id: &str, s3_path: &str) -> bool {
+    let tenant_path = create_tenant_path(tenant_id, "");
+    s3_path
+        .strip_prefix(&tenant_path)
+        .is_some_and(|sub_path| sub_path.is_empty() || sub_path.starts_with('/'))
+}
+
+/// Gets [Option<String>] authenticated tenant identifier by [&HeaderMap] request headers (tenant token from
+/// `Authorization: Bearer {token}` request header - i.e.: download quotas caller, even if multi-tenancy is not enabled)
+/// Returns [None] if tenant token or [TENANT_TOKEN_SECRET_ENV_VAR] secret are not defined or tenant token is not valid
+pub fn get_authenticated_tenant_id(request_headers: &HeaderMap) -> Option<String> {
+    let token_secret = get_secret_env_var(TENANT_TOKEN_SECRET_ENV_VAR).ok().filter(|token_secret| !token_secret.is_empty())?;
+    get_token_tenant_id(request_headers, &token_secret, current_epoch_seconds())
+}
+
+/// Gets [Option<String>] tenant identifier by [&HeaderMap] request headers (tenant token from `Authorization: Bearer {token}`
+/// request header), [&str] token secret and [u64] current time as seconds since unix epoch
+/// Returns [None] if tenant token is not defined or it is not valid
+pub fn get_token_tenant_id(request_headers: &HeaderMap, token_secret: &str, now: u64) -> Option<String> {
+    let tenant_token = request_headers
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| authorization.strip_prefix("Bearer "))?;
+    verify_tenant_token(token_secret, tenant_token, now)
+}
+
+/// Gets [Option<String>] tenant identifier ([TENANT_TOKEN_CLAIM] claim) by [&str] token secret, [&str] tenant token
+/// (HS256 JWT - "header.claims.signature", all base64 URL-safe encoded) and [u64] current time as seconds since unix epoch
+/// Returns [None] if tenant token signature is not valid, it is expired (`exp` claim is required) or tenant is not valid
+fn verify_tenant_token(token_secret: &str, tenant_token: &str, now: u64) -> Option<String> {
+    let (signed_content, signature) = tenant_token.rsplit_once('.')?;
+    let (token_header, token_claims) = signed_content.split_once('.')?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(token_secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(signed_content.as_bytes());
+    mac.verify_slice(&URL_SAFE_NO_PAD.decode(signature).ok()?).ok()?;
+
+    let token_header = serde_json::from_slice::<Value>(&URL_SAFE_NO_PAD.decode(token_header).ok()?).ok()?;
+    let token_claims = serde_json::from_slice::<Value>(&URL_SAFE_NO_PAD.decode(token_claims).ok()?).ok()?;
+    let expires_at = token_claims.get("exp").and_then(Value::as_u64)?;
+    if token_header.get("alg").and_then(Value::as_str) != Some("HS256") || expires_at <= now {
+        return None;
+    }
+    token_claims
+        .get(TENANT_TOKEN_CLAIM)
+        .and_then(Value::as_str)
+        .filter(|tenant_id| is_valid_tenant_id(tenant_id))
+        .map(String::from)
+}
+
+/// Scopes the requested S3 paths (`full_path` and `target_full_path` string fields at any depth of [&mut Value] JSON
+/// request body) under [&str] tenant identifier S3 path
+fn scope_request_paths(tenant_id: &str, request_json: &mut Value) {
+    match request_json {
+        Value::Object(request_fields) => request_fields.iter_mut().for_each(|(field_name, field_value)| match field_value {
+            Value::String(requested_path) if field_name == "full_path" || field_name == "target_full_path" => {
+                *requested_path = create_tenant_path(tenant_id, requested_path);
+            }
+            _ => scope_request_paths(tenant_id, field_value),
+        }),
+        Value::Array(request_values) => request_values.iter_mut().for_each(|request_value| scope_request_paths(tenant_id, request_value)),
+        _ => {}
+    }
+}
+
+/// Gets [bool] true if [&str] tenant identifier is valid (ASCII alphanumeric, hyphen or underscore characters)
+fn is_valid_tenant_id(tenant_id: &str) -> bool {
+    !tenant_id.is_empty()
+        && tenant_id.len() <= TENANT_ID_MAX_LENGTH
+        && tenant_id.chars().all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_')
+}
+
+/// Creates a new [String] tenant-scoped S3 path by [&str] tenant identifier and [&str] requested S3 path
+fn create_tenant_path(tenant_id: &str, requested_path: &str) -> String {
+    let requested_path = requested_path.trim_matches('/');
+    if requested_path.is_empty() {
+        return format!("{TENANT_PATH_PREFIX}/{tenant_id}");
+    }
+    format!("{TENANT_PATH_PREFIX}/{tenant_id}/{requested_path}")
+}
+
+/// Creates a new [Uri] tenant-scoped request URI by [&str] tenant identifier and [&Uri] requested URI
+/// (any `full_path` query param is scoped under the tenant S3 path - query param names are decoded, so an encoded
+/// name can't skip the tenant scope)
+/// Returns [None] if the tenant-scoped request URI is not valid
+fn create_tenant_uri(tenant_id: &str, uri: &Uri) -> Option<Uri> {
+    let decode_query_value = |query_value: &str| percent_decode_str(&query_value.replace('+', " ")).decode_utf8_lossy().into_owned();
+    let query = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .map(|query_param| match query_param.split_once('=') {
+            Some((query_name, requested_path)) if decode_query_value(query_name) == "full_path" => format!(
+                "full_path={}",
+                utf8_percent_encode(&create_tenant_path(tenant_id, &decode_query_value(requested_path)), NON_ALPHANUMERIC)
+            ),
+            _ => String::from(query_param),
+        })
+        .collect::<Vec<String>>()
+        .join("&");
+
+    let mut uri_parts = uri.clone().into_parts();
+    uri_parts.path_and_query = Some(PathAndQuery::try_from(format!("{}?{query}", uri.path())).ok()?);
+    Uri::from_parts(uri_parts).ok()
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use axum::http::Uri;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use crate::middleware::tenant_middleware::{create_tenant_path, create_tenant_uri, is_valid_tenant_id, scope_request_paths, verify_tenant_token};
+    use hmac::{Hmac, Mac};
+    use serde_json::{json, Value};
+    use sha2::Sha256;
+
+    /// Scenario:
+    /// Creates tenant-scoped S3 paths for a valid tenant
+    /// Expectation:
+    /// Requested S3 paths should be scoped under the tenant S3 path and tenants with path characters should not be valid
+    #[test]
+    fn when_create_tenant_path_should_scope_path_under_tenant() {
+        assert_eq!("tenants/acme/reports/2025", create_tenant_path("acme", "/reports/2025/"));
+        assert_eq!("tenants/acme", create_tenant_path("acme", ""));
+        assert!(is_valid_tenant_id("acme_01"));
+        assert!(!is_valid_tenant_id("acme/../other"));
+        assert!(!is_valid_tenant_id(""));
+    }
+
+    /// Scenario:
+    /// Creates tenant-scoped request URIs for single S3 object proxy query params (plain and encoded param names)
+    /// Expectation:
+    /// Any `full_path` query param should be scoped under the tenant S3 path and other query params should be kept
+    #[test]
+    fn when_create_tenant_uri_should_scope_full_path_query_param() {
+        let uri = "/api/v1/download/object?bucket_name=bucket&full_path=videos%2F2025&s3_key=intro.mp4".parse::<Uri>().unwrap();
+        assert_eq!(
+            "/api/v1/download/object?bucket_name=bucket&full_path=tenants%2Facme%2Fvideos%2F2025&s3_key=intro.mp4",
+            create_tenant_uri("acme", &uri).unwrap().to_string()
+        );
+
+        let uri = "/api/v1/download/object?full%5Fpath=other&s3_key=intro.mp4".parse::<Uri>().unwrap();
+        assert_eq!(
+            "/api/v1/download/object?full_path=tenants%2Facme%2Fother&s3_key=intro.mp4",
+            create_tenant_uri("acme", &uri).unwrap().to_string()
+        );
+    }
+
+    /// Scenario:
+    /// Verifies tenant tokens: valid, expired, without expiration, signed by another secret, with another algorithm and
+    /// with a not valid tenant claim
+    /// Expectation:
+    /// Just the tenant of the valid token should be retrieved
+    #[test]
+    fn when_verify_tenant_token_should_retrieve_tenant_just_for_valid_tokens() {
+        let hs256_header = json!({"alg": "HS256", "typ": "JWT"});
+        let valid_token = create_tenant_token("some-secret", &hs256_header, &json!({"tenant_id": "acme", "exp": 2000}));
+        assert_eq!(Some(String::from("acme")), verify_tenant_token("some-secret", &valid_token, 1000));
+        assert_eq!(None, verify_tenant_token("some-secret", &valid_token, 2000));
+        assert_eq!(None, verify_tenant_token("other-secret", &valid_token, 1000));
+
+        let not_expiring_token = create_tenant_token("some-secret", &hs256_header, &json!({"tenant_id": "acme"}));
+        assert_eq!(None, verify_tenant_token("some-secret", &not_expiring_token, 1000));
+        let none_algorithm_token = create_tenant_token("some-secret", &json!({"alg": "none"}), &json!({"tenant_id": "acme", "exp": 2000}));
+        assert_eq!(None, verify_tenant_token("some-secret", &none_algorithm_token, 1000));
+        let not_valid_tenant_token = create_tenant_token("some-secret", &hs256_header, &json!({"tenant_id": "acme/../other", "exp": 2000}));
+        assert_eq!(None, verify_tenant_token("some-secret", &not_valid_tenant_token, 1000));
+        assert_eq!(None, verify_tenant_token("some-secret", "not-a-token", 1000));
+    }
+
+    /// Scenario:
+    /// Scopes a JSON request body with top-level, nested (export template request) and array S3 paths
+    /// Expectation:
+    /// All `full_path` and `target_full_path` string fields should be scoped under the tenant S3 path
+    #[test]
+    fn when_scope_request_paths_should_scope_nested_paths() {
+        let mut request_json = json!({
+            "full_path": "reports",
+            "target_full_path": "backup",
+            "request": {"bucket_name": "some-bucket", "full_path": "/videos/"},
+            "requests": [{"full_path": "a"}, {"full_path": null}],
+            "name": "full_path"
+        });
+        scope_request_paths("acme", &mut request_json);
+        assert_eq!(
+            json!({
+                "full_path": "tenants/acme/reports",
+                "target_full_path": "tenants/acme/backup",
+                "request": {"bucket_name": "some-bucket", "full_path": "tenants/acme/videos"},
+                "requests": [{"full_path": "tenants/acme/a"}, {"full_path": null}],
+                "name": "full_path"
+            }),
+            request_json
+        );
+    }
+
+    /// Creates a new [String] tenant token by [&str] token secret and [&Value] token header and claims
+    fn create_tenant_token(token_secret: &str, token_header: &Value, token_claims: &Value) -> String {
+        let signed_content = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(token_header.to_string()),
+            URL_SAFE_NO_PAD.encode(token_claims.to_string())
+        );
+        let mut mac = Hmac::<Sha256>::new_from_slice(token_secret.as_bytes()).unwrap();
+        mac.update(signed_content.as_bytes());
+        format!("{signed_content}.{}", URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+}
@@ -1,33 +1,63 @@
 use crate::config::aws_sdk_s3_client::{AwsSdkS3Client, DynAwsSdkS3Client};
+use crate::config::bucket_regions::{get_other_bucket_region, BUCKET_REGIONS};
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_i64, get_env_var_as_u64, get_env_var_as_usize};
+use crate::config::envelope_encryption::ENVELOPE_ENCRYPTION;
+use crate::config::fetch_attempts_interceptor::FetchAttemptsInterceptor;
+use crate::config::assume_role::get_caller_session_tags;
+use crate::config::caller_credentials::get_caller_credentials;
+use crate::config::listing_cache::LISTING_CACHE;
+use crate::config::memory_budget::{MemoryReservation, MEMORY_BUDGET};
+use crate::config::object_body_cache::OBJECT_BODY_CACHE;
+use crate::dto::lifecycle_transition::LifecycleTransition;
+use crate::dto::s3_fetch_options::S3FetchOptions;
+use crate::dto::s3_object::S3Object;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::dto::s3_object_stream::S3ObjectStream;
+use crate::dto::s3_object_stream_options::S3ObjectStreamOptions;
 use crate::enums::common_error::CommonError;
+use crate::enums::s3_restore_status::S3RestoreStatus;
+use crate::enums::s3_restore_tier::S3RestoreTier;
 use async_trait::async_trait;
-use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::config::http::HttpResponse;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::primitives::{ByteStream, ByteStreamError, DateTime, DateTimeFormat};
+use aws_sdk_s3::types::{ChecksumMode, ExpirationStatus, GlacierJobParameters, LifecycleRule, Object, ObjectLockLegalHoldStatus, ObjectLockMode, OptionalObjectAttributes, RestoreRequest as S3RestoreRequest, RestoreStatus, ServerSideEncryption, Tier};
 use aws_sdk_s3::Client;
 use axum::body::Bytes;
-use log::{debug, error, warn};
+use log::{debug, error, info, warn};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
-use tokio::io::AsyncReadExt;
-use tokio::task::JoinSet;
-use crate::constant::constants::{AWS_S3_MAX_FILE_QUANTITY_DEFAULT, AWS_S3_MAX_FILE_QUANTITY_ENV_VAR, AWS_S3_MAX_FILE_SIZE_BYTES_DEFAULT, AWS_S3_MAX_FILE_SIZE_BYTES_ENV_VAR};
+use std::time::Instant;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::task::{JoinError, JoinSet};
+use tokio_util::sync::CancellationToken;
+use crate::constant::constants::{EXPORT_SSE_KMS_KEY_ID_ENV_VAR, AWS_S3_BUCKET_REGION_HEADER, AWS_S3_DEFAULT_STORAGE_CLASS, AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_DEFAULT, AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_ENV_VAR, AWS_S3_RESTORE_REQUIRED_STORAGE_CLASSES, AWS_S3_MAX_FILE_QUANTITY_DEFAULT, AWS_S3_MAX_FILE_QUANTITY_ENV_VAR, AWS_S3_MAX_FILE_SIZE_BYTES_DEFAULT, AWS_S3_MAX_FILE_SIZE_BYTES_ENV_VAR, AWS_S3_FETCH_WINDOW_SIZE_DEFAULT, AWS_S3_FETCH_WINDOW_SIZE_ENV_VAR, AWS_S3_MAX_MULTIPART_CHUNK_BYTES_DEFAULT, AWS_S3_MAX_MULTIPART_CHUNK_BYTES_ENV_VAR, AWS_S3_MULTIPART_CHUNK_BYTES_MIN, AWS_S3_STREAM_MIN_BYTES_DEFAULT, AWS_S3_STREAM_MIN_BYTES_ENV_VAR, AWS_S3_OBJECT_CHANNEL_CAPACITY};
 
 /// AWS SDK S3 client
 /// important: libs can't export test attributes so we should use debug_assertions instead of test macro for child crates
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait AwsSdkS3ServiceTrait {
-    /// Adds S3 object by [String] bucket name, [String] path, [String] s3 key and [Bytes] content
+    /// Adds S3 object by [String] bucket name, [String] path, [String] s3 key and [Bytes] content (owned content is
+    /// uploaded as it is - no copy of the payload is made - and encrypted by SSE-KMS if the export KMS key is configured)
+    /// Important: if the export envelope KMS key is configured, content is encrypted client-side by a KMS data key and
+    /// the wrapped data key is stored in S3 object metadata (please, check [ENVELOPE_ENCRYPTION])
     /// Returns a [String] with added S3 file key value
-    /// Returns a [CommonError] if result is empty or S3 throws any error
+    /// Returns a [CommonError::OBJECT_LOCKED] if an existing S3 object is protected by Object Lock (not overwritten)
+    /// Returns a [CommonError] if result is empty, content can't be encrypted or S3 throws any error
     async fn add_s3_object(
         &self,
         bucket_name: String,
         path: String,
         s3_key: String,
-        s3_key_content: &Bytes,
+        s3_key_content: Bytes,
     ) -> Result<String, CommonError>;
 
     /// Gets [(String, Vec<u8>)] S3 key value and stream content by [String] bucket name,
     /// [String] path and [String] s3 key
+    /// Important: the whole S3 object content is buffered (convenience method - please, check
+    /// [AwsSdkS3ServiceTrait::get_s3_object_as_stream] for consumers that shouldn't hold the whole S3 object)
     /// Returns a [CommonError] if result is empty or S3 throws any error
     async fn get_s3_object(
         &self,
@@ -44,6 +74,30 @@ pub trait AwsSdkS3ServiceTrait {
         path: String,
     ) -> Result<Vec<String>, CommonError>;
 
+    /// Gets [Vec<String>] all S3 keys (files directly under the S3 path) by [String] bucket name, [String] path and
+    /// [bool] allow oversize flag (S3 objects greater than the configured maximum file size are excluded unless oversize
+    /// files are allowed)
+    /// Important: all S3 listing pages are requested (by ListObjectsV2 continuation tokens) and S3 key list is not limited
+    /// by the configured maximum file quantity (huge exports partitioned by volumes - please, check
+    /// [AwsSdkS3ServiceTrait::get_s3_object_key_list] for single exports)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_all_object_key_list(
+        &self,
+        bucket_name: String,
+        path: String,
+        allow_oversize: bool,
+    ) -> Result<Vec<String>, CommonError>;
+
+    /// Gets [Vec<String>] excluded S3 key list (S3 objects greater than the configured maximum file size)
+    /// by [String] bucket name and [String] path
+    /// Important: excluded S3 key lists are not cached (please, check [AwsSdkS3ServiceTrait::get_s3_object_key_list])
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_excluded_object_key_list(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<Vec<String>, CommonError>;
+
     /// Gets [(String, Vec<u8>)] S3 objects keys and contents by [String] bucket name and [String] path
     /// Returns a [CommonError] if result is empty or S3 throws any error
     async fn get_s3_objects_by_path(
@@ -52,8 +106,39 @@ pub trait AwsSdkS3ServiceTrait {
         path: String,
     ) -> Result<Vec<(String, Vec<u8>)>, CommonError>;
 
+    /// Gets a [Receiver<S3Object>] that yields S3 objects (keys and contents) by [String]
+    /// bucket name, [String] path and [S3FetchOptions] fetch options in listing order while downloads are in progress
+    /// Important:
+    /// - downloads are executed in parallel but completed downloads are buffered (up to the configured
+    ///   fetch window size or requested max parallel fetches) until all previous S3 objects are sent
+    /// - requested multipart chunk size is limited by configured min and max values (please, check constants.rs)
+    /// - S3 objects contents are reserved from the memory budget until each [S3Object] is dropped
+    /// Returns a [CommonError] if S3 key list is empty or S3 throws any error
+    async fn get_s3_objects_by_path_as_channel(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_fetch_options: S3FetchOptions,
+    ) -> Result<Receiver<S3Object>, CommonError>;
+
+    /// Gets a [Receiver<S3Object>] that yields S3 objects (keys and contents) by [String] bucket name,
+    /// [String] path, [Vec<String>] S3 key list and [S3FetchOptions] fetch options in S3 key list order
+    /// while downloads are in progress (please, check [AwsSdkS3ServiceTrait::get_s3_objects_by_path_as_channel])
+    /// Important: S3 keys not found in the S3 path listing are skipped - if direct key lookup is requested, S3 keys are
+    /// fetched without listing and not found S3 keys are yielded with a [CommonError::NO_DATA_FOUND] fetch error
+    /// Returns a [CommonError] if S3 key list is empty or S3 throws any error
+    async fn get_s3_objects_by_keys_as_channel(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_keys: Vec<String>,
+        s3_fetch_options: S3FetchOptions,
+    ) -> Result<Receiver<S3Object>, CommonError>;
+
     /// Gets [(Vec<(String, Vec<u8>)>, Vec<String>)] S3 objects keys and contents + not found keys
-    /// by [String] bucket name, [String] path and [Vec<String>] S3 key list
+    /// by [String] bucket name, [String] path, [Vec<String>] S3 key list and [S3FetchOptions] fetch options
+    /// Important: S3 keys are checked against the S3 path listing - if direct key lookup is requested, S3 keys are
+    /// fetched without listing and just genuine 404 responses are reported as not found
     /// Returns a [CommonError] if result is empty or S3 throws any error
     #[allow(clippy::type_complexity)] // avoid define the result as a type (suggested by clippy)
     async fn get_s3_objects_by_keys(
@@ -61,7 +146,134 @@ pub trait AwsSdkS3ServiceTrait {
         bucket_name: String,
         path: String,
         s3_keys: Vec<String>,
+        s3_fetch_options: S3FetchOptions,
     ) -> Result<(Vec<(String, Vec<u8>)>, Vec<String>), CommonError>;
+
+    /// Gets [Option<S3ObjectMetadata>] S3 object metadata by [String] bucket name, [String] path
+    /// and [Option<String>] s3 key (by HeadObject) or the first S3 object found under the path
+    /// if s3 key is not defined (by ListObjectsV2 with max keys = 1)
+    /// Returns [None] if S3 object (or S3 prefix) is not found
+    /// Returns a [CommonError] if S3 throws any other error
+    async fn get_s3_object_metadata(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: Option<String>,
+    ) -> Result<Option<S3ObjectMetadata>, CommonError>;
+
+    /// Gets [(Vec<S3ObjectMetadata>, Option<String>)] one page of S3 objects metadata (all nested folders included)
+    /// + next continuation token by [String] bucket name, [String] path, [Option<i32>] max keys and
+    /// [Option<String>] continuation token (by ListObjectsV2)
+    /// Important: next continuation token is [None] once the last page is retrieved
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_metadata_page(
+        &self,
+        bucket_name: String,
+        path: String,
+        max_keys: Option<i32>,
+        continuation_token: Option<String>,
+    ) -> Result<(Vec<S3ObjectMetadata>, Option<String>), CommonError>;
+
+    /// Gets [BTreeMap<String, String>] S3 object tags by [String] bucket name, [String] path and [String] s3 key
+    /// (by GetObjectTagging)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_tags(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<BTreeMap<String, String>, CommonError>;
+
+    /// Gets [Option<String>] stored full object SHA-256 checksum (base64 encoded) by [String] bucket name,
+    /// [String] path and [String] s3 key (by HeadObject with checksum mode enabled)
+    /// Returns [None] if S3 object has no stored SHA-256 checksum or it is a composite (multipart) checksum
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_checksum(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<Option<String>, CommonError>;
+
+    /// Gets [(Vec<u8>, u64)] S3 object content range and S3 object total size by [String] bucket name, [String] path,
+    /// [String] s3 key and [String] HTTP range (i.e.: "bytes=0-1023" or "bytes=-1024" - by ranged GetObject)
+    /// Important: empty S3 objects (ranged request not satisfiable) are retrieved as empty content with zero size
+    /// and the content range is buffered (convenience method over [AwsSdkS3ServiceTrait::get_s3_object_stream])
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist or a [CommonError] if S3 throws any error
+    async fn get_s3_object_range(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        range: String,
+    ) -> Result<(Vec<u8>, u64), CommonError>;
+
+    /// Gets [S3ObjectStream] S3 object content stream by [String] bucket name, [String] path, [String] s3 key and
+    /// [S3ObjectStreamOptions] HTTP range and conditional request headers (forwarded as they are to S3)
+    /// Important: S3 object content is not buffered (it is read from S3 as the stream is consumed) and a not modified
+    /// S3 object is retrieved without content
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist, a [CommonError::RANGE_NOT_SATISFIABLE] if HTTP range
+    /// is out of the S3 object size or a [CommonError] if S3 throws any error
+    async fn get_s3_object_stream(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        s3_object_stream_options: S3ObjectStreamOptions,
+    ) -> Result<S3ObjectStream, CommonError>;
+
+    /// Gets [(String, ByteStream, Option<i64>)] S3 key value, whole S3 object content stream and content length
+    /// by [String] bucket name, [String] path and [String] s3 key (please, check [AwsSdkS3ServiceTrait::get_s3_object_stream])
+    /// Important: S3 object content is not buffered (it is read from S3 as the stream is consumed)
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist or a [CommonError] if S3 throws any error
+    async fn get_s3_object_as_stream(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<(String, ByteStream, Option<i64>), CommonError>;
+
+    /// Gets [(Vec<S3ObjectMetadata>, Vec<String>)] S3 objects metadata directly under the S3 path + first-level
+    /// sub-folder names (S3 common prefixes) by [String] bucket name and [String] path (by ListObjectsV2 with "/" delimiter)
+    /// Important: all S3 listing pages are requested
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_first_level_entries(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<(Vec<S3ObjectMetadata>, Vec<String>), CommonError>;
+
+    /// Gets [Vec<(String, S3RestoreStatus)>] archived S3 objects (S3 keys + restore status) directly under [String] path
+    /// of [String] bucket name (archive storage classes - by ListObjectsV2 with restore status attribute)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_archived_objects(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<Vec<(String, S3RestoreStatus)>, CommonError>;
+
+    /// Requests archived S3 object restore by [String] bucket name, [String] path, [String] s3 key,
+    /// [i32] restored copy lifetime (in days) and [S3RestoreTier] restore tier
+    /// Important: restores already in progress are not requested again (no error is returned)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn restore_s3_object(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        restore_days: i32,
+        restore_tier: S3RestoreTier,
+    ) -> Result<(), CommonError>;
+
+    /// Removes S3 object by [String] bucket name, [String] path and [String] s3 key (by DeleteObject)
+    /// Returns a [CommonError::OBJECT_LOCKED] if S3 object is protected by Object Lock (not removed)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn remove_s3_object(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<(), CommonError>;
 }
 
 /// AWS SDK S3 service implementation struct
@@ -70,6 +282,19 @@ pub struct AwsSdkS3Service {
     aws_sdk_s3_client: DynAwsSdkS3Client,
     aws_sdk_s3_max_file_qty: usize,
     aws_sdk_s3_max_file_size: i64,
+    aws_sdk_s3_fetch_window_size: usize,
+    aws_sdk_s3_max_multipart_chunk_bytes: u64,
+    aws_sdk_s3_stream_min_bytes: u64,
+    aws_sdk_s3_lifecycle_transitions_enabled: bool,
+    aws_sdk_s3_sse_kms_key_id: Option<String>,
+}
+
+/// AWS S3 lifecycle transition rule (enabled bucket lifecycle rule transition filtered by S3 key prefix)
+struct LifecycleTransitionRule {
+    s3_key_prefix: String,
+    days: Option<i64>,
+    date_epoch_seconds: Option<i64>,
+    storage_class: String,
 }
 
 /// default initialization
@@ -79,6 +304,15 @@ impl Default for AwsSdkS3Service {
             aws_sdk_s3_client: Arc::new(AwsSdkS3Client::default()) as DynAwsSdkS3Client,
             aws_sdk_s3_max_file_qty: get_env_var_as_usize(AWS_S3_MAX_FILE_QUANTITY_ENV_VAR, AWS_S3_MAX_FILE_QUANTITY_DEFAULT),
             aws_sdk_s3_max_file_size: get_env_var_as_i64(AWS_S3_MAX_FILE_SIZE_BYTES_ENV_VAR, AWS_S3_MAX_FILE_SIZE_BYTES_DEFAULT),
+            aws_sdk_s3_fetch_window_size: get_env_var_as_usize(AWS_S3_FETCH_WINDOW_SIZE_ENV_VAR, AWS_S3_FETCH_WINDOW_SIZE_DEFAULT).max(1),
+            aws_sdk_s3_max_multipart_chunk_bytes: get_env_var_as_u64(AWS_S3_MAX_MULTIPART_CHUNK_BYTES_ENV_VAR, AWS_S3_MAX_MULTIPART_CHUNK_BYTES_DEFAULT)
+                .max(AWS_S3_MULTIPART_CHUNK_BYTES_MIN),
+            aws_sdk_s3_stream_min_bytes: get_env_var_as_u64(AWS_S3_STREAM_MIN_BYTES_ENV_VAR, AWS_S3_STREAM_MIN_BYTES_DEFAULT).max(1),
+            aws_sdk_s3_lifecycle_transitions_enabled: get_env_var_as_bool(
+                AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_ENV_VAR,
+                AWS_S3_LIFECYCLE_TRANSITIONS_ENABLED_DEFAULT,
+            ),
+            aws_sdk_s3_sse_kms_key_id: std::env::var(EXPORT_SSE_KMS_KEY_ID_ENV_VAR).ok().filter(|sse_kms_key_id| !sse_kms_key_id.is_empty()),
         }
     }
 }
@@ -86,15 +320,19 @@ impl Default for AwsSdkS3Service {
 /// AWS S3 client implementation logic
 #[async_trait]
 impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
-    /// Adds S3 object by [String] bucket name, [String] path, [String] s3 key and [Bytes] content
+    /// Adds S3 object by [String] bucket name, [String] path, [String] s3 key and [Bytes] content (owned content is
+    /// uploaded as it is - no copy of the payload is made - and encrypted by SSE-KMS if the export KMS key is configured)
+    /// Important: if the export envelope KMS key is configured, content is encrypted client-side by a KMS data key and
+    /// the wrapped data key is stored in S3 object metadata (please, check [ENVELOPE_ENCRYPTION])
     /// Returns a [String] with added S3 file key value
-    /// Returns a [CommonError] if result is empty or S3 throws any error
+    /// Returns a [CommonError::OBJECT_LOCKED] if an existing S3 object is protected by Object Lock (not overwritten)
+    /// Returns a [CommonError] if result is empty, content can't be encrypted or S3 throws any error
     async fn add_s3_object(
         &self,
         bucket_name: String,
         path: String,
         s3_key: String,
-        s3_key_content: &Bytes,
+        s3_key_content: Bytes,
     ) -> Result<String, CommonError> {
         debug!("add_s3_object - start");
 
@@ -109,19 +347,40 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
             return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
         }
 
+        // exports are never uploaded in plain text if envelope encryption is enabled (upload fails instead)
+        let (s3_key_content, envelope_metadata) = if ENVELOPE_ENCRYPTION.is_enabled() {
+            let Ok((ciphertext, envelope_metadata)) = ENVELOPE_ENCRYPTION.encrypt(&s3_key_content).await else {
+                error!("add_s3_object - content not encrypted - s3 key: {s3_key}");
+                return Err(CommonError::AWS_ACCESS_ERROR);
+            };
+            (Bytes::from(ciphertext), Some(envelope_metadata))
+        } else {
+            (s3_key_content, None)
+        };
+
         debug!("add_s3_object - upload start - s3 key: {}", &s3_key);
-        let client_s3 = self.aws_sdk_s3_client.create_aws_sdk_client().await;
+        let client_s3 = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = sanitize_path(path);
+        if let Some(object_lock_reason) = self.get_object_lock_reason(&client_s3, &bucket_name, &join_s3_key(&s3_prefix, &s3_key)).await {
+            warn!("add_s3_object - s3 object locked (not overwritten) - s3 key: {s3_key} - lock: {object_lock_reason}");
+            return Err(CommonError::OBJECT_LOCKED);
+        }
 
         match client_s3
             .put_object()
             .bucket(&bucket_name)
-            .key(format!("{}/{}", sanitize_path(path), &s3_key))
-            .body(ByteStream::from(s3_key_content.clone()))
+            .key(join_s3_key(&s3_prefix, &s3_key))
+            .body(ByteStream::from(s3_key_content))
+            .set_metadata(envelope_metadata)
+            .set_server_side_encryption(self.aws_sdk_s3_sse_kms_key_id.as_ref().map(|_| ServerSideEncryption::AwsKms))
+            .set_ssekms_key_id(self.aws_sdk_s3_sse_kms_key_id.clone())
             .send()
             .await
         {
             Ok(_) => {
                 debug!("add_s3_object - upload completed - s3 key: {s3_key}");
+                // invalidated just once the upload is completed (a concurrent listing can't cache the previous content)
+                LISTING_CACHE.invalidate(&bucket_name, Some(&s3_prefix)).await;
                 debug!("add_s3_object - done");
                 Ok(s3_key)
             }
@@ -135,6 +394,8 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
 
     /// Gets [(String, Vec<u8>)] S3 key value and stream content by [String] bucket name,
     /// [String] path and [String] s3 key
+    /// Important: the whole S3 object content is buffered (convenience method - please, check
+    /// [AwsSdkS3ServiceTrait::get_s3_object_as_stream] for consumers that shouldn't hold the whole S3 object)
     /// Returns a [CommonError] if result is empty or S3 throws any error
     async fn get_s3_object(
         &self,
@@ -147,15 +408,18 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
         debug!("get_s3_object - s3 path: {}", &path);
         debug!("get_s3_object - s3 key: {}", &s3_key);
 
-        let aws_sdk_client = self.aws_sdk_s3_client.create_aws_sdk_client().await;
-        match get_s3_object_content(
-            aws_sdk_client,
-            bucket_name.clone(),
-            path.clone(),
-            s3_key.clone(),
-        )
-        .await
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let get_s3_object_result = |aws_sdk_client: Client| {
+            get_s3_object_content(aws_sdk_client, bucket_name.clone(), path.clone(), s3_key.clone(), None, FetchAttemptsInterceptor::default(), None)
+        };
+        let mut s3_object_result = get_s3_object_result(aws_sdk_client.clone()).await;
+        if s3_object_result.is_err()
+            && let Some(regional_aws_sdk_client) = self.discover_bucket_client(&aws_sdk_client, &bucket_name, None).await
         {
+            s3_object_result = get_s3_object_result(regional_aws_sdk_client).await;
+        }
+
+        match s3_object_result {
             Ok(s3_object) => {
                 debug!("get_s3_object - done");
                 Ok(s3_object)
@@ -170,6 +434,8 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
     }
 
     /// Gets [Vec<String>] S3 key list by [String] bucket name and [String] path
+    /// Important: S3 key lists are cached for a short TTL (please, check constants.rs)
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if S3 key list is greater than configured maximum file quantity
     /// Returns a [CommonError] if result is empty or S3 throws any error
     async fn get_s3_object_key_list(
         &self,
@@ -180,53 +446,106 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
         debug!("get_s3_object_key_list - bucket name: {}", &bucket_name);
         debug!("get_s3_object_key_list - path: {}", &path);
 
-        let aws_sdk_client = self.aws_sdk_s3_client.create_aws_sdk_client().await;
+        self.list_s3_object_keys(bucket_name, path, false).await
+    }
+
+    /// Gets [Vec<String>] all S3 keys (files directly under the S3 path) by [String] bucket name, [String] path and
+    /// [bool] allow oversize flag (S3 objects greater than the configured maximum file size are excluded unless oversize
+    /// files are allowed)
+    /// Important: all S3 listing pages are requested (by ListObjectsV2 continuation tokens) and S3 key list is not limited
+    /// by the configured maximum file quantity (huge exports partitioned by volumes - please, check
+    /// [AwsSdkS3ServiceTrait::get_s3_object_key_list] for single exports)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_all_object_key_list(
+        &self,
+        bucket_name: String,
+        path: String,
+        allow_oversize: bool,
+    ) -> Result<Vec<String>, CommonError> {
+        debug!("get_s3_all_object_key_list - start");
+        debug!("get_s3_all_object_key_list - bucket name: {}", &bucket_name);
+        debug!("get_s3_all_object_key_list - path: {}", &path);
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = join_s3_key(&sanitize_path(path.clone()), "");
+        let mut s3_object_key_list = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let s3_object_list = match aws_sdk_client
+                .list_objects_v2()
+                .bucket(&bucket_name)
+                .prefix(&s3_prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+            {
+                Ok(s3_object_list) => s3_object_list,
+                Err(s3_object_error) => {
+                    error!("get_s3_all_object_key_list - s3 object key list not found - error: {s3_object_error}");
+                    error!("get_s3_all_object_key_list - s3 object key list not found - bucket name: {bucket_name}");
+                    error!("get_s3_all_object_key_list - s3 object key list not found - path: {path}");
+                    return Err(CommonError::AWS_ACCESS_ERROR);
+                }
+            };
+            s3_object_key_list.extend(
+                s3_object_list
+                    .contents()
+                    .iter()
+                    .filter(|s3_object| allow_oversize || s3_object.size.unwrap_or_default() < self.aws_sdk_s3_max_file_size)
+                    .filter_map(|s3_object| s3_object.key())
+                    .filter_map(|s3_key| s3_key.strip_prefix(&s3_prefix))
+                    .filter(|s3_key| !s3_key.is_empty() && !s3_key.contains('/'))
+                    .map(String::from),
+            );
+
+            continuation_token = s3_object_list.next_continuation_token().map(String::from);
+            if continuation_token.is_none() {
+                debug!("get_s3_all_object_key_list - done - s3 keys: {}", s3_object_key_list.len());
+                return Ok(s3_object_key_list);
+            }
+        }
+    }
+
+    /// Gets [Vec<String>] excluded S3 key list (S3 objects greater than the configured maximum file size)
+    /// by [String] bucket name and [String] path
+    /// Important: excluded S3 key lists are not cached (please, check [AwsSdkS3ServiceTrait::get_s3_object_key_list])
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_excluded_object_key_list(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<Vec<String>, CommonError> {
+        debug!("get_s3_excluded_object_key_list - start");
+        debug!("get_s3_excluded_object_key_list - bucket name: {}", &bucket_name);
+        debug!("get_s3_excluded_object_key_list - path: {}", &path);
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = join_s3_key(&sanitize_path(path.clone()), "");
         match aws_sdk_client
             .list_objects()
             .bucket(&bucket_name)
-            .prefix(sanitize_path(path.clone()))
+            .prefix(&s3_prefix)
             .send()
             .await
         {
             Ok(s3_object_list) => {
-                let s3_object_key_list: Vec<String> = s3_object_list
+                let s3_excluded_key_list: Vec<String> = s3_object_list
                     .contents
                     .unwrap_or_default()
-                    .iter()
-                    .filter(|s3_object| s3_object.key.is_some())
-                    .filter(|s3_object| s3_object.size.unwrap_or_default() < self.aws_sdk_s3_max_file_size)
-                    .map(|s3_object| s3_object.key.clone().unwrap_or_default())
-                    .map(|s3_key| {
-                        String::from(
-                            s3_key
-                                .strip_prefix(&format!("{}/", &sanitize_path(path.clone())))
-                                .unwrap(),
-                        )
-                    })
+                    .into_iter()
+                    .filter(|s3_object| s3_object.size.unwrap_or_default() >= self.aws_sdk_s3_max_file_size)
+                    .filter_map(|s3_object| s3_object.key)
+                    .filter_map(|s3_key| s3_key.strip_prefix(&s3_prefix).map(String::from))
                     .filter(|s3_key| !s3_key.contains("/"))
                     .collect();
 
-                if s3_object_key_list.len() > self.aws_sdk_s3_max_file_qty {
-                    error!(
-                        "get_s3_object_key_list - s3 object key list is greater than configured maximum file quantity - bucket name: {bucket_name}"
-                    );
-                    error!(
-                        "get_s3_object_key_list - s3 object key list is greater than configured maximum file quantity - path: {path}"
-                    );
-                    return Err(CommonError::AWS_ACCESS_ERROR);
-                }
-
-                debug!("get_s3_object_key_list - done");
-                Ok(s3_object_key_list)
+                debug!("get_s3_excluded_object_key_list - done - excluded s3 keys: {}", s3_excluded_key_list.len());
+                Ok(s3_excluded_key_list)
             }
             Err(s3_object_error) => {
-                error!("get_s3_object_key_list - s3 object key list not found - error: {s3_object_error}");
-                error!(
-                    "get_s3_object_key_list - s3 object key list not found - bucket name: {bucket_name}"
-                );
-                error!(
-                    "get_s3_object_key_list - s3 object key list not found - path: {path}"
-                );
+                error!("get_s3_excluded_object_key_list - s3 object key list not found - error: {s3_object_error}");
+                error!("get_s3_excluded_object_key_list - s3 object key list not found - bucket name: {bucket_name}");
+                error!("get_s3_excluded_object_key_list - s3 object key list not found - path: {path}");
                 Err(CommonError::AWS_ACCESS_ERROR)
             }
         }
@@ -248,7 +567,7 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
             .await
         {
             Ok(s3_object_key_list_values) => {
-                let aws_sdk_client = self.aws_sdk_s3_client.create_aws_sdk_client().await;
+                let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
 
                 let mut tokio_join_set = JoinSet::new();
                 let mut s3_object_key_found_list = Vec::new();
@@ -259,11 +578,17 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
                         bucket_name.clone(),
                         path.clone(),
                         s3_key.clone(),
+                        None,
+                        FetchAttemptsInterceptor::default(),
+                        None,
                     ));
                 });
 
                 while let Some(result) = tokio_join_set.join_next().await {
-                    s3_object_key_found_list.push(result.unwrap().unwrap_or_default());
+                    match result {
+                        Ok(s3_object) => s3_object_key_found_list.push(s3_object.unwrap_or_default()),
+                        Err(join_error) => error!("get_s3_objects_by_path - s3 object fetch task failed - error: {join_error}"),
+                    }
                 }
 
                 debug!("get_s3_objects_by_path - done");
@@ -273,7 +598,97 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
                 error!("get_s3_objects_by_path - s3 objects not found - error: {s3_object_key_list_error}");
                 error!("get_s3_objects_by_path - s3 objects not found - bucket name: {bucket_name}");
                 error!("get_s3_objects_by_path - s3 objects not found - path: {path}");
-                Err(CommonError::AWS_ACCESS_ERROR)
+                Err(s3_object_key_list_error)
+            }
+        }
+    }
+
+    /// Gets a [Receiver<S3Object>] that yields S3 objects (keys and contents) by [String]
+    /// bucket name, [String] path and [S3FetchOptions] fetch options in listing order while downloads are in progress
+    /// Important:
+    /// - downloads are executed in parallel but completed downloads are buffered (up to the configured
+    ///   fetch window size or requested max parallel fetches) until all previous S3 objects are sent
+    /// - requested multipart chunk size is limited by configured min and max values (please, check constants.rs)
+    /// - S3 objects contents are reserved from the memory budget until each [S3Object] is dropped
+    /// Returns a [CommonError] if S3 key list is empty or S3 throws any error
+    async fn get_s3_objects_by_path_as_channel(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_fetch_options: S3FetchOptions,
+    ) -> Result<Receiver<S3Object>, CommonError> {
+        debug!("get_s3_objects_by_path_as_channel - start");
+        debug!("get_s3_objects_by_path_as_channel - bucket name: {}", &bucket_name);
+        debug!("get_s3_objects_by_path_as_channel - path: {}", &path);
+
+        match self
+            .list_s3_object_keys(bucket_name.clone(), path.clone(), s3_fetch_options.allow_oversize)
+            .await
+        {
+            Ok(s3_object_key_list_values) => {
+                let s3_object_receiver = self
+                    .create_s3_object_channel(bucket_name, path, s3_object_key_list_values, s3_fetch_options)
+                    .await;
+
+                debug!("get_s3_objects_by_path_as_channel - done");
+                Ok(s3_object_receiver)
+            }
+            Err(s3_object_key_list_error) => {
+                error!("get_s3_objects_by_path_as_channel - s3 objects not found - error: {s3_object_key_list_error}");
+                error!("get_s3_objects_by_path_as_channel - s3 objects not found - bucket name: {bucket_name}");
+                error!("get_s3_objects_by_path_as_channel - s3 objects not found - path: {path}");
+                Err(s3_object_key_list_error)
+            }
+        }
+    }
+
+    /// Gets a [Receiver<S3Object>] that yields S3 objects (keys and contents) by [String] bucket name,
+    /// [String] path, [Vec<String>] S3 key list and [S3FetchOptions] fetch options in S3 key list order
+    /// while downloads are in progress (please, check [AwsSdkS3ServiceTrait::get_s3_objects_by_path_as_channel])
+    /// Important: S3 keys not found in the S3 path listing are skipped - if direct key lookup is requested, S3 keys are
+    /// fetched without listing and not found S3 keys are yielded with a [CommonError::NO_DATA_FOUND] fetch error
+    /// Returns a [CommonError] if S3 key list is empty or S3 throws any error
+    async fn get_s3_objects_by_keys_as_channel(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_keys: Vec<String>,
+        s3_fetch_options: S3FetchOptions,
+    ) -> Result<Receiver<S3Object>, CommonError> {
+        debug!("get_s3_objects_by_keys_as_channel - start");
+        debug!("get_s3_objects_by_keys_as_channel - bucket name: {}", &bucket_name);
+        debug!("get_s3_objects_by_keys_as_channel - path: {}", &path);
+        debug!("get_s3_objects_by_keys_as_channel - s3 keys: {:?}", &s3_keys);
+
+        if s3_fetch_options.direct_key_lookup {
+            debug!("get_s3_objects_by_keys_as_channel - direct key lookup (no listing)");
+            return Ok(self.create_s3_object_channel(bucket_name, path, s3_keys, s3_fetch_options).await);
+        }
+
+        match self
+            .list_s3_object_keys(bucket_name.clone(), path.clone(), s3_fetch_options.allow_oversize)
+            .await
+        {
+            Ok(s3_object_key_list) => {
+                let (s3_keys_found, s3_keys_not_found): (Vec<String>, Vec<String>) = s3_keys
+                    .into_iter()
+                    .partition(|s3_key| s3_object_key_list.contains(s3_key));
+                if !s3_keys_not_found.is_empty() {
+                    warn!("get_s3_objects_by_keys_as_channel - s3 keys not found: {s3_keys_not_found:?}");
+                }
+
+                let s3_object_receiver = self
+                    .create_s3_object_channel(bucket_name, path, s3_keys_found, s3_fetch_options)
+                    .await;
+
+                debug!("get_s3_objects_by_keys_as_channel - done");
+                Ok(s3_object_receiver)
+            }
+            Err(s3_object_key_list_error) => {
+                error!("get_s3_objects_by_keys_as_channel - s3 objects not found - error: {s3_object_key_list_error}");
+                error!("get_s3_objects_by_keys_as_channel - s3 objects not found - bucket name: {bucket_name}");
+                error!("get_s3_objects_by_keys_as_channel - s3 objects not found - path: {path}");
+                Err(s3_object_key_list_error)
             }
         }
     }
@@ -286,99 +701,1203 @@ impl AwsSdkS3ServiceTrait for AwsSdkS3Service {
         bucket_name: String,
         path: String,
         s3_keys: Vec<String>,
+        s3_fetch_options: S3FetchOptions,
     ) -> Result<(Vec<(String, Vec<u8>)>, Vec<String>), CommonError> {
         debug!("get_s3_objects_by_keys - start");
         debug!("get_s3_objects_by_keys - bucket name: {}", &bucket_name);
         debug!("get_s3_objects_by_keys - path: {}", &path);
         debug!("get_s3_objects_by_keys - s3 keys: {:?}", &s3_keys);
 
-        match self
-            .get_s3_object_key_list(bucket_name.clone(), path.clone())
+        let (s3_keys_to_fetch, mut s3_object_key_not_found_list): (Vec<String>, Vec<String>) =
+            if s3_fetch_options.direct_key_lookup {
+                (s3_keys, Vec::new())
+            } else {
+                match self.list_s3_object_keys(bucket_name.clone(), path.clone(), s3_fetch_options.allow_oversize).await {
+                    Ok(s3_object_key_list) => s3_keys.into_iter().partition(|s3_key| s3_object_key_list.contains(s3_key)),
+                    Err(s3_object_key_list_error) => {
+                        error!("get_s3_objects_by_keys - s3 objects not found - error: {s3_object_key_list_error}");
+                        error!("get_s3_objects_by_keys - s3 objects not found - bucket name: {bucket_name}");
+                        error!("get_s3_objects_by_keys - s3 objects not found - path: {path}");
+                        return Err(s3_object_key_list_error);
+                    }
+                }
+            };
+
+        let client_s3 = self.create_bucket_client(&bucket_name).await;
+        let mut tokio_join_set = JoinSet::new();
+        let mut fetch_task_s3_keys = HashMap::new();
+        s3_keys_to_fetch.into_iter().for_each(|s3_key| {
+            let s3_object_content = get_s3_object_content(
+                client_s3.clone(),
+                bucket_name.clone(),
+                path.clone(),
+                s3_key.clone(),
+                s3_fetch_options.multipart_chunk_bytes,
+                FetchAttemptsInterceptor::default(),
+                None,
+            );
+            let fetch_task_s3_key = s3_key.clone();
+            let fetch_task = tokio_join_set.spawn(async move { s3_object_content.await.map_err(|fetch_error| (s3_key, fetch_error)) });
+            fetch_task_s3_keys.insert(fetch_task.id(), fetch_task_s3_key);
+        });
+
+        let mut s3_object_key_found_list = Vec::new();
+        while let Some(result) = tokio_join_set.join_next_with_id().await {
+            let s3_object = match result {
+                Ok((task_id, s3_object)) => {
+                    fetch_task_s3_keys.remove(&task_id);
+                    s3_object
+                }
+                Err(join_error) => {
+                    let s3_key = fetch_task_s3_keys.remove(&join_error.id()).unwrap_or_default();
+                    Err((s3_key.clone(), get_fetch_task_error(&s3_key, &join_error)))
+                }
+            };
+            match s3_object {
+                Ok(s3_object) => s3_object_key_found_list.push(s3_object),
+                Err((s3_key, CommonError::NO_DATA_FOUND)) => s3_object_key_not_found_list.push(s3_key),
+                Err((_, fetch_error)) => return Err(fetch_error),
+            }
+        }
+        if !s3_object_key_not_found_list.is_empty() {
+            warn!("get_s3_objects_by_keys - s3 keys not found: {s3_object_key_not_found_list:?}");
+        }
+
+        debug!("get_s3_objects_by_keys - done");
+        Ok((s3_object_key_found_list, s3_object_key_not_found_list))
+    }
+
+    /// Gets [Option<S3ObjectMetadata>] S3 object metadata by [String] bucket name, [String] path
+    /// and [Option<String>] s3 key (by HeadObject) or the first S3 object found under the path
+    /// if s3 key is not defined (by ListObjectsV2 with max keys = 1)
+    /// Returns [None] if S3 object (or S3 prefix) is not found
+    /// Returns a [CommonError] if S3 throws any other error
+    async fn get_s3_object_metadata(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: Option<String>,
+    ) -> Result<Option<S3ObjectMetadata>, CommonError> {
+        debug!("get_s3_object_metadata - start");
+        debug!("get_s3_object_metadata - bucket name: {}", &bucket_name);
+        debug!("get_s3_object_metadata - path: {}", &path);
+        debug!("get_s3_object_metadata - s3 key: {:?}", &s3_key);
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = sanitize_path(path.clone());
+
+        let Some(s3_key) = s3_key else {
+            return match aws_sdk_client
+                .list_objects_v2()
+                .bucket(&bucket_name)
+                .prefix(join_s3_key(&s3_prefix, ""))
+                .max_keys(1)
+                .send()
+                .await
+            {
+                Ok(s3_object_list) => {
+                    let s3_key_prefix = join_s3_key(&s3_prefix, "");
+                    let mut s3_object_metadata_list: Vec<S3ObjectMetadata> = s3_object_list
+                        .contents()
+                        .first()
+                        .and_then(|s3_object| create_s3_object_metadata(s3_object, &s3_key_prefix))
+                        .into_iter()
+                        .collect();
+                    self.add_pending_transitions(&aws_sdk_client, &bucket_name, &s3_key_prefix, &mut s3_object_metadata_list).await;
+                    debug!("get_s3_object_metadata - done");
+                    Ok(s3_object_metadata_list.pop())
+                }
+                Err(s3_object_error) => {
+                    error!("get_s3_object_metadata - s3 prefix error - error: {s3_object_error}");
+                    error!("get_s3_object_metadata - s3 prefix error - bucket name: {bucket_name}");
+                    error!("get_s3_object_metadata - s3 prefix error - path: {path}");
+                    Err(CommonError::AWS_ACCESS_ERROR)
+                }
+            };
+        };
+
+        match aws_sdk_client
+            .head_object()
+            .bucket(&bucket_name)
+            .key(join_s3_key(&s3_prefix, &s3_key))
+            .send()
             .await
         {
-            Ok(s3_object_key_list) => {
-                let client_s3 = self.aws_sdk_s3_client.create_aws_sdk_client().await;
+            Ok(s3_object) => {
+                let storage_class = s3_object.storage_class().map(|storage_class| String::from(storage_class.as_str()));
+                let mut s3_object_metadata_list = vec![S3ObjectMetadata {
+                    s3_key,
+                    size_bytes: s3_object.content_length().unwrap_or_default(),
+                    last_modified: s3_object
+                        .last_modified()
+                        .and_then(|last_modified| last_modified.fmt(DateTimeFormat::DateTime).ok()),
+                    e_tag: s3_object.e_tag().map(String::from),
+                    restore_required: is_restore_required(storage_class.as_deref()),
+                    storage_class,
+                    pending_transition: None,
+                    tags: None,
+                    user_metadata: s3_object.metadata().cloned(),
+                }];
+                self.add_pending_transitions(&aws_sdk_client, &bucket_name, &join_s3_key(&s3_prefix, ""), &mut s3_object_metadata_list).await;
+                debug!("get_s3_object_metadata - done");
+                Ok(s3_object_metadata_list.pop())
+            }
+            Err(s3_object_error) if s3_object_error.as_service_error().is_some_and(|error| error.is_not_found()) => {
+                debug!("get_s3_object_metadata - s3 object not found - s3 key: {s3_key}");
+                Ok(None)
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_metadata - s3 object error - error: {s3_object_error}");
+                error!("get_s3_object_metadata - s3 object error - bucket name: {bucket_name}");
+                error!("get_s3_object_metadata - s3 object error - path: {path}");
+                error!("get_s3_object_metadata - s3 object error - s3 key: {s3_key}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
 
-                let mut s3_object_key_found_list = Vec::new();
-                let mut s3_object_key_not_found_list = Vec::new();
-                let mut tokio_join_set = JoinSet::new();
+    /// Gets [(Vec<S3ObjectMetadata>, Option<String>)] one page of S3 objects metadata (all nested folders included)
+    /// + next continuation token by [String] bucket name, [String] path, [Option<i32>] max keys and
+    /// [Option<String>] continuation token (by ListObjectsV2)
+    /// Important: next continuation token is [None] once the last page is retrieved
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_metadata_page(
+        &self,
+        bucket_name: String,
+        path: String,
+        max_keys: Option<i32>,
+        continuation_token: Option<String>,
+    ) -> Result<(Vec<S3ObjectMetadata>, Option<String>), CommonError> {
+        debug!("get_s3_object_metadata_page - start");
+        debug!("get_s3_object_metadata_page - bucket name: {}", &bucket_name);
+        debug!("get_s3_object_metadata_page - path: {}", &path);
 
-                s3_object_key_list.iter().for_each(|s3_key| {
-                    if s3_keys.contains(s3_key) {
-                        tokio_join_set.spawn(get_s3_object_content(
-                            client_s3.clone(),
-                            bucket_name.clone(),
-                            path.clone(),
-                            s3_key.clone(),
-                        ));
-                    } else {
-                        warn!("get_s3_objects_by_keys - s3 key not found: {}", &s3_key);
-                        s3_object_key_not_found_list.push(s3_key.clone());
-                    }
-                });
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = join_s3_key(&sanitize_path(path.clone()), "");
 
-                while let Some(result) = tokio_join_set.join_next().await {
-                    s3_object_key_found_list.push(result.unwrap().unwrap_or_default());
+        match aws_sdk_client
+            .list_objects_v2()
+            .bucket(&bucket_name)
+            .prefix(&s3_prefix)
+            .set_max_keys(max_keys)
+            .set_continuation_token(continuation_token)
+            .send()
+            .await
+        {
+            Ok(s3_object_list) => {
+                let mut s3_object_metadata_list: Vec<S3ObjectMetadata> = s3_object_list
+                    .contents()
+                    .iter()
+                    .filter_map(|s3_object| create_s3_object_metadata(s3_object, &s3_prefix))
+                    .collect();
+                self.add_pending_transitions(&aws_sdk_client, &bucket_name, &s3_prefix, &mut s3_object_metadata_list).await;
+
+                debug!("get_s3_object_metadata_page - done");
+                Ok((s3_object_metadata_list, s3_object_list.next_continuation_token().map(String::from)))
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_metadata_page - s3 objects not found - error: {s3_object_error}");
+                error!("get_s3_object_metadata_page - s3 objects not found - bucket name: {bucket_name}");
+                error!("get_s3_object_metadata_page - s3 objects not found - path: {path}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Gets [BTreeMap<String, String>] S3 object tags by [String] bucket name, [String] path and [String] s3 key
+    /// (by GetObjectTagging)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_tags(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<BTreeMap<String, String>, CommonError> {
+        debug!("get_s3_object_tags - start");
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        match aws_sdk_client
+            .get_object_tagging()
+            .bucket(&bucket_name)
+            .key(join_s3_key(&sanitize_path(path.clone()), &s3_key))
+            .send()
+            .await
+        {
+            Ok(s3_object_tagging) => {
+                debug!("get_s3_object_tags - done");
+                Ok(s3_object_tagging.tag_set().iter().map(|tag| (String::from(tag.key()), String::from(tag.value()))).collect())
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_tags - s3 object tags error - error: {s3_object_error}");
+                error!("get_s3_object_tags - s3 object tags error - bucket name: {bucket_name}");
+                error!("get_s3_object_tags - s3 object tags error - s3 key: {s3_key}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Gets [Option<String>] stored full object SHA-256 checksum (base64 encoded) by [String] bucket name,
+    /// [String] path and [String] s3 key (by HeadObject with checksum mode enabled)
+    /// Returns [None] if S3 object has no stored SHA-256 checksum or it is a composite (multipart) checksum
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_object_checksum(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<Option<String>, CommonError> {
+        debug!("get_s3_object_checksum - start");
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        match aws_sdk_client
+            .head_object()
+            .bucket(&bucket_name)
+            .key(join_s3_key(&sanitize_path(path.clone()), &s3_key))
+            .checksum_mode(ChecksumMode::Enabled)
+            .send()
+            .await
+        {
+            Ok(s3_object_head) => {
+                debug!("get_s3_object_checksum - done");
+                Ok(s3_object_head
+                    .checksum_sha256()
+                    .filter(|checksum_sha256| !checksum_sha256.contains('-'))
+                    .map(String::from))
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_checksum - s3 object checksum error - error: {s3_object_error}");
+                error!("get_s3_object_checksum - s3 object checksum error - bucket name: {bucket_name}");
+                error!("get_s3_object_checksum - s3 object checksum error - s3 key: {s3_key}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Gets [(Vec<u8>, u64)] S3 object content range and S3 object total size by [String] bucket name, [String] path,
+    /// [String] s3 key and [String] HTTP range (i.e.: "bytes=0-1023" or "bytes=-1024" - by ranged GetObject)
+    /// Important: empty S3 objects (ranged request not satisfiable) are retrieved as empty content with zero size
+    /// and the content range is buffered (convenience method over [AwsSdkS3ServiceTrait::get_s3_object_stream])
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist or a [CommonError] if S3 throws any error
+    async fn get_s3_object_range(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        range: String,
+    ) -> Result<(Vec<u8>, u64), CommonError> {
+        debug!("get_s3_object_range - start - s3 key: {s3_key} - range: {range}");
+        let s3_object_stream_options = S3ObjectStreamOptions { range: Some(range), ..Default::default() };
+        let s3_object_stream = match self.get_s3_object_stream(bucket_name, path, s3_key.clone(), s3_object_stream_options).await {
+            Ok(s3_object_stream) => s3_object_stream,
+            Err(CommonError::RANGE_NOT_SATISFIABLE) => {
+                debug!("get_s3_object_range - empty s3 object (ranged request not satisfiable)");
+                return Ok((Vec::new(), 0));
+            }
+            Err(s3_object_error) => return Err(s3_object_error),
+        };
+
+        let content_total_size = s3_object_stream
+            .content_range
+            .as_deref()
+            .and_then(|content_range| content_range.rsplit_once('/'))
+            .and_then(|(_, content_total_size)| content_total_size.parse::<u64>().ok());
+        let mut content_as_vec = Vec::with_capacity(get_content_reserve_bytes(0, s3_object_stream.content_length, None));
+        if let Err(read_error) = append_s3_object_body(s3_object_stream.content, &mut content_as_vec).await {
+            error!("get_s3_object_range - s3 object range can't be read - s3 key: {s3_key} - error: {read_error}");
+            return Err(CommonError::AWS_ACCESS_ERROR);
+        }
+
+        debug!("get_s3_object_range - done - range bytes: {}", content_as_vec.len());
+        let content_total_size = content_total_size.unwrap_or(content_as_vec.len() as u64);
+        Ok((content_as_vec, content_total_size))
+    }
+
+    /// Gets [S3ObjectStream] S3 object content stream by [String] bucket name, [String] path, [String] s3 key and
+    /// [S3ObjectStreamOptions] HTTP range and conditional request headers (forwarded as they are to S3)
+    /// Important: S3 object content is not buffered (it is read from S3 as the stream is consumed) and a not modified
+    /// S3 object is retrieved without content (S3 key is not prefixed if path is empty - i.e.: S3 bucket root)
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist, a [CommonError::RANGE_NOT_SATISFIABLE] if HTTP range
+    /// is out of the S3 object size or a [CommonError] if S3 throws any error
+    async fn get_s3_object_stream(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        s3_object_stream_options: S3ObjectStreamOptions,
+    ) -> Result<S3ObjectStream, CommonError> {
+        debug!("get_s3_object_stream - start - s3 key: {s3_key} - range: {:?}", s3_object_stream_options.range);
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_object_key = join_s3_key(&sanitize_path(path), &s3_key);
+        match aws_sdk_client
+            .get_object()
+            .bucket(&bucket_name)
+            .key(s3_object_key)
+            .set_range(s3_object_stream_options.range)
+            .set_if_none_match(s3_object_stream_options.if_none_match)
+            .set_if_modified_since(s3_object_stream_options.if_modified_since)
+            .send()
+            .await
+        {
+            Ok(s3_object_content) => {
+                debug!("get_s3_object_stream - done - content range: {:?}", s3_object_content.content_range());
+                Ok(S3ObjectStream {
+                    not_modified: false,
+                    content_length: s3_object_content.content_length(),
+                    content_range: s3_object_content.content_range().map(String::from),
+                    content_type: s3_object_content.content_type().map(String::from),
+                    e_tag: s3_object_content.e_tag().map(String::from),
+                    last_modified: s3_object_content.last_modified().cloned(),
+                    content: s3_object_content.body,
+                })
+            }
+            Err(s3_object_error)
+                if s3_object_error.raw_response().is_some_and(|raw_response| raw_response.status().as_u16() == 304) =>
+            {
+                debug!("get_s3_object_stream - s3 object not modified - s3 key: {s3_key}");
+                let raw_response_header = |header_name: &str| {
+                    s3_object_error.raw_response().and_then(|raw_response| raw_response.headers().get(header_name)).map(String::from)
+                };
+                Ok(S3ObjectStream {
+                    not_modified: true,
+                    content: ByteStream::from_static(&[]),
+                    content_length: None,
+                    content_range: None,
+                    content_type: None,
+                    e_tag: raw_response_header("ETag"),
+                    last_modified: raw_response_header("Last-Modified")
+                        .and_then(|last_modified| DateTime::from_str(&last_modified, DateTimeFormat::HttpDate).ok()),
+                })
+            }
+            Err(s3_object_error) if s3_object_error.code() == Some("InvalidRange") => {
+                debug!("get_s3_object_stream - ranged request not satisfiable - s3 key: {s3_key}");
+                Err(CommonError::RANGE_NOT_SATISFIABLE)
+            }
+            Err(s3_object_error)
+                if s3_object_error.code() == Some("NoSuchKey")
+                    || s3_object_error.raw_response().is_some_and(|raw_response| raw_response.status().as_u16() == 404) =>
+            {
+                warn!("get_s3_object_stream - s3 key not found - bucket name: {bucket_name} - s3 key: {s3_key}");
+                Err(CommonError::NO_DATA_FOUND)
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_stream - s3 object stream error - error: {s3_object_error}");
+                error!("get_s3_object_stream - s3 object stream error - bucket name: {bucket_name}");
+                error!("get_s3_object_stream - s3 object stream error - s3 key: {s3_key}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Gets [(String, ByteStream, Option<i64>)] S3 key value, whole S3 object content stream and content length
+    /// by [String] bucket name, [String] path and [String] s3 key (please, check [AwsSdkS3ServiceTrait::get_s3_object_stream])
+    /// Important: S3 object content is not buffered (it is read from S3 as the stream is consumed)
+    /// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist or a [CommonError] if S3 throws any error
+    async fn get_s3_object_as_stream(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<(String, ByteStream, Option<i64>), CommonError> {
+        let s3_object_stream = self
+            .get_s3_object_stream(bucket_name, path, s3_key.clone(), S3ObjectStreamOptions::default())
+            .await?;
+        Ok((s3_key, s3_object_stream.content, s3_object_stream.content_length))
+    }
+
+    /// Gets [(Vec<S3ObjectMetadata>, Vec<String>)] S3 objects metadata directly under the S3 path + first-level
+    /// sub-folder names (S3 common prefixes) by [String] bucket name and [String] path (by ListObjectsV2 with "/" delimiter)
+    /// Important: all S3 listing pages are requested
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_first_level_entries(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<(Vec<S3ObjectMetadata>, Vec<String>), CommonError> {
+        debug!("get_s3_first_level_entries - start");
+        debug!("get_s3_first_level_entries - bucket name: {}", &bucket_name);
+        debug!("get_s3_first_level_entries - path: {}", &path);
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = join_s3_key(&sanitize_path(path.clone()), "");
+        let mut s3_listing_pages = aws_sdk_client
+            .list_objects_v2()
+            .bucket(&bucket_name)
+            .prefix(&s3_prefix)
+            .delimiter("/")
+            .into_paginator()
+            .send();
+
+        let mut s3_object_metadata_list = Vec::new();
+        let mut s3_sub_folders = Vec::new();
+        while let Some(s3_listing_page) = s3_listing_pages.next().await {
+            let s3_object_list = match s3_listing_page {
+                Ok(s3_object_list) => s3_object_list,
+                Err(s3_object_error) => {
+                    error!("get_s3_first_level_entries - s3 objects not found - error: {s3_object_error}");
+                    error!("get_s3_first_level_entries - s3 objects not found - bucket name: {bucket_name}");
+                    error!("get_s3_first_level_entries - s3 objects not found - path: {path}");
+                    return Err(CommonError::AWS_ACCESS_ERROR);
                 }
+            };
+            s3_object_metadata_list.extend(
+                s3_object_list
+                    .contents()
+                    .iter()
+                    .filter_map(|s3_object| create_s3_object_metadata(s3_object, &s3_prefix)),
+            );
+            s3_sub_folders.extend(
+                s3_object_list
+                    .common_prefixes()
+                    .iter()
+                    .filter_map(|common_prefix| common_prefix.prefix())
+                    .filter_map(|common_prefix| common_prefix.strip_prefix(&s3_prefix))
+                    .map(|sub_folder| String::from(sub_folder.trim_end_matches('/'))),
+            );
+        }
+
+        debug!("get_s3_first_level_entries - done - sub-folders: {}", s3_sub_folders.len());
+        Ok((s3_object_metadata_list, s3_sub_folders))
+    }
 
-                debug!("get_s3_objects_by_keys - done");
-                Ok((s3_object_key_found_list, s3_object_key_not_found_list))
+    /// Gets [Vec<(String, S3RestoreStatus)>] archived S3 objects (S3 keys + restore status) directly under [String] path
+    /// of [String] bucket name (archive storage classes - by ListObjectsV2 with restore status attribute)
+    /// Important: all S3 listing pages are requested
+    /// Returns a [CommonError] if S3 throws any error
+    async fn get_s3_archived_objects(
+        &self,
+        bucket_name: String,
+        path: String,
+    ) -> Result<Vec<(String, S3RestoreStatus)>, CommonError> {
+        debug!("get_s3_archived_objects - start");
+        debug!("get_s3_archived_objects - bucket name: {}", &bucket_name);
+        debug!("get_s3_archived_objects - path: {}", &path);
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = join_s3_key(&sanitize_path(path.clone()), "");
+        let mut s3_listing_pages = aws_sdk_client
+            .list_objects_v2()
+            .bucket(&bucket_name)
+            .prefix(&s3_prefix)
+            .delimiter("/")
+            .optional_object_attributes(OptionalObjectAttributes::RestoreStatus)
+            .into_paginator()
+            .send();
+
+        let mut s3_archived_objects = Vec::new();
+        while let Some(s3_listing_page) = s3_listing_pages.next().await {
+            let s3_object_list = match s3_listing_page {
+                Ok(s3_object_list) => s3_object_list,
+                Err(s3_object_error) => {
+                    error!("get_s3_archived_objects - s3 objects not found - error: {s3_object_error}");
+                    error!("get_s3_archived_objects - s3 objects not found - bucket name: {bucket_name}");
+                    error!("get_s3_archived_objects - s3 objects not found - path: {path}");
+                    return Err(CommonError::AWS_ACCESS_ERROR);
+                }
+            };
+            s3_archived_objects.extend(
+                s3_object_list
+                    .contents()
+                    .iter()
+                    .filter(|s3_object| is_restore_required(s3_object.storage_class().map(|storage_class| storage_class.as_str())))
+                    .filter_map(|s3_object| {
+                        let s3_key = s3_object.key()?.strip_prefix(&s3_prefix)?;
+                        Some((String::from(s3_key), get_s3_restore_status(s3_object.restore_status())))
+                    }),
+            );
+        }
+
+        debug!("get_s3_archived_objects - done - archived s3 objects: {}", s3_archived_objects.len());
+        Ok(s3_archived_objects)
+    }
+
+    /// Requests archived S3 object restore by [String] bucket name, [String] path, [String] s3 key,
+    /// [i32] restored copy lifetime (in days) and [S3RestoreTier] restore tier
+    /// Important: restores already in progress are not requested again (no error is returned)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn restore_s3_object(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+        restore_days: i32,
+        restore_tier: S3RestoreTier,
+    ) -> Result<(), CommonError> {
+        debug!("restore_s3_object - start - s3 key: {s3_key}");
+        let tier = match restore_tier {
+            S3RestoreTier::EXPEDITED => Tier::Expedited,
+            S3RestoreTier::STANDARD => Tier::Standard,
+            S3RestoreTier::BULK => Tier::Bulk,
+        };
+        let glacier_job_parameters = GlacierJobParameters::builder()
+            .tier(tier)
+            .build()
+            .map_err(|_| CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        match aws_sdk_client
+            .restore_object()
+            .bucket(&bucket_name)
+            .key(join_s3_key(&sanitize_path(path), &s3_key))
+            .restore_request(S3RestoreRequest::builder().days(restore_days).glacier_job_parameters(glacier_job_parameters).build())
+            .send()
+            .await
+        {
+            Ok(_) => {
+                debug!("restore_s3_object - done - s3 key: {s3_key}");
+                Ok(())
             }
-            Err(s3_object_key_list_error) => {
-                error!("get_s3_objects_by_keys - s3 objects not found - error: {s3_object_key_list_error}");
-                error!("get_s3_objects_by_keys - s3 objects not found - bucket name: {bucket_name}");
-                error!("get_s3_objects_by_keys - s3 objects not found - path: {path}");
-                debug!("get_s3_objects_by_keys - s3 objects not found - s3 keys: {s3_keys:?}");
+            Err(restore_error) if restore_error.code() == Some("RestoreAlreadyInProgress") => {
+                debug!("restore_s3_object - restore already in progress - s3 key: {s3_key}");
+                Ok(())
+            }
+            Err(restore_error) => {
+                error!("restore_s3_object - restore error - error: {restore_error}");
+                error!("restore_s3_object - restore error - bucket name: {bucket_name}");
+                error!("restore_s3_object - restore error - s3 key: {s3_key}");
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Removes S3 object by [String] bucket name, [String] path and [String] s3 key (by DeleteObject)
+    /// Returns a [CommonError::OBJECT_LOCKED] if S3 object is protected by Object Lock (not removed)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn remove_s3_object(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key: String,
+    ) -> Result<(), CommonError> {
+        debug!("remove_s3_object - start - s3 key: {s3_key}");
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let s3_prefix = sanitize_path(path);
+        if let Some(object_lock_reason) = self.get_object_lock_reason(&aws_sdk_client, &bucket_name, &join_s3_key(&s3_prefix, &s3_key)).await {
+            warn!("remove_s3_object - s3 object locked (not removed) - s3 key: {s3_key} - lock: {object_lock_reason}");
+            return Err(CommonError::OBJECT_LOCKED);
+        }
+
+        match aws_sdk_client
+            .delete_object()
+            .bucket(&bucket_name)
+            .key(join_s3_key(&s3_prefix, &s3_key))
+            .send()
+            .await
+        {
+            Ok(_) => {
+                LISTING_CACHE.invalidate(&bucket_name, Some(&s3_prefix)).await;
+                debug!("remove_s3_object - done - s3 key: {s3_key}");
+                Ok(())
+            }
+            Err(remove_error) => {
+                error!("remove_s3_object - remove error - error: {remove_error}");
+                error!("remove_s3_object - remove error - bucket name: {bucket_name}");
+                error!("remove_s3_object - remove error - s3 key: {s3_key}");
                 Err(CommonError::AWS_ACCESS_ERROR)
             }
         }
     }
 }
 
+/// AWS SDK S3 service internal logic
+impl AwsSdkS3Service {
+    /// Gets [Option<String>] Object Lock reason (legal hold or retention mode and date) if [&str] S3 object key of
+    /// [&str] S3 bucket name is protected by Object Lock by [&Client] AWS SDK client (by HeadObject)
+    /// Returns [None] if S3 object doesn't exist, it is not locked or its Object Lock status can't be retrieved
+    async fn get_object_lock_reason(&self, aws_sdk_client: &Client, bucket_name: &str, s3_object_key: &str) -> Option<String> {
+        let s3_object = aws_sdk_client.head_object().bucket(bucket_name).key(s3_object_key).send().await.ok()?;
+        get_object_lock_reason(
+            s3_object.object_lock_legal_hold_status(),
+            s3_object.object_lock_mode(),
+            s3_object.object_lock_retain_until_date(),
+            current_epoch_seconds() as i64,
+        )
+    }
+
+    /// Creates a new [Client] AWS SDK client by [&str] S3 bucket name (discovered bucket region client, if any)
+    async fn create_bucket_client(&self, bucket_name: &str) -> Client {
+        self.aws_sdk_s3_client.create_aws_sdk_regional_client(BUCKET_REGIONS.get(bucket_name)).await
+    }
+
+    /// Discovers [&str] S3 bucket region after a failed request by [&Client] AWS SDK client and [Option<&HttpResponse>]
+    /// failed request raw response (S3 region redirects define `x-amz-bucket-region` header, otherwise bucket region is
+    /// requested by a `HeadBucket` request)
+    /// Returns [Option<Client>] bucket region AWS SDK client just if bucket region is not the client region (failed
+    /// request should be retried with it) - discovered bucket region is cached
+    async fn discover_bucket_client(&self, aws_sdk_client: &Client, bucket_name: &str, raw_response: Option<&HttpResponse>) -> Option<Client> {
+        if BUCKET_REGIONS.get(bucket_name).is_some() {
+            return None;
+        }
+
+        let get_bucket_region_header =
+            |raw_response: &HttpResponse| raw_response.headers().get(AWS_S3_BUCKET_REGION_HEADER).map(String::from);
+        let bucket_region = match raw_response.and_then(get_bucket_region_header) {
+            Some(bucket_region) => Some(bucket_region),
+            None => match aws_sdk_client.head_bucket().bucket(bucket_name).send().await {
+                Ok(head_bucket_output) => head_bucket_output.bucket_region().map(String::from),
+                Err(head_bucket_error) => head_bucket_error.raw_response().and_then(get_bucket_region_header),
+            },
+        };
+        let client_region = aws_sdk_client.config().region().map(ToString::to_string);
+        let bucket_region = get_other_bucket_region(client_region.as_deref(), bucket_region.as_deref())?;
+
+        info!("discover_bucket_client - bucket region discovered - bucket name: {bucket_name} - region: {bucket_region}");
+        BUCKET_REGIONS.put(bucket_name, &bucket_region);
+        Some(self.aws_sdk_s3_client.create_aws_sdk_regional_client(Some(bucket_region)).await)
+    }
+
+    /// Adds pending storage class transitions (derived from [&str] S3 bucket lifecycle rules) to
+    /// [&mut [S3ObjectMetadata]] S3 objects metadata (S3 keys relative to [&str] S3 key prefix) by [&Client] AWS SDK client
+    /// Important: nothing is added if lifecycle transitions are not enabled or lifecycle rules can't be requested
+    async fn add_pending_transitions(
+        &self,
+        aws_sdk_client: &Client,
+        bucket_name: &str,
+        s3_key_prefix: &str,
+        s3_object_metadata_list: &mut [S3ObjectMetadata],
+    ) {
+        if !self.aws_sdk_s3_lifecycle_transitions_enabled || s3_object_metadata_list.is_empty() {
+            return;
+        }
+
+        let lifecycle_transition_rules = match aws_sdk_client.get_bucket_lifecycle_configuration().bucket(bucket_name).send().await {
+            Ok(lifecycle_configuration) => create_lifecycle_transition_rules(lifecycle_configuration.rules()),
+            Err(lifecycle_error) => {
+                debug!("add_pending_transitions - no lifecycle rules - bucket name: {bucket_name} - error: {:?}", lifecycle_error.code());
+                return;
+            }
+        };
+        let now_epoch_seconds = current_epoch_seconds() as i64;
+        for s3_object_metadata in s3_object_metadata_list.iter_mut() {
+            s3_object_metadata.pending_transition =
+                get_pending_transition(&lifecycle_transition_rules, s3_key_prefix, s3_object_metadata, now_epoch_seconds);
+        }
+    }
+
+    /// Gets [Vec<String>] S3 key list by [String] bucket name, [String] path and [bool] allow oversize flag
+    /// (S3 objects greater than the configured maximum file size are excluded unless oversize files are allowed)
+    /// Important: S3 key lists are cached for a short TTL, but oversize listings are not cached (please, check constants.rs)
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if S3 key list is greater than configured maximum file quantity
+    /// Returns a [CommonError] if result is empty or S3 throws any error
+    async fn list_s3_object_keys(
+        &self,
+        bucket_name: String,
+        path: String,
+        allow_oversize: bool,
+    ) -> Result<Vec<String>, CommonError> {
+        // listings of requests with caller credentials are not cached (S3 permissions should be checked for each caller)
+        let listing_cache_enabled = !allow_oversize && get_caller_credentials().is_none() && get_caller_session_tags().is_empty();
+        if listing_cache_enabled
+            && let Some(s3_object_key_list) = LISTING_CACHE.get(&bucket_name, &sanitize_path(path.clone())).await
+        {
+            debug!("list_s3_object_keys - done (cached)");
+            return Ok(s3_object_key_list);
+        }
+
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let list_s3_objects = |aws_sdk_client: Client| {
+            let list_objects_request = aws_sdk_client.list_objects().bucket(&bucket_name).prefix(sanitize_path(path.clone()));
+            list_objects_request.send()
+        };
+        let mut s3_object_list_result = list_s3_objects(aws_sdk_client.clone()).await;
+        if let Err(s3_object_error) = &s3_object_list_result
+            && let Some(regional_aws_sdk_client) =
+                self.discover_bucket_client(&aws_sdk_client, &bucket_name, s3_object_error.raw_response()).await
+        {
+            s3_object_list_result = list_s3_objects(regional_aws_sdk_client).await;
+        }
+
+        match s3_object_list_result {
+            Ok(s3_object_list) => {
+                let s3_object_key_list: Vec<String> = s3_object_list
+                    .contents
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|s3_object| s3_object.key.is_some())
+                    .filter(|s3_object| allow_oversize || s3_object.size.unwrap_or_default() < self.aws_sdk_s3_max_file_size)
+                    .map(|s3_object| s3_object.key.clone().unwrap_or_default())
+                    .map(|s3_key| {
+                        String::from(
+                            s3_key
+                                .strip_prefix(&join_s3_key(&sanitize_path(path.clone()), ""))
+                                .unwrap_or(&s3_key),
+                        )
+                    })
+                    .filter(|s3_key| !s3_key.contains("/"))
+                    .collect();
+
+                if s3_object_key_list.len() > self.aws_sdk_s3_max_file_qty {
+                    error!(
+                        "list_s3_object_keys - s3 object key list is greater than configured maximum file quantity - bucket name: {bucket_name}"
+                    );
+                    error!(
+                        "list_s3_object_keys - s3 object key list is greater than configured maximum file quantity - path: {path}"
+                    );
+                    return Err(CommonError::LIMIT_EXCEEDED {
+                        limit: self.aws_sdk_s3_max_file_qty,
+                        actual: s3_object_key_list.len(),
+                    });
+                }
+
+                if listing_cache_enabled {
+                    LISTING_CACHE.put(&bucket_name, &sanitize_path(path), &s3_object_key_list).await;
+                }
+                debug!("list_s3_object_keys - done");
+                Ok(s3_object_key_list)
+            }
+            Err(s3_object_error) => {
+                error!("list_s3_object_keys - s3 object key list not found - error: {s3_object_error}");
+                error!(
+                    "list_s3_object_keys - s3 object key list not found - bucket name: {bucket_name}"
+                );
+                error!(
+                    "list_s3_object_keys - s3 object key list not found - path: {path}"
+                );
+                Err(CommonError::AWS_ACCESS_ERROR)
+            }
+        }
+    }
+
+    /// Gets a [Receiver<S3Object>] that yields S3 objects (keys and contents) by [String] bucket name,
+    /// [String] path, [Vec<String>] S3 key list and [S3FetchOptions] fetch options in S3 key list order
+    /// Important: requested fetch options are limited by server configuration (please, check constants.rs)
+    async fn create_s3_object_channel(
+        &self,
+        bucket_name: String,
+        path: String,
+        s3_key_list: Vec<String>,
+        s3_fetch_options: S3FetchOptions,
+    ) -> Receiver<S3Object> {
+        let aws_sdk_client = self.create_bucket_client(&bucket_name).await;
+        let (s3_object_sender, s3_object_receiver) = channel(AWS_S3_OBJECT_CHANNEL_CAPACITY);
+
+        let fetch_window_size = s3_fetch_options
+            .max_parallel_fetches
+            .map_or(self.aws_sdk_s3_fetch_window_size, |max_parallel_fetches| {
+                max_parallel_fetches.clamp(1, self.aws_sdk_s3_fetch_window_size)
+            });
+        let multipart_chunk_bytes = s3_fetch_options
+            .multipart_chunk_bytes
+            .map(|multipart_chunk_bytes| {
+                multipart_chunk_bytes.clamp(AWS_S3_MULTIPART_CHUNK_BYTES_MIN, self.aws_sdk_s3_max_multipart_chunk_bytes)
+            });
+        debug!("create_s3_object_channel - fetch window size: {fetch_window_size}");
+        let stream_min_bytes = s3_fetch_options.stream_content.then_some(self.aws_sdk_s3_stream_min_bytes);
+        debug!("create_s3_object_channel - multipart chunk bytes: {multipart_chunk_bytes:?}");
+        debug!("create_s3_object_channel - stream min bytes: {stream_min_bytes:?}");
+
+        tokio::spawn(send_s3_objects_in_order(
+            aws_sdk_client,
+            bucket_name,
+            path,
+            s3_key_list,
+            (fetch_window_size, multipart_chunk_bytes, stream_min_bytes),
+            s3_object_sender,
+            s3_fetch_options.cancellation_token,
+        ));
+        s3_object_receiver
+    }
+}
+
 /// Gets [(String, Vec<u8>)] S3 key value and stream content by [Client] AWS SDK client,
-/// [String] bucket name, [String] path and [String] s3 key
-/// Returns a [CommonError] if result is empty or S3 throws any error
+/// [String] bucket name, [String] path, [String] s3 key, [Option<u64>] multipart chunk bytes
+/// and [FetchAttemptsInterceptor] fetch attempts interceptor (it counts all requests attempts) - the expected S3 object
+/// size is reserved into [Option<&mut MemoryReservation>] memory reservation (if any) once the response headers are
+/// received, before its body is read (content length or ranged responses total size)
+/// Important: whole S3 object contents are buffered (please, check [fetch_s3_object_content])
+/// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist (i.e.: deleted after listing) or a [CommonError]
+/// if S3 throws any other error
 async fn get_s3_object_content(
     aws_sdk_client: Client,
     bucket_name: String,
     path: String,
     s3_key: String,
+    multipart_chunk_bytes: Option<u64>,
+    fetch_attempts_interceptor: FetchAttemptsInterceptor,
+    memory_reservation: Option<&mut MemoryReservation>,
 ) -> Result<(String, Vec<u8>), CommonError> {
+    let (s3_key, content_as_vec, _) = fetch_s3_object_content(
+        aws_sdk_client,
+        bucket_name,
+        path,
+        s3_key,
+        (multipart_chunk_bytes, None),
+        fetch_attempts_interceptor,
+        memory_reservation,
+    )
+    .await?;
+    Ok((s3_key, content_as_vec))
+}
+
+/// Gets [(String, Vec<u8>, Option<ByteStream>)] S3 key value, buffered content and content stream by [Client] AWS SDK
+/// client, [String] bucket name, [String] path, [String] s3 key, [(Option<u64>, Option<u64>)] multipart chunk bytes and
+/// stream min bytes and [FetchAttemptsInterceptor] fetch attempts interceptor (it counts all requests attempts) - the
+/// expected S3 object size is reserved into [Option<&mut MemoryReservation>] memory reservation (if any) once the
+/// response headers are received, before its body is read (content length or ranged responses total size)
+/// Important: if multipart chunk bytes is defined, content is downloaded by sequential ranged requests
+/// Important: single requests are conditional (If-None-Match) if the S3 object is cached, so cached content is reused
+/// on 304 (not modified) responses
+/// Important: if stream min bytes is defined, single responses not smaller than it are retrieved as content stream
+/// (empty buffered content - nothing is reserved from the memory budget and the S3 object body is read by the consumer)
+/// Returns a [CommonError::NO_DATA_FOUND] if S3 key doesn't exist (i.e.: deleted after listing) or a [CommonError]
+/// if S3 throws any other error
+async fn fetch_s3_object_content(
+    aws_sdk_client: Client,
+    bucket_name: String,
+    path: String,
+    s3_key: String,
+    (multipart_chunk_bytes, stream_min_bytes): (Option<u64>, Option<u64>),
+    fetch_attempts_interceptor: FetchAttemptsInterceptor,
+    mut memory_reservation: Option<&mut MemoryReservation>,
+) -> Result<(String, Vec<u8>, Option<ByteStream>), CommonError> {
     debug!("get_s3_object_content - start");
+    let s3_object_key = join_s3_key(&sanitize_path(path.clone()), &s3_key);
+    let mut content_as_vec = Vec::new();
+    let mut cached_e_tag = multipart_chunk_bytes
+        .is_none()
+        .then(|| OBJECT_BODY_CACHE.get_e_tag(&bucket_name, &s3_object_key))
+        .flatten();
+
+    loop {
+        let range_start = content_as_vec.len() as u64;
+        let range = multipart_chunk_bytes
+            .map(|multipart_chunk_bytes| format!("bytes={}-{}", range_start, range_start + multipart_chunk_bytes - 1));
+
+        match aws_sdk_client
+            .get_object()
+            .bucket(bucket_name.clone())
+            .key(&s3_object_key)
+            .set_range(range)
+            .set_if_none_match(cached_e_tag.clone())
+            .customize()
+            .interceptor(fetch_attempts_interceptor.clone())
+            .send()
+            .await
+        {
+            Ok(s3_object_content) => {
+                debug!("get_s3_object_content - s3 key found");
+                let content_total_size = s3_object_content
+                    .content_range()
+                    .and_then(|content_range| content_range.rsplit_once('/'))
+                    .and_then(|(_, content_total_size)| content_total_size.parse::<u64>().ok());
+                let e_tag = s3_object_content.e_tag().map(String::from);
+                if multipart_chunk_bytes.is_none()
+                    && let Some(content_length) = s3_object_content.content_length()
+                    && stream_min_bytes.is_some_and(|stream_min_bytes| content_length.max(0) as u64 >= stream_min_bytes)
+                {
+                    debug!("get_s3_object_content - done - s3 object content streamed (not buffered) - content length: {content_length}");
+                    return Ok((s3_key, Vec::new(), Some(s3_object_content.body)));
+                }
+                let content_reserve_bytes =
+                    get_content_reserve_bytes(content_as_vec.len(), s3_object_content.content_length(), content_total_size);
+                if let Some(memory_reservation) = memory_reservation.as_mut() {
+                    memory_reservation.resize((content_as_vec.len() + content_reserve_bytes) as u64);
+                }
+                content_as_vec.reserve(content_reserve_bytes);
+                if let Err(read_error) = append_s3_object_body(s3_object_content.body, &mut content_as_vec).await {
+                    error!("get_s3_object_content - s3 object body can't be read - s3 key: {s3_key} - error: {read_error}");
+                    return Err(CommonError::AWS_ACCESS_ERROR);
+                }
+
+                if multipart_chunk_bytes.is_none()
+                    && let Some(e_tag) = e_tag
+                {
+                    OBJECT_BODY_CACHE.put(&bucket_name, &s3_object_key, &e_tag, &content_as_vec);
+                }
+                if multipart_chunk_bytes.is_none()
+                    || content_as_vec.len() as u64 == range_start
+                    || content_total_size.is_none_or(|content_total_size| content_as_vec.len() as u64 >= content_total_size)
+                {
+                    break;
+                }
+            }
+            Err(s3_object_error)
+                if s3_object_error.raw_response().is_some_and(|raw_response| raw_response.status().as_u16() == 304) =>
+            {
+                let Some(cached_content) = cached_e_tag
+                    .as_deref()
+                    .and_then(|cached_e_tag| OBJECT_BODY_CACHE.get_body(&bucket_name, &s3_object_key, cached_e_tag))
+                else {
+                    debug!("get_s3_object_content - cached content evicted (s3 object requested again)");
+                    cached_e_tag = None;
+                    continue;
+                };
+                debug!("get_s3_object_content - s3 object not modified (cached content reused)");
+                if let Some(memory_reservation) = memory_reservation.as_mut() {
+                    memory_reservation.resize(cached_content.len() as u64);
+                }
+                content_as_vec = cached_content;
+                break;
+            }
+            Err(s3_object_error) if range_start == 0 && s3_object_error.code() == Some("InvalidRange") => {
+                debug!("get_s3_object_content - empty s3 object (ranged request not satisfiable)");
+                break;
+            }
+            Err(s3_object_error)
+                if s3_object_error.code() == Some("NoSuchKey")
+                    || s3_object_error.raw_response().is_some_and(|raw_response| raw_response.status().as_u16() == 404) =>
+            {
+                warn!("get_s3_object_content - s3 key deleted after listing - bucket name: {bucket_name} - s3 key: {s3_key}");
+                return Err(CommonError::NO_DATA_FOUND);
+            }
+            Err(s3_object_error) => {
+                error!("get_s3_object_content - s3 object not found - error: {s3_object_error}",);
+                error!("get_s3_object_content - s3 object not found - bucket name: {bucket_name}");
+                error!("get_s3_object_content - s3 object not found - path: {path}");
+                error!("get_s3_object_content - s3 object not found - s3 key: {s3_key}");
+                return Err(CommonError::AWS_ACCESS_ERROR);
+            }
+        }
+    }
+
+    debug!("get_s3_object_content - done");
+    Ok((s3_key, content_as_vec, None))
+}
+
+/// Appends [ByteStream] S3 object body into [&mut Vec<u8>] content (body segments are collected and copied once)
+/// Returns a [ByteStreamError] if the body can't be read
+async fn append_s3_object_body(s3_object_body: ByteStream, content_as_vec: &mut Vec<u8>) -> Result<(), ByteStreamError> {
+    s3_object_body
+        .collect()
+        .await?
+        .into_segments()
+        .for_each(|body_segment| content_as_vec.extend_from_slice(&body_segment));
+    Ok(())
+}
+
+/// Gets [usize] bytes to be reserved in the content buffer before reading a S3 object body by [usize] already received bytes,
+/// [Option<i64>] response content length and [Option<u64>] S3 object total size (from ranged responses - the whole
+/// S3 object is reserved by the first ranged response, so the buffer is not reallocated by the next ones)
+fn get_content_reserve_bytes(received_bytes: usize, content_length: Option<i64>, content_total_size: Option<u64>) -> usize {
+    content_total_size
+        .map(|content_total_size| content_total_size.saturating_sub(received_bytes as u64))
+        .or(content_length.map(|content_length| content_length.max(0) as u64))
+        .unwrap_or_default() as usize
+}
+
+/// Sends [S3Object] S3 keys and contents by [Client] AWS SDK client, [String] bucket name,
+/// [String] path, [Vec<String>] S3 key list and [(usize, Option<u64>, Option<u64>)] fetch window size, multipart
+/// chunk bytes and stream min bytes into [Sender<S3Object>] S3 object sender keeping the S3 key list order
+/// Important:
+/// - up to fetch window size downloads are in progress (or completed and buffered waiting for previous S3 objects) at
+///   the same time - pending downloads are aborted once [CancellationToken] cancellation token is cancelled (i.e.: the
+///   client disconnected)
+/// - each S3 object size is reserved from the memory budget before its body is read (released once the S3 object is
+///   written) and no more downloads are started while the memory budget is exceeded (but the first one, so the export
+///   always makes progress)
+async fn send_s3_objects_in_order(
+    aws_sdk_client: Client,
+    bucket_name: String,
+    path: String,
+    s3_key_list: Vec<String>,
+    (fetch_window_size, multipart_chunk_bytes, stream_min_bytes): (usize, Option<u64>, Option<u64>),
+    s3_object_sender: Sender<S3Object>,
+    cancellation_token: CancellationToken,
+) {
+    debug!("send_s3_objects_in_order - start");
+    let mut tokio_join_set = JoinSet::new();
+    let mut fetch_task_s3_keys = HashMap::new();
+    let mut s3_objects_completed = BTreeMap::new();
+    let mut s3_key_iterator = s3_key_list.into_iter().enumerate();
+    let mut next_s3_object_index = 0;
 
-    match aws_sdk_client
-        .get_object()
-        .bucket(bucket_name.clone())
-        .key(format!(
-            "{}/{}",
-            sanitize_path(path.clone()),
-            s3_key.clone()
-        ))
-        .send()
-        .await
-    {
-        Ok(s3_object_content) => {
-            debug!("get_s3_object_content - s3 key found");
-            let mut content_as_vec = Vec::new();
-            let _ = s3_object_content
-                .body
-                .into_async_read()
-                .read_to_end(&mut content_as_vec)
-                .await;
-
-            debug!("get_s3_object_content - done");
-            Ok((s3_key, content_as_vec))
-        }
-        Err(s3_object_error) => {
-            error!("get_s3_object_content - s3 object not found - error: {s3_object_error}",);
-            error!("get_s3_object_content - s3 object not found - bucket name: {bucket_name}");
-            error!("get_s3_object_content - s3 object not found - path: {path}");
-            error!("get_s3_object_content - s3 object not found - s3 key: {s3_key}");
-            Err(CommonError::AWS_ACCESS_ERROR)
+    loop {
+        while tokio_join_set.len() + s3_objects_completed.len() < fetch_window_size
+            && (tokio_join_set.is_empty() && s3_objects_completed.is_empty() || !MEMORY_BUDGET.is_exceeded())
+        {
+            let Some((s3_object_index, s3_key)) = s3_key_iterator.next() else {
+                break;
+            };
+            let (aws_sdk_client, bucket_name, path) = (aws_sdk_client.clone(), bucket_name.clone(), path.clone());
+            let fetch_task_s3_key = (s3_object_index, s3_key.clone());
+            let fetch_task = tokio_join_set.spawn(async move {
+                let fetch_started_at = Instant::now();
+                let fetch_attempts_interceptor = FetchAttemptsInterceptor::default();
+                let mut memory_reservation = MEMORY_BUDGET.reserve(0);
+                let s3_object_content = fetch_s3_object_content(
+                    aws_sdk_client,
+                    bucket_name,
+                    path,
+                    s3_key.clone(),
+                    (multipart_chunk_bytes, stream_min_bytes),
+                    fetch_attempts_interceptor.clone(),
+                    Some(&mut memory_reservation),
+                )
+                .await
+                .map_err(|fetch_error| (s3_key, fetch_error));
+                let fetch_latency_millis = fetch_started_at.elapsed().as_millis() as u64;
+                (s3_object_index, s3_object_content, fetch_latency_millis, fetch_attempts_interceptor.retries(), memory_reservation)
+            });
+            fetch_task_s3_keys.insert(fetch_task.id(), fetch_task_s3_key);
         }
+
+        let next_result = tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                warn!("send_s3_objects_in_order - export cancelled - pending downloads aborted");
+                tokio_join_set.abort_all();
+                return;
+            }
+            next_result = tokio_join_set.join_next_with_id() => next_result,
+        };
+        let Some(result) = next_result else {
+            break;
+        };
+        let (s3_object_index, s3_object, fetch_latency_millis, fetch_retries, mut memory_reservation) = match result {
+            Ok((task_id, fetch_result)) => {
+                fetch_task_s3_keys.remove(&task_id);
+                fetch_result
+            }
+            Err(join_error) => {
+                let Some((s3_object_index, s3_key)) = fetch_task_s3_keys.remove(&join_error.id()) else {
+                    continue;
+                };
+                let fetch_error = get_fetch_task_error(&s3_key, &join_error);
+                (s3_object_index, Err((s3_key, fetch_error)), 0, 0, MEMORY_BUDGET.reserve(0))
+            }
+        };
+        let ((s3_key, content, content_stream), fetch_error) = match s3_object {
+            Ok(s3_object) => (s3_object, None),
+            Err((s3_key, fetch_error)) => ((s3_key, Vec::new(), None), Some(fetch_error)),
+        };
+        memory_reservation.resize(content.len() as u64);
+        s3_objects_completed.insert(
+            s3_object_index,
+            S3Object { s3_key, content, content_stream, fetch_latency_millis, fetch_retries, memory_reservation, fetch_error },
+        );
+
+        while let Some(s3_object) = s3_objects_completed.remove(&next_s3_object_index) {
+            if s3_object_sender.send(s3_object).await.is_err() {
+                warn!("send_s3_objects_in_order - receiver dropped - pending downloads aborted");
+                tokio_join_set.abort_all();
+                return;
+            }
+            next_s3_object_index += 1;
+        }
+    }
+
+    debug!("send_s3_objects_in_order - done - s3 objects sent: {next_s3_object_index}");
+}
+
+/// Gets [CommonError] S3 object fetch error by [&str] S3 key and [&JoinError] fetch task join error (a panicked fetch task
+/// is reported as a fetch failure of its S3 key, so the export goes on or is aborted by the requested policy)
+fn get_fetch_task_error(s3_key: &str, join_error: &JoinError) -> CommonError {
+    match join_error.is_panic() {
+        true => error!("get_fetch_task_error - s3 object fetch task panicked - s3 key: {s3_key} - error: {join_error}"),
+        false => warn!("get_fetch_task_error - s3 object fetch task cancelled - s3 key: {s3_key}"),
+    }
+    CommonError::AWS_ACCESS_ERROR
+}
+
+/// Creates a new [Option<S3ObjectMetadata>] by [&Object] S3 object (S3 key relative to [&str] S3 prefix)
+/// Returns [None] if S3 object has no S3 key
+fn create_s3_object_metadata(s3_object: &Object, s3_prefix: &str) -> Option<S3ObjectMetadata> {
+    s3_object.key().map(|s3_key| S3ObjectMetadata {
+        s3_key: String::from(s3_key.strip_prefix(s3_prefix).unwrap_or(s3_key)),
+        size_bytes: s3_object.size().unwrap_or_default(),
+        last_modified: s3_object
+            .last_modified()
+            .and_then(|last_modified| last_modified.fmt(DateTimeFormat::DateTime).ok()),
+        e_tag: s3_object.e_tag().map(String::from),
+        storage_class: s3_object.storage_class().map(|storage_class| String::from(storage_class.as_str())),
+        restore_required: is_restore_required(s3_object.storage_class().map(|storage_class| storage_class.as_str())),
+        pending_transition: None,
+        tags: None,
+        user_metadata: None,
+    })
+}
+
+/// Gets [Option<String>] Object Lock reason by [Option<&ObjectLockLegalHoldStatus>] legal hold status,
+/// [Option<&ObjectLockMode>] retention mode and [Option<&DateTime>] retain until date ([i64] now - seconds since unix epoch)
+/// Returns [None] if legal hold is not enabled and retention period is not active
+fn get_object_lock_reason(
+    legal_hold_status: Option<&ObjectLockLegalHoldStatus>,
+    retention_mode: Option<&ObjectLockMode>,
+    retain_until_date: Option<&DateTime>,
+    now: i64,
+) -> Option<String> {
+    if legal_hold_status == Some(&ObjectLockLegalHoldStatus::On) {
+        return Some(String::from("legal hold"));
+    }
+    retention_mode
+        .zip(retain_until_date.filter(|retain_until_date| retain_until_date.secs() > now))
+        .map(|(retention_mode, retain_until_date)| {
+            let retain_until_date = retain_until_date.fmt(DateTimeFormat::DateTime).unwrap_or_default();
+            format!("{} retention until {retain_until_date}", retention_mode.as_str())
+        })
+}
+
+/// Gets [bool] true if [Option<&str>] storage class requires a restore before S3 object download
+fn is_restore_required(storage_class: Option<&str>) -> bool {
+    storage_class.is_some_and(|storage_class| AWS_S3_RESTORE_REQUIRED_STORAGE_CLASSES.contains(&storage_class))
+}
+
+/// Gets [S3RestoreStatus] archived S3 object restore status by [Option<&RestoreStatus>] S3 restore status attribute
+fn get_s3_restore_status(restore_status: Option<&RestoreStatus>) -> S3RestoreStatus {
+    match restore_status {
+        Some(restore_status) if restore_status.is_restore_in_progress() == Some(true) => S3RestoreStatus::IN_PROGRESS,
+        Some(restore_status) if restore_status.restore_expiry_date().is_some() => S3RestoreStatus::RESTORED,
+        _ => S3RestoreStatus::NOT_RESTORED,
     }
 }
 
+/// Creates a new [Vec<LifecycleTransitionRule>] by [&[LifecycleRule]] S3 bucket lifecycle rules
+/// Important: disabled rules and rules filtered by tags (or object sizes) are skipped (not derivable from listings)
+fn create_lifecycle_transition_rules(lifecycle_rules: &[LifecycleRule]) -> Vec<LifecycleTransitionRule> {
+    lifecycle_rules
+        .iter()
+        .filter(|lifecycle_rule| *lifecycle_rule.status() == ExpirationStatus::Enabled)
+        .filter(|lifecycle_rule| lifecycle_rule.filter().is_none_or(|rule_filter| rule_filter.tag().is_none() && rule_filter.and().is_none()))
+        .flat_map(|lifecycle_rule| {
+            #[allow(deprecated)]
+            let s3_key_prefix = lifecycle_rule
+                .filter()
+                .and_then(|rule_filter| rule_filter.prefix())
+                .or(lifecycle_rule.prefix())
+                .unwrap_or_default();
+            lifecycle_rule.transitions().iter().filter_map(move |transition| {
+                Some(LifecycleTransitionRule {
+                    s3_key_prefix: String::from(s3_key_prefix),
+                    days: transition.days().map(i64::from),
+                    date_epoch_seconds: transition.date().map(|date| date.secs()),
+                    storage_class: String::from(transition.storage_class()?.as_str()),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Gets [Option<LifecycleTransition>] next pending storage class transition by [&[LifecycleTransitionRule]] lifecycle
+/// transition rules, [&str] S3 key prefix, [&S3ObjectMetadata] S3 object metadata (S3 key relative to the S3 key prefix)
+/// and [i64] current time (seconds since unix epoch)
+/// Returns [None] if there is no future transition to other storage class (or S3 object last modified date is unknown)
+fn get_pending_transition(
+    lifecycle_transition_rules: &[LifecycleTransitionRule],
+    s3_key_prefix: &str,
+    s3_object_metadata: &S3ObjectMetadata,
+    now_epoch_seconds: i64,
+) -> Option<LifecycleTransition> {
+    let s3_key = format!("{s3_key_prefix}{}", s3_object_metadata.s3_key);
+    let last_modified_epoch_seconds = DateTime::from_str(s3_object_metadata.last_modified.as_deref()?, DateTimeFormat::DateTime).ok()?.secs();
+    let storage_class = s3_object_metadata.storage_class.as_deref().unwrap_or(AWS_S3_DEFAULT_STORAGE_CLASS);
+
+    lifecycle_transition_rules
+        .iter()
+        .filter(|lifecycle_transition_rule| s3_key.starts_with(&lifecycle_transition_rule.s3_key_prefix))
+        .filter(|lifecycle_transition_rule| lifecycle_transition_rule.storage_class != storage_class)
+        .filter_map(|lifecycle_transition_rule| {
+            let transition_epoch_seconds = lifecycle_transition_rule
+                .date_epoch_seconds
+                .or(lifecycle_transition_rule.days.map(|days| last_modified_epoch_seconds + days * 86400))?;
+            Some((transition_epoch_seconds, lifecycle_transition_rule))
+        })
+        .filter(|(transition_epoch_seconds, _)| *transition_epoch_seconds > now_epoch_seconds)
+        .min_by_key(|(transition_epoch_seconds, _)| *transition_epoch_seconds)
+        .and_then(|(transition_epoch_seconds, lifecycle_transition_rule)| {
+            Some(LifecycleTransition {
+                storage_class: lifecycle_transition_rule.storage_class.clone(),
+                transition_date: DateTime::from_secs(transition_epoch_seconds).fmt(DateTimeFormat::DateTime).ok()?,
+                restore_required: is_restore_required(Some(&lifecycle_transition_rule.storage_class)),
+            })
+        })
+}
+
 /// Gets a [String] sanitized path by [String] S3 path
 /// Important: removes start and end slashes to avoid included nested folders as part of
 /// implemented S3 operations (like read, add, delete, copy, etc.)
@@ -394,17 +1913,111 @@ fn sanitize_path(mut path_to_sanitize: String) -> String {
     path_to_sanitize
 }
 
-/// Gets [i64] value by [&str] environment variable name and [&str] environment variable default value
-fn get_env_var_as_i64(env_var_name: &str, env_var_default: &str) -> i64 {
-    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
-    return value.parse().unwrap_or_default()
-}
-
-/// Gets [usize] value by [&str] environment variable name and [&str] environment variable default value
-fn get_env_var_as_usize(env_var_name: &str, env_var_default: &str) -> usize {
-    let value = std::env::var(env_var_name).unwrap_or(String::from(env_var_default));
-    return value.parse().unwrap_or_default()
+/// Gets a [String] S3 object key by [&str] sanitized path (S3 prefix) and [&str] S3 key
+/// Important: separator is omitted for an empty S3 prefix (bucket root), so a S3 listing prefix (with its end slash)
+/// can be created by an empty S3 key too
+fn join_s3_key(s3_prefix: &str, s3_key: &str) -> String {
+    match s3_prefix.is_empty() {
+        true => String::from(s3_key),
+        false => format!("{s3_prefix}/{s3_key}"),
+    }
 }
 
 /// AWS SDK S3 service trait dyn type
 pub type DynAwsSdkS3Service = Arc<dyn AwsSdkS3ServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::common_error::CommonError;
+    use crate::service::aws_sdk_s3_service::{get_content_reserve_bytes, get_fetch_task_error, get_object_lock_reason, get_pending_transition, join_s3_key, sanitize_path, LifecycleTransitionRule};
+    use aws_sdk_s3::primitives::DateTime;
+    use aws_sdk_s3::types::{ObjectLockLegalHoldStatus, ObjectLockMode};
+    use tokio::task::JoinSet;
+
+    /// Scenario:
+    /// Gets the pending transition of a standard S3 object (modified at 2026-01-01) with lifecycle rules moving
+    /// "reports/" S3 objects to STANDARD_IA after 30 days and to DEEP_ARCHIVE after 90 days (current time: 2026-02-15)
+    /// Expectation:
+    /// The DEEP_ARCHIVE transition (requires restore) should be retrieved, and no transition for other S3 prefixes
+    #[test]
+    fn when_get_pending_transition_should_retrieve_next_future_transition() {
+        let lifecycle_transition_rules = vec![
+            LifecycleTransitionRule { s3_key_prefix: String::from("reports/"), days: Some(30), date_epoch_seconds: None, storage_class: String::from("STANDARD_IA") },
+            LifecycleTransitionRule { s3_key_prefix: String::from("reports/"), days: Some(90), date_epoch_seconds: None, storage_class: String::from("DEEP_ARCHIVE") },
+        ];
+        let s3_object_metadata = S3ObjectMetadata {
+            s3_key: String::from("2026/a.csv"),
+            last_modified: Some(String::from("2026-01-01T00:00:00Z")),
+            ..Default::default()
+        };
+
+        let pending_transition = get_pending_transition(&lifecycle_transition_rules, "reports/", &s3_object_metadata, 1771113600).unwrap();
+
+        assert_eq!("DEEP_ARCHIVE", pending_transition.storage_class);
+        assert_eq!("2026-04-01T00:00:00Z", pending_transition.transition_date);
+        assert!(pending_transition.restore_required);
+        assert!(get_pending_transition(&lifecycle_transition_rules, "invoices/", &s3_object_metadata, 1771113600).is_none());
+    }
+
+    /// Scenario:
+    /// Gets the content buffer bytes to be reserved for single responses, the first and the last ranged responses
+    /// of a 10 MB S3 object (4 MB chunks) and responses without content length
+    /// Expectation:
+    /// The whole S3 object should be reserved by the first ranged response and just the content length by single responses
+    #[test]
+    fn when_get_content_reserve_bytes_should_reserve_whole_s3_object() {
+        assert_eq!(1024, get_content_reserve_bytes(0, Some(1024), None));
+        assert_eq!(10_485_760, get_content_reserve_bytes(0, Some(4_194_304), Some(10_485_760)));
+        assert_eq!(2_097_152, get_content_reserve_bytes(8_388_608, Some(2_097_152), Some(10_485_760)));
+        assert_eq!(0, get_content_reserve_bytes(0, None, None));
+    }
+
+    /// Scenario:
+    /// Gets the Object Lock reason of S3 objects under legal hold, under an active governance retention period, under an
+    /// expired compliance retention period and without Object Lock (current time: 2026-02-15)
+    /// Expectation:
+    /// Just legal hold and active retention period should be reported as locked
+    #[test]
+    fn when_get_object_lock_reason_should_report_legal_hold_and_active_retention() {
+        let now = 1771113600;
+        let retain_until_date = |secs: i64| DateTime::from_secs(secs);
+
+        assert_eq!(Some(String::from("legal hold")), get_object_lock_reason(Some(&ObjectLockLegalHoldStatus::On), None, None, now));
+        assert_eq!(
+            Some(String::from("GOVERNANCE retention until 2026-03-01T00:00:00Z")),
+            get_object_lock_reason(Some(&ObjectLockLegalHoldStatus::Off), Some(&ObjectLockMode::Governance), Some(&retain_until_date(1772323200)), now)
+        );
+        assert_eq!(None, get_object_lock_reason(None, Some(&ObjectLockMode::Compliance), Some(&retain_until_date(1767225600)), now));
+        assert_eq!(None, get_object_lock_reason(None, None, None, now));
+    }
+
+    /// Scenario:
+    /// Joins a S3 object fetch task that panics
+    /// Expectation:
+    /// The panic should be captured as a fetch failure of the task S3 key (the export is not taken down)
+    #[tokio::test]
+    async fn when_fetch_task_panics_should_get_fetch_task_error() {
+        let mut tokio_join_set: JoinSet<()> = JoinSet::new();
+        tokio_join_set.spawn(async { panic!("fetch task panic") });
+
+        let join_error = tokio_join_set.join_next().await.unwrap().unwrap_err();
+
+        assert!(join_error.is_panic());
+        assert_eq!(CommonError::AWS_ACCESS_ERROR, get_fetch_task_error("a.csv", &join_error));
+    }
+
+    /// Scenario:
+    /// Joins S3 keys and listing prefixes of nested and bucket root (empty or slash) paths
+    /// Expectation:
+    /// Separator should be added just for nested paths (bucket root S3 keys and listing prefix are kept as they are)
+    #[test]
+    fn when_join_s3_key_should_omit_separator_for_bucket_root() {
+        assert_eq!("a.csv", join_s3_key(&sanitize_path(String::from("")), "a.csv"));
+        assert_eq!("a.csv", join_s3_key(&sanitize_path(String::from("/")), "a.csv"));
+        assert_eq!("", join_s3_key(&sanitize_path(String::from("/")), ""));
+        assert_eq!("reports/2026/a.csv", join_s3_key(&sanitize_path(String::from("/reports/2026/")), "a.csv"));
+        assert_eq!("reports/", join_s3_key(&sanitize_path(String::from("reports")), ""));
+    }
+}
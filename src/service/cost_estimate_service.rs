@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use crate::constant::constants::{AWS_S3_DEFAULT_STORAGE_CLASS, COST_PRICING_FILE_ENV_VAR};
+use crate::dto::cost_estimate::CostEstimate;
+use crate::dto::cost_estimate_request::CostEstimateRequest;
+use crate::dto::pricing_table::PricingTable;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use log::{debug, error};
+
+/// Bytes per GB (pricing table unit)
+const BYTES_PER_GB: f64 = 1_073_741_824.0;
+
+/// Cost estimate service (AWS S3 costs of a proposed export)
+#[async_trait]
+pub trait CostEstimateServiceTrait {
+    /// Gets [CostEstimate] AWS S3 costs (requests, data transfer and retrieval) by [CostEstimateRequest] request
+    /// based on the S3 listing and the configured pricing table
+    /// Returns a [CommonError] if S3 throws any error
+    async fn estimate_export_cost(&self, cost_estimate_request: CostEstimateRequest) -> Result<CostEstimate, CommonError>;
+}
+
+/// Cost estimate service implementation struct
+pub struct CostEstimateService {
+    aws_s3_service: DynAwsSdkS3Service,
+    pricing_table: PricingTable,
+}
+
+/// initialization by shared dependencies
+/// Important: default pricing table is used if pricing file is not configured or it is not valid
+impl CostEstimateService {
+    /// Creates a new [CostEstimateService] by [DynAwsSdkS3Service] AWS S3 service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service) -> Self {
+        CostEstimateService {
+            aws_s3_service,
+            pricing_table: std::env::var(COST_PRICING_FILE_ENV_VAR)
+                .map(|pricing_file| read_pricing_table(&pricing_file))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// default initialization
+impl Default for CostEstimateService {
+    fn default() -> Self {
+        CostEstimateService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service)
+    }
+}
+
+/// Cost estimate service implementation logic
+#[async_trait]
+impl CostEstimateServiceTrait for CostEstimateService {
+    /// Gets [CostEstimate] AWS S3 costs (requests, data transfer and retrieval) by [CostEstimateRequest] request
+    /// based on the S3 listing and the configured pricing table
+    /// Important: just files directly under the S3 folder are exported (nested folders are not included)
+    /// Returns a [CommonError] if S3 throws any error
+    async fn estimate_export_cost(&self, cost_estimate_request: CostEstimateRequest) -> Result<CostEstimate, CommonError> {
+        debug!("estimate_export_cost - start");
+        let mut s3_object_metadata_list: Vec<S3ObjectMetadata> = Vec::new();
+        let mut list_requests = 0;
+        let mut continuation_token = None;
+        loop {
+            let (s3_object_metadata_page, next_continuation_token) = self
+                .aws_s3_service
+                .get_s3_object_metadata_page(
+                    cost_estimate_request.bucket_name.clone(),
+                    cost_estimate_request.full_path.clone(),
+                    None,
+                    continuation_token,
+                )
+                .await?;
+            list_requests += 1;
+            s3_object_metadata_list.extend(
+                s3_object_metadata_page
+                    .into_iter()
+                    .filter(|s3_object_metadata| !s3_object_metadata.s3_key.contains('/')),
+            );
+
+            continuation_token = next_continuation_token;
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        debug!("estimate_export_cost - done - files: {}", s3_object_metadata_list.len());
+        Ok(create_cost_estimate(
+            &s3_object_metadata_list,
+            list_requests,
+            cost_estimate_request.multipart_chunk_bytes,
+            &self.pricing_table,
+        ))
+    }
+}
+
+/// Creates a new [CostEstimate] by [&[S3ObjectMetadata]] exported S3 objects, [u64] LIST requests,
+/// [Option<u64>] multipart chunk bytes and [&PricingTable] pricing table
+fn create_cost_estimate(
+    s3_object_metadata_list: &[S3ObjectMetadata],
+    list_requests: u64,
+    multipart_chunk_bytes: Option<u64>,
+    pricing_table: &PricingTable,
+) -> CostEstimate {
+    let mut bytes_by_storage_class: BTreeMap<String, u64> = BTreeMap::new();
+    let mut get_requests = 0;
+    for s3_object_metadata in s3_object_metadata_list {
+        let size_bytes = s3_object_metadata.size_bytes.max(0) as u64;
+        let storage_class = s3_object_metadata.storage_class.as_deref().unwrap_or(AWS_S3_DEFAULT_STORAGE_CLASS);
+        *bytes_by_storage_class.entry(String::from(storage_class)).or_default() += size_bytes;
+        get_requests += match multipart_chunk_bytes.filter(|multipart_chunk_bytes| *multipart_chunk_bytes > 0) {
+            Some(multipart_chunk_bytes) => size_bytes.div_ceil(multipart_chunk_bytes).max(1),
+            None => 1,
+        };
+    }
+
+    let bytes_total: u64 = bytes_by_storage_class.values().sum();
+    let list_requests_cost = list_requests as f64 / 1000.0 * pricing_table.list_requests_per_thousand;
+    let get_requests_cost = get_requests as f64 / 1000.0 * pricing_table.get_requests_per_thousand;
+    let data_transfer_cost = bytes_total as f64 / BYTES_PER_GB * pricing_table.data_transfer_per_gb;
+    let retrieval_cost = bytes_by_storage_class
+        .iter()
+        .map(|(storage_class, bytes)| {
+            *bytes as f64 / BYTES_PER_GB * pricing_table.retrieval_per_gb.get(storage_class).copied().unwrap_or_default()
+        })
+        .sum::<f64>();
+
+    CostEstimate {
+        files_total: s3_object_metadata_list.len(),
+        bytes_total,
+        bytes_by_storage_class,
+        list_requests,
+        get_requests,
+        list_requests_cost,
+        get_requests_cost,
+        data_transfer_cost,
+        retrieval_cost,
+        total_cost: list_requests_cost + get_requests_cost + data_transfer_cost + retrieval_cost,
+        restore_required_keys: s3_object_metadata_list
+            .iter()
+            .filter(|s3_object_metadata| s3_object_metadata.restore_required)
+            .map(|s3_object_metadata| s3_object_metadata.s3_key.clone())
+            .collect(),
+    }
+}
+
+/// Gets [PricingTable] pricing table by [&str] pricing file path (JSON format)
+/// Important: default pricing table is retrieved if pricing file is not valid
+fn read_pricing_table(pricing_file: &str) -> PricingTable {
+    match std::fs::read_to_string(pricing_file)
+        .map_err(|read_error| read_error.to_string())
+        .and_then(|pricing_table| serde_json::from_str(&pricing_table).map_err(|parse_error| parse_error.to_string()))
+    {
+        Ok(pricing_table) => pricing_table,
+        Err(pricing_table_error) => {
+            error!("read_pricing_table - pricing file not valid - default prices are used - error: {pricing_table_error}");
+            PricingTable::default()
+        }
+    }
+}
+
+/// Cost estimate service trait dyn type
+pub type DynCostEstimateService = Arc<dyn CostEstimateServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::pricing_table::PricingTable;
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::service::cost_estimate_service::create_cost_estimate;
+
+    /// Scenario:
+    /// Estimates an export with a 2 GB standard file and a 1 GB archived file downloaded by 512 MB ranged requests
+    /// Expectation:
+    /// GET requests should be counted by ranged requests and retrieval cost should be applied just to the archived file
+    #[test]
+    fn when_create_cost_estimate_should_count_ranged_requests_and_retrieval() {
+        let s3_object_metadata_list = vec![
+            S3ObjectMetadata { s3_key: String::from("a.bin"), size_bytes: 2_147_483_648, ..Default::default() },
+            S3ObjectMetadata {
+                s3_key: String::from("b.bin"),
+                size_bytes: 1_073_741_824,
+                storage_class: Some(String::from("GLACIER")),
+                restore_required: true,
+                ..Default::default()
+            },
+        ];
+
+        let cost_estimate = create_cost_estimate(&s3_object_metadata_list, 1, Some(536_870_912), &PricingTable::default());
+
+        assert_eq!(2, cost_estimate.files_total);
+        assert_eq!(6, cost_estimate.get_requests);
+        assert_eq!(Some(&1_073_741_824), cost_estimate.bytes_by_storage_class.get("GLACIER"));
+        assert!((cost_estimate.data_transfer_cost - 0.27).abs() < 1e-9);
+        assert!((cost_estimate.retrieval_cost - 0.01).abs() < 1e-9);
+        assert_eq!(vec!["b.bin"], cost_estimate.restore_required_keys);
+    }
+}
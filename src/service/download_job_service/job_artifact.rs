@@ -0,0 +1,144 @@
+use axum::body::Bytes;
+use crate::config::base_path::create_public_base_url;
+use crate::config::envelope_encryption::{get_wrapped_data_key, ENVELOPE_ENCRYPTION};
+use crate::constant::constants::{API_DOWNLOAD_ARTIFACT_PATH, API_DOWNLOAD_MAIN_PATH, ARTIFACT_STORE_BUCKET_ENV_VAR, ARTIFACT_STORE_PATH_DEFAULT, ARTIFACT_STORE_PATH_ENV_VAR};
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use crate::service::download_service::encode_archive_digest;
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+
+/// Artifact store struct (content-addressable job results stored in the configured S3 bucket - disabled if it is not
+/// configured, please check constants.rs)
+#[derive(Clone)]
+pub(super) struct ArtifactStore {
+    aws_s3_service: DynAwsSdkS3Service,
+    artifact_store_bucket: Option<String>,
+    artifact_store_path: String,
+}
+
+impl ArtifactStore {
+    /// Creates a new [ArtifactStore] by [DynAwsSdkS3Service] AWS S3 service (S3 bucket and path are read from
+    /// environment variables)
+    pub(super) fn new(aws_s3_service: DynAwsSdkS3Service) -> Self {
+        ArtifactStore {
+            aws_s3_service,
+            artifact_store_bucket: std::env::var(ARTIFACT_STORE_BUCKET_ENV_VAR).ok().filter(|artifact_store_bucket| !artifact_store_bucket.is_empty()),
+            artifact_store_path: std::env::var(ARTIFACT_STORE_PATH_ENV_VAR).unwrap_or_else(|_| String::from(ARTIFACT_STORE_PATH_DEFAULT)),
+        }
+    }
+
+    /// Stores [&Bytes] job result content named [&str] zip file name in the artifact store (if enabled)
+    /// under a content-hash S3 key: identical exports share the same stored artifact (not uploaded again)
+    /// Returns [Option<String>] content-addressable artifact name ([None] if artifact store is not enabled or it fails)
+    pub(super) async fn store_artifact(&self, zip_file_name: &str, zip_content: &Bytes) -> Option<String> {
+        let artifact_store_bucket = self.artifact_store_bucket.clone()?;
+        let artifact_name = create_artifact_name(zip_file_name, &Sha256::digest(zip_content));
+
+        match self
+            .aws_s3_service
+            .get_s3_object_metadata(artifact_store_bucket.clone(), self.artifact_store_path.clone(), Some(artifact_name.clone()))
+            .await
+        {
+            Ok(Some(_)) => {
+                debug!("store_artifact - artifact already stored (deduplicated) - artifact name: {artifact_name}");
+                Some(artifact_name)
+            }
+            _ => match self
+                .aws_s3_service
+                .add_s3_object(artifact_store_bucket, self.artifact_store_path.clone(), artifact_name.clone(), zip_content.clone())
+                .await
+            {
+                Ok(_) => {
+                    info!("store_artifact - artifact stored - artifact name: {artifact_name}");
+                    Some(artifact_name)
+                }
+                Err(_) => {
+                    warn!("store_artifact - artifact not stored - artifact name: {artifact_name}");
+                    None
+                }
+            },
+        }
+    }
+
+    /// Gets [(String, Bytes)] stored artifact name and content by [String] content-addressable artifact name
+    /// Important: artifacts are removed by the retention cleanup just if exports retention window is configured and
+    /// client-side envelope encrypted artifacts are decrypted (please, check [ENVELOPE_ENCRYPTION])
+    /// Returns a [CommonError] if artifact store is not enabled, artifact name is not valid, artifact is not found or it
+    /// can't be decrypted
+    pub(super) async fn get_artifact(&self, artifact_name: String) -> Result<(String, Bytes), CommonError> {
+        debug!("get_artifact - start - artifact name: {artifact_name}");
+        let Some(artifact_store_bucket) = self.artifact_store_bucket.clone().filter(|_| is_valid_artifact_name(&artifact_name)) else {
+            warn!("get_artifact - artifact store not enabled or artifact name not valid - artifact name: {artifact_name}");
+            return Err(CommonError::NO_DATA_FOUND);
+        };
+
+        let envelope_metadata = self
+            .aws_s3_service
+            .get_s3_object_metadata(artifact_store_bucket.clone(), self.artifact_store_path.clone(), Some(artifact_name.clone()))
+            .await?
+            .ok_or(CommonError::NO_DATA_FOUND)?
+            .user_metadata
+            .filter(|user_metadata| get_wrapped_data_key(user_metadata).is_some());
+        let (_, artifact_content) = self
+            .aws_s3_service
+            .get_s3_object(artifact_store_bucket, self.artifact_store_path.clone(), artifact_name.clone())
+            .await?;
+        let artifact_content = match envelope_metadata {
+            Some(envelope_metadata) => ENVELOPE_ENCRYPTION.decrypt(&envelope_metadata, &artifact_content).await?,
+            None => artifact_content,
+        };
+        debug!("get_artifact - done");
+        Ok((artifact_name, Bytes::from(artifact_content)))
+    }
+}
+
+/// Creates a new [String] content-addressable artifact name by [&str] zip file name (its extensions are kept,
+/// i.e.: ".zip" or ".zip.age") and [&[u8]] content SHA-256 digest
+fn create_artifact_name(zip_file_name: &str, content_digest: &[u8]) -> String {
+    let extensions = zip_file_name.find('.').map(|extensions_start| &zip_file_name[extensions_start..]).unwrap_or_default();
+    format!("{}{extensions}", encode_archive_digest(content_digest))
+}
+
+/// Gets [bool] true if [&str] artifact name is a valid content-addressable artifact name
+/// (hex encoded SHA-256 digest followed by alphanumeric extensions)
+fn is_valid_artifact_name(artifact_name: &str) -> bool {
+    let (content_hash, extensions) = artifact_name.split_once('.').unwrap_or((artifact_name, ""));
+    content_hash.len() == 64
+        && content_hash.chars().all(|hash_char| hash_char.is_ascii_hexdigit())
+        && extensions.split('.').all(|extension| extension.chars().all(|extension_char| extension_char.is_ascii_alphanumeric()))
+}
+
+/// Creates a new [String] stored artifact URL by [&str] content-addressable artifact name and [Option<&str>] forwarded
+/// public base URL (please, check [create_public_base_url])
+pub(super) fn create_artifact_url(artifact_name: &str, forwarded_public_base_url: Option<&str>) -> String {
+    format!(
+        "{}{API_DOWNLOAD_MAIN_PATH}{}",
+        create_public_base_url(forwarded_public_base_url),
+        API_DOWNLOAD_ARTIFACT_PATH.replace("{artifact_name}", artifact_name)
+    )
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::service::download_job_service::job_artifact::{create_artifact_name, is_valid_artifact_name};
+    use sha2::{Digest, Sha256};
+
+    /// Scenario:
+    /// Creates content-addressable artifact names for identical and encrypted exports and checks invalid artifact names
+    /// Expectation:
+    /// Identical contents should share the same valid artifact name (extensions kept) and path-like names should be rejected
+    #[test]
+    fn when_create_artifact_name_should_be_content_addressed() {
+        let content_digest = Sha256::digest(b"some archive content");
+        let artifact_name = create_artifact_name("s3-export.zip", &content_digest);
+
+        assert_eq!(artifact_name, create_artifact_name("s3-export.zip", &Sha256::digest(b"some archive content")));
+        assert!(artifact_name.ends_with(".zip"));
+        assert!(create_artifact_name("s3-export.zip.age", &content_digest).ends_with(".zip.age"));
+        assert!(is_valid_artifact_name(&artifact_name));
+        assert!(!is_valid_artifact_name("../secret.zip"));
+        assert!(!is_valid_artifact_name(&format!("{artifact_name}/../other")));
+    }
+}
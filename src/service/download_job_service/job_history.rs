@@ -0,0 +1,217 @@
+use crate::config::clock::current_epoch_seconds;
+use crate::constant::constants::{EXPORT_HISTORY_MAX_RECORDS, SHARED_STATE_HISTORY_KEY_PREFIX};
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::export_record::ExportRecord;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_status::DownloadJobStatus;
+use crate::enums::export_filter::parse_filter_date;
+use crate::service::download_job_service::{DownloadJobService, DownloadJobServiceTrait};
+use log::{debug, warn};
+
+/// Download job service export history logic (finished jobs records and incremental re-runs)
+impl DownloadJobService {
+    /// Gets [Vec<ManifestEntry>] incremental manifest by [&ExportRecord] previous export record: S3 objects under the
+    /// previous export S3 path (all nested folders included) not exported by the previous job or modified since it started
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if previous job is not completed or its exported files are
+    /// not recorded, a [CommonError::NO_DATA_FOUND] if there are no new or modified files or a [CommonError] if S3 throws any error
+    pub(super) async fn get_incremental_manifest(&self, export_record: &ExportRecord) -> Result<Vec<ManifestEntry>, CommonError> {
+        if export_record.status != DownloadJobStatus::COMPLETED || export_record.exported_files.is_empty() {
+            warn!("get_incremental_manifest - exported files not recorded - previous job id: {}", export_record.job_id);
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+
+        let mut changed_s3_keys = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let (s3_object_metadata_page, next_continuation_token) = self
+                .aws_s3_service
+                .get_s3_object_metadata_page(
+                    export_record.request.bucket_name.clone(),
+                    export_record.request.full_path.clone(),
+                    None,
+                    continuation_token,
+                )
+                .await?;
+            changed_s3_keys.extend(
+                s3_object_metadata_page
+                    .into_iter()
+                    .filter(|s3_object_metadata| {
+                        !s3_object_metadata.s3_key.ends_with('/')
+                            && is_changed_s3_object(s3_object_metadata, &export_record.exported_files, export_record.started_at)
+                    })
+                    .map(|s3_object_metadata| s3_object_metadata.s3_key),
+            );
+            continuation_token = next_continuation_token;
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+        changed_s3_keys.sort();
+
+        let manifest: Vec<ManifestEntry> = match &export_record.request.manifest {
+            Some(manifest) => manifest
+                .iter()
+                .filter(|manifest_entry| changed_s3_keys.binary_search(&manifest_entry.s3_key).is_ok())
+                .cloned()
+                .collect(),
+            None => changed_s3_keys.into_iter().map(|s3_key| ManifestEntry { s3_key, archive_name: None }).collect(),
+        };
+        if manifest.is_empty() {
+            warn!("get_incremental_manifest - no new or modified files - previous job id: {}", export_record.job_id);
+            return Err(CommonError::NO_DATA_FOUND);
+        }
+        debug!("get_incremental_manifest - done - files: {}", manifest.len());
+        Ok(manifest)
+    }
+
+    /// Records [&str] finished job identifier export history record by [DownloadRequest] download request and [u64] job
+    /// start date (records are shared with other API replicas if shared state store is enabled and kept for the export
+    /// history retention window)
+    /// Important: request caller and public base URL are not kept by the saved request (they are set again once the
+    /// export is triggered again)
+    pub(super) async fn record_export(&self, job_id: &str, mut download_request: DownloadRequest, started_at: u64) {
+        let Ok(download_job) = self.get_job(String::from(job_id)).await else {
+            return;
+        };
+        download_request.public_base_url = None;
+        let mut exported_files: Vec<String> = download_job
+            .report
+            .iter()
+            .flat_map(|export_report| export_report.files.iter().map(|file_fetch_report| file_fetch_report.s3_key.clone()))
+            .collect();
+        exported_files.sort();
+        let export_record = ExportRecord {
+            job_id: String::from(job_id),
+            status: download_job.status,
+            bucket_name: download_request.bucket_name.clone(),
+            full_path: download_request.full_path.clone(),
+            caller_id: download_request.caller_id.take(),
+            started_at,
+            finished_at: current_epoch_seconds(),
+            files_total: download_job.report.as_ref().map(|export_report| export_report.files_total),
+            bytes_total: download_job.report.as_ref().map(|export_report| export_report.bytes_total),
+            exported_files,
+            request: download_request,
+        };
+        if self.shared_state_store.is_enabled()
+            && let Ok(export_record_as_json) = serde_json::to_string(&export_record)
+        {
+            self.shared_state_store
+                .set_value(
+                    &format!("{SHARED_STATE_HISTORY_KEY_PREFIX}{job_id}"),
+                    &export_record_as_json,
+                    self.export_history_retention_seconds,
+                )
+                .await;
+        }
+
+        let expired_at = export_record.finished_at.saturating_sub(self.export_history_retention_seconds);
+        let mut export_records = self.export_records.write().unwrap();
+        while export_records.len() >= EXPORT_HISTORY_MAX_RECORDS
+            || export_records.front().is_some_and(|oldest_record| oldest_record.finished_at < expired_at)
+        {
+            export_records.pop_front();
+        }
+        export_records.push_back(export_record);
+        debug!("record_export - done - job id: {job_id}");
+    }
+}
+
+/// Gets [bool] true if [&S3ObjectMetadata] S3 object is not in [&[String]] previously exported S3 keys (sorted) or it
+/// was modified since [u64] previous export start date (seconds since unix epoch - S3 objects without last modified
+/// date are considered modified)
+fn is_changed_s3_object(s3_object_metadata: &S3ObjectMetadata, exported_files: &[String], since: u64) -> bool {
+    exported_files.binary_search(&s3_object_metadata.s3_key).is_err()
+        || s3_object_metadata
+            .last_modified
+            .as_deref()
+            .and_then(parse_filter_date)
+            .is_none_or(|last_modified| last_modified >= since as i64)
+}
+
+/// Gets [Vec<ExportRecord>] selected export records (newest records first - by finish date and job identifier) by
+/// [Vec<ExportRecord>] export records, [Option<&str>] S3 bucket, [u64] from and [Option<u64>] to finish dates (seconds
+/// since unix epoch - both inclusive)
+pub(super) fn select_export_records(export_records: Vec<ExportRecord>, bucket_name: Option<&str>, from: u64, to: Option<u64>) -> Vec<ExportRecord> {
+    let mut export_records: Vec<ExportRecord> = export_records
+        .into_iter()
+        .filter(|export_record| bucket_name.is_none_or(|bucket_name| export_record.bucket_name == bucket_name))
+        .filter(|export_record| export_record.finished_at >= from && to.is_none_or(|to| export_record.finished_at <= to))
+        .collect();
+    export_records.sort_by(|export_record, other_record| {
+        (other_record.finished_at, &other_record.job_id).cmp(&(export_record.finished_at, &export_record.job_id))
+    });
+    export_records
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::download_request::DownloadRequest;
+    use crate::dto::export_record::ExportRecord;
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::download_job_status::DownloadJobStatus;
+    use crate::service::download_job_service::is_part_job_id;
+    use crate::service::download_job_service::job_history::{is_changed_s3_object, select_export_records};
+
+    /// Scenario:
+    /// Selects export history records of a S3 bucket within a finish dates range (from records of several S3 buckets,
+    /// some of them out of the range) and checks part job identifiers
+    /// Expectation:
+    /// Just the S3 bucket records within the range should be retrieved (newest records first) and just volume and
+    /// folder job identifiers should be part job identifiers
+    #[test]
+    fn when_select_export_records_should_filter_and_sort_newest_first() {
+        let export_record_fn = |job_id: &str, bucket_name: &str, finished_at: u64| ExportRecord {
+            job_id: String::from(job_id),
+            status: DownloadJobStatus::COMPLETED,
+            bucket_name: String::from(bucket_name),
+            full_path: String::from("some/path"),
+            caller_id: None,
+            started_at: finished_at,
+            finished_at,
+            files_total: Some(1),
+            bytes_total: Some(1024),
+            exported_files: Vec::new(),
+            request: DownloadRequest::default(),
+        };
+        let export_records = vec![
+            export_record_fn("a", "some-bucket", 100),
+            export_record_fn("b", "other-bucket", 200),
+            export_record_fn("c", "some-bucket", 300),
+            export_record_fn("d", "some-bucket", 300),
+            export_record_fn("e", "some-bucket", 400),
+        ];
+
+        let selected_job_ids: Vec<String> = select_export_records(export_records, Some("some-bucket"), 100, Some(300))
+            .into_iter()
+            .map(|export_record| export_record.job_id)
+            .collect();
+        assert_eq!(vec!["d", "c", "a"], selected_job_ids);
+        assert!(is_part_job_id("1b7c2a1e-volume-3"));
+        assert!(is_part_job_id("1b7c2a1e-folder-0"));
+        assert!(!is_part_job_id("1b7c2a1e-4f0a-4a39-9f1e-1f6a0b2d9c11"));
+    }
+
+    /// Scenario:
+    /// Checks S3 objects against the exported files of a previous export started at 2026-01-31T10:00:00Z (incremental re-run)
+    /// Expectation:
+    /// New S3 objects, S3 objects modified since the previous export started and S3 objects without last modified date
+    /// should be changed - previously exported and not modified S3 objects should not
+    #[test]
+    fn when_is_changed_s3_object_should_detect_new_and_modified_files() {
+        let exported_files = vec![String::from("a.csv"), String::from("b.csv")];
+        let s3_object_metadata_fn = |s3_key: &str, last_modified: Option<&str>| S3ObjectMetadata {
+            s3_key: String::from(s3_key),
+            last_modified: last_modified.map(String::from),
+            ..Default::default()
+        };
+
+        assert!(!is_changed_s3_object(&s3_object_metadata_fn("a.csv", Some("2026-01-30T10:00:00Z")), &exported_files, 1_769_853_600));
+        assert!(is_changed_s3_object(&s3_object_metadata_fn("b.csv", Some("2026-01-31T10:05:00Z")), &exported_files, 1_769_853_600));
+        assert!(is_changed_s3_object(&s3_object_metadata_fn("b.csv", None), &exported_files, 1_769_853_600));
+        assert!(is_changed_s3_object(&s3_object_metadata_fn("c.csv", Some("2026-01-01T00:00:00Z")), &exported_files, 1_769_853_600));
+    }
+}
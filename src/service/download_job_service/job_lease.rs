@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use crate::config::assume_role::get_caller_session_tags;
+use crate::config::caller_credentials::get_caller_credentials;
+use crate::config::shared_state_store::REPLICA_ID;
+use crate::constant::constants::{SHARED_STATE_JOB_KEY_PREFIX, SHARED_STATE_JOB_LEASE_KEY_PREFIX, SHARED_STATE_JOB_REQUEST_KEY_PREFIX};
+use crate::dto::download_job::DownloadJob;
+use crate::dto::download_request::DownloadRequest;
+use crate::service::download_job_service::DownloadJobService;
+use log::{debug, warn};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Download job service job leases and shared job state logic (jobs are owned by the replica holding their lease and
+/// shared with other API replicas, so stalled jobs can be taken over)
+impl DownloadJobService {
+    /// Acquires (or renews) [&str] job identifier lease for this replica
+    /// Returns [bool] true if job lease is owned by this replica
+    pub(super) async fn acquire_job_lease(&self, job_id: &str) -> bool {
+        self.shared_state_store
+            .acquire_lease(&format!("{SHARED_STATE_JOB_LEASE_KEY_PREFIX}{job_id}"), &REPLICA_ID, self.job_lease_seconds)
+            .await
+    }
+
+    /// Starts a background task that renews [String] job identifier lease every third of the lease TTL
+    /// (if the lease is lost, [CancellationToken] job lease token is cancelled and the heartbeat is stopped)
+    /// Returns a [JoinHandle<()>] to stop the heartbeat once the job is finished
+    pub(super) fn start_job_lease_heartbeat(&self, job_id: String, job_lease_token: CancellationToken) -> JoinHandle<()> {
+        let download_job_service = self.clone();
+        tokio::spawn(async move {
+            let mut heartbeat_interval = tokio::time::interval(Duration::from_secs(download_job_service.job_lease_seconds / 3));
+            heartbeat_interval.tick().await;
+            loop {
+                heartbeat_interval.tick().await;
+                if !download_job_service.acquire_job_lease(&job_id).await {
+                    warn!("job_lease_heartbeat - job lease lost - job cancelled - job id: {job_id}");
+                    job_lease_token.cancel();
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Gets [bool] true if download requests of the current jobs can be shared with other API replicas (shared state
+    /// store is enabled and jobs don't run under caller temporary AWS credentials or caller session tags)
+    pub(super) fn is_job_request_shareable(&self) -> bool {
+        self.shared_state_store.is_enabled() && get_caller_credentials().is_none() && get_caller_session_tags().is_empty()
+    }
+
+    /// Shares [&DownloadRequest] download request by [&str] job identifier with other API replicas
+    /// (if shared state store is enabled) for the configured retention window, so stalled jobs can be taken over
+    /// Important: requests of jobs running under caller temporary AWS credentials or caller session tags are not shared
+    /// (they can't be taken over - other API replicas would run them with service credentials or untagged assumed role)
+    pub(super) async fn share_job_request(&self, job_id: &str, download_request: &DownloadRequest) {
+        if !self.is_job_request_shareable() {
+            debug!("share_job_request - job request not shared - job id: {job_id}");
+            return;
+        }
+        if let Ok(download_request_as_json) = serde_json::to_string(download_request) {
+            self.shared_state_store
+                .set_value(
+                    &format!("{SHARED_STATE_JOB_REQUEST_KEY_PREFIX}{job_id}"),
+                    &download_request_as_json,
+                    self.job_result_retention_seconds,
+                )
+                .await;
+        }
+    }
+
+    /// Shares [&DownloadJob] download job state with other API replicas (if shared state store is enabled)
+    /// for the configured retention window
+    /// Important: job results are kept in memory by the API replica that ran the job
+    pub(super) async fn share_job(&self, download_job: &DownloadJob) {
+        if !self.shared_state_store.is_enabled() {
+            return;
+        }
+        if let Ok(download_job_as_json) = serde_json::to_string(download_job) {
+            self.shared_state_store
+                .set_value(
+                    &format!("{SHARED_STATE_JOB_KEY_PREFIX}{}", download_job.job_id),
+                    &download_job_as_json,
+                    self.job_result_retention_seconds,
+                )
+                .await;
+        }
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use crate::config::shared_state_store::{DynSharedStateStore, SharedStateStoreTrait};
+    use crate::constant::constants::{SHARED_STATE_JOB_KEY_PREFIX, SHARED_STATE_JOB_REQUEST_KEY_PREFIX};
+    use crate::dto::download_job::DownloadJob;
+    use crate::enums::common_error::CommonError;
+    use crate::enums::download_job_priority::DownloadJobPriority;
+    use crate::enums::download_job_status::DownloadJobStatus;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use crate::service::download_service::{DynDownloadService, MockDownloadServiceTrait};
+    use crate::service::download_job_service::{DownloadJobService, DownloadJobServiceTrait};
+    use async_trait::async_trait;
+    use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
+
+    /// Scenario:
+    /// Runs a job lease heartbeat while the job lease is owned by other replica (lease lost)
+    /// Expectation:
+    /// The job lease token should be cancelled (job is cancelled) and the heartbeat should be stopped
+    #[tokio::test]
+    async fn when_job_lease_lost_should_cancel_job() {
+        let (download_job_service, _) = create_download_job_service(Some("other-replica"));
+        let job_lease_token = CancellationToken::new();
+
+        let job_lease_heartbeat = download_job_service.start_job_lease_heartbeat(String::from("some-job"), job_lease_token.clone());
+
+        assert!(tokio::time::timeout(Duration::from_secs(5), job_lease_token.cancelled()).await.is_ok());
+        assert!(tokio::time::timeout(Duration::from_secs(1), job_lease_heartbeat).await.is_ok());
+    }
+
+    /// Scenario:
+    /// Takes over a stalled shared job while its lease is owned by other replica and once the lease is expired, and
+    /// starts a new job while its lease can't be acquired
+    /// Expectation:
+    /// The stalled job should be taken over just once its lease is expired and the new job should fail without
+    /// being shared (other replicas can't take it over)
+    #[tokio::test]
+    async fn when_take_over_stalled_jobs_should_take_over_just_expired_leases() {
+        let (download_job_service, lease_owner) = create_download_job_service(Some("other-replica"));
+        let download_job = DownloadJob {
+            job_id: String::from("stalled-job"),
+            status: DownloadJobStatus::RUNNING,
+            priority: DownloadJobPriority::BATCH,
+            tenant_id: None,
+            attempts: 1,
+            errors: Vec::new(),
+            resume_token: None,
+            expires_at: None,
+            artifact_name: None,
+            report: None,
+        };
+        let download_request = r#"{"bucket_name": "some-bucket", "full_path": "some/path"}"#;
+        let shared_state_store = download_job_service.shared_state_store.clone();
+        shared_state_store
+            .set_value(&format!("{SHARED_STATE_JOB_KEY_PREFIX}stalled-job"), &serde_json::to_string(&download_job).unwrap(), 60)
+            .await;
+        shared_state_store.set_value(&format!("{SHARED_STATE_JOB_REQUEST_KEY_PREFIX}stalled-job"), download_request, 60).await;
+
+        assert_eq!(0, download_job_service.take_over_stalled_jobs().await);
+        let new_download_job = download_job_service.create_job(serde_json::from_str(download_request).unwrap()).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let new_download_job = download_job_service.get_job(new_download_job.job_id).await.unwrap();
+        assert_eq!(DownloadJobStatus::FAILED, new_download_job.status);
+        let new_download_job_request_key = format!("{SHARED_STATE_JOB_REQUEST_KEY_PREFIX}{}", new_download_job.job_id);
+        assert!(shared_state_store.get_value(&new_download_job_request_key).await.is_none());
+
+        *lease_owner.lock().unwrap() = None;
+        assert_eq!(1, download_job_service.take_over_stalled_jobs().await);
+        assert!(download_job_service.download_jobs.read().unwrap().contains_key("stalled-job"));
+    }
+
+    /// In memory shared state store (enabled) with a single lease owner for all job leases
+    struct InMemoryStateStore {
+        values: Mutex<HashMap<String, String>>,
+        lease_owner: Arc<Mutex<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl SharedStateStoreTrait for InMemoryStateStore {
+        fn is_enabled(&self) -> bool {
+            true
+        }
+
+        async fn get_value(&self, key: &str) -> Option<String> {
+            self.values.lock().unwrap().get(key).cloned()
+        }
+
+        async fn set_value(&self, key: &str, value: &str, _ttl_seconds: u64) {
+            self.values.lock().unwrap().insert(String::from(key), String::from(value));
+        }
+
+        async fn remove_values(&self, key_prefix: &str) -> usize {
+            let mut values = self.values.lock().unwrap();
+            let values_total = values.len();
+            values.retain(|key, _| !key.starts_with(key_prefix));
+            values_total - values.len()
+        }
+
+        async fn get_keys(&self, key_prefix: &str) -> Vec<String> {
+            self.values.lock().unwrap().keys().filter(|key| key.starts_with(key_prefix)).cloned().collect()
+        }
+
+        async fn acquire_lease(&self, _key: &str, owner: &str, _ttl_seconds: u64) -> bool {
+            let mut lease_owner = self.lease_owner.lock().unwrap();
+            lease_owner.get_or_insert_with(|| String::from(owner)) == owner
+        }
+    }
+
+    /// Creates a new download job service (3 seconds job leases - failed downloads) with an in memory shared state
+    /// store by optional job leases owner (retrieved to change it)
+    fn create_download_job_service(lease_owner: Option<&str>) -> (DownloadJobService, Arc<Mutex<Option<String>>>) {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service.expect_get_s3_archived_objects().returning(|_, _| Err(CommonError::AWS_ACCESS_ERROR));
+        let mut download_service = MockDownloadServiceTrait::new();
+        download_service.expect_download_files().returning(|_| Err(CommonError::NO_VALID_INPUT_OR_PARAMETER));
+        let mut download_job_service = DownloadJobService::new(
+            Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            Arc::new(download_service) as DynDownloadService,
+        );
+        let lease_owner = Arc::new(Mutex::new(lease_owner.map(String::from)));
+        download_job_service.job_lease_seconds = 3;
+        download_job_service.shared_state_store = Arc::new(InMemoryStateStore {
+            values: Mutex::new(HashMap::new()),
+            lease_owner: lease_owner.clone(),
+        }) as DynSharedStateStore;
+        (download_job_service, lease_owner)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::config::shared_state_store::DynSharedStateStore;
+use crate::constant::constants::{EXPORT_TEMPLATE_RETENTION_SECONDS, SHARED_STATE_TEMPLATE_KEY_PREFIX};
+use crate::dto::export_template::ExportTemplate;
+use crate::enums::common_error::CommonError;
+use log::{debug, warn};
+
+/// Export template store struct (saved export templates by template identifier - shared with other API replicas by the
+/// shared state store, if enabled)
+#[derive(Clone)]
+pub(super) struct ExportTemplateStore {
+    shared_state_store: DynSharedStateStore,
+    export_templates: Arc<RwLock<HashMap<String, ExportTemplate>>>,
+}
+
+impl ExportTemplateStore {
+    /// Creates a new [ExportTemplateStore] by [DynSharedStateStore] shared state store
+    pub(super) fn new(shared_state_store: DynSharedStateStore) -> Self {
+        ExportTemplateStore { shared_state_store, export_templates: Arc::new(RwLock::new(HashMap::new())) }
+    }
+
+    /// Stores (creates or replaces) [ExportTemplate] export template
+    /// Important: templates are shared with other API replicas by the shared state store (if enabled)
+    pub(super) async fn save_template(&self, export_template: ExportTemplate) {
+        debug!("save_template - start - template id: {}", export_template.template_id);
+        if self.shared_state_store.is_enabled()
+            && let Ok(export_template_as_json) = serde_json::to_string(&export_template)
+        {
+            self.shared_state_store
+                .set_value(
+                    &format!("{SHARED_STATE_TEMPLATE_KEY_PREFIX}{}", export_template.template_id),
+                    &export_template_as_json,
+                    EXPORT_TEMPLATE_RETENTION_SECONDS,
+                )
+                .await;
+        }
+        self.export_templates.write().unwrap().insert(export_template.template_id.clone(), export_template);
+    }
+
+    /// Gets [ExportTemplate] export template by [String] template identifier
+    /// Important: templates are retrieved from the shared state store (if enabled), so updates and removals by other
+    /// API replicas are always visible
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    pub(super) async fn get_template(&self, template_id: String) -> Result<ExportTemplate, CommonError> {
+        debug!("get_template - start - template id: {template_id}");
+        let export_template = match self.shared_state_store.is_enabled() {
+            true => self.shared_state_store
+                .get_value(&format!("{SHARED_STATE_TEMPLATE_KEY_PREFIX}{template_id}"))
+                .await
+                .and_then(|export_template| serde_json::from_str::<ExportTemplate>(&export_template).ok()),
+            false => self.export_templates.read().unwrap().get(&template_id).cloned(),
+        };
+        export_template.ok_or_else(|| {
+            warn!("get_template - template not found - template id: {template_id}");
+            CommonError::NO_DATA_FOUND
+        })
+    }
+
+    /// Gets [Vec<ExportTemplate>] all export templates (sorted by name)
+    pub(super) async fn get_templates(&self) -> Vec<ExportTemplate> {
+        let mut export_templates = match self.shared_state_store.is_enabled() {
+            true => {
+                let mut export_templates = Vec::new();
+                for template_key in self.shared_state_store.get_keys(SHARED_STATE_TEMPLATE_KEY_PREFIX).await {
+                    export_templates.extend(
+                        self.shared_state_store
+                            .get_value(&template_key)
+                            .await
+                            .and_then(|export_template| serde_json::from_str::<ExportTemplate>(&export_template).ok()),
+                    );
+                }
+                export_templates
+            }
+            false => self.export_templates.read().unwrap().values().cloned().collect(),
+        };
+        export_templates.sort_by(|template, other_template| template.name.cmp(&other_template.name));
+        export_templates
+    }
+
+    /// Removes export template by [String] template identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    pub(super) async fn remove_template(&self, template_id: String) -> Result<(), CommonError> {
+        debug!("remove_template - start - template id: {template_id}");
+        let removed_local = self.export_templates.write().unwrap().remove(&template_id).is_some();
+        let removed_shared = self.shared_state_store.is_enabled()
+            && self.shared_state_store
+                .remove_values(&format!("{SHARED_STATE_TEMPLATE_KEY_PREFIX}{template_id}"))
+                .await
+                > 0;
+        match removed_local || removed_shared {
+            true => Ok(()),
+            false => {
+                warn!("remove_template - template not found - template id: {template_id}");
+                Err(CommonError::NO_DATA_FOUND)
+            }
+        }
+    }
+}
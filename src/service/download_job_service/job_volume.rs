@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use axum::body::Bytes;
+use crate::constant::constants::{DOWNLOAD_JOB_MAX_VOLUMES, DOWNLOAD_JOB_VOLUMES_MANIFEST_FILE_NAME, DOWNLOAD_JOB_VOLUMES_POLL_SECONDS};
+use crate::dto::download_job::DownloadJob;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::export_report::ExportReport;
+use crate::dto::export_volume::ExportVolume;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_status::DownloadJobStatus;
+use crate::middleware::tenant_middleware::get_path_tenant_id;
+use crate::service::download_job_service::job_artifact::create_artifact_url;
+use crate::service::download_job_service::{create_job_result_url, DownloadJobService, DownloadJobServiceTrait};
+use crate::service::download_service::get_filtered_s3_keys;
+use log::{error, info, warn};
+
+/// Download job service part jobs logic (volumes and folder split exports - a coordinator job starts one part job per
+/// S3 key range or first-level sub-folder)
+impl DownloadJobService {
+    /// Gets [(String, Bytes, ExportReport)] volumes manifest file name, manifest content and aggregated export report
+    /// by [&str] coordinator job identifier, [DownloadRequest] download request and [usize] requested volumes:
+    /// the export is partitioned by S3 key ranges and one download job (volume) is started per S3 key range
+    /// Returns a [CommonError] if there are no files to export or any volume job fails
+    /// Important:
+    /// - volumes quantity is limited by [DOWNLOAD_JOB_MAX_VOLUMES] (please, check [DownloadJobService::run_part_jobs])
+    /// - all S3 listing pages are requested (not limited by the configured maximum file quantity) and volume jobs of
+    ///   listed S3 keys fetch them directly (no S3 listing by volume job)
+    pub(super) async fn run_volume_jobs(
+        &self,
+        job_id: &str,
+        mut download_request: DownloadRequest,
+        volumes: usize,
+    ) -> Result<(String, Bytes, ExportReport), CommonError> {
+        let started_at = Instant::now();
+        // filtered exports are partitioned by matching files (volume jobs are not filtered again)
+        let s3_keys_listed = download_request.manifest.is_none() || download_request.filter.is_some();
+        let manifest = match (download_request.manifest.take(), download_request.filter.take()) {
+            (manifest, Some(export_filter)) => {
+                let s3_keys =
+                    get_filtered_s3_keys(&self.aws_s3_service, &download_request.bucket_name, &download_request.full_path, &export_filter)
+                        .await?;
+                match manifest {
+                    Some(manifest) => manifest
+                        .into_iter()
+                        .filter(|manifest_entry| s3_keys.binary_search(&manifest_entry.s3_key).is_ok())
+                        .collect(),
+                    None => s3_keys.into_iter().map(|s3_key| ManifestEntry { s3_key, archive_name: None }).collect(),
+                }
+            }
+            (Some(manifest), None) => manifest,
+            (None, None) => self
+                .aws_s3_service
+                .get_s3_all_object_key_list(
+                    download_request.bucket_name.clone(),
+                    download_request.full_path.clone(),
+                    download_request.allow_oversize,
+                )
+                .await?
+                .into_iter()
+                .map(|s3_key| ManifestEntry { s3_key, archive_name: None })
+                .collect(),
+        };
+        let volume_manifests = partition_manifest(manifest, volumes.min(DOWNLOAD_JOB_MAX_VOLUMES));
+        if volume_manifests.is_empty() {
+            warn!("run_volume_jobs - no files to export - job id: {job_id}");
+            return Err(CommonError::NO_DATA_FOUND);
+        }
+
+        let part_jobs = volume_manifests
+            .into_iter()
+            .enumerate()
+            .map(|(volume, volume_manifest)| {
+                let export_volume = ExportVolume {
+                    volume,
+                    job_id: format!("{job_id}-volume-{volume}"),
+                    folder: None,
+                    first_s3_key: volume_manifest.first().map(|manifest_entry| manifest_entry.s3_key.clone()).unwrap_or_default(),
+                    last_s3_key: volume_manifest.last().map(|manifest_entry| manifest_entry.s3_key.clone()).unwrap_or_default(),
+                    files_total: volume_manifest.len(),
+                    result_location: None,
+                };
+                let mut part_request = create_part_request(&download_request, download_request.full_path.clone(), Some(volume_manifest));
+                part_request.direct_key_lookup |= s3_keys_listed;
+                (export_volume, part_request)
+            })
+            .collect();
+        self.run_part_jobs(job_id, part_jobs, download_request.public_base_url.as_deref(), started_at).await
+    }
+
+    /// Gets [(String, Bytes, ExportReport)] folders manifest file name, manifest content and aggregated export report
+    /// by [&str] coordinator job identifier and [DownloadRequest] download request: one download job (zip file) is started
+    /// per first-level sub-folder of the S3 path (i.e.: one zip file per customer folder) and one more for the S3 objects
+    /// directly under the S3 path (if any)
+    /// Returns a [CommonError] if there are no files to export, folders are more than the configured maximum folder
+    /// archives or any folder job fails
+    /// Important: if a manifest is requested, manifest entries are grouped by the first segment of their S3 keys - if a
+    /// filter is requested, folders are exported by their matching files (folders without matching files are skipped)
+    pub(super) async fn run_folder_jobs(&self, job_id: &str, mut download_request: DownloadRequest) -> Result<(String, Bytes, ExportReport), CommonError> {
+        let started_at = Instant::now();
+        let folder_full_path_fn = |folder: &Option<String>| match folder {
+            Some(folder) => format!("{}/{folder}", download_request.full_path.trim_end_matches('/')),
+            None => download_request.full_path.clone(),
+        };
+        let mut folder_manifests = match download_request.manifest.take() {
+            Some(manifest) => partition_manifest_by_folder(manifest),
+            None => {
+                let (s3_object_metadata_list, s3_sub_folders) = self
+                    .aws_s3_service
+                    .get_s3_first_level_entries(download_request.bucket_name.clone(), download_request.full_path.clone())
+                    .await?;
+                let root_manifest = s3_object_metadata_list
+                    .into_iter()
+                    .map(|s3_object_metadata| ManifestEntry { s3_key: s3_object_metadata.s3_key, archive_name: None })
+                    .collect::<Vec<ManifestEntry>>();
+                let mut folder_manifests: BTreeMap<Option<String>, Option<Vec<ManifestEntry>>> =
+                    s3_sub_folders.into_iter().map(|s3_sub_folder| (Some(s3_sub_folder), None)).collect();
+                if !root_manifest.is_empty() {
+                    folder_manifests.insert(None, Some(root_manifest));
+                }
+                folder_manifests
+            }
+        };
+        if folder_manifests.is_empty() {
+            warn!("run_folder_jobs - no files to export - job id: {job_id}");
+            return Err(CommonError::NO_DATA_FOUND);
+        }
+        if folder_manifests.len() > self.job_max_folder_archives {
+            warn!("run_folder_jobs - too many folders - job id: {job_id} - folders: {}", folder_manifests.len());
+            return Err(CommonError::LIMIT_EXCEEDED { limit: self.job_max_folder_archives, actual: folder_manifests.len() });
+        }
+        if let Some(export_filter) = download_request.filter.take() {
+            let mut filtered_folder_manifests = BTreeMap::new();
+            for (folder, folder_manifest) in folder_manifests {
+                let s3_keys =
+                    get_filtered_s3_keys(&self.aws_s3_service, &download_request.bucket_name, &folder_full_path_fn(&folder), &export_filter)
+                        .await?;
+                let folder_manifest: Vec<ManifestEntry> = match folder_manifest {
+                    Some(folder_manifest) => folder_manifest
+                        .into_iter()
+                        .filter(|manifest_entry| s3_keys.binary_search(&manifest_entry.s3_key).is_ok())
+                        .collect(),
+                    None => s3_keys.into_iter().map(|s3_key| ManifestEntry { s3_key, archive_name: None }).collect(),
+                };
+                if !folder_manifest.is_empty() {
+                    filtered_folder_manifests.insert(folder, Some(folder_manifest));
+                }
+            }
+            if filtered_folder_manifests.is_empty() {
+                warn!("run_folder_jobs - no matching files to export - job id: {job_id}");
+                return Err(CommonError::NO_DATA_FOUND);
+            }
+            folder_manifests = filtered_folder_manifests;
+        }
+
+        let part_jobs = folder_manifests
+            .into_iter()
+            .enumerate()
+            .map(|(volume, (folder, folder_manifest))| {
+                let full_path = folder_full_path_fn(&folder);
+                let export_volume = ExportVolume {
+                    volume,
+                    job_id: format!("{job_id}-folder-{volume}"),
+                    folder,
+                    first_s3_key: String::new(),
+                    last_s3_key: String::new(),
+                    files_total: folder_manifest.as_ref().map(Vec::len).unwrap_or_default(),
+                    result_location: None,
+                };
+                (export_volume, create_part_request(&download_request, full_path, folder_manifest))
+            })
+            .collect();
+        self.run_part_jobs(job_id, part_jobs, download_request.public_base_url.as_deref(), started_at).await
+    }
+
+    /// Gets [(String, Bytes, ExportReport)] parts manifest file name, manifest content and aggregated export report by
+    /// [&str] coordinator job identifier and [Vec<(ExportVolume, DownloadRequest)>] part jobs (export volumes or folders
+    /// with their download requests), started at [Instant] coordinator start - part zip file URLs are built by
+    /// [Option<&str>] forwarded public base URL
+    /// Returns a [CommonError] if any part job fails
+    /// Important: first part job is run by this replica, other part jobs are taken over by any replica if shared state
+    /// store is enabled (otherwise, or if they run under caller credentials or session tags, all part jobs are run by
+    /// this replica)
+    async fn run_part_jobs(
+        &self,
+        job_id: &str,
+        part_jobs: Vec<(ExportVolume, DownloadRequest)>,
+        public_base_url: Option<&str>,
+        started_at: Instant,
+    ) -> Result<(String, Bytes, ExportReport), CommonError> {
+        let mut export_volumes = Vec::new();
+        for (part, (export_volume, part_request)) in part_jobs.into_iter().enumerate() {
+            let part_job = DownloadJob {
+                job_id: export_volume.job_id.clone(),
+                status: DownloadJobStatus::PENDING,
+                priority: part_request.priority,
+                tenant_id: get_path_tenant_id(&part_request.full_path),
+                attempts: 0,
+                errors: Vec::new(),
+                resume_token: None,
+                expires_at: None,
+                artifact_name: None,
+                report: None,
+            };
+            if part == 0 || !self.is_job_request_shareable() {
+                self.start_job(part_job, part_request);
+            } else {
+                self.share_job(&part_job).await;
+                self.share_job_request(&part_job.job_id, &part_request).await;
+            }
+            export_volumes.push(export_volume);
+        }
+        info!("run_part_jobs - part jobs started - job id: {job_id} - parts: {}", export_volumes.len());
+
+        let mut volume_reports = Vec::new();
+        for export_volume in export_volumes.iter_mut() {
+            let volume_job = loop {
+                let volume_job = self.get_job(export_volume.job_id.clone()).await?;
+                if volume_job.status.is_finished() {
+                    break volume_job;
+                }
+                tokio::time::sleep(Duration::from_secs(DOWNLOAD_JOB_VOLUMES_POLL_SECONDS)).await;
+            };
+            let (Some(resume_token), Some(volume_report)) = (volume_job.resume_token, volume_job.report) else {
+                error!("run_part_jobs - part job failed - job id: {}", export_volume.job_id);
+                return Err(CommonError::AWS_ACCESS_ERROR);
+            };
+            // stored artifacts are reachable from any replica (job results are kept by the replica which run the part job)
+            export_volume.result_location = Some(match volume_job.artifact_name {
+                Some(artifact_name) => create_artifact_url(&artifact_name, public_base_url),
+                None => create_job_result_url(&resume_token, public_base_url),
+            });
+            export_volume.files_total = volume_report.files_total;
+            volume_reports.push(volume_report);
+        }
+
+        let volumes_manifest = serde_json::to_vec_pretty(&export_volumes).map_err(|_| CommonError::AWS_ACCESS_ERROR)?;
+        let export_report = create_volumes_export_report(started_at.elapsed().as_millis() as u64, &volume_reports);
+        Ok((String::from(DOWNLOAD_JOB_VOLUMES_MANIFEST_FILE_NAME), Bytes::from(volumes_manifest), export_report))
+    }
+}
+
+/// Gets [Vec<Vec<ManifestEntry>>] volume manifests (contiguous S3 key ranges with similar files quantity)
+/// by [Vec<ManifestEntry>] manifest and [usize] volumes
+fn partition_manifest(mut manifest: Vec<ManifestEntry>, volumes: usize) -> Vec<Vec<ManifestEntry>> {
+    if manifest.is_empty() {
+        return Vec::new();
+    }
+
+    manifest.sort_by(|manifest_entry, other_manifest_entry| manifest_entry.s3_key.cmp(&other_manifest_entry.s3_key));
+    let volume_size = manifest.len().div_ceil(volumes.max(1));
+    manifest.chunks(volume_size).map(|volume_manifest| volume_manifest.to_vec()).collect()
+}
+
+/// Gets [BTreeMap<Option<String>, Option<Vec<ManifestEntry>>>] folder manifests by first-level sub-folder (none for S3
+/// keys without sub-folder) by [Vec<ManifestEntry>] manifest - S3 keys are relative to their sub-folder
+fn partition_manifest_by_folder(manifest: Vec<ManifestEntry>) -> BTreeMap<Option<String>, Option<Vec<ManifestEntry>>> {
+    let mut folder_manifests: BTreeMap<Option<String>, Option<Vec<ManifestEntry>>> = BTreeMap::new();
+    for manifest_entry in manifest {
+        let (folder, s3_key) = match manifest_entry.s3_key.split_once('/') {
+            Some((folder, s3_key)) => (Some(String::from(folder)), String::from(s3_key)),
+            None => (None, manifest_entry.s3_key),
+        };
+        folder_manifests
+            .entry(folder)
+            .or_default()
+            .get_or_insert_with(Vec::new)
+            .push(ManifestEntry { s3_key, archive_name: manifest_entry.archive_name });
+    }
+    folder_manifests
+}
+
+/// Creates a new [DownloadRequest] part (volume or folder) download request by [&DownloadRequest] coordinator download
+/// request, [String] part S3 path and [Option<Vec<ManifestEntry>>] part manifest (part jobs are not partitioned again)
+fn create_part_request(download_request: &DownloadRequest, full_path: String, manifest: Option<Vec<ManifestEntry>>) -> DownloadRequest {
+    DownloadRequest {
+        bucket_name: download_request.bucket_name.clone(),
+        full_path,
+        deduplicate: download_request.deduplicate,
+        max_parallel_fetches: download_request.max_parallel_fetches,
+        multipart_chunk_bytes: download_request.multipart_chunk_bytes,
+        manifest,
+        filter: None,
+        duplicate_entry_policy: download_request.duplicate_entry_policy,
+        missing_key_policy: download_request.missing_key_policy,
+        direct_key_lookup: download_request.direct_key_lookup,
+        allow_oversize: download_request.allow_oversize,
+        encryption_recipient: download_request.encryption_recipient.clone(),
+        notify_email: None,
+        volumes: None,
+        split_by_folder: false,
+        priority: download_request.priority,
+        caller_id: download_request.caller_id.clone(),
+        restore: download_request.restore.clone(),
+        metadata_report: download_request.metadata_report,
+        checksum_audit: download_request.checksum_audit,
+        public_base_url: download_request.public_base_url.clone(),
+        cancellation_token: download_request.cancellation_token.clone(),
+    }
+}
+
+/// Creates a new [ExportReport] aggregated export report by [u64] coordinator elapsed time (in milliseconds)
+/// and [&[ExportReport]] volume export reports (per file statistics are kept by volume jobs)
+fn create_volumes_export_report(elapsed_millis: u64, volume_reports: &[ExportReport]) -> ExportReport {
+    let bytes_total = volume_reports.iter().map(|volume_report| volume_report.bytes_total).sum();
+    ExportReport {
+        elapsed_millis,
+        files_total: volume_reports.iter().map(|volume_report| volume_report.files_total).sum(),
+        bytes_total,
+        throughput_bytes_per_second: bytes_total * 1000 / elapsed_millis.max(1),
+        retries_total: volume_reports.iter().map(|volume_report| volume_report.retries_total).sum(),
+        slowest_files: Vec::new(),
+        files: Vec::new(),
+        missing_files: volume_reports.iter().flat_map(|volume_report| volume_report.missing_files.clone()).collect(),
+        excluded_files: volume_reports.iter().flat_map(|volume_report| volume_report.excluded_files.clone()).collect(),
+        checksum_mismatches: volume_reports.iter().flat_map(|volume_report| volume_report.checksum_mismatches.clone()).collect(),
+        archive_sha256: None,
+        archive_signature: None,
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::manifest_entry::ManifestEntry;
+    use crate::service::download_job_service::job_volume::{partition_manifest, partition_manifest_by_folder};
+
+    /// Scenario:
+    /// Partitions an unsorted manifest with five S3 keys into two volumes
+    /// Expectation:
+    /// Two contiguous S3 key ranges should be retrieved (three and two S3 keys)
+    #[test]
+    fn when_partition_manifest_should_split_sorted_key_ranges() {
+        let manifest = ["d.txt", "a.txt", "e.txt", "c.txt", "b.txt"]
+            .into_iter()
+            .map(|s3_key| ManifestEntry { s3_key: String::from(s3_key), archive_name: None })
+            .collect();
+
+        let volume_manifests = partition_manifest(manifest, 2);
+
+        let volume_keys: Vec<Vec<&str>> = volume_manifests
+            .iter()
+            .map(|volume_manifest| volume_manifest.iter().map(|manifest_entry| manifest_entry.s3_key.as_str()).collect())
+            .collect();
+        assert_eq!(vec![vec!["a.txt", "b.txt", "c.txt"], vec!["d.txt", "e.txt"]], volume_keys);
+    }
+
+    /// Scenario:
+    /// Partitions a manifest with root and nested S3 keys of two customer folders by folder
+    /// Expectation:
+    /// One manifest per first-level sub-folder (S3 keys relative to it) and one root manifest should be retrieved
+    #[test]
+    fn when_partition_manifest_by_folder_should_group_by_first_level_folder() {
+        let manifest = ["acme/a.txt", "root.txt", "globex/reports/b.txt", "acme/c.txt"]
+            .into_iter()
+            .map(|s3_key| ManifestEntry { s3_key: String::from(s3_key), archive_name: None })
+            .collect();
+
+        let folder_manifests = partition_manifest_by_folder(manifest);
+
+        let folder_keys: Vec<(Option<&str>, Vec<&str>)> = folder_manifests
+            .iter()
+            .map(|(folder, folder_manifest)| {
+                let s3_keys = folder_manifest.iter().flatten().map(|manifest_entry| manifest_entry.s3_key.as_str()).collect();
+                (folder.as_deref(), s3_keys)
+            })
+            .collect();
+        assert_eq!(
+            vec![(None, vec!["root.txt"]), (Some("acme"), vec!["a.txt", "c.txt"]), (Some("globex"), vec!["reports/b.txt"])],
+            folder_keys
+        );
+    }
+}
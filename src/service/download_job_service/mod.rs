@@ -0,0 +1,882 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use crate::config::caller_credentials::spawn_with_caller_credentials;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::{get_env_var_as_u64, get_env_var_as_usize};
+use crate::config::email_notifier::EMAIL_NOTIFIER;
+use crate::config::archive_signer::ARCHIVE_SIGNER;
+use crate::config::error_reporter::ERROR_REPORTER;
+use crate::config::job_event_publisher::JOB_EVENT_PUBLISHER;
+use crate::config::memory_budget::{MemoryReservation, MEMORY_BUDGET};
+use crate::config::shared_state_store::{DynSharedStateStore, SHARED_STATE_STORE};
+use crate::config::base_path::create_public_base_url;
+use crate::constant::constants::{API_DOWNLOAD_JOB_BY_ID_PATH, API_DOWNLOAD_JOB_RESULT_PATH, API_DOWNLOAD_MAIN_PATH, DOWNLOAD_JOB_MEMORY_BUDGET_WAIT_SECONDS, DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_DEFAULT, DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_ENV_VAR, SHARED_STATE_JOB_KEY_PREFIX, SHARED_STATE_JOB_REQUEST_KEY_PREFIX, SHARED_STATE_HISTORY_KEY_PREFIX, EXPORT_HISTORY_RETENTION_SECONDS_DEFAULT, EXPORT_HISTORY_RETENTION_SECONDS_ENV_VAR, DOWNLOAD_JOB_LEASE_SECONDS_DEFAULT, DOWNLOAD_JOB_LEASE_SECONDS_ENV_VAR, DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_DEFAULT, DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_ENV_VAR, DOWNLOAD_JOB_BATCH_CONCURRENCY_DEFAULT, DOWNLOAD_JOB_BATCH_CONCURRENCY_ENV_VAR, DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_DEFAULT, DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_ENV_VAR, DOWNLOAD_JOB_MAX_ATTEMPTS_DEFAULT, DOWNLOAD_JOB_MAX_ATTEMPTS_ENV_VAR, DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_DEFAULT, DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_ENV_VAR, DOWNLOAD_JOB_RETRY_MAX_BACKOFF_SECONDS, DOWNLOAD_JOB_RESTORE_DAYS_DEFAULT, DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_DEFAULT, DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_ENV_VAR, DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_DEFAULT, DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_ENV_VAR};
+use crate::dto::download_job::DownloadJob;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::export_record::ExportRecord;
+use crate::dto::export_report::ExportReport;
+use crate::dto::export_template::ExportTemplate;
+use crate::dto::job_event::JobEvent;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::dto::restore_request::RestoreRequest;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::download_job_status::DownloadJobStatus;
+use crate::enums::s3_restore_status::S3RestoreStatus;
+use crate::middleware::tenant_middleware::get_path_tenant_id;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use crate::service::download_job_service::job_artifact::ArtifactStore;
+use crate::service::download_job_service::job_history::select_export_records;
+use crate::service::download_job_service::job_template::ExportTemplateStore;
+use crate::service::download_service::{encode_archive_digest, DownloadService, DynDownloadService};
+use log::{debug, error, info, warn};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+mod job_artifact;
+mod job_history;
+mod job_lease;
+mod job_template;
+mod job_volume;
+
+/// Download job service
+#[async_trait]
+pub trait DownloadJobServiceTrait {
+    /// Creates a new [DownloadJob] (pending status) that downloads all files from Amazon S3
+    /// by [DownloadRequest] download request in background
+    async fn create_job(&self, download_request: DownloadRequest) -> DownloadJob;
+
+    /// Gets [DownloadJob] by [String] job identifier
+    /// Returns a [CommonError] if job is not found
+    async fn get_job(&self, job_id: String) -> Result<DownloadJob, CommonError>;
+
+    /// Gets [(String, Bytes)] stored zip file name and content by [String] resume token
+    /// Returns a [CommonError] if result is not found or retention window is expired
+    async fn get_job_result(&self, resume_token: String) -> Result<(String, Bytes), CommonError>;
+
+    /// Gets [(String, Bytes)] stored artifact name and content by [String] content-addressable artifact name
+    /// Returns a [CommonError] if artifact store is not enabled, artifact name is not valid or artifact is not found
+    async fn get_artifact(&self, artifact_name: String) -> Result<(String, Bytes), CommonError>;
+
+    /// Removes all jobs (and stored results) with an expired retention window
+    /// Returns [(usize, u64)] removed jobs total and reclaimed result bytes total
+    async fn remove_expired_jobs(&self) -> (usize, u64);
+
+    /// Takes over stalled jobs (pending or running jobs with an expired lease) from other API replicas
+    /// (just if shared state store is enabled)
+    /// Returns [usize] taken over jobs total
+    async fn take_over_stalled_jobs(&self) -> usize;
+
+    /// Stores (creates or replaces) [ExportTemplate] export template
+    async fn save_template(&self, export_template: ExportTemplate);
+
+    /// Gets [ExportTemplate] export template by [String] template identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    async fn get_template(&self, template_id: String) -> Result<ExportTemplate, CommonError>;
+
+    /// Gets [Vec<ExportTemplate>] all export templates (sorted by name)
+    async fn get_templates(&self) -> Vec<ExportTemplate>;
+
+    /// Removes export template by [String] template identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    async fn remove_template(&self, template_id: String) -> Result<(), CommonError>;
+
+    /// Gets [Vec<ExportRecord>] export history records (finished download jobs - newest records first) by [Option<String>]
+    /// S3 bucket and [Option<u64>] from and [Option<u64>] to finish dates (seconds since unix epoch - both inclusive)
+    async fn get_export_records(&self, bucket_name: Option<String>, from: Option<u64>, to: Option<u64>) -> Vec<ExportRecord>;
+
+    /// Gets [ExportRecord] export history record by [String] job identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if record is not found (or its retention window is expired)
+    async fn get_export_record(&self, job_id: String) -> Result<ExportRecord, CommonError>;
+
+    /// Creates a new [DownloadJob] (pending status) by [ExportRecord] previous export record (same download request) and
+    /// [bool] incremental mode flag (just files not exported by the previous job or modified since it started are exported)
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if incremental mode is requested for a not completed job
+    /// or a job without recorded exported files (volume or folder split jobs), a [CommonError::NO_DATA_FOUND] if
+    /// there are no new or modified files or a [CommonError] if S3 throws any error
+    async fn rerun_job(&self, export_record: ExportRecord, incremental: bool) -> Result<DownloadJob, CommonError>;
+}
+
+/// Download job entry (job + stored result + stored result memory budget reservation - released once the entry is
+/// removed)
+struct DownloadJobEntry {
+    job: DownloadJob,
+    result: Option<(String, Bytes)>,
+    result_reservation: Option<MemoryReservation>,
+}
+
+/// Download job service implementation struct
+#[derive(Clone)]
+pub struct DownloadJobService {
+    aws_s3_service: DynAwsSdkS3Service,
+    download_service: DynDownloadService,
+    shared_state_store: DynSharedStateStore,
+    download_jobs: Arc<RwLock<HashMap<String, DownloadJobEntry>>>,
+    export_template_store: ExportTemplateStore,
+    export_records: Arc<RwLock<VecDeque<ExportRecord>>>,
+    export_history_retention_seconds: u64,
+    job_result_retention_seconds: u64,
+    job_lease_seconds: u64,
+    interactive_job_permits: Arc<Semaphore>,
+    batch_job_permits: Arc<Semaphore>,
+    job_max_attempts: u32,
+    job_retry_backoff_seconds: u64,
+    job_restore_poll_interval_seconds: u64,
+    job_restore_max_wait_seconds: u64,
+    job_max_folder_archives: usize,
+    artifact_store: ArtifactStore,
+}
+
+/// initialization by shared dependencies
+impl DownloadJobService {
+    /// Creates a new [DownloadJobService] by [DynAwsSdkS3Service] AWS S3 service and [DynDownloadService] download service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service, download_service: DynDownloadService) -> Self {
+        DownloadJobService {
+            aws_s3_service: aws_s3_service.clone(),
+            download_service,
+            shared_state_store: SHARED_STATE_STORE.clone(),
+            download_jobs: Arc::new(RwLock::new(HashMap::new())),
+            export_template_store: ExportTemplateStore::new(SHARED_STATE_STORE.clone()),
+            export_records: Arc::new(RwLock::new(VecDeque::new())),
+            export_history_retention_seconds: get_env_var_as_u64(
+                EXPORT_HISTORY_RETENTION_SECONDS_ENV_VAR,
+                EXPORT_HISTORY_RETENTION_SECONDS_DEFAULT,
+            )
+            .max(1),
+            job_result_retention_seconds: get_env_var_as_u64(
+                DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_ENV_VAR,
+                DOWNLOAD_JOB_RESULT_RETENTION_SECONDS_DEFAULT,
+            ),
+            job_lease_seconds: get_env_var_as_u64(DOWNLOAD_JOB_LEASE_SECONDS_ENV_VAR, DOWNLOAD_JOB_LEASE_SECONDS_DEFAULT).max(3),
+            interactive_job_permits: Arc::new(Semaphore::new(
+                get_env_var_as_usize(DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_ENV_VAR, DOWNLOAD_JOB_INTERACTIVE_CONCURRENCY_DEFAULT).max(1),
+            )),
+            batch_job_permits: Arc::new(Semaphore::new(
+                get_env_var_as_usize(DOWNLOAD_JOB_BATCH_CONCURRENCY_ENV_VAR, DOWNLOAD_JOB_BATCH_CONCURRENCY_DEFAULT).max(1),
+            )),
+            job_max_attempts: get_env_var_as_u64(DOWNLOAD_JOB_MAX_ATTEMPTS_ENV_VAR, DOWNLOAD_JOB_MAX_ATTEMPTS_DEFAULT).clamp(1, u32::MAX as u64) as u32,
+            job_retry_backoff_seconds: get_env_var_as_u64(DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_ENV_VAR, DOWNLOAD_JOB_RETRY_BACKOFF_SECONDS_DEFAULT),
+            job_restore_poll_interval_seconds: get_env_var_as_u64(
+                DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_ENV_VAR,
+                DOWNLOAD_JOB_RESTORE_POLL_INTERVAL_SECONDS_DEFAULT,
+            )
+            .max(1),
+            job_restore_max_wait_seconds: get_env_var_as_u64(DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_ENV_VAR, DOWNLOAD_JOB_RESTORE_MAX_WAIT_SECONDS_DEFAULT),
+            job_max_folder_archives: get_env_var_as_usize(DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_ENV_VAR, DOWNLOAD_JOB_MAX_FOLDER_ARCHIVES_DEFAULT).max(1),
+            artifact_store: ArtifactStore::new(aws_s3_service),
+        }
+    }
+}
+
+/// default initialization
+impl Default for DownloadJobService {
+    fn default() -> Self {
+        DownloadJobService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service, Arc::new(DownloadService::default()) as DynDownloadService)
+    }
+}
+
+/// Download job service implementation logic
+#[async_trait]
+impl DownloadJobServiceTrait for DownloadJobService {
+    /// Creates a new [DownloadJob] (pending status) that downloads all files from Amazon S3
+    /// by [DownloadRequest] download request in background
+    async fn create_job(&self, download_request: DownloadRequest) -> DownloadJob {
+        info!("create_job - start");
+        let download_job = DownloadJob {
+            job_id: Uuid::new_v4().to_string(),
+            status: DownloadJobStatus::PENDING,
+            priority: download_request.priority,
+            tenant_id: get_path_tenant_id(&download_request.full_path),
+            attempts: 0,
+            errors: Vec::new(),
+            resume_token: None,
+            expires_at: None,
+            artifact_name: None,
+            report: None,
+        };
+
+        self.start_job(download_job.clone(), download_request);
+
+        info!("create_job - done - job id: {}", &download_job.job_id);
+        download_job
+    }
+
+    /// Gets [DownloadJob] by [String] job identifier
+    /// Important: jobs created by other API replicas are retrieved from the shared state store (if enabled)
+    /// Returns a [CommonError] if job is not found
+    async fn get_job(&self, job_id: String) -> Result<DownloadJob, CommonError> {
+        debug!("get_job - start - job id: {job_id}");
+        let local_download_job = self
+            .download_jobs
+            .read()
+            .unwrap()
+            .get(&job_id)
+            .map(|download_job_entry| download_job_entry.job.clone());
+        let download_job = match local_download_job {
+            Some(download_job) => Some(download_job),
+            None => self.shared_state_store
+                .get_value(&format!("{SHARED_STATE_JOB_KEY_PREFIX}{job_id}"))
+                .await
+                .and_then(|download_job| serde_json::from_str::<DownloadJob>(&download_job).ok()),
+        };
+
+        match download_job {
+            Some(download_job) => {
+                debug!("get_job - done");
+                Ok(download_job)
+            }
+            None => {
+                warn!("get_job - job not found - job id: {job_id}");
+                Err(CommonError::NO_DATA_FOUND)
+            }
+        }
+    }
+
+    /// Gets [(String, Bytes)] stored zip file name and content by [String] resume token
+    /// Returns a [CommonError] if result is not found or retention window is expired
+    async fn get_job_result(&self, resume_token: String) -> Result<(String, Bytes), CommonError> {
+        debug!("get_job_result - start");
+        let now = current_epoch_seconds();
+        let download_jobs = self.download_jobs.read().unwrap();
+
+        match download_jobs.values().find(|download_job_entry| {
+            download_job_entry.job.resume_token.as_ref() == Some(&resume_token)
+                && download_job_entry.job.expires_at.unwrap_or_default() > now
+        }) {
+            Some(DownloadJobEntry { result: Some(job_result), .. }) => {
+                debug!("get_job_result - done");
+                Ok(job_result.clone())
+            }
+            _ => {
+                warn!("get_job_result - job result not found or expired");
+                Err(CommonError::NO_DATA_FOUND)
+            }
+        }
+    }
+
+    /// Gets [(String, Bytes)] stored artifact name and content by [String] content-addressable artifact name
+    /// (please, check [ArtifactStore::get_artifact])
+    async fn get_artifact(&self, artifact_name: String) -> Result<(String, Bytes), CommonError> {
+        self.artifact_store.get_artifact(artifact_name).await
+    }
+
+    /// Removes all jobs (and stored results) with an expired retention window
+    /// Returns [(usize, u64)] removed jobs total and reclaimed result bytes total
+    async fn remove_expired_jobs(&self) -> (usize, u64) {
+        debug!("remove_expired_jobs - start");
+        let now = current_epoch_seconds();
+        let mut removed_jobs_total = 0;
+        let mut reclaimed_bytes_total = 0;
+
+        self.download_jobs.write().unwrap().retain(|_, download_job_entry| {
+            let expired = download_job_entry.job.expires_at.is_some_and(|expires_at| expires_at <= now);
+            if expired {
+                removed_jobs_total += 1;
+                reclaimed_bytes_total += download_job_entry
+                    .result
+                    .as_ref()
+                    .map(|job_result| job_result.1.len() as u64)
+                    .unwrap_or_default();
+            }
+            !expired
+        });
+
+        debug!("remove_expired_jobs - done");
+        (removed_jobs_total, reclaimed_bytes_total)
+    }
+
+    /// Takes over stalled jobs (pending or running jobs with an expired lease) from other API replicas
+    /// (just if shared state store is enabled)
+    /// Returns [usize] taken over jobs total
+    async fn take_over_stalled_jobs(&self) -> usize {
+        debug!("take_over_stalled_jobs - start");
+        let mut taken_over_jobs_total = 0;
+
+        for job_key in self.shared_state_store.get_keys(SHARED_STATE_JOB_KEY_PREFIX).await {
+            let job_id = job_key.trim_start_matches(SHARED_STATE_JOB_KEY_PREFIX);
+            if self.download_jobs.read().unwrap().contains_key(job_id) {
+                continue;
+            }
+            let Some(download_job) = self.shared_state_store
+                .get_value(&job_key)
+                .await
+                .and_then(|download_job| serde_json::from_str::<DownloadJob>(&download_job).ok())
+                .filter(|download_job| !download_job.status.is_finished())
+            else {
+                continue;
+            };
+            let Some(download_request) = self.shared_state_store
+                .get_value(&format!("{SHARED_STATE_JOB_REQUEST_KEY_PREFIX}{job_id}"))
+                .await
+                .and_then(|download_request| serde_json::from_str::<DownloadRequest>(&download_request).ok())
+            else {
+                continue;
+            };
+            if !self.acquire_job_lease(job_id).await {
+                continue;
+            }
+
+            warn!("take_over_stalled_jobs - stalled job taken over - job id: {job_id}");
+            let download_job = DownloadJob { status: DownloadJobStatus::PENDING, ..download_job };
+            self.download_jobs.write().unwrap().insert(
+                download_job.job_id.clone(),
+                DownloadJobEntry { job: download_job.clone(), result: None, result_reservation: None },
+            );
+            let download_job_service = self.clone();
+            tokio::spawn(async move { download_job_service.run_job(download_job.job_id, download_request).await });
+            taken_over_jobs_total += 1;
+        }
+
+        debug!("take_over_stalled_jobs - done");
+        taken_over_jobs_total
+    }
+
+    /// Stores (creates or replaces) [ExportTemplate] export template (please, check [ExportTemplateStore::save_template])
+    async fn save_template(&self, export_template: ExportTemplate) {
+        self.export_template_store.save_template(export_template).await
+    }
+
+    /// Gets [ExportTemplate] export template by [String] template identifier (please, check [ExportTemplateStore::get_template])
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    async fn get_template(&self, template_id: String) -> Result<ExportTemplate, CommonError> {
+        self.export_template_store.get_template(template_id).await
+    }
+
+    /// Gets [Vec<ExportTemplate>] all export templates (sorted by name)
+    async fn get_templates(&self) -> Vec<ExportTemplate> {
+        self.export_template_store.get_templates().await
+    }
+
+    /// Removes export template by [String] template identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if template is not found
+    async fn remove_template(&self, template_id: String) -> Result<(), CommonError> {
+        self.export_template_store.remove_template(template_id).await
+    }
+
+    /// Gets [Vec<ExportRecord>] export history records (finished download jobs - newest records first) by [Option<String>]
+    /// S3 bucket and [Option<u64>] from and [Option<u64>] to finish dates (seconds since unix epoch - both inclusive)
+    /// Important: records are retrieved from the shared state store (if enabled), so jobs finished by other API replicas
+    /// are included
+    async fn get_export_records(&self, bucket_name: Option<String>, from: Option<u64>, to: Option<u64>) -> Vec<ExportRecord> {
+        debug!("get_export_records - start");
+        let export_records = match self.shared_state_store.is_enabled() {
+            true => {
+                let mut export_records = Vec::new();
+                for record_key in self.shared_state_store.get_keys(SHARED_STATE_HISTORY_KEY_PREFIX).await {
+                    export_records.extend(
+                        self.shared_state_store
+                            .get_value(&record_key)
+                            .await
+                            .and_then(|export_record| serde_json::from_str::<ExportRecord>(&export_record).ok()),
+                    );
+                }
+                export_records
+            }
+            false => self.export_records.read().unwrap().iter().cloned().collect(),
+        };
+        let expired_at = current_epoch_seconds().saturating_sub(self.export_history_retention_seconds);
+        select_export_records(export_records, bucket_name.as_deref(), from.unwrap_or_default().max(expired_at), to)
+    }
+
+    /// Gets [ExportRecord] export history record by [String] job identifier
+    /// Returns a [CommonError::NO_DATA_FOUND] if record is not found (or its retention window is expired)
+    async fn get_export_record(&self, job_id: String) -> Result<ExportRecord, CommonError> {
+        debug!("get_export_record - start - job id: {job_id}");
+        let export_record = match self.shared_state_store.is_enabled() {
+            true => self.shared_state_store
+                .get_value(&format!("{SHARED_STATE_HISTORY_KEY_PREFIX}{job_id}"))
+                .await
+                .and_then(|export_record| serde_json::from_str::<ExportRecord>(&export_record).ok()),
+            false => self.export_records.read().unwrap().iter().rev().find(|export_record| export_record.job_id == job_id).cloned(),
+        };
+        let expired_at = current_epoch_seconds().saturating_sub(self.export_history_retention_seconds);
+        export_record.filter(|export_record| export_record.finished_at >= expired_at).ok_or_else(|| {
+            warn!("get_export_record - export record not found - job id: {job_id}");
+            CommonError::NO_DATA_FOUND
+        })
+    }
+
+    /// Creates a new [DownloadJob] (pending status) by [ExportRecord] previous export record (same download request) and
+    /// [bool] incremental mode flag (just files not exported by the previous job or modified since it started are exported)
+    /// Important: in incremental mode, the download request manifest is replaced by the new or modified files (just the
+    /// previous manifest entries, if the previous export had a manifest - filters are applied again once the job runs)
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if incremental mode is requested for a not completed job
+    /// or a job without recorded exported files (volume or folder split jobs), a [CommonError::NO_DATA_FOUND] if
+    /// there are no new or modified files or a [CommonError] if S3 throws any error
+    async fn rerun_job(&self, mut export_record: ExportRecord, incremental: bool) -> Result<DownloadJob, CommonError> {
+        info!("rerun_job - start - previous job id: {} - incremental: {incremental}", export_record.job_id);
+        if incremental {
+            let manifest = self.get_incremental_manifest(&export_record).await?;
+            export_record.request.manifest = Some(manifest);
+        }
+        Ok(self.create_job(export_record.request).await)
+    }
+}
+
+/// Download job service internal logic
+impl DownloadJobService {
+    /// Starts [DownloadJob] pending download job by [DownloadRequest] download request in background
+    /// (job lease is acquired before job and request are shared with other API replicas if shared state store is enabled,
+    /// so other replicas can't take over the job before it's started)
+    /// Important: job runs under the caller temporary AWS credentials and session tags of the current request (if any),
+    /// so its request is not shared (other API replicas can't take over the job - please, check [DownloadJobService::share_job_request])
+    fn start_job(&self, download_job: DownloadJob, download_request: DownloadRequest) {
+        self.download_jobs.write().unwrap().insert(
+            download_job.job_id.clone(),
+            DownloadJobEntry { job: download_job.clone(), result: None, result_reservation: None },
+        );
+
+        let download_job_service = self.clone();
+        spawn_with_caller_credentials(async move {
+            if !download_job_service.acquire_job_lease(&download_job.job_id).await {
+                warn!("start_job - job lease can't be acquired - job id: {}", download_job.job_id);
+                download_job_service.update_job(&download_job.job_id, DownloadJobStatus::FAILED, None).await;
+                return;
+            }
+            download_job_service.share_job(&download_job).await;
+            download_job_service.share_job_request(&download_job.job_id, &download_request).await;
+            download_job_service.run_job(download_job.job_id, download_request).await
+        });
+    }
+
+    /// Runs [String] job identifier download flow by [DownloadRequest] download request
+    /// and stores the result (or failed status) for the configured retention window
+    /// Important:
+    /// - job lease should be already owned by this replica (renewed by a heartbeat task while the job is running): if the
+    ///   lease is lost, the job is cancelled and abandoned (no status is stored - other replica takes it over)
+    /// - job keeps waiting restore status while archived S3 objects restores are in progress
+    ///   (please, check [DownloadJobService::wait_for_restores])
+    /// - job keeps pending status (queued) while its priority concurrency budget is exhausted
+    ///   or the export memory budget is exceeded
+    /// - if volumes (or folder split) are requested, job just coordinates the volume (or folder) jobs (please, check
+    ///   [DownloadJobService::run_volume_jobs] and [DownloadJobService::run_folder_jobs])
+    /// - transient errors are retried with backoff (up to the configured attempts - volume jobs coordinator is not retried)
+    ///   and the job is moved to dead letter status once all attempts fail
+    async fn run_job(&self, job_id: String, mut download_request: DownloadRequest) {
+        info!("run_job - start - job id: {job_id}");
+        let job_lease_token = CancellationToken::new();
+        download_request.cancellation_token = job_lease_token.clone();
+        let job_lease_heartbeat = self.start_job_lease_heartbeat(job_id.clone(), job_lease_token.clone());
+
+        let volumes = download_request.volumes.filter(|volumes| *volumes > 1);
+        let coordinator = volumes.is_some() || download_request.split_by_folder;
+        if !coordinator {
+            self.wait_for_restores(&job_id, &download_request).await;
+        }
+        let job_permits = match download_request.priority {
+            DownloadJobPriority::INTERACTIVE => self.interactive_job_permits.clone(),
+            DownloadJobPriority::BATCH => self.batch_job_permits.clone(),
+        };
+        let _job_permit = match coordinator {
+            true => None,
+            false => job_permits.acquire_owned().await.ok(),
+        };
+        while !coordinator && MEMORY_BUDGET.is_exceeded() {
+            debug!("run_job - memory budget exceeded - job queued - job id: {job_id}");
+            tokio::time::sleep(Duration::from_secs(DOWNLOAD_JOB_MEMORY_BUDGET_WAIT_SECONDS)).await;
+        }
+        let started_at = current_epoch_seconds();
+        self.update_job(&job_id, DownloadJobStatus::RUNNING, None).await;
+
+        let request_context = format!(
+            "download job: {job_id} - bucket name: {} - path: {}",
+            download_request.bucket_name, download_request.full_path
+        );
+        let (s3_bucket, s3_path) = (download_request.bucket_name.clone(), download_request.full_path.clone());
+        let notify_email = download_request.notify_email.clone();
+        let public_base_url = download_request.public_base_url.clone();
+        let mut attempt = 0;
+        let job_result = loop {
+            attempt += 1;
+            let job_result = match volumes {
+                _ if download_request.split_by_folder => self.run_folder_jobs(&job_id, download_request.clone()).await,
+                Some(volumes) => self.run_volume_jobs(&job_id, download_request.clone(), volumes).await,
+                None => self.download_job_result(download_request.clone()).await,
+            };
+            if job_lease_token.is_cancelled() {
+                break job_result;
+            }
+            self.record_job_attempt(&job_id, attempt, job_result.as_ref().err()).await;
+            match job_result {
+                Err(download_error) if !coordinator && is_retryable_error(&download_error) && attempt < self.job_max_attempts => {
+                    let retry_backoff = get_retry_backoff(self.job_retry_backoff_seconds, attempt);
+                    warn!("run_job - attempt failed - job id: {job_id} - attempt: {attempt} - retry in: {retry_backoff:?}");
+                    tokio::time::sleep(retry_backoff).await;
+                }
+                job_result => break job_result,
+            }
+        };
+
+        if job_lease_token.is_cancelled() {
+            warn!("run_job - job lease lost - job abandoned - job id: {job_id}");
+            self.download_jobs.write().unwrap().remove(&job_id);
+            return;
+        }
+
+        match job_result {
+            Ok((zip_file_name, zip_content, export_report)) => {
+                let artifact_name = self.artifact_store.store_artifact(&zip_file_name, &zip_content).await;
+                if let Some(download_job_entry) = self.download_jobs.write().unwrap().get_mut(&job_id) {
+                    download_job_entry.job.report = Some(export_report);
+                    download_job_entry.job.artifact_name = artifact_name;
+                }
+                self.update_job(&job_id, DownloadJobStatus::COMPLETED, Some((zip_file_name, zip_content))).await;
+                info!("run_job - done - job id: {job_id}");
+            }
+            Err(download_error) if is_retryable_error(&download_error) => {
+                self.update_job(&job_id, DownloadJobStatus::DEAD_LETTER, None).await;
+                error!("run_job - all attempts failed (dead letter) - job id: {job_id} - attempts: {attempt}");
+                ERROR_REPORTER.report_error(&download_error, &request_context);
+            }
+            Err(download_error) => {
+                self.update_job(&job_id, DownloadJobStatus::FAILED, None).await;
+                error!("run_job - download error - job id: {job_id}");
+                ERROR_REPORTER.report_error(&download_error, &request_context);
+            }
+        }
+        job_lease_heartbeat.abort();
+        self.notify_job_finished(&job_id, s3_bucket, s3_path, notify_email, public_base_url).await;
+        if !is_part_job_id(&job_id) {
+            self.record_export(&job_id, download_request, started_at).await;
+        }
+    }
+
+    /// Gets [(String, Bytes, ExportReport)] zip file name, zip content and export report by [DownloadRequest] download request
+    /// Returns a [CommonError] if download flow fails
+    async fn download_job_result(&self, download_request: DownloadRequest) -> Result<(String, Bytes, ExportReport), CommonError> {
+        let mut download_export = self.download_service.download_files(download_request).await?;
+        let mut zip_content = Vec::new();
+        download_export
+            .content
+            .read_to_end(&mut zip_content)
+            .await
+            .map_err(|_| CommonError::AWS_ACCESS_ERROR)?;
+        download_export
+            .report
+            .await
+            .map(|export_report| {
+                let archive_digest = Sha256::digest(&zip_content);
+                let export_report = ExportReport {
+                    archive_sha256: Some(encode_archive_digest(&archive_digest)),
+                    archive_signature: ARCHIVE_SIGNER.sign_archive_digest(&archive_digest),
+                    ..export_report
+                };
+                (download_export.file_name, Bytes::from(zip_content), export_report)
+            })
+            .map_err(|_| CommonError::AWS_ACCESS_ERROR)
+    }
+
+    /// Notifies [&str] finished job identifier (S3 bucket and [String] S3 path) by a job event and,
+    /// for completed jobs, sends the result link and export summary to [Option<String>] email address
+    /// (result link is built by [Option<String>] forwarded public base URL)
+    /// Important: notification errors are just logged (job status is not affected)
+    async fn notify_job_finished(
+        &self,
+        job_id: &str,
+        bucket_name: String,
+        full_path: String,
+        notify_email: Option<String>,
+        public_base_url: Option<String>,
+    ) {
+        let Ok(download_job) = self.get_job(String::from(job_id)).await else {
+            return;
+        };
+        JOB_EVENT_PUBLISHER
+            .publish_job_event(&JobEvent {
+                job_id: String::from(job_id),
+                status: download_job.status,
+                bucket_name,
+                full_path,
+                job_location: create_job_status_url(job_id, public_base_url.as_deref()),
+                expires_at: download_job.expires_at,
+            })
+            .await;
+
+        let result_url = download_job
+            .resume_token
+            .as_deref()
+            .map(|resume_token| create_job_result_url(resume_token, public_base_url.as_deref()));
+        let (Some(notify_email), Some(result_url), Some(expires_at), Some(export_report)) =
+            (notify_email, result_url, download_job.expires_at, download_job.report)
+        else {
+            return;
+        };
+        let email_body = create_job_result_email_body(&result_url, expires_at, &export_report);
+        if EMAIL_NOTIFIER.send_email(&notify_email, "Your S3 export is ready", &email_body).await.is_err() {
+            warn!("notify_job_finished - job result email not sent - job id: {job_id}");
+        }
+    }
+
+    /// Updates [String] job identifier by [DownloadJobStatus] new status and [Option<(String, Bytes)>] job result
+    /// Important:
+    /// - a new resume token is issued when a job result is stored (its size is reserved from the memory budget until
+    ///   the job is removed)
+    /// - finished jobs (completed, failed or dead letter) are kept for the configured retention window
+    async fn update_job(&self, job_id: &str, status: DownloadJobStatus, job_result: Option<(String, Bytes)>) {
+        let updated_download_job = self.download_jobs.write().unwrap().get_mut(job_id).map(|download_job_entry| {
+            if status.is_finished() {
+                download_job_entry.job.expires_at = Some(current_epoch_seconds() + self.job_result_retention_seconds);
+            }
+            if let Some((_, job_result_content)) = &job_result {
+                download_job_entry.job.resume_token = Some(Uuid::new_v4().simple().to_string());
+                download_job_entry.result_reservation = Some(MEMORY_BUDGET.reserve(job_result_content.len() as u64));
+                download_job_entry.result = job_result;
+            }
+            download_job_entry.job.status = status;
+            download_job_entry.job.clone()
+        });
+        if let Some(updated_download_job) = updated_download_job {
+            self.share_job(&updated_download_job).await;
+        }
+    }
+
+    /// Waits until archived S3 objects of [&DownloadRequest] download request with an ongoing restore are retrievable
+    /// (restore status is polled by the configured interval while [&str] job identifier has waiting restore status)
+    /// Important:
+    /// - if restore is requested, not restored archived S3 objects restores are requested first (just once)
+    /// - job continues once the configured maximum wait is exceeded or restore status can't be requested
+    ///   (not retrievable S3 objects errors are reported by the download flow)
+    async fn wait_for_restores(&self, job_id: &str, download_request: &DownloadRequest) {
+        let wait_started_at = Instant::now();
+        let mut waiting_restore = false;
+        let mut requested_s3_keys = None;
+        loop {
+            let s3_archived_objects = match self
+                .aws_s3_service
+                .get_s3_archived_objects(download_request.bucket_name.clone(), download_request.full_path.clone())
+                .await
+            {
+                Ok(s3_archived_objects) => s3_archived_objects,
+                Err(_) => {
+                    warn!("wait_for_restores - restore status not available - job id: {job_id}");
+                    return;
+                }
+            };
+            if let Some(restore_request) = download_request.restore.as_ref().filter(|_| requested_s3_keys.is_none()) {
+                requested_s3_keys = Some(self.request_restores(job_id, download_request, restore_request, &s3_archived_objects).await);
+            }
+            let restoring_s3_keys = get_restoring_s3_keys(
+                &s3_archived_objects,
+                download_request.manifest.as_deref(),
+                requested_s3_keys.as_deref().unwrap_or_default(),
+            );
+            if restoring_s3_keys.is_empty() {
+                return;
+            }
+            if wait_started_at.elapsed() >= Duration::from_secs(self.job_restore_max_wait_seconds) {
+                warn!("wait_for_restores - maximum wait exceeded - job id: {job_id} - restoring s3 objects: {}", restoring_s3_keys.len());
+                return;
+            }
+
+            if !waiting_restore {
+                self.update_job(job_id, DownloadJobStatus::WAITING_RESTORE, None).await;
+                waiting_restore = true;
+            }
+            info!("wait_for_restores - waiting for restores - job id: {job_id} - restoring s3 objects: {}", restoring_s3_keys.len());
+            tokio::time::sleep(Duration::from_secs(self.job_restore_poll_interval_seconds)).await;
+        }
+    }
+
+    /// Requests restores of not restored [&[(String, S3RestoreStatus)]] archived S3 objects exported by [&DownloadRequest]
+    /// download request of [&str] job identifier by [&RestoreRequest] restore request (restore days and tier)
+    /// Returns [Vec<String>] requested S3 keys (S3 keys with failed restore requests are not included)
+    async fn request_restores(
+        &self,
+        job_id: &str,
+        download_request: &DownloadRequest,
+        restore_request: &RestoreRequest,
+        s3_archived_objects: &[(String, S3RestoreStatus)],
+    ) -> Vec<String> {
+        let mut requested_s3_keys = Vec::new();
+        for (s3_key, _) in s3_archived_objects.iter().filter(|(s3_key, s3_restore_status)| {
+            *s3_restore_status == S3RestoreStatus::NOT_RESTORED && is_exported_s3_key(s3_key, download_request.manifest.as_deref())
+        }) {
+            if self
+                .aws_s3_service
+                .restore_s3_object(
+                    download_request.bucket_name.clone(),
+                    download_request.full_path.clone(),
+                    s3_key.clone(),
+                    restore_request.days.unwrap_or(DOWNLOAD_JOB_RESTORE_DAYS_DEFAULT),
+                    restore_request.tier,
+                )
+                .await
+                .is_ok()
+            {
+                requested_s3_keys.push(s3_key.clone());
+            }
+        }
+
+        info!("request_restores - restores requested - job id: {job_id} - s3 objects: {}", requested_s3_keys.len());
+        requested_s3_keys
+    }
+
+    /// Records [u32] attempt of [&str] job identifier with [Option<&CommonError>] attempt error (error chain)
+    async fn record_job_attempt(&self, job_id: &str, attempt: u32, attempt_error: Option<&CommonError>) {
+        let updated_download_job = self.download_jobs.write().unwrap().get_mut(job_id).map(|download_job_entry| {
+            download_job_entry.job.attempts = attempt;
+            if let Some(attempt_error) = attempt_error {
+                download_job_entry.job.errors.push(format!("attempt {attempt}: {attempt_error}"));
+            }
+            download_job_entry.job.clone()
+        });
+        if let Some(updated_download_job) = updated_download_job {
+            self.share_job(&updated_download_job).await;
+        }
+    }
+}
+
+/// Gets [bool] true if [&CommonError] download error is transient (job attempt should be retried)
+fn is_retryable_error(download_error: &CommonError) -> bool {
+    matches!(download_error, CommonError::AWS_ACCESS_ERROR | CommonError::MEMORY_BUDGET_EXCEEDED)
+}
+
+/// Gets [Duration] retry backoff by [u64] base backoff (in seconds) and [u32] failed attempt
+/// (base backoff is doubled by each attempt and limited by [DOWNLOAD_JOB_RETRY_MAX_BACKOFF_SECONDS])
+fn get_retry_backoff(retry_backoff_seconds: u64, attempt: u32) -> Duration {
+    let retry_backoff_seconds = retry_backoff_seconds.saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)));
+    Duration::from_secs(retry_backoff_seconds.min(DOWNLOAD_JOB_RETRY_MAX_BACKOFF_SECONDS))
+}
+
+/// Gets [bool] true if [&str] job identifier is a part (volume or folder) job identifier (`{job_id}-volume-{n}` or
+/// `{job_id}-folder-{n}` - part jobs are not recorded by export history, just their coordinator job)
+fn is_part_job_id(job_id: &str) -> bool {
+    ["-volume-", "-folder-"]
+        .iter()
+        .any(|part_separator| job_id.rsplit_once(part_separator).is_some_and(|(_, part)| part.parse::<usize>().is_ok()))
+}
+
+/// Gets [Vec<String>] S3 keys with an ongoing restore by [&[(String, S3RestoreStatus)]] archived S3 objects,
+/// [Option<&[ManifestEntry]>] download request manifest (just manifest S3 keys are checked if it is defined) and
+/// [&[String]] requested S3 keys (not restored yet requested S3 keys are still restoring - S3 listings are eventually consistent)
+fn get_restoring_s3_keys(
+    s3_archived_objects: &[(String, S3RestoreStatus)],
+    manifest: Option<&[ManifestEntry]>,
+    requested_s3_keys: &[String],
+) -> Vec<String> {
+    s3_archived_objects
+        .iter()
+        .filter(|(s3_key, s3_restore_status)| {
+            *s3_restore_status == S3RestoreStatus::IN_PROGRESS
+                || (*s3_restore_status == S3RestoreStatus::NOT_RESTORED && requested_s3_keys.contains(s3_key))
+        })
+        .map(|(s3_key, _)| s3_key.clone())
+        .filter(|s3_key| is_exported_s3_key(s3_key, manifest))
+        .collect()
+}
+
+/// Gets [bool] true if [&str] S3 key is exported by [Option<&[ManifestEntry]>] download request manifest
+/// (all S3 keys are exported if manifest is not defined)
+fn is_exported_s3_key(s3_key: &str, manifest: Option<&[ManifestEntry]>) -> bool {
+    manifest.is_none_or(|manifest| manifest.iter().any(|manifest_entry| manifest_entry.s3_key == s3_key))
+}
+
+/// Creates a new [String] job result URL by [&str] resume token and [Option<&str>] forwarded public base URL
+/// (configured public base URL and API base path are used if it is not defined - please, check constants.rs)
+fn create_job_result_url(resume_token: &str, forwarded_public_base_url: Option<&str>) -> String {
+    format!(
+        "{}{API_DOWNLOAD_MAIN_PATH}{}",
+        create_public_base_url(forwarded_public_base_url),
+        API_DOWNLOAD_JOB_RESULT_PATH.replace("{resume_token}", resume_token)
+    )
+}
+
+/// Creates a new [String] job status URL by [&str] job identifier and [Option<&str>] forwarded public base URL
+/// (configured public base URL and API base path are used if it is not defined - please, check constants.rs)
+fn create_job_status_url(job_id: &str, forwarded_public_base_url: Option<&str>) -> String {
+    format!(
+        "{}{API_DOWNLOAD_MAIN_PATH}{}",
+        create_public_base_url(forwarded_public_base_url),
+        API_DOWNLOAD_JOB_BY_ID_PATH.replace("{job_id}", job_id)
+    )
+}
+
+/// Creates a new [String] job result email body by [&str] result URL, [u64] result expiration (seconds since unix epoch)
+/// and [&ExportReport] export report (summary)
+fn create_job_result_email_body(result_url: &str, expires_at: u64, export_report: &ExportReport) -> String {
+    format!(
+        "Your S3 export is ready.\n\nDownload link: {result_url}\nLink expiration (seconds since unix epoch): {expires_at}\n\n\
+         Files: {}\nSize (bytes): {}\nElapsed (milliseconds): {}\n",
+        export_report.files_total, export_report.bytes_total, export_report.elapsed_millis
+    )
+}
+
+/// Download job service trait for API router state (based on Rust samples for Axum DI)
+pub type DynDownloadJobService = Arc<dyn DownloadJobServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::export_report::ExportReport;
+    use crate::dto::manifest_entry::ManifestEntry;
+    use crate::enums::s3_restore_status::S3RestoreStatus;
+    use crate::service::download_job_service::{create_job_result_email_body, create_job_status_url, get_restoring_s3_keys, get_retry_backoff};
+    use std::time::Duration;
+
+    /// Scenario:
+    /// Creates a job result email body for a completed export
+    /// Expectation:
+    /// Email body with the result link, expiration and export summary should be retrieved
+    #[test]
+    fn when_create_job_result_email_body_should_include_link_and_summary() {
+        let export_report = ExportReport { files_total: 3, bytes_total: 2048, elapsed_millis: 150, ..Default::default() };
+
+        let email_body = create_job_result_email_body("http://localhost:8097/api/v1/download/jobs/results/abc", 1767225600, &export_report);
+
+        assert!(email_body.contains("Download link: http://localhost:8097/api/v1/download/jobs/results/abc"));
+        assert!(email_body.contains("Link expiration (seconds since unix epoch): 1767225600"));
+        assert!(email_body.contains("Files: 3"));
+        assert!(email_body.contains("Size (bytes): 2048"));
+    }
+
+    /// Scenario:
+    /// Creates a job status URL (published by job events) for a forwarded public base URL
+    /// Expectation:
+    /// Job status URL by job identifier should be retrieved (no resume token is included)
+    #[test]
+    fn when_create_job_status_url_should_not_include_resume_token() {
+        let job_status_url = create_job_status_url("1b7c", Some("https://downloader.example.com"));
+
+        assert!(job_status_url.starts_with("https://downloader.example.com"));
+        assert!(job_status_url.ends_with("/download/jobs/1b7c"));
+        assert!(!job_status_url.contains("/results/"));
+    }
+
+    /// Scenario:
+    /// Gets retry backoffs (5 seconds base) for the first, third and tenth failed attempts
+    /// Expectation:
+    /// Base backoff should be doubled by each attempt and limited by the maximum backoff
+    #[test]
+    fn when_get_retry_backoff_should_double_by_attempt_up_to_max() {
+        assert_eq!(Duration::from_secs(5), get_retry_backoff(5, 1));
+        assert_eq!(Duration::from_secs(20), get_retry_backoff(5, 3));
+        assert_eq!(Duration::from_secs(300), get_retry_backoff(5, 10));
+    }
+
+    /// Scenario:
+    /// Gets restoring S3 keys from archived S3 objects (not restored, in progress and restored) with and without manifest
+    /// and with a requested restore not listed as in progress yet
+    /// Expectation:
+    /// Just S3 keys with an ongoing (or requested) restore should be retrieved (and just manifest S3 keys if manifest is defined)
+    #[test]
+    fn when_get_restoring_s3_keys_should_retrieve_in_progress_keys() {
+        let s3_archived_objects = vec![
+            (String::from("a.csv"), S3RestoreStatus::NOT_RESTORED),
+            (String::from("b.csv"), S3RestoreStatus::IN_PROGRESS),
+            (String::from("c.csv"), S3RestoreStatus::IN_PROGRESS),
+            (String::from("d.csv"), S3RestoreStatus::RESTORED),
+        ];
+        let manifest = vec![ManifestEntry { s3_key: String::from("c.csv"), archive_name: None }];
+
+        assert_eq!(vec!["b.csv", "c.csv"], get_restoring_s3_keys(&s3_archived_objects, None, &[]));
+        assert_eq!(vec!["c.csv"], get_restoring_s3_keys(&s3_archived_objects, Some(&manifest), &[]));
+        assert_eq!(vec!["a.csv", "b.csv", "c.csv"], get_restoring_s3_keys(&s3_archived_objects, None, &[String::from("a.csv")]));
+    }
+}
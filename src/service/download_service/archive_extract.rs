@@ -0,0 +1,88 @@
+use std::io::ErrorKind;
+use std::time::Instant;
+
+use crate::config::memory_budget::MEMORY_BUDGET;
+use crate::dto::archive_request::ArchiveRequest;
+use crate::dto::s3_object::S3Object;
+use crate::enums::archive_format::ArchiveFormat;
+use crate::enums::common_error::CommonError;
+use crate::service::download_service::DownloadService;
+use crate::utils::archive_reader::ArchiveReader;
+use crate::utils::s3_range_reader::S3RangeReader;
+use log::warn;
+use tokio::sync::mpsc::Sender;
+use tokio_util::sync::CancellationToken;
+
+/// Download service archive logic (ZIP and tar archives stored in S3 are read by S3 ranged requests)
+impl DownloadService {
+    /// Opens [ArchiveReader] archive reader of a ZIP or tar archive stored in S3 by [&ArchiveRequest] archive request
+    /// (archive format is resolved by S3 key extension - archive is read by a blocking task)
+    /// Returns a [CommonError::NO_DATA_FOUND] if archive S3 key doesn't exist
+    /// Returns a [CommonError] if archive format is not supported or valid or S3 throws any error
+    pub(super) async fn open_archive(&self, archive_request: &ArchiveRequest) -> Result<ArchiveReader, CommonError> {
+        let Some(archive_format) = ArchiveFormat::from_s3_key(&archive_request.s3_key) else {
+            warn!("open_archive - not supported archive format - s3 key: {}", archive_request.s3_key);
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        };
+
+        let aws_s3_service = self.aws_s3_service.clone();
+        let s3_object = (archive_request.bucket_name.clone(), archive_request.full_path.clone(), archive_request.s3_key.clone());
+        let (archive_read_ahead_bytes, archive_entry_max_bytes) = (self.archive_read_ahead_bytes, self.archive_entry_max_bytes);
+        let archive_reader = tokio::task::spawn_blocking(move || {
+            let s3_range_reader =
+                S3RangeReader::open(aws_s3_service, s3_object, archive_read_ahead_bytes, archive_format == ArchiveFormat::ZIP)?;
+            ArchiveReader::open(s3_range_reader, archive_format, archive_entry_max_bytes)
+        })
+        .await
+        .unwrap_or_else(|join_error| Err(std::io::Error::other(join_error.to_string())));
+
+        archive_reader.map_err(|archive_error| {
+            warn!("open_archive - archive can't be read - s3 key: {} - error: {archive_error}", archive_request.s3_key);
+            match archive_error.kind() {
+                ErrorKind::NotFound => CommonError::NO_DATA_FOUND,
+                ErrorKind::InvalidData | ErrorKind::UnexpectedEof => CommonError::NO_VALID_INPUT_OR_PARAMETER,
+                _ => CommonError::AWS_ACCESS_ERROR,
+            }
+        })
+    }
+}
+
+/// Sends [S3Object] selected archive entries (entry name as S3 key) by [ArchiveReader] archive reader and
+/// [Vec<String>] selected entry names into [Sender<S3Object>] S3 object sender (in selected entries order)
+/// Important: entries are read by blocking S3 ranged requests - reading stops on the first error (sent as fetch
+/// error), if the receiver is dropped or if [CancellationToken] export cancellation token is cancelled (i.e.: client
+/// disconnected)
+pub(super) fn read_archive_entries(
+    mut archive_reader: ArchiveReader,
+    entry_names: Vec<String>,
+    s3_object_sender: Sender<S3Object>,
+    cancellation_token: CancellationToken,
+) {
+    for entry_name in entry_names {
+        if cancellation_token.is_cancelled() {
+            warn!("read_archive_entries - export cancelled (client disconnected)");
+            break;
+        }
+        let entry_started_at = Instant::now();
+        let (content, fetch_error) = match archive_reader.read_entry(&entry_name) {
+            Ok(content) => (content, None),
+            Err(read_error) => {
+                warn!("read_archive_entries - archive entry can't be read: {entry_name} - error: {read_error}");
+                (Vec::new(), Some(CommonError::AWS_ACCESS_ERROR))
+            }
+        };
+        let read_failed = fetch_error.is_some();
+        let s3_object = S3Object {
+            s3_key: entry_name,
+            memory_reservation: MEMORY_BUDGET.reserve(content.len() as u64),
+            content,
+            content_stream: None,
+            fetch_latency_millis: entry_started_at.elapsed().as_millis() as u64,
+            fetch_retries: 0,
+            fetch_error,
+        };
+        if s3_object_sender.blocking_send(s3_object).is_err() || read_failed {
+            break;
+        }
+    }
+}
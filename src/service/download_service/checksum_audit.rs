@@ -0,0 +1,284 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+use age::Encryptor;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{CHECKSUM_AUDIT_FILE_NAME, ENCRYPTED_EXPORT_FILE_EXTENSION, ZIP_STREAM_BUFFER_SIZE};
+use crate::dto::checksum_audit_entry::ChecksumAuditEntry;
+use crate::dto::download_export::DownloadExport;
+use crate::dto::export_report::{ExportReport, FileFetchReport};
+use crate::dto::s3_fetch_options::S3FetchOptions;
+use crate::dto::s3_object::S3Object;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::enums::checksum_audit_status::ChecksumAuditStatus;
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use crate::service::download_service::export_encryption::create_encrypted_content;
+use crate::service::download_service::{create_export_report, encode_archive_digest, DownloadService};
+use log::{error, info, warn};
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use tokio::io::{duplex, AsyncWriteExt, DuplexStream};
+use tokio::sync::mpsc::Receiver;
+use tokio::sync::oneshot;
+
+/// Download service checksum audit logic (downloaded S3 objects checksums compared with the stored ones)
+impl DownloadService {
+    /// Gets [DownloadExport] checksum audit report file name, content stream and export report by [ChecksumAuditOptions]
+    /// checksum audit options, [Option<Encryptor>] report encryptor and [Instant] export start
+    /// Important: S3 objects are downloaded by the bounded fetch pipeline (same limits as zip exports) and stored entity
+    /// tags are listed before the report is streamed (S3 errors are reported as [CommonError::AWS_ACCESS_ERROR])
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    pub(super) async fn download_checksum_audit(
+        &self,
+        checksum_audit_options: ChecksumAuditOptions,
+        encryptor: Option<Encryptor>,
+        export_started_at: Instant,
+    ) -> Result<DownloadExport, CommonError> {
+        let ChecksumAuditOptions { s3_bucket, s3_path, s3_keys, s3_fetch_options } = checksum_audit_options;
+        let s3_file_receiver = match &s3_keys {
+            Some(s3_keys) => {
+                self.aws_s3_service
+                    .get_s3_objects_by_keys_as_channel(s3_bucket.clone(), s3_path.clone(), s3_keys.iter().cloned().collect(), s3_fetch_options)
+                    .await
+            }
+            None => {
+                self.aws_s3_service
+                    .get_s3_objects_by_path_as_channel(s3_bucket.clone(), s3_path.clone(), s3_fetch_options)
+                    .await
+            }
+        };
+        let s3_file_receiver = match s3_file_receiver {
+            Ok(s3_file_receiver) => s3_file_receiver,
+            Err(download_error) => {
+                error!("download_checksum_audit - can't get files from s3 bucket: {s3_bucket} - path: {s3_path} - error: {download_error}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return match download_error {
+                    CommonError::LIMIT_EXCEEDED { .. } => Err(download_error),
+                    _ => Err(CommonError::AWS_ACCESS_ERROR),
+                };
+            }
+        };
+
+        let mut s3_objects_metadata = HashMap::new();
+        let mut continuation_token = None;
+        loop {
+            let (s3_object_metadata_list, next_continuation_token) = match self
+                .aws_s3_service
+                .get_s3_object_metadata_page(s3_bucket.clone(), s3_path.clone(), None, continuation_token)
+                .await
+            {
+                Ok(s3_object_metadata_page) => s3_object_metadata_page,
+                Err(metadata_error) => {
+                    error!("download_checksum_audit - can't get metadata from s3 bucket: {s3_bucket} - path: {s3_path} - error: {metadata_error}");
+                    USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                    return Err(CommonError::AWS_ACCESS_ERROR);
+                }
+            };
+            s3_objects_metadata.extend(
+                s3_object_metadata_list
+                    .into_iter()
+                    .filter(|s3_object_metadata| s3_keys.as_ref().is_none_or(|s3_keys| s3_keys.contains(&s3_object_metadata.s3_key)))
+                    .map(|s3_object_metadata| (s3_object_metadata.s3_key.clone(), s3_object_metadata)),
+            );
+            continuation_token = next_continuation_token;
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        let (report_content_reader, report_content_writer) = duplex(ZIP_STREAM_BUFFER_SIZE);
+        let (export_report_sender, mut export_report_receiver) = oneshot::channel();
+        tokio::spawn(write_checksum_audit_report(
+            self.aws_s3_service.clone(),
+            (s3_bucket.clone(), s3_path.clone()),
+            s3_objects_metadata,
+            s3_file_receiver,
+            report_content_writer,
+            export_started_at,
+            export_report_sender,
+        ));
+
+        let mut file_name = String::from(CHECKSUM_AUDIT_FILE_NAME);
+        let mut content = report_content_reader;
+        if let Some(encryptor) = encryptor {
+            file_name.push_str(ENCRYPTED_EXPORT_FILE_EXTENSION);
+            (content, export_report_receiver) = create_encrypted_content(content, export_report_receiver, encryptor);
+        }
+
+        info!("download_checksum_audit - done - s3 bucket: {s3_bucket} - s3 path: {s3_path}");
+        Ok(DownloadExport {
+            file_name,
+            content,
+            report: export_report_receiver,
+            excluded_count: 0,
+        })
+    }
+}
+
+/// Checksum audit options struct (S3 bucket and path, optional S3 keys filter - manifest keys - and fetch options)
+pub(super) struct ChecksumAuditOptions {
+    pub(super) s3_bucket: String,
+    pub(super) s3_path: String,
+    pub(super) s3_keys: Option<HashSet<String>>,
+    pub(super) s3_fetch_options: S3FetchOptions,
+}
+
+/// Writes a checksum audit report (JSON array of [ChecksumAuditEntry]) into [DuplexStream] report content writer with
+/// all S3 objects received by [Receiver<S3Object>] S3 file receiver from [(String, String)] S3 bucket and path, compared
+/// with [HashMap<String, S3ObjectMetadata>] listed S3 objects metadata by S3 key, and sends its export report (with
+/// checksum mismatches) to [oneshot::Sender<ExportReport>] sender
+/// Important: stored SHA-256 checksums are requested by [DynAwsSdkS3Service] AWS S3 service just for S3 objects
+/// without a single part entity tag (i.e.: multipart uploads)
+async fn write_checksum_audit_report(
+    aws_s3_service: DynAwsSdkS3Service,
+    (s3_bucket, s3_path): (String, String),
+    s3_objects_metadata: HashMap<String, S3ObjectMetadata>,
+    mut s3_file_receiver: Receiver<S3Object>,
+    mut report_content_writer: DuplexStream,
+    export_started_at: Instant,
+    export_report_sender: oneshot::Sender<ExportReport>,
+) {
+    if report_content_writer.write_all(b"[").await.is_err() {
+        return;
+    }
+
+    let mut file_fetch_reports = Vec::new();
+    let mut missing_files = Vec::new();
+    let mut checksum_mismatches = Vec::new();
+    while let Some(s3_file) = s3_file_receiver.recv().await {
+        let checksum_audit_entry = match s3_file.fetch_error {
+            Some(CommonError::NO_DATA_FOUND) => {
+                missing_files.push(s3_file.s3_key.clone());
+                ChecksumAuditEntry {
+                    s3_key: s3_file.s3_key,
+                    size_bytes: 0,
+                    e_tag: None,
+                    md5: None,
+                    sha256: None,
+                    status: ChecksumAuditStatus::MISSING,
+                }
+            }
+            Some(fetch_error) => {
+                warn!("write_checksum_audit_report - can't fetch file: {} - error: {fetch_error}", s3_file.s3_key);
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return;
+            }
+            None => {
+                file_fetch_reports.push(FileFetchReport {
+                    s3_key: s3_file.s3_key.clone(),
+                    size_bytes: s3_file.content.len() as u64,
+                    latency_millis: s3_file.fetch_latency_millis,
+                    retries: s3_file.fetch_retries,
+                });
+                let s3_object_metadata = s3_objects_metadata.get(&s3_file.s3_key);
+                let stored_sha256 = match s3_object_metadata.and_then(|s3_object_metadata| s3_object_metadata.e_tag.as_deref()) {
+                    Some(e_tag) if !e_tag.contains('-') => None,
+                    _ => aws_s3_service
+                        .get_s3_object_checksum(s3_bucket.clone(), s3_path.clone(), s3_file.s3_key.clone())
+                        .await
+                        .ok()
+                        .flatten(),
+                };
+                create_checksum_audit_entry(&s3_file.s3_key, &s3_file.content, s3_object_metadata, stored_sha256.as_deref())
+            }
+        };
+
+        if checksum_audit_entry.status == ChecksumAuditStatus::MISMATCHED {
+            checksum_mismatches.push(checksum_audit_entry.s3_key.clone());
+        }
+        let separator = if file_fetch_reports.len() + missing_files.len() == 1 { "\n" } else { ",\n" };
+        let report_row = format!("{separator}{}", serde_json::to_string(&checksum_audit_entry).unwrap_or_default());
+        if let Err(write_error) = report_content_writer.write_all(report_row.as_bytes()).await {
+            warn!("write_checksum_audit_report - can't write report (client disconnected?) - error: {write_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+            return;
+        }
+    }
+
+    if report_content_writer.write_all(b"\n]\n").await.is_err() {
+        return;
+    }
+    let _ = report_content_writer.shutdown().await;
+
+    let mut export_report = create_export_report(export_started_at, file_fetch_reports, missing_files, Vec::new());
+    export_report.checksum_mismatches = checksum_mismatches;
+    info!("write_checksum_audit_report - done - s3 bucket: {s3_bucket} - mismatches: {}", export_report.checksum_mismatches.len());
+    USAGE_STATS_REGISTRY.record_export(&s3_bucket, export_report.bytes_total, false);
+    let _ = export_report_sender.send(export_report);
+}
+
+/// Creates a new [ChecksumAuditEntry] by [&str] S3 key, [&[u8]] downloaded content, [Option<&S3ObjectMetadata>] listed
+/// S3 object metadata and [Option<&str>] stored SHA-256 checksum (base64 encoded): recomputed SHA-256 checksum is compared
+/// with the stored one (if defined), otherwise recomputed MD5 checksum is compared with single part entity tags
+/// Important: entity tags of multipart uploads (`{checksum}-{parts}`) are not comparable (unverifiable status)
+fn create_checksum_audit_entry(
+    s3_key: &str,
+    content: &[u8],
+    s3_object_metadata: Option<&S3ObjectMetadata>,
+    stored_sha256: Option<&str>,
+) -> ChecksumAuditEntry {
+    let md5 = encode_archive_digest(&Md5::digest(content));
+    let sha256_digest = Sha256::digest(content);
+    let e_tag = s3_object_metadata.and_then(|s3_object_metadata| s3_object_metadata.e_tag.clone());
+    let single_part_e_tag = e_tag.as_deref().map(|e_tag| e_tag.trim_matches('"')).filter(|e_tag| !e_tag.contains('-'));
+
+    let checksum_matched = match (stored_sha256, single_part_e_tag) {
+        (Some(stored_sha256), _) => Some(stored_sha256 == STANDARD.encode(sha256_digest)),
+        (None, Some(single_part_e_tag)) => Some(single_part_e_tag.eq_ignore_ascii_case(&md5)),
+        (None, None) => None,
+    };
+    let size_matched = s3_object_metadata.is_none_or(|s3_object_metadata| s3_object_metadata.size_bytes == content.len() as i64);
+    let status = match checksum_matched {
+        _ if !size_matched => ChecksumAuditStatus::MISMATCHED,
+        Some(true) => ChecksumAuditStatus::MATCHED,
+        Some(false) => ChecksumAuditStatus::MISMATCHED,
+        None => ChecksumAuditStatus::UNVERIFIABLE,
+    };
+
+    ChecksumAuditEntry {
+        s3_key: String::from(s3_key),
+        size_bytes: content.len() as u64,
+        e_tag,
+        md5: Some(md5),
+        sha256: Some(encode_archive_digest(&sha256_digest)),
+        status,
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::checksum_audit_status::ChecksumAuditStatus;
+    use crate::service::download_service::checksum_audit::create_checksum_audit_entry;
+
+    /// Scenario:
+    /// Audits a downloaded content against single part, changed, multipart and stored SHA-256 checksums
+    /// Expectation:
+    /// Recomputed checksums should be compared with comparable stored checksums (multipart entity tags are unverifiable)
+    #[test]
+    fn when_create_checksum_audit_entry_should_compare_stored_checksums() {
+        let s3_object_metadata_fn = |e_tag: &str| S3ObjectMetadata {
+            s3_key: String::from("a.txt"),
+            size_bytes: 5,
+            e_tag: Some(String::from(e_tag)),
+            ..Default::default()
+        };
+        let audit_status_fn = |e_tag: &str, stored_sha256: Option<&str>| {
+            create_checksum_audit_entry("a.txt", b"hello", Some(&s3_object_metadata_fn(e_tag)), stored_sha256).status
+        };
+
+        assert_eq!(ChecksumAuditStatus::MATCHED, audit_status_fn("\"5d41402abc4b2a76b9719d911017c592\"", None));
+        assert_eq!(ChecksumAuditStatus::MISMATCHED, audit_status_fn("\"9a0364b9e99bb480dd25e1f0284c8555\"", None));
+        assert_eq!(ChecksumAuditStatus::UNVERIFIABLE, audit_status_fn("\"9a0364b9e99bb480dd25e1f0284c8555-2\"", None));
+        assert_eq!(
+            ChecksumAuditStatus::MATCHED,
+            audit_status_fn("\"9a0364b9e99bb480dd25e1f0284c8555-2\"", Some("LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="))
+        );
+        let checksum_audit_entry = create_checksum_audit_entry("a.txt", b"hello!", Some(&s3_object_metadata_fn("\"5d41402abc4b2a76b9719d911017c592\"")), None);
+        assert_eq!(ChecksumAuditStatus::MISMATCHED, checksum_audit_entry.status);
+    }
+}
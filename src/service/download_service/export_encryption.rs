@@ -0,0 +1,117 @@
+use std::io::{Read, Write};
+use std::iter;
+use std::str::FromStr;
+
+use age::Encryptor;
+use age::x25519::Recipient;
+use crate::constant::constants::ZIP_STREAM_BUFFER_SIZE;
+use crate::dto::export_report::ExportReport;
+use log::warn;
+use tokio::io::{duplex, DuplexStream};
+use tokio::sync::oneshot;
+use tokio_util::io::SyncIoBridge;
+
+/// Creates a new [Encryptor] age encryptor by [&str] age public key (i.e.: "age1...")
+/// Returns a [String] error if the age public key is not valid
+pub(super) fn create_encryptor(encryption_recipient: &str) -> Result<Encryptor, String> {
+    let recipient = Recipient::from_str(encryption_recipient.trim()).map_err(String::from)?;
+    Encryptor::with_recipients(iter::once(&recipient as &dyn age::Recipient)).map_err(|encryption_error| encryption_error.to_string())
+}
+
+/// Encrypts [R] content reader into [W] encrypted content writer by [Encryptor] age encryptor
+/// Returns an [std::io::Error] if content can't be read or written
+/// Important: this is a blocking function (encryptor stage): it should be executed by a blocking thread
+fn encrypt_content<R: Read, W: Write>(mut content_reader: R, encrypted_content_writer: W, encryptor: Encryptor) -> std::io::Result<()> {
+    let mut encrypting_writer = encryptor.wrap_output(encrypted_content_writer)?;
+    std::io::copy(&mut content_reader, &mut encrypting_writer)?;
+    encrypting_writer.finish()?.flush()
+}
+
+/// Gets [(DuplexStream, oneshot::Receiver<ExportReport>)] encrypted content stream and its export report by
+/// [DuplexStream] content stream, [oneshot::Receiver<ExportReport>] content export report and [Encryptor] encryptor
+/// (content is encrypted in a blocking thread while it is streamed)
+/// Important: export report is forwarded just once the whole content is encrypted (it is dropped if encryption fails,
+/// so a truncated encrypted content is not taken as a completed export)
+pub(super) fn create_encrypted_content(
+    content: DuplexStream,
+    export_report: oneshot::Receiver<ExportReport>,
+    encryptor: Encryptor,
+) -> (DuplexStream, oneshot::Receiver<ExportReport>) {
+    let (encrypted_content_reader, encrypted_content_writer) = duplex(ZIP_STREAM_BUFFER_SIZE);
+    let (encrypted_export_report_sender, encrypted_export_report_receiver) = oneshot::channel();
+    let content_reader = SyncIoBridge::new(content);
+    let encrypted_content_writer = SyncIoBridge::new(encrypted_content_writer);
+    tokio::task::spawn_blocking(move || {
+        if let Err(encryption_error) = encrypt_content(content_reader, encrypted_content_writer, encryptor) {
+            warn!("create_encrypted_content - can't encrypt content (client disconnected?) - error: {encryption_error}");
+            return;
+        }
+        if let Ok(export_report) = export_report.blocking_recv() {
+            let _ = encrypted_export_report_sender.send(export_report);
+        }
+    });
+    (encrypted_content_reader, encrypted_export_report_receiver)
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+    use std::iter;
+
+    use crate::dto::export_report::ExportReport;
+    use crate::service::download_service::export_encryption::{create_encrypted_content, create_encryptor, encrypt_content};
+    use age::Decryptor;
+    use age::x25519::Identity;
+    use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    use tokio::sync::oneshot;
+
+    /// Scenario:
+    /// Encrypts a zip content for an age public key
+    /// Expectation:
+    /// Encrypted content should be decrypted with the age private key into the original zip content
+    #[test]
+    fn when_encrypt_content_should_encrypt_for_recipient() {
+        let identity = Identity::generate();
+        let encryptor = create_encryptor(&identity.to_public().to_string()).unwrap();
+        let mut encrypted_content = Vec::new();
+
+        encrypt_content(Cursor::new(b"zip content".to_vec()), &mut encrypted_content, encryptor).unwrap();
+
+        let decryptor = Decryptor::new(Cursor::new(encrypted_content)).unwrap();
+        let mut decrypted_content = Vec::new();
+        decryptor
+            .decrypt(iter::once(&identity as &dyn age::Identity))
+            .unwrap()
+            .read_to_end(&mut decrypted_content)
+            .unwrap();
+        assert_eq!(b"zip content".to_vec(), decrypted_content);
+        assert!(create_encryptor("not-an-age-key").is_err());
+    }
+
+    /// Scenario:
+    /// Encrypts a completed zip content (export report sent) and a failed one (export report dropped)
+    /// Expectation:
+    /// Export report should be forwarded just for the completed zip content once it is encrypted
+    #[tokio::test]
+    async fn when_create_encrypted_content_should_forward_just_completed_export_report() {
+        for completed in [true, false] {
+            let encryptor = create_encryptor(&Identity::generate().to_public().to_string()).unwrap();
+            let (content, mut content_writer) = duplex(64);
+            let (export_report_sender, export_report) = oneshot::channel();
+            tokio::spawn(async move {
+                content_writer.write_all(b"zip content").await.unwrap();
+                if completed {
+                    export_report_sender.send(ExportReport::default()).unwrap();
+                }
+            });
+
+            let (mut encrypted_content, encrypted_export_report) = create_encrypted_content(content, export_report, encryptor);
+            let mut encrypted_zip_content = Vec::new();
+            encrypted_content.read_to_end(&mut encrypted_zip_content).await.unwrap();
+
+            assert!(!encrypted_zip_content.is_empty());
+            assert_eq!(completed, encrypted_export_report.await.is_ok());
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use age::Encryptor;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{ENCRYPTED_EXPORT_FILE_EXTENSION, METADATA_REPORT_CSV_HEADER, METADATA_REPORT_FILE_NAME, ZIP_STREAM_BUFFER_SIZE};
+use crate::dto::download_export::DownloadExport;
+use crate::dto::export_report::ExportReport;
+use crate::dto::s3_object_metadata::S3ObjectMetadata;
+use crate::enums::common_error::CommonError;
+use crate::enums::metadata_report_format::MetadataReportFormat;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use crate::service::download_service::export_encryption::create_encrypted_content;
+use crate::service::download_service::{create_export_report, DownloadService};
+use log::{error, info, warn};
+use tokio::io::{duplex, AsyncWriteExt, DuplexStream};
+use tokio::sync::oneshot;
+
+/// Download service metadata report logic (S3 objects metadata and tags streamed as a CSV or JSON report)
+impl DownloadService {
+    /// Gets [DownloadExport] metadata report file name, content stream and export report by [MetadataReportOptions]
+    /// metadata report options, [Option<Encryptor>] report encryptor and [Instant] export start
+    /// Important: the first S3 listing page is requested before the report is streamed (S3 errors are reported as
+    /// [CommonError::AWS_ACCESS_ERROR]) - next pages and S3 object tags are requested while the report is streamed
+    pub(super) async fn download_metadata_report(
+        &self,
+        metadata_report_options: MetadataReportOptions,
+        encryptor: Option<Encryptor>,
+        export_started_at: Instant,
+    ) -> Result<DownloadExport, CommonError> {
+        let s3_bucket = metadata_report_options.s3_bucket.clone();
+        let s3_path = metadata_report_options.s3_path.clone();
+        let s3_object_metadata_page = match self.aws_s3_service.get_s3_object_metadata_page(s3_bucket.clone(), s3_path.clone(), None, None).await {
+            Ok(s3_object_metadata_page) => s3_object_metadata_page,
+            Err(metadata_error) => {
+                error!("download_metadata_report - can't get metadata from s3 bucket: {s3_bucket} - path: {s3_path} - error: {metadata_error}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return Err(CommonError::AWS_ACCESS_ERROR);
+            }
+        };
+
+        let (report_content_reader, report_content_writer) = duplex(ZIP_STREAM_BUFFER_SIZE);
+        let (export_report_sender, mut export_report_receiver) = oneshot::channel();
+        let mut file_name = match metadata_report_options.metadata_report_format {
+            MetadataReportFormat::CSV => format!("{METADATA_REPORT_FILE_NAME}.csv"),
+            MetadataReportFormat::JSON => format!("{METADATA_REPORT_FILE_NAME}.json"),
+        };
+        tokio::spawn(write_metadata_report(
+            self.aws_s3_service.clone(),
+            metadata_report_options,
+            s3_object_metadata_page,
+            report_content_writer,
+            export_started_at,
+            export_report_sender,
+        ));
+
+        let mut content = report_content_reader;
+        if let Some(encryptor) = encryptor {
+            file_name.push_str(ENCRYPTED_EXPORT_FILE_EXTENSION);
+            (content, export_report_receiver) = create_encrypted_content(content, export_report_receiver, encryptor);
+        }
+
+        info!("download_metadata_report - done - s3 bucket: {s3_bucket} - s3 path: {s3_path}");
+        Ok(DownloadExport {
+            file_name,
+            content,
+            report: export_report_receiver,
+            excluded_count: 0,
+        })
+    }
+}
+
+/// Metadata report options struct (S3 bucket and path, optional S3 keys filter - manifest keys - and report format)
+pub(super) struct MetadataReportOptions {
+    pub(super) s3_bucket: String,
+    pub(super) s3_path: String,
+    pub(super) s3_keys: Option<HashSet<String>>,
+    pub(super) metadata_report_format: MetadataReportFormat,
+}
+
+/// Writes a metadata report into [DuplexStream] report content writer with all S3 objects metadata (and tags) under
+/// [MetadataReportOptions] S3 path (starting by [(Vec<S3ObjectMetadata>, Option<String>)] first S3 listing page)
+/// by [DynAwsSdkS3Service] AWS S3 service and sends its export report to [oneshot::Sender<ExportReport>] sender
+/// Important: S3 objects with tags that can't be requested are reported without tags
+async fn write_metadata_report(
+    aws_s3_service: DynAwsSdkS3Service,
+    metadata_report_options: MetadataReportOptions,
+    s3_object_metadata_page: (Vec<S3ObjectMetadata>, Option<String>),
+    mut report_content_writer: DuplexStream,
+    export_started_at: Instant,
+    export_report_sender: oneshot::Sender<ExportReport>,
+) {
+    let MetadataReportOptions { s3_bucket, s3_path, s3_keys, metadata_report_format } = metadata_report_options;
+    let report_header = match metadata_report_format {
+        MetadataReportFormat::CSV => format!("{METADATA_REPORT_CSV_HEADER}\n"),
+        MetadataReportFormat::JSON => String::from("["),
+    };
+    if report_content_writer.write_all(report_header.as_bytes()).await.is_err() {
+        return;
+    }
+
+    let mut objects_total = 0;
+    let (mut s3_object_metadata_list, mut continuation_token) = s3_object_metadata_page;
+    loop {
+        for mut s3_object_metadata in s3_object_metadata_list
+            .into_iter()
+            .filter(|s3_object_metadata| s3_keys.as_ref().is_none_or(|s3_keys| s3_keys.contains(&s3_object_metadata.s3_key)))
+        {
+            s3_object_metadata.tags = aws_s3_service
+                .get_s3_object_tags(s3_bucket.clone(), s3_path.clone(), s3_object_metadata.s3_key.clone())
+                .await
+                .ok();
+            let report_row = create_metadata_report_row(metadata_report_format, &s3_object_metadata, objects_total == 0);
+            if let Err(write_error) = report_content_writer.write_all(report_row.as_bytes()).await {
+                warn!("write_metadata_report - can't write report (client disconnected?) - error: {write_error}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return;
+            }
+            objects_total += 1;
+        }
+
+        let Some(next_continuation_token) = continuation_token else {
+            break;
+        };
+        (s3_object_metadata_list, continuation_token) = match aws_s3_service
+            .get_s3_object_metadata_page(s3_bucket.clone(), s3_path.clone(), None, Some(next_continuation_token))
+            .await
+        {
+            Ok(s3_object_metadata_page) => s3_object_metadata_page,
+            Err(metadata_error) => {
+                warn!("write_metadata_report - can't get next metadata page - s3 bucket: {s3_bucket} - error: {metadata_error}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return;
+            }
+        };
+    }
+
+    if metadata_report_format == MetadataReportFormat::JSON && report_content_writer.write_all(b"\n]\n").await.is_err() {
+        return;
+    }
+    let _ = report_content_writer.shutdown().await;
+
+    let mut export_report = create_export_report(export_started_at, Vec::new(), Vec::new(), Vec::new());
+    export_report.files_total = objects_total;
+    USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, false);
+    let _ = export_report_sender.send(export_report);
+}
+
+/// Gets [String] metadata report row by [MetadataReportFormat] report format, [&S3ObjectMetadata] S3 object metadata
+/// and [bool] first row flag (CSV rows: key, size, last modified date, entity tag, storage class and `key=value` tags
+/// separated by `;` - JSON rows: S3 object metadata array items)
+fn create_metadata_report_row(metadata_report_format: MetadataReportFormat, s3_object_metadata: &S3ObjectMetadata, first_row: bool) -> String {
+    match metadata_report_format {
+        MetadataReportFormat::CSV => {
+            let tags = s3_object_metadata
+                .tags
+                .iter()
+                .flatten()
+                .map(|(tag_key, tag_value)| format!("{tag_key}={tag_value}"))
+                .collect::<Vec<String>>()
+                .join(";");
+            let report_fields = [
+                s3_object_metadata.s3_key.clone(),
+                s3_object_metadata.size_bytes.to_string(),
+                s3_object_metadata.last_modified.clone().unwrap_or_default(),
+                s3_object_metadata.e_tag.clone().unwrap_or_default(),
+                s3_object_metadata.storage_class.clone().unwrap_or_default(),
+                tags,
+            ];
+            let report_row = report_fields.iter().map(|report_field| escape_csv_field(report_field)).collect::<Vec<String>>().join(",");
+            format!("{report_row}\n")
+        }
+        MetadataReportFormat::JSON => {
+            let separator = if first_row { "\n" } else { ",\n" };
+            format!("{separator}{}", serde_json::to_string(s3_object_metadata).unwrap_or_default())
+        }
+    }
+}
+
+/// Gets [String] CSV field by [&str] field value (quoted if it contains commas, quotes or line breaks)
+fn escape_csv_field(field_value: &str) -> String {
+    match field_value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field_value.replace('"', "\"\"")),
+        false => String::from(field_value),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::enums::metadata_report_format::MetadataReportFormat;
+    use crate::service::download_service::metadata_report::create_metadata_report_row;
+
+    /// Scenario:
+    /// Creates CSV and JSON metadata report rows for an S3 object with commas in its S3 key and two tags
+    /// Expectation:
+    /// CSV row should be escaped with sorted tags and JSON rows should be separated just after the first row
+    #[test]
+    fn when_create_metadata_report_row_should_escape_csv_fields() {
+        let s3_object_metadata = S3ObjectMetadata {
+            s3_key: String::from("2026/sales, q1.csv"),
+            size_bytes: 1024,
+            e_tag: Some(String::from("\"e-tag\"")),
+            storage_class: Some(String::from("STANDARD")),
+            tags: Some(BTreeMap::from([(String::from("team"), String::from("sales")), (String::from("owner"), String::from("ana"))])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "\"2026/sales, q1.csv\",1024,,\"\"\"e-tag\"\"\",STANDARD,owner=ana;team=sales\n",
+            create_metadata_report_row(MetadataReportFormat::CSV, &s3_object_metadata, true)
+        );
+        assert!(create_metadata_report_row(MetadataReportFormat::JSON, &s3_object_metadata, true).starts_with("\n{"));
+        assert!(create_metadata_report_row(MetadataReportFormat::JSON, &s3_object_metadata, false).starts_with(",\n{"));
+    }
+}
@@ -0,0 +1,1310 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use crate::config::download_quota::DOWNLOAD_QUOTA_REGISTRY;
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_u64, get_env_var_as_usize};
+use crate::config::memory_budget::MEMORY_BUDGET;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{ARCHIVE_ENTRY_MAX_BYTES_DEFAULT, ARCHIVE_ENTRY_MAX_BYTES_ENV_VAR, ARCHIVE_EXTRACTION_FILE_NAME, ARCHIVE_READ_AHEAD_BYTES_DEFAULT, ARCHIVE_READ_AHEAD_BYTES_ENV_VAR, AWS_S3_ALLOW_OVERSIZE_ENABLED_DEFAULT, AWS_S3_ALLOW_OVERSIZE_ENABLED_ENV_VAR, AWS_S3_OBJECT_CHANNEL_CAPACITY, CHECKSUM_AUDIT_FILE_NAME, DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT, DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR, ENCRYPTED_EXPORT_FILE_EXTENSION, EXPORT_REPORT_SLOWEST_FILES_QUANTITY, METADATA_REPORT_FILE_NAME, ZIP_EXCLUDED_FILES_FILE_NAME, ZIP_RESERVED_FOLDER, ZIP_STREAM_BUFFER_SIZE, ZIP_EXPORT_FILE_NAME};
+use crate::dto::archive_entry::ArchiveEntry;
+use crate::dto::archive_preview::ArchivePreview;
+use crate::dto::archive_request::ArchiveRequest;
+use crate::dto::batch_download_request::BatchDownloadRequest;
+use crate::dto::download_export::DownloadExport;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::export_report::{ExportReport, FileFetchReport};
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::dto::s3_fetch_options::S3FetchOptions;
+use crate::enums::common_error::CommonError;
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use crate::enums::export_filter::ExportFilter;
+use crate::enums::metadata_report_format::MetadataReportFormat;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use crate::service::download_service::archive_extract::read_archive_entries;
+use crate::service::download_service::checksum_audit::ChecksumAuditOptions;
+use crate::service::download_service::export_encryption::create_encryptor;
+use crate::service::download_service::metadata_report::MetadataReportOptions;
+use crate::service::download_service::multipart_export::write_multipart_content;
+use crate::service::download_service::zip_export::{create_zip_export, ZipExportOptions};
+use log::{debug, error, info, warn};
+use tokio::io::{duplex, DuplexStream};
+use tokio::sync::mpsc::channel;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+mod archive_extract;
+mod checksum_audit;
+mod export_encryption;
+mod metadata_report;
+mod multipart_export;
+mod zip_export;
+
+/// Download service
+/// Download flow is a bounded pipeline: S3 lister -> S3 fetcher pool (bounded fetch window and S3 file channel)
+/// -> zip archiver (blocking thread) -> zip content stream (bounded buffer) -> response
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DownloadServiceTrait {
+    /// Gets [DownloadExport] zip file name, content stream and export report with all downloaded files from Amazon S3
+    /// by [DownloadRequest] download request (S3 bucket name, S3 path and zip options)
+    /// Returns a [CommonError] if download flow fails, manifest is not valid, memory budget or download quota is exceeded
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT:
+    /// - zip content is streamed while files are downloaded (the response starts before all files are ready)
+    /// - export report is available once the zip content is completed
+    /// - maximum file quantity to be downloaded should be configured (please, check constants.rs)
+    /// - maximum file supported size should be configured (please, check constants.rs) - greater files are exported
+    ///   just if oversize files are requested and enabled by server configuration
+    /// - memory budget shared by all in-flight exports should be configured (please, check constants.rs)
+    /// - if a manifest is requested, just the manifest files are exported (manifest maximum entries should be configured)
+    async fn download_files(&self, download_request: DownloadRequest) -> Result<DownloadExport, CommonError>;
+
+    /// Gets [(String, DuplexStream, oneshot::Receiver<()>)] multipart boundary, multipart/mixed content stream (one part
+    /// per file) and multipart completion (sent once the closing boundary is written - dropped if the content can't be
+    /// completed) with requested downloaded files from Amazon S3 by [BatchDownloadRequest] batch download request
+    /// Returns a [CommonError] if download flow fails, memory budget or download quota is exceeded
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT: parts are streamed in requested S3 keys order (S3 keys not found are skipped)
+    async fn download_files_as_multipart(
+        &self,
+        batch_download_request: BatchDownloadRequest,
+    ) -> Result<(String, DuplexStream, oneshot::Receiver<()>), CommonError>;
+
+    /// Gets [ArchivePreview] export file name and zip entry names an export would contain by [DownloadRequest]
+    /// download request (nothing is downloaded - just S3 listings are requested)
+    /// Returns a [CommonError] if S3 throws any error or manifest is not valid
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT: entries depending on file contents (deduplicated files and missing files list) are not previewed
+    async fn preview_archive(&self, download_request: DownloadRequest) -> Result<ArchivePreview, CommonError>;
+
+    /// Gets [Vec<ArchiveEntry>] entries of a ZIP or tar archive stored in S3 by [ArchiveRequest] archive request
+    /// (ZIP central directory or tar headers are read by ranged requests - the whole archive is not downloaded)
+    /// Returns a [CommonError::NO_DATA_FOUND] if archive S3 key doesn't exist
+    /// Returns a [CommonError] if archive format is not supported or valid or S3 throws any error
+    async fn list_archive_entries(&self, archive_request: ArchiveRequest) -> Result<Vec<ArchiveEntry>, CommonError>;
+
+    /// Gets [DownloadExport] zip file name, content stream and export report with the selected entries of a ZIP or
+    /// tar archive stored in S3 by [ArchiveRequest] archive request (just selected entries are read by ranged requests)
+    /// Returns a [CommonError::NO_DATA_FOUND] if archive S3 key or any selected entry doesn't exist
+    /// Returns a [CommonError] if archive format is not supported or valid, memory budget or download quota is
+    /// exceeded or S3 throws any error
+    async fn extract_archive_entries(&self, archive_request: ArchiveRequest) -> Result<DownloadExport, CommonError>;
+}
+
+/// Download service implementation struct
+pub struct DownloadService {
+    aws_s3_service: DynAwsSdkS3Service,
+    manifest_max_entries: usize,
+    allow_oversize_enabled: bool,
+    archive_read_ahead_bytes: u64,
+    archive_entry_max_bytes: u64,
+}
+
+/// initialization by shared dependencies
+impl DownloadService {
+    /// Creates a new [DownloadService] by [DynAwsSdkS3Service] AWS S3 service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service) -> Self {
+        DownloadService {
+            aws_s3_service,
+            manifest_max_entries: get_env_var_as_usize(DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR, DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT),
+            allow_oversize_enabled: get_env_var_as_bool(AWS_S3_ALLOW_OVERSIZE_ENABLED_ENV_VAR, AWS_S3_ALLOW_OVERSIZE_ENABLED_DEFAULT),
+            archive_read_ahead_bytes: get_env_var_as_u64(ARCHIVE_READ_AHEAD_BYTES_ENV_VAR, ARCHIVE_READ_AHEAD_BYTES_DEFAULT),
+            archive_entry_max_bytes: get_env_var_as_u64(ARCHIVE_ENTRY_MAX_BYTES_ENV_VAR, ARCHIVE_ENTRY_MAX_BYTES_DEFAULT),
+        }
+    }
+}
+
+/// default initialization
+impl Default for DownloadService {
+    fn default() -> Self {
+        DownloadService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service)
+    }
+}
+
+/// Download service implementation logic
+#[async_trait]
+impl DownloadServiceTrait for DownloadService {
+    /// Gets [DownloadExport] zip file name, content stream and export report with all downloaded files from Amazon S3
+    /// by [DownloadRequest] download request (S3 bucket name, S3 path and zip options)
+    /// Returns a [CommonError] if download flow fails, manifest is not valid or memory budget is exceeded
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT:
+    /// - zip content is streamed while files are downloaded (the response starts before all files are ready)
+    /// - export report is available once the zip content is completed
+    /// - maximum file quantity to be downloaded should be configured (please, check constants.rs)
+    /// - maximum file supported size should be configured (please, check constants.rs) - greater files are exported
+    ///   just if oversize files are requested and enabled by server configuration
+    /// - memory budget shared by all in-flight exports should be configured (please, check constants.rs)
+    /// - if a manifest is requested, just the manifest files are exported (manifest maximum entries should be configured)
+    /// - if a filter is requested, just the matching files are exported (please, check [DownloadService::apply_export_filter])
+    async fn download_files(&self, mut download_request: DownloadRequest) -> Result<DownloadExport, CommonError> {
+        info!("download_files - start");
+        let export_started_at = Instant::now();
+        if MEMORY_BUDGET.is_exceeded() {
+            warn!("download_files - memory budget exceeded - buffered bytes: {}", MEMORY_BUDGET.buffered_bytes());
+            USAGE_STATS_REGISTRY.record_export(&download_request.bucket_name, 0, true);
+            return Err(CommonError::MEMORY_BUDGET_EXCEEDED);
+        }
+        if DOWNLOAD_QUOTA_REGISTRY.is_exceeded(&download_request.bucket_name, download_request.caller_id.as_deref()) {
+            warn!("download_files - download quota exceeded - bucket name: {}", download_request.bucket_name);
+            return Err(CommonError::QUOTA_EXCEEDED);
+        }
+        if download_request.allow_oversize && !self.allow_oversize_enabled {
+            warn!("download_files - oversize files requested but not enabled - bucket name: {}", download_request.bucket_name);
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+        self.apply_export_filter(&mut download_request).await?;
+
+        let encryptor = match download_request.encryption_recipient.as_deref().map(create_encryptor) {
+            Some(Ok(encryptor)) => Some(encryptor),
+            Some(Err(encryption_error)) => {
+                warn!("download_files - not valid encryption recipient - error: {encryption_error}");
+                return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+            }
+            None => None,
+        };
+
+        if let Some(metadata_report_format) = download_request.metadata_report {
+            let s3_keys = download_request
+                .manifest
+                .map(|manifest| manifest.into_iter().map(|manifest_entry| manifest_entry.s3_key).collect());
+            let metadata_report_options = MetadataReportOptions {
+                s3_bucket: download_request.bucket_name,
+                s3_path: download_request.full_path,
+                s3_keys,
+                metadata_report_format,
+            };
+            return self.download_metadata_report(metadata_report_options, encryptor, export_started_at).await;
+        }
+        if download_request.checksum_audit {
+            let checksum_audit_options = ChecksumAuditOptions {
+                s3_bucket: download_request.bucket_name,
+                s3_path: download_request.full_path,
+                s3_keys: download_request
+                    .manifest
+                    .map(|manifest| manifest.into_iter().map(|manifest_entry| manifest_entry.s3_key).collect()),
+                s3_fetch_options: S3FetchOptions {
+                    max_parallel_fetches: download_request.max_parallel_fetches,
+                    multipart_chunk_bytes: download_request.multipart_chunk_bytes,
+                    direct_key_lookup: download_request.direct_key_lookup,
+                    allow_oversize: download_request.allow_oversize,
+                    stream_content: false,
+                    cancellation_token: download_request.cancellation_token,
+                },
+            };
+            return self.download_checksum_audit(checksum_audit_options, encryptor, export_started_at).await;
+        }
+
+        let s3_bucket = download_request.bucket_name;
+        let s3_path = download_request.full_path;
+        let s3_fetch_options = S3FetchOptions {
+            max_parallel_fetches: download_request.max_parallel_fetches,
+            multipart_chunk_bytes: download_request.multipart_chunk_bytes,
+            direct_key_lookup: download_request.direct_key_lookup,
+            allow_oversize: download_request.allow_oversize,
+            stream_content: !download_request.deduplicate,
+            cancellation_token: download_request.cancellation_token,
+        };
+
+        let mut excluded_files = match download_request.allow_oversize {
+            true => Vec::new(),
+            false => self.get_excluded_files(&s3_bucket, &s3_path).await,
+        };
+        let mut entry_names = HashMap::new();
+        let s3_file_receiver = match download_request.manifest {
+            Some(manifest) => {
+                if manifest.is_empty() || manifest.len() > self.manifest_max_entries || !is_valid_manifest(&manifest) {
+                    warn!("download_files - not valid manifest - entries: {}", manifest.len());
+                    return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+                }
+                let manifest = self
+                    .resolve_duplicate_entry_names(&s3_bucket, &s3_path, manifest, download_request.duplicate_entry_policy)
+                    .await?;
+                excluded_files.retain(|s3_key| manifest.iter().any(|manifest_entry| &manifest_entry.s3_key == s3_key));
+                let s3_keys = manifest
+                    .iter()
+                    .map(|manifest_entry| manifest_entry.s3_key.clone())
+                    .filter(|s3_key| !excluded_files.contains(s3_key))
+                    .collect();
+                entry_names = manifest
+                    .into_iter()
+                    .filter_map(|manifest_entry| manifest_entry.archive_name.map(|archive_name| (manifest_entry.s3_key, archive_name)))
+                    .collect();
+                self.aws_s3_service
+                    .get_s3_objects_by_keys_as_channel(s3_bucket.clone(), s3_path.clone(), s3_keys, s3_fetch_options)
+                    .await
+            }
+            None => {
+                self.aws_s3_service
+                    .get_s3_objects_by_path_as_channel(s3_bucket.clone(), s3_path.clone(), s3_fetch_options)
+                    .await
+            }
+        };
+
+        match s3_file_receiver {
+            Ok(s3_file_receiver) => {
+                info!("download_files - download files started - s3 bucket: {s3_bucket}");
+                info!("download_files - download files started - s3 path: {s3_path}");
+
+                let zip_export_options = ZipExportOptions {
+                    s3_bucket: s3_bucket.clone(),
+                    caller_id: download_request.caller_id,
+                    deduplicate: download_request.deduplicate,
+                    missing_key_policy: download_request.missing_key_policy,
+                    entry_names,
+                    excluded_files,
+                };
+
+                info!("download_files - done");
+                Ok(create_zip_export(s3_file_receiver, zip_export_options, ZIP_EXPORT_FILE_NAME, encryptor, export_started_at))
+            }
+            Err(download_error) => {
+                error!("download_files - download error - can't get files from s3 bucket: {s3_bucket}");
+                error!("download_files - download error - can't get files from s3 path: {s3_path}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                match download_error {
+                    CommonError::LIMIT_EXCEEDED { .. } => Err(download_error),
+                    _ => Err(CommonError::AWS_ACCESS_ERROR),
+                }
+            }
+        }
+    }
+
+    /// Gets [(String, DuplexStream, oneshot::Receiver<()>)] multipart boundary, multipart/mixed content stream (one part
+    /// per file) and multipart completion (sent once the closing boundary is written - dropped if the content can't be
+    /// completed) with requested downloaded files from Amazon S3 by [BatchDownloadRequest] batch download request
+    /// Returns a [CommonError] if download flow fails or memory budget is exceeded
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT: parts are streamed in requested S3 keys order (S3 keys not found are skipped)
+    async fn download_files_as_multipart(
+        &self,
+        batch_download_request: BatchDownloadRequest,
+    ) -> Result<(String, DuplexStream, oneshot::Receiver<()>), CommonError> {
+        info!("download_files_as_multipart - start");
+        if MEMORY_BUDGET.is_exceeded() {
+            warn!("download_files_as_multipart - memory budget exceeded - buffered bytes: {}", MEMORY_BUDGET.buffered_bytes());
+            USAGE_STATS_REGISTRY.record_export(&batch_download_request.bucket_name, 0, true);
+            return Err(CommonError::MEMORY_BUDGET_EXCEEDED);
+        }
+        if DOWNLOAD_QUOTA_REGISTRY.is_exceeded(&batch_download_request.bucket_name, batch_download_request.caller_id.as_deref()) {
+            warn!("download_files_as_multipart - download quota exceeded - bucket name: {}", batch_download_request.bucket_name);
+            return Err(CommonError::QUOTA_EXCEEDED);
+        }
+
+        let s3_bucket = batch_download_request.bucket_name;
+        let s3_path = batch_download_request.full_path;
+        match self
+            .aws_s3_service
+            .get_s3_objects_by_keys_as_channel(
+                s3_bucket.clone(),
+                s3_path.clone(),
+                batch_download_request.s3_keys,
+                S3FetchOptions {
+                    direct_key_lookup: batch_download_request.direct_key_lookup,
+                    cancellation_token: batch_download_request.cancellation_token,
+                    ..S3FetchOptions::default()
+                },
+            )
+            .await
+        {
+            Ok(s3_file_receiver) => {
+                let multipart_boundary = Uuid::new_v4().simple().to_string();
+                let (multipart_content_reader, multipart_content_writer) = duplex(ZIP_STREAM_BUFFER_SIZE);
+                let (multipart_completion_sender, multipart_completion_receiver) = oneshot::channel();
+                tokio::spawn(write_multipart_content(
+                    s3_file_receiver,
+                    multipart_content_writer,
+                    s3_bucket,
+                    batch_download_request.caller_id,
+                    multipart_boundary.clone(),
+                    multipart_completion_sender,
+                ));
+
+                info!("download_files_as_multipart - done");
+                Ok((multipart_boundary, multipart_content_reader, multipart_completion_receiver))
+            }
+            Err(download_error) => {
+                error!("download_files_as_multipart - download error - can't get files from s3 bucket: {s3_bucket}");
+                error!("download_files_as_multipart - download error - can't get files from s3 path: {s3_path}");
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                match download_error {
+                    CommonError::LIMIT_EXCEEDED { .. } => Err(download_error),
+                    _ => Err(CommonError::AWS_ACCESS_ERROR),
+                }
+            }
+        }
+    }
+
+    /// Gets [ArchivePreview] export file name and zip entry names an export would contain by [DownloadRequest]
+    /// download request (nothing is downloaded - just S3 listings are requested)
+    /// Returns a [CommonError] if S3 throws any error or manifest is not valid
+    /// Returns a [CommonError::LIMIT_EXCEEDED] if requested files are more than configured maximum file quantity
+    /// IMPORTANT: entries depending on file contents (deduplicated files and missing files list) are not previewed
+    async fn preview_archive(&self, mut download_request: DownloadRequest) -> Result<ArchivePreview, CommonError> {
+        info!("preview_archive - start");
+        if download_request.allow_oversize && !self.allow_oversize_enabled {
+            warn!("preview_archive - oversize files requested but not enabled - bucket name: {}", download_request.bucket_name);
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+        if let Some(Err(encryption_error)) = download_request.encryption_recipient.as_deref().map(create_encryptor) {
+            warn!("preview_archive - not valid encryption recipient - error: {encryption_error}");
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+        self.apply_export_filter(&mut download_request).await?;
+
+        let mut file_name = match (download_request.metadata_report, download_request.checksum_audit) {
+            (Some(MetadataReportFormat::CSV), _) => format!("{METADATA_REPORT_FILE_NAME}.csv"),
+            (Some(MetadataReportFormat::JSON), _) => format!("{METADATA_REPORT_FILE_NAME}.json"),
+            (None, true) => String::from(CHECKSUM_AUDIT_FILE_NAME),
+            (None, false) => String::from(ZIP_EXPORT_FILE_NAME),
+        };
+        if download_request.encryption_recipient.is_some() {
+            file_name.push_str(ENCRYPTED_EXPORT_FILE_EXTENSION);
+        }
+        if download_request.metadata_report.is_some() || download_request.checksum_audit {
+            info!("preview_archive - done (report export)");
+            return Ok(ArchivePreview { file_name, entries: Vec::new(), excluded_files: Vec::new() });
+        }
+
+        let s3_bucket = download_request.bucket_name;
+        let s3_path = download_request.full_path;
+        let mut excluded_files = match download_request.allow_oversize {
+            true => Vec::new(),
+            false => self.get_excluded_files(&s3_bucket, &s3_path).await,
+        };
+        let entry_names = match download_request.manifest {
+            Some(manifest) => {
+                if manifest.is_empty() || manifest.len() > self.manifest_max_entries || !is_valid_manifest(&manifest) {
+                    warn!("preview_archive - not valid manifest - entries: {}", manifest.len());
+                    return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+                }
+                let manifest = self
+                    .resolve_duplicate_entry_names(&s3_bucket, &s3_path, manifest, download_request.duplicate_entry_policy)
+                    .await?;
+                excluded_files.retain(|s3_key| manifest.iter().any(|manifest_entry| &manifest_entry.s3_key == s3_key));
+                manifest
+                    .iter()
+                    .filter(|manifest_entry| !excluded_files.contains(&manifest_entry.s3_key))
+                    .map(get_entry_name)
+                    .collect()
+            }
+            None => {
+                let s3_keys = self
+                    .aws_s3_service
+                    .get_s3_object_key_list(s3_bucket.clone(), s3_path.clone())
+                    .await
+                    .map_err(|list_error| match list_error {
+                        CommonError::LIMIT_EXCEEDED { .. } => list_error,
+                        _ => CommonError::AWS_ACCESS_ERROR,
+                    })?;
+                match download_request.allow_oversize {
+                    true => {
+                        let mut s3_keys = [s3_keys, self.get_excluded_files(&s3_bucket, &s3_path).await].concat();
+                        s3_keys.sort();
+                        s3_keys
+                    }
+                    false => s3_keys,
+                }
+            }
+        };
+
+        info!("preview_archive - done - s3 bucket: {s3_bucket} - s3 path: {s3_path}");
+        Ok(create_archive_preview(file_name, entry_names, excluded_files))
+    }
+
+    /// Gets [Vec<ArchiveEntry>] entries of a ZIP or tar archive stored in S3 by [ArchiveRequest] archive request
+    /// (ZIP central directory or tar headers are read by ranged requests - the whole archive is not downloaded)
+    /// Returns a [CommonError::NO_DATA_FOUND] if archive S3 key doesn't exist
+    /// Returns a [CommonError] if archive format is not supported or valid or S3 throws any error
+    async fn list_archive_entries(&self, archive_request: ArchiveRequest) -> Result<Vec<ArchiveEntry>, CommonError> {
+        info!("list_archive_entries - start");
+        let archive_entries = self.open_archive(&archive_request).await?.entries();
+        info!("list_archive_entries - done - s3 key: {} - entries: {}", archive_request.s3_key, archive_entries.len());
+        Ok(archive_entries)
+    }
+
+    /// Gets [DownloadExport] zip file name, content stream and export report with the selected entries of a ZIP or
+    /// tar archive stored in S3 by [ArchiveRequest] archive request (just selected entries are read by ranged requests)
+    /// Returns a [CommonError::NO_DATA_FOUND] if archive S3 key or any selected entry doesn't exist
+    /// Returns a [CommonError] if archive format is not supported or valid, memory budget or download quota is
+    /// exceeded or S3 throws any error
+    async fn extract_archive_entries(&self, archive_request: ArchiveRequest) -> Result<DownloadExport, CommonError> {
+        info!("extract_archive_entries - start");
+        let export_started_at = Instant::now();
+        if MEMORY_BUDGET.is_exceeded() {
+            warn!("extract_archive_entries - memory budget exceeded - buffered bytes: {}", MEMORY_BUDGET.buffered_bytes());
+            USAGE_STATS_REGISTRY.record_export(&archive_request.bucket_name, 0, true);
+            return Err(CommonError::MEMORY_BUDGET_EXCEEDED);
+        }
+        if DOWNLOAD_QUOTA_REGISTRY.is_exceeded(&archive_request.bucket_name, archive_request.caller_id.as_deref()) {
+            warn!("extract_archive_entries - download quota exceeded - bucket name: {}", archive_request.bucket_name);
+            return Err(CommonError::QUOTA_EXCEEDED);
+        }
+        if archive_request.entries.is_empty()
+            || archive_request.entries.len() > self.manifest_max_entries
+            || !archive_request.entries.iter().all(|entry_name| is_safe_entry_name(entry_name))
+        {
+            warn!("extract_archive_entries - not valid selected entries - entries: {}", archive_request.entries.len());
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+
+        let encryptor = match archive_request.encryption_recipient.as_deref().map(create_encryptor) {
+            Some(Ok(encryptor)) => Some(encryptor),
+            Some(Err(encryption_error)) => {
+                warn!("extract_archive_entries - not valid encryption recipient - error: {encryption_error}");
+                return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+            }
+            None => None,
+        };
+
+        let archive_reader = self.open_archive(&archive_request).await?;
+        let archive_entries = archive_reader.entries();
+        if let Some(entry_name) = archive_request.entries.iter().find(|entry_name| {
+            !archive_entries.iter().any(|archive_entry| &archive_entry.name == *entry_name && !archive_entry.directory)
+        }) {
+            warn!("extract_archive_entries - archive entry not found: {entry_name}");
+            return Err(CommonError::NO_DATA_FOUND);
+        }
+
+        let (s3_file_sender, s3_file_receiver) = channel(AWS_S3_OBJECT_CHANNEL_CAPACITY);
+        let selected_entry_names = archive_request.entries;
+        let cancellation_token = archive_request.cancellation_token;
+        tokio::task::spawn_blocking(move || read_archive_entries(archive_reader, selected_entry_names, s3_file_sender, cancellation_token));
+
+        let zip_export_options = ZipExportOptions {
+            s3_bucket: archive_request.bucket_name,
+            caller_id: archive_request.caller_id,
+            deduplicate: false,
+            missing_key_policy: MissingKeyPolicy::default(),
+            entry_names: HashMap::new(),
+            excluded_files: Vec::new(),
+        };
+        info!("extract_archive_entries - done - s3 key: {}", archive_request.s3_key);
+        Ok(create_zip_export(s3_file_receiver, zip_export_options, ARCHIVE_EXTRACTION_FILE_NAME, encryptor, export_started_at))
+    }
+}
+
+/// Download service internal logic
+impl DownloadService {
+    /// Applies [&mut DownloadRequest] download request filter (if any): the manifest is replaced by the matching files
+    /// (just matching manifest entries are kept if a manifest is requested) and the filter is removed
+    /// Returns a [CommonError::NO_DATA_FOUND] if there is no matching file, a [CommonError::LIMIT_EXCEEDED] if matching
+    /// files are more than manifest maximum entries or a [CommonError] if S3 throws any error
+    async fn apply_export_filter(&self, download_request: &mut DownloadRequest) -> Result<(), CommonError> {
+        let Some(export_filter) = download_request.filter.take() else {
+            return Ok(());
+        };
+        let filtered_s3_keys = get_filtered_s3_keys(
+            &self.aws_s3_service,
+            &download_request.bucket_name,
+            &download_request.full_path,
+            &export_filter,
+        )
+        .await?;
+        let manifest: Vec<ManifestEntry> = match download_request.manifest.take() {
+            Some(manifest) => manifest
+                .into_iter()
+                .filter(|manifest_entry| filtered_s3_keys.binary_search(&manifest_entry.s3_key).is_ok())
+                .collect(),
+            None => filtered_s3_keys
+                .into_iter()
+                .map(|s3_key| ManifestEntry { s3_key, archive_name: None })
+                .collect(),
+        };
+        if manifest.is_empty() {
+            warn!("apply_export_filter - no matching files - bucket name: {}", download_request.bucket_name);
+            return Err(CommonError::NO_DATA_FOUND);
+        }
+        if manifest.len() > self.manifest_max_entries {
+            warn!("apply_export_filter - too many matching files - matching files: {}", manifest.len());
+            return Err(CommonError::LIMIT_EXCEEDED { limit: self.manifest_max_entries, actual: manifest.len() });
+        }
+
+        debug!("apply_export_filter - done - matching files: {}", manifest.len());
+        download_request.manifest = Some(manifest);
+        Ok(())
+    }
+
+    /// Gets [Vec<String>] S3 keys excluded by limits (files greater than the maximum file size) by [&str] S3 bucket
+    /// and [&str] S3 path
+    /// Important: excluded files are just reported, so S3 errors are logged and no excluded file is retrieved
+    async fn get_excluded_files(&self, s3_bucket: &str, s3_path: &str) -> Vec<String> {
+        match self
+            .aws_s3_service
+            .get_s3_excluded_object_key_list(String::from(s3_bucket), String::from(s3_path))
+            .await
+        {
+            Ok(excluded_files) => excluded_files,
+            Err(excluded_files_error) => {
+                warn!("get_excluded_files - can't get excluded files - s3 bucket: {s3_bucket} - error: {excluded_files_error}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Gets [Vec<ManifestEntry>] manifest without duplicated zip entry names by [&str] S3 bucket, [&str] S3 path,
+    /// [Vec<ManifestEntry>] requested manifest and [DuplicateEntryPolicy] duplicate entry policy
+    /// Returns a [CommonError] if a duplicated zip entry name is found (error policy) or S3 throws any error
+    async fn resolve_duplicate_entry_names(
+        &self,
+        s3_bucket: &str,
+        s3_path: &str,
+        manifest: Vec<ManifestEntry>,
+        duplicate_entry_policy: DuplicateEntryPolicy,
+    ) -> Result<Vec<ManifestEntry>, CommonError> {
+        let mut entry_name_counts: HashMap<String, usize> = HashMap::new();
+        manifest.iter().for_each(|manifest_entry| *entry_name_counts.entry(get_entry_name(manifest_entry)).or_default() += 1);
+        if entry_name_counts.values().all(|entry_name_count| *entry_name_count == 1) {
+            return Ok(manifest);
+        }
+
+        match duplicate_entry_policy {
+            DuplicateEntryPolicy::ERROR => {
+                warn!("resolve_duplicate_entry_names - duplicated zip entry names found");
+                Err(CommonError::NO_VALID_INPUT_OR_PARAMETER)
+            }
+            DuplicateEntryPolicy::SUFFIX => Ok(add_entry_name_suffixes(manifest)),
+            DuplicateEntryPolicy::KEEP_NEWEST => {
+                let mut newest_manifest_entries: HashMap<String, (usize, Option<String>, ManifestEntry)> = HashMap::new();
+                for (manifest_entry_index, manifest_entry) in manifest.into_iter().enumerate() {
+                    let entry_name = get_entry_name(&manifest_entry);
+                    let last_modified = if entry_name_counts[&entry_name] > 1 {
+                        self.aws_s3_service
+                            .get_s3_object_metadata(String::from(s3_bucket), String::from(s3_path), Some(manifest_entry.s3_key.clone()))
+                            .await?
+                            .and_then(|s3_object_metadata| s3_object_metadata.last_modified)
+                    } else {
+                        None
+                    };
+
+                    match newest_manifest_entries.entry(entry_name) {
+                        Entry::Occupied(mut newest_manifest_entry) if newest_manifest_entry.get().1 < last_modified => {
+                            newest_manifest_entry.insert((manifest_entry_index, last_modified, manifest_entry));
+                        }
+                        Entry::Occupied(_) => debug!("resolve_duplicate_entry_names - older s3 key skipped: {}", manifest_entry.s3_key),
+                        Entry::Vacant(new_manifest_entry) => {
+                            new_manifest_entry.insert((manifest_entry_index, last_modified, manifest_entry));
+                        }
+                    }
+                }
+
+                let mut newest_manifest_entries: Vec<_> = newest_manifest_entries.into_values().collect();
+                newest_manifest_entries.sort_by_key(|(manifest_entry_index, _, _)| *manifest_entry_index);
+                Ok(newest_manifest_entries.into_iter().map(|(_, _, manifest_entry)| manifest_entry).collect())
+            }
+        }
+    }
+}
+
+/// Gets [Vec<String>] S3 keys (files directly under the S3 path - sorted) matching [&ExportFilter] export filter by
+/// [&DynAwsSdkS3Service] AWS S3 service, [&str] bucket name and [&str] path (all S3 listing pages are requested and
+/// S3 object tags are requested just if the filter has tag conditions)
+/// Returns a [CommonError] if S3 throws any error
+pub async fn get_filtered_s3_keys(
+    aws_s3_service: &DynAwsSdkS3Service,
+    bucket_name: &str,
+    path: &str,
+    export_filter: &ExportFilter,
+) -> Result<Vec<String>, CommonError> {
+    let requires_tags = export_filter.requires_tags();
+    let mut filtered_s3_keys = Vec::new();
+    let mut continuation_token = None;
+    loop {
+        let (s3_object_metadata_page, next_continuation_token) = aws_s3_service
+            .get_s3_object_metadata_page(String::from(bucket_name), String::from(path), None, continuation_token)
+            .await?;
+        for mut s3_object_metadata in s3_object_metadata_page
+            .into_iter()
+            .filter(|s3_object_metadata| !s3_object_metadata.s3_key.is_empty() && !s3_object_metadata.s3_key.contains('/'))
+        {
+            if requires_tags {
+                s3_object_metadata.tags = aws_s3_service
+                    .get_s3_object_tags(String::from(bucket_name), String::from(path), s3_object_metadata.s3_key.clone())
+                    .await
+                    .ok();
+            }
+            if export_filter.is_match(&s3_object_metadata) {
+                filtered_s3_keys.push(s3_object_metadata.s3_key);
+            }
+        }
+
+        continuation_token = next_continuation_token;
+        if continuation_token.is_none() {
+            filtered_s3_keys.sort();
+            return Ok(filtered_s3_keys);
+        }
+    }
+}
+
+/// Creates a new [ArchivePreview] by [String] export file name, [Vec<String>] zip entry names of exported files and
+/// [Vec<String>] excluded S3 keys (excluded files list entry is added if there is any excluded file)
+fn create_archive_preview(file_name: String, mut entry_names: Vec<String>, excluded_files: Vec<String>) -> ArchivePreview {
+    if !excluded_files.is_empty() {
+        entry_names.push(String::from(ZIP_EXCLUDED_FILES_FILE_NAME));
+    }
+    ArchivePreview { file_name, entries: entry_names, excluded_files }
+}
+
+/// Gets [String] zip entry name by [&ManifestEntry] manifest entry (S3 key is used if archive name is not defined)
+fn get_entry_name(manifest_entry: &ManifestEntry) -> String {
+    manifest_entry.archive_name.clone().unwrap_or_else(|| manifest_entry.s3_key.clone())
+}
+
+/// Gets [Vec<ManifestEntry>] manifest with unique zip entry names by [Vec<ManifestEntry>] manifest
+/// Important: duplicated zip entry names get a numeric suffix before the extension (i.e.: "report (1).pdf")
+fn add_entry_name_suffixes(manifest: Vec<ManifestEntry>) -> Vec<ManifestEntry> {
+    let mut entry_names: HashSet<String> = HashSet::new();
+    manifest
+        .into_iter()
+        .map(|mut manifest_entry| {
+            let entry_name = get_entry_name(&manifest_entry);
+            let (entry_name_stem, entry_name_extension) = match entry_name.rsplit_once('.') {
+                Some((entry_name_stem, entry_name_extension)) if !entry_name_stem.is_empty() && !entry_name_stem.ends_with('/') => {
+                    (String::from(entry_name_stem), format!(".{entry_name_extension}"))
+                }
+                _ => (entry_name.clone(), String::new()),
+            };
+
+            let mut unique_entry_name = entry_name;
+            let mut entry_name_suffix = 0;
+            while !entry_names.insert(unique_entry_name.clone()) {
+                entry_name_suffix += 1;
+                unique_entry_name = format!("{entry_name_stem} ({entry_name_suffix}){entry_name_extension}");
+            }
+            manifest_entry.archive_name = Some(unique_entry_name);
+            manifest_entry
+        })
+        .collect()
+}
+
+/// Gets [bool] true if all manifest entries have a S3 key and a safe zip entry name (relative path without parent
+/// folder references and not under the reserved export entries folder) by [&[ManifestEntry]] manifest
+fn is_valid_manifest(manifest: &[ManifestEntry]) -> bool {
+    manifest.iter().all(|manifest_entry| {
+        !manifest_entry.s3_key.is_empty()
+            && manifest_entry.archive_name.as_deref().is_none_or(is_safe_entry_name)
+            && !manifest_entry.archive_name.as_deref().unwrap_or(&manifest_entry.s3_key).starts_with(ZIP_RESERVED_FOLDER)
+    })
+}
+
+/// Gets [bool] true if [&str] zip entry name is safe (not empty relative path without parent folder references)
+fn is_safe_entry_name(entry_name: &str) -> bool {
+    !entry_name.is_empty()
+        && !entry_name.starts_with('/')
+        && !entry_name.split(['/', '\\']).any(|entry_name_part| entry_name_part == "..")
+}
+
+/// Gets [ExportReport] export report by [Instant] export start, [Vec<FileFetchReport>] file fetch reports,
+/// [Vec<String>] missing files (S3 keys deleted after listing) and [Vec<String>] excluded files (S3 keys excluded by limits)
+/// Important: slowest files are limited by configured quantity (please, check constants.rs)
+fn create_export_report(
+    export_started_at: Instant,
+    file_fetch_reports: Vec<FileFetchReport>,
+    missing_files: Vec<String>,
+    excluded_files: Vec<String>,
+) -> ExportReport {
+    let elapsed_millis = export_started_at.elapsed().as_millis() as u64;
+    let bytes_total: u64 = file_fetch_reports.iter().map(|file_fetch_report| file_fetch_report.size_bytes).sum();
+
+    let mut slowest_file_fetch_reports: Vec<&FileFetchReport> = file_fetch_reports.iter().collect();
+    slowest_file_fetch_reports.sort_by_key(|file_fetch_report| Reverse(file_fetch_report.latency_millis));
+
+    ExportReport {
+        elapsed_millis,
+        files_total: file_fetch_reports.len(),
+        bytes_total,
+        throughput_bytes_per_second: bytes_total * 1000 / elapsed_millis.max(1),
+        retries_total: file_fetch_reports.iter().map(|file_fetch_report| file_fetch_report.retries).sum(),
+        slowest_files: slowest_file_fetch_reports
+            .iter()
+            .take(EXPORT_REPORT_SLOWEST_FILES_QUANTITY)
+            .map(|file_fetch_report| file_fetch_report.s3_key.clone())
+            .collect(),
+        files: file_fetch_reports,
+        missing_files,
+        excluded_files,
+        checksum_mismatches: Vec::new(),
+        archive_sha256: None,
+        archive_signature: None,
+    }
+}
+
+/// Gets [String] SHA-256 checksum (hex encoded) by [&[u8]] archive SHA-256 digest
+pub fn encode_archive_digest(archive_digest: &[u8]) -> String {
+    archive_digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Download service trait for API router state (based on Rust samples for Axum DI)
+pub type DynDownloadService = Arc<dyn DownloadServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+    use std::sync::Arc;
+
+    use crate::config::memory_budget::MEMORY_BUDGET;
+    use crate::dto::batch_download_request::BatchDownloadRequest;
+    use crate::dto::download_request::DownloadRequest;
+    use crate::dto::manifest_entry::ManifestEntry;
+    use crate::dto::s3_object::S3Object;
+    use crate::enums::common_error::CommonError;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use crate::service::download_service::{add_entry_name_suffixes, is_valid_manifest, DownloadService, DownloadServiceTrait};
+    use age::x25519::Identity;
+    use aws_sdk_s3::primitives::ByteStream;
+    use tokio::io::AsyncReadExt;
+    use tokio::sync::mpsc::channel;
+    use zip::ZipArchive;
+
+    /// Scenario:
+    /// Downloads files from a S3 path with two files
+    /// Expectation:
+    /// A streamed zip file with both files and their contents should be retrieved
+    #[tokio::test]
+    async fn when_download_files_should_stream_zip_with_all_files() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .returning(|_, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                s3_file_sender.try_send(create_s3_object("b.txt", b"content b")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+        let export_report = download_export.report.await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        let mut file_content = String::new();
+        zip_archive.by_name("b.txt").unwrap().read_to_string(&mut file_content).unwrap();
+
+        assert_eq!("s3-export.zip", download_export.file_name);
+        assert_eq!(2, zip_archive.len());
+        assert_eq!("content b", file_content);
+        assert_eq!(2, export_report.files_total);
+        assert_eq!(18, export_report.bytes_total);
+        assert_eq!(2, export_report.retries_total);
+        assert_eq!(vec!["a.txt", "b.txt"], export_report.slowest_files);
+    }
+
+    /// Scenario:
+    /// Downloads files from a S3 path with a file greater than the maximum file size
+    /// Expectation:
+    /// A streamed zip file with an excluded files list should be retrieved (excluded file in export report and total)
+    #[tokio::test]
+    async fn when_download_files_with_excluded_file_should_list_and_count_it() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(vec![String::from("big.bin")]));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .returning(|_, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(1);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+        let export_report = download_export.report.await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        let mut excluded_files = String::new();
+        zip_archive.by_name(".s3-export/excluded.txt").unwrap().read_to_string(&mut excluded_files).unwrap();
+
+        assert_eq!(1, download_export.excluded_count);
+        assert_eq!("big.bin\n", excluded_files);
+        assert_eq!(vec!["big.bin"], export_report.excluded_files);
+    }
+
+    /// Scenario:
+    /// Downloads files from a S3 path with a small file (buffered content) and a big file (content stream)
+    /// Expectation:
+    /// S3 objects content streams should be requested (no deduplication) and a streamed zip file with both files
+    /// should be retrieved (streamed file content copied into its zip entry and its size in the export report)
+    #[tokio::test]
+    async fn when_download_files_with_content_stream_should_copy_it_into_zip_entry() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .withf(|_, _, s3_fetch_options| s3_fetch_options.stream_content)
+            .returning(|_, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                let content_stream = Some(ByteStream::from(b"big content b".to_vec()));
+                s3_file_sender.try_send(S3Object { content_stream, ..create_s3_object("b.bin", b"") }).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+        let export_report = download_export.report.await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        let mut streamed_content = String::new();
+        zip_archive.by_name("b.bin").unwrap().read_to_string(&mut streamed_content).unwrap();
+
+        assert_eq!(2, zip_archive.len());
+        assert_eq!("big content b", streamed_content);
+        assert_eq!(22, export_report.bytes_total);
+    }
+
+    /// Scenario:
+    /// Downloads files from a S3 path with two files (one of them deleted after listing)
+    /// Expectation:
+    /// A streamed zip file with the found file and a missing files list should be retrieved (missing file in export report)
+    #[tokio::test]
+    async fn when_download_files_with_missing_key_should_skip_and_record_it() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .returning(|_, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                s3_file_sender
+                    .try_send(S3Object { fetch_error: Some(CommonError::NO_DATA_FOUND), ..create_s3_object("b.txt", b"") })
+                    .unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+        let export_report = download_export.report.await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        let mut missing_files = String::new();
+        zip_archive.by_name(".s3-export/missing-files.txt").unwrap().read_to_string(&mut missing_files).unwrap();
+
+        assert_eq!(2, zip_archive.len());
+        assert_eq!("b.txt\n", missing_files);
+        assert_eq!(1, export_report.files_total);
+        assert_eq!(vec!["b.txt"], export_report.missing_files);
+    }
+
+    /// Scenario:
+    /// Downloads files from a S3 path with two files with the same content and deduplicate option enabled
+    /// Expectation:
+    /// A streamed zip file with just one of the files and a dedup manifest should be retrieved
+    #[tokio::test]
+    async fn when_download_files_with_deduplicate_should_stream_zip_with_unique_files() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .returning(|_, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"same content")).unwrap();
+                s3_file_sender.try_send(create_s3_object("b.txt", b"same content")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            deduplicate: true,
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        let mut dedup_manifest = String::new();
+        zip_archive.by_name(".s3-export/dedup-manifest.txt").unwrap().read_to_string(&mut dedup_manifest).unwrap();
+
+        assert_eq!(2, zip_archive.len());
+        assert!(zip_archive.by_name("a.txt").is_ok());
+        assert_eq!("b.txt -> a.txt\n", dedup_manifest);
+    }
+
+    /// Scenario:
+    /// Downloads two requested files as a batch (multipart/mixed content)
+    /// Expectation:
+    /// A streamed multipart content with one part per file and a closing boundary should be retrieved
+    #[tokio::test]
+    async fn when_download_files_as_multipart_should_stream_one_part_per_file() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_objects_by_keys_as_channel()
+            .returning(|_, _, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                s3_file_sender.try_send(create_s3_object("b.txt", b"content b")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let batch_download_request = BatchDownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            s3_keys: vec![String::from("a.txt"), String::from("b.txt")],
+            direct_key_lookup: false,
+            caller_id: None,
+            ..Default::default()
+        };
+
+        let (multipart_boundary, mut multipart_content_reader, multipart_completion) =
+            download_service.download_files_as_multipart(batch_download_request).await.unwrap();
+        let mut multipart_content = String::new();
+        multipart_content_reader.read_to_string(&mut multipart_content).await.unwrap();
+
+        assert_eq!(3, multipart_content.matches(&format!("--{multipart_boundary}")).count());
+        assert!(multipart_content.contains("filename=\"b.txt\"\r\nContent-Length: 9\r\n\r\ncontent b\r\n"));
+        assert!(multipart_content.ends_with(&format!("--{multipart_boundary}--\r\n")));
+        assert!(multipart_completion.await.is_ok());
+    }
+
+    /// Scenario:
+    /// Downloads two requested files as a batch (multipart/mixed content), but the second file can't be fetched
+    /// Expectation:
+    /// The multipart content should end without closing boundary and the multipart completion should be dropped
+    #[tokio::test]
+    async fn when_download_files_as_multipart_fetch_fails_should_drop_multipart_completion() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_objects_by_keys_as_channel()
+            .returning(|_, _, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                s3_file_sender
+                    .try_send(S3Object { fetch_error: Some(CommonError::AWS_ACCESS_ERROR), ..create_s3_object("b.txt", b"") })
+                    .unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let batch_download_request = BatchDownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            s3_keys: vec![String::from("a.txt"), String::from("b.txt")],
+            ..Default::default()
+        };
+
+        let (multipart_boundary, mut multipart_content_reader, multipart_completion) =
+            download_service.download_files_as_multipart(batch_download_request).await.unwrap();
+        let mut multipart_content = String::new();
+        multipart_content_reader.read_to_string(&mut multipart_content).await.unwrap();
+
+        assert!(!multipart_content.ends_with(&format!("--{multipart_boundary}--\r\n")));
+        assert!(multipart_completion.await.is_err());
+    }
+
+    /// Scenario:
+    /// Downloads files by a manifest with a renamed file
+    /// Expectation:
+    /// A streamed zip file with the renamed file should be retrieved
+    #[tokio::test]
+    async fn when_download_files_with_manifest_should_stream_zip_with_renamed_files() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_keys_as_channel()
+            .returning(|_, _, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(2);
+                s3_file_sender.try_send(create_s3_object("1f0c-a.txt", b"content a")).unwrap();
+                s3_file_sender.try_send(create_s3_object("b.txt", b"content b")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            manifest: Some(vec![
+                ManifestEntry { s3_key: String::from("1f0c-a.txt"), archive_name: Some(String::from("docs/a.txt")) },
+                ManifestEntry { s3_key: String::from("b.txt"), archive_name: None },
+            ]),
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        assert_eq!(2, zip_archive.len());
+        assert!(zip_archive.by_name("docs/a.txt").is_ok());
+        assert!(zip_archive.by_name("b.txt").is_ok());
+    }
+
+    /// Scenario:
+    /// Previews an encrypted export by a manifest with a renamed file and a file greater than the maximum file size
+    /// Expectation:
+    /// Renamed entry names and the excluded files list entry should be retrieved (excluded file is not an entry)
+    #[tokio::test]
+    async fn when_preview_archive_with_manifest_should_retrieve_renamed_entry_names() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(vec![String::from("big.bin"), String::from("other.bin")]));
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            manifest: Some(vec![
+                ManifestEntry { s3_key: String::from("1f0c-a.txt"), archive_name: Some(String::from("docs/a.txt")) },
+                ManifestEntry { s3_key: String::from("b.txt"), archive_name: None },
+                ManifestEntry { s3_key: String::from("big.bin"), archive_name: None },
+            ]),
+            encryption_recipient: Some(Identity::generate().to_public().to_string()),
+            ..Default::default()
+        };
+
+        let archive_preview = download_service.preview_archive(download_request).await.unwrap();
+
+        assert_eq!("s3-export.zip.age", archive_preview.file_name);
+        assert_eq!(vec!["docs/a.txt", "b.txt", ".s3-export/excluded.txt"], archive_preview.entries);
+        assert_eq!(vec!["big.bin"], archive_preview.excluded_files);
+    }
+
+    /// Scenario:
+    /// Downloads files by a manifest with direct key lookup requested
+    /// Expectation:
+    /// S3 objects should be requested with direct key lookup fetch options
+    #[tokio::test]
+    async fn when_download_files_with_direct_key_lookup_should_fetch_keys_directly() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_keys_as_channel()
+            .withf(|_, _, _, s3_fetch_options| s3_fetch_options.direct_key_lookup)
+            .returning(|_, _, _, _| {
+                let (s3_file_sender, s3_file_receiver) = channel(1);
+                s3_file_sender.try_send(create_s3_object("a.txt", b"content a")).unwrap();
+                Ok(s3_file_receiver)
+            });
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            manifest: Some(vec![ManifestEntry { s3_key: String::from("a.txt"), archive_name: None }]),
+            direct_key_lookup: true,
+            ..Default::default()
+        };
+
+        let mut download_export = download_service.download_files(download_request).await.unwrap();
+        let mut zip_content = Vec::new();
+        download_export.content.read_to_end(&mut zip_content).await.unwrap();
+
+        let mut zip_archive = ZipArchive::new(Cursor::new(zip_content)).unwrap();
+        assert!(zip_archive.by_name("a.txt").is_ok());
+    }
+
+    /// Scenario:
+    /// Downloads files from a S3 path with more files than the configured maximum file quantity
+    /// Expectation:
+    /// A limit exceeded error with the configured limit and the actual file quantity should be retrieved
+    #[tokio::test]
+    async fn when_download_files_over_max_file_quantity_should_retrieve_limit_exceeded_error() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_excluded_object_key_list()
+            .returning(|_, _| Ok(Vec::new()));
+        aws_s3_service
+            .expect_get_s3_objects_by_path_as_channel()
+            .returning(|_, _, _| Err(CommonError::LIMIT_EXCEEDED { limit: 100, actual: 250 }));
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let download_result = download_service.download_files(download_request).await;
+
+        assert!(matches!(download_result, Err(CommonError::LIMIT_EXCEEDED { limit: 100, actual: 250 })));
+    }
+
+    /// Scenario:
+    /// Downloads files with oversize files requested but not enabled by server configuration
+    /// Expectation:
+    /// A no valid input error should be retrieved (S3 objects are not requested)
+    #[tokio::test]
+    async fn when_download_files_with_oversize_not_enabled_should_retrieve_no_valid_input_error() {
+        let download_service = DownloadService {
+            aws_s3_service: Arc::new(MockAwsSdkS3ServiceTrait::new()) as DynAwsSdkS3Service,
+            manifest_max_entries: 10,
+            allow_oversize_enabled: false,
+            archive_read_ahead_bytes: 1024,
+            archive_entry_max_bytes: 1048576,
+        };
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            allow_oversize: true,
+            ..Default::default()
+        };
+
+        let download_result = download_service.download_files(download_request).await;
+
+        assert!(matches!(download_result, Err(CommonError::NO_VALID_INPUT_OR_PARAMETER)));
+    }
+
+    /// Scenario:
+    /// Adds suffixes to a manifest with three S3 keys renamed with the same zip entry name
+    /// Expectation:
+    /// Unique zip entry names with numeric suffixes before the extension should be retrieved
+    #[test]
+    fn when_add_entry_name_suffixes_should_retrieve_unique_entry_names() {
+        let manifest = vec![
+            ManifestEntry { s3_key: String::from("1-report.pdf"), archive_name: Some(String::from("report.pdf")) },
+            ManifestEntry { s3_key: String::from("2-report.pdf"), archive_name: Some(String::from("report.pdf")) },
+            ManifestEntry { s3_key: String::from("report.pdf"), archive_name: None },
+        ];
+
+        let entry_names: Vec<String> = add_entry_name_suffixes(manifest)
+            .into_iter()
+            .map(|manifest_entry| manifest_entry.archive_name.unwrap())
+            .collect();
+
+        assert_eq!(vec!["report.pdf", "report (1).pdf", "report (2).pdf"], entry_names);
+    }
+
+    /// Scenario:
+    /// Validates manifests with safe zip entry names, parent folder references and entries under the reserved folder
+    /// Expectation:
+    /// Just manifests without unsafe or reserved zip entry names should be valid
+    #[test]
+    fn when_is_valid_manifest_should_reject_reserved_entry_names() {
+        let create_manifest = |s3_key: &str, archive_name: Option<&str>| {
+            vec![ManifestEntry { s3_key: String::from(s3_key), archive_name: archive_name.map(String::from) }]
+        };
+
+        assert!(is_valid_manifest(&create_manifest("a.txt", Some("docs/excluded.txt"))));
+        assert!(!is_valid_manifest(&create_manifest("a.txt", Some("../a.txt"))));
+        assert!(!is_valid_manifest(&create_manifest("a.txt", Some(".s3-export/excluded.txt"))));
+        assert!(!is_valid_manifest(&create_manifest(".s3-export/missing-files.txt", None)));
+    }
+
+    /// Creates a new [S3Object] by [&str] S3 key and [&[u8]] content
+    fn create_s3_object(s3_key: &str, content: &[u8]) -> S3Object {
+        S3Object {
+            s3_key: String::from(s3_key),
+            content: content.to_vec(),
+            content_stream: None,
+            fetch_latency_millis: content.len() as u64,
+            fetch_retries: 1,
+            memory_reservation: MEMORY_BUDGET.reserve(content.len() as u64),
+            fetch_error: None,
+        }
+    }
+}
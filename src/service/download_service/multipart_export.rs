@@ -0,0 +1,135 @@
+use std::time::Instant;
+
+use crate::config::download_quota::DOWNLOAD_QUOTA_REGISTRY;
+use crate::config::export_alerter::EXPORT_ALERTER;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::dto::s3_object::S3Object;
+use crate::enums::common_error::CommonError;
+use log::{debug, info, warn};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use tokio::io::{AsyncWriteExt, DuplexStream};
+use tokio::sync::mpsc::Receiver;
+use tokio::sync::oneshot;
+
+/// Writes a multipart/mixed content into [DuplexStream] multipart content writer with all files received
+/// by [Receiver<S3Object>] S3 file receiver (one part per file, delimited by [String] multipart boundary)
+/// and records the export in usage stats and download quotas by [String] S3 bucket and [Option<String>] caller
+/// Important: [oneshot::Sender<()>] multipart completion is sent just once the closing boundary is written - it is
+/// dropped on any fetch or write error (the response body ends with an error instead of a truncated content)
+pub(super) async fn write_multipart_content(
+    mut s3_file_receiver: Receiver<S3Object>,
+    mut multipart_content_writer: DuplexStream,
+    s3_bucket: String,
+    caller_id: Option<String>,
+    multipart_boundary: String,
+    multipart_completion_sender: oneshot::Sender<()>,
+) {
+    info!("write_multipart_content - start");
+    let export_started_at = Instant::now();
+    let mut bytes_total = 0;
+
+    while let Some(s3_file) = s3_file_receiver.recv().await {
+        match s3_file.fetch_error {
+            Some(CommonError::NO_DATA_FOUND) => {
+                debug!("write_multipart_content - missing file skipped: {}", s3_file.s3_key);
+                continue;
+            }
+            Some(fetch_error) => {
+                warn!("write_multipart_content - can't fetch file: {} - error: {fetch_error}", s3_file.s3_key);
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, bytes_total, true);
+                DOWNLOAD_QUOTA_REGISTRY.record_bytes(&s3_bucket, caller_id.as_deref(), bytes_total);
+                return;
+            }
+            None => {}
+        }
+        let part_headers = format!(
+            "--{multipart_boundary}\r\nContent-Type: application/octet-stream\r\nContent-Disposition: {}\r\nContent-Length: {}\r\n\r\n",
+            create_part_content_disposition(&s3_file.s3_key),
+            s3_file.content.len()
+        );
+        let part_result = async {
+            multipart_content_writer.write_all(part_headers.as_bytes()).await?;
+            multipart_content_writer.write_all(&s3_file.content).await?;
+            multipart_content_writer.write_all(b"\r\n").await
+        }
+        .await;
+
+        if let Err(write_error) = part_result {
+            warn!("write_multipart_content - can't add part (client disconnected?) - error: {write_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, bytes_total, true);
+            DOWNLOAD_QUOTA_REGISTRY.record_bytes(&s3_bucket, caller_id.as_deref(), bytes_total);
+            return;
+        }
+        bytes_total += s3_file.content.len() as u64;
+    }
+
+    let closing_boundary = format!("--{multipart_boundary}--\r\n");
+    if let Err(write_error) = multipart_content_writer.write_all(closing_boundary.as_bytes()).await {
+        warn!("write_multipart_content - can't close multipart content (client disconnected?) - error: {write_error}");
+        USAGE_STATS_REGISTRY.record_export(&s3_bucket, bytes_total, true);
+        DOWNLOAD_QUOTA_REGISTRY.record_bytes(&s3_bucket, caller_id.as_deref(), bytes_total);
+        return;
+    }
+
+    let _ = multipart_completion_sender.send(());
+
+    USAGE_STATS_REGISTRY.record_export(&s3_bucket, bytes_total, false);
+    DOWNLOAD_QUOTA_REGISTRY.record_bytes(&s3_bucket, caller_id.as_deref(), bytes_total);
+    EXPORT_ALERTER.check_export(&s3_bucket, export_started_at.elapsed().as_millis() as u64, bytes_total);
+    info!("write_multipart_content - done");
+}
+
+/// RFC 5987 "attr-char" encode set (all characters but alphanumerics and "!#$&+-.^_`|~" are percent-encoded)
+const RFC5987_ATTR_CHAR_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+/// Gets [String] multipart part content disposition by [&str] S3 key: S3 keys with control, non-ASCII, quote or
+/// backslash characters get an ASCII fallback file name ("_" replacements) and a RFC 5987 percent-encoded UTF-8 file
+/// name (no raw header-breaking characters are ever written into the part headers)
+fn create_part_content_disposition(s3_key: &str) -> String {
+    let is_plain_char = |key_char: char| key_char.is_ascii() && !key_char.is_ascii_control() && key_char != '"' && key_char != '\\';
+    if s3_key.chars().all(is_plain_char) {
+        return format!("attachment; filename=\"{s3_key}\"");
+    }
+
+    let fallback_file_name: String = s3_key.chars().map(|key_char| if is_plain_char(key_char) { key_char } else { '_' }).collect();
+    format!(
+        "attachment; filename=\"{fallback_file_name}\"; filename*=UTF-8''{}",
+        utf8_percent_encode(s3_key, RFC5987_ATTR_CHAR_ENCODE_SET)
+    )
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use crate::service::download_service::multipart_export::create_part_content_disposition;
+
+    /// Scenario:
+    /// Creates multipart part content dispositions for plain, quoted, control characters and non-ASCII S3 keys
+    /// Expectation:
+    /// Plain S3 keys should be sent as they are and the others as ASCII fallback and RFC 5987 encoded file names
+    #[test]
+    fn when_create_part_content_disposition_should_encode_unsafe_s3_keys() {
+        assert_eq!("attachment; filename=\"some/a.txt\"", create_part_content_disposition("some/a.txt"));
+        assert_eq!(
+            "attachment; filename=\"a_.txt\"; filename*=UTF-8''a%22.txt",
+            create_part_content_disposition("a\".txt")
+        );
+        assert_eq!(
+            "attachment; filename=\"a__X-Injected: 1.txt\"; filename*=UTF-8''a%0D%0AX-Injected%3A%201.txt",
+            create_part_content_disposition("a\r\nX-Injected: 1.txt")
+        );
+        assert_eq!("attachment; filename=\"_.txt\"; filename*=UTF-8''%C3%B1.txt", create_part_content_disposition("ñ.txt"));
+    }
+}
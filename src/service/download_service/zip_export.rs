@@ -0,0 +1,242 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{self, Seek, Write};
+use std::time::Instant;
+
+use age::Encryptor;
+use aws_sdk_s3::primitives::ByteStream;
+use crate::config::download_quota::DOWNLOAD_QUOTA_REGISTRY;
+use crate::config::export_alerter::EXPORT_ALERTER;
+use crate::config::usage_stats::USAGE_STATS_REGISTRY;
+use crate::constant::constants::{ENCRYPTED_EXPORT_FILE_EXTENSION, ZIP_DEDUP_MANIFEST_FILE_NAME, ZIP_EXCLUDED_FILES_FILE_NAME, ZIP_MISSING_FILES_FILE_NAME, ZIP_STREAM_BUFFER_SIZE};
+use crate::dto::download_export::DownloadExport;
+use crate::dto::export_report::{ExportReport, FileFetchReport};
+use crate::dto::s3_object::S3Object;
+use crate::enums::common_error::CommonError;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::service::download_service::create_export_report;
+use crate::service::download_service::export_encryption::create_encrypted_content;
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use tokio::io::duplex;
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::Receiver;
+use tokio::sync::oneshot;
+use tokio_util::io::SyncIoBridge;
+use zip::result::ZipError;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// Zip export options (zip writer settings by download request)
+pub(super) struct ZipExportOptions {
+    /// S3 bucket (to record the export in usage stats and download quotas)
+    pub(super) s3_bucket: String,
+    /// caller (to record the export in download quotas)
+    pub(super) caller_id: Option<String>,
+    /// stores files with identical content just once
+    pub(super) deduplicate: bool,
+    /// policy for S3 keys deleted after listing
+    pub(super) missing_key_policy: MissingKeyPolicy,
+    /// zip entry names by S3 key (S3 key is used as zip entry name if it is not found)
+    pub(super) entry_names: HashMap<String, String>,
+    /// S3 keys excluded by limits (files greater than the maximum file size)
+    pub(super) excluded_files: Vec<String>,
+}
+
+/// Creates a new [DownloadExport] streamed zip file by [Receiver<S3Object>] S3 file receiver, [ZipExportOptions] zip
+/// export options, [&str] zip file name and [Option<Encryptor>] encryptor (zip file is encrypted if it is defined)
+/// Important: zip file is written by a blocking task while files are received
+pub(super) fn create_zip_export(
+    s3_file_receiver: Receiver<S3Object>,
+    zip_export_options: ZipExportOptions,
+    file_name: &str,
+    encryptor: Option<Encryptor>,
+    export_started_at: Instant,
+) -> DownloadExport {
+    let (zip_content_reader, zip_content_writer) = duplex(ZIP_STREAM_BUFFER_SIZE);
+    let zip_content_writer = SyncIoBridge::new(zip_content_writer);
+    let (export_report_sender, mut export_report_receiver) = oneshot::channel();
+    let excluded_count = zip_export_options.excluded_files.len();
+    tokio::task::spawn_blocking(move || {
+        write_zip_file(
+            s3_file_receiver,
+            zip_content_writer,
+            zip_export_options,
+            export_started_at,
+            export_report_sender,
+        )
+    });
+
+    let mut file_name = String::from(file_name);
+    let mut content = zip_content_reader;
+    if let Some(encryptor) = encryptor {
+        file_name.push_str(ENCRYPTED_EXPORT_FILE_EXTENSION);
+        (content, export_report_receiver) = create_encrypted_content(content, export_report_receiver, encryptor);
+    }
+
+    DownloadExport {
+        file_name,
+        content,
+        report: export_report_receiver,
+        excluded_count,
+    }
+}
+
+/// Writes a zip file into [W] zip content writer with all files received by [Receiver<S3Object>] S3 file receiver
+/// by [ZipExportOptions] zip export options (zip entry names and deduplication)
+/// If deduplicate is enabled, files with an already added content (same SHA-256 checksum)
+/// are not added again and they are listed in a dedup manifest file (duplicate file -> added file)
+/// S3 keys deleted after listing are skipped and listed in a missing files zip entry (or the export is aborted)
+/// by the missing key policy and S3 keys excluded by limits are listed in an excluded files zip entry
+/// An [ExportReport] export report (measured from [Instant] export start) is sent into [oneshot::Sender<ExportReport>]
+/// export report sender once the zip file is completed and the export is recorded in usage stats
+/// Important:
+/// - this is a blocking function (archiver stage): it should be executed by a blocking thread
+/// - zip content writer should apply backpressure (i.e.: a bounded stream), so a slow client
+///   stops the archiver and the archiver stops S3 downloads (bounded S3 file channel)
+fn write_zip_file<W: Write>(
+    mut s3_file_receiver: Receiver<S3Object>,
+    zip_content_writer: W,
+    zip_export_options: ZipExportOptions,
+    export_started_at: Instant,
+    export_report_sender: oneshot::Sender<ExportReport>,
+) {
+    info!("write_zip_file - create zip file - start");
+    let mut zip_writer = ZipWriter::new_stream(zip_content_writer);
+    let mut s3_files_total = 0;
+    let mut file_fetch_reports = Vec::new();
+    let mut s3_file_checksums: HashMap<Vec<u8>, String> = HashMap::new();
+    let mut dedup_manifest = String::new();
+    let mut missing_files = Vec::new();
+    let ZipExportOptions { s3_bucket, caller_id, deduplicate, missing_key_policy, entry_names, excluded_files } = zip_export_options;
+
+    while let Some(s3_file) = s3_file_receiver.blocking_recv() {
+        match s3_file.fetch_error {
+            Some(CommonError::NO_DATA_FOUND) if missing_key_policy == MissingKeyPolicy::SKIP => {
+                debug!("write_zip_file - create zip file - missing file skipped: {}", s3_file.s3_key);
+                missing_files.push(s3_file.s3_key);
+                continue;
+            }
+            Some(fetch_error) => {
+                warn!("write_zip_file - create zip file - can't fetch file: {} - error: {fetch_error}", s3_file.s3_key);
+                USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                return;
+            }
+            None => {}
+        }
+        if let Some(content_stream) = s3_file.content_stream {
+            let entry_name = entry_names.get(&s3_file.s3_key).unwrap_or(&s3_file.s3_key);
+            match add_zip_file_stream(&mut zip_writer, entry_name, content_stream) {
+                Ok(size_bytes) => file_fetch_reports.push(FileFetchReport {
+                    s3_key: s3_file.s3_key,
+                    size_bytes,
+                    latency_millis: s3_file.fetch_latency_millis,
+                    retries: s3_file.fetch_retries,
+                }),
+                Err(zip_error) => {
+                    warn!("write_zip_file - create zip file - can't add streamed file (client disconnected?) - error: {zip_error}");
+                    USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+                    return;
+                }
+            }
+            s3_files_total += 1;
+            continue;
+        }
+        file_fetch_reports.push(FileFetchReport {
+            s3_key: s3_file.s3_key.clone(),
+            size_bytes: s3_file.content.len() as u64,
+            latency_millis: s3_file.fetch_latency_millis,
+            retries: s3_file.fetch_retries,
+        });
+
+        let entry_name = entry_names.get(&s3_file.s3_key).unwrap_or(&s3_file.s3_key);
+        if deduplicate {
+            match s3_file_checksums.entry(Sha256::digest(&s3_file.content).to_vec()) {
+                Entry::Occupied(added_s3_file) => {
+                    debug!("write_zip_file - create zip file - duplicated file skipped: {entry_name}");
+                    dedup_manifest.push_str(&format!("{entry_name} -> {}\n", added_s3_file.get()));
+                    continue;
+                }
+                Entry::Vacant(new_s3_file) => {
+                    new_s3_file.insert(entry_name.clone());
+                }
+            }
+        }
+
+        if let Err(zip_error) = add_zip_file(&mut zip_writer, entry_name, &s3_file.content) {
+            warn!("write_zip_file - create zip file - can't add file (client disconnected?) - error: {zip_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+            return;
+        }
+
+        s3_files_total += 1;
+    }
+
+    if !dedup_manifest.is_empty() {
+        info!("write_zip_file - create zip file - adding dedup manifest");
+        if let Err(zip_error) = add_zip_file(&mut zip_writer, ZIP_DEDUP_MANIFEST_FILE_NAME, dedup_manifest.as_bytes()) {
+            warn!("write_zip_file - create zip file - can't add dedup manifest (client disconnected?) - error: {zip_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+            return;
+        }
+    }
+
+    if !missing_files.is_empty() {
+        info!("write_zip_file - create zip file - adding missing files list - missing files: {}", missing_files.len());
+        let missing_files_list = missing_files.iter().map(|s3_key| format!("{s3_key}\n")).collect::<String>();
+        if let Err(zip_error) = add_zip_file(&mut zip_writer, ZIP_MISSING_FILES_FILE_NAME, missing_files_list.as_bytes()) {
+            warn!("write_zip_file - create zip file - can't add missing files list (client disconnected?) - error: {zip_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+            return;
+        }
+    }
+
+    if !excluded_files.is_empty() {
+        info!("write_zip_file - create zip file - adding excluded files list - excluded files: {}", excluded_files.len());
+        let excluded_files_list = excluded_files.iter().map(|s3_key| format!("{s3_key}\n")).collect::<String>();
+        if let Err(zip_error) = add_zip_file(&mut zip_writer, ZIP_EXCLUDED_FILES_FILE_NAME, excluded_files_list.as_bytes()) {
+            warn!("write_zip_file - create zip file - can't add excluded files list (client disconnected?) - error: {zip_error}");
+            USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+            return;
+        }
+    }
+
+    if let Err(zip_error) = zip_writer.finish().and_then(|mut zip_stream_writer| Ok(zip_stream_writer.flush()?)) {
+        warn!("write_zip_file - create zip file - can't finish zip file (client disconnected?) - error: {zip_error}");
+        USAGE_STATS_REGISTRY.record_export(&s3_bucket, 0, true);
+        return;
+    }
+
+    let export_report = create_export_report(export_started_at, file_fetch_reports, missing_files, excluded_files);
+    info!("write_zip_file - create zip file - s3 files total: {s3_files_total}");
+    info!("write_zip_file - create zip file - elapsed millis: {}", export_report.elapsed_millis);
+    USAGE_STATS_REGISTRY.record_export(&s3_bucket, export_report.bytes_total, false);
+    DOWNLOAD_QUOTA_REGISTRY.record_bytes(&s3_bucket, caller_id.as_deref(), export_report.bytes_total);
+    EXPORT_ALERTER.check_export(&s3_bucket, export_report.elapsed_millis, export_report.bytes_total);
+    let _ = export_report_sender.send(export_report);
+    info!("write_zip_file - create zip file - done");
+}
+
+/// Adds a new file into [ZipWriter] zip writer by [&str] file name and [&[u8]] file content
+/// Returns a [ZipError] if file can't be added
+fn add_zip_file<W: Write + Seek>(zip_writer: &mut ZipWriter<W>, file_name: &str, file_content: &[u8]) -> Result<(), ZipError> {
+    zip_writer.start_file(file_name, SimpleFileOptions::default())?;
+    zip_writer.write_all(file_content)?;
+    Ok(())
+}
+
+/// Adds a new file into [ZipWriter] zip writer by [&str] file name and [ByteStream] file content stream (each chunk is
+/// copied into the zip entry as it is read from S3 - the whole file is not buffered)
+/// Important: this is a blocking function (content stream chunks are awaited by the current runtime)
+/// Returns [u64] file size or a [ZipError] if file can't be added or its content stream can't be read
+fn add_zip_file_stream<W: Write + Seek>(zip_writer: &mut ZipWriter<W>, file_name: &str, mut file_content_stream: ByteStream) -> Result<u64, ZipError> {
+    let runtime_handle = Handle::current();
+    zip_writer.start_file(file_name, SimpleFileOptions::default())?;
+    let mut file_size = 0;
+    while let Some(content_chunk) = runtime_handle.block_on(file_content_stream.next()) {
+        let content_chunk = content_chunk.map_err(io::Error::other)?;
+        zip_writer.write_all(&content_chunk)?;
+        file_size += content_chunk.len() as u64;
+    }
+    Ok(file_size)
+}
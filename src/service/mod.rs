@@ -1,2 +1,8 @@
 pub mod aws_sdk_s3_service;
+pub mod cost_estimate_service;
+pub mod download_job_service;
 pub mod download_service;
+pub mod s3_event_service;
+pub mod search_service;
+pub mod signed_link_service;
+pub mod watch_service;
@@ -0,0 +1,490 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use axum::http::header::CONTENT_TYPE;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::external_secrets::get_secret_env_var;
+use crate::config::replay_cache::REPLAY_CACHE;
+use crate::constant::constants::{S3_EVENT_DESTINATION_PATH_DEFAULT, S3_EVENT_NONCE_SCOPE, S3_EVENT_OBJECT_CREATED_PREFIX, S3_EVENT_RULES_FILE_ENV_VAR, S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_DEFAULT, S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_ENV_VAR, S3_EVENT_SIGNING_SECRET_ENV_VAR};
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::dto::s3_event_notification::{S3EventNotification, S3EventRecord};
+use crate::dto::s3_event_response::S3EventResponse;
+use crate::dto::s3_event_rule::S3EventRule;
+use crate::dto::s3_event_webhook::S3EventWebhook;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use crate::enums::s3_event_action::S3EventAction;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use crate::service::download_service::{DownloadService, DynDownloadService};
+use hmac::{Hmac, Mac};
+use log::{debug, error, info, warn};
+use percent_encoding::percent_decode_str;
+use sha2::Sha256;
+use tokio::io::AsyncReadExt;
+use tokio_util::sync::CancellationToken;
+
+/// S3 event service (S3 object created events processing by configured rules)
+#[async_trait]
+pub trait S3EventServiceTrait {
+    /// Processes [S3EventNotification] S3 event notification: configured actions (archive, copy or webhook)
+    /// are triggered for S3 objects created under matching S3 prefixes
+    /// Returns [S3EventResponse] received events, triggered actions and failed actions totals
+    async fn process_s3_events(&self, s3_event_notification: S3EventNotification) -> S3EventResponse;
+
+    /// Gets [bool] true if [&[u8]] S3 event notification body is verified by [Option<&str>] signature timestamp
+    /// (epoch seconds), [Option<&str>] signature nonce and [Option<&str>] signature (always false if signing secret
+    /// is not configured - notifications can't be verified, so they are rejected)
+    /// Important: timestamps out of the tolerance window and already used nonces (replayed notifications) are rejected
+    async fn verify_s3_events_signature(
+        &self,
+        signature_timestamp: Option<&str>,
+        signature_nonce: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> bool;
+
+    /// Gets [Vec<(String, String)>] destination S3 buckets and paths of configured rules (archive and copy actions)
+    fn get_destination_prefixes(&self) -> Vec<(String, String)>;
+}
+
+/// S3 event service implementation struct
+pub struct S3EventService {
+    aws_s3_service: DynAwsSdkS3Service,
+    download_service: DynDownloadService,
+    http_client: reqwest::Client,
+    s3_event_rules: Vec<S3EventRule>,
+    signing_secret: Option<Vec<u8>>,
+    signature_tolerance_seconds: u64,
+}
+
+/// initialization by shared dependencies
+/// Important: no action is triggered if rules file is not configured or it is not valid (rules for any S3 bucket are
+/// skipped - all actions have side effects, so they should be scoped by S3 bucket)
+impl S3EventService {
+    /// Creates a new [S3EventService] by [DynAwsSdkS3Service] AWS S3 service and [DynDownloadService] download service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service, download_service: DynDownloadService) -> Self {
+        S3EventService {
+            aws_s3_service,
+            download_service,
+            http_client: reqwest::Client::new(),
+            s3_event_rules: std::env::var(S3_EVENT_RULES_FILE_ENV_VAR)
+                .map(|s3_event_rules_file| read_s3_event_rules(&s3_event_rules_file))
+                .unwrap_or_default(),
+            signing_secret: get_secret_env_var(S3_EVENT_SIGNING_SECRET_ENV_VAR)
+                .ok()
+                .filter(|signing_secret| !signing_secret.is_empty())
+                .map(String::into_bytes),
+            signature_tolerance_seconds: get_env_var_as_u64(
+                S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_ENV_VAR,
+                S3_EVENT_SIGNATURE_TOLERANCE_SECONDS_DEFAULT,
+            ),
+        }
+    }
+}
+
+/// default initialization
+impl Default for S3EventService {
+    fn default() -> Self {
+        S3EventService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service, Arc::new(DownloadService::default()) as DynDownloadService)
+    }
+}
+
+/// S3 event service implementation logic
+#[async_trait]
+impl S3EventServiceTrait for S3EventService {
+    /// Processes [S3EventNotification] S3 event notification: configured actions (archive, copy or webhook)
+    /// are triggered for S3 objects created under matching S3 prefixes
+    /// Returns [S3EventResponse] received events, triggered actions and failed actions totals
+    async fn process_s3_events(&self, s3_event_notification: S3EventNotification) -> S3EventResponse {
+        info!("process_s3_events - start - records: {}", s3_event_notification.records.len());
+        let mut s3_event_response = S3EventResponse { events_received: 0, actions_triggered: 0, actions_failed: 0 };
+
+        for s3_event_record in s3_event_notification
+            .records
+            .iter()
+            .filter(|s3_event_record| s3_event_record.event_name.starts_with(S3_EVENT_OBJECT_CREATED_PREFIX))
+        {
+            s3_event_response.events_received += 1;
+            let s3_bucket = &s3_event_record.s3.bucket.name;
+            let s3_key = decode_s3_event_key(&s3_event_record.s3.object.key);
+            for s3_event_rule in self.s3_event_rules.iter().filter(|s3_event_rule| {
+                &s3_event_rule.bucket_name == s3_bucket && s3_key.starts_with(&s3_event_rule.prefix)
+            }) {
+                s3_event_response.actions_triggered += 1;
+                if let Err(action_error) = self.run_action(s3_event_rule, s3_event_record, s3_bucket, &s3_key).await {
+                    error!(
+                        "process_s3_events - action error - action: {:?} - s3 bucket: {s3_bucket} - s3 key: {s3_key} - error: {action_error}",
+                        s3_event_rule.action
+                    );
+                    s3_event_response.actions_failed += 1;
+                }
+            }
+        }
+
+        info!("process_s3_events - done - triggered actions: {}", s3_event_response.actions_triggered);
+        s3_event_response
+    }
+
+    /// Gets [bool] true if [&[u8]] S3 event notification body is verified by [Option<&str>] signature timestamp
+    /// (epoch seconds), [Option<&str>] signature nonce and [Option<&str>] signature (always false if signing secret
+    /// is not configured - notifications can't be verified, so they are rejected)
+    /// Important: timestamps out of the tolerance window and already used nonces (replayed notifications) are rejected
+    async fn verify_s3_events_signature(
+        &self,
+        signature_timestamp: Option<&str>,
+        signature_nonce: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> bool {
+        let Some(signing_secret) = &self.signing_secret else {
+            warn!("verify_s3_events_signature - signing secret not configured - notification rejected");
+            return false;
+        };
+        let (Some(signature_timestamp), Some(signature_nonce), Some(signature)) =
+            (signature_timestamp.and_then(|signature_timestamp| signature_timestamp.parse::<u64>().ok()), signature_nonce, signature)
+        else {
+            warn!("verify_s3_events_signature - signature headers not defined (or not valid timestamp)");
+            return false;
+        };
+        if signature_timestamp.abs_diff(current_epoch_seconds()) > self.signature_tolerance_seconds {
+            warn!("verify_s3_events_signature - signature timestamp out of tolerance window - timestamp: {signature_timestamp}");
+            return false;
+        }
+        if !is_valid_s3_events_signature(signing_secret, signature_timestamp, signature_nonce, body, signature) {
+            warn!("verify_s3_events_signature - not valid signature");
+            return false;
+        }
+
+        let nonce_expires_at = signature_timestamp.saturating_add(self.signature_tolerance_seconds);
+        if !REPLAY_CACHE.register_nonce(S3_EVENT_NONCE_SCOPE, signature_nonce, nonce_expires_at).await {
+            warn!("verify_s3_events_signature - replayed notification - nonce: {signature_nonce}");
+            return false;
+        }
+        true
+    }
+
+    /// Gets [Vec<(String, String)>] destination S3 buckets and paths of configured rules (archive and copy actions)
+    fn get_destination_prefixes(&self) -> Vec<(String, String)> {
+        self.s3_event_rules
+            .iter()
+            .filter(|s3_event_rule| s3_event_rule.action != S3EventAction::WEBHOOK)
+            .filter_map(|s3_event_rule| {
+                s3_event_rule.destination_bucket.clone().map(|destination_bucket| {
+                    let destination_path = s3_event_rule
+                        .destination_path
+                        .clone()
+                        .unwrap_or(String::from(S3_EVENT_DESTINATION_PATH_DEFAULT));
+                    (destination_bucket, destination_path)
+                })
+            })
+            .collect()
+    }
+}
+
+/// S3 event service internal logic
+impl S3EventService {
+    /// Runs [&S3EventRule] rule action for [&S3EventRecord] S3 event record ([&str] S3 bucket and [&str] decoded S3 key)
+    /// Returns a [CommonError] if the action can't be completed
+    async fn run_action(
+        &self,
+        s3_event_rule: &S3EventRule,
+        s3_event_record: &S3EventRecord,
+        s3_bucket: &str,
+        s3_key: &str,
+    ) -> Result<(), CommonError> {
+        debug!("run_action - action: {:?} - s3 bucket: {s3_bucket} - s3 key: {s3_key}", s3_event_rule.action);
+        if s3_event_rule.action == S3EventAction::WEBHOOK {
+            let webhook_url = s3_event_rule.webhook_url.as_ref().ok_or(CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+            let s3_event_webhook = S3EventWebhook {
+                event_name: s3_event_record.event_name.clone(),
+                bucket_name: String::from(s3_bucket),
+                s3_key: String::from(s3_key),
+                size_bytes: s3_event_record.s3.object.size,
+            };
+            return match self
+                .http_client
+                .post(webhook_url)
+                .header(CONTENT_TYPE, "application/json")
+                .body(serde_json::to_string(&s3_event_webhook).unwrap_or_default())
+                .send()
+                .await
+                .and_then(|webhook_response| webhook_response.error_for_status())
+            {
+                Ok(_) => Ok(()),
+                Err(webhook_error) => {
+                    warn!("run_action - webhook error - url: {webhook_url} - error: {webhook_error}");
+                    Err(CommonError::NO_DATA_FOUND)
+                }
+            };
+        }
+
+        let destination_bucket = s3_event_rule.destination_bucket.clone().ok_or(CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+        let destination_path = s3_event_rule
+            .destination_path
+            .clone()
+            .unwrap_or(String::from(S3_EVENT_DESTINATION_PATH_DEFAULT));
+        // S3 objects are addressed by S3 path + file name (S3 objects in S3 bucket root are not supported)
+        let (s3_path, s3_file_name) = s3_key.rsplit_once('/').ok_or(CommonError::NO_VALID_INPUT_OR_PARAMETER)?;
+
+        let (destination_s3_key, destination_content) = match s3_event_rule.action {
+            S3EventAction::ARCHIVE => {
+                let download_request = DownloadRequest {
+                    bucket_name: String::from(s3_bucket),
+                    full_path: String::from(s3_path),
+                    deduplicate: false,
+                    max_parallel_fetches: None,
+                    multipart_chunk_bytes: None,
+                    manifest: Some(vec![ManifestEntry { s3_key: String::from(s3_file_name), archive_name: None }]),
+                    filter: None,
+                    duplicate_entry_policy: DuplicateEntryPolicy::default(),
+                    missing_key_policy: MissingKeyPolicy::default(),
+                    direct_key_lookup: false,
+                    allow_oversize: false,
+                    encryption_recipient: None,
+                    notify_email: None,
+                    volumes: None,
+                    split_by_folder: false,
+                    priority: DownloadJobPriority::default(),
+                    caller_id: None,
+                    restore: None,
+                    metadata_report: None,
+                    checksum_audit: false,
+                    public_base_url: None,
+                    cancellation_token: CancellationToken::default(),
+                };
+                let mut download_export = self.download_service.download_files(download_request).await?;
+                let mut zip_content = Vec::new();
+                download_export
+                    .content
+                    .read_to_end(&mut zip_content)
+                    .await
+                    .map_err(|_| CommonError::AWS_ACCESS_ERROR)?;
+                download_export.report.await.map_err(|_| CommonError::AWS_ACCESS_ERROR)?;
+                (format!("{s3_bucket}/{s3_key}.zip"), zip_content)
+            }
+            _ => {
+                let (_, s3_object_content) = self
+                    .aws_s3_service
+                    .get_s3_object(String::from(s3_bucket), String::from(s3_path), String::from(s3_file_name))
+                    .await?;
+                (format!("{s3_bucket}/{s3_key}"), s3_object_content)
+            }
+        };
+
+        self.aws_s3_service
+            .add_s3_object(destination_bucket, destination_path, destination_s3_key, Bytes::from(destination_content))
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Gets [bool] true if [&str] signature (hex encoded HMAC-SHA256 of "{timestamp}.{nonce}.{body}") is valid
+/// by [&[u8]] signing secret, [u64] signature timestamp, [&str] signature nonce and [&[u8]] S3 event notification body
+fn is_valid_s3_events_signature(signing_secret: &[u8], signature_timestamp: u64, signature_nonce: &str, body: &[u8], signature: &str) -> bool {
+    let Some(signature) = decode_hex(signature) else {
+        return false;
+    };
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret).expect("HMAC accepts keys of any size");
+    mac.update(format!("{signature_timestamp}.{signature_nonce}.").as_bytes());
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Gets [Option<Vec<u8>>] bytes by [&str] hex encoded value (none if it is not valid)
+fn decode_hex(hex_value: &str) -> Option<Vec<u8>> {
+    if !hex_value.is_ascii() || !hex_value.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex_value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex_value[index..index + 2], 16).ok())
+        .collect()
+}
+
+/// Gets [String] decoded S3 key by [&str] S3 event key (S3 event keys are URL encoded - spaces as "+")
+fn decode_s3_event_key(s3_event_key: &str) -> String {
+    percent_decode_str(&s3_event_key.replace('+', " ")).decode_utf8_lossy().to_string()
+}
+
+/// Reads [Vec<S3EventRule>] S3 event rules from [&str] JSON rules file
+/// Important: if rules file is not valid, no rule is defined (no action is triggered)
+fn read_s3_event_rules(s3_event_rules_file: &str) -> Vec<S3EventRule> {
+    match std::fs::read_to_string(s3_event_rules_file)
+        .map_err(|read_error| read_error.to_string())
+        .and_then(|s3_event_rules| serde_json::from_str(&s3_event_rules).map_err(|parse_error| parse_error.to_string()))
+    {
+        Ok(s3_event_rules) => remove_wildcard_bucket_rules(s3_event_rules),
+        Err(s3_event_rules_error) => {
+            error!("read_s3_event_rules - rules file not valid - no action is triggered - error: {s3_event_rules_error}");
+            Vec::new()
+        }
+    }
+}
+
+/// Removes all rules for any S3 bucket (`*` S3 bucket name) from [Vec<S3EventRule>] S3 event rules
+/// Important: archive, copy and webhook actions have side effects, so a rule for any S3 bucket would let notifications
+/// trigger them for arbitrary S3 buckets
+fn remove_wildcard_bucket_rules(s3_event_rules: Vec<S3EventRule>) -> Vec<S3EventRule> {
+    s3_event_rules
+        .into_iter()
+        .filter(|s3_event_rule| {
+            let is_wildcard_bucket = s3_event_rule.bucket_name.trim() == "*";
+            if is_wildcard_bucket {
+                error!(
+                    "remove_wildcard_bucket_rules - rule for any S3 bucket skipped - action: {:?} - prefix: {}",
+                    s3_event_rule.action, s3_event_rule.prefix
+                );
+            }
+            !is_wildcard_bucket
+        })
+        .collect()
+}
+
+/// S3 event service trait dyn type
+pub type DynS3EventService = Arc<dyn S3EventServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dto::s3_event_notification::{S3EventBucket, S3EventEntity, S3EventNotification, S3EventObject, S3EventRecord};
+    use crate::dto::s3_event_rule::S3EventRule;
+    use crate::enums::s3_event_action::S3EventAction;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use crate::service::download_service::{DynDownloadService, MockDownloadServiceTrait};
+    use crate::config::clock::current_epoch_seconds;
+    use crate::service::download_service::encode_archive_digest;
+    use crate::service::s3_event_service::{remove_wildcard_bucket_rules, S3EventService, S3EventServiceTrait};
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    /// Scenario:
+    /// Processes two S3 object created events (just one under the copy rule prefix) and a S3 object removed event
+    /// Expectation:
+    /// Just the matching S3 object should be copied into the destination S3 bucket (with decoded S3 key)
+    #[tokio::test]
+    async fn when_process_s3_events_should_trigger_matching_rule_actions() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service
+            .expect_get_s3_object()
+            .withf(|bucket_name, path, s3_key| bucket_name == "some-bucket" && path == "incoming" && s3_key == "new report.pdf")
+            .times(1)
+            .returning(|_, _, s3_key| Ok((s3_key, b"content".to_vec())));
+        aws_s3_service
+            .expect_add_s3_object()
+            .withf(|bucket_name, path, s3_key, _| {
+                bucket_name == "copy-bucket" && path == "copies" && s3_key == "some-bucket/incoming/new report.pdf"
+            })
+            .times(1)
+            .returning(|_, _, s3_key, _| Ok(s3_key));
+        let s3_event_service = S3EventService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            download_service: Arc::new(MockDownloadServiceTrait::new()) as DynDownloadService,
+            http_client: reqwest::Client::new(),
+            signing_secret: None,
+            signature_tolerance_seconds: 300,
+            s3_event_rules: vec![S3EventRule {
+                bucket_name: String::from("some-bucket"),
+                prefix: String::from("incoming/"),
+                action: S3EventAction::COPY,
+                destination_bucket: Some(String::from("copy-bucket")),
+                destination_path: Some(String::from("copies")),
+                webhook_url: None,
+            }],
+        };
+        let s3_event_notification = S3EventNotification {
+            records: vec![
+                create_s3_event_record("ObjectCreated:Put", "incoming/new+report.pdf"),
+                create_s3_event_record("ObjectCreated:Put", "other/file.txt"),
+                create_s3_event_record("ObjectRemoved:Delete", "incoming/old.pdf"),
+            ],
+        };
+
+        let s3_event_response = s3_event_service.process_s3_events(s3_event_notification).await;
+
+        assert_eq!(2, s3_event_response.events_received);
+        assert_eq!(1, s3_event_response.actions_triggered);
+        assert_eq!(0, s3_event_response.actions_failed);
+    }
+
+    /// Scenario:
+    /// Verifies S3 event notifications signatures (valid, tampered body, not valid hex and replayed nonce)
+    /// Expectation:
+    /// Just the valid and not replayed notification should be verified
+    #[tokio::test]
+    async fn when_verify_s3_events_signature_should_reject_tampered_and_replayed_notifications() {
+        let s3_event_service = S3EventService {
+            aws_s3_service: Arc::new(MockAwsSdkS3ServiceTrait::new()) as DynAwsSdkS3Service,
+            download_service: Arc::new(MockDownloadServiceTrait::new()) as DynDownloadService,
+            http_client: reqwest::Client::new(),
+            s3_event_rules: Vec::new(),
+            signing_secret: Some(b"some-secret".to_vec()),
+            signature_tolerance_seconds: 300,
+        };
+        let signature_timestamp = current_epoch_seconds().to_string();
+        let body = br#"{"Records":[]}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"some-secret").unwrap();
+        mac.update(format!("{signature_timestamp}.some-nonce.").as_bytes());
+        mac.update(body);
+        let signature = encode_archive_digest(&mac.finalize().into_bytes());
+
+        let verify = |body: &'static [u8], signature: String| {
+            let signature_timestamp = signature_timestamp.clone();
+            let s3_event_service = &s3_event_service;
+            async move {
+                s3_event_service
+                    .verify_s3_events_signature(Some(&signature_timestamp), Some("some-nonce"), Some(&signature), body)
+                    .await
+            }
+        };
+        assert!(!verify(br#"{"Records":[{}]}"#, signature.clone()).await);
+        assert!(!verify(body, String::from("not-hex")).await);
+        assert!(verify(body, signature.clone()).await);
+        assert!(!verify(body, signature.clone()).await);
+
+        let s3_event_service = S3EventService { signing_secret: None, ..s3_event_service };
+        assert!(
+            !s3_event_service
+                .verify_s3_events_signature(Some(&signature_timestamp), Some("other-nonce"), Some(&signature), body)
+                .await
+        );
+    }
+
+    /// Scenario:
+    /// Reads S3 event rules for a S3 bucket and for any S3 bucket (`*` S3 bucket name)
+    /// Expectation:
+    /// Rules for any S3 bucket should be skipped (their actions have side effects)
+    #[test]
+    fn when_remove_wildcard_bucket_rules_should_skip_any_bucket_rules() {
+        let s3_event_rule_fn = |bucket_name: &str| S3EventRule {
+            bucket_name: String::from(bucket_name),
+            prefix: String::from("incoming/"),
+            action: S3EventAction::WEBHOOK,
+            destination_bucket: None,
+            destination_path: None,
+            webhook_url: Some(String::from("https://hooks.example.com")),
+        };
+
+        let s3_event_rules = remove_wildcard_bucket_rules(vec![s3_event_rule_fn("some-bucket"), s3_event_rule_fn("*"), s3_event_rule_fn(" * ")]);
+
+        assert_eq!(1, s3_event_rules.len());
+        assert_eq!("some-bucket", s3_event_rules[0].bucket_name);
+    }
+
+    /// Creates a new [S3EventRecord] by [&str] S3 event name and [&str] S3 event key
+    fn create_s3_event_record(event_name: &str, s3_event_key: &str) -> S3EventRecord {
+        S3EventRecord {
+            event_name: String::from(event_name),
+            s3: S3EventEntity {
+                bucket: S3EventBucket { name: String::from("some-bucket") },
+                object: S3EventObject { key: String::from(s3_event_key), size: Some(7) },
+            },
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use crate::config::env_vars::{get_env_var_as_u64, get_env_var_as_usize};
+use crate::constant::constants::{SEARCH_MAX_MATCHES_DEFAULT, SEARCH_MAX_OBJECTS_DEFAULT, SEARCH_MAX_OBJECTS_ENV_VAR, SEARCH_MAX_OBJECT_BYTES_DEFAULT, SEARCH_MAX_OBJECT_BYTES_ENV_VAR, SEARCH_MAX_PARALLEL_FETCHES_DEFAULT, SEARCH_MAX_PARALLEL_FETCHES_ENV_VAR, SEARCH_SNIPPET_MAX_CHARS};
+use crate::dto::search_match::SearchMatch;
+use crate::dto::search_request::SearchRequest;
+use crate::dto::search_response::SearchResponse;
+use crate::dto::search_result::SearchResult;
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use futures_util::StreamExt;
+use log::{debug, warn};
+use regex::{Regex, RegexBuilder};
+
+/// Compiled search regular expressions maximum size (in bytes)
+const SEARCH_REGEX_SIZE_LIMIT: usize = 1_048_576;
+
+/// Search service (full-text search across small text S3 objects under a S3 folder)
+#[async_trait]
+pub trait SearchServiceTrait {
+    /// Gets [SearchResponse] S3 objects with matching lines by [SearchRequest] search request (S3 objects are fetched
+    /// in parallel - too large, archived and binary S3 objects are skipped)
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if query is not a valid regular expression,
+    /// a [CommonError::LIMIT_EXCEEDED] if S3 objects exceed the configured maximum or a [CommonError] if S3 throws any error
+    async fn search_objects(&self, search_request: SearchRequest) -> Result<SearchResponse, CommonError>;
+}
+
+/// Search service implementation struct
+pub struct SearchService {
+    aws_s3_service: DynAwsSdkS3Service,
+    max_objects: usize,
+    max_object_bytes: u64,
+    max_parallel_fetches: usize,
+}
+
+/// initialization by shared dependencies (search limits are configured by environment variables - please, check constants.rs)
+impl SearchService {
+    /// Creates a new [SearchService] by [DynAwsSdkS3Service] AWS S3 service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service) -> Self {
+        SearchService {
+            aws_s3_service,
+            max_objects: get_env_var_as_usize(SEARCH_MAX_OBJECTS_ENV_VAR, SEARCH_MAX_OBJECTS_DEFAULT).max(1),
+            max_object_bytes: get_env_var_as_u64(SEARCH_MAX_OBJECT_BYTES_ENV_VAR, SEARCH_MAX_OBJECT_BYTES_DEFAULT).max(1),
+            max_parallel_fetches: get_env_var_as_usize(SEARCH_MAX_PARALLEL_FETCHES_ENV_VAR, SEARCH_MAX_PARALLEL_FETCHES_DEFAULT).max(1),
+        }
+    }
+}
+
+/// default initialization
+impl Default for SearchService {
+    fn default() -> Self {
+        SearchService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service)
+    }
+}
+
+/// Search service implementation logic
+#[async_trait]
+impl SearchServiceTrait for SearchService {
+    /// Gets [SearchResponse] S3 objects with matching lines by [SearchRequest] search request (S3 objects are fetched
+    /// in parallel - too large, archived and binary S3 objects are skipped)
+    /// Important: all nested folders are searched and search stops once the maximum matching lines are found
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if query is not a valid regular expression,
+    /// a [CommonError::LIMIT_EXCEEDED] if S3 objects exceed the configured maximum or a [CommonError] if S3 throws any error
+    async fn search_objects(&self, search_request: SearchRequest) -> Result<SearchResponse, CommonError> {
+        debug!("search_objects - start");
+        let search_regex = create_search_regex(&search_request)?;
+        let max_matches = search_request.max_matches.unwrap_or(SEARCH_MAX_MATCHES_DEFAULT);
+
+        let mut s3_keys = Vec::new();
+        let mut skipped_total = 0;
+        let mut continuation_token = None;
+        loop {
+            let (s3_object_metadata_page, next_continuation_token) = self
+                .aws_s3_service
+                .get_s3_object_metadata_page(
+                    search_request.bucket_name.clone(),
+                    search_request.full_path.clone(),
+                    None,
+                    continuation_token,
+                )
+                .await?;
+            for s3_object_metadata in s3_object_metadata_page {
+                if s3_object_metadata.s3_key.ends_with('/') {
+                    continue;
+                }
+                match s3_object_metadata.restore_required || s3_object_metadata.size_bytes.max(0) as u64 > self.max_object_bytes {
+                    true => skipped_total += 1,
+                    false => s3_keys.push(s3_object_metadata.s3_key),
+                }
+            }
+            if s3_keys.len() + skipped_total > self.max_objects {
+                warn!("search_objects - too many s3 objects - path: {}", search_request.full_path);
+                return Err(CommonError::LIMIT_EXCEEDED { limit: self.max_objects, actual: s3_keys.len() + skipped_total });
+            }
+
+            continuation_token = next_continuation_token;
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        let range = format!("bytes=0-{}", self.max_object_bytes - 1);
+        let mut s3_object_contents = futures_util::stream::iter(s3_keys)
+            .map(|s3_key| {
+                let s3_object_range = self.aws_s3_service.get_s3_object_range(
+                    search_request.bucket_name.clone(),
+                    search_request.full_path.clone(),
+                    s3_key.clone(),
+                    range.clone(),
+                );
+                async move { (s3_key, s3_object_range.await) }
+            })
+            .buffered(self.max_parallel_fetches);
+
+        let mut search_response = SearchResponse { results: Vec::new(), scanned_total: 0, skipped_total, truncated: false };
+        let mut matches_total = 0;
+        while let Some((s3_key, s3_object_range)) = s3_object_contents.next().await {
+            let s3_object_content = match s3_object_range {
+                Ok((s3_object_content, _)) => s3_object_content,
+                Err(CommonError::NO_DATA_FOUND) => {
+                    debug!("search_objects - s3 object deleted while searching - s3 key: {s3_key}");
+                    search_response.skipped_total += 1;
+                    continue;
+                }
+                Err(search_error) => return Err(search_error),
+            };
+            if s3_object_content.contains(&0) {
+                search_response.skipped_total += 1;
+                continue;
+            }
+
+            search_response.scanned_total += 1;
+            let matches = find_search_matches(&s3_object_content, &search_regex, max_matches - matches_total);
+            matches_total += matches.len();
+            if !matches.is_empty() {
+                search_response.results.push(SearchResult { s3_key, matches });
+            }
+            if matches_total >= max_matches {
+                search_response.truncated = true;
+                break;
+            }
+        }
+
+        debug!("search_objects - done - scanned: {} - matches: {matches_total}", search_response.scanned_total);
+        Ok(search_response)
+    }
+}
+
+/// Creates a new [Regex] search regular expression by [&SearchRequest] search request (plain text queries are escaped)
+/// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if query is not a valid regular expression
+fn create_search_regex(search_request: &SearchRequest) -> Result<Regex, CommonError> {
+    let pattern = match search_request.regex {
+        true => search_request.query.clone(),
+        false => regex::escape(&search_request.query),
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(search_request.case_insensitive)
+        .size_limit(SEARCH_REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|regex_error| {
+            debug!("create_search_regex - query is not a valid regular expression - error: {regex_error}");
+            CommonError::NO_VALID_INPUT_OR_PARAMETER
+        })
+}
+
+/// Gets [Vec<SearchMatch>] matching lines by [&[u8]] text content, [&Regex] search regular expression and [usize] maximum
+/// matching lines (not valid UTF-8 sequences are replaced and long lines are truncated)
+fn find_search_matches(text_content: &[u8], search_regex: &Regex, max_matches: usize) -> Vec<SearchMatch> {
+    String::from_utf8_lossy(text_content)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| search_regex.is_match(line))
+        .take(max_matches)
+        .map(|(line_index, line)| SearchMatch {
+            line_number: line_index + 1,
+            snippet: line.trim().chars().take(SEARCH_SNIPPET_MAX_CHARS).collect(),
+        })
+        .collect()
+}
+
+/// Search service trait dyn type
+pub type DynSearchService = Arc<dyn SearchServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dto::s3_object_metadata::S3ObjectMetadata;
+    use crate::dto::search_match::SearchMatch;
+    use crate::dto::search_request::SearchRequest;
+    use crate::dto::search_result::SearchResult;
+    use crate::enums::common_error::CommonError;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use crate::service::search_service::{SearchService, SearchServiceTrait};
+
+    /// Scenario:
+    /// Searches a case insensitive regular expression across a S3 folder with two text S3 objects, a binary S3 object and
+    /// a too large S3 object (just two matching lines are requested)
+    /// Expectation:
+    /// Matching lines should be retrieved by S3 key and line number, binary and too large S3 objects should be skipped,
+    /// the search should be truncated and a not valid regular expression should be rejected
+    #[tokio::test]
+    async fn when_search_objects_should_retrieve_matching_lines() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        aws_s3_service.expect_get_s3_object_metadata_page().times(1).returning(|_, _, _, _| {
+            let s3_object_metadata_fn =
+                |s3_key: &str, size_bytes: i64| S3ObjectMetadata { s3_key: String::from(s3_key), size_bytes, ..Default::default() };
+            Ok((
+                vec![
+                    s3_object_metadata_fn("a.log", 32),
+                    s3_object_metadata_fn("b.bin", 4),
+                    s3_object_metadata_fn("c.log", 2048),
+                    s3_object_metadata_fn("logs/d.log", 32),
+                ],
+                None,
+            ))
+        });
+        aws_s3_service.expect_get_s3_object_range().times(3).returning(|_, _, s3_key, _| {
+            let s3_object_content: &[u8] = match s3_key.as_str() {
+                "a.log" => b"info: started\nERROR: disk full\n",
+                "b.bin" => b"\0error",
+                _ => b"error: timeout\nerror: retry\n",
+            };
+            Ok((s3_object_content.to_vec(), s3_object_content.len() as u64))
+        });
+        let search_service = SearchService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            max_objects: 10,
+            max_object_bytes: 1024,
+            max_parallel_fetches: 2,
+        };
+        let search_request = SearchRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            query: String::from("^error:"),
+            regex: true,
+            case_insensitive: true,
+            max_matches: Some(2),
+        };
+
+        let search_response = search_service.search_objects(search_request.clone()).await.unwrap();
+        let search_match_fn = |line_number: usize, snippet: &str| SearchMatch { line_number, snippet: String::from(snippet) };
+        assert_eq!(
+            vec![
+                SearchResult { s3_key: String::from("a.log"), matches: vec![search_match_fn(2, "ERROR: disk full")] },
+                SearchResult { s3_key: String::from("logs/d.log"), matches: vec![search_match_fn(1, "error: timeout")] },
+            ],
+            search_response.results
+        );
+        assert_eq!((2, 2, true), (search_response.scanned_total, search_response.skipped_total, search_response.truncated));
+        assert_eq!(
+            Err(CommonError::NO_VALID_INPUT_OR_PARAMETER),
+            search_service
+                .search_objects(SearchRequest { query: String::from("error("), ..search_request })
+                .await
+                .map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use crate::config::base_path::get_api_base_path;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::{get_env_var_as_bool, get_env_var_as_u64};
+use crate::config::external_secrets::{get_secret_env_var, EXTERNAL_SECRETS};
+use crate::config::replay_cache::REPLAY_CACHE;
+use crate::config::shared_state_store::SHARED_STATE_STORE;
+use crate::constant::constants::{API_DOWNLOAD_ALL_AS_ZIP_PATH, API_DOWNLOAD_MAIN_PATH, API_DOWNLOAD_SIGNED_PATH, DOWNLOAD_SIGNED_LINK_EXPIRATION_SECONDS_DEFAULT, DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_DEFAULT, DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_ENV_VAR, DOWNLOAD_SIGNED_LINK_NONCE_SCOPE, DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_DEFAULT, DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_ENV_VAR, DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR, DOWNLOAD_TOKEN_NONCE_SCOPE, DOWNLOAD_TOKEN_TTL_SECONDS_DEFAULT, DOWNLOAD_TOKEN_TTL_SECONDS_ENV_VAR, SHARED_STATE_DOWNLOAD_TOKEN_KEY_PREFIX};
+use crate::dto::download_export::DownloadExport;
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::signed_link::SignedLink;
+use crate::dto::signed_link_request::SignedLinkRequest;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use crate::service::download_service::{DownloadService, DynDownloadService};
+use hmac::{Hmac, Mac};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// Signed link service (HMAC-SHA256 signed and time-limited export links - single use if replay protection is enabled -
+/// and one-time download tokens exchanged by download requests)
+#[async_trait]
+pub trait SignedLinkServiceTrait {
+    /// Creates a new [SignedLink] by [SignedLinkRequest] signed link request (S3 bucket name, S3 path and expiration)
+    /// Returns a [CommonError] if request is not valid
+    async fn create_signed_link(&self, signed_link_request: SignedLinkRequest) -> Result<SignedLink, CommonError>;
+
+    /// Gets [DownloadExport] zip file name, content stream and export report by [String] signed token and
+    /// [CancellationToken] export cancellation token (cancelled once the client disconnects)
+    /// Returns a [CommonError] if token is not valid or expired (access denied) or download flow fails
+    async fn download_files_by_signed_link(&self, token: String, cancellation_token: CancellationToken) -> Result<DownloadExport, CommonError>;
+
+    /// Creates a new [SignedLink] one-time download token (short-lived) by [DownloadRequest] download request
+    /// (exchanged request is stored server-side, so browsers can download it by a plain GET request)
+    /// Returns a [CommonError] if request is not valid
+    async fn create_download_token(&self, download_request: DownloadRequest) -> Result<SignedLink, CommonError>;
+
+    /// Gets [DownloadExport] zip file name, content stream and export report by [String] one-time download token and
+    /// [CancellationToken] export cancellation token (cancelled once the client disconnects)
+    /// Returns a [CommonError] if token is not valid, expired or already used (access denied) or download flow fails
+    async fn download_files_by_download_token(&self, token: String, cancellation_token: CancellationToken) -> Result<DownloadExport, CommonError>;
+}
+
+/// Signed link claims (signed token payload)
+#[derive(Serialize, Deserialize)]
+struct SignedLinkClaims {
+    bucket_name: String,
+    full_path: String,
+    expires_at: u64,
+    /// unique link nonce (replay protection - links without nonce are rejected if it is enabled)
+    #[serde(default)]
+    nonce: String,
+    /// link creator caller identifier (link downloads are accounted to the link creator download quota)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    caller_id: Option<String>,
+}
+
+/// Signed link service implementation struct
+pub struct SignedLinkService {
+    download_service: DynDownloadService,
+    signing_secret: Vec<u8>,
+    max_expiration_seconds: u64,
+    replay_protection_enabled: bool,
+    download_token_ttl_seconds: u64,
+    download_tokens: Mutex<HashMap<String, (DownloadRequest, u64)>>,
+}
+
+/// initialization by shared dependencies
+/// Important: if signing secret is not configured, a random one is generated (links are not valid on other API replicas
+/// or after a restart - it is a configuration error if the shared state store is configured, please check config_dump.rs)
+impl SignedLinkService {
+    /// Creates a new [SignedLinkService] by [DynDownloadService] download service
+    pub fn new(download_service: DynDownloadService) -> Self {
+        let signing_secret = get_secret_env_var(DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR).unwrap_or_else(|_| {
+            warn!("signed_link_service - signing secret not configured - a random signing secret is used (signed links are not valid on other replicas or after a restart)");
+            format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+        });
+        SignedLinkService {
+            download_service,
+            signing_secret: signing_secret.into_bytes(),
+            max_expiration_seconds: get_env_var_as_u64(
+                DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_ENV_VAR,
+                DOWNLOAD_SIGNED_LINK_MAX_EXPIRATION_SECONDS_DEFAULT,
+            ),
+            replay_protection_enabled: get_env_var_as_bool(
+                DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_ENV_VAR,
+                DOWNLOAD_SIGNED_LINK_REPLAY_PROTECTION_ENABLED_DEFAULT,
+            ),
+            download_token_ttl_seconds: get_env_var_as_u64(DOWNLOAD_TOKEN_TTL_SECONDS_ENV_VAR, DOWNLOAD_TOKEN_TTL_SECONDS_DEFAULT).max(1),
+            download_tokens: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// default initialization
+impl Default for SignedLinkService {
+    fn default() -> Self {
+        SignedLinkService::new(Arc::new(DownloadService::default()) as DynDownloadService)
+    }
+}
+
+/// Signed link service implementation logic
+#[async_trait]
+impl SignedLinkServiceTrait for SignedLinkService {
+    /// Creates a new [SignedLink] by [SignedLinkRequest] signed link request (S3 bucket name, S3 path and expiration)
+    /// Returns a [CommonError] if request is not valid
+    async fn create_signed_link(&self, signed_link_request: SignedLinkRequest) -> Result<SignedLink, CommonError> {
+        info!("create_signed_link - start");
+        if signed_link_request.bucket_name.is_empty() || signed_link_request.full_path.is_empty() {
+            warn!("create_signed_link - empty bucket name or path");
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+
+        let expires_in_seconds = signed_link_request
+            .expires_in_seconds
+            .unwrap_or(DOWNLOAD_SIGNED_LINK_EXPIRATION_SECONDS_DEFAULT)
+            .clamp(1, self.max_expiration_seconds.max(1));
+        let signed_link_claims = SignedLinkClaims {
+            bucket_name: signed_link_request.bucket_name,
+            full_path: signed_link_request.full_path,
+            expires_at: current_epoch_seconds() + expires_in_seconds,
+            nonce: Uuid::new_v4().simple().to_string(),
+            caller_id: signed_link_request.caller_id,
+        };
+        let token = self.sign_claims(&signed_link_claims);
+
+        info!("create_signed_link - done - expires at: {}", signed_link_claims.expires_at);
+        Ok(SignedLink {
+            url: format!("{}{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_SIGNED_PATH}?token={token}", get_api_base_path()),
+            token,
+            expires_at: signed_link_claims.expires_at,
+        })
+    }
+
+    /// Gets [DownloadExport] zip file name, content stream and export report by [String] signed token and
+    /// [CancellationToken] export cancellation token (cancelled once the client disconnects)
+    /// Returns a [CommonError] if token is not valid or expired (access denied) or download flow fails
+    async fn download_files_by_signed_link(&self, token: String, cancellation_token: CancellationToken) -> Result<DownloadExport, CommonError> {
+        info!("download_files_by_signed_link - start");
+        let Some(signed_link_claims) = self.verify_token(&token) else {
+            warn!("download_files_by_signed_link - not valid or expired token");
+            return Err(CommonError::ACCESS_DENIED);
+        };
+        if self.replay_protection_enabled
+            && (signed_link_claims.nonce.is_empty()
+                || !REPLAY_CACHE
+                    .register_nonce(DOWNLOAD_SIGNED_LINK_NONCE_SCOPE, &signed_link_claims.nonce, signed_link_claims.expires_at)
+                    .await)
+        {
+            warn!("download_files_by_signed_link - replayed token (or token without nonce)");
+            return Err(CommonError::ACCESS_DENIED);
+        }
+
+        let download_request = DownloadRequest {
+            bucket_name: signed_link_claims.bucket_name,
+            full_path: signed_link_claims.full_path,
+            deduplicate: false,
+            max_parallel_fetches: None,
+            multipart_chunk_bytes: None,
+            manifest: None,
+            filter: None,
+            duplicate_entry_policy: DuplicateEntryPolicy::default(),
+            missing_key_policy: MissingKeyPolicy::default(),
+            direct_key_lookup: false,
+            allow_oversize: false,
+            encryption_recipient: None,
+            notify_email: None,
+            volumes: None,
+            split_by_folder: false,
+            priority: DownloadJobPriority::default(),
+            caller_id: signed_link_claims.caller_id,
+            restore: None,
+            metadata_report: None,
+            checksum_audit: false,
+            public_base_url: None,
+            cancellation_token,
+        };
+        self.download_service.download_files(download_request).await
+    }
+
+    /// Creates a new [SignedLink] one-time download token (short-lived) by [DownloadRequest] download request
+    /// (exchanged request is stored server-side, so browsers can download it by a plain GET request)
+    /// Returns a [CommonError] if request is not valid
+    async fn create_download_token(&self, download_request: DownloadRequest) -> Result<SignedLink, CommonError> {
+        info!("create_download_token - start");
+        if download_request.bucket_name.is_empty() || download_request.full_path.is_empty() {
+            warn!("create_download_token - empty bucket name or path");
+            return Err(CommonError::NO_VALID_INPUT_OR_PARAMETER);
+        }
+
+        let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let expires_at = current_epoch_seconds() + self.download_token_ttl_seconds;
+        if SHARED_STATE_STORE.is_enabled() {
+            let download_request_json = serde_json::to_string(&download_request).unwrap_or_default();
+            SHARED_STATE_STORE
+                .set_value(&format!("{SHARED_STATE_DOWNLOAD_TOKEN_KEY_PREFIX}{token}"), &download_request_json, self.download_token_ttl_seconds)
+                .await;
+        } else {
+            let mut download_tokens = self.download_tokens.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            download_tokens.retain(|_, (_, token_expires_at)| *token_expires_at > current_epoch_seconds());
+            download_tokens.insert(token.clone(), (download_request, expires_at));
+        }
+
+        info!("create_download_token - done - expires at: {expires_at}");
+        Ok(SignedLink {
+            url: format!("{}{API_DOWNLOAD_MAIN_PATH}{API_DOWNLOAD_ALL_AS_ZIP_PATH}?token={token}", get_api_base_path()),
+            token,
+            expires_at,
+        })
+    }
+
+    /// Gets [DownloadExport] zip file name, content stream and export report by [String] one-time download token and
+    /// [CancellationToken] export cancellation token (cancelled once the client disconnects)
+    /// Returns a [CommonError] if token is not valid, expired or already used (access denied) or download flow fails
+    async fn download_files_by_download_token(&self, token: String, cancellation_token: CancellationToken) -> Result<DownloadExport, CommonError> {
+        info!("download_files_by_download_token - start");
+        let Some(mut download_request) = self.take_download_request(&token).await else {
+            warn!("download_files_by_download_token - not valid, expired or already used token");
+            return Err(CommonError::ACCESS_DENIED);
+        };
+        download_request.cancellation_token = cancellation_token;
+        self.download_service.download_files(download_request).await
+    }
+}
+
+/// Signed link service internal logic
+impl SignedLinkService {
+    /// Takes [Option<DownloadRequest>] exchanged download request by [&str] one-time download token
+    /// Returns [None] if token is not found, it's expired or it was already used (by any replica)
+    async fn take_download_request(&self, token: &str) -> Option<DownloadRequest> {
+        if !SHARED_STATE_STORE.is_enabled() {
+            let mut download_tokens = self.download_tokens.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            return download_tokens
+                .remove(token)
+                .filter(|(_, expires_at)| *expires_at > current_epoch_seconds())
+                .map(|(download_request, _)| download_request);
+        }
+
+        let download_token_key = format!("{SHARED_STATE_DOWNLOAD_TOKEN_KEY_PREFIX}{token}");
+        let download_request = SHARED_STATE_STORE
+            .get_value(&download_token_key)
+            .await
+            .and_then(|download_request_json| serde_json::from_str::<DownloadRequest>(&download_request_json).ok())?;
+        let expires_at = current_epoch_seconds() + self.download_token_ttl_seconds;
+        if !REPLAY_CACHE.register_nonce(DOWNLOAD_TOKEN_NONCE_SCOPE, token, expires_at).await {
+            return None;
+        }
+        SHARED_STATE_STORE.remove_values(&download_token_key).await;
+        Some(download_request)
+    }
+
+    /// Gets [String] signed token ("payload.signature" - both base64 URL-safe encoded) by [&SignedLinkClaims] claims
+    fn sign_claims(&self, signed_link_claims: &SignedLinkClaims) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(signed_link_claims).unwrap_or_default());
+        let signature = URL_SAFE_NO_PAD.encode(self.create_mac(&payload).finalize().into_bytes());
+        format!("{payload}.{signature}")
+    }
+
+    /// Gets [Option<SignedLinkClaims>] claims by [&str] signed token
+    /// Returns [None] if token signature is not valid or token is expired
+    fn verify_token(&self, token: &str) -> Option<SignedLinkClaims> {
+        let (payload, signature) = token.split_once('.')?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        self.create_mac(payload).verify_slice(&signature).ok()?;
+
+        let signed_link_claims: SignedLinkClaims = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).ok()?).ok()?;
+        (signed_link_claims.expires_at > current_epoch_seconds()).then_some(signed_link_claims)
+    }
+
+    /// Creates a new [Hmac<Sha256>] HMAC with [&str] payload by configured signing secret
+    /// (refreshed external signing secret, if any - rotated secrets invalidate previous links)
+    fn create_mac(&self, payload: &str) -> Hmac<Sha256> {
+        let external_signing_secret = EXTERNAL_SECRETS.get_value(DOWNLOAD_SIGNED_LINK_SECRET_ENV_VAR);
+        let signing_secret = external_signing_secret.as_ref().map(String::as_bytes).unwrap_or(&self.signing_secret);
+        let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret).expect("HMAC accepts keys of any size");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+/// Signed link service trait for API router state (based on Rust samples for Axum DI)
+pub type DynSignedLinkService = Arc<dyn SignedLinkServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use crate::config::clock::current_epoch_seconds;
+    use crate::dto::download_request::DownloadRequest;
+    use crate::service::download_service::{DynDownloadService, MockDownloadServiceTrait};
+    use crate::service::signed_link_service::{SignedLinkClaims, SignedLinkService, SignedLinkServiceTrait};
+
+    /// Scenario:
+    /// Signs valid and expired claims (link creator caller included) and verifies them (also with a tampered signature)
+    /// Expectation:
+    /// Just the valid and not tampered token should be verified (with its link creator caller)
+    #[test]
+    fn when_verify_token_should_retrieve_claims_just_for_valid_tokens() {
+        let signed_link_service = create_signed_link_service();
+        let valid_token = signed_link_service.sign_claims(&create_signed_link_claims(current_epoch_seconds() + 60));
+        let expired_token = signed_link_service.sign_claims(&create_signed_link_claims(current_epoch_seconds() - 1));
+        let tampered_token = format!("{valid_token}x");
+
+        let valid_claims = signed_link_service.verify_token(&valid_token).unwrap();
+        assert_eq!("some/path", valid_claims.full_path);
+        assert_eq!(Some(String::from("some-caller")), valid_claims.caller_id);
+        assert!(signed_link_service.verify_token(&expired_token).is_none());
+        assert!(signed_link_service.verify_token(&tampered_token).is_none());
+    }
+
+    /// Scenario:
+    /// Exchanges a download request for a download token and takes it twice (also with an unknown token)
+    /// Expectation:
+    /// The exchanged download request should be taken just once
+    #[tokio::test]
+    async fn when_take_download_request_should_retrieve_exchanged_request_just_once() {
+        let signed_link_service = create_signed_link_service();
+        let download_request = DownloadRequest {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            ..Default::default()
+        };
+
+        let download_token = signed_link_service.create_download_token(download_request).await.unwrap();
+
+        assert!(download_token.url.ends_with(&format!("/zip?token={}", download_token.token)));
+        assert_eq!("some/path", signed_link_service.take_download_request(&download_token.token).await.unwrap().full_path);
+        assert!(signed_link_service.take_download_request(&download_token.token).await.is_none());
+        assert!(signed_link_service.take_download_request("unknown").await.is_none());
+    }
+
+    /// Creates a new [SignedLinkService] with a mocked download service and a fixed signing secret
+    fn create_signed_link_service() -> SignedLinkService {
+        SignedLinkService {
+            download_service: Arc::new(MockDownloadServiceTrait::new()) as DynDownloadService,
+            signing_secret: b"some-secret".to_vec(),
+            max_expiration_seconds: 3600,
+            replay_protection_enabled: true,
+            download_token_ttl_seconds: 60,
+            download_tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Creates a new [SignedLinkClaims] by [u64] expiration as seconds since unix epoch
+    fn create_signed_link_claims(expires_at: u64) -> SignedLinkClaims {
+        SignedLinkClaims {
+            bucket_name: String::from("some-bucket"),
+            full_path: String::from("some/path"),
+            expires_at,
+            nonce: String::from("some-nonce"),
+            caller_id: Some(String::from("some-caller")),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use crate::config::clock::current_epoch_seconds;
+use crate::config::env_vars::get_env_var_as_usize;
+use crate::constant::constants::{DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT, DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR, WATCH_DESTINATION_BUCKET_ENV_VAR, WATCH_DESTINATION_PATH_DEFAULT, WATCH_DESTINATION_PATH_ENV_VAR, WATCH_PREFIXES_ENV_VAR};
+use crate::dto::download_request::DownloadRequest;
+use crate::dto::manifest_entry::ManifestEntry;
+use crate::enums::common_error::CommonError;
+use crate::enums::download_job_priority::DownloadJobPriority;
+use crate::enums::missing_key_policy::MissingKeyPolicy;
+use crate::enums::duplicate_entry_policy::DuplicateEntryPolicy;
+use crate::service::aws_sdk_s3_service::{AwsSdkS3Service, DynAwsSdkS3Service};
+use crate::service::download_service::{DownloadService, DynDownloadService};
+use log::{debug, error, info, warn};
+use tokio::io::AsyncReadExt;
+use tokio_util::sync::CancellationToken;
+
+/// Watch service (continuous S3 prefixes monitoring - new S3 objects are archived into a destination S3 bucket)
+#[async_trait]
+pub trait WatchServiceTrait {
+    /// Gets [bool] true if watch mode is configured (watched S3 prefixes and destination S3 bucket are defined)
+    fn is_enabled(&self) -> bool;
+
+    /// Archives new S3 objects (not archived yet) from all watched S3 prefixes into incremental zip files
+    /// stored in the destination S3 bucket
+    /// Returns [usize] archived S3 objects total
+    async fn archive_new_objects(&self) -> usize;
+}
+
+/// Watch service implementation struct
+pub struct WatchService {
+    aws_s3_service: DynAwsSdkS3Service,
+    download_service: DynDownloadService,
+    watched_prefixes: Vec<(String, String)>,
+    destination_bucket: Option<String>,
+    destination_path: String,
+    max_objects_per_archive: usize,
+    archived_s3_keys: Mutex<HashMap<(String, String), HashSet<String>>>,
+}
+
+/// initialization by shared dependencies
+impl WatchService {
+    /// Creates a new [WatchService] by [DynAwsSdkS3Service] AWS S3 service and [DynDownloadService] download service
+    pub fn new(aws_s3_service: DynAwsSdkS3Service, download_service: DynDownloadService) -> Self {
+        WatchService {
+            aws_s3_service,
+            download_service,
+            watched_prefixes: parse_watched_prefixes(&std::env::var(WATCH_PREFIXES_ENV_VAR).unwrap_or_default()),
+            destination_bucket: std::env::var(WATCH_DESTINATION_BUCKET_ENV_VAR).ok().filter(|bucket| !bucket.is_empty()),
+            destination_path: std::env::var(WATCH_DESTINATION_PATH_ENV_VAR).unwrap_or(String::from(WATCH_DESTINATION_PATH_DEFAULT)),
+            max_objects_per_archive: get_env_var_as_usize(DOWNLOAD_MANIFEST_MAX_ENTRIES_ENV_VAR, DOWNLOAD_MANIFEST_MAX_ENTRIES_DEFAULT),
+            archived_s3_keys: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// default initialization
+impl Default for WatchService {
+    fn default() -> Self {
+        WatchService::new(Arc::new(AwsSdkS3Service::default()) as DynAwsSdkS3Service, Arc::new(DownloadService::default()) as DynDownloadService)
+    }
+}
+
+/// Watch service implementation logic
+#[async_trait]
+impl WatchServiceTrait for WatchService {
+    /// Gets [bool] true if watch mode is configured (watched S3 prefixes and destination S3 bucket are defined)
+    fn is_enabled(&self) -> bool {
+        !self.watched_prefixes.is_empty() && self.destination_bucket.is_some()
+    }
+
+    /// Archives new S3 objects (not archived yet) from all watched S3 prefixes into incremental zip files
+    /// stored in the destination S3 bucket
+    /// Returns [usize] archived S3 objects total
+    /// IMPORTANT:
+    /// - the first archive of each watched S3 prefix includes all current S3 objects (baseline)
+    /// - S3 objects per archive are limited by manifest maximum entries (remaining S3 objects are archived next time)
+    /// - archived S3 keys are kept in memory (a restart produces a new baseline archive)
+    async fn archive_new_objects(&self) -> usize {
+        debug!("archive_new_objects - start");
+        let mut archived_objects_total = 0;
+        for (s3_bucket, s3_path) in &self.watched_prefixes {
+            match self.archive_new_prefix_objects(s3_bucket, s3_path).await {
+                Ok(archived_objects) => archived_objects_total += archived_objects,
+                Err(archive_error) => {
+                    error!("archive_new_objects - archive error - s3 bucket: {s3_bucket} - s3 path: {s3_path} - error: {archive_error}")
+                }
+            }
+        }
+
+        debug!("archive_new_objects - done - archived objects: {archived_objects_total}");
+        archived_objects_total
+    }
+}
+
+/// Watch service internal logic
+impl WatchService {
+    /// Archives new S3 objects from [&str] S3 bucket and [&str] S3 path into a new zip file
+    /// stored in the destination S3 bucket
+    /// Returns [usize] archived S3 objects or a [CommonError] if S3 objects can't be listed, downloaded or stored
+    async fn archive_new_prefix_objects(&self, s3_bucket: &str, s3_path: &str) -> Result<usize, CommonError> {
+        let Some(destination_bucket) = &self.destination_bucket else {
+            return Ok(0);
+        };
+        let watched_prefix = (String::from(s3_bucket), String::from(s3_path));
+        let s3_keys = self
+            .aws_s3_service
+            .get_s3_object_key_list(String::from(s3_bucket), String::from(s3_path))
+            .await?;
+        let new_s3_keys: Vec<String> = {
+            let archived_s3_keys = self.archived_s3_keys.lock().unwrap();
+            let prefix_archived_s3_keys = archived_s3_keys.get(&watched_prefix);
+            s3_keys
+                .into_iter()
+                .filter(|s3_key| !prefix_archived_s3_keys.is_some_and(|archived_keys| archived_keys.contains(s3_key)))
+                .take(self.max_objects_per_archive)
+                .collect()
+        };
+        if new_s3_keys.is_empty() {
+            debug!("archive_new_prefix_objects - no new s3 objects - s3 bucket: {s3_bucket} - s3 path: {s3_path}");
+            return Ok(0);
+        }
+
+        info!("archive_new_prefix_objects - new s3 objects: {} - s3 bucket: {s3_bucket} - s3 path: {s3_path}", new_s3_keys.len());
+        let download_request = DownloadRequest {
+            bucket_name: String::from(s3_bucket),
+            full_path: String::from(s3_path),
+            deduplicate: false,
+            max_parallel_fetches: None,
+            multipart_chunk_bytes: None,
+            manifest: Some(
+                new_s3_keys
+                    .iter()
+                    .map(|s3_key| ManifestEntry { s3_key: s3_key.clone(), archive_name: None })
+                    .collect(),
+            ),
+            filter: None,
+            duplicate_entry_policy: DuplicateEntryPolicy::default(),
+            missing_key_policy: MissingKeyPolicy::default(),
+            direct_key_lookup: false,
+            allow_oversize: false,
+            encryption_recipient: None,
+            notify_email: None,
+            volumes: None,
+            split_by_folder: false,
+            priority: DownloadJobPriority::default(),
+            caller_id: None,
+            restore: None,
+            metadata_report: None,
+            checksum_audit: false,
+            public_base_url: None,
+            cancellation_token: CancellationToken::default(),
+        };
+        let mut download_export = self.download_service.download_files(download_request).await?;
+        let mut zip_content = Vec::new();
+        if download_export.content.read_to_end(&mut zip_content).await.is_err() || download_export.report.await.is_err() {
+            warn!("archive_new_prefix_objects - zip file can't be read - s3 bucket: {s3_bucket} - s3 path: {s3_path}");
+            return Err(CommonError::AWS_ACCESS_ERROR);
+        }
+
+        let archive_s3_key = format!("{s3_bucket}/{}/watch-{}.zip", s3_path.trim_matches('/'), current_epoch_seconds());
+        self.aws_s3_service
+            .add_s3_object(destination_bucket.clone(), self.destination_path.clone(), archive_s3_key, Bytes::from(zip_content))
+            .await?;
+
+        let archived_objects = new_s3_keys.len();
+        self.archived_s3_keys
+            .lock()
+            .unwrap()
+            .entry(watched_prefix)
+            .or_default()
+            .extend(new_s3_keys);
+        Ok(archived_objects)
+    }
+}
+
+/// Gets [Vec<(String, String)>] watched S3 buckets and S3 paths by [&str] watched prefixes
+/// (comma separated "bucket:path" values - not valid values are skipped)
+fn parse_watched_prefixes(watched_prefixes: &str) -> Vec<(String, String)> {
+    watched_prefixes
+        .split(',')
+        .filter_map(|watched_prefix| watched_prefix.trim().split_once(':'))
+        .filter(|(s3_bucket, s3_path)| !s3_bucket.is_empty() && !s3_path.is_empty())
+        .map(|(s3_bucket, s3_path)| (String::from(s3_bucket), String::from(s3_path)))
+        .collect()
+}
+
+/// Watch service trait dyn type
+pub type DynWatchService = Arc<dyn WatchServiceTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use crate::dto::download_export::DownloadExport;
+    use crate::dto::export_report::ExportReport;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use crate::service::download_service::{DynDownloadService, MockDownloadServiceTrait};
+    use crate::service::watch_service::{WatchService, WatchServiceTrait};
+    use tokio::io::{duplex, AsyncWriteExt};
+    use tokio::sync::oneshot;
+
+    /// Scenario:
+    /// Archives a watched S3 prefix twice (a new S3 object is added between both executions)
+    /// Expectation:
+    /// The first archive should include all S3 objects and the second one just the new S3 object
+    #[tokio::test]
+    async fn when_archive_new_objects_should_archive_just_new_objects() {
+        let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+        let mut s3_key_lists = vec![
+            vec![String::from("a.txt"), String::from("b.txt"), String::from("c.txt")],
+            vec![String::from("a.txt"), String::from("b.txt")],
+        ];
+        aws_s3_service
+            .expect_get_s3_object_key_list()
+            .times(2)
+            .returning(move |_, _| Ok(s3_key_lists.pop().unwrap()));
+        aws_s3_service
+            .expect_add_s3_object()
+            .times(2)
+            .returning(|_, _, s3_key, _| Ok(s3_key));
+        let mut download_service = MockDownloadServiceTrait::new();
+        download_service.expect_download_files().times(2).returning(|_| {
+            let (zip_content_reader, mut zip_content_writer) = duplex(64);
+            let (export_report_sender, export_report) = oneshot::channel();
+            export_report_sender.send(ExportReport::default()).unwrap();
+            tokio::spawn(async move { zip_content_writer.write_all(b"zip content").await });
+            Ok(DownloadExport {
+                file_name: String::from("s3-export.zip"),
+                content: zip_content_reader,
+                report: export_report,
+                excluded_count: 0,
+            })
+        });
+        let watch_service = WatchService {
+            aws_s3_service: Arc::new(aws_s3_service) as DynAwsSdkS3Service,
+            download_service: Arc::new(download_service) as DynDownloadService,
+            watched_prefixes: vec![(String::from("some-bucket"), String::from("some/path"))],
+            destination_bucket: Some(String::from("archive-bucket")),
+            destination_path: String::from("archives"),
+            max_objects_per_archive: 10,
+            archived_s3_keys: Mutex::new(HashMap::new()),
+        };
+
+        assert_eq!(2, watch_service.archive_new_objects().await);
+        assert_eq!(1, watch_service.archive_new_objects().await);
+    }
+}
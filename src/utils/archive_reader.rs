@@ -0,0 +1,342 @@
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+
+use crate::dto::archive_entry::ArchiveEntry;
+use crate::enums::archive_format::ArchiveFormat;
+use crate::utils::s3_range_reader::S3RangeReader;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+/// tar header (and content) block size
+const TAR_BLOCK_SIZE: u64 = 512;
+/// tar extended headers (GNU long names and PAX records) maximum size
+const TAR_EXTENDED_HEADER_MAX_SIZE: u64 = 1048576;
+
+/// Archive reader struct (ZIP or tar archive stored in S3 - entries are listed and read by ranged reads, so the
+/// whole archive is not downloaded)
+/// Important: S3 ranged requests block the current thread (please, check [S3RangeReader])
+pub struct ArchiveReader {
+    archive_content: ArchiveContent,
+    archive_entries: Vec<(ArchiveEntry, u64)>,
+    entry_max_bytes: u64,
+}
+
+/// Archive content (ZIP entries are read by its central directory - tar entries by their content position)
+enum ArchiveContent {
+    Zip(Box<ZipArchive<S3RangeReader>>),
+    Tar(S3RangeReader),
+}
+
+impl ArchiveReader {
+    /// Opens a new [ArchiveReader] by [S3RangeReader] S3 archive reader, [ArchiveFormat] archive format and
+    /// [u64] entry maximum bytes (uncompressed - bigger entries can be listed but not read)
+    /// (ZIP central directory or tar headers are read)
+    /// Returns an [Error] if S3 throws any error or archive is not valid ([ErrorKind::InvalidData])
+    pub fn open(mut s3_range_reader: S3RangeReader, archive_format: ArchiveFormat, entry_max_bytes: u64) -> std::io::Result<Self> {
+        match archive_format {
+            ArchiveFormat::ZIP => {
+                let mut zip_archive = ZipArchive::new(s3_range_reader).map_err(create_zip_read_error)?;
+                let archive_entries = read_zip_entries(&mut zip_archive)?
+                    .into_iter()
+                    .map(|archive_entry| (archive_entry, 0))
+                    .collect();
+                Ok(ArchiveReader { archive_content: ArchiveContent::Zip(Box::new(zip_archive)), archive_entries, entry_max_bytes })
+            }
+            ArchiveFormat::TAR => {
+                let archive_entries = read_tar_entries(&mut s3_range_reader)?;
+                Ok(ArchiveReader { archive_content: ArchiveContent::Tar(s3_range_reader), archive_entries, entry_max_bytes })
+            }
+        }
+    }
+
+    /// Gets [Vec<ArchiveEntry>] archive entries (in archive order)
+    pub fn entries(&self) -> Vec<ArchiveEntry> {
+        self.archive_entries.iter().map(|(archive_entry, _)| archive_entry.clone()).collect()
+    }
+
+    /// Reads [Vec<u8>] archive entry content (uncompressed) by [&str] entry name
+    /// Important: entry content is read up to the entry maximum bytes (declared entry sizes are not trusted)
+    /// Returns an [Error] if entry is not found ([ErrorKind::NotFound]), S3 throws any error or entry is not valid
+    /// or bigger than the entry maximum bytes ([ErrorKind::InvalidData])
+    pub fn read_entry(&mut self, entry_name: &str) -> std::io::Result<Vec<u8>> {
+        let (archive_entry, content_start) = self
+            .archive_entries
+            .iter()
+            .find(|(archive_entry, _)| archive_entry.name == entry_name && !archive_entry.directory)
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, format!("archive entry not found: {entry_name}")))?;
+        if archive_entry.size_bytes > self.entry_max_bytes {
+            return Err(create_invalid_data_error("archive entry is too large"));
+        }
+
+        let mut entry_content = Vec::with_capacity(archive_entry.size_bytes as usize);
+        match &mut self.archive_content {
+            ArchiveContent::Zip(zip_archive) => {
+                zip_archive
+                    .by_name(entry_name)
+                    .map_err(create_zip_read_error)?
+                    .take(self.entry_max_bytes + 1)
+                    .read_to_end(&mut entry_content)?;
+            }
+            ArchiveContent::Tar(s3_range_reader) => {
+                s3_range_reader.seek(SeekFrom::Start(*content_start))?;
+                s3_range_reader.take(archive_entry.size_bytes).read_to_end(&mut entry_content)?;
+            }
+        }
+        match entry_content.len() as u64 > self.entry_max_bytes {
+            true => Err(create_invalid_data_error("archive entry is too large")),
+            false => Ok(entry_content),
+        }
+    }
+}
+
+/// Reads [Vec<ArchiveEntry>] ZIP archive entries by [&mut ZipArchive<S3RangeReader>] ZIP archive (its central
+/// directory is already read - ZIP64 archives are supported)
+/// Returns an [Error] if S3 throws any error or an entry is not valid ([ErrorKind::InvalidData])
+fn read_zip_entries(zip_archive: &mut ZipArchive<S3RangeReader>) -> std::io::Result<Vec<ArchiveEntry>> {
+    (0..zip_archive.len())
+        .map(|entry_index| {
+            let zip_file = zip_archive.by_index_raw(entry_index).map_err(create_zip_read_error)?;
+            Ok(ArchiveEntry {
+                name: String::from(zip_file.name()),
+                directory: zip_file.is_dir(),
+                size_bytes: zip_file.size(),
+                compressed_size_bytes: Some(zip_file.compressed_size()),
+            })
+        })
+        .collect()
+}
+
+/// Reads [Vec<(ArchiveEntry, u64)>] tar archive entries and their content positions from their headers (GNU long
+/// names and PAX paths are supported - links and special files are skipped) by [&mut S3RangeReader] S3 archive reader
+/// Returns an [Error] if S3 throws any error or a header is not valid or out of archive bounds ([ErrorKind::InvalidData])
+fn read_tar_entries(s3_range_reader: &mut S3RangeReader) -> std::io::Result<Vec<(ArchiveEntry, u64)>> {
+    let mut archive_entries = Vec::new();
+    let mut header_start: u64 = 0;
+    let mut extended_name: Option<String> = None;
+    while header_start.checked_add(TAR_BLOCK_SIZE).is_some_and(|header_end| header_end <= s3_range_reader.size()) {
+        let header = read_range(s3_range_reader, header_start, TAR_BLOCK_SIZE as usize)?;
+        if header.iter().all(|header_byte| *header_byte == 0) {
+            break;
+        }
+
+        let size = parse_tar_number(&header[124..136])?;
+        let content_start = header_start + TAR_BLOCK_SIZE;
+        let content_end = size
+            .div_ceil(TAR_BLOCK_SIZE)
+            .checked_mul(TAR_BLOCK_SIZE)
+            .and_then(|content_blocks_size| content_start.checked_add(content_blocks_size))
+            .filter(|_| content_start.checked_add(size).is_some_and(|content_size_end| content_size_end <= s3_range_reader.size()))
+            .ok_or_else(|| create_invalid_data_error("tar entry out of archive bounds"))?;
+        if matches!(header[156], b'L' | b'x') && size > TAR_EXTENDED_HEADER_MAX_SIZE {
+            return Err(create_invalid_data_error("tar extended header is too large"));
+        }
+        match header[156] {
+            b'L' => {
+                let long_name = read_range(s3_range_reader, content_start, size as usize)?;
+                extended_name = Some(read_tar_string(&long_name));
+            }
+            b'x' => {
+                let pax_records = read_range(s3_range_reader, content_start, size as usize)?;
+                extended_name = get_pax_path(&pax_records).or(extended_name);
+            }
+            type_flag @ (b'0' | b'7' | b'5' | 0) => {
+                let name = extended_name.take().unwrap_or_else(|| match (&header[257..262] == b"ustar", read_tar_string(&header[345..500])) {
+                    (true, prefix) if !prefix.is_empty() => format!("{prefix}/{}", read_tar_string(&header[0..100])),
+                    _ => read_tar_string(&header[0..100]),
+                });
+                archive_entries.push((
+                    ArchiveEntry {
+                        directory: type_flag == b'5' || name.ends_with('/'),
+                        name,
+                        size_bytes: size,
+                        compressed_size_bytes: None,
+                    },
+                    content_start,
+                ));
+            }
+            _ => extended_name = None,
+        }
+        header_start = content_end;
+    }
+    Ok(archive_entries)
+}
+
+/// Parses [u64] tar header number by [&[u8]] header field (octal - or base-256 if the first byte high bit is set)
+/// Returns an [Error] if header field is not valid ([ErrorKind::InvalidData])
+fn parse_tar_number(header_field: &[u8]) -> std::io::Result<u64> {
+    if header_field.first().is_some_and(|first_byte| first_byte & 0x80 != 0) {
+        return Ok(header_field
+            .iter()
+            .enumerate()
+            .fold(0, |number, (index, number_byte)| (number << 8) | (if index == 0 { number_byte & 0x7f } else { *number_byte }) as u64));
+    }
+
+    let octal_number = String::from_utf8_lossy(header_field);
+    let octal_number = octal_number.trim_matches(|number_char: char| number_char == '\0' || number_char == ' ');
+    match octal_number.is_empty() {
+        true => Ok(0),
+        false => u64::from_str_radix(octal_number, 8).map_err(|_| create_invalid_data_error("not valid tar header number")),
+    }
+}
+
+/// Gets [String] tar header string (up to the first NUL byte) by [&[u8]] header field
+fn read_tar_string(header_field: &[u8]) -> String {
+    let string_length = header_field.iter().position(|header_byte| *header_byte == 0).unwrap_or(header_field.len());
+    String::from_utf8_lossy(&header_field[..string_length]).into_owned()
+}
+
+/// Gets [Option<String>] entry path by [&[u8]] PAX extended header records ("{length} {key}={value}\n" records)
+fn get_pax_path(pax_records: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(pax_records)
+        .lines()
+        .filter_map(|pax_record| pax_record.split_once(' '))
+        .find_map(|(_, pax_record)| pax_record.strip_prefix("path=").map(String::from))
+}
+
+/// Reads [Vec<u8>] archive bytes by [&mut S3RangeReader] S3 archive reader, [u64] start position and [usize] length
+/// Returns an [Error] if S3 throws any error or archive is truncated ([ErrorKind::UnexpectedEof])
+fn read_range(s3_range_reader: &mut S3RangeReader, start: u64, length: usize) -> std::io::Result<Vec<u8>> {
+    let mut range_content = vec![0; length];
+    s3_range_reader.seek(SeekFrom::Start(start))?;
+    s3_range_reader.read_exact(&mut range_content)?;
+    Ok(range_content)
+}
+
+/// Creates a new [Error] with [ErrorKind::InvalidData] kind by [&str] error message
+fn create_invalid_data_error(error_message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, String::from(error_message))
+}
+
+/// Creates a new [Error] by [ZipError] ZIP read error (I/O errors are kept - other errors are invalid data errors)
+fn create_zip_read_error(zip_error: ZipError) -> Error {
+    match zip_error {
+        ZipError::Io(io_error) => io_error,
+        ZipError::FileNotFound => Error::new(ErrorKind::NotFound, zip_error),
+        _ => Error::new(ErrorKind::InvalidData, zip_error),
+    }
+}
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, ErrorKind, Write};
+    use std::sync::Arc;
+
+    use crate::utils::archive_reader::ArchiveReader;
+    use crate::utils::s3_range_reader::S3RangeReader;
+    use crate::enums::archive_format::ArchiveFormat;
+    use crate::service::aws_sdk_s3_service::{DynAwsSdkS3Service, MockAwsSdkS3ServiceTrait};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    /// Scenario:
+    /// Lists and reads entries of ZIP and tar archives (with a directory) by small ranged requests
+    /// Expectation:
+    /// Entries should be listed in archive order and selected entry content should be retrieved
+    #[tokio::test]
+    async fn when_read_entry_should_read_zip_and_tar_entries_by_ranged_requests() {
+        let entry_content = b"content a".repeat(100);
+        let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+        zip_writer.add_directory("docs/", SimpleFileOptions::default()).unwrap();
+        zip_writer.start_file("docs/a.txt", SimpleFileOptions::default()).unwrap();
+        zip_writer.write_all(&entry_content).unwrap();
+        zip_writer.start_file("b.txt", SimpleFileOptions::default()).unwrap();
+        zip_writer.write_all(b"content b").unwrap();
+        let zip_content = zip_writer.finish().unwrap().into_inner();
+
+        let mut tar_content = Vec::new();
+        tar_content.extend(create_tar_header("docs/", 0, b'5'));
+        tar_content.extend(create_tar_header("docs/a.txt", entry_content.len(), b'0'));
+        tar_content.extend(&entry_content);
+        tar_content.resize(tar_content.len().div_ceil(512) * 512, 0);
+        tar_content.extend(create_tar_header("b.txt", 9, b'0'));
+        tar_content.extend(b"content b");
+        tar_content.resize(tar_content.len().div_ceil(512) * 512 + 1024, 0);
+
+        for (s3_key, archive_content, archive_format) in [("a.zip", zip_content, ArchiveFormat::ZIP), ("a.tar", tar_content, ArchiveFormat::TAR)] {
+            let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+            aws_s3_service
+                .expect_get_s3_object_range()
+                .returning(move |_, _, _, range| Ok(get_content_range(&archive_content, &range)));
+            let aws_s3_service = Arc::new(aws_s3_service) as DynAwsSdkS3Service;
+
+            let (archive_entries, content) = tokio::task::spawn_blocking(move || {
+                let s3_object = (String::from("some-bucket"), String::from("some/path"), String::from(s3_key));
+                let s3_range_reader = S3RangeReader::open(aws_s3_service, s3_object, 64, archive_format == ArchiveFormat::ZIP).unwrap();
+                let mut archive_reader = ArchiveReader::open(s3_range_reader, archive_format, 1024).unwrap();
+                (archive_reader.entries(), archive_reader.read_entry("docs/a.txt").unwrap())
+            })
+            .await
+            .unwrap();
+
+            let entry_names: Vec<&str> = archive_entries.iter().map(|archive_entry| archive_entry.name.as_str()).collect();
+            assert_eq!(vec!["docs/", "docs/a.txt", "b.txt"], entry_names);
+            assert!(archive_entries[0].directory);
+            assert_eq!(900, archive_entries[1].size_bytes);
+            assert_eq!(entry_content, content);
+        }
+    }
+
+    /// Scenario:
+    /// Reads entries bigger than the entry maximum bytes and lists a tar archive with an entry size out of archive bounds
+    /// Expectation:
+    /// Bigger entries should be rejected (but listed) and the out of bounds tar entry should be rejected as not valid data
+    #[tokio::test]
+    async fn when_read_entry_should_reject_too_large_and_out_of_bounds_entries() {
+        let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+        zip_writer.start_file("a.txt", SimpleFileOptions::default()).unwrap();
+        zip_writer.write_all(&b"a".repeat(2048)).unwrap();
+        let zip_content = zip_writer.finish().unwrap().into_inner();
+
+        let mut tar_content = create_tar_header("a.txt", 2048, b'0');
+        tar_content.extend(b"a".repeat(2048));
+        tar_content.extend(vec![0; 1024]);
+        let mut out_of_bounds_tar_content = create_tar_header("a.txt", 0, b'0');
+        out_of_bounds_tar_content[124..136].copy_from_slice(&[0x80, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        out_of_bounds_tar_content.extend(vec![0; 1024]);
+
+        for (s3_key, archive_content, archive_format) in [
+            ("a.zip", zip_content, ArchiveFormat::ZIP),
+            ("a.tar", tar_content, ArchiveFormat::TAR),
+            ("b.tar", out_of_bounds_tar_content, ArchiveFormat::TAR),
+        ] {
+            let mut aws_s3_service = MockAwsSdkS3ServiceTrait::new();
+            aws_s3_service
+                .expect_get_s3_object_range()
+                .returning(move |_, _, _, range| Ok(get_content_range(&archive_content, &range)));
+            let aws_s3_service = Arc::new(aws_s3_service) as DynAwsSdkS3Service;
+
+            let read_error = tokio::task::spawn_blocking(move || {
+                let s3_object = (String::from("some-bucket"), String::from("some/path"), String::from(s3_key));
+                let s3_range_reader = S3RangeReader::open(aws_s3_service, s3_object, 64, archive_format == ArchiveFormat::ZIP).unwrap();
+                let mut archive_reader = ArchiveReader::open(s3_range_reader, archive_format, 1024)?;
+                assert_eq!(2048, archive_reader.entries()[0].size_bytes);
+                archive_reader.read_entry("a.txt")
+            })
+            .await
+            .unwrap()
+            .unwrap_err();
+
+            assert_eq!(ErrorKind::InvalidData, read_error.kind());
+        }
+    }
+
+    /// Creates a new tar header (ustar format) by entry name, size and type flag
+    fn create_tar_header(entry_name: &str, size: usize, type_flag: u8) -> Vec<u8> {
+        let mut tar_header = vec![0; 512];
+        tar_header[..entry_name.len()].copy_from_slice(entry_name.as_bytes());
+        tar_header[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+        tar_header[156] = type_flag;
+        tar_header[257..263].copy_from_slice(b"ustar\0");
+        tar_header
+    }
+
+    /// Gets content range and content total size by content and HTTP range ("bytes=-{length}" or "bytes={start}-{end}")
+    fn get_content_range(content: &[u8], range: &str) -> (Vec<u8>, u64) {
+        let (range_start, range_end) = range.strip_prefix("bytes=").unwrap().split_once('-').unwrap();
+        let (range_start, range_end) = match range_start.is_empty() {
+            true => (content.len().saturating_sub(range_end.parse().unwrap()), content.len()),
+            false => (range_start.parse().unwrap(), (range_end.parse::<usize>().unwrap() + 1).min(content.len())),
+        };
+        (content[range_start..range_end].to_vec(), content.len() as u64)
+    }
+}
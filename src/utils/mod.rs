@@ -0,0 +1,3 @@
+pub mod archive_reader;
+pub mod s3_range_reader;
+pub mod thumbnail_renderer;
@@ -0,0 +1,125 @@
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+
+use crate::enums::common_error::CommonError;
+use crate::service::aws_sdk_s3_service::DynAwsSdkS3Service;
+use log::debug;
+use tokio::runtime::Handle;
+
+/// S3 range reader struct (synchronous [Read] and [Seek] over a S3 object by ranged GetObject requests - smaller
+/// reads are extended to the read ahead size and buffered)
+/// Important: the current thread is blocked by each ranged request, so it should be used by blocking tasks
+/// (i.e.: tokio::task::spawn_blocking)
+pub struct S3RangeReader {
+    aws_s3_service: DynAwsSdkS3Service,
+    runtime_handle: Handle,
+    bucket_name: String,
+    path: String,
+    s3_key: String,
+    read_ahead_bytes: u64,
+    size: u64,
+    position: u64,
+    buffer_start: u64,
+    buffer: Vec<u8>,
+}
+
+impl S3RangeReader {
+    /// Opens a new [S3RangeReader] by [DynAwsSdkS3Service] AWS S3 service, [(String, String, String)] S3 bucket,
+    /// path and key and [u64] read ahead bytes - the S3 object tail is buffered if [bool] prefetch tail is true
+    /// (i.e.: ZIP central directory), otherwise the S3 object head is buffered
+    /// Returns an [Error] if S3 throws any error (S3 key not found is retrieved as [ErrorKind::NotFound])
+    pub fn open(
+        aws_s3_service: DynAwsSdkS3Service,
+        (bucket_name, path, s3_key): (String, String, String),
+        read_ahead_bytes: u64,
+        prefetch_tail: bool,
+    ) -> std::io::Result<Self> {
+        let read_ahead_bytes = read_ahead_bytes.max(1);
+        let mut s3_range_reader = S3RangeReader {
+            aws_s3_service,
+            runtime_handle: Handle::current(),
+            bucket_name,
+            path,
+            s3_key,
+            read_ahead_bytes,
+            size: 0,
+            position: 0,
+            buffer_start: 0,
+            buffer: Vec::new(),
+        };
+
+        let range = match prefetch_tail {
+            true => format!("bytes=-{read_ahead_bytes}"),
+            false => format!("bytes=0-{}", read_ahead_bytes - 1),
+        };
+        let (buffer, size) = s3_range_reader.get_range(range)?;
+        s3_range_reader.size = size;
+        s3_range_reader.buffer_start = if prefetch_tail { size.saturating_sub(buffer.len() as u64) } else { 0 };
+        s3_range_reader.buffer = buffer;
+        Ok(s3_range_reader)
+    }
+
+    /// Gets [u64] S3 object size (in bytes)
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Gets [(Vec<u8>, u64)] S3 object content range and S3 object total size by [String] HTTP range
+    /// Returns an [Error] if S3 throws any error (S3 key not found is retrieved as [ErrorKind::NotFound])
+    fn get_range(&self, range: String) -> std::io::Result<(Vec<u8>, u64)> {
+        debug!("get_range - s3 key: {} - range: {range}", self.s3_key);
+        self.runtime_handle
+            .block_on(self.aws_s3_service.get_s3_object_range(
+                self.bucket_name.clone(),
+                self.path.clone(),
+                self.s3_key.clone(),
+                range,
+            ))
+            .map_err(|s3_object_error| match s3_object_error {
+                CommonError::NO_DATA_FOUND => Error::new(ErrorKind::NotFound, s3_object_error.to_string()),
+                _ => Error::other(s3_object_error.to_string()),
+            })
+    }
+}
+
+/// [Read] trait implementation (buffered content is read first - a new range is requested if position is not buffered)
+impl Read for S3RangeReader {
+    fn read(&mut self, read_buffer: &mut [u8]) -> std::io::Result<usize> {
+        if read_buffer.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+
+        if self.position < self.buffer_start || self.position >= self.buffer_start + self.buffer.len() as u64 {
+            let range_end = (self.position + self.read_ahead_bytes.max(read_buffer.len() as u64)).min(self.size) - 1;
+            let (buffer, _) = self.get_range(format!("bytes={}-{range_end}", self.position))?;
+            if buffer.is_empty() {
+                return Err(Error::new(ErrorKind::UnexpectedEof, "empty S3 object range"));
+            }
+            self.buffer_start = self.position;
+            self.buffer = buffer;
+        }
+
+        let buffer_offset = (self.position - self.buffer_start) as usize;
+        let read_bytes = read_buffer.len().min(self.buffer.len() - buffer_offset);
+        read_buffer[..read_bytes].copy_from_slice(&self.buffer[buffer_offset..buffer_offset + read_bytes]);
+        self.position += read_bytes as u64;
+        Ok(read_bytes)
+    }
+}
+
+/// [Seek] trait implementation (just the position is updated - nothing is requested until the next read)
+impl Seek for S3RangeReader {
+    fn seek(&mut self, seek_from: SeekFrom) -> std::io::Result<u64> {
+        let position = match seek_from {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(Error::new(ErrorKind::InvalidInput, "seek to a negative position")),
+        }
+    }
+}
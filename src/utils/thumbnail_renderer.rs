@@ -0,0 +1,132 @@
+use std::sync::{Arc, LazyLock};
+
+use crate::config::env_vars::get_env_var_as_u64;
+use crate::config::object_body_cache::ObjectBodyCache;
+use crate::constant::constants::{THUMBNAIL_CACHE_MAX_BYTES_DEFAULT, THUMBNAIL_CACHE_MAX_BYTES_ENV_VAR};
+use crate::enums::common_error::CommonError;
+
+/// Thumbnail renderer (resized previews of image S3 objects)
+pub trait ThumbnailRendererTrait {
+    /// Gets [bool] true if thumbnails can be rendered (image processing backend is available)
+    fn is_enabled(&self) -> bool;
+
+    /// Renders [Vec<u8>] JPEG thumbnail by [&[u8]] image content and [u32] thumbnail maximum width and height
+    /// (aspect ratio is preserved)
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if image content is not a supported image
+    /// Important: image decoding is CPU bound, so it should be rendered by blocking tasks (i.e.: tokio::task::spawn_blocking)
+    fn render_thumbnail(&self, image_content: &[u8], size: u32) -> Result<Vec<u8>, CommonError>;
+}
+
+/// No thumbnail renderer implementation struct (default thumbnail renderer: thumbnails are not supported)
+pub struct NoThumbnailRenderer {}
+
+/// No thumbnail renderer implementation logic
+impl ThumbnailRendererTrait for NoThumbnailRenderer {
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    fn render_thumbnail(&self, _image_content: &[u8], _size: u32) -> Result<Vec<u8>, CommonError> {
+        Err(CommonError::NO_VALID_INPUT_OR_PARAMETER)
+    }
+}
+
+/// Image thumbnail renderer implementation struct (enabled by `thumbnails` feature - GIF, JPEG, PNG and WebP images)
+#[cfg(feature = "thumbnails")]
+pub struct ImageThumbnailRenderer {}
+
+/// Image thumbnail renderer implementation logic
+#[cfg(feature = "thumbnails")]
+impl ThumbnailRendererTrait for ImageThumbnailRenderer {
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Renders [Vec<u8>] JPEG thumbnail by [&[u8]] image content and [u32] thumbnail maximum width and height
+    /// (aspect ratio is preserved - image dimensions and decoding memory are limited, i.e.: decompression bombs)
+    /// Returns a [CommonError::NO_VALID_INPUT_OR_PARAMETER] if image content is not a supported image
+    fn render_thumbnail(&self, image_content: &[u8], size: u32) -> Result<Vec<u8>, CommonError> {
+        use image::codecs::jpeg::JpegEncoder;
+        use image::{DynamicImage, ImageReader, Limits};
+        use log::debug;
+        use std::io::Cursor;
+
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(16_384);
+        limits.max_image_height = Some(16_384);
+        limits.max_alloc = Some(256 * 1024 * 1024);
+        let mut image_reader = ImageReader::new(Cursor::new(image_content)).with_guessed_format().map_err(|image_error| {
+            debug!("render_thumbnail - image format can't be guessed - error: {image_error}");
+            CommonError::NO_VALID_INPUT_OR_PARAMETER
+        })?;
+        image_reader.limits(limits);
+        let image = image_reader.decode().map_err(|image_error| {
+            debug!("render_thumbnail - image can't be decoded - error: {image_error}");
+            CommonError::NO_VALID_INPUT_OR_PARAMETER
+        })?;
+
+        let mut thumbnail_content = Vec::new();
+        DynamicImage::ImageRgb8(image.thumbnail(size, size).to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut thumbnail_content, 80))
+            .map_err(|image_error| {
+                debug!("render_thumbnail - thumbnail can't be encoded - error: {image_error}");
+                CommonError::NO_VALID_INPUT_OR_PARAMETER
+            })?;
+        Ok(thumbnail_content)
+    }
+}
+
+/// Thumbnail renderer shared instance
+/// Important: image thumbnail renderer is used if `thumbnails` feature is enabled, otherwise thumbnails are not supported
+pub static THUMBNAIL_RENDERER: LazyLock<DynThumbnailRenderer> = LazyLock::new(create_thumbnail_renderer);
+
+/// Thumbnails cache shared instance (rendered thumbnails by S3 bucket name, S3 object key and size, validated by
+/// source S3 object ETags - maximum bytes should be configured - please, check constants.rs)
+pub static THUMBNAIL_CACHE: LazyLock<ObjectBodyCache> = LazyLock::new(|| {
+    ObjectBodyCache::new(get_env_var_as_u64(THUMBNAIL_CACHE_MAX_BYTES_ENV_VAR, THUMBNAIL_CACHE_MAX_BYTES_DEFAULT))
+});
+
+/// Creates a new [DynThumbnailRenderer] thumbnail renderer (please, check [THUMBNAIL_RENDERER])
+#[cfg(feature = "thumbnails")]
+fn create_thumbnail_renderer() -> DynThumbnailRenderer {
+    Arc::new(ImageThumbnailRenderer {}) as DynThumbnailRenderer
+}
+
+/// Creates a new [DynThumbnailRenderer] thumbnail renderer (please, check [THUMBNAIL_RENDERER])
+#[cfg(not(feature = "thumbnails"))]
+fn create_thumbnail_renderer() -> DynThumbnailRenderer {
+    Arc::new(NoThumbnailRenderer {}) as DynThumbnailRenderer
+}
+
+/// Thumbnail renderer trait for shared instance
+pub type DynThumbnailRenderer = Arc<dyn ThumbnailRendererTrait + Send + Sync>;
+
+/// Unit test cases
+#[cfg(test)]
+mod tests {
+    /// Scenario:
+    /// Renders a thumbnail of a PNG image (64x32 pixels) with a 16 pixels size and of a not valid image
+    /// Expectation:
+    /// A JPEG thumbnail of 16x8 pixels should be rendered (aspect ratio preserved) and a not valid image should be rejected
+    #[cfg(feature = "thumbnails")]
+    #[test]
+    fn when_render_thumbnail_should_resize_image_preserving_aspect_ratio() {
+        use crate::utils::thumbnail_renderer::{ImageThumbnailRenderer, ThumbnailRendererTrait};
+        use crate::enums::common_error::CommonError;
+        use image::{DynamicImage, ImageFormat, RgbaImage};
+        use std::io::Cursor;
+
+        let mut image_content = Vec::new();
+        DynamicImage::ImageRgba8(RgbaImage::new(64, 32))
+            .write_to(&mut Cursor::new(&mut image_content), ImageFormat::Png)
+            .unwrap();
+
+        let thumbnail_content = ImageThumbnailRenderer {}.render_thumbnail(&image_content, 16).unwrap();
+        let thumbnail = image::load_from_memory_with_format(&thumbnail_content, ImageFormat::Jpeg).unwrap();
+        assert_eq!((16, 8), (thumbnail.width(), thumbnail.height()));
+        assert_eq!(
+            Err(CommonError::NO_VALID_INPUT_OR_PARAMETER),
+            ImageThumbnailRenderer {}.render_thumbnail(b"not an image", 16)
+        );
+    }
+}